@@ -6,6 +6,8 @@ use std::str::{self, FromStr};
 // opening a GitHub issue if your build environment requires some way to enable
 // these cfgs other than by executing our build script.
 fn main() {
+    println!("cargo:rustc-check-cfg=cfg(syn_nightly_diagnostics)");
+
     let compiler = match rustc_version() {
         Some(compiler) => compiler,
         None => return,
@@ -18,6 +20,16 @@ fn main() {
     if !compiler.nightly {
         println!("cargo:rustc-cfg=syn_disable_nightly_tests");
     }
+
+    // The nightly-diagnostics feature falls back to stable rendering rather
+    // than failing the build on a stable compiler.
+    let wasm = match env::var("CARGO_CFG_TARGET_ARCH") {
+        Ok(arch) => arch == "wasm32",
+        Err(_) => false,
+    };
+    if compiler.nightly && !wasm && env::var_os("CARGO_FEATURE_NIGHTLY_DIAGNOSTICS").is_some() {
+        println!("cargo:rustc-cfg=syn_nightly_diagnostics");
+    }
 }
 
 struct Compiler {
@@ -6,8 +6,9 @@
 // crate (https://docs.rs/syn-codegen/) provides the data structures for parsing
 // and making use of syn.json from Rust code.
 //
-// Finally this crate generates the Visit, VisitMut, and Fold traits in Syn
-// programmatically from the syntax tree description.
+// Finally this crate generates the Visit, VisitMut, and Fold traits, the
+// context-aware VisitCtx and VisitMutCtx traits, as well as the serde impls, in
+// Syn programmatically from the syntax tree description.
 
 #![recursion_limit = "128"]
 #![allow(clippy::needless_pass_by_value)]
@@ -21,8 +22,10 @@ mod gen;
 mod json;
 mod operand;
 mod parse;
+mod serde_impls;
 mod version;
 mod visit;
+mod visit_ctx;
 mod visit_mut;
 
 use crate::error::Result;
@@ -42,6 +45,8 @@ fn do_main() -> Result<()> {
     fold::generate(&defs)?;
     visit::generate(&defs)?;
     visit_mut::generate(&defs)?;
+    visit_ctx::generate(&defs)?;
     debug::generate(&defs)?;
+    serde_impls::generate(&defs)?;
     Ok(())
 }
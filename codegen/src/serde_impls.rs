@@ -0,0 +1,517 @@
+use crate::error::Result;
+use crate::operand::{Borrowed, Operand, Owned};
+use crate::{file, full};
+use proc_macro2::{Ident, Literal, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const SERDE_SRC: &str = "../src/gen/serde.rs";
+
+fn requires_full(features: &Features) -> bool {
+    features.any.contains("full") && features.any.len() == 1
+}
+
+// Fields that carry no information besides their span. These are left out of
+// the serialized representation and filled in with a default on the way back.
+fn skipped_field(ty: &Type) -> Option<TokenStream> {
+    match ty {
+        Type::Ext(t) if t == "Span" => Some(quote!(Span::call_site())),
+        Type::Syn(t) if t == "Reserved" => Some(quote!(Default::default())),
+        _ => None,
+    }
+}
+
+// Whether the type contains a proc-macro2 type, which needs to go through the
+// Ser and De adapters because proc-macro2 does not implement serde traits.
+fn has_ext(ty: &Type) -> bool {
+    match ty {
+        Type::Ext(_) => true,
+        Type::Option(t) | Type::Box(t) | Type::Vec(t) => has_ext(t),
+        Type::Punctuated(p) => has_ext(&p.element),
+        Type::Tuple(t) => t.iter().any(has_ext),
+        Type::Syn(_) | Type::Std(_) | Type::Token(_) | Type::Group(_) => false,
+    }
+}
+
+fn token_type(name: &str, defs: &Definitions) -> TokenStream {
+    let repr = &defs.tokens[name];
+    if repr == "await" {
+        quote!(crate::token::Await)
+    } else {
+        syn::parse_str(&format!("Token![{}]", repr)).unwrap()
+    }
+}
+
+// The type to deserialize a field as before converting it into the syntax
+// tree type.
+fn de_type(ty: &Type, defs: &Definitions) -> TokenStream {
+    match ty {
+        Type::Syn(t) | Type::Std(t) | Type::Group(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            quote!(#ident)
+        }
+        Type::Ext(t) => {
+            let ident = Ident::new(t, Span::call_site());
+            quote!(De<#ident>)
+        }
+        Type::Token(t) => token_type(t, defs),
+        Type::Punctuated(p) => {
+            let element = de_type(&p.element, defs);
+            let punct = token_type(&p.punct, defs);
+            quote!(Punctuated<#element, #punct>)
+        }
+        Type::Option(t) => {
+            let inner = de_type(t, defs);
+            quote!(Option<#inner>)
+        }
+        Type::Box(t) => {
+            let inner = de_type(t, defs);
+            quote!(Box<#inner>)
+        }
+        Type::Vec(t) => {
+            let inner = de_type(t, defs);
+            quote!(Vec<#inner>)
+        }
+        Type::Tuple(t) => {
+            let inner = t.iter().map(|ty| de_type(ty, defs));
+            quote!((#(#inner),*))
+        }
+    }
+}
+
+// Expression of a serializable representation of the operand.
+fn ser_value(ty: &Type, operand: &Operand) -> TokenStream {
+    if !has_ext(ty) {
+        return operand.ref_tokens();
+    }
+    let name = operand.tokens();
+    match ty {
+        Type::Ext(_) => {
+            let val = operand.ref_tokens();
+            quote!(Ser(#val))
+        }
+        Type::Option(t) => match **t {
+            Type::Ext(_) => quote!(#name.as_ref().map(Ser)),
+            _ => {
+                let inner = ser_value(t, &Borrowed(quote!(it)));
+                quote!(#name.as_ref().map(|it| #inner))
+            }
+        },
+        Type::Box(t) => ser_value(t, &Owned(quote!(*#name))),
+        Type::Tuple(t) => {
+            let elems = t.iter().enumerate().map(|(i, ty)| {
+                let i = Index::from(i);
+                ser_value(ty, &Owned(quote!(#name.#i)))
+            });
+            quote!((#(#elems),*))
+        }
+        Type::Vec(_) | Type::Punctuated(_) => unimplemented!("serde of {:?}", ty),
+        Type::Syn(_) | Type::Std(_) | Type::Token(_) | Type::Group(_) => unreachable!(),
+    }
+}
+
+// Expression converting the owned value `val` of type `de_type(ty)` into `ty`.
+fn de_value(ty: &Type, val: TokenStream) -> TokenStream {
+    if !has_ext(ty) {
+        return val;
+    }
+    match ty {
+        Type::Ext(_) => quote!(#val.0),
+        Type::Option(t) => {
+            let inner = de_value(t, quote!(it));
+            quote!(#val.map(|it| #inner))
+        }
+        Type::Box(t) => {
+            let inner = de_value(t, quote!(*#val));
+            quote!(Box::new(#inner))
+        }
+        Type::Tuple(t) => {
+            let bindings: Vec<Ident> = (0..t.len())
+                .map(|i| Ident::new(&format!("_binding_{}", i), Span::call_site()))
+                .collect();
+            let elems = t
+                .iter()
+                .zip(&bindings)
+                .map(|(ty, binding)| de_value(ty, quote!(#binding)));
+            quote!({
+                let (#(#bindings),*) = #val;
+                (#(#elems),*)
+            })
+        }
+        Type::Vec(_) | Type::Punctuated(_) => unimplemented!("serde of {:?}", ty),
+        Type::Syn(_) | Type::Std(_) | Type::Token(_) | Type::Group(_) => unreachable!(),
+    }
+}
+
+fn expand_struct(
+    ident: &Ident,
+    fields: &[(&String, &Type)],
+    defs: &Definitions,
+) -> (TokenStream, TokenStream) {
+    let name = ident.to_string();
+    let expecting = format!("struct {}", name);
+
+    let mut serialized = Vec::new();
+    let mut defaults = TokenStream::new();
+    for (field, ty) in fields {
+        let id = Ident::new(field, Span::call_site());
+        match skipped_field(ty) {
+            Some(default) => defaults.extend(quote!(#id: #default,)),
+            None => serialized.push((field.as_str(), id, *ty)),
+        }
+    }
+
+    let len = Literal::usize_unsuffixed(serialized.len());
+    let names = serialized.iter().map(|(field, _, _)| field);
+    let ser_fields = serialized.iter().map(|(field, id, ty)| {
+        let value = ser_value(ty, &Owned(quote!(self.#id)));
+        let value = if has_ext(ty) { quote!(&#value) } else { value };
+        quote! {
+            state.serialize_field(#field, #value)?;
+        }
+    });
+
+    let mut seq_fields = TokenStream::new();
+    let mut map_slots = TokenStream::new();
+    let mut map_arms = TokenStream::new();
+    let mut map_required = TokenStream::new();
+    let mut construct = TokenStream::new();
+    for (i, (_, id, ty)) in serialized.iter().enumerate() {
+        let i = Literal::usize_unsuffixed(i);
+        let de_ty = de_type(ty, defs);
+        seq_fields.extend(quote! {
+            let #id: #de_ty = element(&mut seq, #i, &self)?;
+        });
+        map_slots.extend(quote! {
+            let mut #id: Option<#de_ty> = None;
+        });
+        map_arms.extend(quote! {
+            #i => field(&mut map, &mut #id, FIELDS[#i])?,
+        });
+        map_required.extend(quote! {
+            let #id = required(#id, FIELDS[#i])?;
+        });
+        if has_ext(ty) {
+            let value = de_value(ty, quote!(#id));
+            construct.extend(quote!(#id: #value,));
+        } else {
+            construct.extend(quote!(#id,));
+        }
+    }
+    construct.extend(defaults);
+
+    let ser = quote! {
+        impl Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                let mut state = serializer.serialize_struct(#name, #len)?;
+                #(#ser_fields)*
+                state.end()
+            }
+        }
+    };
+
+    let de = quote! {
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const FIELDS: &[&str] = &[#(#names),*];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: SeqAccess<'de>,
+                    {
+                        #seq_fields
+                        Ok(#ident { #construct })
+                    }
+
+                    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: MapAccess<'de>,
+                    {
+                        #map_slots
+                        while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                            match key {
+                                #map_arms
+                                _ => unreachable!(),
+                            }
+                        }
+                        #map_required
+                        Ok(#ident { #construct })
+                    }
+                }
+
+                deserializer.deserialize_struct(#name, FIELDS, Visitor)
+            }
+        }
+    };
+
+    (ser, de)
+}
+
+fn expand_enum(
+    ident: &Ident,
+    node: &Node,
+    variants: &[(&String, &Vec<Type>)],
+    defs: &Definitions,
+) -> (TokenStream, TokenStream) {
+    let name = ident.to_string();
+    let expecting = format!("enum {}", name);
+    let names = variants.iter().map(|(variant, _)| variant);
+
+    let mut ser_arms = TokenStream::new();
+    let mut de_arms = TokenStream::new();
+    let mut any_requires_full = false;
+    for (i, (variant, fields)) in variants.iter().enumerate() {
+        let variant_ident = Ident::new(variant, Span::call_site());
+        let index = Literal::u32_unsuffixed(i as u32);
+
+        let full_only = fields.iter().any(|ty| match ty {
+            Type::Syn(t) => {
+                let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+                requires_full(&target.features) && !requires_full(&node.features)
+            }
+            _ => false,
+        });
+        any_requires_full |= full_only;
+
+        let bindings: Vec<Ident> = (0..fields.len())
+            .map(|i| Ident::new(&format!("_binding_{}", i), Span::call_site()))
+            .collect();
+
+        let (mut ser, de) = match fields.len() {
+            0 => (
+                quote! {
+                    serializer.serialize_unit_variant(#name, #index, #variant)
+                },
+                quote! {
+                    variant.unit_variant().map(|()| #ident::#variant_ident)
+                },
+            ),
+            1 => {
+                let ty = &fields[0];
+                let value = ser_value(ty, &Borrowed(quote!(_binding_0)));
+                let value = if has_ext(ty) { quote!(&#value) } else { value };
+                let de_ty = de_type(ty, defs);
+                let de = if has_ext(ty) {
+                    let value = de_value(ty, quote!(it));
+                    quote! {
+                        variant
+                            .newtype_variant::<#de_ty>()
+                            .map(|it| #ident::#variant_ident(#value))
+                    }
+                } else {
+                    quote! {
+                        variant.newtype_variant().map(#ident::#variant_ident)
+                    }
+                };
+                (
+                    quote! {
+                        serializer.serialize_newtype_variant(#name, #index, #variant, #value)
+                    },
+                    de,
+                )
+            }
+            _ => {
+                let values = fields
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(ty, binding)| ser_value(ty, &Borrowed(quote!(#binding))));
+                let de_tys = fields.iter().map(|ty| de_type(ty, defs));
+                let de_values = fields
+                    .iter()
+                    .zip(&bindings)
+                    .map(|(ty, binding)| de_value(ty, quote!(#binding)));
+                (
+                    quote! {
+                        serializer.serialize_newtype_variant(
+                            #name,
+                            #index,
+                            #variant,
+                            &(#(#values),*),
+                        )
+                    },
+                    quote! {
+                        variant
+                            .newtype_variant::<(#(#de_tys),*)>()
+                            .map(|(#(#bindings),*)| #ident::#variant_ident(#(#de_values),*))
+                    },
+                )
+            }
+        };
+
+        let pattern = if fields.is_empty() {
+            quote!(#ident::#variant_ident)
+        } else {
+            quote!(#ident::#variant_ident(#(#bindings),*))
+        };
+        if full_only {
+            ser = quote!(full!(#ser));
+        }
+        ser_arms.extend(quote! {
+            #pattern => #ser,
+        });
+
+        let cfg = if full_only {
+            Some(quote!(#[cfg(feature = "full")]))
+        } else {
+            None
+        };
+        let i = Literal::usize_unsuffixed(i);
+        de_arms.extend(quote! {
+            #cfg
+            #i => #de,
+        });
+    }
+
+    let nonexhaustive = if node.exhaustive {
+        None
+    } else {
+        Some(quote!(_ => unreachable!()))
+    };
+
+    let unknown = if any_requires_full {
+        quote! {
+            #[cfg(not(feature = "full"))]
+            _ => Err(requires_full(VARIANTS[index])),
+            #[cfg(feature = "full")]
+            _ => unreachable!(),
+        }
+    } else {
+        quote!(_ => unreachable!())
+    };
+
+    let ser = quote! {
+        impl Serialize for #ident {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                match self {
+                    #ser_arms
+                    #nonexhaustive
+                }
+            }
+        }
+    };
+
+    let de = quote! {
+        impl<'de> Deserialize<'de> for #ident {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                const VARIANTS: &[&str] = &[#(#names),*];
+
+                struct Visitor;
+
+                impl<'de> de::Visitor<'de> for Visitor {
+                    type Value = #ident;
+
+                    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                        formatter.write_str(#expecting)
+                    }
+
+                    fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+                    where
+                        A: EnumAccess<'de>,
+                    {
+                        let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                        match index {
+                            #de_arms
+                            #unknown
+                        }
+                    }
+                }
+
+                deserializer.deserialize_enum(#name, VARIANTS, Visitor)
+            }
+        }
+    };
+
+    (ser, de)
+}
+
+fn node(impls: &mut TokenStream, s: &Node, defs: &Definitions) {
+    let ident = Ident::new(&s.ident, Span::call_site());
+
+    // Types without public structure, such as the literal types, implement
+    // their serde traits by hand.
+    let (ser, de) = match &s.data {
+        Data::Struct(fields) => {
+            let fields: Vec<_> = fields.iter().collect();
+            expand_struct(&ident, &fields, defs)
+        }
+        Data::Enum(variants) => {
+            let variants: Vec<_> = variants.iter().collect();
+            expand_enum(&ident, s, &variants, defs)
+        }
+        Data::Private => return,
+    };
+
+    let features = &s.features.any;
+    let cfg = match features.len() {
+        0 => quote!(),
+        1 => quote!(#[cfg(feature = #(#features)*)]),
+        _ => quote!(#[cfg(any(#(feature = #features),*))]),
+    };
+
+    impls.extend(quote! {
+        #cfg
+        #ser
+
+        #cfg
+        #de
+    });
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let mut types = defs.types.clone();
+    types.sort_by(|a, b| a.ident.cmp(&b.ident));
+
+    let mut impls = TokenStream::new();
+    for s in &types {
+        if s.ident == "Reserved" {
+            continue;
+        }
+        node(&mut impls, s, defs);
+    }
+
+    let full_macro = full::get_macro();
+    file::write(
+        SERDE_SRC,
+        quote! {
+            // Unreachable code is generated sometimes without the full feature.
+            #![allow(unreachable_code, unused_variables)]
+
+            use crate::*;
+            use crate::gen::helper::serde::*;
+            use crate::punctuated::Punctuated;
+            use crate::token::{Brace, Bracket, Paren, Group};
+            use proc_macro2::{Literal, Span, TokenStream};
+            use serde::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess};
+            use serde::ser::{Serialize, SerializeStruct, Serializer};
+            use std::fmt;
+            use std::result::Result;
+
+            #full_macro
+
+            #impls
+        },
+    )?;
+    Ok(())
+}
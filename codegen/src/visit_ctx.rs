@@ -0,0 +1,442 @@
+use crate::error::Result;
+use crate::operand::{Borrowed, Operand, Owned};
+use crate::{file, full, gen};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
+use syn::Index;
+use syn_codegen::{Data, Definitions, Features, Node, Type};
+
+const VISIT_CTX_SRC: &str = "../src/gen/visit_ctx.rs";
+
+#[derive(Copy, Clone, PartialEq)]
+enum Flavor {
+    Ref,
+    Mut,
+}
+
+fn method_name(item: &str, flavor: Flavor) -> Ident {
+    let ident = gen::under_name(item);
+    let name = match flavor {
+        Flavor::Ref => format!("visit_{}", ident),
+        Flavor::Mut => format!("visit_{}_mut", ident),
+    };
+    Ident::new(&name, Span::call_site())
+}
+
+fn simple_visit(item: &str, name: &Operand, flavor: Flavor) -> TokenStream {
+    let method = method_name(item, flavor);
+    let name = match flavor {
+        Flavor::Ref => name.ref_tokens(),
+        Flavor::Mut => name.ref_mut_tokens(),
+    };
+    quote! {
+        #method(v, #name, ancestors)?
+    }
+}
+
+fn noop_visit(name: &Operand) -> TokenStream {
+    let name = name.tokens();
+    quote! {
+        skip!(#name)
+    }
+}
+
+fn visit(
+    ty: &Type,
+    features: &Features,
+    defs: &Definitions,
+    name: &Operand,
+    flavor: Flavor,
+) -> Option<TokenStream> {
+    let ref_tokens = |name: &Operand| match flavor {
+        Flavor::Ref => name.ref_tokens(),
+        Flavor::Mut => name.ref_mut_tokens(),
+    };
+    match ty {
+        Type::Box(t) => {
+            let name = name.owned_tokens();
+            visit(t, features, defs, &Owned(quote!(*#name)), flavor)
+        }
+        Type::Vec(t) | Type::Punctuated(syn_codegen::Punctuated { element: t, .. }) => {
+            let operand = Borrowed(quote!(it));
+            let val = visit(t, features, defs, &operand, flavor)?;
+            let name = ref_tokens(name);
+            Some(quote! {
+                for it in #name {
+                    #val
+                }
+            })
+        }
+        Type::Option(t) => {
+            let it = Borrowed(quote!(it));
+            let val = visit(t, features, defs, &it, flavor)?;
+            let name = name.owned_tokens();
+            let name = match flavor {
+                Flavor::Ref => quote!(&#name),
+                Flavor::Mut => quote!(&mut #name),
+            };
+            Some(quote! {
+                if let Some(it) = #name {
+                    #val
+                }
+            })
+        }
+        Type::Tuple(t) => {
+            let mut code = TokenStream::new();
+            for (i, elem) in t.iter().enumerate() {
+                let name = name.tokens();
+                let i = Index::from(i);
+                let it = Owned(quote!((#name).#i));
+                let val =
+                    visit(elem, features, defs, &it, flavor).unwrap_or_else(|| noop_visit(&it));
+                code.extend(val);
+                code.extend(quote!(;));
+            }
+            Some(code)
+        }
+        Type::Syn(t) => {
+            fn requires_full(features: &Features) -> bool {
+                features.any.contains("full") && features.any.len() == 1
+            }
+            let mut res = simple_visit(t, name, flavor);
+            let target = defs.types.iter().find(|ty| ty.ident == *t).unwrap();
+            if requires_full(&target.features) && !requires_full(features) {
+                res = quote!(full!(#res));
+            }
+            Some(res)
+        }
+        Type::Ext(t) if t == "Ident" => Some(simple_visit(t, name, flavor)),
+        Type::Token(_) | Type::Group(_) | Type::Ext(_) | Type::Std(_) => None,
+    }
+}
+
+fn walk_children(s: &Node, defs: &Definitions, flavor: Flavor) -> TokenStream {
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let mut walk = TokenStream::new();
+
+    match &s.data {
+        Data::Enum(variants) => {
+            let mut visit_variants = TokenStream::new();
+
+            for (variant, fields) in variants {
+                let variant_ident = Ident::new(variant, Span::call_site());
+
+                if fields.is_empty() {
+                    visit_variants.extend(quote! {
+                        #ty::#variant_ident => {}
+                    });
+                } else {
+                    let mut bind_visit_fields = TokenStream::new();
+                    let mut visit_fields = TokenStream::new();
+
+                    for (idx, ty) in fields.iter().enumerate() {
+                        let name = format!("_binding_{}", idx);
+                        let binding = Ident::new(&name, Span::call_site());
+
+                        bind_visit_fields.extend(quote! {
+                            #binding,
+                        });
+
+                        let borrowed_binding = Borrowed(quote!(#binding));
+
+                        visit_fields.extend(
+                            visit(ty, &s.features, defs, &borrowed_binding, flavor)
+                                .unwrap_or_else(|| noop_visit(&borrowed_binding)),
+                        );
+
+                        visit_fields.extend(quote!(;));
+                    }
+
+                    visit_variants.extend(quote! {
+                        #ty::#variant_ident(#bind_visit_fields) => {
+                            #visit_fields
+                        }
+                    });
+                }
+            }
+
+            let nonexhaustive = if s.exhaustive {
+                None
+            } else {
+                Some(quote!(_ => unreachable!()))
+            };
+
+            walk.extend(quote! {
+                match node {
+                    #visit_variants
+                    #nonexhaustive
+                }
+            });
+        }
+        Data::Struct(fields) => {
+            for (field, ty) in fields {
+                if let Type::Syn(ty) = ty {
+                    if ty == "Reserved" {
+                        continue;
+                    }
+                }
+
+                let id = Ident::new(&field, Span::call_site());
+                let ref_toks = Owned(quote!(node.#id));
+                let visit_field = visit(&ty, &s.features, defs, &ref_toks, flavor)
+                    .unwrap_or_else(|| noop_visit(&ref_toks));
+                walk.extend(quote! {
+                    #visit_field;
+                });
+            }
+        }
+        Data::Private => {}
+    }
+
+    walk
+}
+
+fn node_kind(variants: &mut TokenStream, kinds: &mut TokenStream, s: &Node, _defs: &Definitions) {
+    if s.ident == "Span" {
+        return;
+    }
+    let ty = Ident::new(&s.ident, Span::call_site());
+    variants.extend(quote! {
+        #ty(&'ast #ty),
+    });
+    kinds.extend(quote! {
+        #ty,
+    });
+}
+
+fn kind_arm(arms: &mut TokenStream, dispatch: &mut TokenStream, s: &Node, _defs: &Definitions) {
+    if s.ident == "Span" {
+        return;
+    }
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let visit_fn = method_name(&s.ident, Flavor::Ref);
+    arms.extend(quote! {
+        Node::#ty(_) => NodeKind::#ty,
+    });
+    dispatch.extend(quote! {
+        Node::#ty(node) => #visit_fn(v, node, ancestors),
+    });
+}
+
+fn node_from(impls: &mut TokenStream, _unused: &mut TokenStream, s: &Node, _defs: &Definitions) {
+    if s.ident == "Span" {
+        return;
+    }
+    let ty = Ident::new(&s.ident, Span::call_site());
+    impls.extend(quote! {
+        #[cfg(feature = "visit")]
+        impl<'ast> From<&'ast #ty> for Node<'ast> {
+            fn from(node: &'ast #ty) -> Self {
+                Node::#ty(node)
+            }
+        }
+    });
+}
+
+fn visit_node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Definitions) {
+    if s.ident == "Span" {
+        return;
+    }
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let visit_fn = method_name(&s.ident, Flavor::Ref);
+    let walk = walk_children(s, defs, Flavor::Ref);
+
+    traits.extend(quote! {
+        fn #visit_fn(&mut self, i: &'ast #ty, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+            self.visit_node(Node::#ty(i), ancestors)
+        }
+    });
+
+    impls.extend(quote! {
+        #[cfg(feature = "visit")]
+        pub fn #visit_fn<'ast, V>(
+            v: &mut V,
+            node: &'ast #ty,
+            ancestors: &mut Vec<Node<'ast>>,
+        ) -> Result<(), V::Break>
+        where
+            V: VisitCtx<'ast> + ?Sized,
+        {
+            enter!(v.#visit_fn(node, ancestors));
+            ancestors.push(Node::#ty(node));
+            #walk
+            ancestors.pop();
+            Ok(())
+        }
+    });
+}
+
+fn visit_mut_node(traits: &mut TokenStream, impls: &mut TokenStream, s: &Node, defs: &Definitions) {
+    if s.ident == "Span" {
+        return;
+    }
+    let ty = Ident::new(&s.ident, Span::call_site());
+    let visit_fn = method_name(&s.ident, Flavor::Mut);
+    let walk = walk_children(s, defs, Flavor::Mut);
+
+    traits.extend(quote! {
+        fn #visit_fn(&mut self, i: &mut #ty, ancestors: &[NodeKind]) -> Control<Self::Break> {
+            Control::Continue
+        }
+    });
+
+    impls.extend(quote! {
+        #[cfg(feature = "visit-mut")]
+        pub fn #visit_fn<V>(
+            v: &mut V,
+            node: &mut #ty,
+            ancestors: &mut Vec<NodeKind>,
+        ) -> Result<(), V::Break>
+        where
+            V: VisitMutCtx + ?Sized,
+        {
+            enter!(v.#visit_fn(node, ancestors));
+            ancestors.push(NodeKind::#ty);
+            #walk
+            ancestors.pop();
+            Ok(())
+        }
+    });
+}
+
+pub fn generate(defs: &Definitions) -> Result<()> {
+    let (node_variants, kind_variants) = gen::traverse(defs, node_kind);
+    let (kind_arms, dispatch_arms) = gen::traverse(defs, kind_arm);
+    let (from_impls, _) = gen::traverse(defs, node_from);
+    let (visit_traits, visit_impls) = gen::traverse(defs, visit_node);
+    let (visit_mut_traits, visit_mut_impls) = gen::traverse(defs, visit_mut_node);
+    let full_macro = full::get_macro();
+    file::write(
+        VISIT_CTX_SRC,
+        quote! {
+            #![allow(unused_variables)]
+
+            use crate::*;
+            use std::result::Result;
+
+            #full_macro
+
+            macro_rules! skip {
+                ($($tt:tt)*) => {};
+            }
+
+            macro_rules! enter {
+                ($control:expr) => {
+                    match $control {
+                        Control::Continue => {}
+                        Control::SkipChildren => return Ok(()),
+                        Control::Break(value) => return Err(value),
+                    }
+                };
+            }
+
+            /// What a traversal should do after a hook has seen a node.
+            ///
+            /// *This type is available if Syn is built with the `"visit"` or
+            /// `"visit-mut"` feature.*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum Control<B> {
+                /// Descend into the children of the node.
+                Continue,
+                /// Leave the children of the node unvisited but keep walking
+                /// its siblings.
+                SkipChildren,
+                /// Stop the traversal, handing the value back to the caller.
+                Break(B),
+            }
+
+            /// A shared borrow of any syntax tree node, as found on the stack
+            /// of ancestors passed to [`VisitCtx`] hooks.
+            ///
+            /// *This type is available if Syn is built with the `"visit"`
+            /// feature.*
+            #[cfg(feature = "visit")]
+            #[derive(Clone, Copy)]
+            pub enum Node<'ast> {
+                #node_variants
+            }
+
+            #[cfg(feature = "visit")]
+            impl<'ast> Node<'ast> {
+                /// The type of syntax tree node this is.
+                pub fn kind(self) -> NodeKind {
+                    match self {
+                        #kind_arms
+                    }
+                }
+            }
+
+            #from_impls
+
+            /// The type of a syntax tree node, as found on the path of
+            /// ancestors passed to [`VisitMutCtx`] hooks.
+            ///
+            /// *This type is available if Syn is built with the `"visit"` or
+            /// `"visit-mut"` feature.*
+            #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+            pub enum NodeKind {
+                #kind_variants
+            }
+
+            /// Syntax tree traversal over a shared borrow of a syntax tree
+            /// that tells each hook where it is and lets it cut the walk short.
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This trait is available if Syn is built with the `"visit"`
+            /// feature.*
+            #[cfg(feature = "visit")]
+            pub trait VisitCtx<'ast> {
+                /// The value a hook hands back through [`Control::Break`].
+                type Break;
+
+                /// Hook called for every node whose own hook is not
+                /// overridden.
+                fn visit_node(&mut self, node: Node<'ast>, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+                    Control::Continue
+                }
+
+                #visit_traits
+            }
+
+            /// Walks any syntax tree node, dispatching on its type.
+            #[cfg(feature = "visit")]
+            pub fn visit_node<'ast, V>(
+                v: &mut V,
+                node: Node<'ast>,
+                ancestors: &mut Vec<Node<'ast>>,
+            ) -> Result<(), V::Break>
+            where
+                V: VisitCtx<'ast> + ?Sized,
+            {
+                match node {
+                    #dispatch_arms
+                }
+            }
+
+            #visit_impls
+
+            /// Syntax tree traversal over an exclusive borrow of a syntax tree
+            /// that tells each hook where it is and lets it cut the walk short.
+            ///
+            /// See the [module documentation] for details.
+            ///
+            /// [module documentation]: self
+            ///
+            /// *This trait is available if Syn is built with the `"visit-mut"`
+            /// feature.*
+            #[cfg(feature = "visit-mut")]
+            pub trait VisitMutCtx {
+                /// The value a hook hands back through [`Control::Break`].
+                type Break;
+
+                #visit_mut_traits
+            }
+
+            #visit_mut_impls
+        },
+    )?;
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use crate::attr::{self, FieldAttrs, VariantAttrs};
+use proc_macro2::Span;
+use syn::ext::IdentExt;
+use syn::{Error, Fields, Ident, Index, Member, Result, Type};
+
+pub struct Field<'a> {
+    pub member: Member,
+    pub binding: Ident,
+    pub ty: &'a Type,
+    pub attrs: FieldAttrs,
+}
+
+pub struct Variant<'a> {
+    pub ident: &'a Ident,
+    pub fields: Vec<Field<'a>>,
+    pub attrs: VariantAttrs,
+}
+
+impl<'a> Field<'a> {
+    pub fn multiple_from_syn(fields: &'a Fields) -> Result<Vec<Self>> {
+        let mut result = Vec::new();
+        for (i, field) in fields.iter().enumerate() {
+            let (member, binding) = match &field.ident {
+                Some(ident) => (
+                    Member::Named(ident.clone()),
+                    Ident::new(&format!("__field_{}", ident.unraw()), Span::call_site()),
+                ),
+                None => (
+                    Member::Unnamed(Index::from(i)),
+                    Ident::new(&format!("__field{}", i), Span::call_site()),
+                ),
+            };
+            result.push(Field {
+                member,
+                binding,
+                ty: &field.ty,
+                attrs: attr::field_attrs(&field.attrs)?,
+            });
+        }
+        validate(&result)?;
+        Ok(result)
+    }
+}
+
+impl<'a> Variant<'a> {
+    pub fn from_syn(variant: &'a syn::Variant) -> Result<Self> {
+        Ok(Variant {
+            ident: &variant.ident,
+            fields: Field::multiple_from_syn(&variant.fields)?,
+            attrs: attr::variant_attrs(&variant.attrs)?,
+        })
+    }
+}
+
+// Every `in = ...` must name an earlier field that opens a group, so that the
+// group's contents are available by the time the field is parsed.
+fn validate(fields: &[Field]) -> Result<()> {
+    for (i, field) in fields.iter().enumerate() {
+        if let Some(inside) = &field.attrs.inside {
+            let opens_group = fields[..i].iter().any(|earlier| {
+                same_member(&earlier.member, inside) && earlier.attrs.group.is_some()
+            });
+            if !opens_group {
+                return Err(Error::new_spanned(
+                    inside,
+                    "`in` must name an earlier field marked parenthesized, braced or bracketed",
+                ));
+            }
+        }
+        if field.attrs.parse_with.is_some() && field.attrs.group.is_some() {
+            return Err(Error::new_spanned(
+                field.ty,
+                "`parse_with` cannot be combined with a group",
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub fn same_member(a: &Member, b: &Member) -> bool {
+    match (a, b) {
+        (Member::Named(a), Member::Named(b)) => a == b,
+        (Member::Unnamed(a), Member::Unnamed(b)) => a.index == b.index,
+        _ => false,
+    }
+}
@@ -0,0 +1,172 @@
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream, Result};
+use syn::punctuated::Punctuated;
+use syn::{Attribute, Error, Ident, LitStr, Member, Path, Token, Type};
+
+/// The delimiters a field of type `token::Paren`, `token::Brace` or
+/// `token::Bracket` opens.
+#[derive(Copy, Clone)]
+pub enum Group {
+    Parenthesized,
+    Braced,
+    Bracketed,
+}
+
+#[derive(Default)]
+pub struct FieldAttrs {
+    pub group: Option<Group>,
+    pub inside: Option<Member>,
+    pub parse_with: Option<Path>,
+    pub peek: Option<Type>,
+}
+
+#[derive(Default)]
+pub struct VariantAttrs {
+    pub peek: Option<Type>,
+}
+
+/// Where the value of an absent key comes from: `Default::default()` or the
+/// named function.
+pub enum DefaultValue {
+    Trait,
+    Path(Path),
+}
+
+#[derive(Default)]
+pub struct MetaFieldAttrs {
+    pub rename: Option<LitStr>,
+    pub default: Option<DefaultValue>,
+}
+
+#[derive(Default)]
+pub struct MetaContainerAttrs {
+    pub attribute: Option<Path>,
+}
+
+enum Arg {
+    Group(Ident, Group),
+    In(Ident, Member),
+    ParseWith(Ident, Path),
+    Peek(Ident, Type),
+    Rename(Ident, LitStr),
+    Default(Ident, DefaultValue),
+    Attribute(Ident, Path),
+}
+
+impl Arg {
+    fn key(&self) -> &Ident {
+        match self {
+            Arg::Group(key, _)
+            | Arg::In(key, _)
+            | Arg::ParseWith(key, _)
+            | Arg::Peek(key, _)
+            | Arg::Rename(key, _)
+            | Arg::Default(key, _)
+            | Arg::Attribute(key, _) => key,
+        }
+    }
+}
+
+impl Parse for Arg {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let key = input.call(Ident::parse_any)?;
+        let group = match key.to_string().as_str() {
+            "parenthesized" => Some(Group::Parenthesized),
+            "braced" => Some(Group::Braced),
+            "bracketed" => Some(Group::Bracketed),
+            _ => None,
+        };
+        if let Some(group) = group {
+            return Ok(Arg::Group(key, group));
+        }
+        if key == "default" && !input.peek(Token![=]) {
+            return Ok(Arg::Default(key, DefaultValue::Trait));
+        }
+
+        input.parse::<Token![=]>()?;
+        match key.to_string().as_str() {
+            "in" => Ok(Arg::In(key, input.parse()?)),
+            "parse_with" => Ok(Arg::ParseWith(key, input.parse()?)),
+            "peek" => Ok(Arg::Peek(key, input.parse()?)),
+            "rename" => Ok(Arg::Rename(key, input.parse()?)),
+            "default" => Ok(Arg::Default(key, DefaultValue::Path(input.parse()?))),
+            "attribute" => Ok(Arg::Attribute(key, input.parse()?)),
+            _ => Err(Error::new(key.span(), "unknown syn attribute")),
+        }
+    }
+}
+
+fn args(attrs: &[Attribute]) -> Result<Vec<Arg>> {
+    let mut args = Vec::new();
+    for attr in attrs {
+        if attr.path.is_ident("syn") {
+            let parser = Punctuated::<Arg, Token![,]>::parse_terminated;
+            args.extend(attr.parse_args_with(parser)?);
+        }
+    }
+    Ok(args)
+}
+
+fn set<T>(slot: &mut Option<T>, key: &Ident, value: T) -> Result<()> {
+    if slot.is_some() {
+        return Err(Error::new(
+            key.span(),
+            format!("duplicate syn attribute `{}`", key),
+        ));
+    }
+    *slot = Some(value);
+    Ok(())
+}
+
+fn unsupported(arg: &Arg) -> Error {
+    let key = arg.key();
+    Error::new(key.span(), format!("`{}` is not supported here", key))
+}
+
+pub fn field_attrs(attrs: &[Attribute]) -> Result<FieldAttrs> {
+    let mut field = FieldAttrs::default();
+    for arg in args(attrs)? {
+        match arg {
+            Arg::Group(key, group) => set(&mut field.group, &key, group)?,
+            Arg::In(key, member) => set(&mut field.inside, &key, member)?,
+            Arg::ParseWith(key, path) => set(&mut field.parse_with, &key, path)?,
+            Arg::Peek(key, peek) => set(&mut field.peek, &key, peek)?,
+            other => return Err(unsupported(&other)),
+        }
+    }
+    Ok(field)
+}
+
+pub fn variant_attrs(attrs: &[Attribute]) -> Result<VariantAttrs> {
+    let mut variant = VariantAttrs::default();
+    for arg in args(attrs)? {
+        match arg {
+            Arg::Peek(key, peek) => set(&mut variant.peek, &key, peek)?,
+            other => return Err(unsupported(&other)),
+        }
+    }
+    Ok(variant)
+}
+
+pub fn meta_field_attrs(attrs: &[Attribute]) -> Result<MetaFieldAttrs> {
+    let mut field = MetaFieldAttrs::default();
+    for arg in args(attrs)? {
+        match arg {
+            Arg::Rename(key, name) => set(&mut field.rename, &key, name)?,
+            Arg::Default(key, default) => set(&mut field.default, &key, default)?,
+            other => return Err(unsupported(&other)),
+        }
+    }
+    Ok(field)
+}
+
+pub fn meta_container_attrs(attrs: &[Attribute]) -> Result<MetaContainerAttrs> {
+    let mut container = MetaContainerAttrs::default();
+    for arg in args(attrs)? {
+        match arg {
+            Arg::Attribute(key, path) => set(&mut container.attribute, &key, path)?,
+            other => return Err(unsupported(&other)),
+        }
+    }
+    Ok(container)
+}
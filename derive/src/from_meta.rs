@@ -0,0 +1,251 @@
+use crate::attr::{self, DefaultValue};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::ext::IdentExt;
+use syn::{Data, DeriveInput, Error, Fields, Ident, Result, Type};
+
+struct Field<'a> {
+    ident: &'a Ident,
+    binding: Ident,
+    key: String,
+    ty: &'a Type,
+    default: Option<DefaultValue>,
+}
+
+fn fields<'a>(input: &'a DeriveInput, derive: &str) -> Result<Vec<Field<'a>>> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => return Err(unsupported(input, derive)),
+        },
+        _ => return Err(unsupported(input, derive)),
+    };
+
+    let mut result = Vec::new();
+    for field in fields {
+        let ident = field.ident.as_ref().unwrap();
+        let attrs = attr::meta_field_attrs(&field.attrs)?;
+        let key = match &attrs.rename {
+            Some(rename) => rename.value(),
+            None => ident.unraw().to_string(),
+        };
+        result.push(Field {
+            ident,
+            binding: Ident::new(&format!("__field_{}", ident.unraw()), Span::call_site()),
+            key,
+            ty: &field.ty,
+            default: attrs.default,
+        });
+    }
+    Ok(result)
+}
+
+fn unsupported(input: &DeriveInput, derive: &str) -> Error {
+    Error::new(
+        input.ident.span(),
+        format!(
+            "{} can only be derived for structs with named fields",
+            derive
+        ),
+    )
+}
+
+pub fn derive_from_meta(input: &DeriveInput) -> Result<TokenStream> {
+    attr::meta_container_attrs(&input.attrs)?;
+    let fields = fields(input, "FromMeta")?;
+    let fill = fill(&fields, quote!(::syn::export::Span::call_site()));
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::syn::from_meta::FromMeta for #ident #ty_generics #where_clause {
+            fn from_meta(meta: &::syn::Meta) -> ::syn::Result<Self> {
+                match meta {
+                    ::syn::Meta::Path(_) => {
+                        let items = ::std::iter::empty::<&::syn::NestedMeta>();
+                        <Self as ::syn::from_meta::FromMeta>::from_list(items)
+                    }
+                    ::syn::Meta::List(list) => {
+                        <Self as ::syn::from_meta::FromMeta>::from_list(&list.nested)
+                    }
+                    ::syn::Meta::NameValue(_) => {
+                        let key = ::syn::export::ToTokens::to_token_stream(meta.path());
+                        ::syn::export::Err(::syn::Error::new_spanned(
+                            meta,
+                            format!("expected a list: `{}(...)`", key),
+                        ))
+                    }
+                }
+            }
+
+            fn from_list<'__a, __I>(items: __I) -> ::syn::Result<Self>
+            where
+                __I: ::std::iter::IntoIterator<Item = &'__a ::syn::NestedMeta>,
+            {
+                #fill
+            }
+        }
+    })
+}
+
+pub fn derive_from_attributes(input: &DeriveInput) -> Result<TokenStream> {
+    let container = attr::meta_container_attrs(&input.attrs)?;
+    let name = match container.attribute {
+        Some(path) => match path.segments.first() {
+            Some(segment) if path.leading_colon.is_none() && path.segments.len() == 1 => {
+                segment.ident.to_string()
+            }
+            _ => {
+                return Err(Error::new_spanned(
+                    path,
+                    "expected the name of the attribute",
+                ))
+            }
+        },
+        None => {
+            return Err(Error::new(
+                Span::call_site(),
+                "missing #[syn(attribute = ...)] naming the attribute to read",
+            ))
+        }
+    };
+    let fields = fields(input, "FromAttributes")?;
+    let span = quote! {
+        attrs
+            .iter()
+            .find(|attr| attr.path.is_ident(#name))
+            .map_or_else(::syn::export::Span::call_site, ::syn::spanned::Spanned::span)
+    };
+    let fill = fill(&fields, span);
+    let expected = format!("expected a list: `#[{}(...)]`", name);
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::syn::from_meta::FromAttributes for #ident #ty_generics #where_clause {
+            fn from_attributes(attrs: &[::syn::Attribute]) -> ::syn::Result<Self> {
+                let mut lists = ::std::vec::Vec::new();
+                for attr in attrs {
+                    if !attr.path.is_ident(#name) {
+                        continue;
+                    }
+                    match attr.parse_meta()? {
+                        ::syn::Meta::Path(_) => {}
+                        ::syn::Meta::List(list) => lists.push(list),
+                        meta @ ::syn::Meta::NameValue(_) => {
+                            return ::syn::export::Err(::syn::Error::new_spanned(meta, #expected));
+                        }
+                    }
+                }
+                let items = lists.iter().flat_map(|list| &list.nested);
+                #fill
+            }
+        }
+    })
+}
+
+// Matches each item of `items` to a field by its key, then builds `Self`,
+// filling in absent keys from defaults. Reports missing keys at `span`.
+fn fill(fields: &[Field], span: TokenStream) -> TokenStream {
+    let decls = fields.iter().map(|field| {
+        let binding = &field.binding;
+        if is_vec(field.ty) {
+            quote!(let mut #binding = ::std::vec::Vec::new();)
+        } else {
+            quote!(let mut #binding = ::syn::export::None;)
+        }
+    });
+
+    let branches = fields.iter().map(|field| {
+        let binding = &field.binding;
+        let key = &field.key;
+        let store = if is_vec(field.ty) {
+            quote! {
+                #binding.push(::syn::from_meta::FromMeta::from_meta(meta)?);
+            }
+        } else {
+            let duplicate = format!("duplicate key `{}`", key);
+            quote! {
+                if #binding.is_some() {
+                    return ::syn::export::Err(::syn::Error::new_spanned(path, #duplicate));
+                }
+                #binding = ::syn::export::Some(::syn::from_meta::FromMeta::from_meta(meta)?);
+            }
+        };
+        quote! {
+            if path.is_ident(#key) {
+                #store
+            } else
+        }
+    });
+
+    let expected = fields
+        .iter()
+        .map(|field| format!("`{}`", field.key))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let unknown = if fields.is_empty() {
+        "unknown key `{}`, expected no arguments".to_owned()
+    } else {
+        format!("unknown key `{{}}`, expected one of: {}", expected)
+    };
+
+    let inits = fields.iter().map(|field| {
+        let ident = field.ident;
+        let binding = &field.binding;
+        if is_vec(field.ty) {
+            return quote!(#ident: #binding);
+        }
+        let missing = match &field.default {
+            Some(DefaultValue::Trait) => quote!(::syn::export::Default::default()),
+            Some(DefaultValue::Path(path)) => quote!(#path()),
+            None => {
+                let message = format!("missing key `{}`", field.key);
+                quote! {
+                    match ::syn::from_meta::FromMeta::from_missing() {
+                        ::syn::export::Some(value) => value,
+                        ::syn::export::None => {
+                            return ::syn::export::Err(::syn::Error::new(#span, #message));
+                        }
+                    }
+                }
+            }
+        };
+        quote! {
+            #ident: match #binding {
+                ::syn::export::Some(value) => value,
+                ::syn::export::None => #missing,
+            }
+        }
+    });
+
+    quote! {
+        #(#decls)*
+        for item in items {
+            let meta = match item {
+                ::syn::NestedMeta::Meta(meta) => meta,
+                ::syn::NestedMeta::Lit(lit) => {
+                    return ::syn::export::Err(::syn::Error::new_spanned(lit, "unexpected literal"));
+                }
+            };
+            let path = meta.path();
+            #(#branches)* {
+                let key = ::syn::export::ToTokens::to_token_stream(path);
+                return ::syn::export::Err(::syn::Error::new_spanned(path, format!(#unknown, key)));
+            }
+        }
+        ::syn::export::Ok(Self {
+            #(#inits,)*
+        })
+    }
+}
+
+fn is_vec(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => match ty.path.segments.last() {
+            Some(segment) => segment.ident == "Vec",
+            None => false,
+        },
+        _ => false,
+    }
+}
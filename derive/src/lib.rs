@@ -0,0 +1,194 @@
+//! Derive macros for Syn's traits, configured by `#[syn(...)]` annotations.
+//!
+//! # Parse and ToTokens
+//!
+//! `#[derive(Parse, ToTokens)]` turns a struct or enum into a custom syntax
+//! tree node. The fields of a struct are parsed in order, each with its own `Parse` impl,
+//! and printed in the same order. The following attributes adjust how a field
+//! is handled:
+//!
+//! - `#[syn(parenthesized)]`, `#[syn(braced)]` or `#[syn(bracketed)]` on a
+//!   field of type `token::Paren`, `token::Brace` or `token::Bracket` parses a
+//!   delimited group with the corresponding macro from Syn.
+//!
+//! - `#[syn(in = field)]` parses the field from the contents of the group
+//!   opened by an earlier field, rather than from the surrounding input.
+//!
+//! - `#[syn(peek = Token![=])]` on a field of type `Option<T>` parses a `T`
+//!   only if the next token matches the given token type.
+//!
+//! - `#[syn(parse_with = path)]` parses the field by calling
+//!   `input.call(path)` instead of its `Parse` impl.
+//!
+//! A field of type `Punctuated<T, P>` is parsed with
+//! [`Punctuated::parse_terminated`] unless it names a different parser.
+//!
+//! Each variant of an enum is marked with `#[syn(peek = ...)]` naming the token
+//! that begins it. Variants are tried through `Lookahead1`, so input matching
+//! none of them fails with an error listing every token that was expected.
+//! The last variant may leave out `peek` to be parsed whenever no other
+//! variant applies.
+//!
+//! [`Punctuated::parse_terminated`]: https://docs.rs/syn/1.0/syn/punctuated/struct.Punctuated.html#method.parse_terminated
+//!
+//! ## Example
+//!
+//! ```
+//! use syn::punctuated::Punctuated;
+//! use syn::{token, Expr, Ident, LitStr, Token};
+//! use syn_derive::{Parse, ToTokens};
+//!
+//! // #[route(GET "/users" => list_users(page, limit = 10))]
+//! #[derive(Parse, ToTokens)]
+//! struct Route {
+//!     method: Method,
+//!     path: LitStr,
+//!     arrow_token: Token![=>],
+//!     handler: Ident,
+//!     #[syn(parenthesized)]
+//!     paren_token: token::Paren,
+//!     #[syn(in = paren_token)]
+//!     args: Punctuated<Arg, Token![,]>,
+//! }
+//!
+//! #[derive(Parse, ToTokens)]
+//! enum Method {
+//!     #[syn(peek = kw::GET)]
+//!     Get(kw::GET),
+//!     #[syn(peek = kw::POST)]
+//!     Post(kw::POST),
+//! }
+//!
+//! #[derive(Parse, ToTokens)]
+//! struct Arg {
+//!     name: Ident,
+//!     #[syn(peek = Token![=])]
+//!     default: Option<DefaultValue>,
+//! }
+//!
+//! #[derive(Parse, ToTokens)]
+//! struct DefaultValue {
+//!     eq_token: Token![=],
+//!     value: Expr,
+//! }
+//!
+//! mod kw {
+//!     syn::custom_keyword!(GET);
+//!     syn::custom_keyword!(POST);
+//! }
+//!
+//! let route: Route = syn::parse_str(r#"GET "/users" => list_users(page, limit = 10)"#).unwrap();
+//! assert_eq!(route.args.len(), 2);
+//!
+//! let err = syn::parse_str::<Route>(r#"PUT "/users" => f()"#).err().unwrap();
+//! assert_eq!(err.to_string(), "expected `GET` or `POST`");
+//! ```
+//!
+//! # FromMeta and FromAttributes
+//!
+//! `#[derive(FromMeta)]` implements [`syn::from_meta::FromMeta`] for a struct
+//! with named fields, reading each field from the attribute list item whose
+//! key is the field's name. `#[derive(FromAttributes)]` implements
+//! [`syn::from_meta::FromAttributes`] in the same way, gathering the items of
+//! every attribute named by `#[syn(attribute = name)]` on the struct.
+//!
+//! - `#[syn(rename = "key")]` reads the field from a different key.
+//!
+//! - `#[syn(default)]` uses `Default::default()` when the key is absent, and
+//!   `#[syn(default = path)]` calls the given function instead. Without
+//!   either, an absent key is an error unless the field's type provides a
+//!   value for it, as `bool` and `Option<T>` do.
+//!
+//! A field of type `Vec<T>` collects every occurrence of its key, so the key
+//! may be repeated. Any other key may appear at most once.
+//!
+//! [`syn::from_meta::FromMeta`]: https://docs.rs/syn/1.0/syn/from_meta/trait.FromMeta.html
+//! [`syn::from_meta::FromAttributes`]: https://docs.rs/syn/1.0/syn/from_meta/trait.FromAttributes.html
+//!
+//! ## Example
+//!
+//! ```
+//! use syn::from_meta::FromAttributes;
+//! use syn::{DeriveInput, Path};
+//! use syn_derive::{FromAttributes, FromMeta};
+//!
+//! #[derive(FromAttributes)]
+//! #[syn(attribute = serde)]
+//! struct ContainerOptions {
+//!     rename: Option<String>,
+//!     deny_unknown_fields: bool,
+//!     #[syn(rename = "bound")]
+//!     bounds: Vec<String>,
+//!     #[syn(default)]
+//!     tag: Tag,
+//! }
+//!
+//! #[derive(FromMeta, Default)]
+//! struct Tag {
+//!     name: String,
+//!     with: Option<Path>,
+//! }
+//!
+//! let input: DeriveInput = syn::parse_str(r#"
+//!     #[serde(rename = "point", bound = "T: Copy")]
+//!     #[serde(tag(name = "kind", with = "crate::tags"), bound = "U: Eq")]
+//!     struct Point<T, U>(T, U);
+//! "#).unwrap();
+//!
+//! let options = ContainerOptions::from_attributes(&input.attrs).unwrap();
+//! assert_eq!(options.rename.unwrap(), "point");
+//! assert!(!options.deny_unknown_fields);
+//! assert_eq!(options.bounds, ["T: Copy", "U: Eq"]);
+//! assert_eq!(options.tag.name, "kind");
+//!
+//! let input: DeriveInput = syn::parse_str(r#"
+//!     #[serde(rename = 1)]
+//!     struct Point;
+//! "#).unwrap();
+//!
+//! let err = ContainerOptions::from_attributes(&input.attrs).err().unwrap();
+//! assert_eq!(err.to_string(), "expected string literal");
+//! ```
+
+extern crate proc_macro;
+
+mod ast;
+mod attr;
+mod from_meta;
+mod parse;
+mod to_tokens;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput};
+
+#[proc_macro_derive(Parse, attributes(syn))]
+pub fn derive_parse(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    parse::derive(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(ToTokens, attributes(syn))]
+pub fn derive_to_tokens(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    to_tokens::derive(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(FromMeta, attributes(syn))]
+pub fn derive_from_meta(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_meta::derive_from_meta(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
+
+#[proc_macro_derive(FromAttributes, attributes(syn))]
+pub fn derive_from_attributes(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    from_meta::derive_from_attributes(&input)
+        .unwrap_or_else(|err| err.to_compile_error())
+        .into()
+}
@@ -0,0 +1,171 @@
+use crate::ast::{self, Field, Variant};
+use crate::attr::Group;
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Ident, Result, Type};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = Field::multiple_from_syn(&data.fields)?;
+            parse_fields(quote!(#ident), &fields)
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(Variant::from_syn)
+                .collect::<Result<Vec<_>>>()?;
+            parse_variants(ident, &variants)?
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "union as custom syntax is not supported",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::syn::parse::Parse for #ident #ty_generics #where_clause {
+            fn parse(input: ::syn::parse::ParseStream) -> ::syn::parse::Result<Self> {
+                #body
+            }
+        }
+    })
+}
+
+// Each variant is chosen by peeking at the next token through `Lookahead1`,
+// so that a failure lists every token that would have been accepted. A last
+// variant without `peek` is parsed when no other variant applies.
+fn parse_variants(ident: &Ident, variants: &[Variant]) -> Result<TokenStream> {
+    let (last, rest) = match variants.split_last() {
+        Some(split) => split,
+        None => {
+            return Err(Error::new(
+                ident.span(),
+                "enum without variants cannot be parsed",
+            ))
+        }
+    };
+
+    let mut branches = TokenStream::new();
+    for variant in rest {
+        let peek = match &variant.attrs.peek {
+            Some(peek) => peek,
+            None => {
+                return Err(Error::new(
+                    variant.ident.span(),
+                    "missing #[syn(peek = ...)] to select this variant",
+                ))
+            }
+        };
+        let var = variant.ident;
+        let parse = parse_fields(quote!(#ident::#var), &variant.fields);
+        branches.extend(quote! {
+            if lookahead.peek(#peek) {
+                #parse
+            } else
+        });
+    }
+
+    let var = last.ident;
+    let parse_last = parse_fields(quote!(#ident::#var), &last.fields);
+    let fallback = match &last.attrs.peek {
+        Some(peek) => quote! {
+            if lookahead.peek(#peek) {
+                #parse_last
+            } else {
+                ::syn::export::Err(lookahead.error())
+            }
+        },
+        None => quote! {
+            {
+                #parse_last
+            }
+        },
+    };
+
+    if branches.is_empty() && last.attrs.peek.is_none() {
+        return Ok(fallback);
+    }
+
+    Ok(quote! {
+        let lookahead = input.lookahead1();
+        #branches #fallback
+    })
+}
+
+fn parse_fields(path: TokenStream, fields: &[Field]) -> TokenStream {
+    let stmts = fields.iter().map(|field| {
+        let binding = &field.binding;
+        let ty = field.ty;
+        let source = match &field.attrs.inside {
+            Some(inside) => {
+                let group = fields
+                    .iter()
+                    .find(|group| ast::same_member(&group.member, inside))
+                    .unwrap();
+                content(&group.binding)
+            }
+            None => Ident::new("input", Span::call_site()),
+        };
+
+        if let Some(group) = field.attrs.group {
+            let content = content(binding);
+            let mac = match group {
+                Group::Parenthesized => quote!(parenthesized),
+                Group::Braced => quote!(braced),
+                Group::Bracketed => quote!(bracketed),
+            };
+            return quote! {
+                let #content;
+                let #binding: #ty = ::syn::#mac!(#content in #source);
+            };
+        }
+
+        let value = if let Some(parse_with) = &field.attrs.parse_with {
+            quote!(#source.call(#parse_with)?)
+        } else if let Some(peek) = &field.attrs.peek {
+            quote! {
+                if #source.peek(#peek) {
+                    ::syn::export::Some(#source.parse()?)
+                } else {
+                    ::syn::export::None
+                }
+            }
+        } else if is_punctuated(ty) {
+            quote!(#source.call(::syn::punctuated::Punctuated::parse_terminated)?)
+        } else {
+            quote!(#source.parse()?)
+        };
+        quote! {
+            let #binding: #ty = #value;
+        }
+    });
+
+    let members = fields.iter().map(|field| &field.member);
+    let bindings = fields.iter().map(|field| &field.binding);
+    quote! {
+        #(#stmts)*
+        ::syn::export::Ok(#path {
+            #(#members: #bindings,)*
+        })
+    }
+}
+
+fn content(binding: &Ident) -> Ident {
+    Ident::new(&format!("{}_content", binding), Span::call_site())
+}
+
+fn is_punctuated(ty: &Type) -> bool {
+    match ty {
+        Type::Path(ty) => match ty.path.segments.last() {
+            Some(segment) => segment.ident == "Punctuated",
+            None => false,
+        },
+        _ => false,
+    }
+}
@@ -0,0 +1,95 @@
+use crate::ast::{self, Field, Variant};
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+use syn::{Data, DeriveInput, Error, Member, Result};
+
+pub fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let ident = &input.ident;
+    let body = match &input.data {
+        Data::Struct(data) => {
+            let fields = Field::multiple_from_syn(&data.fields)?;
+            let pattern = pattern(quote!(#ident), &fields);
+            let print = print_fields(&fields, None);
+            quote! {
+                let #pattern = self;
+                #print
+            }
+        }
+        Data::Enum(data) => {
+            let variants = data
+                .variants
+                .iter()
+                .map(Variant::from_syn)
+                .collect::<Result<Vec<_>>>()?;
+            let arms = variants.iter().map(|variant| {
+                let var = variant.ident;
+                let pattern = pattern(quote!(#ident::#var), &variant.fields);
+                let print = print_fields(&variant.fields, None);
+                quote! {
+                    #pattern => {
+                        #print
+                    }
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return Err(Error::new(
+                Span::call_site(),
+                "union as custom syntax is not supported",
+            ))
+        }
+    };
+
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::syn::export::ToTokens for #ident #ty_generics #where_clause {
+            fn to_tokens(&self, tokens: &mut ::syn::export::TokenStream2) {
+                #body
+            }
+        }
+    })
+}
+
+fn pattern(path: TokenStream, fields: &[Field]) -> TokenStream {
+    let members = fields.iter().map(|field| &field.member);
+    let bindings = fields.iter().map(|field| &field.binding);
+    quote! {
+        #path { #(#members: #bindings,)* }
+    }
+}
+
+// Prints the fields that live directly inside `group`, or at the top level
+// if `group` is None. A field that opens a group surrounds the fields that
+// declare themselves `in` it.
+fn print_fields(fields: &[Field], group: Option<&Member>) -> TokenStream {
+    let mut tokens = TokenStream::new();
+    for field in fields {
+        let inside = match (&field.attrs.inside, group) {
+            (None, None) => true,
+            (Some(inside), Some(group)) => ast::same_member(inside, group),
+            _ => false,
+        };
+        if !inside {
+            continue;
+        }
+        let binding = &field.binding;
+        if field.attrs.group.is_some() {
+            let contents = print_fields(fields, Some(&field.member));
+            tokens.extend(quote! {
+                #binding.surround(tokens, |tokens| {
+                    #contents
+                });
+            });
+        } else {
+            tokens.extend(quote! {
+                ::syn::export::ToTokens::to_tokens(#binding, tokens);
+            });
+        }
+    }
+    tokens
+}
@@ -0,0 +1,139 @@
+use quote::quote;
+use syn::punctuated::Punctuated;
+use syn::{token, Expr, Ident, LitInt, Token, Type};
+use syn_derive::{Parse, ToTokens};
+
+#[derive(Parse, ToTokens)]
+struct Field {
+    name: Ident,
+    colon_token: Token![:],
+    ty: Type,
+}
+
+#[derive(Parse, ToTokens)]
+struct Fields {
+    #[syn(braced)]
+    brace_token: token::Brace,
+    #[syn(in = brace_token)]
+    fields: Punctuated<Field, Token![,]>,
+}
+
+#[derive(Parse, ToTokens)]
+struct Nested {
+    #[syn(parenthesized)]
+    paren_token: token::Paren,
+    #[syn(in = paren_token)]
+    first: LitInt,
+    #[syn(in = paren_token)]
+    #[syn(bracketed)]
+    bracket_token: token::Bracket,
+    #[syn(in = bracket_token)]
+    #[syn(parse_with = Punctuated::parse_separated_nonempty)]
+    rest: Punctuated<LitInt, Token![;]>,
+    #[syn(in = paren_token)]
+    #[syn(peek = Token![!])]
+    bang_token: Option<Token![!]>,
+}
+
+#[derive(Parse, ToTokens)]
+struct Pair(Ident, Token![=], Expr);
+
+#[derive(Parse, ToTokens)]
+struct Unit;
+
+#[derive(Parse, ToTokens)]
+struct Raw {
+    r#type: Ident,
+    r#in: Token![in],
+    r#fn: Expr,
+}
+
+#[derive(Parse, ToTokens)]
+enum Value {
+    #[syn(peek = token::Brace)]
+    Fields(Fields),
+    #[syn(peek = Token![-])]
+    Negative {
+        minus_token: Token![-],
+        lit: LitInt,
+    },
+    Pair(Pair),
+}
+
+#[derive(Parse, ToTokens)]
+enum Keyword {
+    #[syn(peek = Token![fn])]
+    Fn(Token![fn]),
+    #[syn(peek = Token![struct])]
+    Struct(Token![struct]),
+}
+
+#[derive(Parse, ToTokens)]
+enum Single {
+    Ident(Ident),
+}
+
+fn roundtrip<T: syn::parse::Parse + quote::ToTokens>(input: &str) -> String {
+    let node: T = syn::parse_str(input).unwrap();
+    quote!(#node).to_string()
+}
+
+#[test]
+fn test_struct() {
+    let fields: Fields = syn::parse_str("{ a: u8, b: Vec<u8>, }").unwrap();
+    let names: Vec<String> = fields.fields.iter().map(|f| f.name.to_string()).collect();
+    assert_eq!(names, ["a", "b"]);
+    assert_eq!(
+        roundtrip::<Fields>("{ a: u8, b: Vec<u8>, }"),
+        "{ a : u8 , b : Vec < u8 > , }",
+    );
+    assert_eq!(roundtrip::<Pair>("x = 1 + 2"), "x = 1 + 2");
+    assert_eq!(roundtrip::<Unit>(""), "");
+}
+
+#[test]
+fn test_raw_fields() {
+    let raw: Raw = syn::parse_str("x in 1").unwrap();
+    assert_eq!(raw.r#type, "x");
+    assert_eq!(quote!(#raw).to_string(), "x in 1");
+}
+
+#[test]
+fn test_nested_groups() {
+    let nested: Nested = syn::parse_str("(1 [2; 3])").unwrap();
+    assert_eq!(nested.first.base10_digits(), "1");
+    assert_eq!(nested.rest.len(), 2);
+    assert!(nested.bang_token.is_none());
+    assert_eq!(quote!(#nested).to_string(), "(1 [2 ; 3])");
+
+    assert_eq!(roundtrip::<Nested>("(1 [2] !)"), "(1 [2] !)");
+
+    let err = syn::parse_str::<Nested>("(1 [2;])").err().unwrap();
+    assert_eq!(err.to_string(), "unexpected end of input, expected literal");
+    let err = syn::parse_str::<Nested>("(1 [2] ?)").err().unwrap();
+    assert_eq!(err.to_string(), "unexpected token");
+}
+
+#[test]
+fn test_enum() {
+    match syn::parse_str("-5").unwrap() {
+        Value::Negative { lit, .. } => assert_eq!(lit.base10_digits(), "5"),
+        _ => panic!("expected negative"),
+    }
+    match syn::parse_str("{ x: u8 }").unwrap() {
+        Value::Fields(fields) => assert_eq!(fields.fields.len(), 1),
+        _ => panic!("expected fields"),
+    }
+    match syn::parse_str("x = y") {
+        Ok(Value::Pair(_)) => {}
+        _ => panic!("expected pair"),
+    }
+    assert_eq!(roundtrip::<Value>("- 5"), "- 5");
+    assert_eq!(roundtrip::<Value>("{ x : u8 }"), "{ x : u8 }");
+
+    assert!(syn::parse_str::<Keyword>("fn").is_ok());
+    let err = syn::parse_str::<Keyword>("impl").err().unwrap();
+    assert_eq!(err.to_string(), "expected `fn` or `struct`");
+
+    assert_eq!(roundtrip::<Single>("x"), "x");
+}
@@ -0,0 +1,183 @@
+use syn::from_meta::{FromAttributes, FromMeta};
+use syn::{parse_quote, AttributeArgs, DeriveInput, Ident, LitStr, Meta, Path};
+use syn_derive::{FromAttributes, FromMeta};
+
+#[derive(FromAttributes)]
+#[syn(attribute = options)]
+struct Options {
+    name: Option<LitStr>,
+    skip: bool,
+    #[syn(rename = "type")]
+    ty: Option<Path>,
+    #[syn(default)]
+    limit: u32,
+    #[syn(default = default_scale)]
+    scale: f64,
+    alias: Vec<Ident>,
+    nested: Option<Nested>,
+}
+
+#[derive(FromMeta)]
+struct Nested {
+    r#enum: char,
+    #[syn(default)]
+    enabled: bool,
+}
+
+#[derive(FromMeta)]
+struct Args {
+    path: String,
+    method: Option<String>,
+}
+
+fn default_scale() -> f64 {
+    1.0
+}
+
+fn parse_options(input: DeriveInput) -> syn::Result<Options> {
+    Options::from_attributes(&input.attrs)
+}
+
+fn error(input: &str) -> (String, String) {
+    let input = syn::parse_str(input).unwrap();
+    let err = parse_options(input).err().unwrap();
+    let span = err.span();
+    let start = span.start();
+    (err.to_string(), format!("{}:{}", start.line, start.column))
+}
+
+#[test]
+fn test_defaults() {
+    let options = parse_options(parse_quote! {
+        #[other(skip)]
+        struct S;
+    })
+    .unwrap();
+    assert!(options.name.is_none());
+    assert!(!options.skip);
+    assert!(options.ty.is_none());
+    assert_eq!(options.limit, 0);
+    assert_eq!(options.scale, 1.0);
+    assert!(options.alias.is_empty());
+    assert!(options.nested.is_none());
+}
+
+#[test]
+fn test_values() {
+    let options = parse_options(parse_quote! {
+        #[options(name = "s", skip, type = "std::string::String")]
+        #[options]
+        #[options(limit = 10, scale = 2.5, alias = "a", alias = "b")]
+        #[options(nested(enum = 'x', enabled))]
+        struct S;
+    })
+    .unwrap();
+    assert_eq!(options.name.unwrap().value(), "s");
+    assert!(options.skip);
+    assert!(options.ty.unwrap().segments.len() == 3);
+    assert_eq!(options.limit, 10);
+    assert_eq!(options.scale, 2.5);
+    assert_eq!(options.alias, ["a", "b"]);
+    let nested = options.nested.unwrap();
+    assert_eq!(nested.r#enum, 'x');
+    assert!(nested.enabled);
+
+    let options = parse_options(parse_quote! {
+        #[options(skip = false, nested(enum = 'y'))]
+        struct S;
+    })
+    .unwrap();
+    assert!(!options.skip);
+    assert!(!options.nested.unwrap().enabled);
+}
+
+#[test]
+fn test_errors() {
+    let input = "#[options(name = \"s\", unknown = 1)] struct S;";
+    assert_eq!(
+        error(input),
+        (
+            "unknown key `unknown`, expected one of: `name`, `skip`, `type`, `limit`, `scale`, `alias`, `nested`".to_owned(),
+            "1:22".to_owned(),
+        ),
+    );
+
+    let input = "#[options(skip, skip)] struct S;";
+    assert_eq!(
+        error(input),
+        ("duplicate key `skip`".to_owned(), "1:16".to_owned()),
+    );
+
+    let input = "#[options(limit = \"ten\")] struct S;";
+    assert_eq!(
+        error(input),
+        ("expected integer literal".to_owned(), "1:18".to_owned()),
+    );
+
+    let input = "#[options(nested(enabled))] struct S;";
+    assert_eq!(
+        error(input),
+        ("missing key `enum`".to_owned(), "1:0".to_owned()),
+    );
+
+    let input = "#[options(name)] struct S;";
+    assert_eq!(
+        error(input),
+        (
+            "expected string literal: `name = ...`".to_owned(),
+            "1:10".to_owned(),
+        ),
+    );
+
+    let input = "#[options(nested = 1)] struct S;";
+    assert_eq!(
+        error(input),
+        (
+            "expected a list: `nested(...)`".to_owned(),
+            "1:10".to_owned()
+        ),
+    );
+
+    let input = "#[options = \"x\"] struct S;";
+    assert_eq!(
+        error(input),
+        (
+            "expected a list: `#[options(...)]`".to_owned(),
+            "1:2".to_owned()
+        ),
+    );
+
+    let input = "#[options(\"x\")] struct S;";
+    assert_eq!(
+        error(input),
+        ("unexpected literal".to_owned(), "1:10".to_owned()),
+    );
+}
+
+#[test]
+fn test_attribute_args() {
+    let args: AttributeArgs = vec![parse_quote!(path = "/users"), parse_quote!(method = "GET")];
+    let args = Args::from_list(&args).unwrap();
+    assert_eq!(args.path, "/users");
+    assert_eq!(args.method.unwrap(), "GET");
+
+    let err = Args::from_list(&AttributeArgs::new()).err().unwrap();
+    assert_eq!(err.to_string(), "missing key `path`");
+}
+
+#[test]
+fn test_primitives() {
+    let meta: Meta = parse_quote!(x = 300);
+    assert_eq!(u16::from_meta(&meta).unwrap(), 300);
+    let err = u8::from_meta(&meta).err().unwrap();
+    assert_eq!(err.to_string(), "number too large to fit in target type");
+
+    let meta: Meta = parse_quote!(x = "not an ident");
+    assert!(Ident::from_meta(&meta).is_err());
+
+    let meta: Meta = parse_quote!(x(y));
+    let err = String::from_meta(&meta).err().unwrap();
+    assert_eq!(err.to_string(), "expected string literal: `x = ...`");
+    assert_eq!(bool::from_missing(), Some(false));
+    assert!(String::from_missing().is_none());
+}
@@ -0,0 +1,301 @@
+//! Desugaring of `async fn` into a function returning a future.
+//!
+//! An `async fn` is equivalent to a plain `fn` whose body is an `async move`
+//! block, returning a future that holds on to every argument. Spelling that
+//! out is needed wherever `async fn` cannot be written, like in a trait that
+//! must be object safe, and takes some care to preserve the meaning of the
+//! original function:
+//!
+//! - Elided lifetimes are made explicit as by
+//!   [`Signature::expand_elided_lifetimes`], since the future borrows from
+//!   all of them.
+//!
+//! - The future is bounded by a fresh lifetime named `'future`, which the
+//!   type of each argument, each type parameter and `Self` are required to
+//!   outlive in the `where` clause. A function without arguments returns a
+//!   future that holds on to nothing, and gets no such lifetime.
+//!
+//! - Every argument is moved into the `async move` block even if the body
+//!   does not use it, so that it is dropped when the future completes rather
+//!   than when the function returns. Arguments bound by a pattern other than
+//!   a plain identifier are renamed `__arg0`, `__arg1` and so on, and
+//!   destructured inside the block.
+//!
+//! - The result of the body is given the declared return type, so that the
+//!   conversions done by `?` and by `return` resolve as they did before.
+//!
+//! The lifetime `'future` must not already be in scope from an enclosing
+//! impl or trait. A future boxed with [`FutureStyle::BoxedSend`] is only
+//! `Send` if everything it captures is, which may call for bounds that this
+//! transform cannot know to add.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"parsing"`
+//! and `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::async_fn::FutureStyle;
+//! use syn::{parse_quote, ImplItemMethod};
+//!
+//! let mut method: ImplItemMethod = parse_quote! {
+//!     async fn get(&self, key: &str) -> Option<&Value> {
+//!         self.map.get(key)
+//!     }
+//! };
+//! method.desugar_async(FutureStyle::BoxedSend).unwrap();
+//!
+//! let expected = quote! {
+//!     fn get<'life0, 'life1, 'future>(&'life0 self, key: &'life1 str)
+//!         -> ::std::pin::Pin<::std::boxed::Box<
+//!             dyn ::std::future::Future<Output = Option<&'life0 Value> >
+//!                 + ::std::marker::Send
+//!                 + 'future
+//!         > >
+//!     where
+//!         &'life0 Self: 'future,
+//!         &'life1 str: 'future
+//!     {
+//!         ::std::boxed::Box::pin(async move {
+//!             let key = key;
+//!             let __ret: Option<&'life0 Value> = {
+//!                 self.map.get(key)
+//!             };
+//!             __ret
+//!         })
+//!     }
+//! };
+//! assert_eq!(quote!(#method).to_string(), expected.to_string());
+//! ```
+
+use std::mem;
+
+use proc_macro2::{Span, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+
+use crate::*;
+
+/// The type of future that a desugared `async fn` returns.
+///
+/// *This type is available if Syn is built with the `"full"`, `"parsing"` and
+/// `"printing"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FutureStyle {
+    /// `impl Future<Output = T> + 'future`.
+    Impl,
+    /// `Pin<Box<dyn Future<Output = T> + 'future>>`.
+    Boxed,
+    /// `Pin<Box<dyn Future<Output = T> + Send + 'future>>`.
+    BoxedSend,
+}
+
+impl ItemFn {
+    /// Turns an `async fn` into a plain `fn` returning a future in the given
+    /// style. A function that is not `async` is left as it is.
+    ///
+    /// Returns an error if the return type has an elided lifetime that the
+    /// arguments do not determine.
+    ///
+    /// *This method is available if Syn is built with the `"full"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn desugar_async(&mut self, style: FutureStyle) -> Result<()> {
+        desugar(&mut self.sig, Some(&mut self.block), style)
+    }
+}
+
+impl ImplItemMethod {
+    /// Turns an `async fn` into a plain `fn` returning a future in the given
+    /// style. A method that is not `async` is left as it is.
+    ///
+    /// Returns an error if the return type has an elided lifetime that the
+    /// arguments do not determine.
+    ///
+    /// *This method is available if Syn is built with the `"full"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn desugar_async(&mut self, style: FutureStyle) -> Result<()> {
+        desugar(&mut self.sig, Some(&mut self.block), style)
+    }
+}
+
+impl TraitItemMethod {
+    /// Turns an `async fn` into a plain `fn` returning a future in the given
+    /// style, along with its default body if it has one. A method that is not
+    /// `async` is left as it is.
+    ///
+    /// Returns an error if the return type has an elided lifetime that the
+    /// arguments do not determine.
+    ///
+    /// *This method is available if Syn is built with the `"full"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn desugar_async(&mut self, style: FutureStyle) -> Result<()> {
+        desugar(&mut self.sig, self.default.as_mut(), style)
+    }
+}
+
+fn desugar(sig: &mut Signature, block: Option<&mut Block>, style: FutureStyle) -> Result<()> {
+    if sig.asyncness.is_none() {
+        return Ok(());
+    }
+    sig.expand_elided_lifetimes()?;
+
+    let lifetime = if sig.inputs.is_empty() {
+        None
+    } else {
+        Some(capture_lifetime(sig))
+    };
+
+    let output: Type = match mem::replace(&mut sig.output, ReturnType::Default) {
+        ReturnType::Default => parse_quote!(()),
+        ReturnType::Type(_, ty) => *ty,
+    };
+    let bound = lifetime.as_ref().map(|lifetime| quote!(+ #lifetime));
+    let future: Type = match style {
+        FutureStyle::Impl => parse_quote! {
+            impl ::std::future::Future<Output = #output> #bound
+        },
+        FutureStyle::Boxed => parse_quote! {
+            ::std::pin::Pin<::std::boxed::Box<
+                dyn ::std::future::Future<Output = #output> #bound
+            >>
+        },
+        FutureStyle::BoxedSend => parse_quote! {
+            ::std::pin::Pin<::std::boxed::Box<
+                dyn ::std::future::Future<Output = #output> + ::std::marker::Send #bound
+            >>
+        },
+    };
+    sig.output = ReturnType::Type(<Token![->]>::default(), Box::new(future));
+    sig.asyncness = None;
+
+    if let Some(block) = block {
+        desugar_body(sig, block, &output, style);
+    }
+    Ok(())
+}
+
+// Declares the lifetime of the future and requires everything it captures to
+// outlive it.
+fn capture_lifetime(sig: &mut Signature) -> Lifetime {
+    let mut name = "'future".to_owned();
+    let mut n = 0;
+    while sig
+        .generics
+        .lifetimes()
+        .any(|def| def.lifetime.to_string() == name)
+    {
+        n += 1;
+        name = format!("'future{}", n);
+    }
+    let lifetime = Lifetime::new(&name, sig.fn_token.span);
+
+    let mut predicates: Vec<WherePredicate> = Vec::new();
+    for param in sig.generics.type_params() {
+        let ident = &param.ident;
+        predicates.push(parse_quote!(#ident: #lifetime));
+    }
+    for arg in &mut sig.inputs {
+        match arg {
+            FnArg::Receiver(receiver) => {
+                let ty = match &receiver.reference {
+                    Some((and_token, self_lifetime)) => {
+                        let mutability = &receiver.mutability;
+                        quote!(#and_token #self_lifetime #mutability Self)
+                    }
+                    None => quote!(Self),
+                };
+                predicates.push(parse_quote!(#ty: #lifetime));
+            }
+            FnArg::Typed(arg) => match &mut *arg.ty {
+                // Not allowed in a `where` clause.
+                Type::ImplTrait(ty) => {
+                    ty.bounds.push(TypeParamBound::Lifetime(lifetime.clone()));
+                }
+                ty => predicates.push(parse_quote!(#ty: #lifetime)),
+            },
+        }
+    }
+
+    let index = sig.generics.lifetimes().count();
+    let param = GenericParam::Lifetime(LifetimeDef::new(lifetime.clone()));
+    sig.generics.params.insert(index, param);
+    sig.generics
+        .lt_token
+        .get_or_insert_with(<Token![<]>::default);
+    sig.generics
+        .gt_token
+        .get_or_insert_with(<Token![>]>::default);
+    sig.generics
+        .make_where_clause()
+        .predicates
+        .extend(predicates);
+    lifetime
+}
+
+fn desugar_body(sig: &mut Signature, block: &mut Block, output: &Type, style: FutureStyle) {
+    let mut bindings: Vec<Stmt> = Vec::new();
+    for (i, arg) in sig.inputs.iter_mut().enumerate() {
+        let arg = match arg {
+            FnArg::Typed(arg) => arg,
+            // `self` is captured like any other variable, but cannot be
+            // rebound.
+            FnArg::Receiver(_) => continue,
+        };
+        match &mut *arg.pat {
+            Pat::Ident(pat) if pat.ident == "self" => {}
+            Pat::Ident(pat) if pat.by_ref.is_none() && pat.subpat.is_none() => {
+                let mutability = pat.mutability.take();
+                let ident = &pat.ident;
+                bindings.push(parse_quote!(let #mutability #ident = #ident;));
+            }
+            pat => {
+                let ident = Ident::new(&format!("__arg{}", i), Span::call_site());
+                let original = mem::replace(
+                    pat,
+                    Pat::Ident(PatIdent {
+                        attrs: Vec::new(),
+                        by_ref: None,
+                        mutability: None,
+                        ident: ident.clone(),
+                        subpat: None,
+                    }),
+                );
+                match original {
+                    // `let _ = __arg0;` would not move the argument.
+                    Pat::Wild(_) => bindings.push(parse_quote!(let #ident = #ident;)),
+                    original => bindings.push(parse_quote!(let #original = #ident;)),
+                }
+            }
+        }
+    }
+
+    let stmts = &block.stmts;
+    let body = if contains_impl(output.to_token_stream()) {
+        // An `impl Trait` return type cannot be written on a `let`.
+        quote! {
+            #(#bindings)*
+            #(#stmts)*
+        }
+    } else {
+        quote! {
+            #(#bindings)*
+            let __ret: #output = { #(#stmts)* };
+            __ret
+        }
+    };
+    let future: Expr = match style {
+        FutureStyle::Impl => parse_quote!(async move { #body }),
+        FutureStyle::Boxed | FutureStyle::BoxedSend => {
+            parse_quote!(::std::boxed::Box::pin(async move { #body }))
+        }
+    };
+    block.stmts = vec![Stmt::Expr(future)];
+}
+
+fn contains_impl(tokens: TokenStream) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == "impl",
+        TokenTree::Group(group) => contains_impl(group.stream()),
+        _ => false,
+    })
+}
@@ -156,36 +156,26 @@ impl Attribute {
     /// feature.*
     #[cfg(feature = "parsing")]
     pub fn parse_meta(&self) -> Result<Meta> {
-        fn clone_ident_segment(segment: &PathSegment) -> PathSegment {
-            PathSegment {
-                ident: segment.ident.clone(),
-                arguments: PathArguments::None,
-            }
-        }
-
-        let path = Path {
-            leading_colon: self
-                .path
-                .leading_colon
-                .as_ref()
-                .map(|colon| Token![::](colon.spans)),
-            segments: self
-                .path
-                .segments
-                .pairs()
-                .map(|pair| match pair {
-                    Pair::Punctuated(seg, punct) => {
-                        Pair::Punctuated(clone_ident_segment(seg), Token![::](punct.spans))
-                    }
-                    Pair::End(seg) => Pair::End(clone_ident_segment(seg)),
-                })
-                .collect(),
-        };
-
+        let path = meta_path(self);
         let parser = |input: ParseStream| parsing::parse_meta_after_path(path, input);
         parse::Parser::parse2(parser, self.tokens.clone())
     }
 
+    /// Parses the content of the attribute as a [`TokenMeta`], which unlike
+    /// [`Meta`] accepts any tokens as the value of a name-value pair, as in
+    /// `#[serde(default = path::to::function)]`.
+    ///
+    /// [`TokenMeta`]: meta/enum.TokenMeta.html
+    ///
+    /// *This function is available if Syn is built with the `"parsing"` and
+    /// `"printing"` features.*
+    #[cfg(all(feature = "parsing", feature = "printing"))]
+    pub fn parse_meta_tokens(&self) -> Result<meta::TokenMeta> {
+        let path = meta_path(self);
+        let parser = |input: ParseStream| meta::parse_meta_after_path(path, input);
+        parse::Parser::parse2(parser, self.tokens.clone())
+    }
+
     /// Parse the arguments to the attribute as a syntax tree.
     ///
     /// This is similar to `syn::parse2::<T>(attr.tokens)` except that:
@@ -246,6 +236,37 @@ impl Attribute {
     }
 }
 
+// Copy of the attribute's path without generic arguments, to begin a meta
+// item with.
+#[cfg(feature = "parsing")]
+fn meta_path(attr: &Attribute) -> Path {
+    fn clone_ident_segment(segment: &PathSegment) -> PathSegment {
+        PathSegment {
+            ident: segment.ident.clone(),
+            arguments: PathArguments::None,
+        }
+    }
+
+    Path {
+        leading_colon: attr
+            .path
+            .leading_colon
+            .as_ref()
+            .map(|colon| Token![::](colon.spans)),
+        segments: attr
+            .path
+            .segments
+            .pairs()
+            .map(|pair| match pair {
+                Pair::Punctuated(seg, punct) => {
+                    Pair::Punctuated(clone_ident_segment(seg), Token![::](punct.spans))
+                }
+                Pair::End(seg) => Pair::End(clone_ident_segment(seg)),
+            })
+            .collect(),
+    }
+}
+
 #[cfg(feature = "parsing")]
 fn error_expected_args(attr: &Attribute) -> Error {
     let style = match attr.style {
@@ -522,7 +543,7 @@ pub mod parsing {
     }
 
     // Like Path::parse_mod_style but accepts keywords in the path.
-    fn parse_meta_path(input: ParseStream) -> Result<Path> {
+    pub fn parse_meta_path(input: ParseStream) -> Result<Path> {
         Ok(Path {
             leading_colon: input.parse()?,
             segments: {
@@ -537,9 +558,9 @@ pub mod parsing {
                     segments.push_punct(punct);
                 }
                 if segments.is_empty() {
-                    return Err(input.error("expected path"));
+                    return Err(input.error_expected(&["path"]));
                 } else if segments.trailing_punct() {
-                    return Err(input.error("expected path segment"));
+                    return Err(input.error_expected(&["path segment"]));
                 }
                 segments
             },
@@ -574,7 +595,7 @@ pub mod parsing {
             } else if input.peek(Ident::peek_any) {
                 input.parse().map(NestedMeta::Meta)
             } else {
-                Err(input.error("expected identifier or literal"))
+                Err(input.error_expected(&["identifier", "literal"]))
             }
         }
     }
@@ -0,0 +1,311 @@
+//! Inference of the trait bounds a derived impl needs.
+//!
+//! A derive macro that puts `T: Trait` on every type parameter asks for
+//! more than the impl needs: a struct holding a `PhantomData<T>` implements
+//! `Debug` whatever `T` is, and a struct holding a `Box<T::Item>` needs
+//! `T::Item: Debug` rather than `T: Debug`. [`DeriveInput::infer_bounds`]
+//! looks at the types of the fields instead, and bounds exactly the type
+//! parameters and associated types like `T::Item` or `<T as Trait>::Item`
+//! that they contain. Type parameters that appear only as the argument of
+//! `PhantomData` are not bounded.
+//!
+//! A field can replace the bounds inferred from its type with its own through
+//! an attribute of the derive, in the way of Serde: `#[debug(bound = "T:
+//! Display")]` bounds `T: Display` for that field, and `#[debug(bound = "")]`
+//! opts the field out of bounds altogether. Other contents of the attribute
+//! are left for the derive macro to interpret.
+//!
+//! If the type of a field is a macro invocation, whose contents cannot be
+//! seen, every type parameter is bounded.
+//!
+//! *This module is available if Syn is built with the `"derive"`,
+//! `"parsing"`, `"printing"` and `"clone-impls"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, DeriveInput};
+//!
+//! let input: DeriveInput = parse_quote! {
+//!     struct Wrapper<T: Iterator, U, V> {
+//!         items: Vec<T::Item>,
+//!         marker: PhantomData<U>,
+//!         #[debug(bound = "")]
+//!         callback: fn(V),
+//!     }
+//! };
+//!
+//! let generics = input
+//!     .generics_with_bounds(&parse_quote!(Debug), "debug")
+//!     .unwrap();
+//! let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+//! let expanded = quote! {
+//!     impl #impl_generics Debug for Wrapper #ty_generics #where_clause {}
+//! };
+//!
+//! let expected = quote! {
+//!     impl<T: Iterator, U, V> Debug for Wrapper<T, U, V>
+//!     where
+//!         T::Item: Debug
+//!     {}
+//! };
+//! assert_eq!(expanded.to_string(), expected.to_string());
+//! ```
+
+use std::collections::HashSet;
+
+use quote::ToTokens;
+
+use crate::meta::{NestedTokenMeta, TokenMeta};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+impl DeriveInput {
+    /// Computes the `where` clause predicates that bound the types appearing
+    /// in the fields by the trait `bound`, in the order of their first
+    /// appearance.
+    ///
+    /// Fields with a `bound = "..."` entry in an attribute named `attr`
+    /// contribute the predicates written there instead, or none if the
+    /// string is empty. Returns an error if such a string does not parse as
+    /// a list of predicates.
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"`, `"printing"` and `"clone-impls"` features.*
+    pub fn infer_bounds(&self, bound: &Path, attr: &str) -> Result<Vec<WherePredicate>> {
+        let params: Vec<&Ident> = self
+            .generics
+            .type_params()
+            .map(|param| &param.ident)
+            .collect();
+        let mut collector = Collector {
+            params: &params,
+            types: Vec::new(),
+            seen: HashSet::new(),
+            opaque: false,
+        };
+        let mut explicit = Vec::new();
+        for field in fields(&self.data) {
+            match field_bound(field, attr)? {
+                Some(predicates) => explicit.extend(predicates),
+                None => collector.visit_type(&field.ty),
+            }
+        }
+        if collector.opaque {
+            for param in &params {
+                collector.push(Type::Path(TypePath {
+                    qself: None,
+                    path: Path::from((*param).clone()),
+                }));
+            }
+        }
+
+        let mut predicates: Vec<WherePredicate> = collector
+            .types
+            .into_iter()
+            .map(|ty| {
+                WherePredicate::Type(PredicateType {
+                    lifetimes: None,
+                    bounded_ty: ty,
+                    colon_token: <Token![:]>::default(),
+                    bounds: {
+                        let mut bounds = Punctuated::new();
+                        bounds.push(TypeParamBound::Trait(TraitBound {
+                            paren_token: None,
+                            modifier: TraitBoundModifier::None,
+                            lifetimes: None,
+                            path: bound.clone(),
+                        }));
+                        bounds
+                    },
+                })
+            })
+            .collect();
+        predicates.extend(explicit);
+        Ok(predicates)
+    }
+
+    /// Returns the generics of the type with the predicates from
+    /// [`infer_bounds`] added to its `where` clause, ready for
+    /// [`Generics::split_for_impl`].
+    ///
+    /// [`infer_bounds`]: DeriveInput::infer_bounds
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"`, `"printing"` and `"clone-impls"` features.*
+    pub fn generics_with_bounds(&self, bound: &Path, attr: &str) -> Result<Generics> {
+        let predicates = self.infer_bounds(bound, attr)?;
+        let mut generics = self.generics.clone();
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+        Ok(generics)
+    }
+}
+
+fn fields(data: &Data) -> Vec<&Field> {
+    match data {
+        Data::Struct(data) => data.fields.iter().collect(),
+        Data::Enum(data) => data
+            .variants
+            .iter()
+            .flat_map(|variant| variant.fields.iter())
+            .collect(),
+        Data::Union(data) => data.fields.named.iter().collect(),
+    }
+}
+
+// The predicates given by `#[attr(bound = "...")]` on a field, if any.
+fn field_bound(field: &Field, attr: &str) -> Result<Option<Vec<WherePredicate>>> {
+    let mut bound = None;
+    for attribute in &field.attrs {
+        if !attribute.path.is_ident(attr) {
+            continue;
+        }
+        let list = match attribute.parse_meta_tokens()? {
+            TokenMeta::List(list) => list,
+            _ => continue,
+        };
+        for nested in &list.nested {
+            if let NestedTokenMeta::Meta(TokenMeta::NameValue(nv)) = nested {
+                if nv.path.is_ident("bound") {
+                    let predicates: LitStr = nv.parse_value()?;
+                    let predicates = predicates
+                        .parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?;
+                    bound.get_or_insert_with(Vec::new).extend(predicates);
+                }
+            }
+        }
+    }
+    Ok(bound)
+}
+
+struct Collector<'a> {
+    params: &'a [&'a Ident],
+    types: Vec<Type>,
+    seen: HashSet<String>,
+    // Whether a field type hides its contents in a macro.
+    opaque: bool,
+}
+
+impl Collector<'_> {
+    fn push(&mut self, ty: Type) {
+        if self.seen.insert(ty.to_token_stream().to_string()) {
+            self.types.push(ty);
+        }
+    }
+
+    fn is_param(&self, path: &Path) -> bool {
+        if path.leading_colon.is_some() {
+            return false;
+        }
+        match path.segments.first() {
+            Some(segment) => {
+                segment.arguments.is_empty() && self.params.iter().any(|p| segment.ident == **p)
+            }
+            None => false,
+        }
+    }
+
+    fn mentions_param(&self, ty: &Type) -> bool {
+        let mut collector = Collector {
+            params: self.params,
+            types: Vec::new(),
+            seen: HashSet::new(),
+            opaque: false,
+        };
+        collector.visit_type(ty);
+        !collector.types.is_empty() || collector.opaque
+    }
+
+    fn visit_type(&mut self, ty: &Type) {
+        match ty {
+            Type::Array(ty) => self.visit_type(&ty.elem),
+            Type::BareFn(ty) => {
+                for arg in &ty.inputs {
+                    self.visit_type(&arg.ty);
+                }
+                self.visit_return_type(&ty.output);
+            }
+            Type::Group(ty) => self.visit_type(&ty.elem),
+            Type::ImplTrait(ty) => self.visit_bounds(&ty.bounds),
+            Type::Macro(_) | Type::Verbatim(_) => self.opaque = true,
+            Type::Paren(ty) => self.visit_type(&ty.elem),
+            Type::Path(ty) => self.visit_type_path(ty),
+            Type::Ptr(ty) => self.visit_type(&ty.elem),
+            Type::Reference(ty) => self.visit_type(&ty.elem),
+            Type::Slice(ty) => self.visit_type(&ty.elem),
+            Type::TraitObject(ty) => self.visit_bounds(&ty.bounds),
+            Type::Tuple(ty) => {
+                for elem in &ty.elems {
+                    self.visit_type(elem);
+                }
+            }
+            // `_` and `!`.
+            _ => {}
+        }
+    }
+
+    fn visit_type_path(&mut self, ty: &TypePath) {
+        if let Some(qself) = &ty.qself {
+            // An associated type of a type involving a parameter, like
+            // `<T as Iterator>::Item`.
+            if self.mentions_param(&qself.ty) {
+                self.push(Type::Path(ty.clone()));
+                return;
+            }
+        } else if self.is_param(&ty.path) {
+            // `T` itself, or an associated type like `T::Item`.
+            self.push(Type::Path(ty.clone()));
+            return;
+        }
+        let last = ty.path.segments.last();
+        if last.map_or(false, |segment| segment.ident == "PhantomData") {
+            return;
+        }
+        for segment in &ty.path.segments {
+            self.visit_path_arguments(&segment.arguments);
+        }
+    }
+
+    fn visit_path_arguments(&mut self, arguments: &PathArguments) {
+        match arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(arguments) => {
+                for arg in &arguments.args {
+                    match arg {
+                        GenericArgument::Type(ty) => self.visit_type(ty),
+                        GenericArgument::Binding(binding) => self.visit_type(&binding.ty),
+                        GenericArgument::Constraint(constraint) => {
+                            self.visit_bounds(&constraint.bounds);
+                        }
+                        GenericArgument::Lifetime(_) | GenericArgument::Const(_) => {}
+                    }
+                }
+            }
+            PathArguments::Parenthesized(arguments) => {
+                for ty in &arguments.inputs {
+                    self.visit_type(ty);
+                }
+                self.visit_return_type(&arguments.output);
+            }
+        }
+    }
+
+    fn visit_return_type(&mut self, output: &ReturnType) {
+        if let ReturnType::Type(_, ty) = output {
+            self.visit_type(ty);
+        }
+    }
+
+    fn visit_bounds(&mut self, bounds: &Punctuated<TypeParamBound, Token![+]>) {
+        for bound in bounds {
+            if let TypeParamBound::Trait(bound) = bound {
+                for segment in &bound.path.segments {
+                    self.visit_path_arguments(&segment.arguments);
+                }
+            }
+        }
+    }
+}
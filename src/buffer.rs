@@ -348,6 +348,11 @@ pub(crate) fn same_scope(a: Cursor, b: Cursor) -> bool {
     a.scope == b.scope
 }
 
+#[cfg(feature = "full")]
+pub(crate) fn same_position(a: Cursor, b: Cursor) -> bool {
+    a.ptr == b.ptr
+}
+
 pub(crate) fn open_span_of_group(cursor: Cursor) -> Span {
     match cursor.entry() {
         Entry::Group(group, _) => group.span_open(),
@@ -0,0 +1,442 @@
+//! Expansion of elided lifetimes in function signatures.
+//!
+//! Moving a signature somewhere elision works differently, like into a trait
+//! object type or an impl with lifetimes of its own, needs its elided
+//! lifetimes spelled out first. [`Signature::expand_elided_lifetimes`] and
+//! [`TypeBareFn::expand_elided_lifetimes`] apply the rules of the language to
+//! do that:
+//!
+//! - Each elided lifetime in the arguments, whether a reference without a
+//!   lifetime like `&str` or a placeholder `'_` like in `Formatter<'_>`,
+//!   becomes a fresh lifetime named `'life0`, `'life1` and so on, skipping
+//!   any name the signature already uses. The fresh lifetimes are declared
+//!   in the generics of the function, or in the `for<...>` of the function
+//!   pointer type.
+//!
+//! - Each elided lifetime in the return type becomes the lifetime of `self`
+//!   if it is taken by reference, and otherwise the one lifetime appearing in
+//!   the arguments. If there is no such lifetime, or more than one, elision
+//!   is ambiguous and an error is returned without changing the signature.
+//!
+//! Function pointer types and `Fn(...)` traits nested within the signature
+//! have their own scope of elision and are left as they are. Lifetime
+//! parameters of a type written without any arguments, like the one of
+//! `Ref<T>` from `std::cell`, cannot be seen in the syntax and are not
+//! counted.
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature.*
+
+use std::collections::HashSet;
+
+use proc_macro2::Span;
+
+use crate::punctuated::Punctuated;
+use crate::*;
+
+#[cfg(feature = "full")]
+impl Signature {
+    /// Makes every elided lifetime of the signature explicit, declaring the
+    /// lifetimes it introduces as generic parameters of the function.
+    ///
+    /// Returns an error if the return type has an elided lifetime that the
+    /// arguments do not determine.
+    ///
+    /// *This method is available if Syn is built with the `"full"` feature.*
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use quote::quote;
+    /// use syn::{parse_quote, ItemFn};
+    ///
+    /// let mut item: ItemFn = parse_quote! {
+    ///     fn first_word<T>(text: &str, words: Words<'_, T>) -> &str { unimplemented!() }
+    /// };
+    /// assert!(item.sig.expand_elided_lifetimes().is_err());
+    ///
+    /// let mut item: ItemFn = parse_quote! {
+    ///     fn trim(&self, text: &str) -> &str { unimplemented!() }
+    /// };
+    /// item.sig.expand_elided_lifetimes().unwrap();
+    ///
+    /// let sig = &item.sig;
+    /// let expected = quote! {
+    ///     fn trim<'life0, 'life1>(&'life0 self, text: &'life1 str) -> &'life0 str
+    /// };
+    /// assert_eq!(quote!(#sig).to_string(), expected.to_string());
+    /// ```
+    pub fn expand_elided_lifetimes(&mut self) -> Result<()> {
+        let mut used = HashSet::new();
+        visit_generics(&mut self.generics, &mut |slot| slot.mark_used(&mut used));
+        let mut args: Vec<Arg> = self
+            .inputs
+            .iter_mut()
+            .filter_map(|arg| match arg {
+                FnArg::Receiver(receiver) => {
+                    let (and_token, lifetime) = receiver.reference.as_mut()?;
+                    Some(Arg::Receiver(lifetime, and_token.spans[0]))
+                }
+                FnArg::Typed(arg) => {
+                    let is_self = match &*arg.pat {
+                        Pat::Ident(pat) => pat.ident == "self",
+                        _ => false,
+                    };
+                    Some(Arg::Typed(&mut arg.ty, is_self))
+                }
+            })
+            .collect();
+        let fresh = expand(&mut args, &mut self.output, used)?;
+        if fresh.is_empty() {
+            return Ok(());
+        }
+
+        // Lifetime parameters come before all others.
+        let index = self.generics.lifetimes().count();
+        for (i, lifetime) in fresh.into_iter().enumerate() {
+            let param = GenericParam::Lifetime(LifetimeDef::new(lifetime));
+            self.generics.params.insert(index + i, param);
+        }
+        self.generics
+            .lt_token
+            .get_or_insert_with(<Token![<]>::default);
+        self.generics
+            .gt_token
+            .get_or_insert_with(<Token![>]>::default);
+        Ok(())
+    }
+}
+
+impl TypeBareFn {
+    /// Makes every elided lifetime of the function pointer type explicit,
+    /// declaring the lifetimes it introduces in its `for<...>`.
+    ///
+    /// Returns an error if the return type has an elided lifetime that the
+    /// arguments do not determine.
+    ///
+    /// *This method is available if Syn is built with the `"derive"` or
+    /// `"full"` feature.*
+    pub fn expand_elided_lifetimes(&mut self) -> Result<()> {
+        let mut used = HashSet::new();
+        if let Some(bound) = &mut self.lifetimes {
+            for def in &mut bound.lifetimes {
+                Slot::Lifetime(&mut def.lifetime).mark_used(&mut used);
+            }
+        }
+        let mut args: Vec<Arg> = self
+            .inputs
+            .iter_mut()
+            .map(|arg| Arg::Typed(&mut arg.ty, false))
+            .collect();
+        let fresh = expand(&mut args, &mut self.output, used)?;
+        if fresh.is_empty() {
+            return Ok(());
+        }
+
+        let bound = self.lifetimes.get_or_insert_with(|| BoundLifetimes {
+            for_token: <Token![for]>::default(),
+            lt_token: <Token![<]>::default(),
+            lifetimes: Punctuated::new(),
+            gt_token: <Token![>]>::default(),
+        });
+        for lifetime in fresh {
+            bound.lifetimes.push(LifetimeDef::new(lifetime));
+        }
+        Ok(())
+    }
+}
+
+// An argument of a function, as far as elision is concerned.
+enum Arg<'a> {
+    // The lifetime of `&self` or `&mut self`.
+    #[cfg(feature = "full")]
+    Receiver(&'a mut Option<Lifetime>, Span),
+    // The type of any other argument, and whether the argument is `self`.
+    Typed(&'a mut Type, bool),
+}
+
+impl Arg<'_> {
+    fn visit<F: FnMut(Slot)>(&mut self, nested: bool, f: &mut F) {
+        match self {
+            #[cfg(feature = "full")]
+            Arg::Receiver(lifetime, span) => f(Slot::Reference(lifetime, *span)),
+            Arg::Typed(ty, _) => visit_type(ty, nested, f),
+        }
+    }
+
+    // The lifetime that elided lifetimes in the return type take, if this is
+    // `self` taken by reference.
+    fn self_lifetime(&self) -> Option<Lifetime> {
+        match self {
+            #[cfg(feature = "full")]
+            Arg::Receiver(lifetime, _) => (**lifetime).clone(),
+            Arg::Typed(Type::Reference(ty), true) => ty.lifetime.clone(),
+            Arg::Typed(..) => None,
+        }
+    }
+}
+
+// Expands the elided lifetimes of the arguments and return type, returning
+// the fresh lifetimes to declare. Nothing is changed if an error is returned.
+fn expand(
+    args: &mut [Arg],
+    output: &mut ReturnType,
+    mut used: HashSet<String>,
+) -> Result<Vec<Lifetime>> {
+    for arg in args.iter_mut() {
+        arg.visit(true, &mut |slot| slot.mark_used(&mut used));
+    }
+    visit_return_type(output, true, &mut |slot| slot.mark_used(&mut used));
+
+    let mut inputs = 0;
+    for arg in args.iter_mut() {
+        arg.visit(false, &mut |_| inputs += 1);
+    }
+    let mut elided_output = None;
+    visit_return_type(output, false, &mut |slot| {
+        if slot.is_elided() && elided_output.is_none() {
+            elided_output = Some(slot.span());
+        }
+    });
+    let by_ref_self = args.first().map_or(false, |arg| match arg {
+        #[cfg(feature = "full")]
+        Arg::Receiver(..) => true,
+        Arg::Typed(Type::Reference(_), is_self) => *is_self,
+        Arg::Typed(..) => false,
+    });
+    if let Some(span) = elided_output {
+        if !by_ref_self && inputs == 0 {
+            return Err(Error::new(
+                span,
+                "cannot infer an elided lifetime in the return type: no argument has a lifetime",
+            ));
+        }
+        if !by_ref_self && inputs > 1 {
+            return Err(Error::new(
+                span,
+                "cannot infer an elided lifetime in the return type: it is ambiguous which argument it borrows from",
+            ));
+        }
+    }
+
+    let mut fresh = Vec::new();
+    let mut next = 0;
+    let mut first_input = None;
+    for arg in args.iter_mut() {
+        arg.visit(false, &mut |mut slot| {
+            if slot.is_elided() {
+                let lifetime = loop {
+                    let name = format!("'life{}", next);
+                    next += 1;
+                    if used.insert(name.clone()) {
+                        break Lifetime::new(&name, slot.span());
+                    }
+                };
+                fresh.push(lifetime.clone());
+                slot.set(lifetime);
+            }
+            if first_input.is_none() {
+                first_input = slot.lifetime().cloned();
+            }
+        });
+    }
+
+    if elided_output.is_some() {
+        let lifetime = match args.first().and_then(Arg::self_lifetime) {
+            Some(lifetime) => lifetime,
+            None => first_input.unwrap(),
+        };
+        visit_return_type(output, false, &mut |mut slot| {
+            if slot.is_elided() {
+                slot.set(lifetime.clone());
+            }
+        });
+    }
+    Ok(fresh)
+}
+
+// A place in a type where a lifetime is written or may be elided.
+enum Slot<'a> {
+    // The lifetime of a reference, with the span of its `&`.
+    Reference(&'a mut Option<Lifetime>, Span),
+    Lifetime(&'a mut Lifetime),
+}
+
+impl Slot<'_> {
+    fn lifetime(&self) -> Option<&Lifetime> {
+        match self {
+            Slot::Reference(lifetime, _) => lifetime.as_ref(),
+            Slot::Lifetime(lifetime) => Some(lifetime),
+        }
+    }
+
+    fn is_elided(&self) -> bool {
+        self.lifetime()
+            .map_or(true, |lifetime| lifetime.ident == "_")
+    }
+
+    fn span(&self) -> Span {
+        match self {
+            Slot::Reference(_, span) => *span,
+            Slot::Lifetime(lifetime) => lifetime.apostrophe,
+        }
+    }
+
+    fn set(&mut self, new: Lifetime) {
+        match self {
+            Slot::Reference(lifetime, _) => **lifetime = Some(new),
+            Slot::Lifetime(lifetime) => **lifetime = new,
+        }
+    }
+
+    fn mark_used(&self, used: &mut HashSet<String>) {
+        if let Some(lifetime) = self.lifetime() {
+            used.insert(lifetime.to_string());
+        }
+    }
+}
+
+// Calls `f` on every lifetime slot of the type. Nested function pointer types
+// and `Fn(...)` arguments, which have a scope of elision of their own, are
+// only visited if `nested` is true.
+fn visit_type<F: FnMut(Slot)>(ty: &mut Type, nested: bool, f: &mut F) {
+    match ty {
+        Type::Array(ty) => visit_type(&mut ty.elem, nested, f),
+        Type::BareFn(ty) if nested => {
+            if let Some(bound) = &mut ty.lifetimes {
+                visit_bound_lifetimes(bound, f);
+            }
+            for arg in &mut ty.inputs {
+                visit_type(&mut arg.ty, nested, f);
+            }
+            visit_return_type(&mut ty.output, nested, f);
+        }
+        Type::Group(ty) => visit_type(&mut ty.elem, nested, f),
+        Type::ImplTrait(ty) => visit_bounds(&mut ty.bounds, nested, f),
+        Type::Paren(ty) => visit_type(&mut ty.elem, nested, f),
+        Type::Path(ty) => {
+            if let Some(qself) = &mut ty.qself {
+                visit_type(&mut qself.ty, nested, f);
+            }
+            visit_path(&mut ty.path, nested, f);
+        }
+        Type::Ptr(ty) => visit_type(&mut ty.elem, nested, f),
+        Type::Reference(ty) => {
+            f(Slot::Reference(&mut ty.lifetime, ty.and_token.spans[0]));
+            visit_type(&mut ty.elem, nested, f);
+        }
+        Type::Slice(ty) => visit_type(&mut ty.elem, nested, f),
+        Type::TraitObject(ty) => visit_bounds(&mut ty.bounds, nested, f),
+        Type::Tuple(ty) => {
+            for elem in &mut ty.elems {
+                visit_type(elem, nested, f);
+            }
+        }
+        // `_`, `!`, macros, and function pointers when not `nested`.
+        _ => {}
+    }
+}
+
+fn visit_return_type<F: FnMut(Slot)>(output: &mut ReturnType, nested: bool, f: &mut F) {
+    if let ReturnType::Type(_, ty) = output {
+        visit_type(ty, nested, f);
+    }
+}
+
+fn visit_path<F: FnMut(Slot)>(path: &mut Path, nested: bool, f: &mut F) {
+    for segment in &mut path.segments {
+        match &mut segment.arguments {
+            PathArguments::AngleBracketed(arguments) => {
+                for arg in &mut arguments.args {
+                    match arg {
+                        GenericArgument::Lifetime(lifetime) => f(Slot::Lifetime(lifetime)),
+                        GenericArgument::Type(ty) => visit_type(ty, nested, f),
+                        GenericArgument::Binding(binding) => visit_type(&mut binding.ty, nested, f),
+                        GenericArgument::Constraint(constraint) => {
+                            visit_bounds(&mut constraint.bounds, nested, f);
+                        }
+                        GenericArgument::Const(_) => {}
+                    }
+                }
+            }
+            PathArguments::Parenthesized(arguments) if nested => {
+                for ty in &mut arguments.inputs {
+                    visit_type(ty, nested, f);
+                }
+                visit_return_type(&mut arguments.output, nested, f);
+            }
+            PathArguments::None | PathArguments::Parenthesized(_) => {}
+        }
+    }
+}
+
+fn visit_bounds<F: FnMut(Slot)>(
+    bounds: &mut Punctuated<TypeParamBound, Token![+]>,
+    nested: bool,
+    f: &mut F,
+) {
+    for bound in bounds {
+        match bound {
+            TypeParamBound::Trait(bound) => {
+                if nested {
+                    if let Some(lifetimes) = &mut bound.lifetimes {
+                        visit_bound_lifetimes(lifetimes, f);
+                    }
+                }
+                visit_path(&mut bound.path, nested, f);
+            }
+            TypeParamBound::Lifetime(lifetime) => f(Slot::Lifetime(lifetime)),
+        }
+    }
+}
+
+fn visit_bound_lifetimes<F: FnMut(Slot)>(bound: &mut BoundLifetimes, f: &mut F) {
+    for def in &mut bound.lifetimes {
+        f(Slot::Lifetime(&mut def.lifetime));
+    }
+}
+
+// Visits every lifetime slot of the generics, for finding the names in use.
+#[cfg(feature = "full")]
+fn visit_generics<F: FnMut(Slot)>(generics: &mut Generics, f: &mut F) {
+    for param in &mut generics.params {
+        match param {
+            GenericParam::Lifetime(def) => {
+                f(Slot::Lifetime(&mut def.lifetime));
+                for bound in &mut def.bounds {
+                    f(Slot::Lifetime(bound));
+                }
+            }
+            GenericParam::Type(param) => {
+                visit_bounds(&mut param.bounds, true, f);
+                if let Some(default) = &mut param.default {
+                    visit_type(default, true, f);
+                }
+            }
+            GenericParam::Const(param) => visit_type(&mut param.ty, true, f),
+        }
+    }
+    if let Some(where_clause) = &mut generics.where_clause {
+        for predicate in &mut where_clause.predicates {
+            match predicate {
+                WherePredicate::Type(predicate) => {
+                    if let Some(lifetimes) = &mut predicate.lifetimes {
+                        visit_bound_lifetimes(lifetimes, f);
+                    }
+                    visit_type(&mut predicate.bounded_ty, true, f);
+                    visit_bounds(&mut predicate.bounds, true, f);
+                }
+                WherePredicate::Lifetime(predicate) => {
+                    f(Slot::Lifetime(&mut predicate.lifetime));
+                    for bound in &mut predicate.bounds {
+                        f(Slot::Lifetime(bound));
+                    }
+                }
+                WherePredicate::Eq(predicate) => {
+                    visit_type(&mut predicate.lhs_ty, true, f);
+                    visit_type(&mut predicate.rhs_ty, true, f);
+                }
+            }
+        }
+    }
+}
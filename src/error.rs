@@ -2,6 +2,7 @@ use std;
 use std::fmt::{self, Debug, Display};
 use std::iter::FromIterator;
 use std::slice;
+use std::sync::atomic::{AtomicUsize, Ordering};
 use std::vec;
 
 use proc_macro2::{
@@ -82,6 +83,38 @@ pub type Result<T> = std::result::Result<T, Error>;
 /// #     }
 /// # }
 /// ```
+///
+/// # Labels, notes and warnings
+///
+/// Beyond its primary message, an error may point at other relevant code with
+/// [`label`] and attach [`note`] and [`help`] text. A macro that wants to warn
+/// without failing the build can create an [`Error::warning`] and append its
+/// [`.to_compile_error()`] to the generated code.
+///
+/// [`label`]: Error::label
+/// [`note`]: Error::note
+/// [`help`]: Error::help
+///
+/// ```
+/// # use proc_macro2::Span;
+/// use syn::{Error, Ident};
+///
+/// fn duplicate_field(first: &Ident, second: &Ident) -> Error {
+///     Error::new(second.span(), format!("duplicate field `{}`", second))
+///         .label(first.span(), "first defined here")
+///         .help("remove one of the fields")
+/// }
+/// # let a = Ident::new("a", Span::call_site());
+/// # let err = duplicate_field(&a, &a);
+/// # assert_eq!(err.to_string(), "duplicate field `a`");
+/// ```
+///
+/// On stable Rust each label and each note or help with a span of its own is
+/// reported as a separate `compile_error!`, and notes and help without a span
+/// are appended to the primary message. Warnings are reported through the
+/// deprecation lint and can only be expanded in item position. With the
+/// `"nightly-diagnostics"` feature on a nightly compiler, all of these are
+/// emitted as a single native diagnostic instead.
 #[derive(Clone)]
 pub struct Error {
     messages: Vec<ErrorMessage>,
@@ -96,6 +129,82 @@ struct ErrorMessage {
     start_span: ThreadBound<Span>,
     end_span: ThreadBound<Span>,
     message: String,
+    kind: ErrorKind,
+    level: Level,
+    labels: Vec<Child>,
+    children: Vec<Child>,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Level {
+    Error,
+    Warning,
+    Note,
+    Help,
+}
+
+// A secondary span with its label, or a note or help message with an optional
+// span of its own.
+struct Child {
+    level: Level,
+    span: Option<ThreadBound<Span>>,
+    message: String,
+}
+
+/// What kind of failure a message of an [`Error`] describes.
+///
+/// Syn's own parsers attach a kind other than `Other` to the errors they
+/// produce, so that callers can react to a failure without matching on its
+/// text. Errors created through [`Error::new`] and the other constructors, or
+/// with a custom message through [`ParseStream::error`], are of kind `Other`,
+/// except that the latter are `UnexpectedEof` at the end of the input.
+///
+/// [`ParseStream::error`]: crate::parse::ParseBuffer::error
+///
+/// # Example
+///
+/// ```
+/// use syn::{ErrorKind, Type};
+///
+/// let err = syn::parse_str::<Type>("Vec<u8 u16>").err().unwrap();
+/// match err.kind() {
+///     ErrorKind::UnexpectedToken { expected, found } => {
+///         assert_eq!(*expected, ["`,`"]);
+///         assert_eq!(found, "u16");
+///     }
+///     _ => unreachable!(),
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ErrorKind {
+    /// A token was found where the parser expected something else.
+    UnexpectedToken {
+        /// What the parser would have accepted, like ``"`,`"`` or
+        /// `"identifier"`, in the words of the error message. Empty if the
+        /// parser expected no more tokens.
+        expected: Vec<String>,
+        /// The token that was found, as source text. For a delimited group
+        /// this is only the opening delimiter.
+        found: String,
+    },
+
+    /// The input ended where the parser expected more tokens.
+    UnexpectedEof {
+        /// What the parser would have accepted, like ``"`,`"`` or
+        /// `"identifier"`, in the words of the error message. Empty if not
+        /// known.
+        expected: Vec<String>,
+    },
+
+    /// A literal that does not hold a valid value of the requested type, like
+    /// an integer that is out of range.
+    InvalidLiteral,
+
+    /// Any other error, usually one with a custom message.
+    Other,
+
+    #[doc(hidden)]
+    __Nonexhaustive,
 }
 
 #[cfg(test)]
@@ -135,11 +244,56 @@ impl Error {
     /// }
     /// ```
     pub fn new<T: Display>(span: Span, message: T) -> Self {
+        Error::with_level(span, span, message.to_string(), Level::Error)
+    }
+
+    /// Creates a warning with the specified message at the given span.
+    ///
+    /// A warning does not fail compilation. Its [`.to_compile_error()`] is
+    /// meant to be appended to the macro's output rather than replace it. On
+    /// stable Rust that output must be in item position.
+    ///
+    /// [`.to_compile_error()`]: Error::to_compile_error
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # extern crate proc_macro;
+    /// #
+    /// use proc_macro::TokenStream;
+    /// use quote::quote;
+    /// use syn::{parse_macro_input, DeriveInput, Error};
+    ///
+    /// # const IGNORE: &str = stringify! {
+    /// #[proc_macro_derive(MyDerive)]
+    /// # };
+    /// pub fn my_derive(input: TokenStream) -> TokenStream {
+    ///     let input = parse_macro_input!(input as DeriveInput);
+    ///     let ident = &input.ident;
+    ///     let mut expanded = quote! {
+    ///         impl MyTrait for #ident {}
+    ///     };
+    ///     if !input.generics.params.is_empty() {
+    ///         let warning = Error::warning(ident.span(), "generic parameters are ignored");
+    ///         expanded.extend(warning.to_compile_error());
+    ///     }
+    ///     TokenStream::from(expanded)
+    /// }
+    /// ```
+    pub fn warning<T: Display>(span: Span, message: T) -> Self {
+        Error::with_level(span, span, message.to_string(), Level::Warning)
+    }
+
+    fn with_level(start: Span, end: Span, message: String, level: Level) -> Self {
         Error {
             messages: vec![ErrorMessage {
-                start_span: ThreadBound::new(span),
-                end_span: ThreadBound::new(span),
-                message: message.to_string(),
+                start_span: ThreadBound::new(start),
+                end_span: ThreadBound::new(end),
+                message,
+                kind: ErrorKind::Other,
+                level,
+                labels: Vec::new(),
+                children: Vec::new(),
             }],
         }
     }
@@ -162,13 +316,7 @@ impl Error {
         let mut iter = tokens.into_token_stream().into_iter();
         let start = iter.next().map_or_else(Span::call_site, |t| t.span());
         let end = iter.last().map_or(start, |t| t.span());
-        Error {
-            messages: vec![ErrorMessage {
-                start_span: ThreadBound::new(start),
-                end_span: ThreadBound::new(end),
-                message: message.to_string(),
-            }],
-        }
+        Error::with_level(start, end, message.to_string(), Level::Error)
     }
 
     /// The source location of the error.
@@ -194,7 +342,24 @@ impl Error {
     /// this method correctly in a procedural macro.
     ///
     /// [`compile_error!`]: https://doc.rust-lang.org/std/macro.compile_error.html
+    ///
+    /// Labels, notes and warnings are rendered as described [above]. With the
+    /// `"nightly-diagnostics"` feature on a nightly compiler, when called from
+    /// within a procedural macro, the diagnostics are emitted directly and the
+    /// returned tokens are empty.
+    ///
+    /// [above]: Error#labels-notes-and-warnings
     pub fn to_compile_error(&self) -> TokenStream {
+        #[cfg(syn_nightly_diagnostics)]
+        {
+            if proc_macro::is_available() {
+                for message in &self.messages {
+                    message.emit();
+                }
+                return TokenStream::new();
+            }
+        }
+
         self.messages
             .iter()
             .map(ErrorMessage::to_compile_error)
@@ -206,50 +371,358 @@ impl Error {
     pub fn combine(&mut self, another: Error) {
         self.messages.extend(another.messages)
     }
+
+    /// Points at other code relevant to the most recently added message, like
+    /// the first definition of a duplicated name.
+    pub fn label<T: Display>(self, span: Span, message: T) -> Self {
+        self.push_child(Level::Note, Some(span), message.to_string(), true)
+    }
+
+    /// Attaches a note to the most recently added message.
+    pub fn note<T: Display>(self, message: T) -> Self {
+        self.push_child(Level::Note, None, message.to_string(), false)
+    }
+
+    /// Attaches a note pointing at the given span to the most recently added
+    /// message.
+    pub fn span_note<T: Display>(self, span: Span, message: T) -> Self {
+        self.push_child(Level::Note, Some(span), message.to_string(), false)
+    }
+
+    /// Attaches a suggestion of how to fix the problem to the most recently
+    /// added message.
+    pub fn help<T: Display>(self, message: T) -> Self {
+        self.push_child(Level::Help, None, message.to_string(), false)
+    }
+
+    /// Attaches a suggestion pointing at the given span to the most recently
+    /// added message.
+    pub fn span_help<T: Display>(self, span: Span, message: T) -> Self {
+        self.push_child(Level::Help, Some(span), message.to_string(), false)
+    }
+
+    /// The kind of failure that the first message in self describes.
+    ///
+    /// Use [`iter`] to look at the kind of every message.
+    ///
+    /// [`iter`]: Error::iter
+    pub fn kind(&self) -> &ErrorKind {
+        &self.messages[0].kind
+    }
+
+    /// Iterates over the messages in self, each as an `Error` of its own.
+    pub fn iter(&self) -> Iter<'_> {
+        self.into_iter()
+    }
+
+    // Sets the kind of the most recently added message.
+    #[cfg(any(feature = "parsing", feature = "full", feature = "derive"))]
+    pub(crate) fn with_kind(mut self, kind: ErrorKind) -> Self {
+        self.messages.last_mut().unwrap().kind = kind;
+        self
+    }
+
+    /// Whether any message in self is an error rather than a warning.
+    pub fn is_fatal(&self) -> bool {
+        self.messages
+            .iter()
+            .any(|message| message.level == Level::Error)
+    }
+
+    fn push_child(
+        mut self,
+        level: Level,
+        span: Option<Span>,
+        message: String,
+        label: bool,
+    ) -> Self {
+        let last = self.messages.last_mut().unwrap();
+        let child = Child {
+            level,
+            span: span.map(ThreadBound::new),
+            message,
+        };
+        if label {
+            last.labels.push(child);
+        } else {
+            last.children.push(child);
+        }
+        self
+    }
 }
 
 impl ErrorMessage {
-    fn to_compile_error(&self) -> TokenStream {
+    fn span(&self) -> (Span, Span) {
         let start = self
             .start_span
             .get()
             .cloned()
             .unwrap_or_else(Span::call_site);
         let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
+        (start, end)
+    }
 
-        // compile_error!($message)
-        TokenStream::from_iter(vec![
-            TokenTree::Ident(Ident::new("compile_error", start)),
-            TokenTree::Punct({
-                let mut punct = Punct::new('!', Spacing::Alone);
-                punct.set_span(start);
-                punct
-            }),
-            TokenTree::Group({
-                let mut group = Group::new(Delimiter::Brace, {
-                    TokenStream::from_iter(vec![TokenTree::Literal({
-                        let mut string = Literal::string(&self.message);
-                        string.set_span(end);
-                        string
-                    })])
-                });
-                group.set_span(end);
-                group
-            }),
-        ])
+    fn to_compile_error(&self) -> TokenStream {
+        let (start, end) = self.span();
+        let mut message = self.message.clone();
+        let mut spanned = Vec::new();
+        for label in &self.labels {
+            spanned.push((label.span(), label.message.clone()));
+        }
+        for child in &self.children {
+            let text = format!("{}: {}", child.level.name(), child.message);
+            match &child.span {
+                Some(_) => spanned.push((child.span(), text)),
+                None => {
+                    message += "\n= ";
+                    message += &text;
+                }
+            }
+        }
+
+        let render = match self.level {
+            Level::Warning => warning,
+            _ => compile_error,
+        };
+        let mut tokens = render(start, end, &message);
+        for (span, message) in spanned {
+            tokens.extend(render(span, span, &message));
+        }
+        tokens
+    }
+
+    #[cfg(syn_nightly_diagnostics)]
+    fn emit(&self) {
+        use proc_macro::{Diagnostic, Level as DiagnosticLevel};
+
+        let (start, end) = self.span();
+        let span = start.join(end).unwrap_or(start).unwrap();
+        let level = match self.level {
+            Level::Warning => DiagnosticLevel::Warning,
+            _ => DiagnosticLevel::Error,
+        };
+        let mut diagnostic = Diagnostic::spanned(span, level, self.message.as_str());
+        for child in self.labels.iter().chain(&self.children) {
+            let message = child.message.as_str();
+            diagnostic = match (child.level, child.span.as_ref()) {
+                (Level::Help, Some(_)) => diagnostic.span_help(child.span().unwrap(), message),
+                (Level::Help, None) => diagnostic.help(message),
+                (_, Some(_)) => diagnostic.span_note(child.span().unwrap(), message),
+                (_, None) => diagnostic.note(message),
+            };
+        }
+        diagnostic.emit();
+    }
+}
+
+impl Child {
+    fn span(&self) -> Span {
+        match &self.span {
+            Some(span) => span.get().cloned().unwrap_or_else(Span::call_site),
+            None => Span::call_site(),
+        }
+    }
+}
+
+impl Level {
+    fn name(self) -> &'static str {
+        match self {
+            Level::Error => "error",
+            Level::Warning => "warning",
+            Level::Note => "note",
+            Level::Help => "help",
+        }
+    }
+}
+
+fn compile_error(start: Span, end: Span, message: &str) -> TokenStream {
+    // compile_error!($message)
+    TokenStream::from_iter(vec![
+        TokenTree::Ident(Ident::new("compile_error", start)),
+        TokenTree::Punct({
+            let mut punct = Punct::new('!', Spacing::Alone);
+            punct.set_span(start);
+            punct
+        }),
+        TokenTree::Group({
+            let mut group = Group::new(Delimiter::Brace, {
+                TokenStream::from_iter(vec![TokenTree::Literal({
+                    let mut string = Literal::string(message);
+                    string.set_span(end);
+                    string
+                })])
+            });
+            group.set_span(end);
+            group
+        }),
+    ])
+}
+
+// Stable Rust has no way for a macro to emit a warning, so refer to a
+// deprecated constant whose deprecation note is the message:
+//
+//     #[allow(non_upper_case_globals, dead_code)]
+//     const __syn_warning_N: () = {
+//         #[deprecated(note = $message)]
+//         #[allow(non_upper_case_globals)]
+//         const warning: () = ();
+//         warning
+//     };
+//
+// The outer constant is numbered so that several warnings can be emitted into
+// the same module; `const _` would need Rust 1.37.
+fn warning(start: Span, end: Span, message: &str) -> TokenStream {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    fn ident(name: &str, span: Span) -> TokenTree {
+        TokenTree::Ident(Ident::new(name, span))
+    }
+
+    fn punct(ch: char, span: Span) -> TokenTree {
+        let mut punct = Punct::new(ch, Spacing::Alone);
+        punct.set_span(span);
+        TokenTree::Punct(punct)
+    }
+
+    fn group(delimiter: Delimiter, tokens: Vec<TokenTree>, span: Span) -> TokenTree {
+        let mut group = Group::new(delimiter, TokenStream::from_iter(tokens));
+        group.set_span(span);
+        TokenTree::Group(group)
+    }
+
+    fn unit(span: Span) -> TokenTree {
+        group(Delimiter::Parenthesis, Vec::new(), span)
     }
+
+    let call_site = Span::call_site();
+    let note = {
+        let mut string = Literal::string(message);
+        string.set_span(end);
+        TokenTree::Literal(string)
+    };
+    let deprecated = group(
+        Delimiter::Bracket,
+        vec![
+            ident("deprecated", call_site),
+            group(
+                Delimiter::Parenthesis,
+                vec![ident("note", call_site), punct('=', call_site), note],
+                call_site,
+            ),
+        ],
+        call_site,
+    );
+    let allow = |lints: &[&str]| {
+        let mut list = Vec::new();
+        for (i, lint) in lints.iter().enumerate() {
+            if i > 0 {
+                list.push(punct(',', call_site));
+            }
+            list.push(ident(lint, call_site));
+        }
+        group(
+            Delimiter::Bracket,
+            vec![
+                ident("allow", call_site),
+                group(Delimiter::Parenthesis, list, call_site),
+            ],
+            call_site,
+        )
+    };
+    let body = vec![
+        punct('#', call_site),
+        deprecated,
+        punct('#', call_site),
+        allow(&["non_upper_case_globals"]),
+        ident("const", call_site),
+        ident("warning", call_site),
+        punct(':', call_site),
+        unit(call_site),
+        punct('=', call_site),
+        unit(call_site),
+        punct(';', call_site),
+        ident("warning", start),
+    ];
+    let name = format!("__syn_warning_{}", COUNTER.fetch_add(1, Ordering::Relaxed));
+    TokenStream::from_iter(vec![
+        punct('#', call_site),
+        allow(&["non_upper_case_globals", "dead_code"]),
+        ident("const", call_site),
+        ident(&name, call_site),
+        punct(':', call_site),
+        unit(call_site),
+        punct('=', call_site),
+        group(Delimiter::Brace, body, call_site),
+        punct(';', call_site),
+    ])
 }
 
 #[cfg(feature = "parsing")]
 pub fn new_at<T: Display>(scope: Span, cursor: Cursor, message: T) -> Error {
     if cursor.eof() {
-        Error::new(scope, format!("unexpected end of input, {}", message))
+        Error::new(scope, format!("unexpected end of input, {}", message)).with_kind(
+            ErrorKind::UnexpectedEof {
+                expected: Vec::new(),
+            },
+        )
     } else {
         let span = crate::buffer::open_span_of_group(cursor);
         Error::new(span, message)
     }
 }
 
+// An error saying which of `expected` the parser would have accepted at the
+// cursor.
+#[cfg(feature = "parsing")]
+pub fn new_expected<T: Display>(scope: Span, cursor: Cursor, expected: &[T]) -> Error {
+    let expected: Vec<String> = expected.iter().map(ToString::to_string).collect();
+    let message = match expected.len() {
+        0 => return new_unexpected(scope, cursor),
+        1 => format!("expected {}", expected[0]),
+        2 => format!("expected {} or {}", expected[0], expected[1]),
+        _ => format!("expected one of: {}", expected.join(", ")),
+    };
+    new_at(scope, cursor, message).with_kind(expected_kind(cursor, expected))
+}
+
+// An error for a token, or the end of input, where none was expected.
+#[cfg(feature = "parsing")]
+pub fn new_unexpected(scope: Span, cursor: Cursor) -> Error {
+    if cursor.eof() {
+        Error::new(scope, "unexpected end of input").with_kind(expected_kind(cursor, Vec::new()))
+    } else {
+        Error::new(cursor.span(), "unexpected token").with_kind(expected_kind(cursor, Vec::new()))
+    }
+}
+
+#[cfg(feature = "parsing")]
+pub fn expected_kind(cursor: Cursor, expected: Vec<String>) -> ErrorKind {
+    if cursor.eof() {
+        ErrorKind::UnexpectedEof { expected }
+    } else {
+        ErrorKind::UnexpectedToken {
+            expected,
+            found: found(cursor),
+        }
+    }
+}
+
+// Source text of the token at the cursor, or just the opening delimiter of a
+// group.
+#[cfg(feature = "parsing")]
+pub fn found(cursor: Cursor) -> String {
+    match cursor.token_tree() {
+        Some((TokenTree::Group(group), _)) => match group.delimiter() {
+            Delimiter::Parenthesis => "(".to_owned(),
+            Delimiter::Brace => "{".to_owned(),
+            Delimiter::Bracket => "[".to_owned(),
+            Delimiter::None => group.stream().to_string(),
+        },
+        Some((tt, _)) => tt.to_string(),
+        None => String::new(),
+    }
+}
+
 impl Debug for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
         if self.messages.len() == 1 {
@@ -280,16 +753,25 @@ impl Display for Error {
 
 impl Clone for ErrorMessage {
     fn clone(&self) -> Self {
-        let start = self
-            .start_span
-            .get()
-            .cloned()
-            .unwrap_or_else(Span::call_site);
-        let end = self.end_span.get().cloned().unwrap_or_else(Span::call_site);
+        let (start, end) = self.span();
         ErrorMessage {
             start_span: ThreadBound::new(start),
             end_span: ThreadBound::new(end),
             message: self.message.clone(),
+            kind: self.kind.clone(),
+            level: self.level,
+            labels: self.labels.clone(),
+            children: self.children.clone(),
+        }
+    }
+}
+
+impl Clone for Child {
+    fn clone(&self) -> Self {
+        Child {
+            level: self.level,
+            span: self.span.as_ref().map(|_| ThreadBound::new(self.span())),
+            message: self.message.clone(),
         }
     }
 }
@@ -355,3 +837,422 @@ impl<'a> Iterator for Iter<'a> {
         })
     }
 }
+
+#[cfg(feature = "span-locations")]
+mod render {
+    use super::*;
+
+    use std::cmp;
+    use std::fmt::Write;
+
+    impl Error {
+        /// Renders every message of the error the way rustc would, as a
+        /// snippet of `source` with the offending code underlined.
+        ///
+        /// This is for tools that parse source files outside of a procedural
+        /// macro. The spans of the error must come from parsing `source` with
+        /// the `"span-locations"` feature enabled, as by [`syn::parse_file`]
+        /// or [`syn::parse_str`]. Messages without a location, such as those
+        /// spanned at `Span::call_site()`, are rendered without a snippet.
+        ///
+        /// [`syn::parse_file`]: crate::parse_file
+        /// [`syn::parse_str`]: crate::parse_str
+        ///
+        /// *This method is available if Syn is built with the
+        /// `"span-locations"` feature.*
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// let source = "fn main() {\n    let x = ;\n}\n";
+        /// let err = syn::parse_file(source).unwrap_err();
+        ///
+        /// let expected = "\
+        /// error: expected expression
+        ///  --> src/main.rs:2:13
+        ///   |
+        /// 2 |     let x = ;
+        ///   |             ^
+        /// ";
+        /// assert_eq!(err.render(source, Some("src/main.rs")), expected);
+        /// ```
+        pub fn render(&self, source: &str, file_name: Option<&str>) -> String {
+            let source = Source::new(source, file_name);
+            let mut out = String::new();
+            for (i, message) in self.messages.iter().enumerate() {
+                if i > 0 {
+                    out.push('\n');
+                }
+                out += &message.render(&source);
+            }
+            out
+        }
+
+        /// Renders every message of the error as a line of JSON in the format
+        /// of rustc's `--error-format=json`, for consumption by editors.
+        ///
+        /// Locations are computed as described for [`render`].
+        ///
+        /// [`render`]: Error::render
+        ///
+        /// *This method is available if Syn is built with the
+        /// `"span-locations"` feature.*
+        pub fn render_json(&self, source: &str, file_name: Option<&str>) -> String {
+            let source = Source::new(source, file_name);
+            let mut out = String::new();
+            for message in &self.messages {
+                message.render_json(&source, &mut out);
+                out.push('\n');
+            }
+            out
+        }
+    }
+
+    struct Source<'a> {
+        text: &'a str,
+        // Length of a byte order mark at the start of `text`, which the
+        // tokenizer never sees and which therefore shifts no columns.
+        bom: usize,
+        // Byte offset of the start of every line after the byte order mark.
+        lines: Vec<usize>,
+        file_name: &'a str,
+    }
+
+    // Where a span lies in the source. Lines are 1-based and columns are
+    // 0-based counts of characters, as in proc_macro2::LineColumn.
+    struct Location {
+        start_line: usize,
+        start_column: usize,
+        end_line: usize,
+        end_column: usize,
+    }
+
+    // A span of a snippet along with how to underline it.
+    struct Annotation<'a> {
+        location: Location,
+        primary: bool,
+        label: Option<&'a str>,
+    }
+
+    impl<'a> Source<'a> {
+        fn new(text: &'a str, file_name: Option<&'a str>) -> Self {
+            let bom = if text.starts_with('\u{feff}') {
+                '\u{feff}'.len_utf8()
+            } else {
+                0
+            };
+            let mut lines = vec![bom];
+            lines.extend(text.match_indices('\n').map(|(i, _)| i + 1));
+            Source {
+                text,
+                bom,
+                lines,
+                file_name: file_name.unwrap_or("<anon>"),
+            }
+        }
+
+        fn locate(&self, start: Span, end: Span) -> Option<Location> {
+            let (start, end) = (start.start(), end.end());
+            // Spans without a location, like Span::call_site(), are reported
+            // at the very beginning with zero width.
+            if start.line == 1 && start.column == 0 && end.line == 1 && end.column == 0 {
+                return None;
+            }
+            if end.line > self.lines.len() || end.column > self.line(end.line).chars().count() {
+                return None;
+            }
+            Some(Location {
+                start_line: start.line,
+                start_column: start.column,
+                end_line: end.line,
+                end_column: end.column,
+            })
+        }
+
+        // Text of a 1-based line without its line ending.
+        fn line(&self, line: usize) -> &'a str {
+            let start = self.lines[line - 1];
+            let end = match self.lines.get(line) {
+                Some(next) => next - 1,
+                None => self.text.len(),
+            };
+            let text = &self.text[start..end];
+            if text.ends_with('\r') {
+                &text[..text.len() - 1]
+            } else {
+                text
+            }
+        }
+
+        fn byte_offset(&self, line: usize, column: usize) -> usize {
+            let text = self.line(line);
+            let within = text
+                .char_indices()
+                .nth(column)
+                .map_or(text.len(), |(i, _)| i);
+            self.lines[line - 1] + within
+        }
+    }
+
+    impl Location {
+        // Columns covered by the span on one of its lines, in characters.
+        fn highlight(&self, source: &Source, line: usize) -> (usize, usize) {
+            let start = if line == self.start_line {
+                self.start_column
+            } else {
+                0
+            };
+            let end = if line == self.end_line {
+                self.end_column
+            } else {
+                source.line(line).chars().count()
+            };
+            (start, end)
+        }
+    }
+
+    // Tabs are shown as four spaces, as rustc does.
+    fn display_width(text: &str) -> usize {
+        text.chars().map(|ch| if ch == '\t' { 4 } else { 1 }).sum()
+    }
+
+    fn child_location(child: &Child, source: &Source) -> Option<Location> {
+        match &child.span {
+            Some(_) => {
+                let span = child.span();
+                source.locate(span, span)
+            }
+            None => None,
+        }
+    }
+
+    impl ErrorMessage {
+        fn location(&self, source: &Source) -> Option<Location> {
+            let (start, end) = self.span();
+            source.locate(start, end)
+        }
+
+        fn render(&self, source: &Source) -> String {
+            let mut annotations = Vec::new();
+            if let Some(location) = self.location(source) {
+                annotations.push(Annotation {
+                    location,
+                    primary: true,
+                    label: None,
+                });
+            }
+            for label in &self.labels {
+                if let Some(location) = child_location(label, source) {
+                    annotations.push(Annotation {
+                        location,
+                        primary: false,
+                        label: Some(&label.message),
+                    });
+                }
+            }
+
+            let mut notes = Vec::new();
+            let mut spanned = Vec::new();
+            for child in &self.children {
+                match child_location(child, source) {
+                    Some(location) => spanned.push((child, location)),
+                    None => notes.push(child),
+                }
+            }
+
+            let gutter = annotations
+                .iter()
+                .map(|annotation| annotation.location.start_line)
+                .chain(spanned.iter().map(|(_, location)| location.start_line))
+                .max()
+                .map_or(0, |line| line.to_string().len());
+
+            let mut out = String::new();
+            let _ = writeln!(out, "{}: {}", self.level.name(), self.message);
+            snippet(&mut out, source, &mut annotations, gutter);
+            if !notes.is_empty() {
+                if !annotations.is_empty() {
+                    let _ = writeln!(out, "{:1$} |", "", gutter);
+                }
+                for note in notes {
+                    let _ = writeln!(
+                        out,
+                        "{:3$} = {}: {}",
+                        "",
+                        note.level.name(),
+                        note.message,
+                        gutter,
+                    );
+                }
+            }
+            for (child, location) in spanned {
+                let _ = writeln!(out, "{}: {}", child.level.name(), child.message);
+                let mut annotations = vec![Annotation {
+                    location,
+                    primary: true,
+                    label: None,
+                }];
+                snippet(&mut out, source, &mut annotations, gutter);
+            }
+            out
+        }
+
+        fn render_json(&self, source: &Source, out: &mut String) {
+            let mut spans = Vec::new();
+            if let Some(location) = self.location(source) {
+                spans.push((location, true, None));
+            }
+            for label in &self.labels {
+                if let Some(location) = child_location(label, source) {
+                    spans.push((location, false, Some(label.message.as_str())));
+                }
+            }
+
+            out.push_str("{\"message\":");
+            json_string(out, &self.message);
+            out.push_str(",\"code\":null,\"level\":");
+            json_string(out, self.level.name());
+            json_spans(out, source, &spans);
+            out.push_str(",\"children\":[");
+            for (i, child) in self.children.iter().enumerate() {
+                if i > 0 {
+                    out.push(',');
+                }
+                let spans: Vec<_> = child_location(child, source)
+                    .map(|location| (location, true, None))
+                    .into_iter()
+                    .collect();
+                out.push_str("{\"message\":");
+                json_string(out, &child.message);
+                out.push_str(",\"code\":null,\"level\":");
+                json_string(out, child.level.name());
+                json_spans(out, source, &spans);
+                out.push_str(",\"children\":[],\"rendered\":null}");
+            }
+            out.push_str("],\"rendered\":");
+            json_string(out, &self.render(source));
+            out.push('}');
+        }
+    }
+
+    // Writes the ` --> file:line:column` header and the annotated source lines.
+    fn snippet(out: &mut String, source: &Source, annotations: &mut [Annotation], gutter: usize) {
+        let primary = match annotations.iter().find(|annotation| annotation.primary) {
+            Some(primary) => &primary.location,
+            None => {
+                let _ = writeln!(out, "{:1$}--> {2}", "", gutter, source.file_name);
+                return;
+            }
+        };
+        let _ = writeln!(
+            out,
+            "{:4$}--> {}:{}:{}",
+            "",
+            source.file_name,
+            primary.start_line,
+            primary.start_column + 1,
+            gutter,
+        );
+        let _ = writeln!(out, "{:1$} |", "", gutter);
+
+        annotations.sort_by_key(|annotation| {
+            let location = &annotation.location;
+            (
+                location.start_line,
+                !annotation.primary,
+                location.start_column,
+            )
+        });
+        let mut previous_line = None;
+        for annotation in annotations.iter() {
+            let line = annotation.location.start_line;
+            if previous_line != Some(line) {
+                if previous_line.map_or(false, |previous| line > previous + 1) {
+                    out.push_str("...\n");
+                }
+                let text = source.line(line).replace('\t', "    ");
+                let _ = writeln!(out, "{:>2$} | {}", line, text, gutter);
+                previous_line = Some(line);
+            }
+
+            // Only the first line of a multi-line span is shown.
+            let text = source.line(line);
+            let (start, end) = annotation.location.highlight(source, line);
+            let prefix: String = text.chars().take(start).collect();
+            let underlined: String = text.chars().skip(start).take(end - start).collect();
+            let mark = if annotation.primary { "^" } else { "-" };
+            let mut underline = " ".repeat(display_width(&prefix));
+            underline += &mark.repeat(cmp::max(display_width(&underlined), 1));
+            if let Some(label) = annotation.label {
+                underline.push(' ');
+                underline += label;
+            }
+            let _ = writeln!(out, "{:2$} | {}", "", underline, gutter);
+        }
+    }
+
+    fn json_spans(out: &mut String, source: &Source, spans: &[(Location, bool, Option<&str>)]) {
+        out.push_str(",\"spans\":[");
+        for (i, (location, primary, label)) in spans.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            let byte_start = source.byte_offset(location.start_line, location.start_column);
+            let byte_end = source.byte_offset(location.end_line, location.end_column);
+            let _ = write!(out, "{{\"file_name\":");
+            json_string(out, source.file_name);
+            let _ = write!(
+                out,
+                ",\"byte_start\":{},\"byte_end\":{},\"line_start\":{},\"line_end\":{},\"column_start\":{},\"column_end\":{},\"is_primary\":{},\"text\":[",
+                byte_start - source.bom,
+                byte_end - source.bom,
+                location.start_line,
+                location.end_line,
+                location.start_column + 1,
+                location.end_column + 1,
+                primary,
+            );
+            for line in location.start_line..=location.end_line {
+                if line > location.start_line {
+                    out.push(',');
+                }
+                let (start, end) = location.highlight(source, line);
+                out.push_str("{\"text\":");
+                json_string(out, source.line(line));
+                let _ = write!(
+                    out,
+                    ",\"highlight_start\":{},\"highlight_end\":{}}}",
+                    start + 1,
+                    end + 1,
+                );
+            }
+            out.push_str("],\"label\":");
+            match label {
+                Some(label) => json_string(out, label),
+                None => out.push_str("null"),
+            }
+            out.push_str(
+                ",\"suggested_replacement\":null,\"suggestion_applicability\":null,\"expansion\":null}",
+            );
+        }
+        out.push(']');
+    }
+
+    fn json_string(out: &mut String, value: &str) {
+        out.push('"');
+        for ch in value.chars() {
+            match ch {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                ch if (ch as u32) < 0x20 => {
+                    let _ = write!(out, "\\u{:04x}", ch as u32);
+                }
+                ch => out.push(ch),
+            }
+        }
+        out.push('"');
+    }
+}
@@ -0,0 +1,879 @@
+//! Evaluation of constant expressions.
+//!
+//! An [`Evaluator`] folds an [`Expr`] made of integer, byte and boolean
+//! literals into the [`Value`] it has at compile time, as needed for an enum
+//! discriminant, the length of an array type or a const generic argument.
+//! The supported expressions are:
+//!
+//! - integer, byte and `bool` literals;
+//! - arithmetic, bitwise, shift, comparison and lazy boolean operators, and
+//!   unary `-` and `!`;
+//! - `as` casts to integer types, including of `char` literals;
+//! - parentheses and invisible groups;
+//! - references by name to the `const` items at the top level of a [`File`],
+//!   or to other constants made known to the evaluator.
+//!
+//! Integers are typed the way the compiler types them: by the suffix of a
+//! literal, the target of a cast or the declared type of a constant, and
+//! otherwise by the other operand of a binary operator or by the type the
+//! surrounding expression expects, falling back to `i32`. Arithmetic that
+//! overflows its type is an error, while casts truncate as they do at run
+//! time. `isize` and `usize` are evaluated as 64-bit types.
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature and with the `"parsing"` and `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::eval::{Evaluator, IntType};
+//! use syn::{Expr, Ident, Type};
+//!
+//! let ident: Ident = syn::parse_str("WIDTH").unwrap();
+//! let ty: Type = syn::parse_str("usize").unwrap();
+//! let expr: Expr = syn::parse_str("1 << 4").unwrap();
+//!
+//! let mut evaluator = Evaluator::new();
+//! evaluator.define(&ident, &ty, &expr);
+//!
+//! let buffer: Type = syn::parse_str("[u8; WIDTH * 2 + 1]").unwrap();
+//! if let Type::Array(buffer) = buffer {
+//!     let len = evaluator.eval_as(&buffer.len, IntType::Usize).unwrap();
+//!     assert_eq!(len.to_u128(), Some(33));
+//! }
+//! ```
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use quote::ToTokens;
+
+use crate::*;
+
+/// An integer type.
+///
+/// *This type is available if Syn is built with the `"derive"` or `"full"`
+/// feature and with the `"parsing"` and `"printing"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum IntType {
+    I8,
+    I16,
+    I32,
+    I64,
+    I128,
+    Isize,
+    U8,
+    U16,
+    U32,
+    U64,
+    U128,
+    Usize,
+}
+
+/// The value of a constant expression.
+///
+/// *This type is available if Syn is built with the `"derive"` or `"full"`
+/// feature and with the `"parsing"` and `"printing"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Value {
+    /// A value of an integer type.
+    Int(Int),
+    /// A `bool`.
+    Bool(bool),
+}
+
+/// A value of one of the integer types.
+///
+/// *This type is available if Syn is built with the `"derive"` or `"full"`
+/// feature and with the `"parsing"` and `"printing"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Int {
+    ty: IntType,
+    // Two's complement, sign extended to 128 bits for the signed types.
+    bits: u128,
+}
+
+/// Evaluates constant expressions, optionally referring to the `const`
+/// items of a file.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This type is available if Syn is built with the `"derive"` or `"full"`
+/// feature and with the `"parsing"` and `"printing"` features.*
+pub struct Evaluator<'ast> {
+    consts: HashMap<String, Const<'ast>>,
+    // Constants being evaluated, to report cycles between them.
+    stack: RefCell<Vec<String>>,
+}
+
+/// Evaluates an expression that does not refer to any constants.
+///
+/// *This function is available if Syn is built with the `"derive"` or `"full"`
+/// feature and with the `"parsing"` and `"printing"` features.*
+pub fn eval(expr: &Expr) -> Result<Value> {
+    Evaluator::new().eval(expr)
+}
+
+struct Const<'ast> {
+    ident: &'ast Ident,
+    ty: &'ast Type,
+    expr: &'ast Expr,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum Ty {
+    Int(IntType),
+    Bool,
+}
+
+impl IntType {
+    /// The integer type named by `ty`, if it is one of the primitive
+    /// integer types written as a single identifier like `u8`.
+    pub fn from_type(ty: &Type) -> Option<Self> {
+        match ty {
+            Type::Path(ty) if ty.qself.is_none() => {
+                let ident = ty.path.get_ident()?;
+                IntType::from_name(&ident.to_string())
+            }
+            Type::Group(ty) => IntType::from_type(&ty.elem),
+            Type::Paren(ty) => IntType::from_type(&ty.elem),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn from_name(name: &str) -> Option<Self> {
+        let ty = match name {
+            "i8" => IntType::I8,
+            "i16" => IntType::I16,
+            "i32" => IntType::I32,
+            "i64" => IntType::I64,
+            "i128" => IntType::I128,
+            "isize" => IntType::Isize,
+            "u8" => IntType::U8,
+            "u16" => IntType::U16,
+            "u32" => IntType::U32,
+            "u64" => IntType::U64,
+            "u128" => IntType::U128,
+            "usize" => IntType::Usize,
+            _ => return None,
+        };
+        Some(ty)
+    }
+
+    /// The name of the type, like `"u8"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            IntType::I8 => "i8",
+            IntType::I16 => "i16",
+            IntType::I32 => "i32",
+            IntType::I64 => "i64",
+            IntType::I128 => "i128",
+            IntType::Isize => "isize",
+            IntType::U8 => "u8",
+            IntType::U16 => "u16",
+            IntType::U32 => "u32",
+            IntType::U64 => "u64",
+            IntType::U128 => "u128",
+            IntType::Usize => "usize",
+        }
+    }
+
+    /// The width of the type in bits.
+    pub fn bits(self) -> u32 {
+        match self {
+            IntType::I8 | IntType::U8 => 8,
+            IntType::I16 | IntType::U16 => 16,
+            IntType::I32 | IntType::U32 => 32,
+            IntType::I64 | IntType::U64 | IntType::Isize | IntType::Usize => 64,
+            IntType::I128 | IntType::U128 => 128,
+        }
+    }
+
+    /// Whether the type can hold negative values.
+    pub fn is_signed(self) -> bool {
+        match self {
+            IntType::I8
+            | IntType::I16
+            | IntType::I32
+            | IntType::I64
+            | IntType::I128
+            | IntType::Isize => true,
+            IntType::U8
+            | IntType::U16
+            | IntType::U32
+            | IntType::U64
+            | IntType::U128
+            | IntType::Usize => false,
+        }
+    }
+
+    fn mask(self) -> u128 {
+        match self.bits() {
+            128 => !0,
+            bits => (1 << bits) - 1,
+        }
+    }
+
+    fn min(self) -> i128 {
+        if self.is_signed() {
+            -1 << (self.bits() - 1)
+        } else {
+            0
+        }
+    }
+
+    fn max(self) -> u128 {
+        if self.is_signed() {
+            self.mask() >> 1
+        } else {
+            self.mask()
+        }
+    }
+}
+
+impl Display for IntType {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str(self.name())
+    }
+}
+
+impl Int {
+    /// The type of the value.
+    pub fn ty(&self) -> IntType {
+        self.ty
+    }
+
+    /// The value as an `i128`, unless it is a `u128` too large to fit.
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.ty.is_signed() || self.bits <= i128::max_value() as u128 {
+            Some(self.bits as i128)
+        } else {
+            None
+        }
+    }
+
+    /// The value as a `u128`, unless it is negative.
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.is_negative() {
+            None
+        } else {
+            Some(self.bits)
+        }
+    }
+
+    #[cfg(feature = "derive")]
+    pub(crate) fn zero(ty: IntType) -> Self {
+        Int { ty, bits: 0 }
+    }
+
+    // The next value of the same type, unless this is its maximum.
+    #[cfg(feature = "derive")]
+    pub(crate) fn checked_succ(self) -> Option<Self> {
+        let one = Int {
+            ty: self.ty,
+            bits: 1,
+        };
+        self.checked(one, i128::checked_add, u128::checked_add)
+    }
+
+    fn is_negative(&self) -> bool {
+        self.ty.is_signed() && (self.bits as i128) < 0
+    }
+
+    fn from_i128(ty: IntType, value: i128) -> Option<Self> {
+        if value < ty.min() || value > 0 && value as u128 > ty.max() {
+            None
+        } else {
+            Some(Int {
+                ty,
+                bits: value as u128,
+            })
+        }
+    }
+
+    fn from_u128(ty: IntType, value: u128) -> Option<Self> {
+        if value > ty.max() {
+            None
+        } else {
+            Some(Int { ty, bits: value })
+        }
+    }
+
+    // Converts as with `as`, truncating to the width of the target type.
+    fn cast(self, ty: IntType) -> Self {
+        let mask = ty.mask();
+        let mut bits = self.bits & mask;
+        if ty.is_signed() && bits >> (ty.bits() - 1) & 1 == 1 {
+            bits |= !mask;
+        }
+        Int { ty, bits }
+    }
+
+    fn checked(
+        self,
+        rhs: Self,
+        signed: fn(i128, i128) -> Option<i128>,
+        unsigned: fn(u128, u128) -> Option<u128>,
+    ) -> Option<Self> {
+        if self.ty.is_signed() {
+            let value = signed(self.bits as i128, rhs.bits as i128)?;
+            Int::from_i128(self.ty, value)
+        } else {
+            let value = unsigned(self.bits, rhs.bits)?;
+            Int::from_u128(self.ty, value)
+        }
+    }
+}
+
+impl Display for Int {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        if self.ty.is_signed() {
+            Display::fmt(&(self.bits as i128), formatter)
+        } else {
+            Display::fmt(&self.bits, formatter)
+        }
+    }
+}
+
+impl Value {
+    fn ty(&self) -> Ty {
+        match self {
+            Value::Int(int) => Ty::Int(int.ty),
+            Value::Bool(_) => Ty::Bool,
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Int(int) => Display::fmt(int, formatter),
+            Value::Bool(b) => Display::fmt(b, formatter),
+        }
+    }
+}
+
+impl Display for Ty {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Ty::Int(ty) => Display::fmt(ty, formatter),
+            Ty::Bool => formatter.write_str("bool"),
+        }
+    }
+}
+
+impl<'ast> Evaluator<'ast> {
+    /// An evaluator that knows no constants.
+    pub fn new() -> Self {
+        Evaluator {
+            consts: HashMap::new(),
+            stack: RefCell::new(Vec::new()),
+        }
+    }
+
+    /// An evaluator that knows the `const` items at the top level of
+    /// `file`.
+    ///
+    /// *This function is available if Syn is built with the `"full"`
+    /// feature.*
+    #[cfg(feature = "full")]
+    pub fn from_file(file: &'ast File) -> Self {
+        let mut evaluator = Evaluator::new();
+        for item in &file.items {
+            if let Item::Const(item) = item {
+                evaluator.add_const(item);
+            }
+        }
+        evaluator
+    }
+
+    /// Makes a constant known by its name, replacing any earlier constant of
+    /// the same name.
+    ///
+    /// *This function is available if Syn is built with the `"full"`
+    /// feature.*
+    #[cfg(feature = "full")]
+    pub fn add_const(&mut self, item: &'ast ItemConst) {
+        self.define(&item.ident, &item.ty, &item.expr);
+    }
+
+    /// Makes a constant `ident` of type `ty` with the value of `expr` known,
+    /// replacing any earlier constant of the same name. This is how to make
+    /// constants known that are not items of a file, like associated
+    /// constants.
+    pub fn define(&mut self, ident: &'ast Ident, ty: &'ast Type, expr: &'ast Expr) {
+        let constant = Const { ident, ty, expr };
+        self.consts.insert(ident.to_string(), constant);
+    }
+
+    /// Evaluates an expression.
+    ///
+    /// Integer literals without a suffix that are not given a type by the
+    /// rest of the expression are typed `i32`.
+    pub fn eval(&self, expr: &Expr) -> Result<Value> {
+        self.eval_expr(expr, None)
+    }
+
+    /// Evaluates an expression that has the integer type `ty`, like the
+    /// length of an array type, which is a `usize`.
+    pub fn eval_as(&self, expr: &Expr, ty: IntType) -> Result<Int> {
+        match self.eval_expr(expr, Some(Ty::Int(ty)))? {
+            Value::Int(int) => Ok(int),
+            Value::Bool(_) => unreachable!(),
+        }
+    }
+
+    /// Evaluates the constant with the given name.
+    pub fn eval_const(&self, ident: &Ident) -> Result<Value> {
+        match self.consts.get(&ident.to_string()) {
+            Some(constant) => self.eval_item(constant),
+            None => Err(Error::new(
+                ident.span(),
+                format!("cannot find constant `{}` in this scope", ident),
+            )),
+        }
+    }
+
+    fn eval_item(&self, item: &Const) -> Result<Value> {
+        let name = item.ident.to_string();
+        if self.stack.borrow().contains(&name) {
+            return Err(Error::new(
+                item.ident.span(),
+                format!("cycle detected when evaluating constant `{}`", name),
+            ));
+        }
+        let ty = match const_type(item.ty) {
+            Some(ty) => ty,
+            None => {
+                return Err(Error::new_spanned(
+                    item.ty,
+                    format!(
+                        "constant `{}` does not have an integer or `bool` type",
+                        name
+                    ),
+                ));
+            }
+        };
+        self.stack.borrow_mut().push(name);
+        let value = self.eval_expr(item.expr, Some(ty));
+        self.stack.borrow_mut().pop();
+        value
+    }
+
+    fn eval_expr(&self, expr: &Expr, expected: Option<Ty>) -> Result<Value> {
+        let value = self.eval_inner(expr, expected)?;
+        match expected {
+            Some(expected) if value.ty() != expected => Err(Error::new_spanned(
+                expr,
+                format!(
+                    "mismatched types: expected `{}`, found `{}`",
+                    expected,
+                    value.ty(),
+                ),
+            )),
+            _ => Ok(value),
+        }
+    }
+
+    fn eval_inner(&self, expr: &Expr, expected: Option<Ty>) -> Result<Value> {
+        match expr {
+            Expr::Lit(expr) => eval_lit(&expr.lit, expected, false),
+            Expr::Paren(expr) => self.eval_expr(&expr.expr, expected),
+            #[cfg(feature = "full")]
+            Expr::Group(expr) => self.eval_expr(&expr.expr, expected),
+            Expr::Unary(unary) => self.eval_unary(expr, unary, expected),
+            Expr::Binary(binary) => self.eval_binary(expr, binary, expected),
+            Expr::Cast(cast) => {
+                let ty = match IntType::from_type(&cast.ty) {
+                    Some(ty) => ty,
+                    None => {
+                        return Err(Error::new_spanned(
+                            &cast.ty,
+                            "only casts to integer types can be evaluated",
+                        ));
+                    }
+                };
+                if let Expr::Lit(ExprLit {
+                    lit: Lit::Char(lit),
+                    ..
+                }) = &*cast.expr
+                {
+                    let bits = u128::from(lit.value() as u32);
+                    let int = Int::from_u128(IntType::U32, bits).unwrap();
+                    return Ok(Value::Int(int.cast(ty)));
+                }
+                let inner = self.infer(&cast.expr).unwrap_or(Ty::Int(ty));
+                match self.eval_expr(&cast.expr, Some(inner))? {
+                    Value::Int(int) => Ok(Value::Int(int.cast(ty))),
+                    Value::Bool(b) => Ok(Value::Int(Int {
+                        ty,
+                        bits: u128::from(b),
+                    })),
+                }
+            }
+            Expr::Path(path) if path.qself.is_none() => match path.path.get_ident() {
+                Some(ident) => self.eval_const(ident),
+                None => Err(Error::new_spanned(
+                    path,
+                    "only constants named by a single identifier can be evaluated",
+                )),
+            },
+            _ => Err(Error::new_spanned(
+                expr,
+                "expression cannot be evaluated at compile time",
+            )),
+        }
+    }
+
+    fn eval_unary(&self, expr: &Expr, unary: &ExprUnary, expected: Option<Ty>) -> Result<Value> {
+        let ty = self
+            .infer(&unary.expr)
+            .or(expected)
+            .unwrap_or(Ty::Int(IntType::I32));
+        match unary.op {
+            UnOp::Neg(_) => {
+                if let Expr::Lit(lit) = &*unary.expr {
+                    return eval_lit(&lit.lit, Some(ty), true);
+                }
+                match self.eval_expr(&unary.expr, Some(ty))? {
+                    Value::Int(int) if int.ty.is_signed() => {
+                        let value = (int.bits as i128).checked_neg();
+                        match value.and_then(|value| Int::from_i128(int.ty, value)) {
+                            Some(int) => Ok(Value::Int(int)),
+                            None => {
+                                Err(Error::new_spanned(expr, "attempt to negate with overflow"))
+                            }
+                        }
+                    }
+                    value => Err(cannot_apply(expr, "-", value.ty())),
+                }
+            }
+            UnOp::Not(_) => match self.eval_expr(&unary.expr, Some(ty))? {
+                Value::Int(int) => Ok(Value::Int(Int {
+                    ty: int.ty,
+                    bits: if int.ty.is_signed() {
+                        !int.bits
+                    } else {
+                        !int.bits & int.ty.mask()
+                    },
+                })),
+                Value::Bool(b) => Ok(Value::Bool(!b)),
+            },
+            UnOp::Deref(_) => Err(Error::new_spanned(
+                expr,
+                "expression cannot be evaluated at compile time",
+            )),
+        }
+    }
+
+    fn eval_binary(&self, expr: &Expr, binary: &ExprBinary, expected: Option<Ty>) -> Result<Value> {
+        let op = &binary.op;
+        match op {
+            BinOp::And(_) | BinOp::Or(_) => {
+                let short_circuit = match op {
+                    BinOp::And(_) => false,
+                    _ => true,
+                };
+                if self.eval_bool(&binary.left)? == short_circuit {
+                    return Ok(Value::Bool(short_circuit));
+                }
+                return self.eval_bool(&binary.right).map(Value::Bool);
+            }
+            BinOp::Shl(_) | BinOp::Shr(_) => {
+                let ty = self.infer(&binary.left).or(expected);
+                let left = self.eval_int(&binary.left, op, ty)?;
+                let ty = self.infer(&binary.right);
+                let right = self.eval_int(&binary.right, op, ty)?;
+                return shift(expr, op, left, right).map(Value::Int);
+            }
+            _ => {}
+        }
+
+        let comparison = match op {
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => true,
+            _ => false,
+        };
+        let ty = self
+            .infer(&binary.left)
+            .or_else(|| self.infer(&binary.right))
+            .or(if comparison { None } else { expected })
+            .unwrap_or(Ty::Int(IntType::I32));
+        let left = self.eval_expr(&binary.left, Some(ty))?;
+        let right = self.eval_expr(&binary.right, Some(ty))?;
+
+        if comparison {
+            let ordering = match (left, right) {
+                (Value::Int(left), Value::Int(right)) => {
+                    if left.ty.is_signed() {
+                        (left.bits as i128).cmp(&(right.bits as i128))
+                    } else {
+                        left.bits.cmp(&right.bits)
+                    }
+                }
+                (Value::Bool(left), Value::Bool(right)) => left.cmp(&right),
+                _ => unreachable!(),
+            };
+            let result = match op {
+                BinOp::Eq(_) => ordering == Ordering::Equal,
+                BinOp::Lt(_) => ordering == Ordering::Less,
+                BinOp::Le(_) => ordering != Ordering::Greater,
+                BinOp::Ne(_) => ordering != Ordering::Equal,
+                BinOp::Ge(_) => ordering != Ordering::Less,
+                _ => ordering == Ordering::Greater,
+            };
+            return Ok(Value::Bool(result));
+        }
+
+        let (left, right) = match (left, right) {
+            (Value::Int(left), Value::Int(right)) => (left, right),
+            (Value::Bool(left), Value::Bool(right)) => {
+                return match op {
+                    BinOp::BitAnd(_) => Ok(Value::Bool(left & right)),
+                    BinOp::BitOr(_) => Ok(Value::Bool(left | right)),
+                    BinOp::BitXor(_) => Ok(Value::Bool(left ^ right)),
+                    _ => Err(cannot_apply(expr, op_str(op), Ty::Bool)),
+                };
+            }
+            _ => unreachable!(),
+        };
+        arithmetic(expr, op, left, right).map(Value::Int)
+    }
+
+    fn eval_bool(&self, expr: &Expr) -> Result<bool> {
+        match self.eval_expr(expr, Some(Ty::Bool))? {
+            Value::Bool(b) => Ok(b),
+            Value::Int(_) => unreachable!(),
+        }
+    }
+
+    fn eval_int(&self, expr: &Expr, op: &BinOp, ty: Option<Ty>) -> Result<Int> {
+        let ty = ty.unwrap_or(Ty::Int(IntType::I32));
+        match self.eval_expr(expr, Some(ty))? {
+            Value::Int(int) => Ok(int),
+            Value::Bool(_) => Err(cannot_apply(expr, op_str(op), Ty::Bool)),
+        }
+    }
+
+    // The type of an expression as far as it can be told without knowing
+    // what type the surrounding expression expects.
+    fn infer(&self, expr: &Expr) -> Option<Ty> {
+        match expr {
+            Expr::Lit(expr) => match &expr.lit {
+                Lit::Int(lit) => IntType::from_name(lit.suffix()).map(Ty::Int),
+                Lit::Byte(_) => Some(Ty::Int(IntType::U8)),
+                Lit::Bool(_) => Some(Ty::Bool),
+                _ => None,
+            },
+            Expr::Paren(expr) => self.infer(&expr.expr),
+            #[cfg(feature = "full")]
+            Expr::Group(expr) => self.infer(&expr.expr),
+            Expr::Unary(expr) => self.infer(&expr.expr),
+            Expr::Cast(expr) => IntType::from_type(&expr.ty).map(Ty::Int),
+            Expr::Path(expr) => {
+                let ident = expr.path.get_ident()?;
+                let constant = self.consts.get(&ident.to_string())?;
+                const_type(constant.ty)
+            }
+            Expr::Binary(expr) => match expr.op {
+                BinOp::Eq(_)
+                | BinOp::Lt(_)
+                | BinOp::Le(_)
+                | BinOp::Ne(_)
+                | BinOp::Ge(_)
+                | BinOp::Gt(_)
+                | BinOp::And(_)
+                | BinOp::Or(_) => Some(Ty::Bool),
+                BinOp::Shl(_) | BinOp::Shr(_) => self.infer(&expr.left),
+                _ => self.infer(&expr.left).or_else(|| self.infer(&expr.right)),
+            },
+            _ => None,
+        }
+    }
+}
+
+impl Default for Evaluator<'_> {
+    fn default() -> Self {
+        Evaluator::new()
+    }
+}
+
+fn const_type(ty: &Type) -> Option<Ty> {
+    if let Some(ty) = IntType::from_type(ty) {
+        return Some(Ty::Int(ty));
+    }
+    match ty {
+        Type::Path(ty) if ty.qself.is_none() && ty.path.is_ident("bool") => Some(Ty::Bool),
+        _ => None,
+    }
+}
+
+fn eval_lit(lit: &Lit, expected: Option<Ty>, negative: bool) -> Result<Value> {
+    let lit = match lit {
+        Lit::Int(lit) => lit,
+        Lit::Byte(lit) => {
+            return Ok(Value::Int(Int {
+                ty: IntType::U8,
+                bits: u128::from(lit.value()),
+            }));
+        }
+        Lit::Bool(lit) => return Ok(Value::Bool(lit.value)),
+        _ => {
+            return Err(Error::new_spanned(
+                lit,
+                "only integer, byte and `bool` literals can be evaluated",
+            ));
+        }
+    };
+    let ty = match IntType::from_name(lit.suffix()) {
+        Some(ty) => ty,
+        None if !lit.suffix().is_empty() => {
+            return Err(Error::new(
+                lit.span(),
+                format!("`{}` literals cannot be evaluated", lit.suffix()),
+            ));
+        }
+        None => match expected {
+            Some(Ty::Int(ty)) => ty,
+            _ => IntType::I32,
+        },
+    };
+    let out_of_range = || Error::new(lit.span(), format!("literal out of range for `{}`", ty));
+    let value = lit.base10_parse::<u128>().map_err(|_| out_of_range())?;
+    let int = if negative {
+        if !ty.is_signed() {
+            return Err(cannot_apply(lit, "-", Ty::Int(ty)));
+        }
+        if value > ty.max() + 1 {
+            None
+        } else {
+            Int::from_i128(ty, (value as i128).wrapping_neg())
+        }
+    } else {
+        Int::from_u128(ty, value)
+    };
+    int.map(Value::Int).ok_or_else(out_of_range)
+}
+
+fn shift(expr: &Expr, op: &BinOp, left: Int, right: Int) -> Result<Int> {
+    let shift = match right.to_u128() {
+        Some(shift) if shift < u128::from(left.ty.bits()) => shift as u32,
+        _ => {
+            let message = match op {
+                BinOp::Shl(_) => "attempt to shift left with overflow",
+                _ => "attempt to shift right with overflow",
+            };
+            return Err(Error::new_spanned(expr, message));
+        }
+    };
+    let int = match op {
+        BinOp::Shl(_) => Int {
+            ty: left.ty,
+            bits: left.bits << shift,
+        }
+        .cast(left.ty),
+        _ if left.ty.is_signed() => Int {
+            ty: left.ty,
+            bits: ((left.bits as i128) >> shift) as u128,
+        },
+        _ => Int {
+            ty: left.ty,
+            bits: left.bits >> shift,
+        },
+    };
+    Ok(int)
+}
+
+fn arithmetic(expr: &Expr, op: &BinOp, left: Int, right: Int) -> Result<Int> {
+    let (int, overflow) = match op {
+        BinOp::Add(_) => (
+            left.checked(right, i128::checked_add, u128::checked_add),
+            "attempt to add with overflow",
+        ),
+        BinOp::Sub(_) => (
+            left.checked(right, i128::checked_sub, u128::checked_sub),
+            "attempt to subtract with overflow",
+        ),
+        BinOp::Mul(_) => (
+            left.checked(right, i128::checked_mul, u128::checked_mul),
+            "attempt to multiply with overflow",
+        ),
+        BinOp::Div(_) | BinOp::Rem(_) if right.bits == 0 => {
+            let message = match op {
+                BinOp::Div(_) => "attempt to divide by zero",
+                _ => "attempt to calculate the remainder with a divisor of zero",
+            };
+            return Err(Error::new_spanned(expr, message));
+        }
+        BinOp::Div(_) => (
+            left.checked(right, i128::checked_div, u128::checked_div),
+            "attempt to divide with overflow",
+        ),
+        BinOp::Rem(_) => (
+            left.checked(right, i128::checked_rem, u128::checked_rem),
+            "attempt to calculate the remainder with overflow",
+        ),
+        BinOp::BitAnd(_) => (
+            Some(Int {
+                ty: left.ty,
+                bits: left.bits & right.bits,
+            }),
+            "",
+        ),
+        BinOp::BitOr(_) => (
+            Some(Int {
+                ty: left.ty,
+                bits: left.bits | right.bits,
+            }),
+            "",
+        ),
+        BinOp::BitXor(_) => (
+            Some(Int {
+                ty: left.ty,
+                bits: left.bits ^ right.bits,
+            }),
+            "",
+        ),
+        _ => {
+            return Err(Error::new_spanned(
+                op,
+                "compound assignment cannot be evaluated at compile time",
+            ));
+        }
+    };
+    match int {
+        Some(int) => Ok(int),
+        None => Err(Error::new_spanned(expr, overflow)),
+    }
+}
+
+fn cannot_apply<T: ToTokens>(tokens: T, op: &str, ty: Ty) -> Error {
+    Error::new_spanned(
+        tokens,
+        format!("cannot apply operator `{}` to type `{}`", op, ty),
+    )
+}
+
+fn op_str(op: &BinOp) -> &'static str {
+    match op {
+        BinOp::Add(_) => "+",
+        BinOp::Sub(_) => "-",
+        BinOp::Mul(_) => "*",
+        BinOp::Div(_) => "/",
+        BinOp::Rem(_) => "%",
+        BinOp::BitXor(_) => "^",
+        BinOp::BitAnd(_) => "&",
+        BinOp::BitOr(_) => "|",
+        BinOp::Shl(_) => "<<",
+        BinOp::Shr(_) => ">>",
+        _ => "",
+    }
+}
@@ -223,6 +223,8 @@ ast_enum_of_structs! {
         #[doc(hidden)]
         __Nonexhaustive,
     }
+
+    do_not_generate_to_tokens
 }
 
 ast_struct! {
@@ -1183,6 +1185,7 @@ pub(crate) mod parsing {
 
     use crate::parse::{Parse, ParseStream, Result};
     use crate::path;
+    use crate::precedence::Precedence;
 
     // When we're parsing expressions which occur before blocks, like in an if
     // statement's condition, we cannot parse a struct literal.
@@ -1192,60 +1195,96 @@ pub(crate) mod parsing {
     #[derive(Copy, Clone)]
     pub struct AllowStruct(bool);
 
-    #[derive(Copy, Clone, PartialEq, PartialOrd)]
-    enum Precedence {
-        Any,
-        Assign,
-        Range,
-        Or,
-        And,
-        Compare,
-        BitOr,
-        BitXor,
-        BitAnd,
-        Shift,
-        Arithmetic,
-        Term,
-        Cast,
-    }
-
-    impl Precedence {
-        fn of(op: &BinOp) -> Self {
-            match *op {
-                BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
-                BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
-                BinOp::And(_) => Precedence::And,
-                BinOp::Or(_) => Precedence::Or,
-                BinOp::BitXor(_) => Precedence::BitXor,
-                BinOp::BitAnd(_) => Precedence::BitAnd,
-                BinOp::BitOr(_) => Precedence::BitOr,
-                BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
-                BinOp::Eq(_)
-                | BinOp::Lt(_)
-                | BinOp::Le(_)
-                | BinOp::Ne(_)
-                | BinOp::Ge(_)
-                | BinOp::Gt(_) => Precedence::Compare,
-                BinOp::AddEq(_)
-                | BinOp::SubEq(_)
-                | BinOp::MulEq(_)
-                | BinOp::DivEq(_)
-                | BinOp::RemEq(_)
-                | BinOp::BitXorEq(_)
-                | BinOp::BitAndEq(_)
-                | BinOp::BitOrEq(_)
-                | BinOp::ShlEq(_)
-                | BinOp::ShrEq(_) => Precedence::Assign,
-            }
-        }
-    }
-
     impl Parse for Expr {
         fn parse(input: ParseStream) -> Result<Self> {
             ambiguous_expr(input, AllowStruct(true))
         }
     }
 
+    #[cfg(feature = "full")]
+    impl Expr {
+        /// An alternative to the primary `Expr::parse` parser (from the
+        /// [`Parse`] trait) for positions in which a trailing brace must not
+        /// be taken as part of the expression.
+        ///
+        /// Rust grammar is ambiguous about whether a brace after a path
+        /// starts a struct literal. In the head of an `if`, `while`, `match`
+        /// or `for` the brace always belongs to the body instead, so `if S {}`
+        /// is a condition `S` followed by an empty block rather than a
+        /// condition `S {}` missing its block. This parser works the same way:
+        /// it does not parse struct literals, except inside parentheses,
+        /// brackets or braces, and stops before the brace. Operators around
+        /// the expression have the same precedence as in any other position.
+        ///
+        /// *This function is available if Syn is built with the `"full"` and
+        /// `"parsing"` features.*
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use syn::{Block, Expr, Result, Token};
+        /// use syn::parse::{Parse, ParseStream};
+        ///
+        /// // Parse the input of a macro invoked as `unless!(cond { ... })`.
+        /// struct Unless {
+        ///     cond: Expr,
+        ///     body: Block,
+        /// }
+        ///
+        /// impl Parse for Unless {
+        ///     fn parse(input: ParseStream) -> Result<Self> {
+        ///         Ok(Unless {
+        ///             cond: input.call(Expr::parse_without_eager_brace)?,
+        ///             body: input.parse()?,
+        ///         })
+        ///     }
+        /// }
+        ///
+        /// let unless: Unless = syn::parse_str("self.done || S == s { return; }").unwrap();
+        /// assert_eq!(unless.body.stmts.len(), 1);
+        /// ```
+        pub fn parse_without_eager_brace(input: ParseStream) -> Result<Expr> {
+            expr_no_struct(input)
+        }
+
+        /// Parses an expression the way it is parsed as a statement of a
+        /// block.
+        ///
+        /// An expression statement that begins with a block-like expression,
+        /// such as `if`, `match`, `loop` or a block, ends at the closing brace
+        /// of that expression unless a method call, field access or `?`
+        /// follows it. In `match x {} - 1` the `- 1` is therefore a separate
+        /// expression and not a subtraction, which is how the compiler reads
+        /// it inside of a function body. Other expressions are parsed as by
+        /// `Expr::parse`.
+        ///
+        /// *This function is available if Syn is built with the `"full"` and
+        /// `"parsing"` features.*
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use syn::{Expr, Result};
+        /// use syn::parse::{ParseStream, Parser};
+        ///
+        /// // Parse a sequence of expressions that are not separated by
+        /// // semicolons, like the statements of `{ if a { b } *c }`.
+        /// fn exprs(input: ParseStream) -> Result<Vec<Expr>> {
+        ///     let mut exprs = Vec::new();
+        ///     while !input.is_empty() {
+        ///         exprs.push(input.call(Expr::parse_as_statement)?);
+        ///     }
+        ///     Ok(exprs)
+        /// }
+        ///
+        /// let exprs = exprs.parse_str("if a { b } *c").unwrap();
+        /// assert_eq!(exprs.len(), 2);
+        /// ```
+        pub fn parse_as_statement(input: ParseStream) -> Result<Expr> {
+            expr_early(input)
+        }
+    }
+
     #[cfg(feature = "full")]
     fn expr_no_struct(input: ParseStream) -> Result<Expr> {
         ambiguous_expr(input, AllowStruct(false))
@@ -1263,10 +1302,10 @@ pub(crate) mod parsing {
                 .fork()
                 .parse::<BinOp>()
                 .ok()
-                .map_or(false, |op| Precedence::of(&op) >= base)
+                .map_or(false, |op| Precedence::of_binop(&op) >= base)
             {
                 let op: BinOp = input.parse()?;
-                let precedence = Precedence::of(&op);
+                let precedence = Precedence::of_binop(&op);
                 let mut rhs = unary_expr(input, allow_struct)?;
                 loop {
                     let next = peek_precedence(input);
@@ -1375,10 +1414,10 @@ pub(crate) mod parsing {
                 .fork()
                 .parse::<BinOp>()
                 .ok()
-                .map_or(false, |op| Precedence::of(&op) >= base)
+                .map_or(false, |op| Precedence::of_binop(&op) >= base)
             {
                 let op: BinOp = input.parse()?;
-                let precedence = Precedence::of(&op);
+                let precedence = Precedence::of_binop(&op);
                 let mut rhs = unary_expr(input, allow_struct)?;
                 loop {
                     let next = peek_precedence(input);
@@ -1412,7 +1451,7 @@ pub(crate) mod parsing {
 
     fn peek_precedence(input: ParseStream) -> Precedence {
         if let Ok(op) = input.fork().parse() {
-            Precedence::of(&op)
+            Precedence::of_binop(&op)
         } else if input.peek(Token![=]) && !input.peek(Token![=>]) {
             Precedence::Assign
         } else if input.peek(Token![..]) {
@@ -1436,6 +1475,12 @@ pub(crate) mod parsing {
     // box <trailer>
     #[cfg(feature = "full")]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
+        // Peeking a prefix operator would look inside of invisible delimiters
+        // and take the group apart.
+        if input.peek(token::Group) {
+            return trailer_expr(input, allow_struct);
+        }
+
         // TODO: optimize using advance_to
         let ahead = input.fork();
         ahead.call(Attribute::parse_outer)?;
@@ -1474,6 +1519,12 @@ pub(crate) mod parsing {
 
     #[cfg(not(feature = "full"))]
     fn unary_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
+        // Peeking a prefix operator would look inside of invisible delimiters
+        // and take the group apart.
+        if input.peek(token::Group) {
+            return trailer_expr(input, allow_struct);
+        }
+
         // TODO: optimize using advance_to
         let ahead = input.fork();
         ahead.call(Attribute::parse_outer)?;
@@ -1497,7 +1548,8 @@ pub(crate) mod parsing {
     #[cfg(feature = "full")]
     fn trailer_expr(input: ParseStream, allow_struct: AllowStruct) -> Result<Expr> {
         if input.peek(token::Group) {
-            return input.call(expr_group).map(Expr::Group);
+            let group = input.call(expr_group).map(Expr::Group)?;
+            return trailer_helper(input, group);
         }
 
         let outer_attrs = input.call(Attribute::parse_outer)?;
@@ -1713,7 +1765,7 @@ pub(crate) mod parsing {
             } else if input.peek(token::Brace) {
                 Expr::Block(input.call(expr_block)?)
             } else {
-                return Err(input.error("expected loop or block expression"));
+                return Err(input.error_expected(&["loop or block expression"]));
             };
             match &mut expr {
                 Expr::While(ExprWhile { label, .. })
@@ -1724,13 +1776,15 @@ pub(crate) mod parsing {
             }
             Ok(expr)
         } else {
-            Err(input.error("expected expression"))
+            Err(input.error_expected(&["expression"]))
         }
     }
 
     #[cfg(not(feature = "full"))]
     fn atom_expr(input: ParseStream, _allow_struct: AllowStruct) -> Result<Expr> {
-        if input.peek(Lit) {
+        if input.peek(token::Group) {
+            input.call(expr_group)
+        } else if input.peek(Lit) {
             input.parse().map(Expr::Lit)
         } else if input.peek(token::Paren) {
             input.call(expr_paren).map(Expr::Paren)
@@ -1872,7 +1926,7 @@ pub(crate) mod parsing {
                 len: Box::new(len),
             }))
         } else {
-            Err(content.error("expected `,` or `;`"))
+            Err(content.error_expected(&["`,`", "`;`"]))
         }
     }
 
@@ -1939,6 +1993,15 @@ pub(crate) mod parsing {
         })
     }
 
+    // Without the "full" feature there is no `Expr::Group`. The invisible
+    // delimiters still keep the expression inside them together as one
+    // operand, which is all that the syntax tree needs to record.
+    #[cfg(not(feature = "full"))]
+    fn expr_group(input: ParseStream) -> Result<Expr> {
+        let group = crate::group::parse_group(input)?;
+        group.content.parse()
+    }
+
     #[cfg(not(feature = "full"))]
     fn expr_paren(input: ParseStream) -> Result<ExprParen> {
         let content;
@@ -1960,32 +2023,7 @@ pub(crate) mod parsing {
         Ok(ExprLet {
             attrs: Vec::new(),
             let_token: input.parse()?,
-            pat: {
-                let leading_vert: Option<Token![|]> = input.parse()?;
-                let pat: Pat = input.parse()?;
-                if leading_vert.is_some()
-                    || input.peek(Token![|]) && !input.peek(Token![||]) && !input.peek(Token![|=])
-                {
-                    let mut cases = Punctuated::new();
-                    cases.push_value(pat);
-                    while input.peek(Token![|])
-                        && !input.peek(Token![||])
-                        && !input.peek(Token![|=])
-                    {
-                        let punct = input.parse()?;
-                        cases.push_punct(punct);
-                        let pat: Pat = input.parse()?;
-                        cases.push_value(pat);
-                    }
-                    Pat::Or(PatOr {
-                        attrs: Vec::new(),
-                        leading_vert,
-                        cases,
-                    })
-                } else {
-                    pat
-                }
-            },
+            pat: Pat::parse_multi_with_leading_vert(input)?,
             eq_token: input.parse()?,
             expr: Box::new(input.call(expr_no_struct)?),
         })
@@ -2036,23 +2074,7 @@ pub(crate) mod parsing {
             let label: Option<Label> = input.parse()?;
             let for_token: Token![for] = input.parse()?;
 
-            let leading_vert: Option<Token![|]> = input.parse()?;
-            let mut pat: Pat = input.parse()?;
-            if leading_vert.is_some() || input.peek(Token![|]) {
-                let mut cases = Punctuated::new();
-                cases.push_value(pat);
-                while input.peek(Token![|]) {
-                    let punct = input.parse()?;
-                    cases.push_punct(punct);
-                    let pat: Pat = input.parse()?;
-                    cases.push_value(pat);
-                }
-                pat = Pat::Or(PatOr {
-                    attrs: Vec::new(),
-                    leading_vert,
-                    cases,
-                });
-            }
+            let pat = Pat::parse_multi_with_leading_vert(input)?;
 
             let in_token: Token![in] = input.parse()?;
             let expr: Expr = input.call(expr_no_struct)?;
@@ -2269,7 +2291,7 @@ pub(crate) mod parsing {
     #[cfg(feature = "full")]
     fn closure_arg(input: ParseStream) -> Result<Pat> {
         let attrs = input.call(Attribute::parse_outer)?;
-        let mut pat: Pat = input.parse()?;
+        let mut pat = Pat::parse_single(input)?;
 
         if input.peek(Token![:]) {
             Ok(Pat::Type(PatType {
@@ -2442,7 +2464,7 @@ pub(crate) mod parsing {
                 if attrs.is_empty() {
                     break;
                 } else {
-                    return Err(content.error("expected struct field"));
+                    return Err(content.error_expected(&["struct field"]));
                 }
             }
 
@@ -2566,7 +2588,7 @@ pub(crate) mod parsing {
             } else if input.peek(LitInt) {
                 input.parse().map(Member::Unnamed)
             } else {
-                Err(input.error("expected identifier or integer"))
+                Err(input.error_expected(&["identifier", "integer"]))
             }
         }
     }
@@ -2577,27 +2599,7 @@ pub(crate) mod parsing {
             let requires_comma;
             Ok(Arm {
                 attrs: input.call(Attribute::parse_outer)?,
-                pat: {
-                    let leading_vert: Option<Token![|]> = input.parse()?;
-                    let pat: Pat = input.parse()?;
-                    if leading_vert.is_some() || input.peek(Token![|]) {
-                        let mut cases = Punctuated::new();
-                        cases.push_value(pat);
-                        while input.peek(Token![|]) {
-                            let punct = input.parse()?;
-                            cases.push_punct(punct);
-                            let pat: Pat = input.parse()?;
-                            cases.push_value(pat);
-                        }
-                        Pat::Or(PatOr {
-                            attrs: Vec::new(),
-                            leading_vert,
-                            cases,
-                        })
-                    } else {
-                        pat
-                    }
-                },
+                pat: Pat::parse_multi_with_leading_vert(input)?,
                 guard: {
                     if input.peek(Token![if]) {
                         let if_token: Token![if] = input.parse()?;
@@ -2656,14 +2658,93 @@ pub(crate) mod parsing {
 pub(crate) mod printing {
     use super::*;
 
-    use proc_macro2::{Literal, TokenStream};
+    use proc_macro2::{Delimiter, Group, Literal, Span, TokenStream};
     use quote::{ToTokens, TokenStreamExt};
 
     #[cfg(feature = "full")]
     use crate::attr::FilterAttrs;
+    use crate::precedence::{self, Precedence};
     #[cfg(feature = "full")]
     use crate::print::TokensOrDefault;
 
+    // An expression made of operators is printed inside of invisible
+    // delimiters, so that it stays a single operand when it is interpolated
+    // next to other tokens, as in `quote!(#sum * c)`. The parser reads those
+    // delimiters back as an `Expr::Group`. Operands that an expression prints
+    // itself are parenthesized where needed instead.
+    impl ToTokens for Expr {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            if !precedence::needs_group(self) {
+                print_expr(self, tokens);
+                return;
+            }
+            let mut inner = TokenStream::new();
+            print_expr(self, &mut inner);
+            let mut group = Group::new(Delimiter::None, inner);
+            group.set_span(join_spans(group.stream()));
+            tokens.append(group);
+        }
+    }
+
+    fn print_expr(expr: &Expr, tokens: &mut TokenStream) {
+        match expr {
+            Expr::Array(e) => e.to_tokens(tokens),
+            Expr::Assign(e) => e.to_tokens(tokens),
+            Expr::AssignOp(e) => e.to_tokens(tokens),
+            Expr::Async(e) => e.to_tokens(tokens),
+            Expr::Await(e) => e.to_tokens(tokens),
+            Expr::Binary(e) => e.to_tokens(tokens),
+            Expr::Block(e) => e.to_tokens(tokens),
+            Expr::Box(e) => e.to_tokens(tokens),
+            Expr::Break(e) => e.to_tokens(tokens),
+            Expr::Call(e) => e.to_tokens(tokens),
+            Expr::Cast(e) => e.to_tokens(tokens),
+            Expr::Closure(e) => e.to_tokens(tokens),
+            Expr::Continue(e) => e.to_tokens(tokens),
+            Expr::Field(e) => e.to_tokens(tokens),
+            Expr::ForLoop(e) => e.to_tokens(tokens),
+            Expr::Group(e) => e.to_tokens(tokens),
+            Expr::If(e) => e.to_tokens(tokens),
+            Expr::Index(e) => e.to_tokens(tokens),
+            Expr::Let(e) => e.to_tokens(tokens),
+            Expr::Lit(e) => e.to_tokens(tokens),
+            Expr::Loop(e) => e.to_tokens(tokens),
+            Expr::Macro(e) => e.to_tokens(tokens),
+            Expr::Match(e) => e.to_tokens(tokens),
+            Expr::MethodCall(e) => e.to_tokens(tokens),
+            Expr::Paren(e) => e.to_tokens(tokens),
+            Expr::Path(e) => e.to_tokens(tokens),
+            Expr::Range(e) => e.to_tokens(tokens),
+            Expr::Reference(e) => e.to_tokens(tokens),
+            Expr::Repeat(e) => e.to_tokens(tokens),
+            Expr::Return(e) => e.to_tokens(tokens),
+            Expr::Struct(e) => e.to_tokens(tokens),
+            Expr::Try(e) => e.to_tokens(tokens),
+            Expr::TryBlock(e) => e.to_tokens(tokens),
+            Expr::Tuple(e) => e.to_tokens(tokens),
+            Expr::Type(e) => e.to_tokens(tokens),
+            Expr::Unary(e) => e.to_tokens(tokens),
+            Expr::Unsafe(e) => e.to_tokens(tokens),
+            Expr::Verbatim(e) => e.to_tokens(tokens),
+            Expr::While(e) => e.to_tokens(tokens),
+            Expr::Yield(e) => e.to_tokens(tokens),
+            Expr::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    // The span of the group, which `Spanned` reports for the expression.
+    fn join_spans(tokens: TokenStream) -> Span {
+        let mut spans = tokens.into_iter().map(|tt| tt.span());
+        let first = match spans.next() {
+            Some(first) => first,
+            None => return Span::call_site(),
+        };
+        spans
+            .last()
+            .and_then(|last| first.join(last))
+            .unwrap_or(first)
+    }
+
     // If the given expression is a bare `ExprStruct`, wraps it in parenthesis
     // before appending it to `TokenStream`.
     #[cfg(feature = "full")]
@@ -2677,6 +2758,18 @@ pub(crate) mod printing {
         }
     }
 
+    // Appends an operand of an operator to `TokenStream`, wrapping it in
+    // parentheses if its precedence is too low to appear there bare.
+    fn print_subexpression(expr: &Expr, needs_parens: bool, tokens: &mut TokenStream) {
+        if needs_parens {
+            token::Paren::default().surround(tokens, |tokens| {
+                print_expr(expr, tokens);
+            });
+        } else {
+            print_expr(expr, tokens);
+        }
+    }
+
     #[cfg(feature = "full")]
     pub(crate) fn outer_attrs_to_tokens(attrs: &[Attribute], tokens: &mut TokenStream) {
         tokens.append_all(attrs.outer());
@@ -2698,7 +2791,8 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.box_token.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = precedence::prefix_needs_parens(&self.expr);
+            print_subexpression(&self.expr, needs_parens, tokens);
         }
     }
 
@@ -2716,7 +2810,13 @@ pub(crate) mod printing {
     impl ToTokens for ExprCall {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.func.to_tokens(tokens);
+            // A call of a field like `(self.f)()` would otherwise print as a
+            // method call.
+            let needs_parens = match *self.func {
+                Expr::Field(_) => true,
+                _ => Precedence::of(&self.func) < Precedence::Postfix,
+            };
+            print_subexpression(&self.func, needs_parens, tokens);
             self.paren_token.surround(tokens, |tokens| {
                 self.args.to_tokens(tokens);
             })
@@ -2727,7 +2827,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprMethodCall {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.receiver.to_tokens(tokens);
+            let needs_parens = Precedence::of(&self.receiver) < Precedence::Postfix;
+            print_subexpression(&self.receiver, needs_parens, tokens);
             self.dot_token.to_tokens(tokens);
             self.method.to_tokens(tokens);
             self.turbofish.to_tokens(tokens);
@@ -2776,9 +2877,12 @@ pub(crate) mod printing {
     impl ToTokens for ExprBinary {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            let needs_parens = precedence::binary_left_needs_parens(&self.op, &self.left);
+            print_subexpression(&self.left, needs_parens, tokens);
             self.op.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            let precedence = Precedence::of_binop(&self.op);
+            let needs_parens = precedence::right_needs_parens(precedence, &self.right);
+            print_subexpression(&self.right, needs_parens, tokens);
         }
     }
 
@@ -2786,7 +2890,8 @@ pub(crate) mod printing {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.op.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = precedence::prefix_needs_parens(&self.expr);
+            print_subexpression(&self.expr, needs_parens, tokens);
         }
     }
 
@@ -2800,7 +2905,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprCast {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = precedence::left_needs_parens(Precedence::Cast, &self.expr, false);
+            print_subexpression(&self.expr, needs_parens, tokens);
             self.as_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
@@ -2810,7 +2916,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprType {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = precedence::left_needs_parens(Precedence::Cast, &self.expr, false);
+            print_subexpression(&self.expr, needs_parens, tokens);
             self.colon_token.to_tokens(tokens);
             self.ty.to_tokens(tokens);
         }
@@ -2936,7 +3043,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprAwait {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.base.to_tokens(tokens);
+            let needs_parens = Precedence::of(&self.base) < Precedence::Postfix;
+            print_subexpression(&self.base, needs_parens, tokens);
             self.dot_token.to_tokens(tokens);
             self.await_token.to_tokens(tokens);
         }
@@ -3003,9 +3111,11 @@ pub(crate) mod printing {
     impl ToTokens for ExprAssign {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            let needs_parens = precedence::left_needs_parens(Precedence::Assign, &self.left, false);
+            print_subexpression(&self.left, needs_parens, tokens);
             self.eq_token.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            let needs_parens = precedence::right_needs_parens(Precedence::Assign, &self.right);
+            print_subexpression(&self.right, needs_parens, tokens);
         }
     }
 
@@ -3013,16 +3123,19 @@ pub(crate) mod printing {
     impl ToTokens for ExprAssignOp {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.left.to_tokens(tokens);
+            let needs_parens = precedence::left_needs_parens(Precedence::Assign, &self.left, false);
+            print_subexpression(&self.left, needs_parens, tokens);
             self.op.to_tokens(tokens);
-            self.right.to_tokens(tokens);
+            let needs_parens = precedence::right_needs_parens(Precedence::Assign, &self.right);
+            print_subexpression(&self.right, needs_parens, tokens);
         }
     }
 
     impl ToTokens for ExprField {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.base.to_tokens(tokens);
+            let needs_parens = Precedence::of(&self.base) < Precedence::Postfix;
+            print_subexpression(&self.base, needs_parens, tokens);
             self.dot_token.to_tokens(tokens);
             self.member.to_tokens(tokens);
         }
@@ -3048,7 +3161,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprIndex {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = Precedence::of(&self.expr) < Precedence::Postfix;
+            print_subexpression(&self.expr, needs_parens, tokens);
             self.bracket_token.surround(tokens, |tokens| {
                 self.index.to_tokens(tokens);
             });
@@ -3059,12 +3173,18 @@ pub(crate) mod printing {
     impl ToTokens for ExprRange {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.from.to_tokens(tokens);
+            if let Some(from) = &self.from {
+                let needs_parens = precedence::left_needs_parens(Precedence::Range, from, false);
+                print_subexpression(from, needs_parens, tokens);
+            }
             match &self.limits {
                 RangeLimits::HalfOpen(t) => t.to_tokens(tokens),
                 RangeLimits::Closed(t) => t.to_tokens(tokens),
             }
-            self.to.to_tokens(tokens);
+            if let Some(to) = &self.to {
+                let needs_parens = precedence::right_needs_parens(Precedence::Range, to);
+                print_subexpression(to, needs_parens, tokens);
+            }
         }
     }
 
@@ -3081,7 +3201,8 @@ pub(crate) mod printing {
             outer_attrs_to_tokens(&self.attrs, tokens);
             self.and_token.to_tokens(tokens);
             self.mutability.to_tokens(tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = precedence::prefix_needs_parens(&self.expr);
+            print_subexpression(&self.expr, needs_parens, tokens);
         }
     }
 
@@ -3174,7 +3295,8 @@ pub(crate) mod printing {
     impl ToTokens for ExprTry {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             outer_attrs_to_tokens(&self.attrs, tokens);
-            self.expr.to_tokens(tokens);
+            let needs_parens = Precedence::of(&self.expr) < Precedence::Postfix;
+            print_subexpression(&self.expr, needs_parens, tokens);
             self.question_token.to_tokens(tokens);
         }
     }
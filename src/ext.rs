@@ -92,7 +92,7 @@ impl IdentExt for Ident {
     fn parse_any(input: ParseStream) -> Result<Self> {
         input.step(|cursor| match cursor.ident() {
             Some((ident, rest)) => Ok((ident, rest)),
-            None => Err(cursor.error("expected ident")),
+            None => Err(cursor.error_expected(&["ident"])),
         })
     }
 
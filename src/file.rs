@@ -78,7 +78,7 @@ ast_struct! {
 pub mod parsing {
     use super::*;
 
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Boundary, Parse, ParseStream, Result};
 
     impl Parse for File {
         fn parse(input: ParseStream) -> Result<Self> {
@@ -88,7 +88,12 @@ pub mod parsing {
                 items: {
                     let mut items = Vec::new();
                     while !input.is_empty() {
-                        items.push(input.parse()?);
+                        items.push(crate::parse::recover(
+                            input,
+                            Boundary::Item,
+                            Item::parse,
+                            Item::Verbatim,
+                        )?);
                     }
                     items
                 },
@@ -0,0 +1,198 @@
+//! Typed configuration read out of attributes.
+//!
+//! Derive macros commonly accept options through a helper attribute such as
+//! `#[mytrait(rename = "x", skip)]`. Rather than walking the [`Meta`] and
+//! [`NestedMeta`] values by hand, a macro can describe its options as a
+//! struct and implement [`FromMeta`] or [`FromAttributes`] for it, usually
+//! with the derives of the same names from the `syn-derive` crate.
+//!
+//! Each item of an attribute list is matched to the struct field named by its
+//! key. The field type decides what the item may look like:
+//!
+//! - `bool` is a flag, written `skip` or `skip = true`, and is false when the
+//!   key is absent;
+//! - `String`, `LitStr`, integers, floats, `char` and `Lit` take a literal
+//!   value, `key = "..."`;
+//! - `Path` and `Ident` take a string literal containing the path or
+//!   identifier, `with = "crate::helper"`;
+//! - a struct that implements `FromMeta` itself takes a nested list,
+//!   `key(...)`;
+//! - `Option<T>` accepts whatever `T` accepts and is `None` when the key is
+//!   absent.
+//!
+//! Errors point at the offending part of the attribute: a literal of the
+//! wrong type, an unknown or repeated key, or a list where a value was
+//! expected.
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature and with the `"parsing"`, `"printing"` and `"clone-impls"`
+//! features.*
+
+use proc_macro2::Span;
+use quote::ToTokens;
+
+use crate::spanned::Spanned;
+use crate::*;
+
+/// Conversion from one item of an attribute, such as the `rename = "x"` in
+/// `#[mytrait(rename = "x")]`.
+pub trait FromMeta: Sized {
+    /// Converts a meta item: `key`, `key = lit` or `key(...)`.
+    fn from_meta(meta: &Meta) -> Result<Self>;
+
+    /// Converts the items of a list, such as the [`AttributeArgs`] of an
+    /// attribute macro.
+    ///
+    /// The default implementation rejects lists.
+    fn from_list<'a, I>(items: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a NestedMeta>,
+    {
+        let span = match items.into_iter().next() {
+            Some(item) => item.span(),
+            None => Span::call_site(),
+        };
+        Err(Error::new(span, "expected a value, not a list"))
+    }
+
+    /// The value to use when the key is absent, or `None` if the key is
+    /// required.
+    fn from_missing() -> Option<Self> {
+        None
+    }
+}
+
+/// Conversion from every attribute of one namespace on an item, such as all
+/// of the `#[mytrait(...)]` attributes on a struct.
+pub trait FromAttributes: Sized {
+    fn from_attributes(attrs: &[Attribute]) -> Result<Self>;
+}
+
+fn lit(meta: &Meta, expected: &str) -> Result<Lit> {
+    match meta {
+        Meta::NameValue(meta) => Ok(meta.lit.clone()),
+        _ => {
+            let key = meta.path().to_token_stream();
+            Err(Error::new_spanned(
+                meta,
+                format!("expected {}: `{} = ...`", expected, key),
+            ))
+        }
+    }
+}
+
+fn lit_str(meta: &Meta) -> Result<LitStr> {
+    match lit(meta, "string literal")? {
+        Lit::Str(lit) => Ok(lit),
+        other => Err(Error::new_spanned(other, "expected string literal")),
+    }
+}
+
+impl FromMeta for bool {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match meta {
+            Meta::Path(_) => Ok(true),
+            Meta::NameValue(MetaNameValue {
+                lit: Lit::Bool(lit),
+                ..
+            }) => Ok(lit.value),
+            Meta::NameValue(meta) => Err(Error::new_spanned(&meta.lit, "expected boolean literal")),
+            Meta::List(_) => Err(Error::new_spanned(meta, "expected a flag, not a list")),
+        }
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(false)
+    }
+}
+
+impl FromMeta for String {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        lit_str(meta).map(|lit| lit.value())
+    }
+}
+
+impl FromMeta for LitStr {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        lit_str(meta)
+    }
+}
+
+impl FromMeta for Lit {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        lit(meta, "literal")
+    }
+}
+
+impl FromMeta for char {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        match lit(meta, "character literal")? {
+            Lit::Char(lit) => Ok(lit.value()),
+            other => Err(Error::new_spanned(other, "expected character literal")),
+        }
+    }
+}
+
+macro_rules! from_meta_int {
+    ($($ty:ident)*) => {
+        $(
+            impl FromMeta for $ty {
+                fn from_meta(meta: &Meta) -> Result<Self> {
+                    match lit(meta, "integer literal")? {
+                        Lit::Int(lit) => lit.base10_parse(),
+                        other => Err(Error::new_spanned(other, "expected integer literal")),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_meta_int!(u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize);
+
+macro_rules! from_meta_float {
+    ($($ty:ident)*) => {
+        $(
+            impl FromMeta for $ty {
+                fn from_meta(meta: &Meta) -> Result<Self> {
+                    match lit(meta, "float literal")? {
+                        Lit::Float(lit) => lit.base10_parse(),
+                        Lit::Int(lit) => lit.base10_parse(),
+                        other => Err(Error::new_spanned(other, "expected float literal")),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+from_meta_float!(f32 f64);
+
+impl FromMeta for Path {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        lit_str(meta)?.parse()
+    }
+}
+
+impl FromMeta for Ident {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        lit_str(meta)?.parse()
+    }
+}
+
+impl<T: FromMeta> FromMeta for Option<T> {
+    fn from_meta(meta: &Meta) -> Result<Self> {
+        T::from_meta(meta).map(Some)
+    }
+
+    fn from_list<'a, I>(items: I) -> Result<Self>
+    where
+        I: IntoIterator<Item = &'a NestedMeta>,
+    {
+        T::from_list(items).map(Some)
+    }
+
+    fn from_missing() -> Option<Self> {
+        Some(None)
+    }
+}
@@ -0,0 +1,13161 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unreachable_code, unused_variables)]
+use crate::gen::helper::serde::*;
+use crate::punctuated::Punctuated;
+use crate::token::{Brace, Bracket, Group, Paren};
+use crate::*;
+use proc_macro2::{Literal, Span, TokenStream};
+use serde::de::{self, Deserialize, Deserializer, EnumAccess, MapAccess, SeqAccess, VariantAccess};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::fmt;
+use std::result::Result;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Abi {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Abi", 2)?;
+        state.serialize_field("extern_token", &self.extern_token)?;
+        state.serialize_field("name", &self.name)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Abi {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["extern_token", "name"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Abi;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Abi")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let extern_token: Token![extern] = element(&mut seq, 0, &self)?;
+                let name: Option<LitStr> = element(&mut seq, 1, &self)?;
+                Ok(Abi { extern_token, name })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut extern_token: Option<Token![extern]> = None;
+                let mut name: Option<Option<LitStr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut extern_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut name, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let extern_token = required(extern_token, FIELDS[0])?;
+                let name = required(name, FIELDS[1])?;
+                Ok(Abi { extern_token, name })
+            }
+        }
+        deserializer.deserialize_struct("Abi", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for AngleBracketedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("AngleBracketedGenericArguments", 4)?;
+        state.serialize_field("colon2_token", &self.colon2_token)?;
+        state.serialize_field("lt_token", &self.lt_token)?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("gt_token", &self.gt_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for AngleBracketedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["colon2_token", "lt_token", "args", "gt_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AngleBracketedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct AngleBracketedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let colon2_token: Option<Token ! [::]> = element(&mut seq, 0, &self)?;
+                let lt_token: Token ! [<] = element(&mut seq, 1, &self)?;
+                let args: Punctuated<GenericArgument, Token ! [,]> = element(&mut seq, 2, &self)?;
+                let gt_token: Token ! [>] = element(&mut seq, 3, &self)?;
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token,
+                    lt_token,
+                    args,
+                    gt_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut colon2_token: Option<Option<Token ! [::]>> = None;
+                let mut lt_token: Option<Token ! [<]> = None;
+                let mut args: Option<Punctuated<GenericArgument, Token ! [,]>> = None;
+                let mut gt_token: Option<Token ! [>]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut colon2_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut lt_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut args, FIELDS[2])?,
+                        3 => field(&mut map, &mut gt_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let colon2_token = required(colon2_token, FIELDS[0])?;
+                let lt_token = required(lt_token, FIELDS[1])?;
+                let args = required(args, FIELDS[2])?;
+                let gt_token = required(gt_token, FIELDS[3])?;
+                Ok(AngleBracketedGenericArguments {
+                    colon2_token,
+                    lt_token,
+                    args,
+                    gt_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("AngleBracketedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Arm {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Arm", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("guard", &self.guard)?;
+        state.serialize_field("fat_arrow_token", &self.fat_arrow_token)?;
+        state.serialize_field("body", &self.body)?;
+        state.serialize_field("comma", &self.comma)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Arm {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "guard", "fat_arrow_token", "body", "comma"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Arm;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Arm")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let pat: Pat = element(&mut seq, 1, &self)?;
+                let guard: Option<(Token![if], Box<Expr>)> = element(&mut seq, 2, &self)?;
+                let fat_arrow_token: Token ! [=>] = element(&mut seq, 3, &self)?;
+                let body: Box<Expr> = element(&mut seq, 4, &self)?;
+                let comma: Option<Token ! [,]> = element(&mut seq, 5, &self)?;
+                Ok(Arm {
+                    attrs,
+                    pat,
+                    guard,
+                    fat_arrow_token,
+                    body,
+                    comma,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut pat: Option<Pat> = None;
+                let mut guard: Option<Option<(Token![if], Box<Expr>)>> = None;
+                let mut fat_arrow_token: Option<Token ! [=>]> = None;
+                let mut body: Option<Box<Expr>> = None;
+                let mut comma: Option<Option<Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut pat, FIELDS[1])?,
+                        2 => field(&mut map, &mut guard, FIELDS[2])?,
+                        3 => field(&mut map, &mut fat_arrow_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut body, FIELDS[4])?,
+                        5 => field(&mut map, &mut comma, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let pat = required(pat, FIELDS[1])?;
+                let guard = required(guard, FIELDS[2])?;
+                let fat_arrow_token = required(fat_arrow_token, FIELDS[3])?;
+                let body = required(body, FIELDS[4])?;
+                let comma = required(comma, FIELDS[5])?;
+                Ok(Arm {
+                    attrs,
+                    pat,
+                    guard,
+                    fat_arrow_token,
+                    body,
+                    comma,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Arm", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for AttrStyle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            AttrStyle::Outer => serializer.serialize_unit_variant("AttrStyle", 0, "Outer"),
+            AttrStyle::Inner(_binding_0) => {
+                serializer.serialize_newtype_variant("AttrStyle", 1, "Inner", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for AttrStyle {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Outer", "Inner"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = AttrStyle;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum AttrStyle")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.unit_variant().map(|()| AttrStyle::Outer),
+                    1 => variant.newtype_variant().map(AttrStyle::Inner),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("AttrStyle", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Attribute {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Attribute", 5)?;
+        state.serialize_field("pound_token", &self.pound_token)?;
+        state.serialize_field("style", &self.style)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("tokens", &Ser(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Attribute {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["pound_token", "style", "bracket_token", "path", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Attribute;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Attribute")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let pound_token: Token ! [#] = element(&mut seq, 0, &self)?;
+                let style: AttrStyle = element(&mut seq, 1, &self)?;
+                let bracket_token: Bracket = element(&mut seq, 2, &self)?;
+                let path: Path = element(&mut seq, 3, &self)?;
+                let tokens: De<TokenStream> = element(&mut seq, 4, &self)?;
+                Ok(Attribute {
+                    pound_token,
+                    style,
+                    bracket_token,
+                    path,
+                    tokens: tokens.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut pound_token: Option<Token ! [#]> = None;
+                let mut style: Option<AttrStyle> = None;
+                let mut bracket_token: Option<Bracket> = None;
+                let mut path: Option<Path> = None;
+                let mut tokens: Option<De<TokenStream>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut pound_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut style, FIELDS[1])?,
+                        2 => field(&mut map, &mut bracket_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut path, FIELDS[3])?,
+                        4 => field(&mut map, &mut tokens, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let pound_token = required(pound_token, FIELDS[0])?;
+                let style = required(style, FIELDS[1])?;
+                let bracket_token = required(bracket_token, FIELDS[2])?;
+                let path = required(path, FIELDS[3])?;
+                let tokens = required(tokens, FIELDS[4])?;
+                Ok(Attribute {
+                    pound_token,
+                    style,
+                    bracket_token,
+                    path,
+                    tokens: tokens.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Attribute", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BareFnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BareFnArg", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("name", &self.name.as_ref().map(|it| (Ser(&it.0), &it.1)))?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BareFnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "name", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BareFnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BareFnArg")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let name: Option<(De<Ident>, Token ! [:])> = element(&mut seq, 1, &self)?;
+                let ty: Type = element(&mut seq, 2, &self)?;
+                Ok(BareFnArg {
+                    attrs,
+                    name: name.map(|it| {
+                        let (_binding_0, _binding_1) = it;
+                        (_binding_0.0, _binding_1)
+                    }),
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut name: Option<Option<(De<Ident>, Token ! [:])>> = None;
+                let mut ty: Option<Type> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut name, FIELDS[1])?,
+                        2 => field(&mut map, &mut ty, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let name = required(name, FIELDS[1])?;
+                let ty = required(ty, FIELDS[2])?;
+                Ok(BareFnArg {
+                    attrs,
+                    name: name.map(|it| {
+                        let (_binding_0, _binding_1) = it;
+                        (_binding_0.0, _binding_1)
+                    }),
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("BareFnArg", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BinOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            BinOp::Add(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 0, "Add", _binding_0)
+            }
+            BinOp::Sub(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 1, "Sub", _binding_0)
+            }
+            BinOp::Mul(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 2, "Mul", _binding_0)
+            }
+            BinOp::Div(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 3, "Div", _binding_0)
+            }
+            BinOp::Rem(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 4, "Rem", _binding_0)
+            }
+            BinOp::And(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 5, "And", _binding_0)
+            }
+            BinOp::Or(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 6, "Or", _binding_0)
+            }
+            BinOp::BitXor(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 7, "BitXor", _binding_0)
+            }
+            BinOp::BitAnd(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 8, "BitAnd", _binding_0)
+            }
+            BinOp::BitOr(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 9, "BitOr", _binding_0)
+            }
+            BinOp::Shl(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 10, "Shl", _binding_0)
+            }
+            BinOp::Shr(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 11, "Shr", _binding_0)
+            }
+            BinOp::Eq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 12, "Eq", _binding_0)
+            }
+            BinOp::Lt(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 13, "Lt", _binding_0)
+            }
+            BinOp::Le(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 14, "Le", _binding_0)
+            }
+            BinOp::Ne(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 15, "Ne", _binding_0)
+            }
+            BinOp::Ge(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 16, "Ge", _binding_0)
+            }
+            BinOp::Gt(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 17, "Gt", _binding_0)
+            }
+            BinOp::AddEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 18, "AddEq", _binding_0)
+            }
+            BinOp::SubEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 19, "SubEq", _binding_0)
+            }
+            BinOp::MulEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 20, "MulEq", _binding_0)
+            }
+            BinOp::DivEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 21, "DivEq", _binding_0)
+            }
+            BinOp::RemEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 22, "RemEq", _binding_0)
+            }
+            BinOp::BitXorEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 23, "BitXorEq", _binding_0)
+            }
+            BinOp::BitAndEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 24, "BitAndEq", _binding_0)
+            }
+            BinOp::BitOrEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 25, "BitOrEq", _binding_0)
+            }
+            BinOp::ShlEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 26, "ShlEq", _binding_0)
+            }
+            BinOp::ShrEq(_binding_0) => {
+                serializer.serialize_newtype_variant("BinOp", 27, "ShrEq", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BinOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Add", "Sub", "Mul", "Div", "Rem", "And", "Or", "BitXor", "BitAnd", "BitOr", "Shl",
+            "Shr", "Eq", "Lt", "Le", "Ne", "Ge", "Gt", "AddEq", "SubEq", "MulEq", "DivEq", "RemEq",
+            "BitXorEq", "BitAndEq", "BitOrEq", "ShlEq", "ShrEq",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BinOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum BinOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(BinOp::Add),
+                    1 => variant.newtype_variant().map(BinOp::Sub),
+                    2 => variant.newtype_variant().map(BinOp::Mul),
+                    3 => variant.newtype_variant().map(BinOp::Div),
+                    4 => variant.newtype_variant().map(BinOp::Rem),
+                    5 => variant.newtype_variant().map(BinOp::And),
+                    6 => variant.newtype_variant().map(BinOp::Or),
+                    7 => variant.newtype_variant().map(BinOp::BitXor),
+                    8 => variant.newtype_variant().map(BinOp::BitAnd),
+                    9 => variant.newtype_variant().map(BinOp::BitOr),
+                    10 => variant.newtype_variant().map(BinOp::Shl),
+                    11 => variant.newtype_variant().map(BinOp::Shr),
+                    12 => variant.newtype_variant().map(BinOp::Eq),
+                    13 => variant.newtype_variant().map(BinOp::Lt),
+                    14 => variant.newtype_variant().map(BinOp::Le),
+                    15 => variant.newtype_variant().map(BinOp::Ne),
+                    16 => variant.newtype_variant().map(BinOp::Ge),
+                    17 => variant.newtype_variant().map(BinOp::Gt),
+                    18 => variant.newtype_variant().map(BinOp::AddEq),
+                    19 => variant.newtype_variant().map(BinOp::SubEq),
+                    20 => variant.newtype_variant().map(BinOp::MulEq),
+                    21 => variant.newtype_variant().map(BinOp::DivEq),
+                    22 => variant.newtype_variant().map(BinOp::RemEq),
+                    23 => variant.newtype_variant().map(BinOp::BitXorEq),
+                    24 => variant.newtype_variant().map(BinOp::BitAndEq),
+                    25 => variant.newtype_variant().map(BinOp::BitOrEq),
+                    26 => variant.newtype_variant().map(BinOp::ShlEq),
+                    27 => variant.newtype_variant().map(BinOp::ShrEq),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("BinOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Binding {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Binding", 3)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Binding {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "eq_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Binding;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Binding")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 1, &self)?;
+                let ty: Type = element(&mut seq, 2, &self)?;
+                Ok(Binding {
+                    ident: ident.0,
+                    eq_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut ty: Option<Type> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        1 => field(&mut map, &mut eq_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut ty, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                let eq_token = required(eq_token, FIELDS[1])?;
+                let ty = required(ty, FIELDS[2])?;
+                Ok(Binding {
+                    ident: ident.0,
+                    eq_token,
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Binding", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Block {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Block", 2)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("stmts", &self.stmts)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Block {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["brace_token", "stmts"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Block;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Block")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let brace_token: Brace = element(&mut seq, 0, &self)?;
+                let stmts: Vec<Stmt> = element(&mut seq, 1, &self)?;
+                Ok(Block { brace_token, stmts })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut brace_token: Option<Brace> = None;
+                let mut stmts: Option<Vec<Stmt>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut brace_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut stmts, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let brace_token = required(brace_token, FIELDS[0])?;
+                let stmts = required(stmts, FIELDS[1])?;
+                Ok(Block { brace_token, stmts })
+            }
+        }
+        deserializer.deserialize_struct("Block", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for BoundLifetimes {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("BoundLifetimes", 4)?;
+        state.serialize_field("for_token", &self.for_token)?;
+        state.serialize_field("lt_token", &self.lt_token)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("gt_token", &self.gt_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for BoundLifetimes {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["for_token", "lt_token", "lifetimes", "gt_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = BoundLifetimes;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct BoundLifetimes")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let for_token: Token![for] = element(&mut seq, 0, &self)?;
+                let lt_token: Token ! [<] = element(&mut seq, 1, &self)?;
+                let lifetimes: Punctuated<LifetimeDef, Token ! [,]> = element(&mut seq, 2, &self)?;
+                let gt_token: Token ! [>] = element(&mut seq, 3, &self)?;
+                Ok(BoundLifetimes {
+                    for_token,
+                    lt_token,
+                    lifetimes,
+                    gt_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut for_token: Option<Token![for]> = None;
+                let mut lt_token: Option<Token ! [<]> = None;
+                let mut lifetimes: Option<Punctuated<LifetimeDef, Token ! [,]>> = None;
+                let mut gt_token: Option<Token ! [>]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut for_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut lt_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut lifetimes, FIELDS[2])?,
+                        3 => field(&mut map, &mut gt_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let for_token = required(for_token, FIELDS[0])?;
+                let lt_token = required(lt_token, FIELDS[1])?;
+                let lifetimes = required(lifetimes, FIELDS[2])?;
+                let gt_token = required(gt_token, FIELDS[3])?;
+                Ok(BoundLifetimes {
+                    for_token,
+                    lt_token,
+                    lifetimes,
+                    gt_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("BoundLifetimes", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ConstParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ConstParam", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("const_token", &self.const_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ConstParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "const_token",
+            "ident",
+            "colon_token",
+            "ty",
+            "eq_token",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ConstParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ConstParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let const_token: Token![const] = element(&mut seq, 1, &self)?;
+                let ident: De<Ident> = element(&mut seq, 2, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 3, &self)?;
+                let ty: Type = element(&mut seq, 4, &self)?;
+                let eq_token: Option<Token ! [=]> = element(&mut seq, 5, &self)?;
+                let default: Option<Expr> = element(&mut seq, 6, &self)?;
+                Ok(ConstParam {
+                    attrs,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut const_token: Option<Token![const]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Type> = None;
+                let mut eq_token: Option<Option<Token ! [=]>> = None;
+                let mut default: Option<Option<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut const_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut ident, FIELDS[2])?,
+                        3 => field(&mut map, &mut colon_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ty, FIELDS[4])?,
+                        5 => field(&mut map, &mut eq_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut default, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let const_token = required(const_token, FIELDS[1])?;
+                let ident = required(ident, FIELDS[2])?;
+                let colon_token = required(colon_token, FIELDS[3])?;
+                let ty = required(ty, FIELDS[4])?;
+                let eq_token = required(eq_token, FIELDS[5])?;
+                let default = required(default, FIELDS[6])?;
+                Ok(ConstParam {
+                    attrs,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    default,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ConstParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Constraint {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Constraint", 3)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Constraint {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "colon_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Constraint;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Constraint")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 1, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 2, &self)?;
+                Ok(Constraint {
+                    ident: ident.0,
+                    colon_token,
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        1 => field(&mut map, &mut colon_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut bounds, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                let colon_token = required(colon_token, FIELDS[1])?;
+                let bounds = required(bounds, FIELDS[2])?;
+                Ok(Constraint {
+                    ident: ident.0,
+                    colon_token,
+                    bounds,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Constraint", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for Data {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Data::Struct(_binding_0) => {
+                serializer.serialize_newtype_variant("Data", 0, "Struct", _binding_0)
+            }
+            Data::Enum(_binding_0) => {
+                serializer.serialize_newtype_variant("Data", 1, "Enum", _binding_0)
+            }
+            Data::Union(_binding_0) => {
+                serializer.serialize_newtype_variant("Data", 2, "Union", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for Data {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Struct", "Enum", "Union"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Data;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Data")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Data::Struct),
+                    1 => variant.newtype_variant().map(Data::Enum),
+                    2 => variant.newtype_variant().map(Data::Union),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Data", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataEnum", 3)?;
+        state.serialize_field("enum_token", &self.enum_token)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["enum_token", "brace_token", "variants"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let enum_token: Token![enum] = element(&mut seq, 0, &self)?;
+                let brace_token: Brace = element(&mut seq, 1, &self)?;
+                let variants: Punctuated<Variant, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(DataEnum {
+                    enum_token,
+                    brace_token,
+                    variants,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut enum_token: Option<Token![enum]> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut variants: Option<Punctuated<Variant, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut enum_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut brace_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut variants, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let enum_token = required(enum_token, FIELDS[0])?;
+                let brace_token = required(brace_token, FIELDS[1])?;
+                let variants = required(variants, FIELDS[2])?;
+                Ok(DataEnum {
+                    enum_token,
+                    brace_token,
+                    variants,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataStruct", 3)?;
+        state.serialize_field("struct_token", &self.struct_token)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["struct_token", "fields", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let struct_token: Token![struct] = element(&mut seq, 0, &self)?;
+                let fields: Fields = element(&mut seq, 1, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 2, &self)?;
+                Ok(DataStruct {
+                    struct_token,
+                    fields,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut struct_token: Option<Token![struct]> = None;
+                let mut fields: Option<Fields> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut struct_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut fields, FIELDS[1])?,
+                        2 => field(&mut map, &mut semi_token, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let struct_token = required(struct_token, FIELDS[0])?;
+                let fields = required(fields, FIELDS[1])?;
+                let semi_token = required(semi_token, FIELDS[2])?;
+                Ok(DataStruct {
+                    struct_token,
+                    fields,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DataUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DataUnion", 2)?;
+        state.serialize_field("union_token", &self.union_token)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DataUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["union_token", "fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DataUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DataUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let union_token: Token![union] = element(&mut seq, 0, &self)?;
+                let fields: FieldsNamed = element(&mut seq, 1, &self)?;
+                Ok(DataUnion {
+                    union_token,
+                    fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut union_token: Option<Token![union]> = None;
+                let mut fields: Option<FieldsNamed> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut union_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut fields, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let union_token = required(union_token, FIELDS[0])?;
+                let fields = required(fields, FIELDS[1])?;
+                Ok(DataUnion {
+                    union_token,
+                    fields,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DataUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "derive")]
+impl Serialize for DeriveInput {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("DeriveInput", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("data", &self.data)?;
+        state.end()
+    }
+}
+#[cfg(feature = "derive")]
+impl<'de> Deserialize<'de> for DeriveInput {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "generics", "data"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = DeriveInput;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct DeriveInput")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let ident: De<Ident> = element(&mut seq, 2, &self)?;
+                let generics: Generics = element(&mut seq, 3, &self)?;
+                let data: Data = element(&mut seq, 4, &self)?;
+                Ok(DeriveInput {
+                    attrs,
+                    vis,
+                    ident: ident.0,
+                    generics,
+                    data,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut data: Option<Data> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut ident, FIELDS[2])?,
+                        3 => field(&mut map, &mut generics, FIELDS[3])?,
+                        4 => field(&mut map, &mut data, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let ident = required(ident, FIELDS[2])?;
+                let generics = required(generics, FIELDS[3])?;
+                let data = required(data, FIELDS[4])?;
+                Ok(DeriveInput {
+                    attrs,
+                    vis,
+                    ident: ident.0,
+                    generics,
+                    data,
+                })
+            }
+        }
+        deserializer.deserialize_struct("DeriveInput", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Expr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Expr::Array(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 0, "Array", _binding_0))
+            }
+            Expr::Assign(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 1, "Assign", _binding_0))
+            }
+            Expr::AssignOp(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 2, "AssignOp", _binding_0))
+            }
+            Expr::Async(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 3, "Async", _binding_0))
+            }
+            Expr::Await(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 4, "Await", _binding_0))
+            }
+            Expr::Binary(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 5, "Binary", _binding_0)
+            }
+            Expr::Block(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 6, "Block", _binding_0))
+            }
+            Expr::Box(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 7, "Box", _binding_0))
+            }
+            Expr::Break(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 8, "Break", _binding_0))
+            }
+            Expr::Call(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 9, "Call", _binding_0)
+            }
+            Expr::Cast(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 10, "Cast", _binding_0)
+            }
+            Expr::Closure(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 11, "Closure", _binding_0))
+            }
+            Expr::Continue(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 12, "Continue", _binding_0))
+            }
+            Expr::Field(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 13, "Field", _binding_0)
+            }
+            Expr::ForLoop(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 14, "ForLoop", _binding_0))
+            }
+            Expr::Group(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 15, "Group", _binding_0))
+            }
+            Expr::If(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 16, "If", _binding_0))
+            }
+            Expr::Index(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 17, "Index", _binding_0)
+            }
+            Expr::Let(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 18, "Let", _binding_0))
+            }
+            Expr::Lit(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 19, "Lit", _binding_0)
+            }
+            Expr::Loop(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 20, "Loop", _binding_0))
+            }
+            Expr::Macro(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 21, "Macro", _binding_0))
+            }
+            Expr::Match(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 22, "Match", _binding_0))
+            }
+            Expr::MethodCall(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 23, "MethodCall", _binding_0))
+            }
+            Expr::Paren(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 24, "Paren", _binding_0)
+            }
+            Expr::Path(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 25, "Path", _binding_0)
+            }
+            Expr::Range(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 26, "Range", _binding_0))
+            }
+            Expr::Reference(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 27, "Reference", _binding_0))
+            }
+            Expr::Repeat(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 28, "Repeat", _binding_0))
+            }
+            Expr::Return(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 29, "Return", _binding_0))
+            }
+            Expr::Struct(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 30, "Struct", _binding_0))
+            }
+            Expr::Try(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 31, "Try", _binding_0))
+            }
+            Expr::TryBlock(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 32, "TryBlock", _binding_0))
+            }
+            Expr::Tuple(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 33, "Tuple", _binding_0))
+            }
+            Expr::Type(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 34, "Type", _binding_0))
+            }
+            Expr::Unary(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 35, "Unary", _binding_0)
+            }
+            Expr::Unsafe(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 36, "Unsafe", _binding_0))
+            }
+            Expr::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("Expr", 37, "Verbatim", &Ser(_binding_0))
+            }
+            Expr::While(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 38, "While", _binding_0))
+            }
+            Expr::Yield(_binding_0) => {
+                full!(serializer.serialize_newtype_variant("Expr", 39, "Yield", _binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Expr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "Assign",
+            "AssignOp",
+            "Async",
+            "Await",
+            "Binary",
+            "Block",
+            "Box",
+            "Break",
+            "Call",
+            "Cast",
+            "Closure",
+            "Continue",
+            "Field",
+            "ForLoop",
+            "Group",
+            "If",
+            "Index",
+            "Let",
+            "Lit",
+            "Loop",
+            "Macro",
+            "Match",
+            "MethodCall",
+            "Paren",
+            "Path",
+            "Range",
+            "Reference",
+            "Repeat",
+            "Return",
+            "Struct",
+            "Try",
+            "TryBlock",
+            "Tuple",
+            "Type",
+            "Unary",
+            "Unsafe",
+            "Verbatim",
+            "While",
+            "Yield",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Expr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Expr")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    #[cfg(feature = "full")]
+                    0 => variant.newtype_variant().map(Expr::Array),
+                    #[cfg(feature = "full")]
+                    1 => variant.newtype_variant().map(Expr::Assign),
+                    #[cfg(feature = "full")]
+                    2 => variant.newtype_variant().map(Expr::AssignOp),
+                    #[cfg(feature = "full")]
+                    3 => variant.newtype_variant().map(Expr::Async),
+                    #[cfg(feature = "full")]
+                    4 => variant.newtype_variant().map(Expr::Await),
+                    5 => variant.newtype_variant().map(Expr::Binary),
+                    #[cfg(feature = "full")]
+                    6 => variant.newtype_variant().map(Expr::Block),
+                    #[cfg(feature = "full")]
+                    7 => variant.newtype_variant().map(Expr::Box),
+                    #[cfg(feature = "full")]
+                    8 => variant.newtype_variant().map(Expr::Break),
+                    9 => variant.newtype_variant().map(Expr::Call),
+                    10 => variant.newtype_variant().map(Expr::Cast),
+                    #[cfg(feature = "full")]
+                    11 => variant.newtype_variant().map(Expr::Closure),
+                    #[cfg(feature = "full")]
+                    12 => variant.newtype_variant().map(Expr::Continue),
+                    13 => variant.newtype_variant().map(Expr::Field),
+                    #[cfg(feature = "full")]
+                    14 => variant.newtype_variant().map(Expr::ForLoop),
+                    #[cfg(feature = "full")]
+                    15 => variant.newtype_variant().map(Expr::Group),
+                    #[cfg(feature = "full")]
+                    16 => variant.newtype_variant().map(Expr::If),
+                    17 => variant.newtype_variant().map(Expr::Index),
+                    #[cfg(feature = "full")]
+                    18 => variant.newtype_variant().map(Expr::Let),
+                    19 => variant.newtype_variant().map(Expr::Lit),
+                    #[cfg(feature = "full")]
+                    20 => variant.newtype_variant().map(Expr::Loop),
+                    #[cfg(feature = "full")]
+                    21 => variant.newtype_variant().map(Expr::Macro),
+                    #[cfg(feature = "full")]
+                    22 => variant.newtype_variant().map(Expr::Match),
+                    #[cfg(feature = "full")]
+                    23 => variant.newtype_variant().map(Expr::MethodCall),
+                    24 => variant.newtype_variant().map(Expr::Paren),
+                    25 => variant.newtype_variant().map(Expr::Path),
+                    #[cfg(feature = "full")]
+                    26 => variant.newtype_variant().map(Expr::Range),
+                    #[cfg(feature = "full")]
+                    27 => variant.newtype_variant().map(Expr::Reference),
+                    #[cfg(feature = "full")]
+                    28 => variant.newtype_variant().map(Expr::Repeat),
+                    #[cfg(feature = "full")]
+                    29 => variant.newtype_variant().map(Expr::Return),
+                    #[cfg(feature = "full")]
+                    30 => variant.newtype_variant().map(Expr::Struct),
+                    #[cfg(feature = "full")]
+                    31 => variant.newtype_variant().map(Expr::Try),
+                    #[cfg(feature = "full")]
+                    32 => variant.newtype_variant().map(Expr::TryBlock),
+                    #[cfg(feature = "full")]
+                    33 => variant.newtype_variant().map(Expr::Tuple),
+                    #[cfg(feature = "full")]
+                    34 => variant.newtype_variant().map(Expr::Type),
+                    35 => variant.newtype_variant().map(Expr::Unary),
+                    #[cfg(feature = "full")]
+                    36 => variant.newtype_variant().map(Expr::Unsafe),
+                    37 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| Expr::Verbatim(it.0)),
+                    #[cfg(feature = "full")]
+                    38 => variant.newtype_variant().map(Expr::While),
+                    #[cfg(feature = "full")]
+                    39 => variant.newtype_variant().map(Expr::Yield),
+                    #[cfg(not(feature = "full"))]
+                    _ => Err(requires_full(VARIANTS[index])),
+                    #[cfg(feature = "full")]
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Expr", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprArray", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "bracket_token", "elems"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let bracket_token: Bracket = element(&mut seq, 1, &self)?;
+                let elems: Punctuated<Expr, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(ExprArray {
+                    attrs,
+                    bracket_token,
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut bracket_token: Option<Bracket> = None;
+                let mut elems: Option<Punctuated<Expr, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut bracket_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut elems, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let bracket_token = required(bracket_token, FIELDS[1])?;
+                let elems = required(elems, FIELDS[2])?;
+                Ok(ExprArray {
+                    attrs,
+                    bracket_token,
+                    elems,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprArray", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAssign {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssign", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAssign {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "eq_token", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssign;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssign")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let left: Box<Expr> = element(&mut seq, 1, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 2, &self)?;
+                let right: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(ExprAssign {
+                    attrs,
+                    left,
+                    eq_token,
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut left: Option<Box<Expr>> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut right: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut left, FIELDS[1])?,
+                        2 => field(&mut map, &mut eq_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut right, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let left = required(left, FIELDS[1])?;
+                let eq_token = required(eq_token, FIELDS[2])?;
+                let right = required(right, FIELDS[3])?;
+                Ok(ExprAssign {
+                    attrs,
+                    left,
+                    eq_token,
+                    right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssign", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAssignOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAssignOp", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAssignOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAssignOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAssignOp")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let left: Box<Expr> = element(&mut seq, 1, &self)?;
+                let op: BinOp = element(&mut seq, 2, &self)?;
+                let right: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(ExprAssignOp {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut left: Option<Box<Expr>> = None;
+                let mut op: Option<BinOp> = None;
+                let mut right: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut left, FIELDS[1])?,
+                        2 => field(&mut map, &mut op, FIELDS[2])?,
+                        3 => field(&mut map, &mut right, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let left = required(left, FIELDS[1])?;
+                let op = required(op, FIELDS[2])?;
+                let right = required(right, FIELDS[3])?;
+                Ok(ExprAssignOp {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAssignOp", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAsync {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAsync", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("async_token", &self.async_token)?;
+        state.serialize_field("capture", &self.capture)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAsync {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "async_token", "capture", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAsync;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAsync")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let async_token: Token![async] = element(&mut seq, 1, &self)?;
+                let capture: Option<Token![move]> = element(&mut seq, 2, &self)?;
+                let block: Block = element(&mut seq, 3, &self)?;
+                Ok(ExprAsync {
+                    attrs,
+                    async_token,
+                    capture,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut async_token: Option<Token![async]> = None;
+                let mut capture: Option<Option<Token![move]>> = None;
+                let mut block: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut async_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut capture, FIELDS[2])?,
+                        3 => field(&mut map, &mut block, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let async_token = required(async_token, FIELDS[1])?;
+                let capture = required(capture, FIELDS[2])?;
+                let block = required(block, FIELDS[3])?;
+                Ok(ExprAsync {
+                    attrs,
+                    async_token,
+                    capture,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAsync", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprAwait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprAwait", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("dot_token", &self.dot_token)?;
+        state.serialize_field("await_token", &self.await_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprAwait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base", "dot_token", "await_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprAwait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprAwait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let base: Box<Expr> = element(&mut seq, 1, &self)?;
+                let dot_token: Token ! [.] = element(&mut seq, 2, &self)?;
+                let await_token: crate::token::Await = element(&mut seq, 3, &self)?;
+                Ok(ExprAwait {
+                    attrs,
+                    base,
+                    dot_token,
+                    await_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut base: Option<Box<Expr>> = None;
+                let mut dot_token: Option<Token ! [.]> = None;
+                let mut await_token: Option<crate::token::Await> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut base, FIELDS[1])?,
+                        2 => field(&mut map, &mut dot_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut await_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let base = required(base, FIELDS[1])?;
+                let dot_token = required(dot_token, FIELDS[2])?;
+                let await_token = required(await_token, FIELDS[3])?;
+                Ok(ExprAwait {
+                    attrs,
+                    base,
+                    dot_token,
+                    await_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprAwait", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprBinary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBinary", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("left", &self.left)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("right", &self.right)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprBinary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "left", "op", "right"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBinary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBinary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let left: Box<Expr> = element(&mut seq, 1, &self)?;
+                let op: BinOp = element(&mut seq, 2, &self)?;
+                let right: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(ExprBinary {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut left: Option<Box<Expr>> = None;
+                let mut op: Option<BinOp> = None;
+                let mut right: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut left, FIELDS[1])?,
+                        2 => field(&mut map, &mut op, FIELDS[2])?,
+                        3 => field(&mut map, &mut right, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let left = required(left, FIELDS[1])?;
+                let op = required(op, FIELDS[2])?;
+                let right = required(right, FIELDS[3])?;
+                Ok(ExprBinary {
+                    attrs,
+                    left,
+                    op,
+                    right,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBinary", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBlock", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let label: Option<Label> = element(&mut seq, 1, &self)?;
+                let block: Block = element(&mut seq, 2, &self)?;
+                Ok(ExprBlock {
+                    attrs,
+                    label,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut label: Option<Option<Label>> = None;
+                let mut block: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut label, FIELDS[1])?,
+                        2 => field(&mut map, &mut block, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let label = required(label, FIELDS[1])?;
+                let block = required(block, FIELDS[2])?;
+                Ok(ExprBlock {
+                    attrs,
+                    label,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBox", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("box_token", &self.box_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "box_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let box_token: Token![box] = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                Ok(ExprBox {
+                    attrs,
+                    box_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut box_token: Option<Token![box]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut box_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let box_token = required(box_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprBox {
+                    attrs,
+                    box_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprBreak {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprBreak", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("break_token", &self.break_token)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprBreak {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "break_token", "label", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprBreak;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprBreak")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let break_token: Token![break] = element(&mut seq, 1, &self)?;
+                let label: Option<Lifetime> = element(&mut seq, 2, &self)?;
+                let expr: Option<Box<Expr>> = element(&mut seq, 3, &self)?;
+                Ok(ExprBreak {
+                    attrs,
+                    break_token,
+                    label,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut break_token: Option<Token![break]> = None;
+                let mut label: Option<Option<Lifetime>> = None;
+                let mut expr: Option<Option<Box<Expr>>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut break_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut label, FIELDS[2])?,
+                        3 => field(&mut map, &mut expr, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let break_token = required(break_token, FIELDS[1])?;
+                let label = required(label, FIELDS[2])?;
+                let expr = required(expr, FIELDS[3])?;
+                Ok(ExprBreak {
+                    attrs,
+                    break_token,
+                    label,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprBreak", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCall", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("func", &self.func)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "func", "paren_token", "args"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let func: Box<Expr> = element(&mut seq, 1, &self)?;
+                let paren_token: Paren = element(&mut seq, 2, &self)?;
+                let args: Punctuated<Expr, Token ! [,]> = element(&mut seq, 3, &self)?;
+                Ok(ExprCall {
+                    attrs,
+                    func,
+                    paren_token,
+                    args,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut func: Option<Box<Expr>> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut args: Option<Punctuated<Expr, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut func, FIELDS[1])?,
+                        2 => field(&mut map, &mut paren_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut args, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let func = required(func, FIELDS[1])?;
+                let paren_token = required(paren_token, FIELDS[2])?;
+                let args = required(args, FIELDS[3])?;
+                Ok(ExprCall {
+                    attrs,
+                    func,
+                    paren_token,
+                    args,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCall", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprCast {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprCast", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("as_token", &self.as_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprCast {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "as_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprCast;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprCast")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 1, &self)?;
+                let as_token: Token![as] = element(&mut seq, 2, &self)?;
+                let ty: Box<Type> = element(&mut seq, 3, &self)?;
+                Ok(ExprCast {
+                    attrs,
+                    expr,
+                    as_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut as_token: Option<Token![as]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut expr, FIELDS[1])?,
+                        2 => field(&mut map, &mut as_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ty, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let expr = required(expr, FIELDS[1])?;
+                let as_token = required(as_token, FIELDS[2])?;
+                let ty = required(ty, FIELDS[3])?;
+                Ok(ExprCast {
+                    attrs,
+                    expr,
+                    as_token,
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprCast", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprClosure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprClosure", 9)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("asyncness", &self.asyncness)?;
+        state.serialize_field("movability", &self.movability)?;
+        state.serialize_field("capture", &self.capture)?;
+        state.serialize_field("or1_token", &self.or1_token)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("or2_token", &self.or2_token)?;
+        state.serialize_field("output", &self.output)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprClosure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "asyncness",
+            "movability",
+            "capture",
+            "or1_token",
+            "inputs",
+            "or2_token",
+            "output",
+            "body",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprClosure;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprClosure")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let asyncness: Option<Token![async]> = element(&mut seq, 1, &self)?;
+                let movability: Option<Token![static]> = element(&mut seq, 2, &self)?;
+                let capture: Option<Token![move]> = element(&mut seq, 3, &self)?;
+                let or1_token: Token ! [|] = element(&mut seq, 4, &self)?;
+                let inputs: Punctuated<Pat, Token ! [,]> = element(&mut seq, 5, &self)?;
+                let or2_token: Token ! [|] = element(&mut seq, 6, &self)?;
+                let output: ReturnType = element(&mut seq, 7, &self)?;
+                let body: Box<Expr> = element(&mut seq, 8, &self)?;
+                Ok(ExprClosure {
+                    attrs,
+                    asyncness,
+                    movability,
+                    capture,
+                    or1_token,
+                    inputs,
+                    or2_token,
+                    output,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut asyncness: Option<Option<Token![async]>> = None;
+                let mut movability: Option<Option<Token![static]>> = None;
+                let mut capture: Option<Option<Token![move]>> = None;
+                let mut or1_token: Option<Token ! [|]> = None;
+                let mut inputs: Option<Punctuated<Pat, Token ! [,]>> = None;
+                let mut or2_token: Option<Token ! [|]> = None;
+                let mut output: Option<ReturnType> = None;
+                let mut body: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut asyncness, FIELDS[1])?,
+                        2 => field(&mut map, &mut movability, FIELDS[2])?,
+                        3 => field(&mut map, &mut capture, FIELDS[3])?,
+                        4 => field(&mut map, &mut or1_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut inputs, FIELDS[5])?,
+                        6 => field(&mut map, &mut or2_token, FIELDS[6])?,
+                        7 => field(&mut map, &mut output, FIELDS[7])?,
+                        8 => field(&mut map, &mut body, FIELDS[8])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let asyncness = required(asyncness, FIELDS[1])?;
+                let movability = required(movability, FIELDS[2])?;
+                let capture = required(capture, FIELDS[3])?;
+                let or1_token = required(or1_token, FIELDS[4])?;
+                let inputs = required(inputs, FIELDS[5])?;
+                let or2_token = required(or2_token, FIELDS[6])?;
+                let output = required(output, FIELDS[7])?;
+                let body = required(body, FIELDS[8])?;
+                Ok(ExprClosure {
+                    attrs,
+                    asyncness,
+                    movability,
+                    capture,
+                    or1_token,
+                    inputs,
+                    or2_token,
+                    output,
+                    body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprClosure", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprContinue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprContinue", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("continue_token", &self.continue_token)?;
+        state.serialize_field("label", &self.label)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprContinue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "continue_token", "label"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprContinue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprContinue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let continue_token: Token![continue] = element(&mut seq, 1, &self)?;
+                let label: Option<Lifetime> = element(&mut seq, 2, &self)?;
+                Ok(ExprContinue {
+                    attrs,
+                    continue_token,
+                    label,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut continue_token: Option<Token![continue]> = None;
+                let mut label: Option<Option<Lifetime>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut continue_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut label, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let continue_token = required(continue_token, FIELDS[1])?;
+                let label = required(label, FIELDS[2])?;
+                Ok(ExprContinue {
+                    attrs,
+                    continue_token,
+                    label,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprContinue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprField {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprField", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("base", &self.base)?;
+        state.serialize_field("dot_token", &self.dot_token)?;
+        state.serialize_field("member", &self.member)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprField {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "base", "dot_token", "member"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprField;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprField")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let base: Box<Expr> = element(&mut seq, 1, &self)?;
+                let dot_token: Token ! [.] = element(&mut seq, 2, &self)?;
+                let member: Member = element(&mut seq, 3, &self)?;
+                Ok(ExprField {
+                    attrs,
+                    base,
+                    dot_token,
+                    member,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut base: Option<Box<Expr>> = None;
+                let mut dot_token: Option<Token ! [.]> = None;
+                let mut member: Option<Member> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut base, FIELDS[1])?,
+                        2 => field(&mut map, &mut dot_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut member, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let base = required(base, FIELDS[1])?;
+                let dot_token = required(dot_token, FIELDS[2])?;
+                let member = required(member, FIELDS[3])?;
+                Ok(ExprField {
+                    attrs,
+                    base,
+                    dot_token,
+                    member,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprField", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprForLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprForLoop", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("for_token", &self.for_token)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("in_token", &self.in_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprForLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "label",
+            "for_token",
+            "pat",
+            "in_token",
+            "expr",
+            "body",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprForLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprForLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let label: Option<Label> = element(&mut seq, 1, &self)?;
+                let for_token: Token![for] = element(&mut seq, 2, &self)?;
+                let pat: Pat = element(&mut seq, 3, &self)?;
+                let in_token: Token![in] = element(&mut seq, 4, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 5, &self)?;
+                let body: Block = element(&mut seq, 6, &self)?;
+                Ok(ExprForLoop {
+                    attrs,
+                    label,
+                    for_token,
+                    pat,
+                    in_token,
+                    expr,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut label: Option<Option<Label>> = None;
+                let mut for_token: Option<Token![for]> = None;
+                let mut pat: Option<Pat> = None;
+                let mut in_token: Option<Token![in]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut body: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut label, FIELDS[1])?,
+                        2 => field(&mut map, &mut for_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut pat, FIELDS[3])?,
+                        4 => field(&mut map, &mut in_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut expr, FIELDS[5])?,
+                        6 => field(&mut map, &mut body, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let label = required(label, FIELDS[1])?;
+                let for_token = required(for_token, FIELDS[2])?;
+                let pat = required(pat, FIELDS[3])?;
+                let in_token = required(in_token, FIELDS[4])?;
+                let expr = required(expr, FIELDS[5])?;
+                let body = required(body, FIELDS[6])?;
+                Ok(ExprForLoop {
+                    attrs,
+                    label,
+                    for_token,
+                    pat,
+                    in_token,
+                    expr,
+                    body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprForLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprGroup", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("group_token", &self.group_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "group_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let group_token: Group = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                Ok(ExprGroup {
+                    attrs,
+                    group_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut group_token: Option<Group> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut group_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let group_token = required(group_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprGroup {
+                    attrs,
+                    group_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprIf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIf", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("if_token", &self.if_token)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("then_branch", &self.then_branch)?;
+        state.serialize_field("else_branch", &self.else_branch)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprIf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "if_token", "cond", "then_branch", "else_branch"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let if_token: Token![if] = element(&mut seq, 1, &self)?;
+                let cond: Box<Expr> = element(&mut seq, 2, &self)?;
+                let then_branch: Block = element(&mut seq, 3, &self)?;
+                let else_branch: Option<(Token![else], Box<Expr>)> = element(&mut seq, 4, &self)?;
+                Ok(ExprIf {
+                    attrs,
+                    if_token,
+                    cond,
+                    then_branch,
+                    else_branch,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut if_token: Option<Token![if]> = None;
+                let mut cond: Option<Box<Expr>> = None;
+                let mut then_branch: Option<Block> = None;
+                let mut else_branch: Option<Option<(Token![else], Box<Expr>)>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut if_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut cond, FIELDS[2])?,
+                        3 => field(&mut map, &mut then_branch, FIELDS[3])?,
+                        4 => field(&mut map, &mut else_branch, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let if_token = required(if_token, FIELDS[1])?;
+                let cond = required(cond, FIELDS[2])?;
+                let then_branch = required(then_branch, FIELDS[3])?;
+                let else_branch = required(else_branch, FIELDS[4])?;
+                Ok(ExprIf {
+                    attrs,
+                    if_token,
+                    cond,
+                    then_branch,
+                    else_branch,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIf", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprIndex {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprIndex", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprIndex {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "bracket_token", "index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprIndex;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprIndex")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 1, &self)?;
+                let bracket_token: Bracket = element(&mut seq, 2, &self)?;
+                let index: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(ExprIndex {
+                    attrs,
+                    expr,
+                    bracket_token,
+                    index,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut bracket_token: Option<Bracket> = None;
+                let mut index: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut expr, FIELDS[1])?,
+                        2 => field(&mut map, &mut bracket_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut index, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let expr = required(expr, FIELDS[1])?;
+                let bracket_token = required(bracket_token, FIELDS[2])?;
+                let index = required(index, FIELDS[3])?;
+                Ok(ExprIndex {
+                    attrs,
+                    expr,
+                    bracket_token,
+                    index,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprIndex", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprLet {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLet", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("let_token", &self.let_token)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprLet {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "let_token", "pat", "eq_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLet;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLet")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let let_token: Token![let] = element(&mut seq, 1, &self)?;
+                let pat: Pat = element(&mut seq, 2, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 3, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 4, &self)?;
+                Ok(ExprLet {
+                    attrs,
+                    let_token,
+                    pat,
+                    eq_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut let_token: Option<Token![let]> = None;
+                let mut pat: Option<Pat> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut let_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut pat, FIELDS[2])?,
+                        3 => field(&mut map, &mut eq_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut expr, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let let_token = required(let_token, FIELDS[1])?;
+                let pat = required(pat, FIELDS[2])?;
+                let eq_token = required(eq_token, FIELDS[3])?;
+                let expr = required(expr, FIELDS[4])?;
+                Ok(ExprLet {
+                    attrs,
+                    let_token,
+                    pat,
+                    eq_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLet", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLit", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let lit: Lit = element(&mut seq, 1, &self)?;
+                Ok(ExprLit { attrs, lit })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut lit: Option<Lit> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut lit, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let lit = required(lit, FIELDS[1])?;
+                Ok(ExprLit { attrs, lit })
+            }
+        }
+        deserializer.deserialize_struct("ExprLit", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprLoop {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprLoop", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("loop_token", &self.loop_token)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprLoop {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "loop_token", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprLoop;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprLoop")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let label: Option<Label> = element(&mut seq, 1, &self)?;
+                let loop_token: Token![loop] = element(&mut seq, 2, &self)?;
+                let body: Block = element(&mut seq, 3, &self)?;
+                Ok(ExprLoop {
+                    attrs,
+                    label,
+                    loop_token,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut label: Option<Option<Label>> = None;
+                let mut loop_token: Option<Token![loop]> = None;
+                let mut body: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut label, FIELDS[1])?,
+                        2 => field(&mut map, &mut loop_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut body, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let label = required(label, FIELDS[1])?;
+                let loop_token = required(loop_token, FIELDS[2])?;
+                let body = required(body, FIELDS[3])?;
+                Ok(ExprLoop {
+                    attrs,
+                    label,
+                    loop_token,
+                    body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprLoop", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMacro", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let mac: Macro = element(&mut seq, 1, &self)?;
+                Ok(ExprMacro { attrs, mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut mac: Option<Macro> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut mac, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let mac = required(mac, FIELDS[1])?;
+                Ok(ExprMacro { attrs, mac })
+            }
+        }
+        deserializer.deserialize_struct("ExprMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMatch", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("match_token", &self.match_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("arms", &self.arms)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "match_token", "expr", "brace_token", "arms"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMatch;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMatch")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let match_token: Token![match] = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                let brace_token: Brace = element(&mut seq, 3, &self)?;
+                let arms: Vec<Arm> = element(&mut seq, 4, &self)?;
+                Ok(ExprMatch {
+                    attrs,
+                    match_token,
+                    expr,
+                    brace_token,
+                    arms,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut match_token: Option<Token![match]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut arms: Option<Vec<Arm>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut match_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        3 => field(&mut map, &mut brace_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut arms, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let match_token = required(match_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                let brace_token = required(brace_token, FIELDS[3])?;
+                let arms = required(arms, FIELDS[4])?;
+                Ok(ExprMatch {
+                    attrs,
+                    match_token,
+                    expr,
+                    brace_token,
+                    arms,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMatch", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprMethodCall {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprMethodCall", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("receiver", &self.receiver)?;
+        state.serialize_field("dot_token", &self.dot_token)?;
+        state.serialize_field("method", &Ser(&self.method))?;
+        state.serialize_field("turbofish", &self.turbofish)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("args", &self.args)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprMethodCall {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "receiver",
+            "dot_token",
+            "method",
+            "turbofish",
+            "paren_token",
+            "args",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprMethodCall;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprMethodCall")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let receiver: Box<Expr> = element(&mut seq, 1, &self)?;
+                let dot_token: Token ! [.] = element(&mut seq, 2, &self)?;
+                let method: De<Ident> = element(&mut seq, 3, &self)?;
+                let turbofish: Option<MethodTurbofish> = element(&mut seq, 4, &self)?;
+                let paren_token: Paren = element(&mut seq, 5, &self)?;
+                let args: Punctuated<Expr, Token ! [,]> = element(&mut seq, 6, &self)?;
+                Ok(ExprMethodCall {
+                    attrs,
+                    receiver,
+                    dot_token,
+                    method: method.0,
+                    turbofish,
+                    paren_token,
+                    args,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut receiver: Option<Box<Expr>> = None;
+                let mut dot_token: Option<Token ! [.]> = None;
+                let mut method: Option<De<Ident>> = None;
+                let mut turbofish: Option<Option<MethodTurbofish>> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut args: Option<Punctuated<Expr, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut receiver, FIELDS[1])?,
+                        2 => field(&mut map, &mut dot_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut method, FIELDS[3])?,
+                        4 => field(&mut map, &mut turbofish, FIELDS[4])?,
+                        5 => field(&mut map, &mut paren_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut args, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let receiver = required(receiver, FIELDS[1])?;
+                let dot_token = required(dot_token, FIELDS[2])?;
+                let method = required(method, FIELDS[3])?;
+                let turbofish = required(turbofish, FIELDS[4])?;
+                let paren_token = required(paren_token, FIELDS[5])?;
+                let args = required(args, FIELDS[6])?;
+                Ok(ExprMethodCall {
+                    attrs,
+                    receiver,
+                    dot_token,
+                    method: method.0,
+                    turbofish,
+                    paren_token,
+                    args,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprMethodCall", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprParen", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "paren_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let paren_token: Paren = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                Ok(ExprParen {
+                    attrs,
+                    paren_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut paren_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let paren_token = required(paren_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprParen {
+                    attrs,
+                    paren_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprPath", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let qself: Option<QSelf> = element(&mut seq, 1, &self)?;
+                let path: Path = element(&mut seq, 2, &self)?;
+                Ok(ExprPath { attrs, qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut qself: Option<Option<QSelf>> = None;
+                let mut path: Option<Path> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut qself, FIELDS[1])?,
+                        2 => field(&mut map, &mut path, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let qself = required(qself, FIELDS[1])?;
+                let path = required(path, FIELDS[2])?;
+                Ok(ExprPath { attrs, qself, path })
+            }
+        }
+        deserializer.deserialize_struct("ExprPath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRange", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("from", &self.from)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("to", &self.to)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "from", "limits", "to"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let from: Option<Box<Expr>> = element(&mut seq, 1, &self)?;
+                let limits: RangeLimits = element(&mut seq, 2, &self)?;
+                let to: Option<Box<Expr>> = element(&mut seq, 3, &self)?;
+                Ok(ExprRange {
+                    attrs,
+                    from,
+                    limits,
+                    to,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut from: Option<Option<Box<Expr>>> = None;
+                let mut limits: Option<RangeLimits> = None;
+                let mut to: Option<Option<Box<Expr>>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut from, FIELDS[1])?,
+                        2 => field(&mut map, &mut limits, FIELDS[2])?,
+                        3 => field(&mut map, &mut to, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let from = required(from, FIELDS[1])?;
+                let limits = required(limits, FIELDS[2])?;
+                let to = required(to, FIELDS[3])?;
+                Ok(ExprRange {
+                    attrs,
+                    from,
+                    limits,
+                    to,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReference", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("and_token", &self.and_token)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "and_token", "mutability", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let and_token: Token ! [&] = element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(ExprReference {
+                    attrs,
+                    and_token,
+                    mutability,
+                    expr,
+                    raw: Default::default(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut and_token: Option<Token ! [&]> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut and_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut expr, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let and_token = required(and_token, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let expr = required(expr, FIELDS[3])?;
+                Ok(ExprReference {
+                    attrs,
+                    and_token,
+                    mutability,
+                    expr,
+                    raw: Default::default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprRepeat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprRepeat", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprRepeat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "bracket_token", "expr", "semi_token", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprRepeat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprRepeat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let bracket_token: Bracket = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 3, &self)?;
+                let len: Box<Expr> = element(&mut seq, 4, &self)?;
+                Ok(ExprRepeat {
+                    attrs,
+                    bracket_token,
+                    expr,
+                    semi_token,
+                    len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut bracket_token: Option<Bracket> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                let mut len: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut bracket_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        3 => field(&mut map, &mut semi_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut len, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let bracket_token = required(bracket_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                let semi_token = required(semi_token, FIELDS[3])?;
+                let len = required(len, FIELDS[4])?;
+                Ok(ExprRepeat {
+                    attrs,
+                    bracket_token,
+                    expr,
+                    semi_token,
+                    len,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprRepeat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprReturn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprReturn", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("return_token", &self.return_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprReturn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "return_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprReturn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprReturn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let return_token: Token![return] = element(&mut seq, 1, &self)?;
+                let expr: Option<Box<Expr>> = element(&mut seq, 2, &self)?;
+                Ok(ExprReturn {
+                    attrs,
+                    return_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut return_token: Option<Token![return]> = None;
+                let mut expr: Option<Option<Box<Expr>>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut return_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let return_token = required(return_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprReturn {
+                    attrs,
+                    return_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprReturn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprStruct", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("dot2_token", &self.dot2_token)?;
+        state.serialize_field("rest", &self.rest)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "path",
+            "brace_token",
+            "fields",
+            "dot2_token",
+            "rest",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let path: Path = element(&mut seq, 1, &self)?;
+                let brace_token: Brace = element(&mut seq, 2, &self)?;
+                let fields: Punctuated<FieldValue, Token ! [,]> = element(&mut seq, 3, &self)?;
+                let dot2_token: Option<Token![..]> = element(&mut seq, 4, &self)?;
+                let rest: Option<Box<Expr>> = element(&mut seq, 5, &self)?;
+                Ok(ExprStruct {
+                    attrs,
+                    path,
+                    brace_token,
+                    fields,
+                    dot2_token,
+                    rest,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut path: Option<Path> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut fields: Option<Punctuated<FieldValue, Token ! [,]>> = None;
+                let mut dot2_token: Option<Option<Token![..]>> = None;
+                let mut rest: Option<Option<Box<Expr>>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut path, FIELDS[1])?,
+                        2 => field(&mut map, &mut brace_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut fields, FIELDS[3])?,
+                        4 => field(&mut map, &mut dot2_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut rest, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let path = required(path, FIELDS[1])?;
+                let brace_token = required(brace_token, FIELDS[2])?;
+                let fields = required(fields, FIELDS[3])?;
+                let dot2_token = required(dot2_token, FIELDS[4])?;
+                let rest = required(rest, FIELDS[5])?;
+                Ok(ExprStruct {
+                    attrs,
+                    path,
+                    brace_token,
+                    fields,
+                    dot2_token,
+                    rest,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTry {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTry", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("question_token", &self.question_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTry {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "question_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTry;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTry")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 1, &self)?;
+                let question_token: Token ! [?] = element(&mut seq, 2, &self)?;
+                Ok(ExprTry {
+                    attrs,
+                    expr,
+                    question_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut question_token: Option<Token ! [?]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut expr, FIELDS[1])?,
+                        2 => field(&mut map, &mut question_token, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let expr = required(expr, FIELDS[1])?;
+                let question_token = required(question_token, FIELDS[2])?;
+                Ok(ExprTry {
+                    attrs,
+                    expr,
+                    question_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTry", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTryBlock {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTryBlock", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("try_token", &self.try_token)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTryBlock {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "try_token", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTryBlock;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTryBlock")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let try_token: Token![try] = element(&mut seq, 1, &self)?;
+                let block: Block = element(&mut seq, 2, &self)?;
+                Ok(ExprTryBlock {
+                    attrs,
+                    try_token,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut try_token: Option<Token![try]> = None;
+                let mut block: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut try_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut block, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let try_token = required(try_token, FIELDS[1])?;
+                let block = required(block, FIELDS[2])?;
+                Ok(ExprTryBlock {
+                    attrs,
+                    try_token,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTryBlock", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprTuple", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "paren_token", "elems"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let paren_token: Paren = element(&mut seq, 1, &self)?;
+                let elems: Punctuated<Expr, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(ExprTuple {
+                    attrs,
+                    paren_token,
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut elems: Option<Punctuated<Expr, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut paren_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut elems, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let paren_token = required(paren_token, FIELDS[1])?;
+                let elems = required(elems, FIELDS[2])?;
+                Ok(ExprTuple {
+                    attrs,
+                    paren_token,
+                    elems,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprType", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr", "colon_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 1, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 2, &self)?;
+                let ty: Box<Type> = element(&mut seq, 3, &self)?;
+                Ok(ExprType {
+                    attrs,
+                    expr,
+                    colon_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut expr, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ty, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let expr = required(expr, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let ty = required(ty, FIELDS[3])?;
+                Ok(ExprType {
+                    attrs,
+                    expr,
+                    colon_token,
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ExprUnary {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnary", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("op", &self.op)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ExprUnary {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "op", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnary;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnary")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let op: UnOp = element(&mut seq, 1, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 2, &self)?;
+                Ok(ExprUnary { attrs, op, expr })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut op: Option<UnOp> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut op, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let op = required(op, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprUnary { attrs, op, expr })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnary", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprUnsafe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprUnsafe", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("unsafe_token", &self.unsafe_token)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprUnsafe {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "unsafe_token", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprUnsafe;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprUnsafe")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let unsafe_token: Token![unsafe] = element(&mut seq, 1, &self)?;
+                let block: Block = element(&mut seq, 2, &self)?;
+                Ok(ExprUnsafe {
+                    attrs,
+                    unsafe_token,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut unsafe_token: Option<Token![unsafe]> = None;
+                let mut block: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut unsafe_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut block, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let unsafe_token = required(unsafe_token, FIELDS[1])?;
+                let block = required(block, FIELDS[2])?;
+                Ok(ExprUnsafe {
+                    attrs,
+                    unsafe_token,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprUnsafe", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprWhile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprWhile", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("label", &self.label)?;
+        state.serialize_field("while_token", &self.while_token)?;
+        state.serialize_field("cond", &self.cond)?;
+        state.serialize_field("body", &self.body)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprWhile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "label", "while_token", "cond", "body"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprWhile;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprWhile")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let label: Option<Label> = element(&mut seq, 1, &self)?;
+                let while_token: Token![while] = element(&mut seq, 2, &self)?;
+                let cond: Box<Expr> = element(&mut seq, 3, &self)?;
+                let body: Block = element(&mut seq, 4, &self)?;
+                Ok(ExprWhile {
+                    attrs,
+                    label,
+                    while_token,
+                    cond,
+                    body,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut label: Option<Option<Label>> = None;
+                let mut while_token: Option<Token![while]> = None;
+                let mut cond: Option<Box<Expr>> = None;
+                let mut body: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut label, FIELDS[1])?,
+                        2 => field(&mut map, &mut while_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut cond, FIELDS[3])?,
+                        4 => field(&mut map, &mut body, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let label = required(label, FIELDS[1])?;
+                let while_token = required(while_token, FIELDS[2])?;
+                let cond = required(cond, FIELDS[3])?;
+                let body = required(body, FIELDS[4])?;
+                Ok(ExprWhile {
+                    attrs,
+                    label,
+                    while_token,
+                    cond,
+                    body,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprWhile", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ExprYield {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ExprYield", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("yield_token", &self.yield_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ExprYield {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "yield_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ExprYield;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ExprYield")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let yield_token: Token![yield] = element(&mut seq, 1, &self)?;
+                let expr: Option<Box<Expr>> = element(&mut seq, 2, &self)?;
+                Ok(ExprYield {
+                    attrs,
+                    yield_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut yield_token: Option<Token![yield]> = None;
+                let mut expr: Option<Option<Box<Expr>>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut yield_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut expr, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let yield_token = required(yield_token, FIELDS[1])?;
+                let expr = required(expr, FIELDS[2])?;
+                Ok(ExprYield {
+                    attrs,
+                    yield_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ExprYield", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Field {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Field", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(Ser))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Field {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "ident", "colon_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Field;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Field")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let ident: Option<De<Ident>> = element(&mut seq, 2, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 3, &self)?;
+                let ty: Type = element(&mut seq, 4, &self)?;
+                Ok(Field {
+                    attrs,
+                    vis,
+                    ident: ident.map(|it| it.0),
+                    colon_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut ident: Option<Option<De<Ident>>> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut ty: Option<Type> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut ident, FIELDS[2])?,
+                        3 => field(&mut map, &mut colon_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ty, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let ident = required(ident, FIELDS[2])?;
+                let colon_token = required(colon_token, FIELDS[3])?;
+                let ty = required(ty, FIELDS[4])?;
+                Ok(Field {
+                    attrs,
+                    vis,
+                    ident: ident.map(|it| it.0),
+                    colon_token,
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Field", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FieldPat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldPat", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FieldPat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldPat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldPat")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let member: Member = element(&mut seq, 1, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 2, &self)?;
+                let pat: Box<Pat> = element(&mut seq, 3, &self)?;
+                Ok(FieldPat {
+                    attrs,
+                    member,
+                    colon_token,
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut member: Option<Member> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut pat: Option<Box<Pat>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut member, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut pat, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let member = required(member, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let pat = required(pat, FIELDS[3])?;
+                Ok(FieldPat {
+                    attrs,
+                    member,
+                    colon_token,
+                    pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldPat", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FieldValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldValue", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("member", &self.member)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FieldValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "member", "colon_token", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let member: Member = element(&mut seq, 1, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 2, &self)?;
+                let expr: Expr = element(&mut seq, 3, &self)?;
+                Ok(FieldValue {
+                    attrs,
+                    member,
+                    colon_token,
+                    expr,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut member: Option<Member> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut expr: Option<Expr> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut member, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut expr, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let member = required(member, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let expr = required(expr, FIELDS[3])?;
+                Ok(FieldValue {
+                    attrs,
+                    member,
+                    colon_token,
+                    expr,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldValue", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Fields {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Fields::Named(_binding_0) => {
+                serializer.serialize_newtype_variant("Fields", 0, "Named", _binding_0)
+            }
+            Fields::Unnamed(_binding_0) => {
+                serializer.serialize_newtype_variant("Fields", 1, "Unnamed", _binding_0)
+            }
+            Fields::Unit => serializer.serialize_unit_variant("Fields", 2, "Unit"),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Fields {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed", "Unit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Fields;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Fields")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Fields::Named),
+                    1 => variant.newtype_variant().map(Fields::Unnamed),
+                    2 => variant.unit_variant().map(|()| Fields::Unit),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Fields", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for FieldsNamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsNamed", 2)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("named", &self.named)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for FieldsNamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["brace_token", "named"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsNamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsNamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let brace_token: Brace = element(&mut seq, 0, &self)?;
+                let named: Punctuated<Field, Token ! [,]> = element(&mut seq, 1, &self)?;
+                Ok(FieldsNamed { brace_token, named })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut brace_token: Option<Brace> = None;
+                let mut named: Option<Punctuated<Field, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut brace_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut named, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let brace_token = required(brace_token, FIELDS[0])?;
+                let named = required(named, FIELDS[1])?;
+                Ok(FieldsNamed { brace_token, named })
+            }
+        }
+        deserializer.deserialize_struct("FieldsNamed", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for FieldsUnnamed {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("FieldsUnnamed", 2)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("unnamed", &self.unnamed)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for FieldsUnnamed {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "unnamed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FieldsUnnamed;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct FieldsUnnamed")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token: Paren = element(&mut seq, 0, &self)?;
+                let unnamed: Punctuated<Field, Token ! [,]> = element(&mut seq, 1, &self)?;
+                Ok(FieldsUnnamed {
+                    paren_token,
+                    unnamed,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token: Option<Paren> = None;
+                let mut unnamed: Option<Punctuated<Field, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut paren_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut unnamed, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let paren_token = required(paren_token, FIELDS[0])?;
+                let unnamed = required(unnamed, FIELDS[1])?;
+                Ok(FieldsUnnamed {
+                    paren_token,
+                    unnamed,
+                })
+            }
+        }
+        deserializer.deserialize_struct("FieldsUnnamed", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for File {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("File", 3)?;
+        state.serialize_field("shebang", &self.shebang)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for File {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["shebang", "attrs", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = File;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct File")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let shebang: Option<String> = element(&mut seq, 0, &self)?;
+                let attrs: Vec<Attribute> = element(&mut seq, 1, &self)?;
+                let items: Vec<Item> = element(&mut seq, 2, &self)?;
+                Ok(File {
+                    shebang,
+                    attrs,
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut shebang: Option<Option<String>> = None;
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut items: Option<Vec<Item>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut shebang, FIELDS[0])?,
+                        1 => field(&mut map, &mut attrs, FIELDS[1])?,
+                        2 => field(&mut map, &mut items, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let shebang = required(shebang, FIELDS[0])?;
+                let attrs = required(attrs, FIELDS[1])?;
+                let items = required(items, FIELDS[2])?;
+                Ok(File {
+                    shebang,
+                    attrs,
+                    items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("File", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for FnArg {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            FnArg::Receiver(_binding_0) => {
+                serializer.serialize_newtype_variant("FnArg", 0, "Receiver", _binding_0)
+            }
+            FnArg::Typed(_binding_0) => {
+                serializer.serialize_newtype_variant("FnArg", 1, "Typed", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for FnArg {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Receiver", "Typed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = FnArg;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum FnArg")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(FnArg::Receiver),
+                    1 => variant.newtype_variant().map(FnArg::Typed),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("FnArg", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ForeignItem::Fn(_binding_0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 0, "Fn", _binding_0)
+            }
+            ForeignItem::Static(_binding_0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 1, "Static", _binding_0)
+            }
+            ForeignItem::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 2, "Type", _binding_0)
+            }
+            ForeignItem::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 3, "Macro", _binding_0)
+            }
+            ForeignItem::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("ForeignItem", 4, "Verbatim", &Ser(_binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Fn", "Static", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ForeignItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(ForeignItem::Fn),
+                    1 => variant.newtype_variant().map(ForeignItem::Static),
+                    2 => variant.newtype_variant().map(ForeignItem::Type),
+                    3 => variant.newtype_variant().map(ForeignItem::Macro),
+                    4 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| ForeignItem::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ForeignItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemFn", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let sig: Signature = element(&mut seq, 2, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 3, &self)?;
+                Ok(ForeignItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut sig: Option<Signature> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut sig, FIELDS[2])?,
+                        3 => field(&mut map, &mut semi_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let sig = required(sig, FIELDS[2])?;
+                let semi_token = required(semi_token, FIELDS[3])?;
+                Ok(ForeignItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemMacro", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let mac: Macro = element(&mut seq, 1, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 2, &self)?;
+                Ok(ForeignItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut mac: Option<Macro> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut mac, FIELDS[1])?,
+                        2 => field(&mut map, &mut semi_token, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let mac = required(mac, FIELDS[1])?;
+                let semi_token = required(semi_token, FIELDS[2])?;
+                Ok(ForeignItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemStatic", 8)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("static_token", &self.static_token)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "static_token",
+            "mutability",
+            "ident",
+            "colon_token",
+            "ty",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let static_token: Token![static] = element(&mut seq, 2, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 3, &self)?;
+                let ident: De<Ident> = element(&mut seq, 4, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 5, &self)?;
+                let ty: Box<Type> = element(&mut seq, 6, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 7, &self)?;
+                Ok(ForeignItemStatic {
+                    attrs,
+                    vis,
+                    static_token,
+                    mutability,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut static_token: Option<Token![static]> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut static_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut mutability, FIELDS[3])?,
+                        4 => field(&mut map, &mut ident, FIELDS[4])?,
+                        5 => field(&mut map, &mut colon_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut ty, FIELDS[6])?,
+                        7 => field(&mut map, &mut semi_token, FIELDS[7])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let static_token = required(static_token, FIELDS[2])?;
+                let mutability = required(mutability, FIELDS[3])?;
+                let ident = required(ident, FIELDS[4])?;
+                let colon_token = required(colon_token, FIELDS[5])?;
+                let ty = required(ty, FIELDS[6])?;
+                let semi_token = required(semi_token, FIELDS[7])?;
+                Ok(ForeignItemStatic {
+                    attrs,
+                    vis,
+                    static_token,
+                    mutability,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ForeignItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ForeignItemType", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("type_token", &self.type_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ForeignItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "type_token", "ident", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ForeignItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ForeignItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let type_token: Token![type] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 4, &self)?;
+                Ok(ForeignItemType {
+                    attrs,
+                    vis,
+                    type_token,
+                    ident: ident.0,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut type_token: Option<Token![type]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut type_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut semi_token, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let type_token = required(type_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let semi_token = required(semi_token, FIELDS[4])?;
+                Ok(ForeignItemType {
+                    attrs,
+                    vis,
+                    type_token,
+                    ident: ident.0,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ForeignItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for GenericArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericArgument::Lifetime(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 0, "Lifetime", _binding_0)
+            }
+            GenericArgument::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 1, "Type", _binding_0)
+            }
+            GenericArgument::Binding(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 2, "Binding", _binding_0)
+            }
+            GenericArgument::Constraint(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 3, "Constraint", _binding_0)
+            }
+            GenericArgument::Const(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericArgument", 4, "Const", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for GenericArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Lifetime", "Type", "Binding", "Constraint", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(GenericArgument::Lifetime),
+                    1 => variant.newtype_variant().map(GenericArgument::Type),
+                    2 => variant.newtype_variant().map(GenericArgument::Binding),
+                    3 => variant.newtype_variant().map(GenericArgument::Constraint),
+                    4 => variant.newtype_variant().map(GenericArgument::Const),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for GenericMethodArgument {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericMethodArgument::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericMethodArgument", 0, "Type", _binding_0)
+            }
+            GenericMethodArgument::Const(_binding_0) => serializer.serialize_newtype_variant(
+                "GenericMethodArgument",
+                1,
+                "Const",
+                _binding_0,
+            ),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for GenericMethodArgument {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericMethodArgument;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericMethodArgument")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(GenericMethodArgument::Type),
+                    1 => variant.newtype_variant().map(GenericMethodArgument::Const),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericMethodArgument", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for GenericParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            GenericParam::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericParam", 0, "Type", _binding_0)
+            }
+            GenericParam::Lifetime(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericParam", 1, "Lifetime", _binding_0)
+            }
+            GenericParam::Const(_binding_0) => {
+                serializer.serialize_newtype_variant("GenericParam", 2, "Const", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for GenericParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Const"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = GenericParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum GenericParam")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(GenericParam::Type),
+                    1 => variant.newtype_variant().map(GenericParam::Lifetime),
+                    2 => variant.newtype_variant().map(GenericParam::Const),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("GenericParam", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Generics {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Generics", 4)?;
+        state.serialize_field("lt_token", &self.lt_token)?;
+        state.serialize_field("params", &self.params)?;
+        state.serialize_field("gt_token", &self.gt_token)?;
+        state.serialize_field("where_clause", &self.where_clause)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Generics {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lt_token", "params", "gt_token", "where_clause"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Generics;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Generics")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lt_token: Option<Token ! [<]> = element(&mut seq, 0, &self)?;
+                let params: Punctuated<GenericParam, Token ! [,]> = element(&mut seq, 1, &self)?;
+                let gt_token: Option<Token ! [>]> = element(&mut seq, 2, &self)?;
+                let where_clause: Option<WhereClause> = element(&mut seq, 3, &self)?;
+                Ok(Generics {
+                    lt_token,
+                    params,
+                    gt_token,
+                    where_clause,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lt_token: Option<Option<Token ! [<]>> = None;
+                let mut params: Option<Punctuated<GenericParam, Token ! [,]>> = None;
+                let mut gt_token: Option<Option<Token ! [>]>> = None;
+                let mut where_clause: Option<Option<WhereClause>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lt_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut params, FIELDS[1])?,
+                        2 => field(&mut map, &mut gt_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut where_clause, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lt_token = required(lt_token, FIELDS[0])?;
+                let params = required(params, FIELDS[1])?;
+                let gt_token = required(gt_token, FIELDS[2])?;
+                let where_clause = required(where_clause, FIELDS[3])?;
+                Ok(Generics {
+                    lt_token,
+                    params,
+                    gt_token,
+                    where_clause,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Generics", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ImplItem::Const(_binding_0) => {
+                serializer.serialize_newtype_variant("ImplItem", 0, "Const", _binding_0)
+            }
+            ImplItem::Method(_binding_0) => {
+                serializer.serialize_newtype_variant("ImplItem", 1, "Method", _binding_0)
+            }
+            ImplItem::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("ImplItem", 2, "Type", _binding_0)
+            }
+            ImplItem::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("ImplItem", 3, "Macro", _binding_0)
+            }
+            ImplItem::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("ImplItem", 4, "Verbatim", &Ser(_binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ImplItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(ImplItem::Const),
+                    1 => variant.newtype_variant().map(ImplItem::Method),
+                    2 => variant.newtype_variant().map(ImplItem::Type),
+                    3 => variant.newtype_variant().map(ImplItem::Macro),
+                    4 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| ImplItem::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ImplItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemConst", 10)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness)?;
+        state.serialize_field("const_token", &self.const_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "defaultness",
+            "const_token",
+            "ident",
+            "colon_token",
+            "ty",
+            "eq_token",
+            "expr",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let defaultness: Option<Token![default]> = element(&mut seq, 2, &self)?;
+                let const_token: Token![const] = element(&mut seq, 3, &self)?;
+                let ident: De<Ident> = element(&mut seq, 4, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 5, &self)?;
+                let ty: Type = element(&mut seq, 6, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 7, &self)?;
+                let expr: Expr = element(&mut seq, 8, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 9, &self)?;
+                Ok(ImplItemConst {
+                    attrs,
+                    vis,
+                    defaultness,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut defaultness: Option<Option<Token![default]>> = None;
+                let mut const_token: Option<Token![const]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Type> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut expr: Option<Expr> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut defaultness, FIELDS[2])?,
+                        3 => field(&mut map, &mut const_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ident, FIELDS[4])?,
+                        5 => field(&mut map, &mut colon_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut ty, FIELDS[6])?,
+                        7 => field(&mut map, &mut eq_token, FIELDS[7])?,
+                        8 => field(&mut map, &mut expr, FIELDS[8])?,
+                        9 => field(&mut map, &mut semi_token, FIELDS[9])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let defaultness = required(defaultness, FIELDS[2])?;
+                let const_token = required(const_token, FIELDS[3])?;
+                let ident = required(ident, FIELDS[4])?;
+                let colon_token = required(colon_token, FIELDS[5])?;
+                let ty = required(ty, FIELDS[6])?;
+                let eq_token = required(eq_token, FIELDS[7])?;
+                let expr = required(expr, FIELDS[8])?;
+                let semi_token = required(semi_token, FIELDS[9])?;
+                Ok(ImplItemConst {
+                    attrs,
+                    vis,
+                    defaultness,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMacro", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let mac: Macro = element(&mut seq, 1, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 2, &self)?;
+                Ok(ImplItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut mac: Option<Macro> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut mac, FIELDS[1])?,
+                        2 => field(&mut map, &mut semi_token, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let mac = required(mac, FIELDS[1])?;
+                let semi_token = required(semi_token, FIELDS[2])?;
+                Ok(ImplItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemMethod", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "defaultness", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let defaultness: Option<Token![default]> = element(&mut seq, 2, &self)?;
+                let sig: Signature = element(&mut seq, 3, &self)?;
+                let block: Block = element(&mut seq, 4, &self)?;
+                Ok(ImplItemMethod {
+                    attrs,
+                    vis,
+                    defaultness,
+                    sig,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut defaultness: Option<Option<Token![default]>> = None;
+                let mut sig: Option<Signature> = None;
+                let mut block: Option<Block> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut defaultness, FIELDS[2])?,
+                        3 => field(&mut map, &mut sig, FIELDS[3])?,
+                        4 => field(&mut map, &mut block, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let defaultness = required(defaultness, FIELDS[2])?;
+                let sig = required(sig, FIELDS[3])?;
+                let block = required(block, FIELDS[4])?;
+                Ok(ImplItemMethod {
+                    attrs,
+                    vis,
+                    defaultness,
+                    sig,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ImplItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ImplItemType", 9)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("defaultness", &self.defaultness)?;
+        state.serialize_field("type_token", &self.type_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ImplItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "defaultness",
+            "type_token",
+            "ident",
+            "generics",
+            "eq_token",
+            "ty",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ImplItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ImplItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let defaultness: Option<Token![default]> = element(&mut seq, 2, &self)?;
+                let type_token: Token![type] = element(&mut seq, 3, &self)?;
+                let ident: De<Ident> = element(&mut seq, 4, &self)?;
+                let generics: Generics = element(&mut seq, 5, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 6, &self)?;
+                let ty: Type = element(&mut seq, 7, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 8, &self)?;
+                Ok(ImplItemType {
+                    attrs,
+                    vis,
+                    defaultness,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    ty,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut defaultness: Option<Option<Token![default]>> = None;
+                let mut type_token: Option<Token![type]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut ty: Option<Type> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut defaultness, FIELDS[2])?,
+                        3 => field(&mut map, &mut type_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ident, FIELDS[4])?,
+                        5 => field(&mut map, &mut generics, FIELDS[5])?,
+                        6 => field(&mut map, &mut eq_token, FIELDS[6])?,
+                        7 => field(&mut map, &mut ty, FIELDS[7])?,
+                        8 => field(&mut map, &mut semi_token, FIELDS[8])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let defaultness = required(defaultness, FIELDS[2])?;
+                let type_token = required(type_token, FIELDS[3])?;
+                let ident = required(ident, FIELDS[4])?;
+                let generics = required(generics, FIELDS[5])?;
+                let eq_token = required(eq_token, FIELDS[6])?;
+                let ty = required(ty, FIELDS[7])?;
+                let semi_token = required(semi_token, FIELDS[8])?;
+                Ok(ImplItemType {
+                    attrs,
+                    vis,
+                    defaultness,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    ty,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ImplItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Index {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Index", 1)?;
+        state.serialize_field("index", &self.index)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Index {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["index"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Index;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Index")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let index: u32 = element(&mut seq, 0, &self)?;
+                Ok(Index {
+                    index,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut index: Option<u32> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut index, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let index = required(index, FIELDS[0])?;
+                Ok(Index {
+                    index,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Index", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Item::Const(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 0, "Const", _binding_0)
+            }
+            Item::Enum(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 1, "Enum", _binding_0)
+            }
+            Item::ExternCrate(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 2, "ExternCrate", _binding_0)
+            }
+            Item::Fn(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 3, "Fn", _binding_0)
+            }
+            Item::ForeignMod(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 4, "ForeignMod", _binding_0)
+            }
+            Item::Impl(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 5, "Impl", _binding_0)
+            }
+            Item::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 6, "Macro", _binding_0)
+            }
+            Item::Macro2(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 7, "Macro2", _binding_0)
+            }
+            Item::Mod(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 8, "Mod", _binding_0)
+            }
+            Item::Static(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 9, "Static", _binding_0)
+            }
+            Item::Struct(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 10, "Struct", _binding_0)
+            }
+            Item::Trait(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 11, "Trait", _binding_0)
+            }
+            Item::TraitAlias(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 12, "TraitAlias", _binding_0)
+            }
+            Item::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 13, "Type", _binding_0)
+            }
+            Item::Union(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 14, "Union", _binding_0)
+            }
+            Item::Use(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 15, "Use", _binding_0)
+            }
+            Item::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("Item", 16, "Verbatim", &Ser(_binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Item {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Const",
+            "Enum",
+            "ExternCrate",
+            "Fn",
+            "ForeignMod",
+            "Impl",
+            "Macro",
+            "Macro2",
+            "Mod",
+            "Static",
+            "Struct",
+            "Trait",
+            "TraitAlias",
+            "Type",
+            "Union",
+            "Use",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Item;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Item")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Item::Const),
+                    1 => variant.newtype_variant().map(Item::Enum),
+                    2 => variant.newtype_variant().map(Item::ExternCrate),
+                    3 => variant.newtype_variant().map(Item::Fn),
+                    4 => variant.newtype_variant().map(Item::ForeignMod),
+                    5 => variant.newtype_variant().map(Item::Impl),
+                    6 => variant.newtype_variant().map(Item::Macro),
+                    7 => variant.newtype_variant().map(Item::Macro2),
+                    8 => variant.newtype_variant().map(Item::Mod),
+                    9 => variant.newtype_variant().map(Item::Static),
+                    10 => variant.newtype_variant().map(Item::Struct),
+                    11 => variant.newtype_variant().map(Item::Trait),
+                    12 => variant.newtype_variant().map(Item::TraitAlias),
+                    13 => variant.newtype_variant().map(Item::Type),
+                    14 => variant.newtype_variant().map(Item::Union),
+                    15 => variant.newtype_variant().map(Item::Use),
+                    16 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| Item::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Item", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemConst", 9)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("const_token", &self.const_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "const_token",
+            "ident",
+            "colon_token",
+            "ty",
+            "eq_token",
+            "expr",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let const_token: Token![const] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 4, &self)?;
+                let ty: Box<Type> = element(&mut seq, 5, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 6, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 7, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 8, &self)?;
+                Ok(ItemConst {
+                    attrs,
+                    vis,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut const_token: Option<Token![const]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut const_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut colon_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut ty, FIELDS[5])?,
+                        6 => field(&mut map, &mut eq_token, FIELDS[6])?,
+                        7 => field(&mut map, &mut expr, FIELDS[7])?,
+                        8 => field(&mut map, &mut semi_token, FIELDS[8])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let const_token = required(const_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let colon_token = required(colon_token, FIELDS[4])?;
+                let ty = required(ty, FIELDS[5])?;
+                let eq_token = required(eq_token, FIELDS[6])?;
+                let expr = required(expr, FIELDS[7])?;
+                let semi_token = required(semi_token, FIELDS[8])?;
+                Ok(ItemConst {
+                    attrs,
+                    vis,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemEnum {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemEnum", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("enum_token", &self.enum_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("variants", &self.variants)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemEnum {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "enum_token",
+            "ident",
+            "generics",
+            "brace_token",
+            "variants",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemEnum;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemEnum")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let enum_token: Token![enum] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let brace_token: Brace = element(&mut seq, 5, &self)?;
+                let variants: Punctuated<Variant, Token ! [,]> = element(&mut seq, 6, &self)?;
+                Ok(ItemEnum {
+                    attrs,
+                    vis,
+                    enum_token,
+                    ident: ident.0,
+                    generics,
+                    brace_token,
+                    variants,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut enum_token: Option<Token![enum]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut variants: Option<Punctuated<Variant, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut enum_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut brace_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut variants, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let enum_token = required(enum_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let brace_token = required(brace_token, FIELDS[5])?;
+                let variants = required(variants, FIELDS[6])?;
+                Ok(ItemEnum {
+                    attrs,
+                    vis,
+                    enum_token,
+                    ident: ident.0,
+                    generics,
+                    brace_token,
+                    variants,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemEnum", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemExternCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemExternCrate", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("extern_token", &self.extern_token)?;
+        state.serialize_field("crate_token", &self.crate_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field(
+            "rename",
+            &self.rename.as_ref().map(|it| (&it.0, Ser(&it.1))),
+        )?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemExternCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "extern_token",
+            "crate_token",
+            "ident",
+            "rename",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemExternCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemExternCrate")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let extern_token: Token![extern] = element(&mut seq, 2, &self)?;
+                let crate_token: Token![crate] = element(&mut seq, 3, &self)?;
+                let ident: De<Ident> = element(&mut seq, 4, &self)?;
+                let rename: Option<(Token![as], De<Ident>)> = element(&mut seq, 5, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 6, &self)?;
+                Ok(ItemExternCrate {
+                    attrs,
+                    vis,
+                    extern_token,
+                    crate_token,
+                    ident: ident.0,
+                    rename: rename.map(|it| {
+                        let (_binding_0, _binding_1) = it;
+                        (_binding_0, _binding_1.0)
+                    }),
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut extern_token: Option<Token![extern]> = None;
+                let mut crate_token: Option<Token![crate]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut rename: Option<Option<(Token![as], De<Ident>)>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut extern_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut crate_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ident, FIELDS[4])?,
+                        5 => field(&mut map, &mut rename, FIELDS[5])?,
+                        6 => field(&mut map, &mut semi_token, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let extern_token = required(extern_token, FIELDS[2])?;
+                let crate_token = required(crate_token, FIELDS[3])?;
+                let ident = required(ident, FIELDS[4])?;
+                let rename = required(rename, FIELDS[5])?;
+                let semi_token = required(semi_token, FIELDS[6])?;
+                Ok(ItemExternCrate {
+                    attrs,
+                    vis,
+                    extern_token,
+                    crate_token,
+                    ident: ident.0,
+                    rename: rename.map(|it| {
+                        let (_binding_0, _binding_1) = it;
+                        (_binding_0, _binding_1.0)
+                    }),
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemExternCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemFn", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("block", &self.block)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "sig", "block"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let sig: Signature = element(&mut seq, 2, &self)?;
+                let block: Box<Block> = element(&mut seq, 3, &self)?;
+                Ok(ItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    block,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut sig: Option<Signature> = None;
+                let mut block: Option<Box<Block>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut sig, FIELDS[2])?,
+                        3 => field(&mut map, &mut block, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let sig = required(sig, FIELDS[2])?;
+                let block = required(block, FIELDS[3])?;
+                Ok(ItemFn {
+                    attrs,
+                    vis,
+                    sig,
+                    block,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemFn", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemForeignMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemForeignMod", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemForeignMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "abi", "brace_token", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemForeignMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemForeignMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let abi: Abi = element(&mut seq, 1, &self)?;
+                let brace_token: Brace = element(&mut seq, 2, &self)?;
+                let items: Vec<ForeignItem> = element(&mut seq, 3, &self)?;
+                Ok(ItemForeignMod {
+                    attrs,
+                    abi,
+                    brace_token,
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut abi: Option<Abi> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut items: Option<Vec<ForeignItem>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut abi, FIELDS[1])?,
+                        2 => field(&mut map, &mut brace_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut items, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let abi = required(abi, FIELDS[1])?;
+                let brace_token = required(brace_token, FIELDS[2])?;
+                let items = required(items, FIELDS[3])?;
+                Ok(ItemForeignMod {
+                    attrs,
+                    abi,
+                    brace_token,
+                    items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemForeignMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemImpl {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemImpl", 9)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("defaultness", &self.defaultness)?;
+        state.serialize_field("unsafety", &self.unsafety)?;
+        state.serialize_field("impl_token", &self.impl_token)?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("trait_", &self.trait_)?;
+        state.serialize_field("self_ty", &self.self_ty)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemImpl {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "defaultness",
+            "unsafety",
+            "impl_token",
+            "generics",
+            "trait_",
+            "self_ty",
+            "brace_token",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemImpl;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemImpl")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let defaultness: Option<Token![default]> = element(&mut seq, 1, &self)?;
+                let unsafety: Option<Token![unsafe]> = element(&mut seq, 2, &self)?;
+                let impl_token: Token![impl] = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let trait_: Option<(Option<Token![!]>, Path, Token![for])> =
+                    element(&mut seq, 5, &self)?;
+                let self_ty: Box<Type> = element(&mut seq, 6, &self)?;
+                let brace_token: Brace = element(&mut seq, 7, &self)?;
+                let items: Vec<ImplItem> = element(&mut seq, 8, &self)?;
+                Ok(ItemImpl {
+                    attrs,
+                    defaultness,
+                    unsafety,
+                    impl_token,
+                    generics,
+                    trait_,
+                    self_ty,
+                    brace_token,
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut defaultness: Option<Option<Token![default]>> = None;
+                let mut unsafety: Option<Option<Token![unsafe]>> = None;
+                let mut impl_token: Option<Token![impl]> = None;
+                let mut generics: Option<Generics> = None;
+                let mut trait_: Option<Option<(Option<Token![!]>, Path, Token![for])>> = None;
+                let mut self_ty: Option<Box<Type>> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut items: Option<Vec<ImplItem>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut defaultness, FIELDS[1])?,
+                        2 => field(&mut map, &mut unsafety, FIELDS[2])?,
+                        3 => field(&mut map, &mut impl_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut trait_, FIELDS[5])?,
+                        6 => field(&mut map, &mut self_ty, FIELDS[6])?,
+                        7 => field(&mut map, &mut brace_token, FIELDS[7])?,
+                        8 => field(&mut map, &mut items, FIELDS[8])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let defaultness = required(defaultness, FIELDS[1])?;
+                let unsafety = required(unsafety, FIELDS[2])?;
+                let impl_token = required(impl_token, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let trait_ = required(trait_, FIELDS[5])?;
+                let self_ty = required(self_ty, FIELDS[6])?;
+                let brace_token = required(brace_token, FIELDS[7])?;
+                let items = required(items, FIELDS[8])?;
+                Ok(ItemImpl {
+                    attrs,
+                    defaultness,
+                    unsafety,
+                    impl_token,
+                    generics,
+                    trait_,
+                    self_ty,
+                    brace_token,
+                    items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemImpl", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &self.ident.as_ref().map(Ser))?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let ident: Option<De<Ident>> = element(&mut seq, 1, &self)?;
+                let mac: Macro = element(&mut seq, 2, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 3, &self)?;
+                Ok(ItemMacro {
+                    attrs,
+                    ident: ident.map(|it| it.0),
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut ident: Option<Option<De<Ident>>> = None;
+                let mut mac: Option<Macro> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut ident, FIELDS[1])?,
+                        2 => field(&mut map, &mut mac, FIELDS[2])?,
+                        3 => field(&mut map, &mut semi_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let ident = required(ident, FIELDS[1])?;
+                let mac = required(mac, FIELDS[2])?;
+                let semi_token = required(semi_token, FIELDS[3])?;
+                Ok(ItemMacro {
+                    attrs,
+                    ident: ident.map(|it| it.0),
+                    mac,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMacro2 {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMacro2", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("macro_token", &self.macro_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("rules", &Ser(&self.rules))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMacro2 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "macro_token", "ident", "rules"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMacro2;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMacro2")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let macro_token: Token![macro] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let rules: De<TokenStream> = element(&mut seq, 4, &self)?;
+                Ok(ItemMacro2 {
+                    attrs,
+                    vis,
+                    macro_token,
+                    ident: ident.0,
+                    rules: rules.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut macro_token: Option<Token![macro]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut rules: Option<De<TokenStream>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut macro_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut rules, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let macro_token = required(macro_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let rules = required(rules, FIELDS[4])?;
+                Ok(ItemMacro2 {
+                    attrs,
+                    vis,
+                    macro_token,
+                    ident: ident.0,
+                    rules: rules.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMacro2", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemMod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemMod", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("mod_token", &self.mod_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("content", &self.content)?;
+        state.serialize_field("semi", &self.semi)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemMod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "mod_token", "ident", "content", "semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemMod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemMod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let mod_token: Token![mod] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let content: Option<(Brace, Vec<Item>)> = element(&mut seq, 4, &self)?;
+                let semi: Option<Token ! [;]> = element(&mut seq, 5, &self)?;
+                Ok(ItemMod {
+                    attrs,
+                    vis,
+                    mod_token,
+                    ident: ident.0,
+                    content,
+                    semi,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut mod_token: Option<Token![mod]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut content: Option<Option<(Brace, Vec<Item>)>> = None;
+                let mut semi: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut mod_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut content, FIELDS[4])?,
+                        5 => field(&mut map, &mut semi, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let mod_token = required(mod_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let content = required(content, FIELDS[4])?;
+                let semi = required(semi, FIELDS[5])?;
+                Ok(ItemMod {
+                    attrs,
+                    vis,
+                    mod_token,
+                    ident: ident.0,
+                    content,
+                    semi,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemMod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemStatic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStatic", 10)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("static_token", &self.static_token)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemStatic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "static_token",
+            "mutability",
+            "ident",
+            "colon_token",
+            "ty",
+            "eq_token",
+            "expr",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStatic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStatic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let static_token: Token![static] = element(&mut seq, 2, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 3, &self)?;
+                let ident: De<Ident> = element(&mut seq, 4, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 5, &self)?;
+                let ty: Box<Type> = element(&mut seq, 6, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 7, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 8, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 9, &self)?;
+                Ok(ItemStatic {
+                    attrs,
+                    vis,
+                    static_token,
+                    mutability,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut static_token: Option<Token![static]> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut static_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut mutability, FIELDS[3])?,
+                        4 => field(&mut map, &mut ident, FIELDS[4])?,
+                        5 => field(&mut map, &mut colon_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut ty, FIELDS[6])?,
+                        7 => field(&mut map, &mut eq_token, FIELDS[7])?,
+                        8 => field(&mut map, &mut expr, FIELDS[8])?,
+                        9 => field(&mut map, &mut semi_token, FIELDS[9])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let static_token = required(static_token, FIELDS[2])?;
+                let mutability = required(mutability, FIELDS[3])?;
+                let ident = required(ident, FIELDS[4])?;
+                let colon_token = required(colon_token, FIELDS[5])?;
+                let ty = required(ty, FIELDS[6])?;
+                let eq_token = required(eq_token, FIELDS[7])?;
+                let expr = required(expr, FIELDS[8])?;
+                let semi_token = required(semi_token, FIELDS[9])?;
+                Ok(ItemStatic {
+                    attrs,
+                    vis,
+                    static_token,
+                    mutability,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    eq_token,
+                    expr,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStatic", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemStruct", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("struct_token", &self.struct_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "struct_token",
+            "ident",
+            "generics",
+            "fields",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let struct_token: Token![struct] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let fields: Fields = element(&mut seq, 5, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 6, &self)?;
+                Ok(ItemStruct {
+                    attrs,
+                    vis,
+                    struct_token,
+                    ident: ident.0,
+                    generics,
+                    fields,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut struct_token: Option<Token![struct]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut fields: Option<Fields> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut struct_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut fields, FIELDS[5])?,
+                        6 => field(&mut map, &mut semi_token, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let struct_token = required(struct_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let fields = required(fields, FIELDS[5])?;
+                let semi_token = required(semi_token, FIELDS[6])?;
+                Ok(ItemStruct {
+                    attrs,
+                    vis,
+                    struct_token,
+                    ident: ident.0,
+                    generics,
+                    fields,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTrait", 11)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("unsafety", &self.unsafety)?;
+        state.serialize_field("auto_token", &self.auto_token)?;
+        state.serialize_field("trait_token", &self.trait_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("supertraits", &self.supertraits)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "unsafety",
+            "auto_token",
+            "trait_token",
+            "ident",
+            "generics",
+            "colon_token",
+            "supertraits",
+            "brace_token",
+            "items",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let unsafety: Option<Token![unsafe]> = element(&mut seq, 2, &self)?;
+                let auto_token: Option<Token![auto]> = element(&mut seq, 3, &self)?;
+                let trait_token: Token![trait] = element(&mut seq, 4, &self)?;
+                let ident: De<Ident> = element(&mut seq, 5, &self)?;
+                let generics: Generics = element(&mut seq, 6, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 7, &self)?;
+                let supertraits: Punctuated<TypeParamBound, Token ! [+]> =
+                    element(&mut seq, 8, &self)?;
+                let brace_token: Brace = element(&mut seq, 9, &self)?;
+                let items: Vec<TraitItem> = element(&mut seq, 10, &self)?;
+                Ok(ItemTrait {
+                    attrs,
+                    vis,
+                    unsafety,
+                    auto_token,
+                    trait_token,
+                    ident: ident.0,
+                    generics,
+                    colon_token,
+                    supertraits,
+                    brace_token,
+                    items,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut unsafety: Option<Option<Token![unsafe]>> = None;
+                let mut auto_token: Option<Option<Token![auto]>> = None;
+                let mut trait_token: Option<Token![trait]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut supertraits: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut items: Option<Vec<TraitItem>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut unsafety, FIELDS[2])?,
+                        3 => field(&mut map, &mut auto_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut trait_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut ident, FIELDS[5])?,
+                        6 => field(&mut map, &mut generics, FIELDS[6])?,
+                        7 => field(&mut map, &mut colon_token, FIELDS[7])?,
+                        8 => field(&mut map, &mut supertraits, FIELDS[8])?,
+                        9 => field(&mut map, &mut brace_token, FIELDS[9])?,
+                        10 => field(&mut map, &mut items, FIELDS[10])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let unsafety = required(unsafety, FIELDS[2])?;
+                let auto_token = required(auto_token, FIELDS[3])?;
+                let trait_token = required(trait_token, FIELDS[4])?;
+                let ident = required(ident, FIELDS[5])?;
+                let generics = required(generics, FIELDS[6])?;
+                let colon_token = required(colon_token, FIELDS[7])?;
+                let supertraits = required(supertraits, FIELDS[8])?;
+                let brace_token = required(brace_token, FIELDS[9])?;
+                let items = required(items, FIELDS[10])?;
+                Ok(ItemTrait {
+                    attrs,
+                    vis,
+                    unsafety,
+                    auto_token,
+                    trait_token,
+                    ident: ident.0,
+                    generics,
+                    colon_token,
+                    supertraits,
+                    brace_token,
+                    items,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemTraitAlias {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemTraitAlias", 8)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("trait_token", &self.trait_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemTraitAlias {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "trait_token",
+            "ident",
+            "generics",
+            "eq_token",
+            "bounds",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemTraitAlias;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemTraitAlias")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let trait_token: Token![trait] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 5, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 6, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 7, &self)?;
+                Ok(ItemTraitAlias {
+                    attrs,
+                    vis,
+                    trait_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    bounds,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut trait_token: Option<Token![trait]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut trait_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut eq_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut bounds, FIELDS[6])?,
+                        7 => field(&mut map, &mut semi_token, FIELDS[7])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let trait_token = required(trait_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let eq_token = required(eq_token, FIELDS[5])?;
+                let bounds = required(bounds, FIELDS[6])?;
+                let semi_token = required(semi_token, FIELDS[7])?;
+                Ok(ItemTraitAlias {
+                    attrs,
+                    vis,
+                    trait_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    bounds,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemTraitAlias", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemType", 8)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("type_token", &self.type_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "type_token",
+            "ident",
+            "generics",
+            "eq_token",
+            "ty",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let type_token: Token![type] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 5, &self)?;
+                let ty: Box<Type> = element(&mut seq, 6, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 7, &self)?;
+                Ok(ItemType {
+                    attrs,
+                    vis,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    ty,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut type_token: Option<Token![type]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut type_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut eq_token, FIELDS[5])?,
+                        6 => field(&mut map, &mut ty, FIELDS[6])?,
+                        7 => field(&mut map, &mut semi_token, FIELDS[7])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let type_token = required(type_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let eq_token = required(eq_token, FIELDS[5])?;
+                let ty = required(ty, FIELDS[6])?;
+                let semi_token = required(semi_token, FIELDS[7])?;
+                Ok(ItemType {
+                    attrs,
+                    vis,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    eq_token,
+                    ty,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemUnion {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUnion", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("union_token", &self.union_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemUnion {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "vis", "union_token", "ident", "generics", "fields"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUnion;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUnion")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let union_token: Token![union] = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let generics: Generics = element(&mut seq, 4, &self)?;
+                let fields: FieldsNamed = element(&mut seq, 5, &self)?;
+                Ok(ItemUnion {
+                    attrs,
+                    vis,
+                    union_token,
+                    ident: ident.0,
+                    generics,
+                    fields,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut union_token: Option<Token![union]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut fields: Option<FieldsNamed> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut union_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut generics, FIELDS[4])?,
+                        5 => field(&mut map, &mut fields, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let union_token = required(union_token, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let generics = required(generics, FIELDS[4])?;
+                let fields = required(fields, FIELDS[5])?;
+                Ok(ItemUnion {
+                    attrs,
+                    vis,
+                    union_token,
+                    ident: ident.0,
+                    generics,
+                    fields,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUnion", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for ItemUse {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ItemUse", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("vis", &self.vis)?;
+        state.serialize_field("use_token", &self.use_token)?;
+        state.serialize_field("leading_colon", &self.leading_colon)?;
+        state.serialize_field("tree", &self.tree)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for ItemUse {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "vis",
+            "use_token",
+            "leading_colon",
+            "tree",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ItemUse;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ItemUse")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let vis: Visibility = element(&mut seq, 1, &self)?;
+                let use_token: Token![use] = element(&mut seq, 2, &self)?;
+                let leading_colon: Option<Token ! [::]> = element(&mut seq, 3, &self)?;
+                let tree: UseTree = element(&mut seq, 4, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 5, &self)?;
+                Ok(ItemUse {
+                    attrs,
+                    vis,
+                    use_token,
+                    leading_colon,
+                    tree,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut vis: Option<Visibility> = None;
+                let mut use_token: Option<Token![use]> = None;
+                let mut leading_colon: Option<Option<Token ! [::]>> = None;
+                let mut tree: Option<UseTree> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut vis, FIELDS[1])?,
+                        2 => field(&mut map, &mut use_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut leading_colon, FIELDS[3])?,
+                        4 => field(&mut map, &mut tree, FIELDS[4])?,
+                        5 => field(&mut map, &mut semi_token, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let vis = required(vis, FIELDS[1])?;
+                let use_token = required(use_token, FIELDS[2])?;
+                let leading_colon = required(leading_colon, FIELDS[3])?;
+                let tree = required(tree, FIELDS[4])?;
+                let semi_token = required(semi_token, FIELDS[5])?;
+                Ok(ItemUse {
+                    attrs,
+                    vis,
+                    use_token,
+                    leading_colon,
+                    tree,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ItemUse", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Label {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Label", 2)?;
+        state.serialize_field("name", &self.name)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["name", "colon_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Label;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Label")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let name: Lifetime = element(&mut seq, 0, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 1, &self)?;
+                Ok(Label { name, colon_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut name: Option<Lifetime> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut name, FIELDS[0])?,
+                        1 => field(&mut map, &mut colon_token, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let name = required(name, FIELDS[0])?;
+                let colon_token = required(colon_token, FIELDS[1])?;
+                Ok(Label { name, colon_token })
+            }
+        }
+        deserializer.deserialize_struct("Label", FIELDS, Visitor)
+    }
+}
+impl Serialize for Lifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Lifetime", 1)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.end()
+    }
+}
+impl<'de> Deserialize<'de> for Lifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Lifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Lifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                Ok(Lifetime {
+                    ident: ident.0,
+                    apostrophe: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                Ok(Lifetime {
+                    ident: ident.0,
+                    apostrophe: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("Lifetime", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for LifetimeDef {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LifetimeDef", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for LifetimeDef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lifetime", "colon_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LifetimeDef;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LifetimeDef")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let lifetime: Lifetime = element(&mut seq, 1, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 2, &self)?;
+                let bounds: Punctuated<Lifetime, Token ! [+]> = element(&mut seq, 3, &self)?;
+                Ok(LifetimeDef {
+                    attrs,
+                    lifetime,
+                    colon_token,
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut lifetime: Option<Lifetime> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut bounds: Option<Punctuated<Lifetime, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut lifetime, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut bounds, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let lifetime = required(lifetime, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let bounds = required(bounds, FIELDS[3])?;
+                Ok(LifetimeDef {
+                    attrs,
+                    lifetime,
+                    colon_token,
+                    bounds,
+                })
+            }
+        }
+        deserializer.deserialize_struct("LifetimeDef", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Lit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Lit::Str(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 0, "Str", _binding_0)
+            }
+            Lit::ByteStr(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 1, "ByteStr", _binding_0)
+            }
+            Lit::Byte(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 2, "Byte", _binding_0)
+            }
+            Lit::Char(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 3, "Char", _binding_0)
+            }
+            Lit::Int(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 4, "Int", _binding_0)
+            }
+            Lit::Float(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 5, "Float", _binding_0)
+            }
+            Lit::Bool(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 6, "Bool", _binding_0)
+            }
+            Lit::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("Lit", 7, "Verbatim", &Ser(_binding_0))
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Lit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Str", "ByteStr", "Byte", "Char", "Int", "Float", "Bool", "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Lit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Lit")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Lit::Str),
+                    1 => variant.newtype_variant().map(Lit::ByteStr),
+                    2 => variant.newtype_variant().map(Lit::Byte),
+                    3 => variant.newtype_variant().map(Lit::Char),
+                    4 => variant.newtype_variant().map(Lit::Int),
+                    5 => variant.newtype_variant().map(Lit::Float),
+                    6 => variant.newtype_variant().map(Lit::Bool),
+                    7 => variant
+                        .newtype_variant::<De<Literal>>()
+                        .map(|it| Lit::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Lit", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for LitBool {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("LitBool", 1)?;
+        state.serialize_field("value", &self.value)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for LitBool {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["value"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = LitBool;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct LitBool")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let value: bool = element(&mut seq, 0, &self)?;
+                Ok(LitBool {
+                    value,
+                    span: Span::call_site(),
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut value: Option<bool> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut value, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let value = required(value, FIELDS[0])?;
+                Ok(LitBool {
+                    value,
+                    span: Span::call_site(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("LitBool", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Local {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Local", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("let_token", &self.let_token)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("init", &self.init)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Local {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "let_token", "pat", "init", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Local;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Local")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let let_token: Token![let] = element(&mut seq, 1, &self)?;
+                let pat: Pat = element(&mut seq, 2, &self)?;
+                let init: Option<(Token ! [=], Box<Expr>)> = element(&mut seq, 3, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 4, &self)?;
+                Ok(Local {
+                    attrs,
+                    let_token,
+                    pat,
+                    init,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut let_token: Option<Token![let]> = None;
+                let mut pat: Option<Pat> = None;
+                let mut init: Option<Option<(Token ! [=], Box<Expr>)>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut let_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut pat, FIELDS[2])?,
+                        3 => field(&mut map, &mut init, FIELDS[3])?,
+                        4 => field(&mut map, &mut semi_token, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let let_token = required(let_token, FIELDS[1])?;
+                let pat = required(pat, FIELDS[2])?;
+                let init = required(init, FIELDS[3])?;
+                let semi_token = required(semi_token, FIELDS[4])?;
+                Ok(Local {
+                    attrs,
+                    let_token,
+                    pat,
+                    init,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Local", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Macro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Macro", 4)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("bang_token", &self.bang_token)?;
+        state.serialize_field("delimiter", &self.delimiter)?;
+        state.serialize_field("tokens", &Ser(&self.tokens))?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Macro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "bang_token", "delimiter", "tokens"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Macro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Macro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path: Path = element(&mut seq, 0, &self)?;
+                let bang_token: Token![!] = element(&mut seq, 1, &self)?;
+                let delimiter: MacroDelimiter = element(&mut seq, 2, &self)?;
+                let tokens: De<TokenStream> = element(&mut seq, 3, &self)?;
+                Ok(Macro {
+                    path,
+                    bang_token,
+                    delimiter,
+                    tokens: tokens.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path: Option<Path> = None;
+                let mut bang_token: Option<Token![!]> = None;
+                let mut delimiter: Option<MacroDelimiter> = None;
+                let mut tokens: Option<De<TokenStream>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut path, FIELDS[0])?,
+                        1 => field(&mut map, &mut bang_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut delimiter, FIELDS[2])?,
+                        3 => field(&mut map, &mut tokens, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let path = required(path, FIELDS[0])?;
+                let bang_token = required(bang_token, FIELDS[1])?;
+                let delimiter = required(delimiter, FIELDS[2])?;
+                let tokens = required(tokens, FIELDS[3])?;
+                Ok(Macro {
+                    path,
+                    bang_token,
+                    delimiter,
+                    tokens: tokens.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Macro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MacroDelimiter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            MacroDelimiter::Paren(_binding_0) => {
+                serializer.serialize_newtype_variant("MacroDelimiter", 0, "Paren", _binding_0)
+            }
+            MacroDelimiter::Brace(_binding_0) => {
+                serializer.serialize_newtype_variant("MacroDelimiter", 1, "Brace", _binding_0)
+            }
+            MacroDelimiter::Bracket(_binding_0) => {
+                serializer.serialize_newtype_variant("MacroDelimiter", 2, "Bracket", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MacroDelimiter {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Paren", "Brace", "Bracket"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MacroDelimiter;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum MacroDelimiter")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(MacroDelimiter::Paren),
+                    1 => variant.newtype_variant().map(MacroDelimiter::Brace),
+                    2 => variant.newtype_variant().map(MacroDelimiter::Bracket),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("MacroDelimiter", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Member {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Member::Named(_binding_0) => {
+                serializer.serialize_newtype_variant("Member", 0, "Named", &Ser(_binding_0))
+            }
+            Member::Unnamed(_binding_0) => {
+                serializer.serialize_newtype_variant("Member", 1, "Unnamed", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Member {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Named", "Unnamed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Member;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Member")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant
+                        .newtype_variant::<De<Ident>>()
+                        .map(|it| Member::Named(it.0)),
+                    1 => variant.newtype_variant().map(Member::Unnamed),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Member", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Meta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Meta::Path(_binding_0) => {
+                serializer.serialize_newtype_variant("Meta", 0, "Path", _binding_0)
+            }
+            Meta::List(_binding_0) => {
+                serializer.serialize_newtype_variant("Meta", 1, "List", _binding_0)
+            }
+            Meta::NameValue(_binding_0) => {
+                serializer.serialize_newtype_variant("Meta", 2, "NameValue", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Meta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "List", "NameValue"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Meta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Meta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Meta::Path),
+                    1 => variant.newtype_variant().map(Meta::List),
+                    2 => variant.newtype_variant().map(Meta::NameValue),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Meta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaList {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaList", 3)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("nested", &self.nested)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaList {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "paren_token", "nested"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaList;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaList")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path: Path = element(&mut seq, 0, &self)?;
+                let paren_token: Paren = element(&mut seq, 1, &self)?;
+                let nested: Punctuated<NestedMeta, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(MetaList {
+                    path,
+                    paren_token,
+                    nested,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path: Option<Path> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut nested: Option<Punctuated<NestedMeta, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut path, FIELDS[0])?,
+                        1 => field(&mut map, &mut paren_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut nested, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let path = required(path, FIELDS[0])?;
+                let paren_token = required(paren_token, FIELDS[1])?;
+                let nested = required(nested, FIELDS[2])?;
+                Ok(MetaList {
+                    path,
+                    paren_token,
+                    nested,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaList", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for MetaNameValue {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MetaNameValue", 3)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("lit", &self.lit)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for MetaNameValue {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["path", "eq_token", "lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MetaNameValue;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MetaNameValue")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let path: Path = element(&mut seq, 0, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 1, &self)?;
+                let lit: Lit = element(&mut seq, 2, &self)?;
+                Ok(MetaNameValue {
+                    path,
+                    eq_token,
+                    lit,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut path: Option<Path> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut lit: Option<Lit> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut path, FIELDS[0])?,
+                        1 => field(&mut map, &mut eq_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut lit, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let path = required(path, FIELDS[0])?;
+                let eq_token = required(eq_token, FIELDS[1])?;
+                let lit = required(lit, FIELDS[2])?;
+                Ok(MetaNameValue {
+                    path,
+                    eq_token,
+                    lit,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MetaNameValue", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for MethodTurbofish {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("MethodTurbofish", 4)?;
+        state.serialize_field("colon2_token", &self.colon2_token)?;
+        state.serialize_field("lt_token", &self.lt_token)?;
+        state.serialize_field("args", &self.args)?;
+        state.serialize_field("gt_token", &self.gt_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for MethodTurbofish {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["colon2_token", "lt_token", "args", "gt_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = MethodTurbofish;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct MethodTurbofish")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let colon2_token: Token ! [::] = element(&mut seq, 0, &self)?;
+                let lt_token: Token ! [<] = element(&mut seq, 1, &self)?;
+                let args: Punctuated<GenericMethodArgument, Token ! [,]> =
+                    element(&mut seq, 2, &self)?;
+                let gt_token: Token ! [>] = element(&mut seq, 3, &self)?;
+                Ok(MethodTurbofish {
+                    colon2_token,
+                    lt_token,
+                    args,
+                    gt_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut colon2_token: Option<Token ! [::]> = None;
+                let mut lt_token: Option<Token ! [<]> = None;
+                let mut args: Option<Punctuated<GenericMethodArgument, Token ! [,]>> = None;
+                let mut gt_token: Option<Token ! [>]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut colon2_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut lt_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut args, FIELDS[2])?,
+                        3 => field(&mut map, &mut gt_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let colon2_token = required(colon2_token, FIELDS[0])?;
+                let lt_token = required(lt_token, FIELDS[1])?;
+                let args = required(args, FIELDS[2])?;
+                let gt_token = required(gt_token, FIELDS[3])?;
+                Ok(MethodTurbofish {
+                    colon2_token,
+                    lt_token,
+                    args,
+                    gt_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("MethodTurbofish", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for NestedMeta {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            NestedMeta::Meta(_binding_0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 0, "Meta", _binding_0)
+            }
+            NestedMeta::Lit(_binding_0) => {
+                serializer.serialize_newtype_variant("NestedMeta", 1, "Lit", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for NestedMeta {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Meta", "Lit"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = NestedMeta;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum NestedMeta")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(NestedMeta::Meta),
+                    1 => variant.newtype_variant().map(NestedMeta::Lit),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("NestedMeta", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ParenthesizedGenericArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("ParenthesizedGenericArguments", 3)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ParenthesizedGenericArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "inputs", "output"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ParenthesizedGenericArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct ParenthesizedGenericArguments")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token: Paren = element(&mut seq, 0, &self)?;
+                let inputs: Punctuated<Type, Token ! [,]> = element(&mut seq, 1, &self)?;
+                let output: ReturnType = element(&mut seq, 2, &self)?;
+                Ok(ParenthesizedGenericArguments {
+                    paren_token,
+                    inputs,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token: Option<Paren> = None;
+                let mut inputs: Option<Punctuated<Type, Token ! [,]>> = None;
+                let mut output: Option<ReturnType> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut paren_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut inputs, FIELDS[1])?,
+                        2 => field(&mut map, &mut output, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let paren_token = required(paren_token, FIELDS[0])?;
+                let inputs = required(inputs, FIELDS[1])?;
+                let output = required(output, FIELDS[2])?;
+                Ok(ParenthesizedGenericArguments {
+                    paren_token,
+                    inputs,
+                    output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("ParenthesizedGenericArguments", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Pat {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Pat::Box(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 0, "Box", _binding_0)
+            }
+            Pat::Ident(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 1, "Ident", _binding_0)
+            }
+            Pat::Lit(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 2, "Lit", _binding_0)
+            }
+            Pat::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 3, "Macro", _binding_0)
+            }
+            Pat::Or(_binding_0) => serializer.serialize_newtype_variant("Pat", 4, "Or", _binding_0),
+            Pat::Path(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 5, "Path", _binding_0)
+            }
+            Pat::Range(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 6, "Range", _binding_0)
+            }
+            Pat::Reference(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 7, "Reference", _binding_0)
+            }
+            Pat::Rest(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 8, "Rest", _binding_0)
+            }
+            Pat::Slice(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 9, "Slice", _binding_0)
+            }
+            Pat::Struct(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 10, "Struct", _binding_0)
+            }
+            Pat::Tuple(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 11, "Tuple", _binding_0)
+            }
+            Pat::TupleStruct(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 12, "TupleStruct", _binding_0)
+            }
+            Pat::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 13, "Type", _binding_0)
+            }
+            Pat::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 14, "Verbatim", &Ser(_binding_0))
+            }
+            Pat::Wild(_binding_0) => {
+                serializer.serialize_newtype_variant("Pat", 15, "Wild", _binding_0)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Pat {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Box",
+            "Ident",
+            "Lit",
+            "Macro",
+            "Or",
+            "Path",
+            "Range",
+            "Reference",
+            "Rest",
+            "Slice",
+            "Struct",
+            "Tuple",
+            "TupleStruct",
+            "Type",
+            "Verbatim",
+            "Wild",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Pat;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Pat")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Pat::Box),
+                    1 => variant.newtype_variant().map(Pat::Ident),
+                    2 => variant.newtype_variant().map(Pat::Lit),
+                    3 => variant.newtype_variant().map(Pat::Macro),
+                    4 => variant.newtype_variant().map(Pat::Or),
+                    5 => variant.newtype_variant().map(Pat::Path),
+                    6 => variant.newtype_variant().map(Pat::Range),
+                    7 => variant.newtype_variant().map(Pat::Reference),
+                    8 => variant.newtype_variant().map(Pat::Rest),
+                    9 => variant.newtype_variant().map(Pat::Slice),
+                    10 => variant.newtype_variant().map(Pat::Struct),
+                    11 => variant.newtype_variant().map(Pat::Tuple),
+                    12 => variant.newtype_variant().map(Pat::TupleStruct),
+                    13 => variant.newtype_variant().map(Pat::Type),
+                    14 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| Pat::Verbatim(it.0)),
+                    15 => variant.newtype_variant().map(Pat::Wild),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Pat", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatBox {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatBox", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("box_token", &self.box_token)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatBox {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "box_token", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatBox;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatBox")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let box_token: Token![box] = element(&mut seq, 1, &self)?;
+                let pat: Box<Pat> = element(&mut seq, 2, &self)?;
+                Ok(PatBox {
+                    attrs,
+                    box_token,
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut box_token: Option<Token![box]> = None;
+                let mut pat: Option<Box<Pat>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut box_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut pat, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let box_token = required(box_token, FIELDS[1])?;
+                let pat = required(pat, FIELDS[2])?;
+                Ok(PatBox {
+                    attrs,
+                    box_token,
+                    pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatBox", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatIdent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatIdent", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("by_ref", &self.by_ref)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("subpat", &self.subpat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatIdent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "by_ref", "mutability", "ident", "subpat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatIdent;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatIdent")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let by_ref: Option<Token![ref]> = element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let ident: De<Ident> = element(&mut seq, 3, &self)?;
+                let subpat: Option<(Token ! [@], Box<Pat>)> = element(&mut seq, 4, &self)?;
+                Ok(PatIdent {
+                    attrs,
+                    by_ref,
+                    mutability,
+                    ident: ident.0,
+                    subpat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut by_ref: Option<Option<Token![ref]>> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut subpat: Option<Option<(Token ! [@], Box<Pat>)>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut by_ref, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut ident, FIELDS[3])?,
+                        4 => field(&mut map, &mut subpat, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let by_ref = required(by_ref, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let ident = required(ident, FIELDS[3])?;
+                let subpat = required(subpat, FIELDS[4])?;
+                Ok(PatIdent {
+                    attrs,
+                    by_ref,
+                    mutability,
+                    ident: ident.0,
+                    subpat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatIdent", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatLit {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatLit", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("expr", &self.expr)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatLit {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "expr"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatLit;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatLit")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let expr: Box<Expr> = element(&mut seq, 1, &self)?;
+                Ok(PatLit { attrs, expr })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut expr: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut expr, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let expr = required(expr, FIELDS[1])?;
+                Ok(PatLit { attrs, expr })
+            }
+        }
+        deserializer.deserialize_struct("PatLit", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatMacro", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let mac: Macro = element(&mut seq, 1, &self)?;
+                Ok(PatMacro { attrs, mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut mac: Option<Macro> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut mac, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let mac = required(mac, FIELDS[1])?;
+                Ok(PatMacro { attrs, mac })
+            }
+        }
+        deserializer.deserialize_struct("PatMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatOr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatOr", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("leading_vert", &self.leading_vert)?;
+        state.serialize_field("cases", &self.cases)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatOr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "leading_vert", "cases"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatOr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatOr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let leading_vert: Option<Token ! [|]> = element(&mut seq, 1, &self)?;
+                let cases: Punctuated<Pat, Token ! [|]> = element(&mut seq, 2, &self)?;
+                Ok(PatOr {
+                    attrs,
+                    leading_vert,
+                    cases,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut leading_vert: Option<Option<Token ! [|]>> = None;
+                let mut cases: Option<Punctuated<Pat, Token ! [|]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut leading_vert, FIELDS[1])?,
+                        2 => field(&mut map, &mut cases, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let leading_vert = required(leading_vert, FIELDS[1])?;
+                let cases = required(cases, FIELDS[2])?;
+                Ok(PatOr {
+                    attrs,
+                    leading_vert,
+                    cases,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatOr", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatPath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatPath", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatPath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatPath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatPath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let qself: Option<QSelf> = element(&mut seq, 1, &self)?;
+                let path: Path = element(&mut seq, 2, &self)?;
+                Ok(PatPath { attrs, qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut qself: Option<Option<QSelf>> = None;
+                let mut path: Option<Path> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut qself, FIELDS[1])?,
+                        2 => field(&mut map, &mut path, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let qself = required(qself, FIELDS[1])?;
+                let path = required(path, FIELDS[2])?;
+                Ok(PatPath { attrs, qself, path })
+            }
+        }
+        deserializer.deserialize_struct("PatPath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatRange {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRange", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("lo", &self.lo)?;
+        state.serialize_field("limits", &self.limits)?;
+        state.serialize_field("hi", &self.hi)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatRange {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "lo", "limits", "hi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRange;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRange")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let lo: Box<Expr> = element(&mut seq, 1, &self)?;
+                let limits: RangeLimits = element(&mut seq, 2, &self)?;
+                let hi: Box<Expr> = element(&mut seq, 3, &self)?;
+                Ok(PatRange {
+                    attrs,
+                    lo,
+                    limits,
+                    hi,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut lo: Option<Box<Expr>> = None;
+                let mut limits: Option<RangeLimits> = None;
+                let mut hi: Option<Box<Expr>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut lo, FIELDS[1])?,
+                        2 => field(&mut map, &mut limits, FIELDS[2])?,
+                        3 => field(&mut map, &mut hi, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let lo = required(lo, FIELDS[1])?;
+                let limits = required(limits, FIELDS[2])?;
+                let hi = required(hi, FIELDS[3])?;
+                Ok(PatRange {
+                    attrs,
+                    lo,
+                    limits,
+                    hi,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatRange", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatReference", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("and_token", &self.and_token)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "and_token", "mutability", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let and_token: Token ! [&] = element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let pat: Box<Pat> = element(&mut seq, 3, &self)?;
+                Ok(PatReference {
+                    attrs,
+                    and_token,
+                    mutability,
+                    pat,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut and_token: Option<Token ! [&]> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut pat: Option<Box<Pat>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut and_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut pat, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let and_token = required(and_token, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let pat = required(pat, FIELDS[3])?;
+                Ok(PatReference {
+                    attrs,
+                    and_token,
+                    mutability,
+                    pat,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatReference", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatRest {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatRest", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("dot2_token", &self.dot2_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatRest {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "dot2_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatRest;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatRest")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let dot2_token: Token![..] = element(&mut seq, 1, &self)?;
+                Ok(PatRest { attrs, dot2_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut dot2_token: Option<Token![..]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut dot2_token, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let dot2_token = required(dot2_token, FIELDS[1])?;
+                Ok(PatRest { attrs, dot2_token })
+            }
+        }
+        deserializer.deserialize_struct("PatRest", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatSlice", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "bracket_token", "elems"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let bracket_token: Bracket = element(&mut seq, 1, &self)?;
+                let elems: Punctuated<Pat, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(PatSlice {
+                    attrs,
+                    bracket_token,
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut bracket_token: Option<Bracket> = None;
+                let mut elems: Option<Punctuated<Pat, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut bracket_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut elems, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let bracket_token = required(bracket_token, FIELDS[1])?;
+                let elems = required(elems, FIELDS[2])?;
+                Ok(PatSlice {
+                    attrs,
+                    bracket_token,
+                    elems,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatStruct", 5)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("dot2_token", &self.dot2_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "brace_token", "fields", "dot2_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let path: Path = element(&mut seq, 1, &self)?;
+                let brace_token: Brace = element(&mut seq, 2, &self)?;
+                let fields: Punctuated<FieldPat, Token ! [,]> = element(&mut seq, 3, &self)?;
+                let dot2_token: Option<Token![..]> = element(&mut seq, 4, &self)?;
+                Ok(PatStruct {
+                    attrs,
+                    path,
+                    brace_token,
+                    fields,
+                    dot2_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut path: Option<Path> = None;
+                let mut brace_token: Option<Brace> = None;
+                let mut fields: Option<Punctuated<FieldPat, Token ! [,]>> = None;
+                let mut dot2_token: Option<Option<Token![..]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut path, FIELDS[1])?,
+                        2 => field(&mut map, &mut brace_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut fields, FIELDS[3])?,
+                        4 => field(&mut map, &mut dot2_token, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let path = required(path, FIELDS[1])?;
+                let brace_token = required(brace_token, FIELDS[2])?;
+                let fields = required(fields, FIELDS[3])?;
+                let dot2_token = required(dot2_token, FIELDS[4])?;
+                Ok(PatStruct {
+                    attrs,
+                    path,
+                    brace_token,
+                    fields,
+                    dot2_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTuple", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "paren_token", "elems"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let paren_token: Paren = element(&mut seq, 1, &self)?;
+                let elems: Punctuated<Pat, Token ! [,]> = element(&mut seq, 2, &self)?;
+                Ok(PatTuple {
+                    attrs,
+                    paren_token,
+                    elems,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut elems: Option<Punctuated<Pat, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut paren_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut elems, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let paren_token = required(paren_token, FIELDS[1])?;
+                let elems = required(elems, FIELDS[2])?;
+                Ok(PatTuple {
+                    attrs,
+                    paren_token,
+                    elems,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatTupleStruct {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatTupleStruct", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("path", &self.path)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatTupleStruct {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "path", "pat"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatTupleStruct;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatTupleStruct")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let path: Path = element(&mut seq, 1, &self)?;
+                let pat: PatTuple = element(&mut seq, 2, &self)?;
+                Ok(PatTupleStruct { attrs, path, pat })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut path: Option<Path> = None;
+                let mut pat: Option<PatTuple> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut path, FIELDS[1])?,
+                        2 => field(&mut map, &mut pat, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let path = required(path, FIELDS[1])?;
+                let pat = required(pat, FIELDS[2])?;
+                Ok(PatTupleStruct { attrs, path, pat })
+            }
+        }
+        deserializer.deserialize_struct("PatTupleStruct", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatType", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("pat", &self.pat)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "pat", "colon_token", "ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let pat: Box<Pat> = element(&mut seq, 1, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 2, &self)?;
+                let ty: Box<Type> = element(&mut seq, 3, &self)?;
+                Ok(PatType {
+                    attrs,
+                    pat,
+                    colon_token,
+                    ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut pat: Option<Box<Pat>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut pat, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut ty, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let pat = required(pat, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let ty = required(ty, FIELDS[3])?;
+                Ok(PatType {
+                    attrs,
+                    pat,
+                    colon_token,
+                    ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatType", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for PatWild {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PatWild", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("underscore_token", &self.underscore_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for PatWild {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "underscore_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PatWild;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PatWild")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let underscore_token: Token![_] = element(&mut seq, 1, &self)?;
+                Ok(PatWild {
+                    attrs,
+                    underscore_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut underscore_token: Option<Token![_]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut underscore_token, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let underscore_token = required(underscore_token, FIELDS[1])?;
+                Ok(PatWild {
+                    attrs,
+                    underscore_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PatWild", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Path", 2)?;
+        state.serialize_field("leading_colon", &self.leading_colon)?;
+        state.serialize_field("segments", &self.segments)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Path {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["leading_colon", "segments"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Path;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Path")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let leading_colon: Option<Token ! [::]> = element(&mut seq, 0, &self)?;
+                let segments: Punctuated<PathSegment, Token ! [::]> = element(&mut seq, 1, &self)?;
+                Ok(Path {
+                    leading_colon,
+                    segments,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut leading_colon: Option<Option<Token ! [::]>> = None;
+                let mut segments: Option<Punctuated<PathSegment, Token ! [::]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut leading_colon, FIELDS[0])?,
+                        1 => field(&mut map, &mut segments, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let leading_colon = required(leading_colon, FIELDS[0])?;
+                let segments = required(segments, FIELDS[1])?;
+                Ok(Path {
+                    leading_colon,
+                    segments,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Path", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PathArguments {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            PathArguments::None => serializer.serialize_unit_variant("PathArguments", 0, "None"),
+            PathArguments::AngleBracketed(_binding_0) => serializer.serialize_newtype_variant(
+                "PathArguments",
+                1,
+                "AngleBracketed",
+                _binding_0,
+            ),
+            PathArguments::Parenthesized(_binding_0) => serializer.serialize_newtype_variant(
+                "PathArguments",
+                2,
+                "Parenthesized",
+                _binding_0,
+            ),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PathArguments {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "AngleBracketed", "Parenthesized"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathArguments;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum PathArguments")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.unit_variant().map(|()| PathArguments::None),
+                    1 => variant.newtype_variant().map(PathArguments::AngleBracketed),
+                    2 => variant.newtype_variant().map(PathArguments::Parenthesized),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("PathArguments", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PathSegment {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PathSegment", 2)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("arguments", &self.arguments)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PathSegment {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "arguments"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PathSegment;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PathSegment")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                let arguments: PathArguments = element(&mut seq, 1, &self)?;
+                Ok(PathSegment {
+                    ident: ident.0,
+                    arguments,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                let mut arguments: Option<PathArguments> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        1 => field(&mut map, &mut arguments, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                let arguments = required(arguments, FIELDS[1])?;
+                Ok(PathSegment {
+                    ident: ident.0,
+                    arguments,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PathSegment", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateEq {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateEq", 3)?;
+        state.serialize_field("lhs_ty", &self.lhs_ty)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("rhs_ty", &self.rhs_ty)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateEq {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lhs_ty", "eq_token", "rhs_ty"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateEq;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateEq")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lhs_ty: Type = element(&mut seq, 0, &self)?;
+                let eq_token: Token ! [=] = element(&mut seq, 1, &self)?;
+                let rhs_ty: Type = element(&mut seq, 2, &self)?;
+                Ok(PredicateEq {
+                    lhs_ty,
+                    eq_token,
+                    rhs_ty,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lhs_ty: Option<Type> = None;
+                let mut eq_token: Option<Token ! [=]> = None;
+                let mut rhs_ty: Option<Type> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lhs_ty, FIELDS[0])?,
+                        1 => field(&mut map, &mut eq_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut rhs_ty, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lhs_ty = required(lhs_ty, FIELDS[0])?;
+                let eq_token = required(eq_token, FIELDS[1])?;
+                let rhs_ty = required(rhs_ty, FIELDS[2])?;
+                Ok(PredicateEq {
+                    lhs_ty,
+                    eq_token,
+                    rhs_ty,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateEq", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateLifetime {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateLifetime", 3)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateLifetime {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetime", "colon_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateLifetime;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateLifetime")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetime: Lifetime = element(&mut seq, 0, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 1, &self)?;
+                let bounds: Punctuated<Lifetime, Token ! [+]> = element(&mut seq, 2, &self)?;
+                Ok(PredicateLifetime {
+                    lifetime,
+                    colon_token,
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetime: Option<Lifetime> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut bounds: Option<Punctuated<Lifetime, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lifetime, FIELDS[0])?,
+                        1 => field(&mut map, &mut colon_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut bounds, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lifetime = required(lifetime, FIELDS[0])?;
+                let colon_token = required(colon_token, FIELDS[1])?;
+                let bounds = required(bounds, FIELDS[2])?;
+                Ok(PredicateLifetime {
+                    lifetime,
+                    colon_token,
+                    bounds,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateLifetime", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for PredicateType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("PredicateType", 4)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("bounded_ty", &self.bounded_ty)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for PredicateType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lifetimes", "bounded_ty", "colon_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = PredicateType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct PredicateType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetimes: Option<BoundLifetimes> = element(&mut seq, 0, &self)?;
+                let bounded_ty: Type = element(&mut seq, 1, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 2, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 3, &self)?;
+                Ok(PredicateType {
+                    lifetimes,
+                    bounded_ty,
+                    colon_token,
+                    bounds,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes: Option<Option<BoundLifetimes>> = None;
+                let mut bounded_ty: Option<Type> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lifetimes, FIELDS[0])?,
+                        1 => field(&mut map, &mut bounded_ty, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut bounds, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lifetimes = required(lifetimes, FIELDS[0])?;
+                let bounded_ty = required(bounded_ty, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let bounds = required(bounds, FIELDS[3])?;
+                Ok(PredicateType {
+                    lifetimes,
+                    bounded_ty,
+                    colon_token,
+                    bounds,
+                })
+            }
+        }
+        deserializer.deserialize_struct("PredicateType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for QSelf {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("QSelf", 5)?;
+        state.serialize_field("lt_token", &self.lt_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("position", &self.position)?;
+        state.serialize_field("as_token", &self.as_token)?;
+        state.serialize_field("gt_token", &self.gt_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for QSelf {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["lt_token", "ty", "position", "as_token", "gt_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = QSelf;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct QSelf")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lt_token: Token ! [<] = element(&mut seq, 0, &self)?;
+                let ty: Box<Type> = element(&mut seq, 1, &self)?;
+                let position: usize = element(&mut seq, 2, &self)?;
+                let as_token: Option<Token![as]> = element(&mut seq, 3, &self)?;
+                let gt_token: Token ! [>] = element(&mut seq, 4, &self)?;
+                Ok(QSelf {
+                    lt_token,
+                    ty,
+                    position,
+                    as_token,
+                    gt_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lt_token: Option<Token ! [<]> = None;
+                let mut ty: Option<Box<Type>> = None;
+                let mut position: Option<usize> = None;
+                let mut as_token: Option<Option<Token![as]>> = None;
+                let mut gt_token: Option<Token ! [>]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lt_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut ty, FIELDS[1])?,
+                        2 => field(&mut map, &mut position, FIELDS[2])?,
+                        3 => field(&mut map, &mut as_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut gt_token, FIELDS[4])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lt_token = required(lt_token, FIELDS[0])?;
+                let ty = required(ty, FIELDS[1])?;
+                let position = required(position, FIELDS[2])?;
+                let as_token = required(as_token, FIELDS[3])?;
+                let gt_token = required(gt_token, FIELDS[4])?;
+                Ok(QSelf {
+                    lt_token,
+                    ty,
+                    position,
+                    as_token,
+                    gt_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("QSelf", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for RangeLimits {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            RangeLimits::HalfOpen(_binding_0) => {
+                serializer.serialize_newtype_variant("RangeLimits", 0, "HalfOpen", _binding_0)
+            }
+            RangeLimits::Closed(_binding_0) => {
+                serializer.serialize_newtype_variant("RangeLimits", 1, "Closed", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for RangeLimits {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["HalfOpen", "Closed"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = RangeLimits;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum RangeLimits")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(RangeLimits::HalfOpen),
+                    1 => variant.newtype_variant().map(RangeLimits::Closed),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("RangeLimits", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Receiver {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Receiver", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("reference", &self.reference)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("self_token", &self.self_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Receiver {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "reference", "mutability", "self_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Receiver;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Receiver")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let reference: Option<(Token ! [&], Option<Lifetime>)> =
+                    element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let self_token: Token![self] = element(&mut seq, 3, &self)?;
+                Ok(Receiver {
+                    attrs,
+                    reference,
+                    mutability,
+                    self_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut reference: Option<Option<(Token ! [&], Option<Lifetime>)>> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut self_token: Option<Token![self]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut reference, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut self_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let reference = required(reference, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let self_token = required(self_token, FIELDS[3])?;
+                Ok(Receiver {
+                    attrs,
+                    reference,
+                    mutability,
+                    self_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Receiver", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for ReturnType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            ReturnType::Default => serializer.serialize_unit_variant("ReturnType", 0, "Default"),
+            ReturnType::Type(_binding_0, _binding_1) => serializer.serialize_newtype_variant(
+                "ReturnType",
+                1,
+                "Type",
+                &(_binding_0, _binding_1),
+            ),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for ReturnType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Default", "Type"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = ReturnType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum ReturnType")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.unit_variant().map(|()| ReturnType::Default),
+                    1 => variant
+                        .newtype_variant::<(Token ! [->], Box<Type>)>()
+                        .map(|(_binding_0, _binding_1)| ReturnType::Type(_binding_0, _binding_1)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("ReturnType", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Signature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Signature", 11)?;
+        state.serialize_field("constness", &self.constness)?;
+        state.serialize_field("asyncness", &self.asyncness)?;
+        state.serialize_field("unsafety", &self.unsafety)?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("fn_token", &self.fn_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("variadic", &self.variadic)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Signature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "constness",
+            "asyncness",
+            "unsafety",
+            "abi",
+            "fn_token",
+            "ident",
+            "generics",
+            "paren_token",
+            "inputs",
+            "variadic",
+            "output",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Signature;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Signature")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let constness: Option<Token![const]> = element(&mut seq, 0, &self)?;
+                let asyncness: Option<Token![async]> = element(&mut seq, 1, &self)?;
+                let unsafety: Option<Token![unsafe]> = element(&mut seq, 2, &self)?;
+                let abi: Option<Abi> = element(&mut seq, 3, &self)?;
+                let fn_token: Token![fn] = element(&mut seq, 4, &self)?;
+                let ident: De<Ident> = element(&mut seq, 5, &self)?;
+                let generics: Generics = element(&mut seq, 6, &self)?;
+                let paren_token: Paren = element(&mut seq, 7, &self)?;
+                let inputs: Punctuated<FnArg, Token ! [,]> = element(&mut seq, 8, &self)?;
+                let variadic: Option<Variadic> = element(&mut seq, 9, &self)?;
+                let output: ReturnType = element(&mut seq, 10, &self)?;
+                Ok(Signature {
+                    constness,
+                    asyncness,
+                    unsafety,
+                    abi,
+                    fn_token,
+                    ident: ident.0,
+                    generics,
+                    paren_token,
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut constness: Option<Option<Token![const]>> = None;
+                let mut asyncness: Option<Option<Token![async]>> = None;
+                let mut unsafety: Option<Option<Token![unsafe]>> = None;
+                let mut abi: Option<Option<Abi>> = None;
+                let mut fn_token: Option<Token![fn]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut inputs: Option<Punctuated<FnArg, Token ! [,]>> = None;
+                let mut variadic: Option<Option<Variadic>> = None;
+                let mut output: Option<ReturnType> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut constness, FIELDS[0])?,
+                        1 => field(&mut map, &mut asyncness, FIELDS[1])?,
+                        2 => field(&mut map, &mut unsafety, FIELDS[2])?,
+                        3 => field(&mut map, &mut abi, FIELDS[3])?,
+                        4 => field(&mut map, &mut fn_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut ident, FIELDS[5])?,
+                        6 => field(&mut map, &mut generics, FIELDS[6])?,
+                        7 => field(&mut map, &mut paren_token, FIELDS[7])?,
+                        8 => field(&mut map, &mut inputs, FIELDS[8])?,
+                        9 => field(&mut map, &mut variadic, FIELDS[9])?,
+                        10 => field(&mut map, &mut output, FIELDS[10])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let constness = required(constness, FIELDS[0])?;
+                let asyncness = required(asyncness, FIELDS[1])?;
+                let unsafety = required(unsafety, FIELDS[2])?;
+                let abi = required(abi, FIELDS[3])?;
+                let fn_token = required(fn_token, FIELDS[4])?;
+                let ident = required(ident, FIELDS[5])?;
+                let generics = required(generics, FIELDS[6])?;
+                let paren_token = required(paren_token, FIELDS[7])?;
+                let inputs = required(inputs, FIELDS[8])?;
+                let variadic = required(variadic, FIELDS[9])?;
+                let output = required(output, FIELDS[10])?;
+                Ok(Signature {
+                    constness,
+                    asyncness,
+                    unsafety,
+                    abi,
+                    fn_token,
+                    ident: ident.0,
+                    generics,
+                    paren_token,
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Signature", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for Stmt {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Stmt::Local(_binding_0) => {
+                serializer.serialize_newtype_variant("Stmt", 0, "Local", _binding_0)
+            }
+            Stmt::Item(_binding_0) => {
+                serializer.serialize_newtype_variant("Stmt", 1, "Item", _binding_0)
+            }
+            Stmt::Expr(_binding_0) => {
+                serializer.serialize_newtype_variant("Stmt", 2, "Expr", _binding_0)
+            }
+            Stmt::Semi(_binding_0, _binding_1) => {
+                serializer.serialize_newtype_variant("Stmt", 3, "Semi", &(_binding_0, _binding_1))
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for Stmt {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Local", "Item", "Expr", "Semi"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Stmt;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Stmt")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Stmt::Local),
+                    1 => variant.newtype_variant().map(Stmt::Item),
+                    2 => variant.newtype_variant().map(Stmt::Expr),
+                    3 => variant
+                        .newtype_variant::<(Expr, Token ! [;])>()
+                        .map(|(_binding_0, _binding_1)| Stmt::Semi(_binding_0, _binding_1)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Stmt", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TraitBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitBound", 4)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("modifier", &self.modifier)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TraitBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "modifier", "lifetimes", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitBound")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token: Option<Paren> = element(&mut seq, 0, &self)?;
+                let modifier: TraitBoundModifier = element(&mut seq, 1, &self)?;
+                let lifetimes: Option<BoundLifetimes> = element(&mut seq, 2, &self)?;
+                let path: Path = element(&mut seq, 3, &self)?;
+                Ok(TraitBound {
+                    paren_token,
+                    modifier,
+                    lifetimes,
+                    path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token: Option<Option<Paren>> = None;
+                let mut modifier: Option<TraitBoundModifier> = None;
+                let mut lifetimes: Option<Option<BoundLifetimes>> = None;
+                let mut path: Option<Path> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut paren_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut modifier, FIELDS[1])?,
+                        2 => field(&mut map, &mut lifetimes, FIELDS[2])?,
+                        3 => field(&mut map, &mut path, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let paren_token = required(paren_token, FIELDS[0])?;
+                let modifier = required(modifier, FIELDS[1])?;
+                let lifetimes = required(lifetimes, FIELDS[2])?;
+                let path = required(path, FIELDS[3])?;
+                Ok(TraitBound {
+                    paren_token,
+                    modifier,
+                    lifetimes,
+                    path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitBound", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TraitBoundModifier {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitBoundModifier::None => {
+                serializer.serialize_unit_variant("TraitBoundModifier", 0, "None")
+            }
+            TraitBoundModifier::Maybe(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitBoundModifier", 1, "Maybe", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TraitBoundModifier {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["None", "Maybe"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitBoundModifier;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitBoundModifier")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.unit_variant().map(|()| TraitBoundModifier::None),
+                    1 => variant.newtype_variant().map(TraitBoundModifier::Maybe),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitBoundModifier", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItem {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TraitItem::Const(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitItem", 0, "Const", _binding_0)
+            }
+            TraitItem::Method(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitItem", 1, "Method", _binding_0)
+            }
+            TraitItem::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitItem", 2, "Type", _binding_0)
+            }
+            TraitItem::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitItem", 3, "Macro", _binding_0)
+            }
+            TraitItem::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("TraitItem", 4, "Verbatim", &Ser(_binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItem {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Const", "Method", "Type", "Macro", "Verbatim"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItem;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TraitItem")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(TraitItem::Const),
+                    1 => variant.newtype_variant().map(TraitItem::Method),
+                    2 => variant.newtype_variant().map(TraitItem::Type),
+                    3 => variant.newtype_variant().map(TraitItem::Macro),
+                    4 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| TraitItem::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TraitItem", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemConst {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemConst", 7)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("const_token", &self.const_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("ty", &self.ty)?;
+        state.serialize_field("default", &self.default)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemConst {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "const_token",
+            "ident",
+            "colon_token",
+            "ty",
+            "default",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemConst;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemConst")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let const_token: Token![const] = element(&mut seq, 1, &self)?;
+                let ident: De<Ident> = element(&mut seq, 2, &self)?;
+                let colon_token: Token ! [:] = element(&mut seq, 3, &self)?;
+                let ty: Type = element(&mut seq, 4, &self)?;
+                let default: Option<(Token ! [=], Expr)> = element(&mut seq, 5, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 6, &self)?;
+                Ok(TraitItemConst {
+                    attrs,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    default,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut const_token: Option<Token![const]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Token ! [:]> = None;
+                let mut ty: Option<Type> = None;
+                let mut default: Option<Option<(Token ! [=], Expr)>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut const_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut ident, FIELDS[2])?,
+                        3 => field(&mut map, &mut colon_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut ty, FIELDS[4])?,
+                        5 => field(&mut map, &mut default, FIELDS[5])?,
+                        6 => field(&mut map, &mut semi_token, FIELDS[6])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let const_token = required(const_token, FIELDS[1])?;
+                let ident = required(ident, FIELDS[2])?;
+                let colon_token = required(colon_token, FIELDS[3])?;
+                let ty = required(ty, FIELDS[4])?;
+                let default = required(default, FIELDS[5])?;
+                let semi_token = required(semi_token, FIELDS[6])?;
+                Ok(TraitItemConst {
+                    attrs,
+                    const_token,
+                    ident: ident.0,
+                    colon_token,
+                    ty,
+                    default,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemConst", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMacro", 3)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "mac", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let mac: Macro = element(&mut seq, 1, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 2, &self)?;
+                Ok(TraitItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut mac: Option<Macro> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut mac, FIELDS[1])?,
+                        2 => field(&mut map, &mut semi_token, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let mac = required(mac, FIELDS[1])?;
+                let semi_token = required(semi_token, FIELDS[2])?;
+                Ok(TraitItemMacro {
+                    attrs,
+                    mac,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemMethod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemMethod", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("sig", &self.sig)?;
+        state.serialize_field("default", &self.default)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemMethod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "sig", "default", "semi_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemMethod;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemMethod")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let sig: Signature = element(&mut seq, 1, &self)?;
+                let default: Option<Block> = element(&mut seq, 2, &self)?;
+                let semi_token: Option<Token ! [;]> = element(&mut seq, 3, &self)?;
+                Ok(TraitItemMethod {
+                    attrs,
+                    sig,
+                    default,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut sig: Option<Signature> = None;
+                let mut default: Option<Option<Block>> = None;
+                let mut semi_token: Option<Option<Token ! [;]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut sig, FIELDS[1])?,
+                        2 => field(&mut map, &mut default, FIELDS[2])?,
+                        3 => field(&mut map, &mut semi_token, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let sig = required(sig, FIELDS[1])?;
+                let default = required(default, FIELDS[2])?;
+                let semi_token = required(semi_token, FIELDS[3])?;
+                Ok(TraitItemMethod {
+                    attrs,
+                    sig,
+                    default,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemMethod", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for TraitItemType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TraitItemType", 8)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("type_token", &self.type_token)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("generics", &self.generics)?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("default", &self.default)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for TraitItemType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "type_token",
+            "ident",
+            "generics",
+            "colon_token",
+            "bounds",
+            "default",
+            "semi_token",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TraitItemType;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TraitItemType")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let type_token: Token![type] = element(&mut seq, 1, &self)?;
+                let ident: De<Ident> = element(&mut seq, 2, &self)?;
+                let generics: Generics = element(&mut seq, 3, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 4, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 5, &self)?;
+                let default: Option<(Token ! [=], Type)> = element(&mut seq, 6, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 7, &self)?;
+                Ok(TraitItemType {
+                    attrs,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    colon_token,
+                    bounds,
+                    default,
+                    semi_token,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut type_token: Option<Token![type]> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut generics: Option<Generics> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                let mut default: Option<Option<(Token ! [=], Type)>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut type_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut ident, FIELDS[2])?,
+                        3 => field(&mut map, &mut generics, FIELDS[3])?,
+                        4 => field(&mut map, &mut colon_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut bounds, FIELDS[5])?,
+                        6 => field(&mut map, &mut default, FIELDS[6])?,
+                        7 => field(&mut map, &mut semi_token, FIELDS[7])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let type_token = required(type_token, FIELDS[1])?;
+                let ident = required(ident, FIELDS[2])?;
+                let generics = required(generics, FIELDS[3])?;
+                let colon_token = required(colon_token, FIELDS[4])?;
+                let bounds = required(bounds, FIELDS[5])?;
+                let default = required(default, FIELDS[6])?;
+                let semi_token = required(semi_token, FIELDS[7])?;
+                Ok(TraitItemType {
+                    attrs,
+                    type_token,
+                    ident: ident.0,
+                    generics,
+                    colon_token,
+                    bounds,
+                    default,
+                    semi_token,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TraitItemType", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Type {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Type::Array(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 0, "Array", _binding_0)
+            }
+            Type::BareFn(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 1, "BareFn", _binding_0)
+            }
+            Type::Group(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 2, "Group", _binding_0)
+            }
+            Type::ImplTrait(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 3, "ImplTrait", _binding_0)
+            }
+            Type::Infer(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 4, "Infer", _binding_0)
+            }
+            Type::Macro(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 5, "Macro", _binding_0)
+            }
+            Type::Never(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 6, "Never", _binding_0)
+            }
+            Type::Paren(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 7, "Paren", _binding_0)
+            }
+            Type::Path(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 8, "Path", _binding_0)
+            }
+            Type::Ptr(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 9, "Ptr", _binding_0)
+            }
+            Type::Reference(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 10, "Reference", _binding_0)
+            }
+            Type::Slice(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 11, "Slice", _binding_0)
+            }
+            Type::TraitObject(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 12, "TraitObject", _binding_0)
+            }
+            Type::Tuple(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 13, "Tuple", _binding_0)
+            }
+            Type::Verbatim(_binding_0) => {
+                serializer.serialize_newtype_variant("Type", 14, "Verbatim", &Ser(_binding_0))
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Type {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &[
+            "Array",
+            "BareFn",
+            "Group",
+            "ImplTrait",
+            "Infer",
+            "Macro",
+            "Never",
+            "Paren",
+            "Path",
+            "Ptr",
+            "Reference",
+            "Slice",
+            "TraitObject",
+            "Tuple",
+            "Verbatim",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Type;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Type")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Type::Array),
+                    1 => variant.newtype_variant().map(Type::BareFn),
+                    2 => variant.newtype_variant().map(Type::Group),
+                    3 => variant.newtype_variant().map(Type::ImplTrait),
+                    4 => variant.newtype_variant().map(Type::Infer),
+                    5 => variant.newtype_variant().map(Type::Macro),
+                    6 => variant.newtype_variant().map(Type::Never),
+                    7 => variant.newtype_variant().map(Type::Paren),
+                    8 => variant.newtype_variant().map(Type::Path),
+                    9 => variant.newtype_variant().map(Type::Ptr),
+                    10 => variant.newtype_variant().map(Type::Reference),
+                    11 => variant.newtype_variant().map(Type::Slice),
+                    12 => variant.newtype_variant().map(Type::TraitObject),
+                    13 => variant.newtype_variant().map(Type::Tuple),
+                    14 => variant
+                        .newtype_variant::<De<TokenStream>>()
+                        .map(|it| Type::Verbatim(it.0)),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Type", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeArray {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeArray", 4)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.serialize_field("semi_token", &self.semi_token)?;
+        state.serialize_field("len", &self.len)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeArray {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bracket_token", "elem", "semi_token", "len"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeArray;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeArray")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let bracket_token: Bracket = element(&mut seq, 0, &self)?;
+                let elem: Box<Type> = element(&mut seq, 1, &self)?;
+                let semi_token: Token ! [;] = element(&mut seq, 2, &self)?;
+                let len: Expr = element(&mut seq, 3, &self)?;
+                Ok(TypeArray {
+                    bracket_token,
+                    elem,
+                    semi_token,
+                    len,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut bracket_token: Option<Bracket> = None;
+                let mut elem: Option<Box<Type>> = None;
+                let mut semi_token: Option<Token ! [;]> = None;
+                let mut len: Option<Expr> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut bracket_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut elem, FIELDS[1])?,
+                        2 => field(&mut map, &mut semi_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut len, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let bracket_token = required(bracket_token, FIELDS[0])?;
+                let elem = required(elem, FIELDS[1])?;
+                let semi_token = required(semi_token, FIELDS[2])?;
+                let len = required(len, FIELDS[3])?;
+                Ok(TypeArray {
+                    bracket_token,
+                    elem,
+                    semi_token,
+                    len,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeArray", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeBareFn {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeBareFn", 8)?;
+        state.serialize_field("lifetimes", &self.lifetimes)?;
+        state.serialize_field("unsafety", &self.unsafety)?;
+        state.serialize_field("abi", &self.abi)?;
+        state.serialize_field("fn_token", &self.fn_token)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("inputs", &self.inputs)?;
+        state.serialize_field("variadic", &self.variadic)?;
+        state.serialize_field("output", &self.output)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeBareFn {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "lifetimes",
+            "unsafety",
+            "abi",
+            "fn_token",
+            "paren_token",
+            "inputs",
+            "variadic",
+            "output",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeBareFn;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeBareFn")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let lifetimes: Option<BoundLifetimes> = element(&mut seq, 0, &self)?;
+                let unsafety: Option<Token![unsafe]> = element(&mut seq, 1, &self)?;
+                let abi: Option<Abi> = element(&mut seq, 2, &self)?;
+                let fn_token: Token![fn] = element(&mut seq, 3, &self)?;
+                let paren_token: Paren = element(&mut seq, 4, &self)?;
+                let inputs: Punctuated<BareFnArg, Token ! [,]> = element(&mut seq, 5, &self)?;
+                let variadic: Option<Variadic> = element(&mut seq, 6, &self)?;
+                let output: ReturnType = element(&mut seq, 7, &self)?;
+                Ok(TypeBareFn {
+                    lifetimes,
+                    unsafety,
+                    abi,
+                    fn_token,
+                    paren_token,
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut lifetimes: Option<Option<BoundLifetimes>> = None;
+                let mut unsafety: Option<Option<Token![unsafe]>> = None;
+                let mut abi: Option<Option<Abi>> = None;
+                let mut fn_token: Option<Token![fn]> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut inputs: Option<Punctuated<BareFnArg, Token ! [,]>> = None;
+                let mut variadic: Option<Option<Variadic>> = None;
+                let mut output: Option<ReturnType> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut lifetimes, FIELDS[0])?,
+                        1 => field(&mut map, &mut unsafety, FIELDS[1])?,
+                        2 => field(&mut map, &mut abi, FIELDS[2])?,
+                        3 => field(&mut map, &mut fn_token, FIELDS[3])?,
+                        4 => field(&mut map, &mut paren_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut inputs, FIELDS[5])?,
+                        6 => field(&mut map, &mut variadic, FIELDS[6])?,
+                        7 => field(&mut map, &mut output, FIELDS[7])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let lifetimes = required(lifetimes, FIELDS[0])?;
+                let unsafety = required(unsafety, FIELDS[1])?;
+                let abi = required(abi, FIELDS[2])?;
+                let fn_token = required(fn_token, FIELDS[3])?;
+                let paren_token = required(paren_token, FIELDS[4])?;
+                let inputs = required(inputs, FIELDS[5])?;
+                let variadic = required(variadic, FIELDS[6])?;
+                let output = required(output, FIELDS[7])?;
+                Ok(TypeBareFn {
+                    lifetimes,
+                    unsafety,
+                    abi,
+                    fn_token,
+                    paren_token,
+                    inputs,
+                    variadic,
+                    output,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeBareFn", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeGroup", 2)?;
+        state.serialize_field("group_token", &self.group_token)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["group_token", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let group_token: Group = element(&mut seq, 0, &self)?;
+                let elem: Box<Type> = element(&mut seq, 1, &self)?;
+                Ok(TypeGroup { group_token, elem })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut group_token: Option<Group> = None;
+                let mut elem: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut group_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut elem, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let group_token = required(group_token, FIELDS[0])?;
+                let elem = required(elem, FIELDS[1])?;
+                Ok(TypeGroup { group_token, elem })
+            }
+        }
+        deserializer.deserialize_struct("TypeGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeImplTrait {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeImplTrait", 2)?;
+        state.serialize_field("impl_token", &self.impl_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeImplTrait {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["impl_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeImplTrait;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeImplTrait")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let impl_token: Token![impl] = element(&mut seq, 0, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 1, &self)?;
+                Ok(TypeImplTrait { impl_token, bounds })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut impl_token: Option<Token![impl]> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut impl_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut bounds, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let impl_token = required(impl_token, FIELDS[0])?;
+                let bounds = required(bounds, FIELDS[1])?;
+                Ok(TypeImplTrait { impl_token, bounds })
+            }
+        }
+        deserializer.deserialize_struct("TypeImplTrait", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeInfer {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeInfer", 1)?;
+        state.serialize_field("underscore_token", &self.underscore_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeInfer {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["underscore_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeInfer;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeInfer")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let underscore_token: Token![_] = element(&mut seq, 0, &self)?;
+                Ok(TypeInfer { underscore_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut underscore_token: Option<Token![_]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut underscore_token, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let underscore_token = required(underscore_token, FIELDS[0])?;
+                Ok(TypeInfer { underscore_token })
+            }
+        }
+        deserializer.deserialize_struct("TypeInfer", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeMacro {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeMacro", 1)?;
+        state.serialize_field("mac", &self.mac)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeMacro {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["mac"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeMacro;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeMacro")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let mac: Macro = element(&mut seq, 0, &self)?;
+                Ok(TypeMacro { mac })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut mac: Option<Macro> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut mac, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let mac = required(mac, FIELDS[0])?;
+                Ok(TypeMacro { mac })
+            }
+        }
+        deserializer.deserialize_struct("TypeMacro", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeNever {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeNever", 1)?;
+        state.serialize_field("bang_token", &self.bang_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeNever {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bang_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeNever;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeNever")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let bang_token: Token![!] = element(&mut seq, 0, &self)?;
+                Ok(TypeNever { bang_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut bang_token: Option<Token![!]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut bang_token, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let bang_token = required(bang_token, FIELDS[0])?;
+                Ok(TypeNever { bang_token })
+            }
+        }
+        deserializer.deserialize_struct("TypeNever", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParam {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParam", 6)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon_token", &self.colon_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.serialize_field("eq_token", &self.eq_token)?;
+        state.serialize_field("default", &self.default)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParam {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "attrs",
+            "ident",
+            "colon_token",
+            "bounds",
+            "eq_token",
+            "default",
+        ];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParam;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParam")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let ident: De<Ident> = element(&mut seq, 1, &self)?;
+                let colon_token: Option<Token ! [:]> = element(&mut seq, 2, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 3, &self)?;
+                let eq_token: Option<Token ! [=]> = element(&mut seq, 4, &self)?;
+                let default: Option<Type> = element(&mut seq, 5, &self)?;
+                Ok(TypeParam {
+                    attrs,
+                    ident: ident.0,
+                    colon_token,
+                    bounds,
+                    eq_token,
+                    default,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon_token: Option<Option<Token ! [:]>> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                let mut eq_token: Option<Option<Token ! [=]>> = None;
+                let mut default: Option<Option<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut ident, FIELDS[1])?,
+                        2 => field(&mut map, &mut colon_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut bounds, FIELDS[3])?,
+                        4 => field(&mut map, &mut eq_token, FIELDS[4])?,
+                        5 => field(&mut map, &mut default, FIELDS[5])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let ident = required(ident, FIELDS[1])?;
+                let colon_token = required(colon_token, FIELDS[2])?;
+                let bounds = required(bounds, FIELDS[3])?;
+                let eq_token = required(eq_token, FIELDS[4])?;
+                let default = required(default, FIELDS[5])?;
+                Ok(TypeParam {
+                    attrs,
+                    ident: ident.0,
+                    colon_token,
+                    bounds,
+                    eq_token,
+                    default,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeParam", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParamBound {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            TypeParamBound::Trait(_binding_0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 0, "Trait", _binding_0)
+            }
+            TypeParamBound::Lifetime(_binding_0) => {
+                serializer.serialize_newtype_variant("TypeParamBound", 1, "Lifetime", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParamBound {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Trait", "Lifetime"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParamBound;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum TypeParamBound")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(TypeParamBound::Trait),
+                    1 => variant.newtype_variant().map(TypeParamBound::Lifetime),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("TypeParamBound", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeParen {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeParen", 2)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeParen {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeParen;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeParen")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token: Paren = element(&mut seq, 0, &self)?;
+                let elem: Box<Type> = element(&mut seq, 1, &self)?;
+                Ok(TypeParen { paren_token, elem })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token: Option<Paren> = None;
+                let mut elem: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut paren_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut elem, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let paren_token = required(paren_token, FIELDS[0])?;
+                let elem = required(elem, FIELDS[1])?;
+                Ok(TypeParen { paren_token, elem })
+            }
+        }
+        deserializer.deserialize_struct("TypeParen", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePath", 2)?;
+        state.serialize_field("qself", &self.qself)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["qself", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let qself: Option<QSelf> = element(&mut seq, 0, &self)?;
+                let path: Path = element(&mut seq, 1, &self)?;
+                Ok(TypePath { qself, path })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut qself: Option<Option<QSelf>> = None;
+                let mut path: Option<Path> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut qself, FIELDS[0])?,
+                        1 => field(&mut map, &mut path, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let qself = required(qself, FIELDS[0])?;
+                let path = required(path, FIELDS[1])?;
+                Ok(TypePath { qself, path })
+            }
+        }
+        deserializer.deserialize_struct("TypePath", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypePtr {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypePtr", 4)?;
+        state.serialize_field("star_token", &self.star_token)?;
+        state.serialize_field("const_token", &self.const_token)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypePtr {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["star_token", "const_token", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypePtr;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypePtr")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let star_token: Token ! [*] = element(&mut seq, 0, &self)?;
+                let const_token: Option<Token![const]> = element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let elem: Box<Type> = element(&mut seq, 3, &self)?;
+                Ok(TypePtr {
+                    star_token,
+                    const_token,
+                    mutability,
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut star_token: Option<Token ! [*]> = None;
+                let mut const_token: Option<Option<Token![const]>> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut elem: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut star_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut const_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut elem, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let star_token = required(star_token, FIELDS[0])?;
+                let const_token = required(const_token, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let elem = required(elem, FIELDS[3])?;
+                Ok(TypePtr {
+                    star_token,
+                    const_token,
+                    mutability,
+                    elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypePtr", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeReference {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeReference", 4)?;
+        state.serialize_field("and_token", &self.and_token)?;
+        state.serialize_field("lifetime", &self.lifetime)?;
+        state.serialize_field("mutability", &self.mutability)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeReference {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["and_token", "lifetime", "mutability", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeReference;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeReference")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let and_token: Token ! [&] = element(&mut seq, 0, &self)?;
+                let lifetime: Option<Lifetime> = element(&mut seq, 1, &self)?;
+                let mutability: Option<Token![mut]> = element(&mut seq, 2, &self)?;
+                let elem: Box<Type> = element(&mut seq, 3, &self)?;
+                Ok(TypeReference {
+                    and_token,
+                    lifetime,
+                    mutability,
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut and_token: Option<Token ! [&]> = None;
+                let mut lifetime: Option<Option<Lifetime>> = None;
+                let mut mutability: Option<Option<Token![mut]>> = None;
+                let mut elem: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut and_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut lifetime, FIELDS[1])?,
+                        2 => field(&mut map, &mut mutability, FIELDS[2])?,
+                        3 => field(&mut map, &mut elem, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let and_token = required(and_token, FIELDS[0])?;
+                let lifetime = required(lifetime, FIELDS[1])?;
+                let mutability = required(mutability, FIELDS[2])?;
+                let elem = required(elem, FIELDS[3])?;
+                Ok(TypeReference {
+                    and_token,
+                    lifetime,
+                    mutability,
+                    elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeReference", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeSlice {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeSlice", 2)?;
+        state.serialize_field("bracket_token", &self.bracket_token)?;
+        state.serialize_field("elem", &self.elem)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeSlice {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["bracket_token", "elem"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeSlice;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeSlice")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let bracket_token: Bracket = element(&mut seq, 0, &self)?;
+                let elem: Box<Type> = element(&mut seq, 1, &self)?;
+                Ok(TypeSlice {
+                    bracket_token,
+                    elem,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut bracket_token: Option<Bracket> = None;
+                let mut elem: Option<Box<Type>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut bracket_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut elem, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let bracket_token = required(bracket_token, FIELDS[0])?;
+                let elem = required(elem, FIELDS[1])?;
+                Ok(TypeSlice {
+                    bracket_token,
+                    elem,
+                })
+            }
+        }
+        deserializer.deserialize_struct("TypeSlice", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeTraitObject {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTraitObject", 2)?;
+        state.serialize_field("dyn_token", &self.dyn_token)?;
+        state.serialize_field("bounds", &self.bounds)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeTraitObject {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["dyn_token", "bounds"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTraitObject;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTraitObject")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let dyn_token: Option<Token![dyn]> = element(&mut seq, 0, &self)?;
+                let bounds: Punctuated<TypeParamBound, Token ! [+]> = element(&mut seq, 1, &self)?;
+                Ok(TypeTraitObject { dyn_token, bounds })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut dyn_token: Option<Option<Token![dyn]>> = None;
+                let mut bounds: Option<Punctuated<TypeParamBound, Token ! [+]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut dyn_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut bounds, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let dyn_token = required(dyn_token, FIELDS[0])?;
+                let bounds = required(bounds, FIELDS[1])?;
+                Ok(TypeTraitObject { dyn_token, bounds })
+            }
+        }
+        deserializer.deserialize_struct("TypeTraitObject", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for TypeTuple {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("TypeTuple", 2)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("elems", &self.elems)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for TypeTuple {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["paren_token", "elems"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = TypeTuple;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct TypeTuple")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let paren_token: Paren = element(&mut seq, 0, &self)?;
+                let elems: Punctuated<Type, Token ! [,]> = element(&mut seq, 1, &self)?;
+                Ok(TypeTuple { paren_token, elems })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut paren_token: Option<Paren> = None;
+                let mut elems: Option<Punctuated<Type, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut paren_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut elems, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let paren_token = required(paren_token, FIELDS[0])?;
+                let elems = required(elems, FIELDS[1])?;
+                Ok(TypeTuple { paren_token, elems })
+            }
+        }
+        deserializer.deserialize_struct("TypeTuple", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for UnOp {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UnOp::Deref(_binding_0) => {
+                serializer.serialize_newtype_variant("UnOp", 0, "Deref", _binding_0)
+            }
+            UnOp::Not(_binding_0) => {
+                serializer.serialize_newtype_variant("UnOp", 1, "Not", _binding_0)
+            }
+            UnOp::Neg(_binding_0) => {
+                serializer.serialize_newtype_variant("UnOp", 2, "Neg", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for UnOp {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Deref", "Not", "Neg"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UnOp;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UnOp")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(UnOp::Deref),
+                    1 => variant.newtype_variant().map(UnOp::Not),
+                    2 => variant.newtype_variant().map(UnOp::Neg),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UnOp", VARIANTS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseGlob {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseGlob", 1)?;
+        state.serialize_field("star_token", &self.star_token)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseGlob {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["star_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGlob;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGlob")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let star_token: Token ! [*] = element(&mut seq, 0, &self)?;
+                Ok(UseGlob { star_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut star_token: Option<Token ! [*]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut star_token, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let star_token = required(star_token, FIELDS[0])?;
+                Ok(UseGlob { star_token })
+            }
+        }
+        deserializer.deserialize_struct("UseGlob", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseGroup", 2)?;
+        state.serialize_field("brace_token", &self.brace_token)?;
+        state.serialize_field("items", &self.items)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["brace_token", "items"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseGroup;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseGroup")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let brace_token: Brace = element(&mut seq, 0, &self)?;
+                let items: Punctuated<UseTree, Token ! [,]> = element(&mut seq, 1, &self)?;
+                Ok(UseGroup { brace_token, items })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut brace_token: Option<Brace> = None;
+                let mut items: Option<Punctuated<UseTree, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut brace_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut items, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let brace_token = required(brace_token, FIELDS[0])?;
+                let items = required(items, FIELDS[1])?;
+                Ok(UseGroup { brace_token, items })
+            }
+        }
+        deserializer.deserialize_struct("UseGroup", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseName {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseName", 1)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseName {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseName;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseName")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                Ok(UseName { ident: ident.0 })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                Ok(UseName { ident: ident.0 })
+            }
+        }
+        deserializer.deserialize_struct("UseName", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UsePath {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UsePath", 3)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("colon2_token", &self.colon2_token)?;
+        state.serialize_field("tree", &self.tree)?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UsePath {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "colon2_token", "tree"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UsePath;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UsePath")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                let colon2_token: Token ! [::] = element(&mut seq, 1, &self)?;
+                let tree: Box<UseTree> = element(&mut seq, 2, &self)?;
+                Ok(UsePath {
+                    ident: ident.0,
+                    colon2_token,
+                    tree,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                let mut colon2_token: Option<Token ! [::]> = None;
+                let mut tree: Option<Box<UseTree>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        1 => field(&mut map, &mut colon2_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut tree, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                let colon2_token = required(colon2_token, FIELDS[1])?;
+                let tree = required(tree, FIELDS[2])?;
+                Ok(UsePath {
+                    ident: ident.0,
+                    colon2_token,
+                    tree,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UsePath", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseRename {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("UseRename", 3)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("as_token", &self.as_token)?;
+        state.serialize_field("rename", &Ser(&self.rename))?;
+        state.end()
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseRename {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["ident", "as_token", "rename"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseRename;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct UseRename")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let ident: De<Ident> = element(&mut seq, 0, &self)?;
+                let as_token: Token![as] = element(&mut seq, 1, &self)?;
+                let rename: De<Ident> = element(&mut seq, 2, &self)?;
+                Ok(UseRename {
+                    ident: ident.0,
+                    as_token,
+                    rename: rename.0,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut ident: Option<De<Ident>> = None;
+                let mut as_token: Option<Token![as]> = None;
+                let mut rename: Option<De<Ident>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut ident, FIELDS[0])?,
+                        1 => field(&mut map, &mut as_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut rename, FIELDS[2])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let ident = required(ident, FIELDS[0])?;
+                let as_token = required(as_token, FIELDS[1])?;
+                let rename = required(rename, FIELDS[2])?;
+                Ok(UseRename {
+                    ident: ident.0,
+                    as_token,
+                    rename: rename.0,
+                })
+            }
+        }
+        deserializer.deserialize_struct("UseRename", FIELDS, Visitor)
+    }
+}
+#[cfg(feature = "full")]
+impl Serialize for UseTree {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            UseTree::Path(_binding_0) => {
+                serializer.serialize_newtype_variant("UseTree", 0, "Path", _binding_0)
+            }
+            UseTree::Name(_binding_0) => {
+                serializer.serialize_newtype_variant("UseTree", 1, "Name", _binding_0)
+            }
+            UseTree::Rename(_binding_0) => {
+                serializer.serialize_newtype_variant("UseTree", 2, "Rename", _binding_0)
+            }
+            UseTree::Glob(_binding_0) => {
+                serializer.serialize_newtype_variant("UseTree", 3, "Glob", _binding_0)
+            }
+            UseTree::Group(_binding_0) => {
+                serializer.serialize_newtype_variant("UseTree", 4, "Group", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(feature = "full")]
+impl<'de> Deserialize<'de> for UseTree {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Path", "Name", "Rename", "Glob", "Group"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = UseTree;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum UseTree")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(UseTree::Path),
+                    1 => variant.newtype_variant().map(UseTree::Name),
+                    2 => variant.newtype_variant().map(UseTree::Rename),
+                    3 => variant.newtype_variant().map(UseTree::Glob),
+                    4 => variant.newtype_variant().map(UseTree::Group),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("UseTree", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Variadic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variadic", 2)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("dots", &self.dots)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Variadic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "dots"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variadic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variadic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let dots: Token ! [...] = element(&mut seq, 1, &self)?;
+                Ok(Variadic { attrs, dots })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut dots: Option<Token ! [...]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut dots, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let dots = required(dots, FIELDS[1])?;
+                Ok(Variadic { attrs, dots })
+            }
+        }
+        deserializer.deserialize_struct("Variadic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Variant {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("Variant", 4)?;
+        state.serialize_field("attrs", &self.attrs)?;
+        state.serialize_field("ident", &Ser(&self.ident))?;
+        state.serialize_field("fields", &self.fields)?;
+        state.serialize_field("discriminant", &self.discriminant)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Variant {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["attrs", "ident", "fields", "discriminant"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Variant;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct Variant")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let attrs: Vec<Attribute> = element(&mut seq, 0, &self)?;
+                let ident: De<Ident> = element(&mut seq, 1, &self)?;
+                let fields: Fields = element(&mut seq, 2, &self)?;
+                let discriminant: Option<(Token ! [=], Expr)> = element(&mut seq, 3, &self)?;
+                Ok(Variant {
+                    attrs,
+                    ident: ident.0,
+                    fields,
+                    discriminant,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut attrs: Option<Vec<Attribute>> = None;
+                let mut ident: Option<De<Ident>> = None;
+                let mut fields: Option<Fields> = None;
+                let mut discriminant: Option<Option<(Token ! [=], Expr)>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut attrs, FIELDS[0])?,
+                        1 => field(&mut map, &mut ident, FIELDS[1])?,
+                        2 => field(&mut map, &mut fields, FIELDS[2])?,
+                        3 => field(&mut map, &mut discriminant, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let attrs = required(attrs, FIELDS[0])?;
+                let ident = required(ident, FIELDS[1])?;
+                let fields = required(fields, FIELDS[2])?;
+                let discriminant = required(discriminant, FIELDS[3])?;
+                Ok(Variant {
+                    attrs,
+                    ident: ident.0,
+                    fields,
+                    discriminant,
+                })
+            }
+        }
+        deserializer.deserialize_struct("Variant", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisCrate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisCrate", 1)?;
+        state.serialize_field("crate_token", &self.crate_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisCrate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["crate_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisCrate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisCrate")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let crate_token: Token![crate] = element(&mut seq, 0, &self)?;
+                Ok(VisCrate { crate_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut crate_token: Option<Token![crate]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut crate_token, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let crate_token = required(crate_token, FIELDS[0])?;
+                Ok(VisCrate { crate_token })
+            }
+        }
+        deserializer.deserialize_struct("VisCrate", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisPublic {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisPublic", 1)?;
+        state.serialize_field("pub_token", &self.pub_token)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisPublic {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["pub_token"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisPublic;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisPublic")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let pub_token: Token![pub] = element(&mut seq, 0, &self)?;
+                Ok(VisPublic { pub_token })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut pub_token: Option<Token![pub]> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut pub_token, FIELDS[0])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let pub_token = required(pub_token, FIELDS[0])?;
+                Ok(VisPublic { pub_token })
+            }
+        }
+        deserializer.deserialize_struct("VisPublic", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for VisRestricted {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("VisRestricted", 4)?;
+        state.serialize_field("pub_token", &self.pub_token)?;
+        state.serialize_field("paren_token", &self.paren_token)?;
+        state.serialize_field("in_token", &self.in_token)?;
+        state.serialize_field("path", &self.path)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for VisRestricted {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["pub_token", "paren_token", "in_token", "path"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = VisRestricted;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct VisRestricted")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let pub_token: Token![pub] = element(&mut seq, 0, &self)?;
+                let paren_token: Paren = element(&mut seq, 1, &self)?;
+                let in_token: Option<Token![in]> = element(&mut seq, 2, &self)?;
+                let path: Box<Path> = element(&mut seq, 3, &self)?;
+                Ok(VisRestricted {
+                    pub_token,
+                    paren_token,
+                    in_token,
+                    path,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut pub_token: Option<Token![pub]> = None;
+                let mut paren_token: Option<Paren> = None;
+                let mut in_token: Option<Option<Token![in]>> = None;
+                let mut path: Option<Box<Path>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut pub_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut paren_token, FIELDS[1])?,
+                        2 => field(&mut map, &mut in_token, FIELDS[2])?,
+                        3 => field(&mut map, &mut path, FIELDS[3])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let pub_token = required(pub_token, FIELDS[0])?;
+                let paren_token = required(paren_token, FIELDS[1])?;
+                let in_token = required(in_token, FIELDS[2])?;
+                let path = required(path, FIELDS[3])?;
+                Ok(VisRestricted {
+                    pub_token,
+                    paren_token,
+                    in_token,
+                    path,
+                })
+            }
+        }
+        deserializer.deserialize_struct("VisRestricted", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for Visibility {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            Visibility::Public(_binding_0) => {
+                serializer.serialize_newtype_variant("Visibility", 0, "Public", _binding_0)
+            }
+            Visibility::Crate(_binding_0) => {
+                serializer.serialize_newtype_variant("Visibility", 1, "Crate", _binding_0)
+            }
+            Visibility::Restricted(_binding_0) => {
+                serializer.serialize_newtype_variant("Visibility", 2, "Restricted", _binding_0)
+            }
+            Visibility::Inherited => {
+                serializer.serialize_unit_variant("Visibility", 3, "Inherited")
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for Visibility {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Public", "Crate", "Restricted", "Inherited"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = Visibility;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum Visibility")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(Visibility::Public),
+                    1 => variant.newtype_variant().map(Visibility::Crate),
+                    2 => variant.newtype_variant().map(Visibility::Restricted),
+                    3 => variant.unit_variant().map(|()| Visibility::Inherited),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("Visibility", VARIANTS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for WhereClause {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct("WhereClause", 2)?;
+        state.serialize_field("where_token", &self.where_token)?;
+        state.serialize_field("predicates", &self.predicates)?;
+        state.end()
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for WhereClause {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &["where_token", "predicates"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WhereClause;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("struct WhereClause")
+            }
+            fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+            where
+                A: SeqAccess<'de>,
+            {
+                let where_token: Token![where] = element(&mut seq, 0, &self)?;
+                let predicates: Punctuated<WherePredicate, Token ! [,]> =
+                    element(&mut seq, 1, &self)?;
+                Ok(WhereClause {
+                    where_token,
+                    predicates,
+                })
+            }
+            fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+            where
+                A: MapAccess<'de>,
+            {
+                let mut where_token: Option<Token![where]> = None;
+                let mut predicates: Option<Punctuated<WherePredicate, Token ! [,]>> = None;
+                while let Some(key) = map.next_key_seed(Identifier::field(FIELDS))? {
+                    match key {
+                        0 => field(&mut map, &mut where_token, FIELDS[0])?,
+                        1 => field(&mut map, &mut predicates, FIELDS[1])?,
+                        _ => unreachable!(),
+                    }
+                }
+                let where_token = required(where_token, FIELDS[0])?;
+                let predicates = required(predicates, FIELDS[1])?;
+                Ok(WhereClause {
+                    where_token,
+                    predicates,
+                })
+            }
+        }
+        deserializer.deserialize_struct("WhereClause", FIELDS, Visitor)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl Serialize for WherePredicate {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self {
+            WherePredicate::Type(_binding_0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 0, "Type", _binding_0)
+            }
+            WherePredicate::Lifetime(_binding_0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 1, "Lifetime", _binding_0)
+            }
+            WherePredicate::Eq(_binding_0) => {
+                serializer.serialize_newtype_variant("WherePredicate", 2, "Eq", _binding_0)
+            }
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+impl<'de> Deserialize<'de> for WherePredicate {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        const VARIANTS: &[&str] = &["Type", "Lifetime", "Eq"];
+        struct Visitor;
+        impl<'de> de::Visitor<'de> for Visitor {
+            type Value = WherePredicate;
+            fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                formatter.write_str("enum WherePredicate")
+            }
+            fn visit_enum<A>(self, data: A) -> Result<Self::Value, A::Error>
+            where
+                A: EnumAccess<'de>,
+            {
+                let (index, variant) = data.variant_seed(Identifier::variant(VARIANTS))?;
+                match index {
+                    0 => variant.newtype_variant().map(WherePredicate::Type),
+                    1 => variant.newtype_variant().map(WherePredicate::Lifetime),
+                    2 => variant.newtype_variant().map(WherePredicate::Eq),
+                    _ => unreachable!(),
+                }
+            }
+        }
+        deserializer.deserialize_enum("WherePredicate", VARIANTS, Visitor)
+    }
+}
@@ -0,0 +1,14479 @@
+// This file is @generated by syn-internal-codegen.
+// It is not intended for manual editing.
+
+#![allow(unused_variables)]
+use crate::*;
+use std::result::Result;
+#[cfg(feature = "full")]
+macro_rules! full {
+    ($e:expr) => {
+        $e
+    };
+}
+#[cfg(all(feature = "derive", not(feature = "full")))]
+macro_rules! full {
+    ($e:expr) => {
+        unreachable!()
+    };
+}
+macro_rules! skip {
+    ($($tt:tt)*) => {};
+}
+macro_rules! enter {
+    ($control:expr) => {
+        match $control {
+            Control::Continue => {}
+            Control::SkipChildren => return Ok(()),
+            Control::Break(value) => return Err(value),
+        }
+    };
+}
+/// What a traversal should do after a hook has seen a node.
+///
+/// *This type is available if Syn is built with the `"visit"` or
+/// `"visit-mut"` feature.*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Control<B> {
+    /// Descend into the children of the node.
+    Continue,
+    /// Leave the children of the node unvisited but keep walking
+    /// its siblings.
+    SkipChildren,
+    /// Stop the traversal, handing the value back to the caller.
+    Break(B),
+}
+/// A shared borrow of any syntax tree node, as found on the stack
+/// of ancestors passed to [`VisitCtx`] hooks.
+///
+/// *This type is available if Syn is built with the `"visit"`
+/// feature.*
+#[cfg(feature = "visit")]
+#[derive(Clone, Copy)]
+pub enum Node<'ast> {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Abi(&'ast Abi),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AngleBracketedGenericArguments(&'ast AngleBracketedGenericArguments),
+    #[cfg(feature = "full")]
+    Arm(&'ast Arm),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrStyle(&'ast AttrStyle),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Attribute(&'ast Attribute),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BareFnArg(&'ast BareFnArg),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BinOp(&'ast BinOp),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Binding(&'ast Binding),
+    #[cfg(feature = "full")]
+    Block(&'ast Block),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BoundLifetimes(&'ast BoundLifetimes),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ConstParam(&'ast ConstParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Constraint(&'ast Constraint),
+    #[cfg(feature = "derive")]
+    Data(&'ast Data),
+    #[cfg(feature = "derive")]
+    DataEnum(&'ast DataEnum),
+    #[cfg(feature = "derive")]
+    DataStruct(&'ast DataStruct),
+    #[cfg(feature = "derive")]
+    DataUnion(&'ast DataUnion),
+    #[cfg(feature = "derive")]
+    DeriveInput(&'ast DeriveInput),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Expr(&'ast Expr),
+    #[cfg(feature = "full")]
+    ExprArray(&'ast ExprArray),
+    #[cfg(feature = "full")]
+    ExprAssign(&'ast ExprAssign),
+    #[cfg(feature = "full")]
+    ExprAssignOp(&'ast ExprAssignOp),
+    #[cfg(feature = "full")]
+    ExprAsync(&'ast ExprAsync),
+    #[cfg(feature = "full")]
+    ExprAwait(&'ast ExprAwait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprBinary(&'ast ExprBinary),
+    #[cfg(feature = "full")]
+    ExprBlock(&'ast ExprBlock),
+    #[cfg(feature = "full")]
+    ExprBox(&'ast ExprBox),
+    #[cfg(feature = "full")]
+    ExprBreak(&'ast ExprBreak),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCall(&'ast ExprCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCast(&'ast ExprCast),
+    #[cfg(feature = "full")]
+    ExprClosure(&'ast ExprClosure),
+    #[cfg(feature = "full")]
+    ExprContinue(&'ast ExprContinue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprField(&'ast ExprField),
+    #[cfg(feature = "full")]
+    ExprForLoop(&'ast ExprForLoop),
+    #[cfg(feature = "full")]
+    ExprGroup(&'ast ExprGroup),
+    #[cfg(feature = "full")]
+    ExprIf(&'ast ExprIf),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprIndex(&'ast ExprIndex),
+    #[cfg(feature = "full")]
+    ExprLet(&'ast ExprLet),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprLit(&'ast ExprLit),
+    #[cfg(feature = "full")]
+    ExprLoop(&'ast ExprLoop),
+    #[cfg(feature = "full")]
+    ExprMacro(&'ast ExprMacro),
+    #[cfg(feature = "full")]
+    ExprMatch(&'ast ExprMatch),
+    #[cfg(feature = "full")]
+    ExprMethodCall(&'ast ExprMethodCall),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprParen(&'ast ExprParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprPath(&'ast ExprPath),
+    #[cfg(feature = "full")]
+    ExprRange(&'ast ExprRange),
+    #[cfg(feature = "full")]
+    ExprReference(&'ast ExprReference),
+    #[cfg(feature = "full")]
+    ExprRepeat(&'ast ExprRepeat),
+    #[cfg(feature = "full")]
+    ExprReturn(&'ast ExprReturn),
+    #[cfg(feature = "full")]
+    ExprStruct(&'ast ExprStruct),
+    #[cfg(feature = "full")]
+    ExprTry(&'ast ExprTry),
+    #[cfg(feature = "full")]
+    ExprTryBlock(&'ast ExprTryBlock),
+    #[cfg(feature = "full")]
+    ExprTuple(&'ast ExprTuple),
+    #[cfg(feature = "full")]
+    ExprType(&'ast ExprType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprUnary(&'ast ExprUnary),
+    #[cfg(feature = "full")]
+    ExprUnsafe(&'ast ExprUnsafe),
+    #[cfg(feature = "full")]
+    ExprWhile(&'ast ExprWhile),
+    #[cfg(feature = "full")]
+    ExprYield(&'ast ExprYield),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Field(&'ast Field),
+    #[cfg(feature = "full")]
+    FieldPat(&'ast FieldPat),
+    #[cfg(feature = "full")]
+    FieldValue(&'ast FieldValue),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Fields(&'ast Fields),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsNamed(&'ast FieldsNamed),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsUnnamed(&'ast FieldsUnnamed),
+    #[cfg(feature = "full")]
+    File(&'ast File),
+    #[cfg(feature = "full")]
+    FnArg(&'ast FnArg),
+    #[cfg(feature = "full")]
+    ForeignItem(&'ast ForeignItem),
+    #[cfg(feature = "full")]
+    ForeignItemFn(&'ast ForeignItemFn),
+    #[cfg(feature = "full")]
+    ForeignItemMacro(&'ast ForeignItemMacro),
+    #[cfg(feature = "full")]
+    ForeignItemStatic(&'ast ForeignItemStatic),
+    #[cfg(feature = "full")]
+    ForeignItemType(&'ast ForeignItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericArgument(&'ast GenericArgument),
+    #[cfg(feature = "full")]
+    GenericMethodArgument(&'ast GenericMethodArgument),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericParam(&'ast GenericParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Generics(&'ast Generics),
+    Ident(&'ast Ident),
+    #[cfg(feature = "full")]
+    ImplItem(&'ast ImplItem),
+    #[cfg(feature = "full")]
+    ImplItemConst(&'ast ImplItemConst),
+    #[cfg(feature = "full")]
+    ImplItemMacro(&'ast ImplItemMacro),
+    #[cfg(feature = "full")]
+    ImplItemMethod(&'ast ImplItemMethod),
+    #[cfg(feature = "full")]
+    ImplItemType(&'ast ImplItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Index(&'ast Index),
+    #[cfg(feature = "full")]
+    Item(&'ast Item),
+    #[cfg(feature = "full")]
+    ItemConst(&'ast ItemConst),
+    #[cfg(feature = "full")]
+    ItemEnum(&'ast ItemEnum),
+    #[cfg(feature = "full")]
+    ItemExternCrate(&'ast ItemExternCrate),
+    #[cfg(feature = "full")]
+    ItemFn(&'ast ItemFn),
+    #[cfg(feature = "full")]
+    ItemForeignMod(&'ast ItemForeignMod),
+    #[cfg(feature = "full")]
+    ItemImpl(&'ast ItemImpl),
+    #[cfg(feature = "full")]
+    ItemMacro(&'ast ItemMacro),
+    #[cfg(feature = "full")]
+    ItemMacro2(&'ast ItemMacro2),
+    #[cfg(feature = "full")]
+    ItemMod(&'ast ItemMod),
+    #[cfg(feature = "full")]
+    ItemStatic(&'ast ItemStatic),
+    #[cfg(feature = "full")]
+    ItemStruct(&'ast ItemStruct),
+    #[cfg(feature = "full")]
+    ItemTrait(&'ast ItemTrait),
+    #[cfg(feature = "full")]
+    ItemTraitAlias(&'ast ItemTraitAlias),
+    #[cfg(feature = "full")]
+    ItemType(&'ast ItemType),
+    #[cfg(feature = "full")]
+    ItemUnion(&'ast ItemUnion),
+    #[cfg(feature = "full")]
+    ItemUse(&'ast ItemUse),
+    #[cfg(feature = "full")]
+    Label(&'ast Label),
+    Lifetime(&'ast Lifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LifetimeDef(&'ast LifetimeDef),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Lit(&'ast Lit),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitBool(&'ast LitBool),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByte(&'ast LitByte),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByteStr(&'ast LitByteStr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitChar(&'ast LitChar),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitFloat(&'ast LitFloat),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitInt(&'ast LitInt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitStr(&'ast LitStr),
+    #[cfg(feature = "full")]
+    Local(&'ast Local),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Macro(&'ast Macro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MacroDelimiter(&'ast MacroDelimiter),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Member(&'ast Member),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Meta(&'ast Meta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaList(&'ast MetaList),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaNameValue(&'ast MetaNameValue),
+    #[cfg(feature = "full")]
+    MethodTurbofish(&'ast MethodTurbofish),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMeta(&'ast NestedMeta),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ParenthesizedGenericArguments(&'ast ParenthesizedGenericArguments),
+    #[cfg(feature = "full")]
+    Pat(&'ast Pat),
+    #[cfg(feature = "full")]
+    PatBox(&'ast PatBox),
+    #[cfg(feature = "full")]
+    PatIdent(&'ast PatIdent),
+    #[cfg(feature = "full")]
+    PatLit(&'ast PatLit),
+    #[cfg(feature = "full")]
+    PatMacro(&'ast PatMacro),
+    #[cfg(feature = "full")]
+    PatOr(&'ast PatOr),
+    #[cfg(feature = "full")]
+    PatPath(&'ast PatPath),
+    #[cfg(feature = "full")]
+    PatRange(&'ast PatRange),
+    #[cfg(feature = "full")]
+    PatReference(&'ast PatReference),
+    #[cfg(feature = "full")]
+    PatRest(&'ast PatRest),
+    #[cfg(feature = "full")]
+    PatSlice(&'ast PatSlice),
+    #[cfg(feature = "full")]
+    PatStruct(&'ast PatStruct),
+    #[cfg(feature = "full")]
+    PatTuple(&'ast PatTuple),
+    #[cfg(feature = "full")]
+    PatTupleStruct(&'ast PatTupleStruct),
+    #[cfg(feature = "full")]
+    PatType(&'ast PatType),
+    #[cfg(feature = "full")]
+    PatWild(&'ast PatWild),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Path(&'ast Path),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathArguments(&'ast PathArguments),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathSegment(&'ast PathSegment),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateEq(&'ast PredicateEq),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateLifetime(&'ast PredicateLifetime),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateType(&'ast PredicateType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    QSelf(&'ast QSelf),
+    #[cfg(feature = "full")]
+    RangeLimits(&'ast RangeLimits),
+    #[cfg(feature = "full")]
+    Receiver(&'ast Receiver),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ReturnType(&'ast ReturnType),
+    #[cfg(feature = "full")]
+    Signature(&'ast Signature),
+    #[cfg(feature = "full")]
+    Stmt(&'ast Stmt),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBound(&'ast TraitBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBoundModifier(&'ast TraitBoundModifier),
+    #[cfg(feature = "full")]
+    TraitItem(&'ast TraitItem),
+    #[cfg(feature = "full")]
+    TraitItemConst(&'ast TraitItemConst),
+    #[cfg(feature = "full")]
+    TraitItemMacro(&'ast TraitItemMacro),
+    #[cfg(feature = "full")]
+    TraitItemMethod(&'ast TraitItemMethod),
+    #[cfg(feature = "full")]
+    TraitItemType(&'ast TraitItemType),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Type(&'ast Type),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeArray(&'ast TypeArray),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeBareFn(&'ast TypeBareFn),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeGroup(&'ast TypeGroup),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeImplTrait(&'ast TypeImplTrait),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeInfer(&'ast TypeInfer),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeMacro(&'ast TypeMacro),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeNever(&'ast TypeNever),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParam(&'ast TypeParam),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParamBound(&'ast TypeParamBound),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParen(&'ast TypeParen),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePath(&'ast TypePath),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePtr(&'ast TypePtr),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeReference(&'ast TypeReference),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeSlice(&'ast TypeSlice),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTraitObject(&'ast TypeTraitObject),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTuple(&'ast TypeTuple),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    UnOp(&'ast UnOp),
+    #[cfg(feature = "full")]
+    UseGlob(&'ast UseGlob),
+    #[cfg(feature = "full")]
+    UseGroup(&'ast UseGroup),
+    #[cfg(feature = "full")]
+    UseName(&'ast UseName),
+    #[cfg(feature = "full")]
+    UsePath(&'ast UsePath),
+    #[cfg(feature = "full")]
+    UseRename(&'ast UseRename),
+    #[cfg(feature = "full")]
+    UseTree(&'ast UseTree),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variadic(&'ast Variadic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variant(&'ast Variant),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisCrate(&'ast VisCrate),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisPublic(&'ast VisPublic),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisRestricted(&'ast VisRestricted),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Visibility(&'ast Visibility),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WhereClause(&'ast WhereClause),
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WherePredicate(&'ast WherePredicate),
+}
+#[cfg(feature = "visit")]
+impl<'ast> Node<'ast> {
+    /// The type of syntax tree node this is.
+    pub fn kind(self) -> NodeKind {
+        match self {
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Abi(_) => NodeKind::Abi,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::AngleBracketedGenericArguments(_) => NodeKind::AngleBracketedGenericArguments,
+            #[cfg(feature = "full")]
+            Node::Arm(_) => NodeKind::Arm,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::AttrStyle(_) => NodeKind::AttrStyle,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Attribute(_) => NodeKind::Attribute,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::BareFnArg(_) => NodeKind::BareFnArg,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::BinOp(_) => NodeKind::BinOp,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Binding(_) => NodeKind::Binding,
+            #[cfg(feature = "full")]
+            Node::Block(_) => NodeKind::Block,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::BoundLifetimes(_) => NodeKind::BoundLifetimes,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ConstParam(_) => NodeKind::ConstParam,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Constraint(_) => NodeKind::Constraint,
+            #[cfg(feature = "derive")]
+            Node::Data(_) => NodeKind::Data,
+            #[cfg(feature = "derive")]
+            Node::DataEnum(_) => NodeKind::DataEnum,
+            #[cfg(feature = "derive")]
+            Node::DataStruct(_) => NodeKind::DataStruct,
+            #[cfg(feature = "derive")]
+            Node::DataUnion(_) => NodeKind::DataUnion,
+            #[cfg(feature = "derive")]
+            Node::DeriveInput(_) => NodeKind::DeriveInput,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Expr(_) => NodeKind::Expr,
+            #[cfg(feature = "full")]
+            Node::ExprArray(_) => NodeKind::ExprArray,
+            #[cfg(feature = "full")]
+            Node::ExprAssign(_) => NodeKind::ExprAssign,
+            #[cfg(feature = "full")]
+            Node::ExprAssignOp(_) => NodeKind::ExprAssignOp,
+            #[cfg(feature = "full")]
+            Node::ExprAsync(_) => NodeKind::ExprAsync,
+            #[cfg(feature = "full")]
+            Node::ExprAwait(_) => NodeKind::ExprAwait,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprBinary(_) => NodeKind::ExprBinary,
+            #[cfg(feature = "full")]
+            Node::ExprBlock(_) => NodeKind::ExprBlock,
+            #[cfg(feature = "full")]
+            Node::ExprBox(_) => NodeKind::ExprBox,
+            #[cfg(feature = "full")]
+            Node::ExprBreak(_) => NodeKind::ExprBreak,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprCall(_) => NodeKind::ExprCall,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprCast(_) => NodeKind::ExprCast,
+            #[cfg(feature = "full")]
+            Node::ExprClosure(_) => NodeKind::ExprClosure,
+            #[cfg(feature = "full")]
+            Node::ExprContinue(_) => NodeKind::ExprContinue,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprField(_) => NodeKind::ExprField,
+            #[cfg(feature = "full")]
+            Node::ExprForLoop(_) => NodeKind::ExprForLoop,
+            #[cfg(feature = "full")]
+            Node::ExprGroup(_) => NodeKind::ExprGroup,
+            #[cfg(feature = "full")]
+            Node::ExprIf(_) => NodeKind::ExprIf,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprIndex(_) => NodeKind::ExprIndex,
+            #[cfg(feature = "full")]
+            Node::ExprLet(_) => NodeKind::ExprLet,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprLit(_) => NodeKind::ExprLit,
+            #[cfg(feature = "full")]
+            Node::ExprLoop(_) => NodeKind::ExprLoop,
+            #[cfg(feature = "full")]
+            Node::ExprMacro(_) => NodeKind::ExprMacro,
+            #[cfg(feature = "full")]
+            Node::ExprMatch(_) => NodeKind::ExprMatch,
+            #[cfg(feature = "full")]
+            Node::ExprMethodCall(_) => NodeKind::ExprMethodCall,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprParen(_) => NodeKind::ExprParen,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprPath(_) => NodeKind::ExprPath,
+            #[cfg(feature = "full")]
+            Node::ExprRange(_) => NodeKind::ExprRange,
+            #[cfg(feature = "full")]
+            Node::ExprReference(_) => NodeKind::ExprReference,
+            #[cfg(feature = "full")]
+            Node::ExprRepeat(_) => NodeKind::ExprRepeat,
+            #[cfg(feature = "full")]
+            Node::ExprReturn(_) => NodeKind::ExprReturn,
+            #[cfg(feature = "full")]
+            Node::ExprStruct(_) => NodeKind::ExprStruct,
+            #[cfg(feature = "full")]
+            Node::ExprTry(_) => NodeKind::ExprTry,
+            #[cfg(feature = "full")]
+            Node::ExprTryBlock(_) => NodeKind::ExprTryBlock,
+            #[cfg(feature = "full")]
+            Node::ExprTuple(_) => NodeKind::ExprTuple,
+            #[cfg(feature = "full")]
+            Node::ExprType(_) => NodeKind::ExprType,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ExprUnary(_) => NodeKind::ExprUnary,
+            #[cfg(feature = "full")]
+            Node::ExprUnsafe(_) => NodeKind::ExprUnsafe,
+            #[cfg(feature = "full")]
+            Node::ExprWhile(_) => NodeKind::ExprWhile,
+            #[cfg(feature = "full")]
+            Node::ExprYield(_) => NodeKind::ExprYield,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Field(_) => NodeKind::Field,
+            #[cfg(feature = "full")]
+            Node::FieldPat(_) => NodeKind::FieldPat,
+            #[cfg(feature = "full")]
+            Node::FieldValue(_) => NodeKind::FieldValue,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Fields(_) => NodeKind::Fields,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::FieldsNamed(_) => NodeKind::FieldsNamed,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::FieldsUnnamed(_) => NodeKind::FieldsUnnamed,
+            #[cfg(feature = "full")]
+            Node::File(_) => NodeKind::File,
+            #[cfg(feature = "full")]
+            Node::FnArg(_) => NodeKind::FnArg,
+            #[cfg(feature = "full")]
+            Node::ForeignItem(_) => NodeKind::ForeignItem,
+            #[cfg(feature = "full")]
+            Node::ForeignItemFn(_) => NodeKind::ForeignItemFn,
+            #[cfg(feature = "full")]
+            Node::ForeignItemMacro(_) => NodeKind::ForeignItemMacro,
+            #[cfg(feature = "full")]
+            Node::ForeignItemStatic(_) => NodeKind::ForeignItemStatic,
+            #[cfg(feature = "full")]
+            Node::ForeignItemType(_) => NodeKind::ForeignItemType,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::GenericArgument(_) => NodeKind::GenericArgument,
+            #[cfg(feature = "full")]
+            Node::GenericMethodArgument(_) => NodeKind::GenericMethodArgument,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::GenericParam(_) => NodeKind::GenericParam,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Generics(_) => NodeKind::Generics,
+            Node::Ident(_) => NodeKind::Ident,
+            #[cfg(feature = "full")]
+            Node::ImplItem(_) => NodeKind::ImplItem,
+            #[cfg(feature = "full")]
+            Node::ImplItemConst(_) => NodeKind::ImplItemConst,
+            #[cfg(feature = "full")]
+            Node::ImplItemMacro(_) => NodeKind::ImplItemMacro,
+            #[cfg(feature = "full")]
+            Node::ImplItemMethod(_) => NodeKind::ImplItemMethod,
+            #[cfg(feature = "full")]
+            Node::ImplItemType(_) => NodeKind::ImplItemType,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Index(_) => NodeKind::Index,
+            #[cfg(feature = "full")]
+            Node::Item(_) => NodeKind::Item,
+            #[cfg(feature = "full")]
+            Node::ItemConst(_) => NodeKind::ItemConst,
+            #[cfg(feature = "full")]
+            Node::ItemEnum(_) => NodeKind::ItemEnum,
+            #[cfg(feature = "full")]
+            Node::ItemExternCrate(_) => NodeKind::ItemExternCrate,
+            #[cfg(feature = "full")]
+            Node::ItemFn(_) => NodeKind::ItemFn,
+            #[cfg(feature = "full")]
+            Node::ItemForeignMod(_) => NodeKind::ItemForeignMod,
+            #[cfg(feature = "full")]
+            Node::ItemImpl(_) => NodeKind::ItemImpl,
+            #[cfg(feature = "full")]
+            Node::ItemMacro(_) => NodeKind::ItemMacro,
+            #[cfg(feature = "full")]
+            Node::ItemMacro2(_) => NodeKind::ItemMacro2,
+            #[cfg(feature = "full")]
+            Node::ItemMod(_) => NodeKind::ItemMod,
+            #[cfg(feature = "full")]
+            Node::ItemStatic(_) => NodeKind::ItemStatic,
+            #[cfg(feature = "full")]
+            Node::ItemStruct(_) => NodeKind::ItemStruct,
+            #[cfg(feature = "full")]
+            Node::ItemTrait(_) => NodeKind::ItemTrait,
+            #[cfg(feature = "full")]
+            Node::ItemTraitAlias(_) => NodeKind::ItemTraitAlias,
+            #[cfg(feature = "full")]
+            Node::ItemType(_) => NodeKind::ItemType,
+            #[cfg(feature = "full")]
+            Node::ItemUnion(_) => NodeKind::ItemUnion,
+            #[cfg(feature = "full")]
+            Node::ItemUse(_) => NodeKind::ItemUse,
+            #[cfg(feature = "full")]
+            Node::Label(_) => NodeKind::Label,
+            Node::Lifetime(_) => NodeKind::Lifetime,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LifetimeDef(_) => NodeKind::LifetimeDef,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Lit(_) => NodeKind::Lit,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitBool(_) => NodeKind::LitBool,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitByte(_) => NodeKind::LitByte,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitByteStr(_) => NodeKind::LitByteStr,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitChar(_) => NodeKind::LitChar,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitFloat(_) => NodeKind::LitFloat,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitInt(_) => NodeKind::LitInt,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::LitStr(_) => NodeKind::LitStr,
+            #[cfg(feature = "full")]
+            Node::Local(_) => NodeKind::Local,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Macro(_) => NodeKind::Macro,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::MacroDelimiter(_) => NodeKind::MacroDelimiter,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Member(_) => NodeKind::Member,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Meta(_) => NodeKind::Meta,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::MetaList(_) => NodeKind::MetaList,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::MetaNameValue(_) => NodeKind::MetaNameValue,
+            #[cfg(feature = "full")]
+            Node::MethodTurbofish(_) => NodeKind::MethodTurbofish,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::NestedMeta(_) => NodeKind::NestedMeta,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ParenthesizedGenericArguments(_) => NodeKind::ParenthesizedGenericArguments,
+            #[cfg(feature = "full")]
+            Node::Pat(_) => NodeKind::Pat,
+            #[cfg(feature = "full")]
+            Node::PatBox(_) => NodeKind::PatBox,
+            #[cfg(feature = "full")]
+            Node::PatIdent(_) => NodeKind::PatIdent,
+            #[cfg(feature = "full")]
+            Node::PatLit(_) => NodeKind::PatLit,
+            #[cfg(feature = "full")]
+            Node::PatMacro(_) => NodeKind::PatMacro,
+            #[cfg(feature = "full")]
+            Node::PatOr(_) => NodeKind::PatOr,
+            #[cfg(feature = "full")]
+            Node::PatPath(_) => NodeKind::PatPath,
+            #[cfg(feature = "full")]
+            Node::PatRange(_) => NodeKind::PatRange,
+            #[cfg(feature = "full")]
+            Node::PatReference(_) => NodeKind::PatReference,
+            #[cfg(feature = "full")]
+            Node::PatRest(_) => NodeKind::PatRest,
+            #[cfg(feature = "full")]
+            Node::PatSlice(_) => NodeKind::PatSlice,
+            #[cfg(feature = "full")]
+            Node::PatStruct(_) => NodeKind::PatStruct,
+            #[cfg(feature = "full")]
+            Node::PatTuple(_) => NodeKind::PatTuple,
+            #[cfg(feature = "full")]
+            Node::PatTupleStruct(_) => NodeKind::PatTupleStruct,
+            #[cfg(feature = "full")]
+            Node::PatType(_) => NodeKind::PatType,
+            #[cfg(feature = "full")]
+            Node::PatWild(_) => NodeKind::PatWild,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Path(_) => NodeKind::Path,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::PathArguments(_) => NodeKind::PathArguments,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::PathSegment(_) => NodeKind::PathSegment,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::PredicateEq(_) => NodeKind::PredicateEq,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::PredicateLifetime(_) => NodeKind::PredicateLifetime,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::PredicateType(_) => NodeKind::PredicateType,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::QSelf(_) => NodeKind::QSelf,
+            #[cfg(feature = "full")]
+            Node::RangeLimits(_) => NodeKind::RangeLimits,
+            #[cfg(feature = "full")]
+            Node::Receiver(_) => NodeKind::Receiver,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::ReturnType(_) => NodeKind::ReturnType,
+            #[cfg(feature = "full")]
+            Node::Signature(_) => NodeKind::Signature,
+            #[cfg(feature = "full")]
+            Node::Stmt(_) => NodeKind::Stmt,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TraitBound(_) => NodeKind::TraitBound,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TraitBoundModifier(_) => NodeKind::TraitBoundModifier,
+            #[cfg(feature = "full")]
+            Node::TraitItem(_) => NodeKind::TraitItem,
+            #[cfg(feature = "full")]
+            Node::TraitItemConst(_) => NodeKind::TraitItemConst,
+            #[cfg(feature = "full")]
+            Node::TraitItemMacro(_) => NodeKind::TraitItemMacro,
+            #[cfg(feature = "full")]
+            Node::TraitItemMethod(_) => NodeKind::TraitItemMethod,
+            #[cfg(feature = "full")]
+            Node::TraitItemType(_) => NodeKind::TraitItemType,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Type(_) => NodeKind::Type,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeArray(_) => NodeKind::TypeArray,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeBareFn(_) => NodeKind::TypeBareFn,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeGroup(_) => NodeKind::TypeGroup,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeImplTrait(_) => NodeKind::TypeImplTrait,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeInfer(_) => NodeKind::TypeInfer,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeMacro(_) => NodeKind::TypeMacro,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeNever(_) => NodeKind::TypeNever,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeParam(_) => NodeKind::TypeParam,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeParamBound(_) => NodeKind::TypeParamBound,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeParen(_) => NodeKind::TypeParen,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypePath(_) => NodeKind::TypePath,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypePtr(_) => NodeKind::TypePtr,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeReference(_) => NodeKind::TypeReference,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeSlice(_) => NodeKind::TypeSlice,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeTraitObject(_) => NodeKind::TypeTraitObject,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::TypeTuple(_) => NodeKind::TypeTuple,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::UnOp(_) => NodeKind::UnOp,
+            #[cfg(feature = "full")]
+            Node::UseGlob(_) => NodeKind::UseGlob,
+            #[cfg(feature = "full")]
+            Node::UseGroup(_) => NodeKind::UseGroup,
+            #[cfg(feature = "full")]
+            Node::UseName(_) => NodeKind::UseName,
+            #[cfg(feature = "full")]
+            Node::UsePath(_) => NodeKind::UsePath,
+            #[cfg(feature = "full")]
+            Node::UseRename(_) => NodeKind::UseRename,
+            #[cfg(feature = "full")]
+            Node::UseTree(_) => NodeKind::UseTree,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Variadic(_) => NodeKind::Variadic,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Variant(_) => NodeKind::Variant,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::VisCrate(_) => NodeKind::VisCrate,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::VisPublic(_) => NodeKind::VisPublic,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::VisRestricted(_) => NodeKind::VisRestricted,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::Visibility(_) => NodeKind::Visibility,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::WhereClause(_) => NodeKind::WhereClause,
+            #[cfg(any(feature = "derive", feature = "full"))]
+            Node::WherePredicate(_) => NodeKind::WherePredicate,
+        }
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Abi> for Node<'ast> {
+    fn from(node: &'ast Abi) -> Self {
+        Node::Abi(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast AngleBracketedGenericArguments> for Node<'ast> {
+    fn from(node: &'ast AngleBracketedGenericArguments) -> Self {
+        Node::AngleBracketedGenericArguments(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Arm> for Node<'ast> {
+    fn from(node: &'ast Arm) -> Self {
+        Node::Arm(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast AttrStyle> for Node<'ast> {
+    fn from(node: &'ast AttrStyle) -> Self {
+        Node::AttrStyle(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Attribute> for Node<'ast> {
+    fn from(node: &'ast Attribute) -> Self {
+        Node::Attribute(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast BareFnArg> for Node<'ast> {
+    fn from(node: &'ast BareFnArg) -> Self {
+        Node::BareFnArg(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast BinOp> for Node<'ast> {
+    fn from(node: &'ast BinOp) -> Self {
+        Node::BinOp(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Binding> for Node<'ast> {
+    fn from(node: &'ast Binding) -> Self {
+        Node::Binding(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Block> for Node<'ast> {
+    fn from(node: &'ast Block) -> Self {
+        Node::Block(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast BoundLifetimes> for Node<'ast> {
+    fn from(node: &'ast BoundLifetimes) -> Self {
+        Node::BoundLifetimes(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ConstParam> for Node<'ast> {
+    fn from(node: &'ast ConstParam) -> Self {
+        Node::ConstParam(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Constraint> for Node<'ast> {
+    fn from(node: &'ast Constraint) -> Self {
+        Node::Constraint(node)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Data> for Node<'ast> {
+    fn from(node: &'ast Data) -> Self {
+        Node::Data(node)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast DataEnum> for Node<'ast> {
+    fn from(node: &'ast DataEnum) -> Self {
+        Node::DataEnum(node)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast DataStruct> for Node<'ast> {
+    fn from(node: &'ast DataStruct) -> Self {
+        Node::DataStruct(node)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast DataUnion> for Node<'ast> {
+    fn from(node: &'ast DataUnion) -> Self {
+        Node::DataUnion(node)
+    }
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast DeriveInput> for Node<'ast> {
+    fn from(node: &'ast DeriveInput) -> Self {
+        Node::DeriveInput(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Expr> for Node<'ast> {
+    fn from(node: &'ast Expr) -> Self {
+        Node::Expr(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprArray> for Node<'ast> {
+    fn from(node: &'ast ExprArray) -> Self {
+        Node::ExprArray(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprAssign> for Node<'ast> {
+    fn from(node: &'ast ExprAssign) -> Self {
+        Node::ExprAssign(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprAssignOp> for Node<'ast> {
+    fn from(node: &'ast ExprAssignOp) -> Self {
+        Node::ExprAssignOp(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprAsync> for Node<'ast> {
+    fn from(node: &'ast ExprAsync) -> Self {
+        Node::ExprAsync(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprAwait> for Node<'ast> {
+    fn from(node: &'ast ExprAwait) -> Self {
+        Node::ExprAwait(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprBinary> for Node<'ast> {
+    fn from(node: &'ast ExprBinary) -> Self {
+        Node::ExprBinary(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprBlock> for Node<'ast> {
+    fn from(node: &'ast ExprBlock) -> Self {
+        Node::ExprBlock(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprBox> for Node<'ast> {
+    fn from(node: &'ast ExprBox) -> Self {
+        Node::ExprBox(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprBreak> for Node<'ast> {
+    fn from(node: &'ast ExprBreak) -> Self {
+        Node::ExprBreak(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprCall> for Node<'ast> {
+    fn from(node: &'ast ExprCall) -> Self {
+        Node::ExprCall(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprCast> for Node<'ast> {
+    fn from(node: &'ast ExprCast) -> Self {
+        Node::ExprCast(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprClosure> for Node<'ast> {
+    fn from(node: &'ast ExprClosure) -> Self {
+        Node::ExprClosure(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprContinue> for Node<'ast> {
+    fn from(node: &'ast ExprContinue) -> Self {
+        Node::ExprContinue(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprField> for Node<'ast> {
+    fn from(node: &'ast ExprField) -> Self {
+        Node::ExprField(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprForLoop> for Node<'ast> {
+    fn from(node: &'ast ExprForLoop) -> Self {
+        Node::ExprForLoop(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprGroup> for Node<'ast> {
+    fn from(node: &'ast ExprGroup) -> Self {
+        Node::ExprGroup(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprIf> for Node<'ast> {
+    fn from(node: &'ast ExprIf) -> Self {
+        Node::ExprIf(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprIndex> for Node<'ast> {
+    fn from(node: &'ast ExprIndex) -> Self {
+        Node::ExprIndex(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprLet> for Node<'ast> {
+    fn from(node: &'ast ExprLet) -> Self {
+        Node::ExprLet(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprLit> for Node<'ast> {
+    fn from(node: &'ast ExprLit) -> Self {
+        Node::ExprLit(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprLoop> for Node<'ast> {
+    fn from(node: &'ast ExprLoop) -> Self {
+        Node::ExprLoop(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprMacro> for Node<'ast> {
+    fn from(node: &'ast ExprMacro) -> Self {
+        Node::ExprMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprMatch> for Node<'ast> {
+    fn from(node: &'ast ExprMatch) -> Self {
+        Node::ExprMatch(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprMethodCall> for Node<'ast> {
+    fn from(node: &'ast ExprMethodCall) -> Self {
+        Node::ExprMethodCall(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprParen> for Node<'ast> {
+    fn from(node: &'ast ExprParen) -> Self {
+        Node::ExprParen(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprPath> for Node<'ast> {
+    fn from(node: &'ast ExprPath) -> Self {
+        Node::ExprPath(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprRange> for Node<'ast> {
+    fn from(node: &'ast ExprRange) -> Self {
+        Node::ExprRange(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprReference> for Node<'ast> {
+    fn from(node: &'ast ExprReference) -> Self {
+        Node::ExprReference(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprRepeat> for Node<'ast> {
+    fn from(node: &'ast ExprRepeat) -> Self {
+        Node::ExprRepeat(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprReturn> for Node<'ast> {
+    fn from(node: &'ast ExprReturn) -> Self {
+        Node::ExprReturn(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprStruct> for Node<'ast> {
+    fn from(node: &'ast ExprStruct) -> Self {
+        Node::ExprStruct(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprTry> for Node<'ast> {
+    fn from(node: &'ast ExprTry) -> Self {
+        Node::ExprTry(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprTryBlock> for Node<'ast> {
+    fn from(node: &'ast ExprTryBlock) -> Self {
+        Node::ExprTryBlock(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprTuple> for Node<'ast> {
+    fn from(node: &'ast ExprTuple) -> Self {
+        Node::ExprTuple(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprType> for Node<'ast> {
+    fn from(node: &'ast ExprType) -> Self {
+        Node::ExprType(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprUnary> for Node<'ast> {
+    fn from(node: &'ast ExprUnary) -> Self {
+        Node::ExprUnary(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprUnsafe> for Node<'ast> {
+    fn from(node: &'ast ExprUnsafe) -> Self {
+        Node::ExprUnsafe(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprWhile> for Node<'ast> {
+    fn from(node: &'ast ExprWhile) -> Self {
+        Node::ExprWhile(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ExprYield> for Node<'ast> {
+    fn from(node: &'ast ExprYield) -> Self {
+        Node::ExprYield(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Field> for Node<'ast> {
+    fn from(node: &'ast Field) -> Self {
+        Node::Field(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast FieldPat> for Node<'ast> {
+    fn from(node: &'ast FieldPat) -> Self {
+        Node::FieldPat(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast FieldValue> for Node<'ast> {
+    fn from(node: &'ast FieldValue) -> Self {
+        Node::FieldValue(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Fields> for Node<'ast> {
+    fn from(node: &'ast Fields) -> Self {
+        Node::Fields(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast FieldsNamed> for Node<'ast> {
+    fn from(node: &'ast FieldsNamed) -> Self {
+        Node::FieldsNamed(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast FieldsUnnamed> for Node<'ast> {
+    fn from(node: &'ast FieldsUnnamed) -> Self {
+        Node::FieldsUnnamed(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast File> for Node<'ast> {
+    fn from(node: &'ast File) -> Self {
+        Node::File(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast FnArg> for Node<'ast> {
+    fn from(node: &'ast FnArg) -> Self {
+        Node::FnArg(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ForeignItem> for Node<'ast> {
+    fn from(node: &'ast ForeignItem) -> Self {
+        Node::ForeignItem(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ForeignItemFn> for Node<'ast> {
+    fn from(node: &'ast ForeignItemFn) -> Self {
+        Node::ForeignItemFn(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ForeignItemMacro> for Node<'ast> {
+    fn from(node: &'ast ForeignItemMacro) -> Self {
+        Node::ForeignItemMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ForeignItemStatic> for Node<'ast> {
+    fn from(node: &'ast ForeignItemStatic) -> Self {
+        Node::ForeignItemStatic(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ForeignItemType> for Node<'ast> {
+    fn from(node: &'ast ForeignItemType) -> Self {
+        Node::ForeignItemType(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast GenericArgument> for Node<'ast> {
+    fn from(node: &'ast GenericArgument) -> Self {
+        Node::GenericArgument(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast GenericMethodArgument> for Node<'ast> {
+    fn from(node: &'ast GenericMethodArgument) -> Self {
+        Node::GenericMethodArgument(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast GenericParam> for Node<'ast> {
+    fn from(node: &'ast GenericParam) -> Self {
+        Node::GenericParam(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Generics> for Node<'ast> {
+    fn from(node: &'ast Generics) -> Self {
+        Node::Generics(node)
+    }
+}
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Ident> for Node<'ast> {
+    fn from(node: &'ast Ident) -> Self {
+        Node::Ident(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ImplItem> for Node<'ast> {
+    fn from(node: &'ast ImplItem) -> Self {
+        Node::ImplItem(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ImplItemConst> for Node<'ast> {
+    fn from(node: &'ast ImplItemConst) -> Self {
+        Node::ImplItemConst(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ImplItemMacro> for Node<'ast> {
+    fn from(node: &'ast ImplItemMacro) -> Self {
+        Node::ImplItemMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ImplItemMethod> for Node<'ast> {
+    fn from(node: &'ast ImplItemMethod) -> Self {
+        Node::ImplItemMethod(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ImplItemType> for Node<'ast> {
+    fn from(node: &'ast ImplItemType) -> Self {
+        Node::ImplItemType(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Index> for Node<'ast> {
+    fn from(node: &'ast Index) -> Self {
+        Node::Index(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Item> for Node<'ast> {
+    fn from(node: &'ast Item) -> Self {
+        Node::Item(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemConst> for Node<'ast> {
+    fn from(node: &'ast ItemConst) -> Self {
+        Node::ItemConst(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemEnum> for Node<'ast> {
+    fn from(node: &'ast ItemEnum) -> Self {
+        Node::ItemEnum(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemExternCrate> for Node<'ast> {
+    fn from(node: &'ast ItemExternCrate) -> Self {
+        Node::ItemExternCrate(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemFn> for Node<'ast> {
+    fn from(node: &'ast ItemFn) -> Self {
+        Node::ItemFn(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemForeignMod> for Node<'ast> {
+    fn from(node: &'ast ItemForeignMod) -> Self {
+        Node::ItemForeignMod(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemImpl> for Node<'ast> {
+    fn from(node: &'ast ItemImpl) -> Self {
+        Node::ItemImpl(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemMacro> for Node<'ast> {
+    fn from(node: &'ast ItemMacro) -> Self {
+        Node::ItemMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemMacro2> for Node<'ast> {
+    fn from(node: &'ast ItemMacro2) -> Self {
+        Node::ItemMacro2(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemMod> for Node<'ast> {
+    fn from(node: &'ast ItemMod) -> Self {
+        Node::ItemMod(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemStatic> for Node<'ast> {
+    fn from(node: &'ast ItemStatic) -> Self {
+        Node::ItemStatic(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemStruct> for Node<'ast> {
+    fn from(node: &'ast ItemStruct) -> Self {
+        Node::ItemStruct(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemTrait> for Node<'ast> {
+    fn from(node: &'ast ItemTrait) -> Self {
+        Node::ItemTrait(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemTraitAlias> for Node<'ast> {
+    fn from(node: &'ast ItemTraitAlias) -> Self {
+        Node::ItemTraitAlias(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemType> for Node<'ast> {
+    fn from(node: &'ast ItemType) -> Self {
+        Node::ItemType(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemUnion> for Node<'ast> {
+    fn from(node: &'ast ItemUnion) -> Self {
+        Node::ItemUnion(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ItemUse> for Node<'ast> {
+    fn from(node: &'ast ItemUse) -> Self {
+        Node::ItemUse(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Label> for Node<'ast> {
+    fn from(node: &'ast Label) -> Self {
+        Node::Label(node)
+    }
+}
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Lifetime> for Node<'ast> {
+    fn from(node: &'ast Lifetime) -> Self {
+        Node::Lifetime(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LifetimeDef> for Node<'ast> {
+    fn from(node: &'ast LifetimeDef) -> Self {
+        Node::LifetimeDef(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Lit> for Node<'ast> {
+    fn from(node: &'ast Lit) -> Self {
+        Node::Lit(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitBool> for Node<'ast> {
+    fn from(node: &'ast LitBool) -> Self {
+        Node::LitBool(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitByte> for Node<'ast> {
+    fn from(node: &'ast LitByte) -> Self {
+        Node::LitByte(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitByteStr> for Node<'ast> {
+    fn from(node: &'ast LitByteStr) -> Self {
+        Node::LitByteStr(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitChar> for Node<'ast> {
+    fn from(node: &'ast LitChar) -> Self {
+        Node::LitChar(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitFloat> for Node<'ast> {
+    fn from(node: &'ast LitFloat) -> Self {
+        Node::LitFloat(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitInt> for Node<'ast> {
+    fn from(node: &'ast LitInt) -> Self {
+        Node::LitInt(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast LitStr> for Node<'ast> {
+    fn from(node: &'ast LitStr) -> Self {
+        Node::LitStr(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Local> for Node<'ast> {
+    fn from(node: &'ast Local) -> Self {
+        Node::Local(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Macro> for Node<'ast> {
+    fn from(node: &'ast Macro) -> Self {
+        Node::Macro(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast MacroDelimiter> for Node<'ast> {
+    fn from(node: &'ast MacroDelimiter) -> Self {
+        Node::MacroDelimiter(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Member> for Node<'ast> {
+    fn from(node: &'ast Member) -> Self {
+        Node::Member(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Meta> for Node<'ast> {
+    fn from(node: &'ast Meta) -> Self {
+        Node::Meta(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast MetaList> for Node<'ast> {
+    fn from(node: &'ast MetaList) -> Self {
+        Node::MetaList(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast MetaNameValue> for Node<'ast> {
+    fn from(node: &'ast MetaNameValue) -> Self {
+        Node::MetaNameValue(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast MethodTurbofish> for Node<'ast> {
+    fn from(node: &'ast MethodTurbofish) -> Self {
+        Node::MethodTurbofish(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast NestedMeta> for Node<'ast> {
+    fn from(node: &'ast NestedMeta) -> Self {
+        Node::NestedMeta(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ParenthesizedGenericArguments> for Node<'ast> {
+    fn from(node: &'ast ParenthesizedGenericArguments) -> Self {
+        Node::ParenthesizedGenericArguments(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Pat> for Node<'ast> {
+    fn from(node: &'ast Pat) -> Self {
+        Node::Pat(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatBox> for Node<'ast> {
+    fn from(node: &'ast PatBox) -> Self {
+        Node::PatBox(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatIdent> for Node<'ast> {
+    fn from(node: &'ast PatIdent) -> Self {
+        Node::PatIdent(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatLit> for Node<'ast> {
+    fn from(node: &'ast PatLit) -> Self {
+        Node::PatLit(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatMacro> for Node<'ast> {
+    fn from(node: &'ast PatMacro) -> Self {
+        Node::PatMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatOr> for Node<'ast> {
+    fn from(node: &'ast PatOr) -> Self {
+        Node::PatOr(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatPath> for Node<'ast> {
+    fn from(node: &'ast PatPath) -> Self {
+        Node::PatPath(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatRange> for Node<'ast> {
+    fn from(node: &'ast PatRange) -> Self {
+        Node::PatRange(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatReference> for Node<'ast> {
+    fn from(node: &'ast PatReference) -> Self {
+        Node::PatReference(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatRest> for Node<'ast> {
+    fn from(node: &'ast PatRest) -> Self {
+        Node::PatRest(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatSlice> for Node<'ast> {
+    fn from(node: &'ast PatSlice) -> Self {
+        Node::PatSlice(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatStruct> for Node<'ast> {
+    fn from(node: &'ast PatStruct) -> Self {
+        Node::PatStruct(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatTuple> for Node<'ast> {
+    fn from(node: &'ast PatTuple) -> Self {
+        Node::PatTuple(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatTupleStruct> for Node<'ast> {
+    fn from(node: &'ast PatTupleStruct) -> Self {
+        Node::PatTupleStruct(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatType> for Node<'ast> {
+    fn from(node: &'ast PatType) -> Self {
+        Node::PatType(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PatWild> for Node<'ast> {
+    fn from(node: &'ast PatWild) -> Self {
+        Node::PatWild(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Path> for Node<'ast> {
+    fn from(node: &'ast Path) -> Self {
+        Node::Path(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PathArguments> for Node<'ast> {
+    fn from(node: &'ast PathArguments) -> Self {
+        Node::PathArguments(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PathSegment> for Node<'ast> {
+    fn from(node: &'ast PathSegment) -> Self {
+        Node::PathSegment(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PredicateEq> for Node<'ast> {
+    fn from(node: &'ast PredicateEq) -> Self {
+        Node::PredicateEq(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PredicateLifetime> for Node<'ast> {
+    fn from(node: &'ast PredicateLifetime) -> Self {
+        Node::PredicateLifetime(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast PredicateType> for Node<'ast> {
+    fn from(node: &'ast PredicateType) -> Self {
+        Node::PredicateType(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast QSelf> for Node<'ast> {
+    fn from(node: &'ast QSelf) -> Self {
+        Node::QSelf(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast RangeLimits> for Node<'ast> {
+    fn from(node: &'ast RangeLimits) -> Self {
+        Node::RangeLimits(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Receiver> for Node<'ast> {
+    fn from(node: &'ast Receiver) -> Self {
+        Node::Receiver(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast ReturnType> for Node<'ast> {
+    fn from(node: &'ast ReturnType) -> Self {
+        Node::ReturnType(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Signature> for Node<'ast> {
+    fn from(node: &'ast Signature) -> Self {
+        Node::Signature(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Stmt> for Node<'ast> {
+    fn from(node: &'ast Stmt) -> Self {
+        Node::Stmt(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitBound> for Node<'ast> {
+    fn from(node: &'ast TraitBound) -> Self {
+        Node::TraitBound(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitBoundModifier> for Node<'ast> {
+    fn from(node: &'ast TraitBoundModifier) -> Self {
+        Node::TraitBoundModifier(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitItem> for Node<'ast> {
+    fn from(node: &'ast TraitItem) -> Self {
+        Node::TraitItem(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitItemConst> for Node<'ast> {
+    fn from(node: &'ast TraitItemConst) -> Self {
+        Node::TraitItemConst(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitItemMacro> for Node<'ast> {
+    fn from(node: &'ast TraitItemMacro) -> Self {
+        Node::TraitItemMacro(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitItemMethod> for Node<'ast> {
+    fn from(node: &'ast TraitItemMethod) -> Self {
+        Node::TraitItemMethod(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TraitItemType> for Node<'ast> {
+    fn from(node: &'ast TraitItemType) -> Self {
+        Node::TraitItemType(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Type> for Node<'ast> {
+    fn from(node: &'ast Type) -> Self {
+        Node::Type(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeArray> for Node<'ast> {
+    fn from(node: &'ast TypeArray) -> Self {
+        Node::TypeArray(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeBareFn> for Node<'ast> {
+    fn from(node: &'ast TypeBareFn) -> Self {
+        Node::TypeBareFn(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeGroup> for Node<'ast> {
+    fn from(node: &'ast TypeGroup) -> Self {
+        Node::TypeGroup(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeImplTrait> for Node<'ast> {
+    fn from(node: &'ast TypeImplTrait) -> Self {
+        Node::TypeImplTrait(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeInfer> for Node<'ast> {
+    fn from(node: &'ast TypeInfer) -> Self {
+        Node::TypeInfer(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeMacro> for Node<'ast> {
+    fn from(node: &'ast TypeMacro) -> Self {
+        Node::TypeMacro(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeNever> for Node<'ast> {
+    fn from(node: &'ast TypeNever) -> Self {
+        Node::TypeNever(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeParam> for Node<'ast> {
+    fn from(node: &'ast TypeParam) -> Self {
+        Node::TypeParam(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeParamBound> for Node<'ast> {
+    fn from(node: &'ast TypeParamBound) -> Self {
+        Node::TypeParamBound(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeParen> for Node<'ast> {
+    fn from(node: &'ast TypeParen) -> Self {
+        Node::TypeParen(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypePath> for Node<'ast> {
+    fn from(node: &'ast TypePath) -> Self {
+        Node::TypePath(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypePtr> for Node<'ast> {
+    fn from(node: &'ast TypePtr) -> Self {
+        Node::TypePtr(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeReference> for Node<'ast> {
+    fn from(node: &'ast TypeReference) -> Self {
+        Node::TypeReference(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeSlice> for Node<'ast> {
+    fn from(node: &'ast TypeSlice) -> Self {
+        Node::TypeSlice(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeTraitObject> for Node<'ast> {
+    fn from(node: &'ast TypeTraitObject) -> Self {
+        Node::TypeTraitObject(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast TypeTuple> for Node<'ast> {
+    fn from(node: &'ast TypeTuple) -> Self {
+        Node::TypeTuple(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UnOp> for Node<'ast> {
+    fn from(node: &'ast UnOp) -> Self {
+        Node::UnOp(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UseGlob> for Node<'ast> {
+    fn from(node: &'ast UseGlob) -> Self {
+        Node::UseGlob(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UseGroup> for Node<'ast> {
+    fn from(node: &'ast UseGroup) -> Self {
+        Node::UseGroup(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UseName> for Node<'ast> {
+    fn from(node: &'ast UseName) -> Self {
+        Node::UseName(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UsePath> for Node<'ast> {
+    fn from(node: &'ast UsePath) -> Self {
+        Node::UsePath(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UseRename> for Node<'ast> {
+    fn from(node: &'ast UseRename) -> Self {
+        Node::UseRename(node)
+    }
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast UseTree> for Node<'ast> {
+    fn from(node: &'ast UseTree) -> Self {
+        Node::UseTree(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Variadic> for Node<'ast> {
+    fn from(node: &'ast Variadic) -> Self {
+        Node::Variadic(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Variant> for Node<'ast> {
+    fn from(node: &'ast Variant) -> Self {
+        Node::Variant(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast VisCrate> for Node<'ast> {
+    fn from(node: &'ast VisCrate) -> Self {
+        Node::VisCrate(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast VisPublic> for Node<'ast> {
+    fn from(node: &'ast VisPublic) -> Self {
+        Node::VisPublic(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast VisRestricted> for Node<'ast> {
+    fn from(node: &'ast VisRestricted) -> Self {
+        Node::VisRestricted(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast Visibility> for Node<'ast> {
+    fn from(node: &'ast Visibility) -> Self {
+        Node::Visibility(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast WhereClause> for Node<'ast> {
+    fn from(node: &'ast WhereClause) -> Self {
+        Node::WhereClause(node)
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+impl<'ast> From<&'ast WherePredicate> for Node<'ast> {
+    fn from(node: &'ast WherePredicate) -> Self {
+        Node::WherePredicate(node)
+    }
+}
+/// The type of a syntax tree node, as found on the path of
+/// ancestors passed to [`VisitMutCtx`] hooks.
+///
+/// *This type is available if Syn is built with the `"visit"` or
+/// `"visit-mut"` feature.*
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NodeKind {
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Abi,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AngleBracketedGenericArguments,
+    #[cfg(feature = "full")]
+    Arm,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    AttrStyle,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Attribute,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BareFnArg,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BinOp,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Binding,
+    #[cfg(feature = "full")]
+    Block,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    BoundLifetimes,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ConstParam,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Constraint,
+    #[cfg(feature = "derive")]
+    Data,
+    #[cfg(feature = "derive")]
+    DataEnum,
+    #[cfg(feature = "derive")]
+    DataStruct,
+    #[cfg(feature = "derive")]
+    DataUnion,
+    #[cfg(feature = "derive")]
+    DeriveInput,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Expr,
+    #[cfg(feature = "full")]
+    ExprArray,
+    #[cfg(feature = "full")]
+    ExprAssign,
+    #[cfg(feature = "full")]
+    ExprAssignOp,
+    #[cfg(feature = "full")]
+    ExprAsync,
+    #[cfg(feature = "full")]
+    ExprAwait,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprBinary,
+    #[cfg(feature = "full")]
+    ExprBlock,
+    #[cfg(feature = "full")]
+    ExprBox,
+    #[cfg(feature = "full")]
+    ExprBreak,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCall,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprCast,
+    #[cfg(feature = "full")]
+    ExprClosure,
+    #[cfg(feature = "full")]
+    ExprContinue,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprField,
+    #[cfg(feature = "full")]
+    ExprForLoop,
+    #[cfg(feature = "full")]
+    ExprGroup,
+    #[cfg(feature = "full")]
+    ExprIf,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprIndex,
+    #[cfg(feature = "full")]
+    ExprLet,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprLit,
+    #[cfg(feature = "full")]
+    ExprLoop,
+    #[cfg(feature = "full")]
+    ExprMacro,
+    #[cfg(feature = "full")]
+    ExprMatch,
+    #[cfg(feature = "full")]
+    ExprMethodCall,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprParen,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprPath,
+    #[cfg(feature = "full")]
+    ExprRange,
+    #[cfg(feature = "full")]
+    ExprReference,
+    #[cfg(feature = "full")]
+    ExprRepeat,
+    #[cfg(feature = "full")]
+    ExprReturn,
+    #[cfg(feature = "full")]
+    ExprStruct,
+    #[cfg(feature = "full")]
+    ExprTry,
+    #[cfg(feature = "full")]
+    ExprTryBlock,
+    #[cfg(feature = "full")]
+    ExprTuple,
+    #[cfg(feature = "full")]
+    ExprType,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ExprUnary,
+    #[cfg(feature = "full")]
+    ExprUnsafe,
+    #[cfg(feature = "full")]
+    ExprWhile,
+    #[cfg(feature = "full")]
+    ExprYield,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Field,
+    #[cfg(feature = "full")]
+    FieldPat,
+    #[cfg(feature = "full")]
+    FieldValue,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Fields,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsNamed,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    FieldsUnnamed,
+    #[cfg(feature = "full")]
+    File,
+    #[cfg(feature = "full")]
+    FnArg,
+    #[cfg(feature = "full")]
+    ForeignItem,
+    #[cfg(feature = "full")]
+    ForeignItemFn,
+    #[cfg(feature = "full")]
+    ForeignItemMacro,
+    #[cfg(feature = "full")]
+    ForeignItemStatic,
+    #[cfg(feature = "full")]
+    ForeignItemType,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericArgument,
+    #[cfg(feature = "full")]
+    GenericMethodArgument,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    GenericParam,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Generics,
+    Ident,
+    #[cfg(feature = "full")]
+    ImplItem,
+    #[cfg(feature = "full")]
+    ImplItemConst,
+    #[cfg(feature = "full")]
+    ImplItemMacro,
+    #[cfg(feature = "full")]
+    ImplItemMethod,
+    #[cfg(feature = "full")]
+    ImplItemType,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Index,
+    #[cfg(feature = "full")]
+    Item,
+    #[cfg(feature = "full")]
+    ItemConst,
+    #[cfg(feature = "full")]
+    ItemEnum,
+    #[cfg(feature = "full")]
+    ItemExternCrate,
+    #[cfg(feature = "full")]
+    ItemFn,
+    #[cfg(feature = "full")]
+    ItemForeignMod,
+    #[cfg(feature = "full")]
+    ItemImpl,
+    #[cfg(feature = "full")]
+    ItemMacro,
+    #[cfg(feature = "full")]
+    ItemMacro2,
+    #[cfg(feature = "full")]
+    ItemMod,
+    #[cfg(feature = "full")]
+    ItemStatic,
+    #[cfg(feature = "full")]
+    ItemStruct,
+    #[cfg(feature = "full")]
+    ItemTrait,
+    #[cfg(feature = "full")]
+    ItemTraitAlias,
+    #[cfg(feature = "full")]
+    ItemType,
+    #[cfg(feature = "full")]
+    ItemUnion,
+    #[cfg(feature = "full")]
+    ItemUse,
+    #[cfg(feature = "full")]
+    Label,
+    Lifetime,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LifetimeDef,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Lit,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitBool,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByte,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitByteStr,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitChar,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitFloat,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitInt,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    LitStr,
+    #[cfg(feature = "full")]
+    Local,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Macro,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MacroDelimiter,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Member,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Meta,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaList,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    MetaNameValue,
+    #[cfg(feature = "full")]
+    MethodTurbofish,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    NestedMeta,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ParenthesizedGenericArguments,
+    #[cfg(feature = "full")]
+    Pat,
+    #[cfg(feature = "full")]
+    PatBox,
+    #[cfg(feature = "full")]
+    PatIdent,
+    #[cfg(feature = "full")]
+    PatLit,
+    #[cfg(feature = "full")]
+    PatMacro,
+    #[cfg(feature = "full")]
+    PatOr,
+    #[cfg(feature = "full")]
+    PatPath,
+    #[cfg(feature = "full")]
+    PatRange,
+    #[cfg(feature = "full")]
+    PatReference,
+    #[cfg(feature = "full")]
+    PatRest,
+    #[cfg(feature = "full")]
+    PatSlice,
+    #[cfg(feature = "full")]
+    PatStruct,
+    #[cfg(feature = "full")]
+    PatTuple,
+    #[cfg(feature = "full")]
+    PatTupleStruct,
+    #[cfg(feature = "full")]
+    PatType,
+    #[cfg(feature = "full")]
+    PatWild,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Path,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathArguments,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PathSegment,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateEq,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateLifetime,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    PredicateType,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    QSelf,
+    #[cfg(feature = "full")]
+    RangeLimits,
+    #[cfg(feature = "full")]
+    Receiver,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    ReturnType,
+    #[cfg(feature = "full")]
+    Signature,
+    #[cfg(feature = "full")]
+    Stmt,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBound,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TraitBoundModifier,
+    #[cfg(feature = "full")]
+    TraitItem,
+    #[cfg(feature = "full")]
+    TraitItemConst,
+    #[cfg(feature = "full")]
+    TraitItemMacro,
+    #[cfg(feature = "full")]
+    TraitItemMethod,
+    #[cfg(feature = "full")]
+    TraitItemType,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Type,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeArray,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeBareFn,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeGroup,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeImplTrait,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeInfer,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeMacro,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeNever,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParam,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParamBound,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeParen,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePath,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypePtr,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeReference,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeSlice,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTraitObject,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    TypeTuple,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    UnOp,
+    #[cfg(feature = "full")]
+    UseGlob,
+    #[cfg(feature = "full")]
+    UseGroup,
+    #[cfg(feature = "full")]
+    UseName,
+    #[cfg(feature = "full")]
+    UsePath,
+    #[cfg(feature = "full")]
+    UseRename,
+    #[cfg(feature = "full")]
+    UseTree,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variadic,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Variant,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisCrate,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisPublic,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    VisRestricted,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    Visibility,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WhereClause,
+    #[cfg(any(feature = "derive", feature = "full"))]
+    WherePredicate,
+}
+/// Syntax tree traversal over a shared borrow of a syntax tree
+/// that tells each hook where it is and lets it cut the walk short.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available if Syn is built with the `"visit"`
+/// feature.*
+#[cfg(feature = "visit")]
+pub trait VisitCtx<'ast> {
+    /// The value a hook hands back through [`Control::Break`].
+    type Break;
+    /// Hook called for every node whose own hook is not
+    /// overridden.
+    fn visit_node(&mut self, node: Node<'ast>, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_abi(&mut self, i: &'ast Abi, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Abi(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_angle_bracketed_generic_arguments(
+        &mut self,
+        i: &'ast AngleBracketedGenericArguments,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::AngleBracketedGenericArguments(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm(&mut self, i: &'ast Arm, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Arm(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_style(
+        &mut self,
+        i: &'ast AttrStyle,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::AttrStyle(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attribute(
+        &mut self,
+        i: &'ast Attribute,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Attribute(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg(
+        &mut self,
+        i: &'ast BareFnArg,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::BareFnArg(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bin_op(&mut self, i: &'ast BinOp, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::BinOp(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_binding(
+        &mut self,
+        i: &'ast Binding,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Binding(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_block(&mut self, i: &'ast Block, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Block(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bound_lifetimes(
+        &mut self,
+        i: &'ast BoundLifetimes,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::BoundLifetimes(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param(
+        &mut self,
+        i: &'ast ConstParam,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ConstParam(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_constraint(
+        &mut self,
+        i: &'ast Constraint,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Constraint(i), ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data(&mut self, i: &'ast Data, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Data(i), ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum(
+        &mut self,
+        i: &'ast DataEnum,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::DataEnum(i), ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct(
+        &mut self,
+        i: &'ast DataStruct,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::DataStruct(i), ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union(
+        &mut self,
+        i: &'ast DataUnion,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::DataUnion(i), ancestors)
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input(
+        &mut self,
+        i: &'ast DeriveInput,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::DeriveInput(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr(&mut self, i: &'ast Expr, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Expr(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array(
+        &mut self,
+        i: &'ast ExprArray,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprArray(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign(
+        &mut self,
+        i: &'ast ExprAssign,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprAssign(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op(
+        &mut self,
+        i: &'ast ExprAssignOp,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprAssignOp(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async(
+        &mut self,
+        i: &'ast ExprAsync,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprAsync(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await(
+        &mut self,
+        i: &'ast ExprAwait,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprAwait(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary(
+        &mut self,
+        i: &'ast ExprBinary,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprBinary(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block(
+        &mut self,
+        i: &'ast ExprBlock,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprBlock(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box(
+        &mut self,
+        i: &'ast ExprBox,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprBox(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break(
+        &mut self,
+        i: &'ast ExprBreak,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprBreak(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call(
+        &mut self,
+        i: &'ast ExprCall,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprCall(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast(
+        &mut self,
+        i: &'ast ExprCast,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprCast(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure(
+        &mut self,
+        i: &'ast ExprClosure,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprClosure(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue(
+        &mut self,
+        i: &'ast ExprContinue,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprContinue(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field(
+        &mut self,
+        i: &'ast ExprField,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprField(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop(
+        &mut self,
+        i: &'ast ExprForLoop,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprForLoop(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group(
+        &mut self,
+        i: &'ast ExprGroup,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprGroup(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if(&mut self, i: &'ast ExprIf, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::ExprIf(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index(
+        &mut self,
+        i: &'ast ExprIndex,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprIndex(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let(
+        &mut self,
+        i: &'ast ExprLet,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprLet(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit(
+        &mut self,
+        i: &'ast ExprLit,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprLit(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop(
+        &mut self,
+        i: &'ast ExprLoop,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprLoop(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro(
+        &mut self,
+        i: &'ast ExprMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match(
+        &mut self,
+        i: &'ast ExprMatch,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprMatch(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call(
+        &mut self,
+        i: &'ast ExprMethodCall,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprMethodCall(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren(
+        &mut self,
+        i: &'ast ExprParen,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprParen(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path(
+        &mut self,
+        i: &'ast ExprPath,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprPath(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range(
+        &mut self,
+        i: &'ast ExprRange,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprRange(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference(
+        &mut self,
+        i: &'ast ExprReference,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprReference(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat(
+        &mut self,
+        i: &'ast ExprRepeat,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprRepeat(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return(
+        &mut self,
+        i: &'ast ExprReturn,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprReturn(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct(
+        &mut self,
+        i: &'ast ExprStruct,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprStruct(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try(
+        &mut self,
+        i: &'ast ExprTry,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprTry(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block(
+        &mut self,
+        i: &'ast ExprTryBlock,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprTryBlock(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple(
+        &mut self,
+        i: &'ast ExprTuple,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprTuple(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type(
+        &mut self,
+        i: &'ast ExprType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprType(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary(
+        &mut self,
+        i: &'ast ExprUnary,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprUnary(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe(
+        &mut self,
+        i: &'ast ExprUnsafe,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprUnsafe(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while(
+        &mut self,
+        i: &'ast ExprWhile,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprWhile(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield(
+        &mut self,
+        i: &'ast ExprYield,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ExprYield(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field(&mut self, i: &'ast Field, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Field(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat(
+        &mut self,
+        i: &'ast FieldPat,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::FieldPat(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value(
+        &mut self,
+        i: &'ast FieldValue,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::FieldValue(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields(&mut self, i: &'ast Fields, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Fields(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named(
+        &mut self,
+        i: &'ast FieldsNamed,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::FieldsNamed(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed(
+        &mut self,
+        i: &'ast FieldsUnnamed,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::FieldsUnnamed(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_file(&mut self, i: &'ast File, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::File(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg(&mut self, i: &'ast FnArg, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::FnArg(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item(
+        &mut self,
+        i: &'ast ForeignItem,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ForeignItem(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn(
+        &mut self,
+        i: &'ast ForeignItemFn,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ForeignItemFn(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro(
+        &mut self,
+        i: &'ast ForeignItemMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ForeignItemMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static(
+        &mut self,
+        i: &'ast ForeignItemStatic,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ForeignItemStatic(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type(
+        &mut self,
+        i: &'ast ForeignItemType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ForeignItemType(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_argument(
+        &mut self,
+        i: &'ast GenericArgument,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::GenericArgument(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument(
+        &mut self,
+        i: &'ast GenericMethodArgument,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::GenericMethodArgument(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_param(
+        &mut self,
+        i: &'ast GenericParam,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::GenericParam(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generics(
+        &mut self,
+        i: &'ast Generics,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Generics(i), ancestors)
+    }
+    fn visit_ident(&mut self, i: &'ast Ident, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Ident(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item(
+        &mut self,
+        i: &'ast ImplItem,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ImplItem(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const(
+        &mut self,
+        i: &'ast ImplItemConst,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ImplItemConst(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro(
+        &mut self,
+        i: &'ast ImplItemMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ImplItemMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method(
+        &mut self,
+        i: &'ast ImplItemMethod,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ImplItemMethod(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type(
+        &mut self,
+        i: &'ast ImplItemType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ImplItemType(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_index(&mut self, i: &'ast Index, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Index(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item(&mut self, i: &'ast Item, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Item(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const(
+        &mut self,
+        i: &'ast ItemConst,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemConst(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum(
+        &mut self,
+        i: &'ast ItemEnum,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemEnum(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate(
+        &mut self,
+        i: &'ast ItemExternCrate,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemExternCrate(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn(&mut self, i: &'ast ItemFn, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::ItemFn(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod(
+        &mut self,
+        i: &'ast ItemForeignMod,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemForeignMod(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl(
+        &mut self,
+        i: &'ast ItemImpl,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemImpl(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro(
+        &mut self,
+        i: &'ast ItemMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2(
+        &mut self,
+        i: &'ast ItemMacro2,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemMacro2(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod(
+        &mut self,
+        i: &'ast ItemMod,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemMod(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static(
+        &mut self,
+        i: &'ast ItemStatic,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemStatic(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct(
+        &mut self,
+        i: &'ast ItemStruct,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemStruct(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait(
+        &mut self,
+        i: &'ast ItemTrait,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemTrait(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias(
+        &mut self,
+        i: &'ast ItemTraitAlias,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemTraitAlias(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type(
+        &mut self,
+        i: &'ast ItemType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemType(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union(
+        &mut self,
+        i: &'ast ItemUnion,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemUnion(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use(
+        &mut self,
+        i: &'ast ItemUse,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ItemUse(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_label(&mut self, i: &'ast Label, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Label(i), ancestors)
+    }
+    fn visit_lifetime(
+        &mut self,
+        i: &'ast Lifetime,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Lifetime(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_def(
+        &mut self,
+        i: &'ast LifetimeDef,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LifetimeDef(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit(&mut self, i: &'ast Lit, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Lit(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_bool(
+        &mut self,
+        i: &'ast LitBool,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LitBool(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte(
+        &mut self,
+        i: &'ast LitByte,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LitByte(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte_str(
+        &mut self,
+        i: &'ast LitByteStr,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LitByteStr(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_char(
+        &mut self,
+        i: &'ast LitChar,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LitChar(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_float(
+        &mut self,
+        i: &'ast LitFloat,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::LitFloat(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_int(&mut self, i: &'ast LitInt, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::LitInt(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_str(&mut self, i: &'ast LitStr, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::LitStr(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_local(&mut self, i: &'ast Local, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Local(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro(&mut self, i: &'ast Macro, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Macro(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_delimiter(
+        &mut self,
+        i: &'ast MacroDelimiter,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::MacroDelimiter(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_member(&mut self, i: &'ast Member, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Member(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta(&mut self, i: &'ast Meta, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Meta(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_list(
+        &mut self,
+        i: &'ast MetaList,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::MetaList(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_name_value(
+        &mut self,
+        i: &'ast MetaNameValue,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::MetaNameValue(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish(
+        &mut self,
+        i: &'ast MethodTurbofish,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::MethodTurbofish(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta(
+        &mut self,
+        i: &'ast NestedMeta,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::NestedMeta(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_parenthesized_generic_arguments(
+        &mut self,
+        i: &'ast ParenthesizedGenericArguments,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ParenthesizedGenericArguments(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat(&mut self, i: &'ast Pat, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Pat(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box(&mut self, i: &'ast PatBox, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::PatBox(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident(
+        &mut self,
+        i: &'ast PatIdent,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatIdent(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit(&mut self, i: &'ast PatLit, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::PatLit(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro(
+        &mut self,
+        i: &'ast PatMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or(&mut self, i: &'ast PatOr, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::PatOr(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path(
+        &mut self,
+        i: &'ast PatPath,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatPath(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range(
+        &mut self,
+        i: &'ast PatRange,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatRange(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference(
+        &mut self,
+        i: &'ast PatReference,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatReference(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest(
+        &mut self,
+        i: &'ast PatRest,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatRest(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice(
+        &mut self,
+        i: &'ast PatSlice,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatSlice(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct(
+        &mut self,
+        i: &'ast PatStruct,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatStruct(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple(
+        &mut self,
+        i: &'ast PatTuple,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatTuple(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct(
+        &mut self,
+        i: &'ast PatTupleStruct,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatTupleStruct(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type(
+        &mut self,
+        i: &'ast PatType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatType(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild(
+        &mut self,
+        i: &'ast PatWild,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PatWild(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path(&mut self, i: &'ast Path, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Path(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_arguments(
+        &mut self,
+        i: &'ast PathArguments,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PathArguments(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_segment(
+        &mut self,
+        i: &'ast PathSegment,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PathSegment(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_eq(
+        &mut self,
+        i: &'ast PredicateEq,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PredicateEq(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_lifetime(
+        &mut self,
+        i: &'ast PredicateLifetime,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PredicateLifetime(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_type(
+        &mut self,
+        i: &'ast PredicateType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::PredicateType(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_qself(&mut self, i: &'ast QSelf, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::QSelf(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits(
+        &mut self,
+        i: &'ast RangeLimits,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::RangeLimits(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver(
+        &mut self,
+        i: &'ast Receiver,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Receiver(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_return_type(
+        &mut self,
+        i: &'ast ReturnType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::ReturnType(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature(
+        &mut self,
+        i: &'ast Signature,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Signature(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt(&mut self, i: &'ast Stmt, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Stmt(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound(
+        &mut self,
+        i: &'ast TraitBound,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitBound(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_modifier(
+        &mut self,
+        i: &'ast TraitBoundModifier,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitBoundModifier(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item(
+        &mut self,
+        i: &'ast TraitItem,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitItem(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const(
+        &mut self,
+        i: &'ast TraitItemConst,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitItemConst(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro(
+        &mut self,
+        i: &'ast TraitItemMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitItemMacro(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method(
+        &mut self,
+        i: &'ast TraitItemMethod,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitItemMethod(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type(
+        &mut self,
+        i: &'ast TraitItemType,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TraitItemType(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type(&mut self, i: &'ast Type, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::Type(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_array(
+        &mut self,
+        i: &'ast TypeArray,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeArray(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_bare_fn(
+        &mut self,
+        i: &'ast TypeBareFn,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeBareFn(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_group(
+        &mut self,
+        i: &'ast TypeGroup,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeGroup(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_impl_trait(
+        &mut self,
+        i: &'ast TypeImplTrait,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeImplTrait(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_infer(
+        &mut self,
+        i: &'ast TypeInfer,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeInfer(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_macro(
+        &mut self,
+        i: &'ast TypeMacro,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeMacro(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_never(
+        &mut self,
+        i: &'ast TypeNever,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeNever(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param(
+        &mut self,
+        i: &'ast TypeParam,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeParam(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_bound(
+        &mut self,
+        i: &'ast TypeParamBound,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeParamBound(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_paren(
+        &mut self,
+        i: &'ast TypeParen,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeParen(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_path(
+        &mut self,
+        i: &'ast TypePath,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypePath(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_ptr(
+        &mut self,
+        i: &'ast TypePtr,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypePtr(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_reference(
+        &mut self,
+        i: &'ast TypeReference,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeReference(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_slice(
+        &mut self,
+        i: &'ast TypeSlice,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeSlice(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_trait_object(
+        &mut self,
+        i: &'ast TypeTraitObject,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeTraitObject(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_tuple(
+        &mut self,
+        i: &'ast TypeTuple,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::TypeTuple(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_un_op(&mut self, i: &'ast UnOp, ancestors: &[Node<'ast>]) -> Control<Self::Break> {
+        self.visit_node(Node::UnOp(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob(
+        &mut self,
+        i: &'ast UseGlob,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UseGlob(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group(
+        &mut self,
+        i: &'ast UseGroup,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UseGroup(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name(
+        &mut self,
+        i: &'ast UseName,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UseName(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path(
+        &mut self,
+        i: &'ast UsePath,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UsePath(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename(
+        &mut self,
+        i: &'ast UseRename,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UseRename(i), ancestors)
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree(
+        &mut self,
+        i: &'ast UseTree,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::UseTree(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variadic(
+        &mut self,
+        i: &'ast Variadic,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Variadic(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant(
+        &mut self,
+        i: &'ast Variant,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Variant(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate(
+        &mut self,
+        i: &'ast VisCrate,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::VisCrate(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public(
+        &mut self,
+        i: &'ast VisPublic,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::VisPublic(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted(
+        &mut self,
+        i: &'ast VisRestricted,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::VisRestricted(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility(
+        &mut self,
+        i: &'ast Visibility,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::Visibility(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_clause(
+        &mut self,
+        i: &'ast WhereClause,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::WhereClause(i), ancestors)
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_predicate(
+        &mut self,
+        i: &'ast WherePredicate,
+        ancestors: &[Node<'ast>],
+    ) -> Control<Self::Break> {
+        self.visit_node(Node::WherePredicate(i), ancestors)
+    }
+}
+/// Walks any syntax tree node, dispatching on its type.
+#[cfg(feature = "visit")]
+pub fn visit_node<'ast, V>(
+    v: &mut V,
+    node: Node<'ast>,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    match node {
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Abi(node) => visit_abi(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::AngleBracketedGenericArguments(node) => {
+            visit_angle_bracketed_generic_arguments(v, node, ancestors)
+        }
+        #[cfg(feature = "full")]
+        Node::Arm(node) => visit_arm(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::AttrStyle(node) => visit_attr_style(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Attribute(node) => visit_attribute(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::BareFnArg(node) => visit_bare_fn_arg(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::BinOp(node) => visit_bin_op(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Binding(node) => visit_binding(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Block(node) => visit_block(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::BoundLifetimes(node) => visit_bound_lifetimes(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ConstParam(node) => visit_const_param(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Constraint(node) => visit_constraint(v, node, ancestors),
+        #[cfg(feature = "derive")]
+        Node::Data(node) => visit_data(v, node, ancestors),
+        #[cfg(feature = "derive")]
+        Node::DataEnum(node) => visit_data_enum(v, node, ancestors),
+        #[cfg(feature = "derive")]
+        Node::DataStruct(node) => visit_data_struct(v, node, ancestors),
+        #[cfg(feature = "derive")]
+        Node::DataUnion(node) => visit_data_union(v, node, ancestors),
+        #[cfg(feature = "derive")]
+        Node::DeriveInput(node) => visit_derive_input(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Expr(node) => visit_expr(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprArray(node) => visit_expr_array(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprAssign(node) => visit_expr_assign(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprAssignOp(node) => visit_expr_assign_op(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprAsync(node) => visit_expr_async(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprAwait(node) => visit_expr_await(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprBinary(node) => visit_expr_binary(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprBlock(node) => visit_expr_block(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprBox(node) => visit_expr_box(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprBreak(node) => visit_expr_break(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprCall(node) => visit_expr_call(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprCast(node) => visit_expr_cast(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprClosure(node) => visit_expr_closure(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprContinue(node) => visit_expr_continue(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprField(node) => visit_expr_field(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprForLoop(node) => visit_expr_for_loop(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprGroup(node) => visit_expr_group(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprIf(node) => visit_expr_if(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprIndex(node) => visit_expr_index(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprLet(node) => visit_expr_let(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprLit(node) => visit_expr_lit(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprLoop(node) => visit_expr_loop(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprMacro(node) => visit_expr_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprMatch(node) => visit_expr_match(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprMethodCall(node) => visit_expr_method_call(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprParen(node) => visit_expr_paren(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprPath(node) => visit_expr_path(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprRange(node) => visit_expr_range(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprReference(node) => visit_expr_reference(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprRepeat(node) => visit_expr_repeat(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprReturn(node) => visit_expr_return(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprStruct(node) => visit_expr_struct(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprTry(node) => visit_expr_try(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprTryBlock(node) => visit_expr_try_block(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprTuple(node) => visit_expr_tuple(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprType(node) => visit_expr_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ExprUnary(node) => visit_expr_unary(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprUnsafe(node) => visit_expr_unsafe(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprWhile(node) => visit_expr_while(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ExprYield(node) => visit_expr_yield(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Field(node) => visit_field(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::FieldPat(node) => visit_field_pat(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::FieldValue(node) => visit_field_value(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Fields(node) => visit_fields(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::FieldsNamed(node) => visit_fields_named(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::FieldsUnnamed(node) => visit_fields_unnamed(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::File(node) => visit_file(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::FnArg(node) => visit_fn_arg(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ForeignItem(node) => visit_foreign_item(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ForeignItemFn(node) => visit_foreign_item_fn(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ForeignItemMacro(node) => visit_foreign_item_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ForeignItemStatic(node) => visit_foreign_item_static(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ForeignItemType(node) => visit_foreign_item_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::GenericArgument(node) => visit_generic_argument(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::GenericMethodArgument(node) => visit_generic_method_argument(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::GenericParam(node) => visit_generic_param(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Generics(node) => visit_generics(v, node, ancestors),
+        Node::Ident(node) => visit_ident(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ImplItem(node) => visit_impl_item(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ImplItemConst(node) => visit_impl_item_const(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ImplItemMacro(node) => visit_impl_item_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ImplItemMethod(node) => visit_impl_item_method(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ImplItemType(node) => visit_impl_item_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Index(node) => visit_index(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Item(node) => visit_item(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemConst(node) => visit_item_const(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemEnum(node) => visit_item_enum(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemExternCrate(node) => visit_item_extern_crate(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemFn(node) => visit_item_fn(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemForeignMod(node) => visit_item_foreign_mod(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemImpl(node) => visit_item_impl(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemMacro(node) => visit_item_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemMacro2(node) => visit_item_macro2(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemMod(node) => visit_item_mod(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemStatic(node) => visit_item_static(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemStruct(node) => visit_item_struct(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemTrait(node) => visit_item_trait(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemTraitAlias(node) => visit_item_trait_alias(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemType(node) => visit_item_type(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemUnion(node) => visit_item_union(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::ItemUse(node) => visit_item_use(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Label(node) => visit_label(v, node, ancestors),
+        Node::Lifetime(node) => visit_lifetime(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LifetimeDef(node) => visit_lifetime_def(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Lit(node) => visit_lit(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitBool(node) => visit_lit_bool(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitByte(node) => visit_lit_byte(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitByteStr(node) => visit_lit_byte_str(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitChar(node) => visit_lit_char(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitFloat(node) => visit_lit_float(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitInt(node) => visit_lit_int(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::LitStr(node) => visit_lit_str(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Local(node) => visit_local(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Macro(node) => visit_macro(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::MacroDelimiter(node) => visit_macro_delimiter(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Member(node) => visit_member(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Meta(node) => visit_meta(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::MetaList(node) => visit_meta_list(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::MetaNameValue(node) => visit_meta_name_value(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::MethodTurbofish(node) => visit_method_turbofish(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::NestedMeta(node) => visit_nested_meta(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ParenthesizedGenericArguments(node) => {
+            visit_parenthesized_generic_arguments(v, node, ancestors)
+        }
+        #[cfg(feature = "full")]
+        Node::Pat(node) => visit_pat(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatBox(node) => visit_pat_box(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatIdent(node) => visit_pat_ident(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatLit(node) => visit_pat_lit(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatMacro(node) => visit_pat_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatOr(node) => visit_pat_or(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatPath(node) => visit_pat_path(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatRange(node) => visit_pat_range(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatReference(node) => visit_pat_reference(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatRest(node) => visit_pat_rest(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatSlice(node) => visit_pat_slice(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatStruct(node) => visit_pat_struct(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatTuple(node) => visit_pat_tuple(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatTupleStruct(node) => visit_pat_tuple_struct(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatType(node) => visit_pat_type(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::PatWild(node) => visit_pat_wild(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Path(node) => visit_path(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::PathArguments(node) => visit_path_arguments(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::PathSegment(node) => visit_path_segment(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::PredicateEq(node) => visit_predicate_eq(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::PredicateLifetime(node) => visit_predicate_lifetime(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::PredicateType(node) => visit_predicate_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::QSelf(node) => visit_qself(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::RangeLimits(node) => visit_range_limits(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Receiver(node) => visit_receiver(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::ReturnType(node) => visit_return_type(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Signature(node) => visit_signature(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::Stmt(node) => visit_stmt(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TraitBound(node) => visit_trait_bound(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TraitBoundModifier(node) => visit_trait_bound_modifier(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::TraitItem(node) => visit_trait_item(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::TraitItemConst(node) => visit_trait_item_const(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::TraitItemMacro(node) => visit_trait_item_macro(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::TraitItemMethod(node) => visit_trait_item_method(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::TraitItemType(node) => visit_trait_item_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Type(node) => visit_type(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeArray(node) => visit_type_array(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeBareFn(node) => visit_type_bare_fn(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeGroup(node) => visit_type_group(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeImplTrait(node) => visit_type_impl_trait(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeInfer(node) => visit_type_infer(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeMacro(node) => visit_type_macro(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeNever(node) => visit_type_never(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeParam(node) => visit_type_param(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeParamBound(node) => visit_type_param_bound(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeParen(node) => visit_type_paren(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypePath(node) => visit_type_path(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypePtr(node) => visit_type_ptr(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeReference(node) => visit_type_reference(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeSlice(node) => visit_type_slice(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeTraitObject(node) => visit_type_trait_object(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::TypeTuple(node) => visit_type_tuple(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::UnOp(node) => visit_un_op(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UseGlob(node) => visit_use_glob(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UseGroup(node) => visit_use_group(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UseName(node) => visit_use_name(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UsePath(node) => visit_use_path(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UseRename(node) => visit_use_rename(v, node, ancestors),
+        #[cfg(feature = "full")]
+        Node::UseTree(node) => visit_use_tree(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Variadic(node) => visit_variadic(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Variant(node) => visit_variant(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::VisCrate(node) => visit_vis_crate(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::VisPublic(node) => visit_vis_public(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::VisRestricted(node) => visit_vis_restricted(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::Visibility(node) => visit_visibility(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::WhereClause(node) => visit_where_clause(v, node, ancestors),
+        #[cfg(any(feature = "derive", feature = "full"))]
+        Node::WherePredicate(node) => visit_where_predicate(v, node, ancestors),
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_abi<'ast, V>(
+    v: &mut V,
+    node: &'ast Abi,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_abi(node, ancestors));
+    ancestors.push(Node::Abi(node));
+    skip!(node.extern_token);
+    if let Some(it) = &node.name {
+        visit_lit_str(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_angle_bracketed_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast AngleBracketedGenericArguments,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_angle_bracketed_generic_arguments(node, ancestors));
+    ancestors.push(Node::AngleBracketedGenericArguments(node));
+    skip!(node.colon2_token);
+    skip!(node.lt_token);
+    for it in &node.args {
+        visit_generic_argument(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_arm<'ast, V>(
+    v: &mut V,
+    node: &'ast Arm,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_arm(node, ancestors));
+    ancestors.push(Node::Arm(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_pat(v, &node.pat, ancestors)?;
+    if let Some(it) = &node.guard {
+        skip!((it).0);
+        visit_expr(v, &*(it).1, ancestors)?;
+    };
+    skip!(node.fat_arrow_token);
+    visit_expr(v, &*node.body, ancestors)?;
+    skip!(node.comma);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_attr_style<'ast, V>(
+    v: &mut V,
+    node: &'ast AttrStyle,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_attr_style(node, ancestors));
+    ancestors.push(Node::AttrStyle(node));
+    match node {
+        AttrStyle::Outer => {}
+        AttrStyle::Inner(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_attribute<'ast, V>(
+    v: &mut V,
+    node: &'ast Attribute,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_attribute(node, ancestors));
+    ancestors.push(Node::Attribute(node));
+    skip!(node.pound_token);
+    visit_attr_style(v, &node.style, ancestors)?;
+    skip!(node.bracket_token);
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.tokens);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_bare_fn_arg<'ast, V>(
+    v: &mut V,
+    node: &'ast BareFnArg,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_bare_fn_arg(node, ancestors));
+    ancestors.push(Node::BareFnArg(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.name {
+        visit_ident(v, &(it).0, ancestors)?;
+        skip!((it).1);
+    };
+    visit_type(v, &node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_bin_op<'ast, V>(
+    v: &mut V,
+    node: &'ast BinOp,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_bin_op(node, ancestors));
+    ancestors.push(Node::BinOp(node));
+    match node {
+        BinOp::Add(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Sub(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Mul(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Div(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Rem(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::And(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Or(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitXor(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitAnd(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitOr(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Shl(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Shr(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Eq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Lt(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Le(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Ne(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Ge(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Gt(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::AddEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::SubEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::MulEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::DivEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::RemEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitXorEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitAndEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitOrEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::ShlEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::ShrEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_binding<'ast, V>(
+    v: &mut V,
+    node: &'ast Binding,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_binding(node, ancestors));
+    ancestors.push(Node::Binding(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.eq_token);
+    visit_type(v, &node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_block<'ast, V>(
+    v: &mut V,
+    node: &'ast Block,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_block(node, ancestors));
+    ancestors.push(Node::Block(node));
+    skip!(node.brace_token);
+    for it in &node.stmts {
+        visit_stmt(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_bound_lifetimes<'ast, V>(
+    v: &mut V,
+    node: &'ast BoundLifetimes,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_bound_lifetimes(node, ancestors));
+    ancestors.push(Node::BoundLifetimes(node));
+    skip!(node.for_token);
+    skip!(node.lt_token);
+    for it in &node.lifetimes {
+        visit_lifetime_def(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_const_param<'ast, V>(
+    v: &mut V,
+    node: &'ast ConstParam,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_const_param(node, ancestors));
+    ancestors.push(Node::ConstParam(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.const_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &node.ty, ancestors)?;
+    skip!(node.eq_token);
+    if let Some(it) = &node.default {
+        visit_expr(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_constraint<'ast, V>(
+    v: &mut V,
+    node: &'ast Constraint,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_constraint(node, ancestors));
+    ancestors.push(Node::Constraint(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+pub fn visit_data<'ast, V>(
+    v: &mut V,
+    node: &'ast Data,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_data(node, ancestors));
+    ancestors.push(Node::Data(node));
+    match node {
+        Data::Struct(_binding_0) => {
+            visit_data_struct(v, _binding_0, ancestors)?;
+        }
+        Data::Enum(_binding_0) => {
+            visit_data_enum(v, _binding_0, ancestors)?;
+        }
+        Data::Union(_binding_0) => {
+            visit_data_union(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+pub fn visit_data_enum<'ast, V>(
+    v: &mut V,
+    node: &'ast DataEnum,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_data_enum(node, ancestors));
+    ancestors.push(Node::DataEnum(node));
+    skip!(node.enum_token);
+    skip!(node.brace_token);
+    for it in &node.variants {
+        visit_variant(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+pub fn visit_data_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast DataStruct,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_data_struct(node, ancestors));
+    ancestors.push(Node::DataStruct(node));
+    skip!(node.struct_token);
+    visit_fields(v, &node.fields, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+pub fn visit_data_union<'ast, V>(
+    v: &mut V,
+    node: &'ast DataUnion,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_data_union(node, ancestors));
+    ancestors.push(Node::DataUnion(node));
+    skip!(node.union_token);
+    visit_fields_named(v, &node.fields, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit")]
+pub fn visit_derive_input<'ast, V>(
+    v: &mut V,
+    node: &'ast DeriveInput,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_derive_input(node, ancestors));
+    ancestors.push(Node::DeriveInput(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    visit_data(v, &node.data, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr<'ast, V>(
+    v: &mut V,
+    node: &'ast Expr,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr(node, ancestors));
+    ancestors.push(Node::Expr(node));
+    match node {
+        Expr::Array(_binding_0) => {
+            full!(visit_expr_array(v, _binding_0, ancestors)?);
+        }
+        Expr::Assign(_binding_0) => {
+            full!(visit_expr_assign(v, _binding_0, ancestors)?);
+        }
+        Expr::AssignOp(_binding_0) => {
+            full!(visit_expr_assign_op(v, _binding_0, ancestors)?);
+        }
+        Expr::Async(_binding_0) => {
+            full!(visit_expr_async(v, _binding_0, ancestors)?);
+        }
+        Expr::Await(_binding_0) => {
+            full!(visit_expr_await(v, _binding_0, ancestors)?);
+        }
+        Expr::Binary(_binding_0) => {
+            visit_expr_binary(v, _binding_0, ancestors)?;
+        }
+        Expr::Block(_binding_0) => {
+            full!(visit_expr_block(v, _binding_0, ancestors)?);
+        }
+        Expr::Box(_binding_0) => {
+            full!(visit_expr_box(v, _binding_0, ancestors)?);
+        }
+        Expr::Break(_binding_0) => {
+            full!(visit_expr_break(v, _binding_0, ancestors)?);
+        }
+        Expr::Call(_binding_0) => {
+            visit_expr_call(v, _binding_0, ancestors)?;
+        }
+        Expr::Cast(_binding_0) => {
+            visit_expr_cast(v, _binding_0, ancestors)?;
+        }
+        Expr::Closure(_binding_0) => {
+            full!(visit_expr_closure(v, _binding_0, ancestors)?);
+        }
+        Expr::Continue(_binding_0) => {
+            full!(visit_expr_continue(v, _binding_0, ancestors)?);
+        }
+        Expr::Field(_binding_0) => {
+            visit_expr_field(v, _binding_0, ancestors)?;
+        }
+        Expr::ForLoop(_binding_0) => {
+            full!(visit_expr_for_loop(v, _binding_0, ancestors)?);
+        }
+        Expr::Group(_binding_0) => {
+            full!(visit_expr_group(v, _binding_0, ancestors)?);
+        }
+        Expr::If(_binding_0) => {
+            full!(visit_expr_if(v, _binding_0, ancestors)?);
+        }
+        Expr::Index(_binding_0) => {
+            visit_expr_index(v, _binding_0, ancestors)?;
+        }
+        Expr::Let(_binding_0) => {
+            full!(visit_expr_let(v, _binding_0, ancestors)?);
+        }
+        Expr::Lit(_binding_0) => {
+            visit_expr_lit(v, _binding_0, ancestors)?;
+        }
+        Expr::Loop(_binding_0) => {
+            full!(visit_expr_loop(v, _binding_0, ancestors)?);
+        }
+        Expr::Macro(_binding_0) => {
+            full!(visit_expr_macro(v, _binding_0, ancestors)?);
+        }
+        Expr::Match(_binding_0) => {
+            full!(visit_expr_match(v, _binding_0, ancestors)?);
+        }
+        Expr::MethodCall(_binding_0) => {
+            full!(visit_expr_method_call(v, _binding_0, ancestors)?);
+        }
+        Expr::Paren(_binding_0) => {
+            visit_expr_paren(v, _binding_0, ancestors)?;
+        }
+        Expr::Path(_binding_0) => {
+            visit_expr_path(v, _binding_0, ancestors)?;
+        }
+        Expr::Range(_binding_0) => {
+            full!(visit_expr_range(v, _binding_0, ancestors)?);
+        }
+        Expr::Reference(_binding_0) => {
+            full!(visit_expr_reference(v, _binding_0, ancestors)?);
+        }
+        Expr::Repeat(_binding_0) => {
+            full!(visit_expr_repeat(v, _binding_0, ancestors)?);
+        }
+        Expr::Return(_binding_0) => {
+            full!(visit_expr_return(v, _binding_0, ancestors)?);
+        }
+        Expr::Struct(_binding_0) => {
+            full!(visit_expr_struct(v, _binding_0, ancestors)?);
+        }
+        Expr::Try(_binding_0) => {
+            full!(visit_expr_try(v, _binding_0, ancestors)?);
+        }
+        Expr::TryBlock(_binding_0) => {
+            full!(visit_expr_try_block(v, _binding_0, ancestors)?);
+        }
+        Expr::Tuple(_binding_0) => {
+            full!(visit_expr_tuple(v, _binding_0, ancestors)?);
+        }
+        Expr::Type(_binding_0) => {
+            full!(visit_expr_type(v, _binding_0, ancestors)?);
+        }
+        Expr::Unary(_binding_0) => {
+            visit_expr_unary(v, _binding_0, ancestors)?;
+        }
+        Expr::Unsafe(_binding_0) => {
+            full!(visit_expr_unsafe(v, _binding_0, ancestors)?);
+        }
+        Expr::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Expr::While(_binding_0) => {
+            full!(visit_expr_while(v, _binding_0, ancestors)?);
+        }
+        Expr::Yield(_binding_0) => {
+            full!(visit_expr_yield(v, _binding_0, ancestors)?);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_array<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprArray,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_array(node, ancestors));
+    ancestors.push(Node::ExprArray(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    for it in &node.elems {
+        visit_expr(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_assign<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssign,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_assign(node, ancestors));
+    ancestors.push(Node::ExprAssign(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.left, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr(v, &*node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_assign_op<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAssignOp,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_assign_op(node, ancestors));
+    ancestors.push(Node::ExprAssignOp(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.left, ancestors)?;
+    visit_bin_op(v, &node.op, ancestors)?;
+    visit_expr(v, &*node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_async<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAsync,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_async(node, ancestors));
+    ancestors.push(Node::ExprAsync(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.async_token);
+    skip!(node.capture);
+    visit_block(v, &node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_await<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprAwait,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_await(node, ancestors));
+    ancestors.push(Node::ExprAwait(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.base, ancestors)?;
+    skip!(node.dot_token);
+    skip!(node.await_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_binary<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBinary,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_binary(node, ancestors));
+    ancestors.push(Node::ExprBinary(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.left, ancestors)?;
+    visit_bin_op(v, &node.op, ancestors)?;
+    visit_expr(v, &*node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_block<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBlock,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_block(node, ancestors));
+    ancestors.push(Node::ExprBlock(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.label {
+        visit_label(v, it, ancestors)?
+    };
+    visit_block(v, &node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_box<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBox,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_box(node, ancestors));
+    ancestors.push(Node::ExprBox(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.box_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_break<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprBreak,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_break(node, ancestors));
+    ancestors.push(Node::ExprBreak(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.break_token);
+    if let Some(it) = &node.label {
+        visit_lifetime(v, it, ancestors)?
+    };
+    if let Some(it) = &node.expr {
+        visit_expr(v, &**it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_call<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprCall,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_call(node, ancestors));
+    ancestors.push(Node::ExprCall(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.func, ancestors)?;
+    skip!(node.paren_token);
+    for it in &node.args {
+        visit_expr(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_cast<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprCast,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_cast(node, ancestors));
+    ancestors.push(Node::ExprCast(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.as_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_closure<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprClosure,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_closure(node, ancestors));
+    ancestors.push(Node::ExprClosure(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.asyncness);
+    skip!(node.movability);
+    skip!(node.capture);
+    skip!(node.or1_token);
+    for it in &node.inputs {
+        visit_pat(v, it, ancestors)?
+    }
+    skip!(node.or2_token);
+    visit_return_type(v, &node.output, ancestors)?;
+    visit_expr(v, &*node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_continue<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprContinue,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_continue(node, ancestors));
+    ancestors.push(Node::ExprContinue(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.continue_token);
+    if let Some(it) = &node.label {
+        visit_lifetime(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_field<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprField,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_field(node, ancestors));
+    ancestors.push(Node::ExprField(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.base, ancestors)?;
+    skip!(node.dot_token);
+    visit_member(v, &node.member, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_for_loop<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprForLoop,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_for_loop(node, ancestors));
+    ancestors.push(Node::ExprForLoop(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.label {
+        visit_label(v, it, ancestors)?
+    };
+    skip!(node.for_token);
+    visit_pat(v, &node.pat, ancestors)?;
+    skip!(node.in_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    visit_block(v, &node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_group<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprGroup,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_group(node, ancestors));
+    ancestors.push(Node::ExprGroup(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.group_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_if<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprIf,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_if(node, ancestors));
+    ancestors.push(Node::ExprIf(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.if_token);
+    visit_expr(v, &*node.cond, ancestors)?;
+    visit_block(v, &node.then_branch, ancestors)?;
+    if let Some(it) = &node.else_branch {
+        skip!((it).0);
+        visit_expr(v, &*(it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_index<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprIndex,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_index(node, ancestors));
+    ancestors.push(Node::ExprIndex(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.bracket_token);
+    visit_expr(v, &*node.index, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_let<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprLet,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_let(node, ancestors));
+    ancestors.push(Node::ExprLet(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.let_token);
+    visit_pat(v, &node.pat, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_lit<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprLit,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_lit(node, ancestors));
+    ancestors.push(Node::ExprLit(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_lit(v, &node.lit, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_loop<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprLoop,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_loop(node, ancestors));
+    ancestors.push(Node::ExprLoop(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.label {
+        visit_label(v, it, ancestors)?
+    };
+    skip!(node.loop_token);
+    visit_block(v, &node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_macro(node, ancestors));
+    ancestors.push(Node::ExprMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_macro(v, &node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_match<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMatch,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_match(node, ancestors));
+    ancestors.push(Node::ExprMatch(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.match_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.arms {
+        visit_arm(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_method_call<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprMethodCall,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_method_call(node, ancestors));
+    ancestors.push(Node::ExprMethodCall(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.receiver, ancestors)?;
+    skip!(node.dot_token);
+    visit_ident(v, &node.method, ancestors)?;
+    if let Some(it) = &node.turbofish {
+        visit_method_turbofish(v, it, ancestors)?
+    };
+    skip!(node.paren_token);
+    for it in &node.args {
+        visit_expr(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_paren<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprParen,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_paren(node, ancestors));
+    ancestors.push(Node::ExprParen(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_path<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprPath,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_path(node, ancestors));
+    ancestors.push(Node::ExprPath(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.qself {
+        visit_qself(v, it, ancestors)?
+    };
+    visit_path(v, &node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_range<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprRange,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_range(node, ancestors));
+    ancestors.push(Node::ExprRange(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.from {
+        visit_expr(v, &**it, ancestors)?
+    };
+    visit_range_limits(v, &node.limits, ancestors)?;
+    if let Some(it) = &node.to {
+        visit_expr(v, &**it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReference,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_reference(node, ancestors));
+    ancestors.push(Node::ExprReference(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.and_token);
+    skip!(node.mutability);
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_repeat<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprRepeat,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_repeat(node, ancestors));
+    ancestors.push(Node::ExprRepeat(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.semi_token);
+    visit_expr(v, &*node.len, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_return<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprReturn,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_return(node, ancestors));
+    ancestors.push(Node::ExprReturn(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.return_token);
+    if let Some(it) = &node.expr {
+        visit_expr(v, &**it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprStruct,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_struct(node, ancestors));
+    ancestors.push(Node::ExprStruct(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.fields {
+        visit_field_value(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    if let Some(it) = &node.rest {
+        visit_expr(v, &**it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_try<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprTry,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_try(node, ancestors));
+    ancestors.push(Node::ExprTry(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.question_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_try_block<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprTryBlock,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_try_block(node, ancestors));
+    ancestors.push(Node::ExprTryBlock(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.try_token);
+    visit_block(v, &node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_tuple<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprTuple,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_tuple(node, ancestors));
+    ancestors.push(Node::ExprTuple(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    for it in &node.elems {
+        visit_expr(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_type(node, ancestors));
+    ancestors.push(Node::ExprType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_expr_unary<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprUnary,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_unary(node, ancestors));
+    ancestors.push(Node::ExprUnary(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_un_op(v, &node.op, ancestors)?;
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_unsafe<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprUnsafe,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_unsafe(node, ancestors));
+    ancestors.push(Node::ExprUnsafe(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.unsafe_token);
+    visit_block(v, &node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_while<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprWhile,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_while(node, ancestors));
+    ancestors.push(Node::ExprWhile(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.label {
+        visit_label(v, it, ancestors)?
+    };
+    skip!(node.while_token);
+    visit_expr(v, &*node.cond, ancestors)?;
+    visit_block(v, &node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_expr_yield<'ast, V>(
+    v: &mut V,
+    node: &'ast ExprYield,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_expr_yield(node, ancestors));
+    ancestors.push(Node::ExprYield(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.yield_token);
+    if let Some(it) = &node.expr {
+        visit_expr(v, &**it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_field<'ast, V>(
+    v: &mut V,
+    node: &'ast Field,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_field(node, ancestors));
+    ancestors.push(Node::Field(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    if let Some(it) = &node.ident {
+        visit_ident(v, it, ancestors)?
+    };
+    skip!(node.colon_token);
+    visit_type(v, &node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_field_pat<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldPat,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_field_pat(node, ancestors));
+    ancestors.push(Node::FieldPat(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_member(v, &node.member, ancestors)?;
+    skip!(node.colon_token);
+    visit_pat(v, &*node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_field_value<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldValue,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_field_value(node, ancestors));
+    ancestors.push(Node::FieldValue(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_member(v, &node.member, ancestors)?;
+    skip!(node.colon_token);
+    visit_expr(v, &node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_fields<'ast, V>(
+    v: &mut V,
+    node: &'ast Fields,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_fields(node, ancestors));
+    ancestors.push(Node::Fields(node));
+    match node {
+        Fields::Named(_binding_0) => {
+            visit_fields_named(v, _binding_0, ancestors)?;
+        }
+        Fields::Unnamed(_binding_0) => {
+            visit_fields_unnamed(v, _binding_0, ancestors)?;
+        }
+        Fields::Unit => {}
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_fields_named<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsNamed,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_fields_named(node, ancestors));
+    ancestors.push(Node::FieldsNamed(node));
+    skip!(node.brace_token);
+    for it in &node.named {
+        visit_field(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_fields_unnamed<'ast, V>(
+    v: &mut V,
+    node: &'ast FieldsUnnamed,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_fields_unnamed(node, ancestors));
+    ancestors.push(Node::FieldsUnnamed(node));
+    skip!(node.paren_token);
+    for it in &node.unnamed {
+        visit_field(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_file<'ast, V>(
+    v: &mut V,
+    node: &'ast File,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_file(node, ancestors));
+    ancestors.push(Node::File(node));
+    skip!(node.shebang);
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    for it in &node.items {
+        visit_item(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_fn_arg<'ast, V>(
+    v: &mut V,
+    node: &'ast FnArg,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_fn_arg(node, ancestors));
+    ancestors.push(Node::FnArg(node));
+    match node {
+        FnArg::Receiver(_binding_0) => {
+            visit_receiver(v, _binding_0, ancestors)?;
+        }
+        FnArg::Typed(_binding_0) => {
+            visit_pat_type(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_foreign_item<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItem,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_foreign_item(node, ancestors));
+    ancestors.push(Node::ForeignItem(node));
+    match node {
+        ForeignItem::Fn(_binding_0) => {
+            visit_foreign_item_fn(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Static(_binding_0) => {
+            visit_foreign_item_static(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Type(_binding_0) => {
+            visit_foreign_item_type(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Macro(_binding_0) => {
+            visit_foreign_item_macro(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_foreign_item_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemFn,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_foreign_item_fn(node, ancestors));
+    ancestors.push(Node::ForeignItemFn(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    visit_signature(v, &node.sig, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_foreign_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_foreign_item_macro(node, ancestors));
+    ancestors.push(Node::ForeignItemMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_macro(v, &node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_foreign_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemStatic,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_foreign_item_static(node, ancestors));
+    ancestors.push(Node::ForeignItemStatic(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.static_token);
+    skip!(node.mutability);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_foreign_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ForeignItemType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_foreign_item_type(node, ancestors));
+    ancestors.push(Node::ForeignItemType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.type_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_generic_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericArgument,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_generic_argument(node, ancestors));
+    ancestors.push(Node::GenericArgument(node));
+    match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            visit_lifetime(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Type(_binding_0) => {
+            visit_type(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Binding(_binding_0) => {
+            visit_binding(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            visit_constraint(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Const(_binding_0) => {
+            visit_expr(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_generic_method_argument<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericMethodArgument,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_generic_method_argument(node, ancestors));
+    ancestors.push(Node::GenericMethodArgument(node));
+    match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            visit_type(v, _binding_0, ancestors)?;
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            visit_expr(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_generic_param<'ast, V>(
+    v: &mut V,
+    node: &'ast GenericParam,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_generic_param(node, ancestors));
+    ancestors.push(Node::GenericParam(node));
+    match node {
+        GenericParam::Type(_binding_0) => {
+            visit_type_param(v, _binding_0, ancestors)?;
+        }
+        GenericParam::Lifetime(_binding_0) => {
+            visit_lifetime_def(v, _binding_0, ancestors)?;
+        }
+        GenericParam::Const(_binding_0) => {
+            visit_const_param(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_generics<'ast, V>(
+    v: &mut V,
+    node: &'ast Generics,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_generics(node, ancestors));
+    ancestors.push(Node::Generics(node));
+    skip!(node.lt_token);
+    for it in &node.params {
+        visit_generic_param(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    if let Some(it) = &node.where_clause {
+        visit_where_clause(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "visit")]
+pub fn visit_ident<'ast, V>(
+    v: &mut V,
+    node: &'ast Ident,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_ident(node, ancestors));
+    ancestors.push(Node::Ident(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_impl_item<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItem,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_impl_item(node, ancestors));
+    ancestors.push(Node::ImplItem(node));
+    match node {
+        ImplItem::Const(_binding_0) => {
+            visit_impl_item_const(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Method(_binding_0) => {
+            visit_impl_item_method(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Type(_binding_0) => {
+            visit_impl_item_type(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Macro(_binding_0) => {
+            visit_impl_item_macro(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_impl_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemConst,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_impl_item_const(node, ancestors));
+    ancestors.push(Node::ImplItemConst(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.defaultness);
+    skip!(node.const_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr(v, &node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_impl_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_impl_item_macro(node, ancestors));
+    ancestors.push(Node::ImplItemMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_macro(v, &node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_impl_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemMethod,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_impl_item_method(node, ancestors));
+    ancestors.push(Node::ImplItemMethod(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.defaultness);
+    visit_signature(v, &node.sig, ancestors)?;
+    visit_block(v, &node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_impl_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ImplItemType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_impl_item_type(node, ancestors));
+    ancestors.push(Node::ImplItemType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.defaultness);
+    skip!(node.type_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.eq_token);
+    visit_type(v, &node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_index<'ast, V>(
+    v: &mut V,
+    node: &'ast Index,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_index(node, ancestors));
+    ancestors.push(Node::Index(node));
+    skip!(node.index);
+    skip!(node.span);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item<'ast, V>(
+    v: &mut V,
+    node: &'ast Item,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item(node, ancestors));
+    ancestors.push(Node::Item(node));
+    match node {
+        Item::Const(_binding_0) => {
+            visit_item_const(v, _binding_0, ancestors)?;
+        }
+        Item::Enum(_binding_0) => {
+            visit_item_enum(v, _binding_0, ancestors)?;
+        }
+        Item::ExternCrate(_binding_0) => {
+            visit_item_extern_crate(v, _binding_0, ancestors)?;
+        }
+        Item::Fn(_binding_0) => {
+            visit_item_fn(v, _binding_0, ancestors)?;
+        }
+        Item::ForeignMod(_binding_0) => {
+            visit_item_foreign_mod(v, _binding_0, ancestors)?;
+        }
+        Item::Impl(_binding_0) => {
+            visit_item_impl(v, _binding_0, ancestors)?;
+        }
+        Item::Macro(_binding_0) => {
+            visit_item_macro(v, _binding_0, ancestors)?;
+        }
+        Item::Macro2(_binding_0) => {
+            visit_item_macro2(v, _binding_0, ancestors)?;
+        }
+        Item::Mod(_binding_0) => {
+            visit_item_mod(v, _binding_0, ancestors)?;
+        }
+        Item::Static(_binding_0) => {
+            visit_item_static(v, _binding_0, ancestors)?;
+        }
+        Item::Struct(_binding_0) => {
+            visit_item_struct(v, _binding_0, ancestors)?;
+        }
+        Item::Trait(_binding_0) => {
+            visit_item_trait(v, _binding_0, ancestors)?;
+        }
+        Item::TraitAlias(_binding_0) => {
+            visit_item_trait_alias(v, _binding_0, ancestors)?;
+        }
+        Item::Type(_binding_0) => {
+            visit_item_type(v, _binding_0, ancestors)?;
+        }
+        Item::Union(_binding_0) => {
+            visit_item_union(v, _binding_0, ancestors)?;
+        }
+        Item::Use(_binding_0) => {
+            visit_item_use(v, _binding_0, ancestors)?;
+        }
+        Item::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemConst,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_const(node, ancestors));
+    ancestors.push(Node::ItemConst(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.const_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_enum<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemEnum,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_enum(node, ancestors));
+    ancestors.push(Node::ItemEnum(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.enum_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.variants {
+        visit_variant(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_extern_crate<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemExternCrate,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_extern_crate(node, ancestors));
+    ancestors.push(Node::ItemExternCrate(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.extern_token);
+    skip!(node.crate_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    if let Some(it) = &node.rename {
+        skip!((it).0);
+        visit_ident(v, &(it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemFn,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_fn(node, ancestors));
+    ancestors.push(Node::ItemFn(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    visit_signature(v, &node.sig, ancestors)?;
+    visit_block(v, &*node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_foreign_mod<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemForeignMod,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_foreign_mod(node, ancestors));
+    ancestors.push(Node::ItemForeignMod(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_abi(v, &node.abi, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.items {
+        visit_foreign_item(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_impl<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemImpl,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_impl(node, ancestors));
+    ancestors.push(Node::ItemImpl(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.defaultness);
+    skip!(node.unsafety);
+    skip!(node.impl_token);
+    visit_generics(v, &node.generics, ancestors)?;
+    if let Some(it) = &node.trait_ {
+        skip!((it).0);
+        visit_path(v, &(it).1, ancestors)?;
+        skip!((it).2);
+    };
+    visit_type(v, &*node.self_ty, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.items {
+        visit_impl_item(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_macro(node, ancestors));
+    ancestors.push(Node::ItemMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.ident {
+        visit_ident(v, it, ancestors)?
+    };
+    visit_macro(v, &node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_macro2<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemMacro2,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_macro2(node, ancestors));
+    ancestors.push(Node::ItemMacro2(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.macro_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.rules);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_mod<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemMod,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_mod(node, ancestors));
+    ancestors.push(Node::ItemMod(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.mod_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    if let Some(it) = &node.content {
+        skip!((it).0);
+        for it in &(it).1 {
+            visit_item(v, it, ancestors)?
+        }
+    };
+    skip!(node.semi);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_static<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStatic,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_static(node, ancestors));
+    ancestors.push(Node::ItemStatic(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.static_token);
+    skip!(node.mutability);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr(v, &*node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemStruct,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_struct(node, ancestors));
+    ancestors.push(Node::ItemStruct(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.struct_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    visit_fields(v, &node.fields, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_trait<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemTrait,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_trait(node, ancestors));
+    ancestors.push(Node::ItemTrait(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.unsafety);
+    skip!(node.auto_token);
+    skip!(node.trait_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.supertraits {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    skip!(node.brace_token);
+    for it in &node.items {
+        visit_trait_item(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_trait_alias<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemTraitAlias,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_trait_alias(node, ancestors));
+    ancestors.push(Node::ItemTraitAlias(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.trait_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.eq_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_type(node, ancestors));
+    ancestors.push(Node::ItemType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.type_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.eq_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_union<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemUnion,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_union(node, ancestors));
+    ancestors.push(Node::ItemUnion(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.union_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    visit_fields_named(v, &node.fields, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_item_use<'ast, V>(
+    v: &mut V,
+    node: &'ast ItemUse,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_item_use(node, ancestors));
+    ancestors.push(Node::ItemUse(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_visibility(v, &node.vis, ancestors)?;
+    skip!(node.use_token);
+    skip!(node.leading_colon);
+    visit_use_tree(v, &node.tree, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_label<'ast, V>(
+    v: &mut V,
+    node: &'ast Label,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_label(node, ancestors));
+    ancestors.push(Node::Label(node));
+    visit_lifetime(v, &node.name, ancestors)?;
+    skip!(node.colon_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "visit")]
+pub fn visit_lifetime<'ast, V>(
+    v: &mut V,
+    node: &'ast Lifetime,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lifetime(node, ancestors));
+    ancestors.push(Node::Lifetime(node));
+    skip!(node.apostrophe);
+    visit_ident(v, &node.ident, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lifetime_def<'ast, V>(
+    v: &mut V,
+    node: &'ast LifetimeDef,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lifetime_def(node, ancestors));
+    ancestors.push(Node::LifetimeDef(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_lifetime(v, &node.lifetime, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_lifetime(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit<'ast, V>(
+    v: &mut V,
+    node: &'ast Lit,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit(node, ancestors));
+    ancestors.push(Node::Lit(node));
+    match node {
+        Lit::Str(_binding_0) => {
+            visit_lit_str(v, _binding_0, ancestors)?;
+        }
+        Lit::ByteStr(_binding_0) => {
+            visit_lit_byte_str(v, _binding_0, ancestors)?;
+        }
+        Lit::Byte(_binding_0) => {
+            visit_lit_byte(v, _binding_0, ancestors)?;
+        }
+        Lit::Char(_binding_0) => {
+            visit_lit_char(v, _binding_0, ancestors)?;
+        }
+        Lit::Int(_binding_0) => {
+            visit_lit_int(v, _binding_0, ancestors)?;
+        }
+        Lit::Float(_binding_0) => {
+            visit_lit_float(v, _binding_0, ancestors)?;
+        }
+        Lit::Bool(_binding_0) => {
+            visit_lit_bool(v, _binding_0, ancestors)?;
+        }
+        Lit::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_bool<'ast, V>(
+    v: &mut V,
+    node: &'ast LitBool,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_bool(node, ancestors));
+    ancestors.push(Node::LitBool(node));
+    skip!(node.value);
+    skip!(node.span);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_byte<'ast, V>(
+    v: &mut V,
+    node: &'ast LitByte,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_byte(node, ancestors));
+    ancestors.push(Node::LitByte(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_byte_str<'ast, V>(
+    v: &mut V,
+    node: &'ast LitByteStr,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_byte_str(node, ancestors));
+    ancestors.push(Node::LitByteStr(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_char<'ast, V>(
+    v: &mut V,
+    node: &'ast LitChar,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_char(node, ancestors));
+    ancestors.push(Node::LitChar(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_float<'ast, V>(
+    v: &mut V,
+    node: &'ast LitFloat,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_float(node, ancestors));
+    ancestors.push(Node::LitFloat(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_int<'ast, V>(
+    v: &mut V,
+    node: &'ast LitInt,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_int(node, ancestors));
+    ancestors.push(Node::LitInt(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_lit_str<'ast, V>(
+    v: &mut V,
+    node: &'ast LitStr,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_lit_str(node, ancestors));
+    ancestors.push(Node::LitStr(node));
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_local<'ast, V>(
+    v: &mut V,
+    node: &'ast Local,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_local(node, ancestors));
+    ancestors.push(Node::Local(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.let_token);
+    visit_pat(v, &node.pat, ancestors)?;
+    if let Some(it) = &node.init {
+        skip!((it).0);
+        visit_expr(v, &*(it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast Macro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_macro(node, ancestors));
+    ancestors.push(Node::Macro(node));
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.bang_token);
+    visit_macro_delimiter(v, &node.delimiter, ancestors)?;
+    skip!(node.tokens);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_macro_delimiter<'ast, V>(
+    v: &mut V,
+    node: &'ast MacroDelimiter,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_macro_delimiter(node, ancestors));
+    ancestors.push(Node::MacroDelimiter(node));
+    match node {
+        MacroDelimiter::Paren(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroDelimiter::Brace(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroDelimiter::Bracket(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_member<'ast, V>(
+    v: &mut V,
+    node: &'ast Member,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_member(node, ancestors));
+    ancestors.push(Node::Member(node));
+    match node {
+        Member::Named(_binding_0) => {
+            visit_ident(v, _binding_0, ancestors)?;
+        }
+        Member::Unnamed(_binding_0) => {
+            visit_index(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_meta<'ast, V>(
+    v: &mut V,
+    node: &'ast Meta,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_meta(node, ancestors));
+    ancestors.push(Node::Meta(node));
+    match node {
+        Meta::Path(_binding_0) => {
+            visit_path(v, _binding_0, ancestors)?;
+        }
+        Meta::List(_binding_0) => {
+            visit_meta_list(v, _binding_0, ancestors)?;
+        }
+        Meta::NameValue(_binding_0) => {
+            visit_meta_name_value(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_meta_list<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaList,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_meta_list(node, ancestors));
+    ancestors.push(Node::MetaList(node));
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.paren_token);
+    for it in &node.nested {
+        visit_nested_meta(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_meta_name_value<'ast, V>(
+    v: &mut V,
+    node: &'ast MetaNameValue,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_meta_name_value(node, ancestors));
+    ancestors.push(Node::MetaNameValue(node));
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.eq_token);
+    visit_lit(v, &node.lit, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_method_turbofish<'ast, V>(
+    v: &mut V,
+    node: &'ast MethodTurbofish,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_method_turbofish(node, ancestors));
+    ancestors.push(Node::MethodTurbofish(node));
+    skip!(node.colon2_token);
+    skip!(node.lt_token);
+    for it in &node.args {
+        visit_generic_method_argument(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_nested_meta<'ast, V>(
+    v: &mut V,
+    node: &'ast NestedMeta,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_nested_meta(node, ancestors));
+    ancestors.push(Node::NestedMeta(node));
+    match node {
+        NestedMeta::Meta(_binding_0) => {
+            visit_meta(v, _binding_0, ancestors)?;
+        }
+        NestedMeta::Lit(_binding_0) => {
+            visit_lit(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_parenthesized_generic_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast ParenthesizedGenericArguments,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_parenthesized_generic_arguments(node, ancestors));
+    ancestors.push(Node::ParenthesizedGenericArguments(node));
+    skip!(node.paren_token);
+    for it in &node.inputs {
+        visit_type(v, it, ancestors)?
+    }
+    visit_return_type(v, &node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat<'ast, V>(
+    v: &mut V,
+    node: &'ast Pat,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat(node, ancestors));
+    ancestors.push(Node::Pat(node));
+    match node {
+        Pat::Box(_binding_0) => {
+            visit_pat_box(v, _binding_0, ancestors)?;
+        }
+        Pat::Ident(_binding_0) => {
+            visit_pat_ident(v, _binding_0, ancestors)?;
+        }
+        Pat::Lit(_binding_0) => {
+            visit_pat_lit(v, _binding_0, ancestors)?;
+        }
+        Pat::Macro(_binding_0) => {
+            visit_pat_macro(v, _binding_0, ancestors)?;
+        }
+        Pat::Or(_binding_0) => {
+            visit_pat_or(v, _binding_0, ancestors)?;
+        }
+        Pat::Path(_binding_0) => {
+            visit_pat_path(v, _binding_0, ancestors)?;
+        }
+        Pat::Range(_binding_0) => {
+            visit_pat_range(v, _binding_0, ancestors)?;
+        }
+        Pat::Reference(_binding_0) => {
+            visit_pat_reference(v, _binding_0, ancestors)?;
+        }
+        Pat::Rest(_binding_0) => {
+            visit_pat_rest(v, _binding_0, ancestors)?;
+        }
+        Pat::Slice(_binding_0) => {
+            visit_pat_slice(v, _binding_0, ancestors)?;
+        }
+        Pat::Struct(_binding_0) => {
+            visit_pat_struct(v, _binding_0, ancestors)?;
+        }
+        Pat::Tuple(_binding_0) => {
+            visit_pat_tuple(v, _binding_0, ancestors)?;
+        }
+        Pat::TupleStruct(_binding_0) => {
+            visit_pat_tuple_struct(v, _binding_0, ancestors)?;
+        }
+        Pat::Type(_binding_0) => {
+            visit_pat_type(v, _binding_0, ancestors)?;
+        }
+        Pat::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Pat::Wild(_binding_0) => {
+            visit_pat_wild(v, _binding_0, ancestors)?;
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_box<'ast, V>(
+    v: &mut V,
+    node: &'ast PatBox,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_box(node, ancestors));
+    ancestors.push(Node::PatBox(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.box_token);
+    visit_pat(v, &*node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_ident<'ast, V>(
+    v: &mut V,
+    node: &'ast PatIdent,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_ident(node, ancestors));
+    ancestors.push(Node::PatIdent(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.by_ref);
+    skip!(node.mutability);
+    visit_ident(v, &node.ident, ancestors)?;
+    if let Some(it) = &node.subpat {
+        skip!((it).0);
+        visit_pat(v, &*(it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_lit<'ast, V>(
+    v: &mut V,
+    node: &'ast PatLit,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_lit(node, ancestors));
+    ancestors.push(Node::PatLit(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast PatMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_macro(node, ancestors));
+    ancestors.push(Node::PatMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_macro(v, &node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_or<'ast, V>(
+    v: &mut V,
+    node: &'ast PatOr,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_or(node, ancestors));
+    ancestors.push(Node::PatOr(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.leading_vert);
+    for it in &node.cases {
+        visit_pat(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_path<'ast, V>(
+    v: &mut V,
+    node: &'ast PatPath,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_path(node, ancestors));
+    ancestors.push(Node::PatPath(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.qself {
+        visit_qself(v, it, ancestors)?
+    };
+    visit_path(v, &node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_range<'ast, V>(
+    v: &mut V,
+    node: &'ast PatRange,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_range(node, ancestors));
+    ancestors.push(Node::PatRange(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_expr(v, &*node.lo, ancestors)?;
+    visit_range_limits(v, &node.limits, ancestors)?;
+    visit_expr(v, &*node.hi, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast PatReference,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_reference(node, ancestors));
+    ancestors.push(Node::PatReference(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.and_token);
+    skip!(node.mutability);
+    visit_pat(v, &*node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_rest<'ast, V>(
+    v: &mut V,
+    node: &'ast PatRest,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_rest(node, ancestors));
+    ancestors.push(Node::PatRest(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_slice<'ast, V>(
+    v: &mut V,
+    node: &'ast PatSlice,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_slice(node, ancestors));
+    ancestors.push(Node::PatSlice(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    for it in &node.elems {
+        visit_pat(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast PatStruct,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_struct(node, ancestors));
+    ancestors.push(Node::PatStruct(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_path(v, &node.path, ancestors)?;
+    skip!(node.brace_token);
+    for it in &node.fields {
+        visit_field_pat(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_tuple<'ast, V>(
+    v: &mut V,
+    node: &'ast PatTuple,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_tuple(node, ancestors));
+    ancestors.push(Node::PatTuple(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    for it in &node.elems {
+        visit_pat(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_tuple_struct<'ast, V>(
+    v: &mut V,
+    node: &'ast PatTupleStruct,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_tuple_struct(node, ancestors));
+    ancestors.push(Node::PatTupleStruct(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_path(v, &node.path, ancestors)?;
+    visit_pat_tuple(v, &node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_type<'ast, V>(
+    v: &mut V,
+    node: &'ast PatType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_type(node, ancestors));
+    ancestors.push(Node::PatType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_pat(v, &*node.pat, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_pat_wild<'ast, V>(
+    v: &mut V,
+    node: &'ast PatWild,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_pat_wild(node, ancestors));
+    ancestors.push(Node::PatWild(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.underscore_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_path<'ast, V>(
+    v: &mut V,
+    node: &'ast Path,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_path(node, ancestors));
+    ancestors.push(Node::Path(node));
+    skip!(node.leading_colon);
+    for it in &node.segments {
+        visit_path_segment(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_path_arguments<'ast, V>(
+    v: &mut V,
+    node: &'ast PathArguments,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_path_arguments(node, ancestors));
+    ancestors.push(Node::PathArguments(node));
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(_binding_0) => {
+            visit_angle_bracketed_generic_arguments(v, _binding_0, ancestors)?;
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            visit_parenthesized_generic_arguments(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_path_segment<'ast, V>(
+    v: &mut V,
+    node: &'ast PathSegment,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_path_segment(node, ancestors));
+    ancestors.push(Node::PathSegment(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_path_arguments(v, &node.arguments, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_predicate_eq<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateEq,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_predicate_eq(node, ancestors));
+    ancestors.push(Node::PredicateEq(node));
+    visit_type(v, &node.lhs_ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_type(v, &node.rhs_ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_predicate_lifetime<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateLifetime,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_predicate_lifetime(node, ancestors));
+    ancestors.push(Node::PredicateLifetime(node));
+    visit_lifetime(v, &node.lifetime, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_lifetime(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_predicate_type<'ast, V>(
+    v: &mut V,
+    node: &'ast PredicateType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_predicate_type(node, ancestors));
+    ancestors.push(Node::PredicateType(node));
+    if let Some(it) = &node.lifetimes {
+        visit_bound_lifetimes(v, it, ancestors)?
+    };
+    visit_type(v, &node.bounded_ty, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_qself<'ast, V>(
+    v: &mut V,
+    node: &'ast QSelf,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_qself(node, ancestors));
+    ancestors.push(Node::QSelf(node));
+    skip!(node.lt_token);
+    visit_type(v, &*node.ty, ancestors)?;
+    skip!(node.position);
+    skip!(node.as_token);
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_range_limits<'ast, V>(
+    v: &mut V,
+    node: &'ast RangeLimits,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_range_limits(node, ancestors));
+    ancestors.push(Node::RangeLimits(node));
+    match node {
+        RangeLimits::HalfOpen(_binding_0) => {
+            skip!(_binding_0);
+        }
+        RangeLimits::Closed(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_receiver<'ast, V>(
+    v: &mut V,
+    node: &'ast Receiver,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_receiver(node, ancestors));
+    ancestors.push(Node::Receiver(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    if let Some(it) = &node.reference {
+        skip!((it).0);
+        if let Some(it) = &(it).1 {
+            visit_lifetime(v, it, ancestors)?
+        };
+    };
+    skip!(node.mutability);
+    skip!(node.self_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_return_type<'ast, V>(
+    v: &mut V,
+    node: &'ast ReturnType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_return_type(node, ancestors));
+    ancestors.push(Node::ReturnType(node));
+    match node {
+        ReturnType::Default => {}
+        ReturnType::Type(_binding_0, _binding_1) => {
+            skip!(_binding_0);
+            visit_type(v, &**_binding_1, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_signature<'ast, V>(
+    v: &mut V,
+    node: &'ast Signature,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_signature(node, ancestors));
+    ancestors.push(Node::Signature(node));
+    skip!(node.constness);
+    skip!(node.asyncness);
+    skip!(node.unsafety);
+    if let Some(it) = &node.abi {
+        visit_abi(v, it, ancestors)?
+    };
+    skip!(node.fn_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.paren_token);
+    for it in &node.inputs {
+        visit_fn_arg(v, it, ancestors)?
+    }
+    if let Some(it) = &node.variadic {
+        visit_variadic(v, it, ancestors)?
+    };
+    visit_return_type(v, &node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_stmt<'ast, V>(
+    v: &mut V,
+    node: &'ast Stmt,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_stmt(node, ancestors));
+    ancestors.push(Node::Stmt(node));
+    match node {
+        Stmt::Local(_binding_0) => {
+            visit_local(v, _binding_0, ancestors)?;
+        }
+        Stmt::Item(_binding_0) => {
+            visit_item(v, _binding_0, ancestors)?;
+        }
+        Stmt::Expr(_binding_0) => {
+            visit_expr(v, _binding_0, ancestors)?;
+        }
+        Stmt::Semi(_binding_0, _binding_1) => {
+            visit_expr(v, _binding_0, ancestors)?;
+            skip!(_binding_1);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_trait_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBound,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_bound(node, ancestors));
+    ancestors.push(Node::TraitBound(node));
+    skip!(node.paren_token);
+    visit_trait_bound_modifier(v, &node.modifier, ancestors)?;
+    if let Some(it) = &node.lifetimes {
+        visit_bound_lifetimes(v, it, ancestors)?
+    };
+    visit_path(v, &node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_trait_bound_modifier<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitBoundModifier,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_bound_modifier(node, ancestors));
+    ancestors.push(Node::TraitBoundModifier(node));
+    match node {
+        TraitBoundModifier::None => {}
+        TraitBoundModifier::Maybe(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_trait_item<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItem,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_item(node, ancestors));
+    ancestors.push(Node::TraitItem(node));
+    match node {
+        TraitItem::Const(_binding_0) => {
+            visit_trait_item_const(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Method(_binding_0) => {
+            visit_trait_item_method(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Type(_binding_0) => {
+            visit_trait_item_type(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Macro(_binding_0) => {
+            visit_trait_item_macro(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_trait_item_const<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemConst,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_item_const(node, ancestors));
+    ancestors.push(Node::TraitItemConst(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.const_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type(v, &node.ty, ancestors)?;
+    if let Some(it) = &node.default {
+        skip!((it).0);
+        visit_expr(v, &(it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_trait_item_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_item_macro(node, ancestors));
+    ancestors.push(Node::TraitItemMacro(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_macro(v, &node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_trait_item_method<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemMethod,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_item_method(node, ancestors));
+    ancestors.push(Node::TraitItemMethod(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_signature(v, &node.sig, ancestors)?;
+    if let Some(it) = &node.default {
+        visit_block(v, it, ancestors)?
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_trait_item_type<'ast, V>(
+    v: &mut V,
+    node: &'ast TraitItemType,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_trait_item_type(node, ancestors));
+    ancestors.push(Node::TraitItemType(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.type_token);
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_generics(v, &node.generics, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    if let Some(it) = &node.default {
+        skip!((it).0);
+        visit_type(v, &(it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type<'ast, V>(
+    v: &mut V,
+    node: &'ast Type,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type(node, ancestors));
+    ancestors.push(Node::Type(node));
+    match node {
+        Type::Array(_binding_0) => {
+            visit_type_array(v, _binding_0, ancestors)?;
+        }
+        Type::BareFn(_binding_0) => {
+            visit_type_bare_fn(v, _binding_0, ancestors)?;
+        }
+        Type::Group(_binding_0) => {
+            visit_type_group(v, _binding_0, ancestors)?;
+        }
+        Type::ImplTrait(_binding_0) => {
+            visit_type_impl_trait(v, _binding_0, ancestors)?;
+        }
+        Type::Infer(_binding_0) => {
+            visit_type_infer(v, _binding_0, ancestors)?;
+        }
+        Type::Macro(_binding_0) => {
+            visit_type_macro(v, _binding_0, ancestors)?;
+        }
+        Type::Never(_binding_0) => {
+            visit_type_never(v, _binding_0, ancestors)?;
+        }
+        Type::Paren(_binding_0) => {
+            visit_type_paren(v, _binding_0, ancestors)?;
+        }
+        Type::Path(_binding_0) => {
+            visit_type_path(v, _binding_0, ancestors)?;
+        }
+        Type::Ptr(_binding_0) => {
+            visit_type_ptr(v, _binding_0, ancestors)?;
+        }
+        Type::Reference(_binding_0) => {
+            visit_type_reference(v, _binding_0, ancestors)?;
+        }
+        Type::Slice(_binding_0) => {
+            visit_type_slice(v, _binding_0, ancestors)?;
+        }
+        Type::TraitObject(_binding_0) => {
+            visit_type_trait_object(v, _binding_0, ancestors)?;
+        }
+        Type::Tuple(_binding_0) => {
+            visit_type_tuple(v, _binding_0, ancestors)?;
+        }
+        Type::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_array<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeArray,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_array(node, ancestors));
+    ancestors.push(Node::TypeArray(node));
+    skip!(node.bracket_token);
+    visit_type(v, &*node.elem, ancestors)?;
+    skip!(node.semi_token);
+    visit_expr(v, &node.len, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_bare_fn<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeBareFn,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_bare_fn(node, ancestors));
+    ancestors.push(Node::TypeBareFn(node));
+    if let Some(it) = &node.lifetimes {
+        visit_bound_lifetimes(v, it, ancestors)?
+    };
+    skip!(node.unsafety);
+    if let Some(it) = &node.abi {
+        visit_abi(v, it, ancestors)?
+    };
+    skip!(node.fn_token);
+    skip!(node.paren_token);
+    for it in &node.inputs {
+        visit_bare_fn_arg(v, it, ancestors)?
+    }
+    if let Some(it) = &node.variadic {
+        visit_variadic(v, it, ancestors)?
+    };
+    visit_return_type(v, &node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_group<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeGroup,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_group(node, ancestors));
+    ancestors.push(Node::TypeGroup(node));
+    skip!(node.group_token);
+    visit_type(v, &*node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_impl_trait<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeImplTrait,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_impl_trait(node, ancestors));
+    ancestors.push(Node::TypeImplTrait(node));
+    skip!(node.impl_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_infer<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeInfer,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_infer(node, ancestors));
+    ancestors.push(Node::TypeInfer(node));
+    skip!(node.underscore_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_macro<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeMacro,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_macro(node, ancestors));
+    ancestors.push(Node::TypeMacro(node));
+    visit_macro(v, &node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_never<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeNever,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_never(node, ancestors));
+    ancestors.push(Node::TypeNever(node));
+    skip!(node.bang_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_param<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParam,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_param(node, ancestors));
+    ancestors.push(Node::TypeParam(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    skip!(node.eq_token);
+    if let Some(it) = &node.default {
+        visit_type(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_param_bound<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParamBound,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_param_bound(node, ancestors));
+    ancestors.push(Node::TypeParamBound(node));
+    match node {
+        TypeParamBound::Trait(_binding_0) => {
+            visit_trait_bound(v, _binding_0, ancestors)?;
+        }
+        TypeParamBound::Lifetime(_binding_0) => {
+            visit_lifetime(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_paren<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeParen,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_paren(node, ancestors));
+    ancestors.push(Node::TypeParen(node));
+    skip!(node.paren_token);
+    visit_type(v, &*node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_path<'ast, V>(
+    v: &mut V,
+    node: &'ast TypePath,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_path(node, ancestors));
+    ancestors.push(Node::TypePath(node));
+    if let Some(it) = &node.qself {
+        visit_qself(v, it, ancestors)?
+    };
+    visit_path(v, &node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_ptr<'ast, V>(
+    v: &mut V,
+    node: &'ast TypePtr,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_ptr(node, ancestors));
+    ancestors.push(Node::TypePtr(node));
+    skip!(node.star_token);
+    skip!(node.const_token);
+    skip!(node.mutability);
+    visit_type(v, &*node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_reference<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeReference,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_reference(node, ancestors));
+    ancestors.push(Node::TypeReference(node));
+    skip!(node.and_token);
+    if let Some(it) = &node.lifetime {
+        visit_lifetime(v, it, ancestors)?
+    };
+    skip!(node.mutability);
+    visit_type(v, &*node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_slice<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeSlice,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_slice(node, ancestors));
+    ancestors.push(Node::TypeSlice(node));
+    skip!(node.bracket_token);
+    visit_type(v, &*node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_trait_object<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeTraitObject,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_trait_object(node, ancestors));
+    ancestors.push(Node::TypeTraitObject(node));
+    skip!(node.dyn_token);
+    for it in &node.bounds {
+        visit_type_param_bound(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_type_tuple<'ast, V>(
+    v: &mut V,
+    node: &'ast TypeTuple,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_type_tuple(node, ancestors));
+    ancestors.push(Node::TypeTuple(node));
+    skip!(node.paren_token);
+    for it in &node.elems {
+        visit_type(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_un_op<'ast, V>(
+    v: &mut V,
+    node: &'ast UnOp,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_un_op(node, ancestors));
+    ancestors.push(Node::UnOp(node));
+    match node {
+        UnOp::Deref(_binding_0) => {
+            skip!(_binding_0);
+        }
+        UnOp::Not(_binding_0) => {
+            skip!(_binding_0);
+        }
+        UnOp::Neg(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_glob<'ast, V>(
+    v: &mut V,
+    node: &'ast UseGlob,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_glob(node, ancestors));
+    ancestors.push(Node::UseGlob(node));
+    skip!(node.star_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_group<'ast, V>(
+    v: &mut V,
+    node: &'ast UseGroup,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_group(node, ancestors));
+    ancestors.push(Node::UseGroup(node));
+    skip!(node.brace_token);
+    for it in &node.items {
+        visit_use_tree(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_name<'ast, V>(
+    v: &mut V,
+    node: &'ast UseName,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_name(node, ancestors));
+    ancestors.push(Node::UseName(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_path<'ast, V>(
+    v: &mut V,
+    node: &'ast UsePath,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_path(node, ancestors));
+    ancestors.push(Node::UsePath(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.colon2_token);
+    visit_use_tree(v, &*node.tree, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_rename<'ast, V>(
+    v: &mut V,
+    node: &'ast UseRename,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_rename(node, ancestors));
+    ancestors.push(Node::UseRename(node));
+    visit_ident(v, &node.ident, ancestors)?;
+    skip!(node.as_token);
+    visit_ident(v, &node.rename, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit")]
+pub fn visit_use_tree<'ast, V>(
+    v: &mut V,
+    node: &'ast UseTree,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_use_tree(node, ancestors));
+    ancestors.push(Node::UseTree(node));
+    match node {
+        UseTree::Path(_binding_0) => {
+            visit_use_path(v, _binding_0, ancestors)?;
+        }
+        UseTree::Name(_binding_0) => {
+            visit_use_name(v, _binding_0, ancestors)?;
+        }
+        UseTree::Rename(_binding_0) => {
+            visit_use_rename(v, _binding_0, ancestors)?;
+        }
+        UseTree::Glob(_binding_0) => {
+            visit_use_glob(v, _binding_0, ancestors)?;
+        }
+        UseTree::Group(_binding_0) => {
+            visit_use_group(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_variadic<'ast, V>(
+    v: &mut V,
+    node: &'ast Variadic,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_variadic(node, ancestors));
+    ancestors.push(Node::Variadic(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    skip!(node.dots);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_variant<'ast, V>(
+    v: &mut V,
+    node: &'ast Variant,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_variant(node, ancestors));
+    ancestors.push(Node::Variant(node));
+    for it in &node.attrs {
+        visit_attribute(v, it, ancestors)?
+    }
+    visit_ident(v, &node.ident, ancestors)?;
+    visit_fields(v, &node.fields, ancestors)?;
+    if let Some(it) = &node.discriminant {
+        skip!((it).0);
+        visit_expr(v, &(it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_vis_crate<'ast, V>(
+    v: &mut V,
+    node: &'ast VisCrate,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_vis_crate(node, ancestors));
+    ancestors.push(Node::VisCrate(node));
+    skip!(node.crate_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_vis_public<'ast, V>(
+    v: &mut V,
+    node: &'ast VisPublic,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_vis_public(node, ancestors));
+    ancestors.push(Node::VisPublic(node));
+    skip!(node.pub_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_vis_restricted<'ast, V>(
+    v: &mut V,
+    node: &'ast VisRestricted,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_vis_restricted(node, ancestors));
+    ancestors.push(Node::VisRestricted(node));
+    skip!(node.pub_token);
+    skip!(node.paren_token);
+    skip!(node.in_token);
+    visit_path(v, &*node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_visibility<'ast, V>(
+    v: &mut V,
+    node: &'ast Visibility,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_visibility(node, ancestors));
+    ancestors.push(Node::Visibility(node));
+    match node {
+        Visibility::Public(_binding_0) => {
+            visit_vis_public(v, _binding_0, ancestors)?;
+        }
+        Visibility::Crate(_binding_0) => {
+            visit_vis_crate(v, _binding_0, ancestors)?;
+        }
+        Visibility::Restricted(_binding_0) => {
+            visit_vis_restricted(v, _binding_0, ancestors)?;
+        }
+        Visibility::Inherited => {}
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_where_clause<'ast, V>(
+    v: &mut V,
+    node: &'ast WhereClause,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_where_clause(node, ancestors));
+    ancestors.push(Node::WhereClause(node));
+    skip!(node.where_token);
+    for it in &node.predicates {
+        visit_where_predicate(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit")]
+pub fn visit_where_predicate<'ast, V>(
+    v: &mut V,
+    node: &'ast WherePredicate,
+    ancestors: &mut Vec<Node<'ast>>,
+) -> Result<(), V::Break>
+where
+    V: VisitCtx<'ast> + ?Sized,
+{
+    enter!(v.visit_where_predicate(node, ancestors));
+    ancestors.push(Node::WherePredicate(node));
+    match node {
+        WherePredicate::Type(_binding_0) => {
+            visit_predicate_type(v, _binding_0, ancestors)?;
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            visit_predicate_lifetime(v, _binding_0, ancestors)?;
+        }
+        WherePredicate::Eq(_binding_0) => {
+            visit_predicate_eq(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+/// Syntax tree traversal over an exclusive borrow of a syntax tree
+/// that tells each hook where it is and lets it cut the walk short.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This trait is available if Syn is built with the `"visit-mut"`
+/// feature.*
+#[cfg(feature = "visit-mut")]
+pub trait VisitMutCtx {
+    /// The value a hook hands back through [`Control::Break`].
+    type Break;
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_abi_mut(&mut self, i: &mut Abi, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_angle_bracketed_generic_arguments_mut(
+        &mut self,
+        i: &mut AngleBracketedGenericArguments,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_arm_mut(&mut self, i: &mut Arm, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attr_style_mut(
+        &mut self,
+        i: &mut AttrStyle,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_attribute_mut(
+        &mut self,
+        i: &mut Attribute,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bare_fn_arg_mut(
+        &mut self,
+        i: &mut BareFnArg,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bin_op_mut(&mut self, i: &mut BinOp, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_binding_mut(
+        &mut self,
+        i: &mut Binding,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_block_mut(&mut self, i: &mut Block, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_bound_lifetimes_mut(
+        &mut self,
+        i: &mut BoundLifetimes,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_const_param_mut(
+        &mut self,
+        i: &mut ConstParam,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_constraint_mut(
+        &mut self,
+        i: &mut Constraint,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_mut(&mut self, i: &mut Data, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_enum_mut(
+        &mut self,
+        i: &mut DataEnum,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_struct_mut(
+        &mut self,
+        i: &mut DataStruct,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "derive")]
+    fn visit_data_union_mut(
+        &mut self,
+        i: &mut DataUnion,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "derive")]
+    fn visit_derive_input_mut(
+        &mut self,
+        i: &mut DeriveInput,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_mut(&mut self, i: &mut Expr, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_array_mut(
+        &mut self,
+        i: &mut ExprArray,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_mut(
+        &mut self,
+        i: &mut ExprAssign,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_assign_op_mut(
+        &mut self,
+        i: &mut ExprAssignOp,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_async_mut(
+        &mut self,
+        i: &mut ExprAsync,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_await_mut(
+        &mut self,
+        i: &mut ExprAwait,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_binary_mut(
+        &mut self,
+        i: &mut ExprBinary,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_block_mut(
+        &mut self,
+        i: &mut ExprBlock,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_box_mut(
+        &mut self,
+        i: &mut ExprBox,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_break_mut(
+        &mut self,
+        i: &mut ExprBreak,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_call_mut(
+        &mut self,
+        i: &mut ExprCall,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_cast_mut(
+        &mut self,
+        i: &mut ExprCast,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_closure_mut(
+        &mut self,
+        i: &mut ExprClosure,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_continue_mut(
+        &mut self,
+        i: &mut ExprContinue,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_field_mut(
+        &mut self,
+        i: &mut ExprField,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_for_loop_mut(
+        &mut self,
+        i: &mut ExprForLoop,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_group_mut(
+        &mut self,
+        i: &mut ExprGroup,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_if_mut(
+        &mut self,
+        i: &mut ExprIf,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_index_mut(
+        &mut self,
+        i: &mut ExprIndex,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_let_mut(
+        &mut self,
+        i: &mut ExprLet,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_lit_mut(
+        &mut self,
+        i: &mut ExprLit,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_loop_mut(
+        &mut self,
+        i: &mut ExprLoop,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_macro_mut(
+        &mut self,
+        i: &mut ExprMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_match_mut(
+        &mut self,
+        i: &mut ExprMatch,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_method_call_mut(
+        &mut self,
+        i: &mut ExprMethodCall,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_paren_mut(
+        &mut self,
+        i: &mut ExprParen,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_path_mut(
+        &mut self,
+        i: &mut ExprPath,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_range_mut(
+        &mut self,
+        i: &mut ExprRange,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_reference_mut(
+        &mut self,
+        i: &mut ExprReference,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_repeat_mut(
+        &mut self,
+        i: &mut ExprRepeat,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_return_mut(
+        &mut self,
+        i: &mut ExprReturn,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_struct_mut(
+        &mut self,
+        i: &mut ExprStruct,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_mut(
+        &mut self,
+        i: &mut ExprTry,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_try_block_mut(
+        &mut self,
+        i: &mut ExprTryBlock,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_tuple_mut(
+        &mut self,
+        i: &mut ExprTuple,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_type_mut(
+        &mut self,
+        i: &mut ExprType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_expr_unary_mut(
+        &mut self,
+        i: &mut ExprUnary,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_unsafe_mut(
+        &mut self,
+        i: &mut ExprUnsafe,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_while_mut(
+        &mut self,
+        i: &mut ExprWhile,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_expr_yield_mut(
+        &mut self,
+        i: &mut ExprYield,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_field_mut(&mut self, i: &mut Field, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_pat_mut(
+        &mut self,
+        i: &mut FieldPat,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_field_value_mut(
+        &mut self,
+        i: &mut FieldValue,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_mut(&mut self, i: &mut Fields, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_named_mut(
+        &mut self,
+        i: &mut FieldsNamed,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_fields_unnamed_mut(
+        &mut self,
+        i: &mut FieldsUnnamed,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_file_mut(&mut self, i: &mut File, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_fn_arg_mut(&mut self, i: &mut FnArg, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_mut(
+        &mut self,
+        i: &mut ForeignItem,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_fn_mut(
+        &mut self,
+        i: &mut ForeignItemFn,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_macro_mut(
+        &mut self,
+        i: &mut ForeignItemMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_static_mut(
+        &mut self,
+        i: &mut ForeignItemStatic,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_foreign_item_type_mut(
+        &mut self,
+        i: &mut ForeignItemType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_argument_mut(
+        &mut self,
+        i: &mut GenericArgument,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_generic_method_argument_mut(
+        &mut self,
+        i: &mut GenericMethodArgument,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generic_param_mut(
+        &mut self,
+        i: &mut GenericParam,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_generics_mut(
+        &mut self,
+        i: &mut Generics,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    fn visit_ident_mut(&mut self, i: &mut Ident, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_mut(
+        &mut self,
+        i: &mut ImplItem,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_const_mut(
+        &mut self,
+        i: &mut ImplItemConst,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_macro_mut(
+        &mut self,
+        i: &mut ImplItemMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_method_mut(
+        &mut self,
+        i: &mut ImplItemMethod,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_impl_item_type_mut(
+        &mut self,
+        i: &mut ImplItemType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_index_mut(&mut self, i: &mut Index, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mut(&mut self, i: &mut Item, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_const_mut(
+        &mut self,
+        i: &mut ItemConst,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_enum_mut(
+        &mut self,
+        i: &mut ItemEnum,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_extern_crate_mut(
+        &mut self,
+        i: &mut ItemExternCrate,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_fn_mut(
+        &mut self,
+        i: &mut ItemFn,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_foreign_mod_mut(
+        &mut self,
+        i: &mut ItemForeignMod,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_impl_mut(
+        &mut self,
+        i: &mut ItemImpl,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro_mut(
+        &mut self,
+        i: &mut ItemMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_macro2_mut(
+        &mut self,
+        i: &mut ItemMacro2,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_mod_mut(
+        &mut self,
+        i: &mut ItemMod,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_static_mut(
+        &mut self,
+        i: &mut ItemStatic,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_struct_mut(
+        &mut self,
+        i: &mut ItemStruct,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_mut(
+        &mut self,
+        i: &mut ItemTrait,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_trait_alias_mut(
+        &mut self,
+        i: &mut ItemTraitAlias,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_type_mut(
+        &mut self,
+        i: &mut ItemType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_union_mut(
+        &mut self,
+        i: &mut ItemUnion,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_item_use_mut(
+        &mut self,
+        i: &mut ItemUse,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_label_mut(&mut self, i: &mut Label, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    fn visit_lifetime_mut(
+        &mut self,
+        i: &mut Lifetime,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lifetime_def_mut(
+        &mut self,
+        i: &mut LifetimeDef,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_mut(&mut self, i: &mut Lit, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_bool_mut(
+        &mut self,
+        i: &mut LitBool,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte_mut(
+        &mut self,
+        i: &mut LitByte,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_byte_str_mut(
+        &mut self,
+        i: &mut LitByteStr,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_char_mut(
+        &mut self,
+        i: &mut LitChar,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_float_mut(
+        &mut self,
+        i: &mut LitFloat,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_int_mut(
+        &mut self,
+        i: &mut LitInt,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_lit_str_mut(
+        &mut self,
+        i: &mut LitStr,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_local_mut(&mut self, i: &mut Local, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_mut(&mut self, i: &mut Macro, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_macro_delimiter_mut(
+        &mut self,
+        i: &mut MacroDelimiter,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_member_mut(&mut self, i: &mut Member, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_mut(&mut self, i: &mut Meta, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_list_mut(
+        &mut self,
+        i: &mut MetaList,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_meta_name_value_mut(
+        &mut self,
+        i: &mut MetaNameValue,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_method_turbofish_mut(
+        &mut self,
+        i: &mut MethodTurbofish,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_nested_meta_mut(
+        &mut self,
+        i: &mut NestedMeta,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_parenthesized_generic_arguments_mut(
+        &mut self,
+        i: &mut ParenthesizedGenericArguments,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_mut(&mut self, i: &mut Pat, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_box_mut(
+        &mut self,
+        i: &mut PatBox,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_ident_mut(
+        &mut self,
+        i: &mut PatIdent,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_lit_mut(
+        &mut self,
+        i: &mut PatLit,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_macro_mut(
+        &mut self,
+        i: &mut PatMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_or_mut(&mut self, i: &mut PatOr, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_path_mut(
+        &mut self,
+        i: &mut PatPath,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_range_mut(
+        &mut self,
+        i: &mut PatRange,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_reference_mut(
+        &mut self,
+        i: &mut PatReference,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_rest_mut(
+        &mut self,
+        i: &mut PatRest,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_slice_mut(
+        &mut self,
+        i: &mut PatSlice,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_struct_mut(
+        &mut self,
+        i: &mut PatStruct,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_mut(
+        &mut self,
+        i: &mut PatTuple,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_tuple_struct_mut(
+        &mut self,
+        i: &mut PatTupleStruct,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_type_mut(
+        &mut self,
+        i: &mut PatType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_pat_wild_mut(
+        &mut self,
+        i: &mut PatWild,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_mut(&mut self, i: &mut Path, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_arguments_mut(
+        &mut self,
+        i: &mut PathArguments,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_path_segment_mut(
+        &mut self,
+        i: &mut PathSegment,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_eq_mut(
+        &mut self,
+        i: &mut PredicateEq,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_lifetime_mut(
+        &mut self,
+        i: &mut PredicateLifetime,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_predicate_type_mut(
+        &mut self,
+        i: &mut PredicateType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_qself_mut(&mut self, i: &mut QSelf, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_range_limits_mut(
+        &mut self,
+        i: &mut RangeLimits,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_receiver_mut(
+        &mut self,
+        i: &mut Receiver,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_return_type_mut(
+        &mut self,
+        i: &mut ReturnType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_signature_mut(
+        &mut self,
+        i: &mut Signature,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_stmt_mut(&mut self, i: &mut Stmt, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_mut(
+        &mut self,
+        i: &mut TraitBound,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_trait_bound_modifier_mut(
+        &mut self,
+        i: &mut TraitBoundModifier,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_mut(
+        &mut self,
+        i: &mut TraitItem,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_const_mut(
+        &mut self,
+        i: &mut TraitItemConst,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_macro_mut(
+        &mut self,
+        i: &mut TraitItemMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_method_mut(
+        &mut self,
+        i: &mut TraitItemMethod,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_trait_item_type_mut(
+        &mut self,
+        i: &mut TraitItemType,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_mut(&mut self, i: &mut Type, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_array_mut(
+        &mut self,
+        i: &mut TypeArray,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_bare_fn_mut(
+        &mut self,
+        i: &mut TypeBareFn,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_group_mut(
+        &mut self,
+        i: &mut TypeGroup,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_impl_trait_mut(
+        &mut self,
+        i: &mut TypeImplTrait,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_infer_mut(
+        &mut self,
+        i: &mut TypeInfer,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_macro_mut(
+        &mut self,
+        i: &mut TypeMacro,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_never_mut(
+        &mut self,
+        i: &mut TypeNever,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_mut(
+        &mut self,
+        i: &mut TypeParam,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_param_bound_mut(
+        &mut self,
+        i: &mut TypeParamBound,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_paren_mut(
+        &mut self,
+        i: &mut TypeParen,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_path_mut(
+        &mut self,
+        i: &mut TypePath,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_ptr_mut(
+        &mut self,
+        i: &mut TypePtr,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_reference_mut(
+        &mut self,
+        i: &mut TypeReference,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_slice_mut(
+        &mut self,
+        i: &mut TypeSlice,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_trait_object_mut(
+        &mut self,
+        i: &mut TypeTraitObject,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_type_tuple_mut(
+        &mut self,
+        i: &mut TypeTuple,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_un_op_mut(&mut self, i: &mut UnOp, ancestors: &[NodeKind]) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_glob_mut(
+        &mut self,
+        i: &mut UseGlob,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_group_mut(
+        &mut self,
+        i: &mut UseGroup,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_name_mut(
+        &mut self,
+        i: &mut UseName,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_path_mut(
+        &mut self,
+        i: &mut UsePath,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_rename_mut(
+        &mut self,
+        i: &mut UseRename,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(feature = "full")]
+    fn visit_use_tree_mut(
+        &mut self,
+        i: &mut UseTree,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variadic_mut(
+        &mut self,
+        i: &mut Variadic,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_variant_mut(
+        &mut self,
+        i: &mut Variant,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_crate_mut(
+        &mut self,
+        i: &mut VisCrate,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_public_mut(
+        &mut self,
+        i: &mut VisPublic,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_vis_restricted_mut(
+        &mut self,
+        i: &mut VisRestricted,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_visibility_mut(
+        &mut self,
+        i: &mut Visibility,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_clause_mut(
+        &mut self,
+        i: &mut WhereClause,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+    #[cfg(any(feature = "derive", feature = "full"))]
+    fn visit_where_predicate_mut(
+        &mut self,
+        i: &mut WherePredicate,
+        ancestors: &[NodeKind],
+    ) -> Control<Self::Break> {
+        Control::Continue
+    }
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_abi_mut<V>(
+    v: &mut V,
+    node: &mut Abi,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_abi_mut(node, ancestors));
+    ancestors.push(NodeKind::Abi);
+    skip!(node.extern_token);
+    if let Some(it) = &mut node.name {
+        visit_lit_str_mut(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_angle_bracketed_generic_arguments_mut<V>(
+    v: &mut V,
+    node: &mut AngleBracketedGenericArguments,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_angle_bracketed_generic_arguments_mut(node, ancestors));
+    ancestors.push(NodeKind::AngleBracketedGenericArguments);
+    skip!(node.colon2_token);
+    skip!(node.lt_token);
+    for it in &mut node.args {
+        visit_generic_argument_mut(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_arm_mut<V>(
+    v: &mut V,
+    node: &mut Arm,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_arm_mut(node, ancestors));
+    ancestors.push(NodeKind::Arm);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_pat_mut(v, &mut node.pat, ancestors)?;
+    if let Some(it) = &mut node.guard {
+        skip!((it).0);
+        visit_expr_mut(v, &mut *(it).1, ancestors)?;
+    };
+    skip!(node.fat_arrow_token);
+    visit_expr_mut(v, &mut *node.body, ancestors)?;
+    skip!(node.comma);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_attr_style_mut<V>(
+    v: &mut V,
+    node: &mut AttrStyle,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_attr_style_mut(node, ancestors));
+    ancestors.push(NodeKind::AttrStyle);
+    match node {
+        AttrStyle::Outer => {}
+        AttrStyle::Inner(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_attribute_mut<V>(
+    v: &mut V,
+    node: &mut Attribute,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_attribute_mut(node, ancestors));
+    ancestors.push(NodeKind::Attribute);
+    skip!(node.pound_token);
+    visit_attr_style_mut(v, &mut node.style, ancestors)?;
+    skip!(node.bracket_token);
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.tokens);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_bare_fn_arg_mut<V>(
+    v: &mut V,
+    node: &mut BareFnArg,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_bare_fn_arg_mut(node, ancestors));
+    ancestors.push(NodeKind::BareFnArg);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.name {
+        visit_ident_mut(v, &mut (it).0, ancestors)?;
+        skip!((it).1);
+    };
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_bin_op_mut<V>(
+    v: &mut V,
+    node: &mut BinOp,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_bin_op_mut(node, ancestors));
+    ancestors.push(NodeKind::BinOp);
+    match node {
+        BinOp::Add(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Sub(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Mul(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Div(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Rem(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::And(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Or(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitXor(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitAnd(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitOr(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Shl(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Shr(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Eq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Lt(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Le(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Ne(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Ge(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::Gt(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::AddEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::SubEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::MulEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::DivEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::RemEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitXorEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitAndEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::BitOrEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::ShlEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+        BinOp::ShrEq(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_binding_mut<V>(
+    v: &mut V,
+    node: &mut Binding,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_binding_mut(node, ancestors));
+    ancestors.push(NodeKind::Binding);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.eq_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_block_mut<V>(
+    v: &mut V,
+    node: &mut Block,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_block_mut(node, ancestors));
+    ancestors.push(NodeKind::Block);
+    skip!(node.brace_token);
+    for it in &mut node.stmts {
+        visit_stmt_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_bound_lifetimes_mut<V>(
+    v: &mut V,
+    node: &mut BoundLifetimes,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_bound_lifetimes_mut(node, ancestors));
+    ancestors.push(NodeKind::BoundLifetimes);
+    skip!(node.for_token);
+    skip!(node.lt_token);
+    for it in &mut node.lifetimes {
+        visit_lifetime_def_mut(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_const_param_mut<V>(
+    v: &mut V,
+    node: &mut ConstParam,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_const_param_mut(node, ancestors));
+    ancestors.push(NodeKind::ConstParam);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.const_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    skip!(node.eq_token);
+    if let Some(it) = &mut node.default {
+        visit_expr_mut(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_constraint_mut<V>(
+    v: &mut V,
+    node: &mut Constraint,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_constraint_mut(node, ancestors));
+    ancestors.push(NodeKind::Constraint);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_data_mut<V>(
+    v: &mut V,
+    node: &mut Data,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_data_mut(node, ancestors));
+    ancestors.push(NodeKind::Data);
+    match node {
+        Data::Struct(_binding_0) => {
+            visit_data_struct_mut(v, _binding_0, ancestors)?;
+        }
+        Data::Enum(_binding_0) => {
+            visit_data_enum_mut(v, _binding_0, ancestors)?;
+        }
+        Data::Union(_binding_0) => {
+            visit_data_union_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_data_enum_mut<V>(
+    v: &mut V,
+    node: &mut DataEnum,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_data_enum_mut(node, ancestors));
+    ancestors.push(NodeKind::DataEnum);
+    skip!(node.enum_token);
+    skip!(node.brace_token);
+    for it in &mut node.variants {
+        visit_variant_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_data_struct_mut<V>(
+    v: &mut V,
+    node: &mut DataStruct,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_data_struct_mut(node, ancestors));
+    ancestors.push(NodeKind::DataStruct);
+    skip!(node.struct_token);
+    visit_fields_mut(v, &mut node.fields, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_data_union_mut<V>(
+    v: &mut V,
+    node: &mut DataUnion,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_data_union_mut(node, ancestors));
+    ancestors.push(NodeKind::DataUnion);
+    skip!(node.union_token);
+    visit_fields_named_mut(v, &mut node.fields, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "derive")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_derive_input_mut<V>(
+    v: &mut V,
+    node: &mut DeriveInput,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_derive_input_mut(node, ancestors));
+    ancestors.push(NodeKind::DeriveInput);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    visit_data_mut(v, &mut node.data, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_mut<V>(
+    v: &mut V,
+    node: &mut Expr,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_mut(node, ancestors));
+    ancestors.push(NodeKind::Expr);
+    match node {
+        Expr::Array(_binding_0) => {
+            full!(visit_expr_array_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Assign(_binding_0) => {
+            full!(visit_expr_assign_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::AssignOp(_binding_0) => {
+            full!(visit_expr_assign_op_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Async(_binding_0) => {
+            full!(visit_expr_async_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Await(_binding_0) => {
+            full!(visit_expr_await_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Binary(_binding_0) => {
+            visit_expr_binary_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Block(_binding_0) => {
+            full!(visit_expr_block_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Box(_binding_0) => {
+            full!(visit_expr_box_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Break(_binding_0) => {
+            full!(visit_expr_break_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Call(_binding_0) => {
+            visit_expr_call_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Cast(_binding_0) => {
+            visit_expr_cast_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Closure(_binding_0) => {
+            full!(visit_expr_closure_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Continue(_binding_0) => {
+            full!(visit_expr_continue_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Field(_binding_0) => {
+            visit_expr_field_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::ForLoop(_binding_0) => {
+            full!(visit_expr_for_loop_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Group(_binding_0) => {
+            full!(visit_expr_group_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::If(_binding_0) => {
+            full!(visit_expr_if_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Index(_binding_0) => {
+            visit_expr_index_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Let(_binding_0) => {
+            full!(visit_expr_let_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Lit(_binding_0) => {
+            visit_expr_lit_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Loop(_binding_0) => {
+            full!(visit_expr_loop_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Macro(_binding_0) => {
+            full!(visit_expr_macro_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Match(_binding_0) => {
+            full!(visit_expr_match_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::MethodCall(_binding_0) => {
+            full!(visit_expr_method_call_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Paren(_binding_0) => {
+            visit_expr_paren_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Path(_binding_0) => {
+            visit_expr_path_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Range(_binding_0) => {
+            full!(visit_expr_range_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Reference(_binding_0) => {
+            full!(visit_expr_reference_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Repeat(_binding_0) => {
+            full!(visit_expr_repeat_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Return(_binding_0) => {
+            full!(visit_expr_return_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Struct(_binding_0) => {
+            full!(visit_expr_struct_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Try(_binding_0) => {
+            full!(visit_expr_try_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::TryBlock(_binding_0) => {
+            full!(visit_expr_try_block_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Tuple(_binding_0) => {
+            full!(visit_expr_tuple_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Type(_binding_0) => {
+            full!(visit_expr_type_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Unary(_binding_0) => {
+            visit_expr_unary_mut(v, _binding_0, ancestors)?;
+        }
+        Expr::Unsafe(_binding_0) => {
+            full!(visit_expr_unsafe_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Expr::While(_binding_0) => {
+            full!(visit_expr_while_mut(v, _binding_0, ancestors)?);
+        }
+        Expr::Yield(_binding_0) => {
+            full!(visit_expr_yield_mut(v, _binding_0, ancestors)?);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_array_mut<V>(
+    v: &mut V,
+    node: &mut ExprArray,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_array_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprArray);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    for it in &mut node.elems {
+        visit_expr_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_assign_mut<V>(
+    v: &mut V,
+    node: &mut ExprAssign,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_assign_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprAssign);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.left, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr_mut(v, &mut *node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_assign_op_mut<V>(
+    v: &mut V,
+    node: &mut ExprAssignOp,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_assign_op_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprAssignOp);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.left, ancestors)?;
+    visit_bin_op_mut(v, &mut node.op, ancestors)?;
+    visit_expr_mut(v, &mut *node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_async_mut<V>(
+    v: &mut V,
+    node: &mut ExprAsync,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_async_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprAsync);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.async_token);
+    skip!(node.capture);
+    visit_block_mut(v, &mut node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_await_mut<V>(
+    v: &mut V,
+    node: &mut ExprAwait,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_await_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprAwait);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.base, ancestors)?;
+    skip!(node.dot_token);
+    skip!(node.await_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_binary_mut<V>(
+    v: &mut V,
+    node: &mut ExprBinary,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_binary_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprBinary);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.left, ancestors)?;
+    visit_bin_op_mut(v, &mut node.op, ancestors)?;
+    visit_expr_mut(v, &mut *node.right, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_block_mut<V>(
+    v: &mut V,
+    node: &mut ExprBlock,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_block_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprBlock);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.label {
+        visit_label_mut(v, it, ancestors)?
+    };
+    visit_block_mut(v, &mut node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_box_mut<V>(
+    v: &mut V,
+    node: &mut ExprBox,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_box_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprBox);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.box_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_break_mut<V>(
+    v: &mut V,
+    node: &mut ExprBreak,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_break_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprBreak);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.break_token);
+    if let Some(it) = &mut node.label {
+        visit_lifetime_mut(v, it, ancestors)?
+    };
+    if let Some(it) = &mut node.expr {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_call_mut<V>(
+    v: &mut V,
+    node: &mut ExprCall,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_call_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprCall);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.func, ancestors)?;
+    skip!(node.paren_token);
+    for it in &mut node.args {
+        visit_expr_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_cast_mut<V>(
+    v: &mut V,
+    node: &mut ExprCast,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_cast_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprCast);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.as_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_closure_mut<V>(
+    v: &mut V,
+    node: &mut ExprClosure,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_closure_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprClosure);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.asyncness);
+    skip!(node.movability);
+    skip!(node.capture);
+    skip!(node.or1_token);
+    for it in &mut node.inputs {
+        visit_pat_mut(v, it, ancestors)?
+    }
+    skip!(node.or2_token);
+    visit_return_type_mut(v, &mut node.output, ancestors)?;
+    visit_expr_mut(v, &mut *node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_continue_mut<V>(
+    v: &mut V,
+    node: &mut ExprContinue,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_continue_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprContinue);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.continue_token);
+    if let Some(it) = &mut node.label {
+        visit_lifetime_mut(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_field_mut<V>(
+    v: &mut V,
+    node: &mut ExprField,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_field_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprField);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.base, ancestors)?;
+    skip!(node.dot_token);
+    visit_member_mut(v, &mut node.member, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_for_loop_mut<V>(
+    v: &mut V,
+    node: &mut ExprForLoop,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_for_loop_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprForLoop);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.label {
+        visit_label_mut(v, it, ancestors)?
+    };
+    skip!(node.for_token);
+    visit_pat_mut(v, &mut node.pat, ancestors)?;
+    skip!(node.in_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    visit_block_mut(v, &mut node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_group_mut<V>(
+    v: &mut V,
+    node: &mut ExprGroup,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_group_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprGroup);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.group_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_if_mut<V>(
+    v: &mut V,
+    node: &mut ExprIf,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_if_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprIf);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.if_token);
+    visit_expr_mut(v, &mut *node.cond, ancestors)?;
+    visit_block_mut(v, &mut node.then_branch, ancestors)?;
+    if let Some(it) = &mut node.else_branch {
+        skip!((it).0);
+        visit_expr_mut(v, &mut *(it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_index_mut<V>(
+    v: &mut V,
+    node: &mut ExprIndex,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_index_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprIndex);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.bracket_token);
+    visit_expr_mut(v, &mut *node.index, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_let_mut<V>(
+    v: &mut V,
+    node: &mut ExprLet,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_let_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprLet);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.let_token);
+    visit_pat_mut(v, &mut node.pat, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_lit_mut<V>(
+    v: &mut V,
+    node: &mut ExprLit,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_lit_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprLit);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_lit_mut(v, &mut node.lit, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_loop_mut<V>(
+    v: &mut V,
+    node: &mut ExprLoop,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_loop_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprLoop);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.label {
+        visit_label_mut(v, it, ancestors)?
+    };
+    skip!(node.loop_token);
+    visit_block_mut(v, &mut node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_macro_mut<V>(
+    v: &mut V,
+    node: &mut ExprMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_match_mut<V>(
+    v: &mut V,
+    node: &mut ExprMatch,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_match_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprMatch);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.match_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.arms {
+        visit_arm_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_method_call_mut<V>(
+    v: &mut V,
+    node: &mut ExprMethodCall,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_method_call_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprMethodCall);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.receiver, ancestors)?;
+    skip!(node.dot_token);
+    visit_ident_mut(v, &mut node.method, ancestors)?;
+    if let Some(it) = &mut node.turbofish {
+        visit_method_turbofish_mut(v, it, ancestors)?
+    };
+    skip!(node.paren_token);
+    for it in &mut node.args {
+        visit_expr_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_paren_mut<V>(
+    v: &mut V,
+    node: &mut ExprParen,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_paren_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprParen);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_path_mut<V>(
+    v: &mut V,
+    node: &mut ExprPath,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_path_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprPath);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.qself {
+        visit_qself_mut(v, it, ancestors)?
+    };
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_range_mut<V>(
+    v: &mut V,
+    node: &mut ExprRange,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_range_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprRange);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.from {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    visit_range_limits_mut(v, &mut node.limits, ancestors)?;
+    if let Some(it) = &mut node.to {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_reference_mut<V>(
+    v: &mut V,
+    node: &mut ExprReference,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_reference_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprReference);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.and_token);
+    skip!(node.mutability);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_repeat_mut<V>(
+    v: &mut V,
+    node: &mut ExprRepeat,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_repeat_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprRepeat);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.semi_token);
+    visit_expr_mut(v, &mut *node.len, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_return_mut<V>(
+    v: &mut V,
+    node: &mut ExprReturn,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_return_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprReturn);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.return_token);
+    if let Some(it) = &mut node.expr {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_struct_mut<V>(
+    v: &mut V,
+    node: &mut ExprStruct,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_struct_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprStruct);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.fields {
+        visit_field_value_mut(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    if let Some(it) = &mut node.rest {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_try_mut<V>(
+    v: &mut V,
+    node: &mut ExprTry,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_try_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprTry);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.question_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_try_block_mut<V>(
+    v: &mut V,
+    node: &mut ExprTryBlock,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_try_block_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprTryBlock);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.try_token);
+    visit_block_mut(v, &mut node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_tuple_mut<V>(
+    v: &mut V,
+    node: &mut ExprTuple,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_tuple_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprTuple);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    for it in &mut node.elems {
+        visit_expr_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_type_mut<V>(
+    v: &mut V,
+    node: &mut ExprType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_type_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_unary_mut<V>(
+    v: &mut V,
+    node: &mut ExprUnary,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_unary_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprUnary);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_un_op_mut(v, &mut node.op, ancestors)?;
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_unsafe_mut<V>(
+    v: &mut V,
+    node: &mut ExprUnsafe,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_unsafe_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprUnsafe);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.unsafe_token);
+    visit_block_mut(v, &mut node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_while_mut<V>(
+    v: &mut V,
+    node: &mut ExprWhile,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_while_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprWhile);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.label {
+        visit_label_mut(v, it, ancestors)?
+    };
+    skip!(node.while_token);
+    visit_expr_mut(v, &mut *node.cond, ancestors)?;
+    visit_block_mut(v, &mut node.body, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_expr_yield_mut<V>(
+    v: &mut V,
+    node: &mut ExprYield,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_expr_yield_mut(node, ancestors));
+    ancestors.push(NodeKind::ExprYield);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.yield_token);
+    if let Some(it) = &mut node.expr {
+        visit_expr_mut(v, &mut **it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_field_mut<V>(
+    v: &mut V,
+    node: &mut Field,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_field_mut(node, ancestors));
+    ancestors.push(NodeKind::Field);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    if let Some(it) = &mut node.ident {
+        visit_ident_mut(v, it, ancestors)?
+    };
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_field_pat_mut<V>(
+    v: &mut V,
+    node: &mut FieldPat,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_field_pat_mut(node, ancestors));
+    ancestors.push(NodeKind::FieldPat);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_member_mut(v, &mut node.member, ancestors)?;
+    skip!(node.colon_token);
+    visit_pat_mut(v, &mut *node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_field_value_mut<V>(
+    v: &mut V,
+    node: &mut FieldValue,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_field_value_mut(node, ancestors));
+    ancestors.push(NodeKind::FieldValue);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_member_mut(v, &mut node.member, ancestors)?;
+    skip!(node.colon_token);
+    visit_expr_mut(v, &mut node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_fields_mut<V>(
+    v: &mut V,
+    node: &mut Fields,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_fields_mut(node, ancestors));
+    ancestors.push(NodeKind::Fields);
+    match node {
+        Fields::Named(_binding_0) => {
+            visit_fields_named_mut(v, _binding_0, ancestors)?;
+        }
+        Fields::Unnamed(_binding_0) => {
+            visit_fields_unnamed_mut(v, _binding_0, ancestors)?;
+        }
+        Fields::Unit => {}
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_fields_named_mut<V>(
+    v: &mut V,
+    node: &mut FieldsNamed,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_fields_named_mut(node, ancestors));
+    ancestors.push(NodeKind::FieldsNamed);
+    skip!(node.brace_token);
+    for it in &mut node.named {
+        visit_field_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_fields_unnamed_mut<V>(
+    v: &mut V,
+    node: &mut FieldsUnnamed,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_fields_unnamed_mut(node, ancestors));
+    ancestors.push(NodeKind::FieldsUnnamed);
+    skip!(node.paren_token);
+    for it in &mut node.unnamed {
+        visit_field_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_file_mut<V>(
+    v: &mut V,
+    node: &mut File,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_file_mut(node, ancestors));
+    ancestors.push(NodeKind::File);
+    skip!(node.shebang);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    for it in &mut node.items {
+        visit_item_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_fn_arg_mut<V>(
+    v: &mut V,
+    node: &mut FnArg,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_fn_arg_mut(node, ancestors));
+    ancestors.push(NodeKind::FnArg);
+    match node {
+        FnArg::Receiver(_binding_0) => {
+            visit_receiver_mut(v, _binding_0, ancestors)?;
+        }
+        FnArg::Typed(_binding_0) => {
+            visit_pat_type_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_foreign_item_mut<V>(
+    v: &mut V,
+    node: &mut ForeignItem,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_foreign_item_mut(node, ancestors));
+    ancestors.push(NodeKind::ForeignItem);
+    match node {
+        ForeignItem::Fn(_binding_0) => {
+            visit_foreign_item_fn_mut(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Static(_binding_0) => {
+            visit_foreign_item_static_mut(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Type(_binding_0) => {
+            visit_foreign_item_type_mut(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Macro(_binding_0) => {
+            visit_foreign_item_macro_mut(v, _binding_0, ancestors)?;
+        }
+        ForeignItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_foreign_item_fn_mut<V>(
+    v: &mut V,
+    node: &mut ForeignItemFn,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_foreign_item_fn_mut(node, ancestors));
+    ancestors.push(NodeKind::ForeignItemFn);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    visit_signature_mut(v, &mut node.sig, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_foreign_item_macro_mut<V>(
+    v: &mut V,
+    node: &mut ForeignItemMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_foreign_item_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::ForeignItemMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_foreign_item_static_mut<V>(
+    v: &mut V,
+    node: &mut ForeignItemStatic,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_foreign_item_static_mut(node, ancestors));
+    ancestors.push(NodeKind::ForeignItemStatic);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.static_token);
+    skip!(node.mutability);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_foreign_item_type_mut<V>(
+    v: &mut V,
+    node: &mut ForeignItemType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_foreign_item_type_mut(node, ancestors));
+    ancestors.push(NodeKind::ForeignItemType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.type_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_generic_argument_mut<V>(
+    v: &mut V,
+    node: &mut GenericArgument,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_generic_argument_mut(node, ancestors));
+    ancestors.push(NodeKind::GenericArgument);
+    match node {
+        GenericArgument::Lifetime(_binding_0) => {
+            visit_lifetime_mut(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Type(_binding_0) => {
+            visit_type_mut(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Binding(_binding_0) => {
+            visit_binding_mut(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Constraint(_binding_0) => {
+            visit_constraint_mut(v, _binding_0, ancestors)?;
+        }
+        GenericArgument::Const(_binding_0) => {
+            visit_expr_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_generic_method_argument_mut<V>(
+    v: &mut V,
+    node: &mut GenericMethodArgument,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_generic_method_argument_mut(node, ancestors));
+    ancestors.push(NodeKind::GenericMethodArgument);
+    match node {
+        GenericMethodArgument::Type(_binding_0) => {
+            visit_type_mut(v, _binding_0, ancestors)?;
+        }
+        GenericMethodArgument::Const(_binding_0) => {
+            visit_expr_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_generic_param_mut<V>(
+    v: &mut V,
+    node: &mut GenericParam,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_generic_param_mut(node, ancestors));
+    ancestors.push(NodeKind::GenericParam);
+    match node {
+        GenericParam::Type(_binding_0) => {
+            visit_type_param_mut(v, _binding_0, ancestors)?;
+        }
+        GenericParam::Lifetime(_binding_0) => {
+            visit_lifetime_def_mut(v, _binding_0, ancestors)?;
+        }
+        GenericParam::Const(_binding_0) => {
+            visit_const_param_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_generics_mut<V>(
+    v: &mut V,
+    node: &mut Generics,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_generics_mut(node, ancestors));
+    ancestors.push(NodeKind::Generics);
+    skip!(node.lt_token);
+    for it in &mut node.params {
+        visit_generic_param_mut(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    if let Some(it) = &mut node.where_clause {
+        visit_where_clause_mut(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "visit-mut")]
+pub fn visit_ident_mut<V>(
+    v: &mut V,
+    node: &mut Ident,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_ident_mut(node, ancestors));
+    ancestors.push(NodeKind::Ident);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_impl_item_mut<V>(
+    v: &mut V,
+    node: &mut ImplItem,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_impl_item_mut(node, ancestors));
+    ancestors.push(NodeKind::ImplItem);
+    match node {
+        ImplItem::Const(_binding_0) => {
+            visit_impl_item_const_mut(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Method(_binding_0) => {
+            visit_impl_item_method_mut(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Type(_binding_0) => {
+            visit_impl_item_type_mut(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Macro(_binding_0) => {
+            visit_impl_item_macro_mut(v, _binding_0, ancestors)?;
+        }
+        ImplItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_impl_item_const_mut<V>(
+    v: &mut V,
+    node: &mut ImplItemConst,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_impl_item_const_mut(node, ancestors));
+    ancestors.push(NodeKind::ImplItemConst);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.defaultness);
+    skip!(node.const_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr_mut(v, &mut node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_impl_item_macro_mut<V>(
+    v: &mut V,
+    node: &mut ImplItemMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_impl_item_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::ImplItemMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_impl_item_method_mut<V>(
+    v: &mut V,
+    node: &mut ImplItemMethod,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_impl_item_method_mut(node, ancestors));
+    ancestors.push(NodeKind::ImplItemMethod);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.defaultness);
+    visit_signature_mut(v, &mut node.sig, ancestors)?;
+    visit_block_mut(v, &mut node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_impl_item_type_mut<V>(
+    v: &mut V,
+    node: &mut ImplItemType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_impl_item_type_mut(node, ancestors));
+    ancestors.push(NodeKind::ImplItemType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.defaultness);
+    skip!(node.type_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.eq_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_index_mut<V>(
+    v: &mut V,
+    node: &mut Index,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_index_mut(node, ancestors));
+    ancestors.push(NodeKind::Index);
+    skip!(node.index);
+    skip!(node.span);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_mut<V>(
+    v: &mut V,
+    node: &mut Item,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_mut(node, ancestors));
+    ancestors.push(NodeKind::Item);
+    match node {
+        Item::Const(_binding_0) => {
+            visit_item_const_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Enum(_binding_0) => {
+            visit_item_enum_mut(v, _binding_0, ancestors)?;
+        }
+        Item::ExternCrate(_binding_0) => {
+            visit_item_extern_crate_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Fn(_binding_0) => {
+            visit_item_fn_mut(v, _binding_0, ancestors)?;
+        }
+        Item::ForeignMod(_binding_0) => {
+            visit_item_foreign_mod_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Impl(_binding_0) => {
+            visit_item_impl_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Macro(_binding_0) => {
+            visit_item_macro_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Macro2(_binding_0) => {
+            visit_item_macro2_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Mod(_binding_0) => {
+            visit_item_mod_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Static(_binding_0) => {
+            visit_item_static_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Struct(_binding_0) => {
+            visit_item_struct_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Trait(_binding_0) => {
+            visit_item_trait_mut(v, _binding_0, ancestors)?;
+        }
+        Item::TraitAlias(_binding_0) => {
+            visit_item_trait_alias_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Type(_binding_0) => {
+            visit_item_type_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Union(_binding_0) => {
+            visit_item_union_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Use(_binding_0) => {
+            visit_item_use_mut(v, _binding_0, ancestors)?;
+        }
+        Item::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_const_mut<V>(
+    v: &mut V,
+    node: &mut ItemConst,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_const_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemConst);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.const_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_enum_mut<V>(
+    v: &mut V,
+    node: &mut ItemEnum,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_enum_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemEnum);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.enum_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.variants {
+        visit_variant_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_extern_crate_mut<V>(
+    v: &mut V,
+    node: &mut ItemExternCrate,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_extern_crate_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemExternCrate);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.extern_token);
+    skip!(node.crate_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    if let Some(it) = &mut node.rename {
+        skip!((it).0);
+        visit_ident_mut(v, &mut (it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_fn_mut<V>(
+    v: &mut V,
+    node: &mut ItemFn,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_fn_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemFn);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    visit_signature_mut(v, &mut node.sig, ancestors)?;
+    visit_block_mut(v, &mut *node.block, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_foreign_mod_mut<V>(
+    v: &mut V,
+    node: &mut ItemForeignMod,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_foreign_mod_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemForeignMod);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_abi_mut(v, &mut node.abi, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.items {
+        visit_foreign_item_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_impl_mut<V>(
+    v: &mut V,
+    node: &mut ItemImpl,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_impl_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemImpl);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.defaultness);
+    skip!(node.unsafety);
+    skip!(node.impl_token);
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    if let Some(it) = &mut node.trait_ {
+        skip!((it).0);
+        visit_path_mut(v, &mut (it).1, ancestors)?;
+        skip!((it).2);
+    };
+    visit_type_mut(v, &mut *node.self_ty, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.items {
+        visit_impl_item_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_macro_mut<V>(
+    v: &mut V,
+    node: &mut ItemMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.ident {
+        visit_ident_mut(v, it, ancestors)?
+    };
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_macro2_mut<V>(
+    v: &mut V,
+    node: &mut ItemMacro2,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_macro2_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemMacro2);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.macro_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.rules);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_mod_mut<V>(
+    v: &mut V,
+    node: &mut ItemMod,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_mod_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemMod);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.mod_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    if let Some(it) = &mut node.content {
+        skip!((it).0);
+        for it in &mut (it).1 {
+            visit_item_mut(v, it, ancestors)?
+        }
+    };
+    skip!(node.semi);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_static_mut<V>(
+    v: &mut V,
+    node: &mut ItemStatic,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_static_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemStatic);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.static_token);
+    skip!(node.mutability);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_struct_mut<V>(
+    v: &mut V,
+    node: &mut ItemStruct,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_struct_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemStruct);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.struct_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    visit_fields_mut(v, &mut node.fields, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_trait_mut<V>(
+    v: &mut V,
+    node: &mut ItemTrait,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_trait_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemTrait);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.unsafety);
+    skip!(node.auto_token);
+    skip!(node.trait_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.supertraits {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    skip!(node.brace_token);
+    for it in &mut node.items {
+        visit_trait_item_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_trait_alias_mut<V>(
+    v: &mut V,
+    node: &mut ItemTraitAlias,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_trait_alias_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemTraitAlias);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.trait_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.eq_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_type_mut<V>(
+    v: &mut V,
+    node: &mut ItemType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_type_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.type_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.eq_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_union_mut<V>(
+    v: &mut V,
+    node: &mut ItemUnion,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_union_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemUnion);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.union_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    visit_fields_named_mut(v, &mut node.fields, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_item_use_mut<V>(
+    v: &mut V,
+    node: &mut ItemUse,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_item_use_mut(node, ancestors));
+    ancestors.push(NodeKind::ItemUse);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_visibility_mut(v, &mut node.vis, ancestors)?;
+    skip!(node.use_token);
+    skip!(node.leading_colon);
+    visit_use_tree_mut(v, &mut node.tree, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_label_mut<V>(
+    v: &mut V,
+    node: &mut Label,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_label_mut(node, ancestors));
+    ancestors.push(NodeKind::Label);
+    visit_lifetime_mut(v, &mut node.name, ancestors)?;
+    skip!(node.colon_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "visit-mut")]
+pub fn visit_lifetime_mut<V>(
+    v: &mut V,
+    node: &mut Lifetime,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lifetime_mut(node, ancestors));
+    ancestors.push(NodeKind::Lifetime);
+    skip!(node.apostrophe);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lifetime_def_mut<V>(
+    v: &mut V,
+    node: &mut LifetimeDef,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lifetime_def_mut(node, ancestors));
+    ancestors.push(NodeKind::LifetimeDef);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_lifetime_mut(v, &mut node.lifetime, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_lifetime_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_mut<V>(
+    v: &mut V,
+    node: &mut Lit,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_mut(node, ancestors));
+    ancestors.push(NodeKind::Lit);
+    match node {
+        Lit::Str(_binding_0) => {
+            visit_lit_str_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::ByteStr(_binding_0) => {
+            visit_lit_byte_str_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Byte(_binding_0) => {
+            visit_lit_byte_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Char(_binding_0) => {
+            visit_lit_char_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Int(_binding_0) => {
+            visit_lit_int_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Float(_binding_0) => {
+            visit_lit_float_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Bool(_binding_0) => {
+            visit_lit_bool_mut(v, _binding_0, ancestors)?;
+        }
+        Lit::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_bool_mut<V>(
+    v: &mut V,
+    node: &mut LitBool,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_bool_mut(node, ancestors));
+    ancestors.push(NodeKind::LitBool);
+    skip!(node.value);
+    skip!(node.span);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_byte_mut<V>(
+    v: &mut V,
+    node: &mut LitByte,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_byte_mut(node, ancestors));
+    ancestors.push(NodeKind::LitByte);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_byte_str_mut<V>(
+    v: &mut V,
+    node: &mut LitByteStr,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_byte_str_mut(node, ancestors));
+    ancestors.push(NodeKind::LitByteStr);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_char_mut<V>(
+    v: &mut V,
+    node: &mut LitChar,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_char_mut(node, ancestors));
+    ancestors.push(NodeKind::LitChar);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_float_mut<V>(
+    v: &mut V,
+    node: &mut LitFloat,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_float_mut(node, ancestors));
+    ancestors.push(NodeKind::LitFloat);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_int_mut<V>(
+    v: &mut V,
+    node: &mut LitInt,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_int_mut(node, ancestors));
+    ancestors.push(NodeKind::LitInt);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_lit_str_mut<V>(
+    v: &mut V,
+    node: &mut LitStr,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_lit_str_mut(node, ancestors));
+    ancestors.push(NodeKind::LitStr);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_local_mut<V>(
+    v: &mut V,
+    node: &mut Local,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_local_mut(node, ancestors));
+    ancestors.push(NodeKind::Local);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.let_token);
+    visit_pat_mut(v, &mut node.pat, ancestors)?;
+    if let Some(it) = &mut node.init {
+        skip!((it).0);
+        visit_expr_mut(v, &mut *(it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_macro_mut<V>(
+    v: &mut V,
+    node: &mut Macro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::Macro);
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.bang_token);
+    visit_macro_delimiter_mut(v, &mut node.delimiter, ancestors)?;
+    skip!(node.tokens);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_macro_delimiter_mut<V>(
+    v: &mut V,
+    node: &mut MacroDelimiter,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_macro_delimiter_mut(node, ancestors));
+    ancestors.push(NodeKind::MacroDelimiter);
+    match node {
+        MacroDelimiter::Paren(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroDelimiter::Brace(_binding_0) => {
+            skip!(_binding_0);
+        }
+        MacroDelimiter::Bracket(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_member_mut<V>(
+    v: &mut V,
+    node: &mut Member,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_member_mut(node, ancestors));
+    ancestors.push(NodeKind::Member);
+    match node {
+        Member::Named(_binding_0) => {
+            visit_ident_mut(v, _binding_0, ancestors)?;
+        }
+        Member::Unnamed(_binding_0) => {
+            visit_index_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_meta_mut<V>(
+    v: &mut V,
+    node: &mut Meta,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_meta_mut(node, ancestors));
+    ancestors.push(NodeKind::Meta);
+    match node {
+        Meta::Path(_binding_0) => {
+            visit_path_mut(v, _binding_0, ancestors)?;
+        }
+        Meta::List(_binding_0) => {
+            visit_meta_list_mut(v, _binding_0, ancestors)?;
+        }
+        Meta::NameValue(_binding_0) => {
+            visit_meta_name_value_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_meta_list_mut<V>(
+    v: &mut V,
+    node: &mut MetaList,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_meta_list_mut(node, ancestors));
+    ancestors.push(NodeKind::MetaList);
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.paren_token);
+    for it in &mut node.nested {
+        visit_nested_meta_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_meta_name_value_mut<V>(
+    v: &mut V,
+    node: &mut MetaNameValue,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_meta_name_value_mut(node, ancestors));
+    ancestors.push(NodeKind::MetaNameValue);
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.eq_token);
+    visit_lit_mut(v, &mut node.lit, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_method_turbofish_mut<V>(
+    v: &mut V,
+    node: &mut MethodTurbofish,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_method_turbofish_mut(node, ancestors));
+    ancestors.push(NodeKind::MethodTurbofish);
+    skip!(node.colon2_token);
+    skip!(node.lt_token);
+    for it in &mut node.args {
+        visit_generic_method_argument_mut(v, it, ancestors)?
+    }
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_nested_meta_mut<V>(
+    v: &mut V,
+    node: &mut NestedMeta,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_nested_meta_mut(node, ancestors));
+    ancestors.push(NodeKind::NestedMeta);
+    match node {
+        NestedMeta::Meta(_binding_0) => {
+            visit_meta_mut(v, _binding_0, ancestors)?;
+        }
+        NestedMeta::Lit(_binding_0) => {
+            visit_lit_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_parenthesized_generic_arguments_mut<V>(
+    v: &mut V,
+    node: &mut ParenthesizedGenericArguments,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_parenthesized_generic_arguments_mut(node, ancestors));
+    ancestors.push(NodeKind::ParenthesizedGenericArguments);
+    skip!(node.paren_token);
+    for it in &mut node.inputs {
+        visit_type_mut(v, it, ancestors)?
+    }
+    visit_return_type_mut(v, &mut node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_mut<V>(
+    v: &mut V,
+    node: &mut Pat,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_mut(node, ancestors));
+    ancestors.push(NodeKind::Pat);
+    match node {
+        Pat::Box(_binding_0) => {
+            visit_pat_box_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Ident(_binding_0) => {
+            visit_pat_ident_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Lit(_binding_0) => {
+            visit_pat_lit_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Macro(_binding_0) => {
+            visit_pat_macro_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Or(_binding_0) => {
+            visit_pat_or_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Path(_binding_0) => {
+            visit_pat_path_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Range(_binding_0) => {
+            visit_pat_range_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Reference(_binding_0) => {
+            visit_pat_reference_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Rest(_binding_0) => {
+            visit_pat_rest_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Slice(_binding_0) => {
+            visit_pat_slice_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Struct(_binding_0) => {
+            visit_pat_struct_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Tuple(_binding_0) => {
+            visit_pat_tuple_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::TupleStruct(_binding_0) => {
+            visit_pat_tuple_struct_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Type(_binding_0) => {
+            visit_pat_type_mut(v, _binding_0, ancestors)?;
+        }
+        Pat::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        Pat::Wild(_binding_0) => {
+            visit_pat_wild_mut(v, _binding_0, ancestors)?;
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_box_mut<V>(
+    v: &mut V,
+    node: &mut PatBox,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_box_mut(node, ancestors));
+    ancestors.push(NodeKind::PatBox);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.box_token);
+    visit_pat_mut(v, &mut *node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_ident_mut<V>(
+    v: &mut V,
+    node: &mut PatIdent,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_ident_mut(node, ancestors));
+    ancestors.push(NodeKind::PatIdent);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.by_ref);
+    skip!(node.mutability);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    if let Some(it) = &mut node.subpat {
+        skip!((it).0);
+        visit_pat_mut(v, &mut *(it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_lit_mut<V>(
+    v: &mut V,
+    node: &mut PatLit,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_lit_mut(node, ancestors));
+    ancestors.push(NodeKind::PatLit);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.expr, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_macro_mut<V>(
+    v: &mut V,
+    node: &mut PatMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::PatMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_or_mut<V>(
+    v: &mut V,
+    node: &mut PatOr,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_or_mut(node, ancestors));
+    ancestors.push(NodeKind::PatOr);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.leading_vert);
+    for it in &mut node.cases {
+        visit_pat_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_path_mut<V>(
+    v: &mut V,
+    node: &mut PatPath,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_path_mut(node, ancestors));
+    ancestors.push(NodeKind::PatPath);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.qself {
+        visit_qself_mut(v, it, ancestors)?
+    };
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_range_mut<V>(
+    v: &mut V,
+    node: &mut PatRange,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_range_mut(node, ancestors));
+    ancestors.push(NodeKind::PatRange);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_expr_mut(v, &mut *node.lo, ancestors)?;
+    visit_range_limits_mut(v, &mut node.limits, ancestors)?;
+    visit_expr_mut(v, &mut *node.hi, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_reference_mut<V>(
+    v: &mut V,
+    node: &mut PatReference,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_reference_mut(node, ancestors));
+    ancestors.push(NodeKind::PatReference);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.and_token);
+    skip!(node.mutability);
+    visit_pat_mut(v, &mut *node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_rest_mut<V>(
+    v: &mut V,
+    node: &mut PatRest,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_rest_mut(node, ancestors));
+    ancestors.push(NodeKind::PatRest);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_slice_mut<V>(
+    v: &mut V,
+    node: &mut PatSlice,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_slice_mut(node, ancestors));
+    ancestors.push(NodeKind::PatSlice);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.bracket_token);
+    for it in &mut node.elems {
+        visit_pat_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_struct_mut<V>(
+    v: &mut V,
+    node: &mut PatStruct,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_struct_mut(node, ancestors));
+    ancestors.push(NodeKind::PatStruct);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    skip!(node.brace_token);
+    for it in &mut node.fields {
+        visit_field_pat_mut(v, it, ancestors)?
+    }
+    skip!(node.dot2_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_tuple_mut<V>(
+    v: &mut V,
+    node: &mut PatTuple,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_tuple_mut(node, ancestors));
+    ancestors.push(NodeKind::PatTuple);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.paren_token);
+    for it in &mut node.elems {
+        visit_pat_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_tuple_struct_mut<V>(
+    v: &mut V,
+    node: &mut PatTupleStruct,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_tuple_struct_mut(node, ancestors));
+    ancestors.push(NodeKind::PatTupleStruct);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    visit_pat_tuple_mut(v, &mut node.pat, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_type_mut<V>(
+    v: &mut V,
+    node: &mut PatType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_type_mut(node, ancestors));
+    ancestors.push(NodeKind::PatType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_pat_mut(v, &mut *node.pat, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_pat_wild_mut<V>(
+    v: &mut V,
+    node: &mut PatWild,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_pat_wild_mut(node, ancestors));
+    ancestors.push(NodeKind::PatWild);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.underscore_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_path_mut<V>(
+    v: &mut V,
+    node: &mut Path,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_path_mut(node, ancestors));
+    ancestors.push(NodeKind::Path);
+    skip!(node.leading_colon);
+    for it in &mut node.segments {
+        visit_path_segment_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_path_arguments_mut<V>(
+    v: &mut V,
+    node: &mut PathArguments,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_path_arguments_mut(node, ancestors));
+    ancestors.push(NodeKind::PathArguments);
+    match node {
+        PathArguments::None => {}
+        PathArguments::AngleBracketed(_binding_0) => {
+            visit_angle_bracketed_generic_arguments_mut(v, _binding_0, ancestors)?;
+        }
+        PathArguments::Parenthesized(_binding_0) => {
+            visit_parenthesized_generic_arguments_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_path_segment_mut<V>(
+    v: &mut V,
+    node: &mut PathSegment,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_path_segment_mut(node, ancestors));
+    ancestors.push(NodeKind::PathSegment);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_path_arguments_mut(v, &mut node.arguments, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_predicate_eq_mut<V>(
+    v: &mut V,
+    node: &mut PredicateEq,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_predicate_eq_mut(node, ancestors));
+    ancestors.push(NodeKind::PredicateEq);
+    visit_type_mut(v, &mut node.lhs_ty, ancestors)?;
+    skip!(node.eq_token);
+    visit_type_mut(v, &mut node.rhs_ty, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_predicate_lifetime_mut<V>(
+    v: &mut V,
+    node: &mut PredicateLifetime,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_predicate_lifetime_mut(node, ancestors));
+    ancestors.push(NodeKind::PredicateLifetime);
+    visit_lifetime_mut(v, &mut node.lifetime, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_lifetime_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_predicate_type_mut<V>(
+    v: &mut V,
+    node: &mut PredicateType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_predicate_type_mut(node, ancestors));
+    ancestors.push(NodeKind::PredicateType);
+    if let Some(it) = &mut node.lifetimes {
+        visit_bound_lifetimes_mut(v, it, ancestors)?
+    };
+    visit_type_mut(v, &mut node.bounded_ty, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_qself_mut<V>(
+    v: &mut V,
+    node: &mut QSelf,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_qself_mut(node, ancestors));
+    ancestors.push(NodeKind::QSelf);
+    skip!(node.lt_token);
+    visit_type_mut(v, &mut *node.ty, ancestors)?;
+    skip!(node.position);
+    skip!(node.as_token);
+    skip!(node.gt_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_range_limits_mut<V>(
+    v: &mut V,
+    node: &mut RangeLimits,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_range_limits_mut(node, ancestors));
+    ancestors.push(NodeKind::RangeLimits);
+    match node {
+        RangeLimits::HalfOpen(_binding_0) => {
+            skip!(_binding_0);
+        }
+        RangeLimits::Closed(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_receiver_mut<V>(
+    v: &mut V,
+    node: &mut Receiver,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_receiver_mut(node, ancestors));
+    ancestors.push(NodeKind::Receiver);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.reference {
+        skip!((it).0);
+        if let Some(it) = &mut (it).1 {
+            visit_lifetime_mut(v, it, ancestors)?
+        };
+    };
+    skip!(node.mutability);
+    skip!(node.self_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_return_type_mut<V>(
+    v: &mut V,
+    node: &mut ReturnType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_return_type_mut(node, ancestors));
+    ancestors.push(NodeKind::ReturnType);
+    match node {
+        ReturnType::Default => {}
+        ReturnType::Type(_binding_0, _binding_1) => {
+            skip!(_binding_0);
+            visit_type_mut(v, &mut **_binding_1, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_signature_mut<V>(
+    v: &mut V,
+    node: &mut Signature,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_signature_mut(node, ancestors));
+    ancestors.push(NodeKind::Signature);
+    skip!(node.constness);
+    skip!(node.asyncness);
+    skip!(node.unsafety);
+    if let Some(it) = &mut node.abi {
+        visit_abi_mut(v, it, ancestors)?
+    };
+    skip!(node.fn_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.paren_token);
+    for it in &mut node.inputs {
+        visit_fn_arg_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.variadic {
+        visit_variadic_mut(v, it, ancestors)?
+    };
+    visit_return_type_mut(v, &mut node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_stmt_mut<V>(
+    v: &mut V,
+    node: &mut Stmt,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_stmt_mut(node, ancestors));
+    ancestors.push(NodeKind::Stmt);
+    match node {
+        Stmt::Local(_binding_0) => {
+            visit_local_mut(v, _binding_0, ancestors)?;
+        }
+        Stmt::Item(_binding_0) => {
+            visit_item_mut(v, _binding_0, ancestors)?;
+        }
+        Stmt::Expr(_binding_0) => {
+            visit_expr_mut(v, _binding_0, ancestors)?;
+        }
+        Stmt::Semi(_binding_0, _binding_1) => {
+            visit_expr_mut(v, _binding_0, ancestors)?;
+            skip!(_binding_1);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_bound_mut<V>(
+    v: &mut V,
+    node: &mut TraitBound,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_bound_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitBound);
+    skip!(node.paren_token);
+    visit_trait_bound_modifier_mut(v, &mut node.modifier, ancestors)?;
+    if let Some(it) = &mut node.lifetimes {
+        visit_bound_lifetimes_mut(v, it, ancestors)?
+    };
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_bound_modifier_mut<V>(
+    v: &mut V,
+    node: &mut TraitBoundModifier,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_bound_modifier_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitBoundModifier);
+    match node {
+        TraitBoundModifier::None => {}
+        TraitBoundModifier::Maybe(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_item_mut<V>(
+    v: &mut V,
+    node: &mut TraitItem,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_item_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitItem);
+    match node {
+        TraitItem::Const(_binding_0) => {
+            visit_trait_item_const_mut(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Method(_binding_0) => {
+            visit_trait_item_method_mut(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Type(_binding_0) => {
+            visit_trait_item_type_mut(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Macro(_binding_0) => {
+            visit_trait_item_macro_mut(v, _binding_0, ancestors)?;
+        }
+        TraitItem::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_item_const_mut<V>(
+    v: &mut V,
+    node: &mut TraitItemConst,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_item_const_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitItemConst);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.const_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    visit_type_mut(v, &mut node.ty, ancestors)?;
+    if let Some(it) = &mut node.default {
+        skip!((it).0);
+        visit_expr_mut(v, &mut (it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_item_macro_mut<V>(
+    v: &mut V,
+    node: &mut TraitItemMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_item_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitItemMacro);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_item_method_mut<V>(
+    v: &mut V,
+    node: &mut TraitItemMethod,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_item_method_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitItemMethod);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_signature_mut(v, &mut node.sig, ancestors)?;
+    if let Some(it) = &mut node.default {
+        visit_block_mut(v, it, ancestors)?
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_trait_item_type_mut<V>(
+    v: &mut V,
+    node: &mut TraitItemType,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_trait_item_type_mut(node, ancestors));
+    ancestors.push(NodeKind::TraitItemType);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.type_token);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_generics_mut(v, &mut node.generics, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.default {
+        skip!((it).0);
+        visit_type_mut(v, &mut (it).1, ancestors)?;
+    };
+    skip!(node.semi_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_mut<V>(
+    v: &mut V,
+    node: &mut Type,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_mut(node, ancestors));
+    ancestors.push(NodeKind::Type);
+    match node {
+        Type::Array(_binding_0) => {
+            visit_type_array_mut(v, _binding_0, ancestors)?;
+        }
+        Type::BareFn(_binding_0) => {
+            visit_type_bare_fn_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Group(_binding_0) => {
+            visit_type_group_mut(v, _binding_0, ancestors)?;
+        }
+        Type::ImplTrait(_binding_0) => {
+            visit_type_impl_trait_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Infer(_binding_0) => {
+            visit_type_infer_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Macro(_binding_0) => {
+            visit_type_macro_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Never(_binding_0) => {
+            visit_type_never_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Paren(_binding_0) => {
+            visit_type_paren_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Path(_binding_0) => {
+            visit_type_path_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Ptr(_binding_0) => {
+            visit_type_ptr_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Reference(_binding_0) => {
+            visit_type_reference_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Slice(_binding_0) => {
+            visit_type_slice_mut(v, _binding_0, ancestors)?;
+        }
+        Type::TraitObject(_binding_0) => {
+            visit_type_trait_object_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Tuple(_binding_0) => {
+            visit_type_tuple_mut(v, _binding_0, ancestors)?;
+        }
+        Type::Verbatim(_binding_0) => {
+            skip!(_binding_0);
+        }
+        _ => unreachable!(),
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_array_mut<V>(
+    v: &mut V,
+    node: &mut TypeArray,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_array_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeArray);
+    skip!(node.bracket_token);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    skip!(node.semi_token);
+    visit_expr_mut(v, &mut node.len, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_bare_fn_mut<V>(
+    v: &mut V,
+    node: &mut TypeBareFn,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_bare_fn_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeBareFn);
+    if let Some(it) = &mut node.lifetimes {
+        visit_bound_lifetimes_mut(v, it, ancestors)?
+    };
+    skip!(node.unsafety);
+    if let Some(it) = &mut node.abi {
+        visit_abi_mut(v, it, ancestors)?
+    };
+    skip!(node.fn_token);
+    skip!(node.paren_token);
+    for it in &mut node.inputs {
+        visit_bare_fn_arg_mut(v, it, ancestors)?
+    }
+    if let Some(it) = &mut node.variadic {
+        visit_variadic_mut(v, it, ancestors)?
+    };
+    visit_return_type_mut(v, &mut node.output, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_group_mut<V>(
+    v: &mut V,
+    node: &mut TypeGroup,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_group_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeGroup);
+    skip!(node.group_token);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_impl_trait_mut<V>(
+    v: &mut V,
+    node: &mut TypeImplTrait,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_impl_trait_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeImplTrait);
+    skip!(node.impl_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_infer_mut<V>(
+    v: &mut V,
+    node: &mut TypeInfer,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_infer_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeInfer);
+    skip!(node.underscore_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_macro_mut<V>(
+    v: &mut V,
+    node: &mut TypeMacro,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_macro_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeMacro);
+    visit_macro_mut(v, &mut node.mac, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_never_mut<V>(
+    v: &mut V,
+    node: &mut TypeNever,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_never_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeNever);
+    skip!(node.bang_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_param_mut<V>(
+    v: &mut V,
+    node: &mut TypeParam,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_param_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeParam);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    skip!(node.eq_token);
+    if let Some(it) = &mut node.default {
+        visit_type_mut(v, it, ancestors)?
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_param_bound_mut<V>(
+    v: &mut V,
+    node: &mut TypeParamBound,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_param_bound_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeParamBound);
+    match node {
+        TypeParamBound::Trait(_binding_0) => {
+            visit_trait_bound_mut(v, _binding_0, ancestors)?;
+        }
+        TypeParamBound::Lifetime(_binding_0) => {
+            visit_lifetime_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_paren_mut<V>(
+    v: &mut V,
+    node: &mut TypeParen,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_paren_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeParen);
+    skip!(node.paren_token);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_path_mut<V>(
+    v: &mut V,
+    node: &mut TypePath,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_path_mut(node, ancestors));
+    ancestors.push(NodeKind::TypePath);
+    if let Some(it) = &mut node.qself {
+        visit_qself_mut(v, it, ancestors)?
+    };
+    visit_path_mut(v, &mut node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_ptr_mut<V>(
+    v: &mut V,
+    node: &mut TypePtr,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_ptr_mut(node, ancestors));
+    ancestors.push(NodeKind::TypePtr);
+    skip!(node.star_token);
+    skip!(node.const_token);
+    skip!(node.mutability);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_reference_mut<V>(
+    v: &mut V,
+    node: &mut TypeReference,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_reference_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeReference);
+    skip!(node.and_token);
+    if let Some(it) = &mut node.lifetime {
+        visit_lifetime_mut(v, it, ancestors)?
+    };
+    skip!(node.mutability);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_slice_mut<V>(
+    v: &mut V,
+    node: &mut TypeSlice,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_slice_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeSlice);
+    skip!(node.bracket_token);
+    visit_type_mut(v, &mut *node.elem, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_trait_object_mut<V>(
+    v: &mut V,
+    node: &mut TypeTraitObject,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_trait_object_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeTraitObject);
+    skip!(node.dyn_token);
+    for it in &mut node.bounds {
+        visit_type_param_bound_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_type_tuple_mut<V>(
+    v: &mut V,
+    node: &mut TypeTuple,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_type_tuple_mut(node, ancestors));
+    ancestors.push(NodeKind::TypeTuple);
+    skip!(node.paren_token);
+    for it in &mut node.elems {
+        visit_type_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_un_op_mut<V>(
+    v: &mut V,
+    node: &mut UnOp,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_un_op_mut(node, ancestors));
+    ancestors.push(NodeKind::UnOp);
+    match node {
+        UnOp::Deref(_binding_0) => {
+            skip!(_binding_0);
+        }
+        UnOp::Not(_binding_0) => {
+            skip!(_binding_0);
+        }
+        UnOp::Neg(_binding_0) => {
+            skip!(_binding_0);
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_glob_mut<V>(
+    v: &mut V,
+    node: &mut UseGlob,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_glob_mut(node, ancestors));
+    ancestors.push(NodeKind::UseGlob);
+    skip!(node.star_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_group_mut<V>(
+    v: &mut V,
+    node: &mut UseGroup,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_group_mut(node, ancestors));
+    ancestors.push(NodeKind::UseGroup);
+    skip!(node.brace_token);
+    for it in &mut node.items {
+        visit_use_tree_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_name_mut<V>(
+    v: &mut V,
+    node: &mut UseName,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_name_mut(node, ancestors));
+    ancestors.push(NodeKind::UseName);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_path_mut<V>(
+    v: &mut V,
+    node: &mut UsePath,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_path_mut(node, ancestors));
+    ancestors.push(NodeKind::UsePath);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.colon2_token);
+    visit_use_tree_mut(v, &mut *node.tree, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_rename_mut<V>(
+    v: &mut V,
+    node: &mut UseRename,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_rename_mut(node, ancestors));
+    ancestors.push(NodeKind::UseRename);
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    skip!(node.as_token);
+    visit_ident_mut(v, &mut node.rename, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(feature = "full")]
+#[cfg(feature = "visit-mut")]
+pub fn visit_use_tree_mut<V>(
+    v: &mut V,
+    node: &mut UseTree,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_use_tree_mut(node, ancestors));
+    ancestors.push(NodeKind::UseTree);
+    match node {
+        UseTree::Path(_binding_0) => {
+            visit_use_path_mut(v, _binding_0, ancestors)?;
+        }
+        UseTree::Name(_binding_0) => {
+            visit_use_name_mut(v, _binding_0, ancestors)?;
+        }
+        UseTree::Rename(_binding_0) => {
+            visit_use_rename_mut(v, _binding_0, ancestors)?;
+        }
+        UseTree::Glob(_binding_0) => {
+            visit_use_glob_mut(v, _binding_0, ancestors)?;
+        }
+        UseTree::Group(_binding_0) => {
+            visit_use_group_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_variadic_mut<V>(
+    v: &mut V,
+    node: &mut Variadic,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_variadic_mut(node, ancestors));
+    ancestors.push(NodeKind::Variadic);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    skip!(node.dots);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_variant_mut<V>(
+    v: &mut V,
+    node: &mut Variant,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_variant_mut(node, ancestors));
+    ancestors.push(NodeKind::Variant);
+    for it in &mut node.attrs {
+        visit_attribute_mut(v, it, ancestors)?
+    }
+    visit_ident_mut(v, &mut node.ident, ancestors)?;
+    visit_fields_mut(v, &mut node.fields, ancestors)?;
+    if let Some(it) = &mut node.discriminant {
+        skip!((it).0);
+        visit_expr_mut(v, &mut (it).1, ancestors)?;
+    };
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_vis_crate_mut<V>(
+    v: &mut V,
+    node: &mut VisCrate,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_vis_crate_mut(node, ancestors));
+    ancestors.push(NodeKind::VisCrate);
+    skip!(node.crate_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_vis_public_mut<V>(
+    v: &mut V,
+    node: &mut VisPublic,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_vis_public_mut(node, ancestors));
+    ancestors.push(NodeKind::VisPublic);
+    skip!(node.pub_token);
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_vis_restricted_mut<V>(
+    v: &mut V,
+    node: &mut VisRestricted,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_vis_restricted_mut(node, ancestors));
+    ancestors.push(NodeKind::VisRestricted);
+    skip!(node.pub_token);
+    skip!(node.paren_token);
+    skip!(node.in_token);
+    visit_path_mut(v, &mut *node.path, ancestors)?;
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_visibility_mut<V>(
+    v: &mut V,
+    node: &mut Visibility,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_visibility_mut(node, ancestors));
+    ancestors.push(NodeKind::Visibility);
+    match node {
+        Visibility::Public(_binding_0) => {
+            visit_vis_public_mut(v, _binding_0, ancestors)?;
+        }
+        Visibility::Crate(_binding_0) => {
+            visit_vis_crate_mut(v, _binding_0, ancestors)?;
+        }
+        Visibility::Restricted(_binding_0) => {
+            visit_vis_restricted_mut(v, _binding_0, ancestors)?;
+        }
+        Visibility::Inherited => {}
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_where_clause_mut<V>(
+    v: &mut V,
+    node: &mut WhereClause,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_where_clause_mut(node, ancestors));
+    ancestors.push(NodeKind::WhereClause);
+    skip!(node.where_token);
+    for it in &mut node.predicates {
+        visit_where_predicate_mut(v, it, ancestors)?
+    }
+    ancestors.pop();
+    Ok(())
+}
+#[cfg(any(feature = "derive", feature = "full"))]
+#[cfg(feature = "visit-mut")]
+pub fn visit_where_predicate_mut<V>(
+    v: &mut V,
+    node: &mut WherePredicate,
+    ancestors: &mut Vec<NodeKind>,
+) -> Result<(), V::Break>
+where
+    V: VisitMutCtx + ?Sized,
+{
+    enter!(v.visit_where_predicate_mut(node, ancestors));
+    ancestors.push(NodeKind::WherePredicate);
+    match node {
+        WherePredicate::Type(_binding_0) => {
+            visit_predicate_type_mut(v, _binding_0, ancestors)?;
+        }
+        WherePredicate::Lifetime(_binding_0) => {
+            visit_predicate_lifetime_mut(v, _binding_0, ancestors)?;
+        }
+        WherePredicate::Eq(_binding_0) => {
+            visit_predicate_eq_mut(v, _binding_0, ancestors)?;
+        }
+    }
+    ancestors.pop();
+    Ok(())
+}
@@ -152,3 +152,232 @@ pub mod visit_mut {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+pub mod serde {
+    use proc_macro2::{Ident, Literal, TokenStream, TokenTree};
+    use serde::de::{self, DeserializeSeed, Expected, MapAccess, SeqAccess, Unexpected};
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+    use std::fmt;
+    use std::str::FromStr;
+
+    // Adapter to serialize proc-macro2 types, which do not implement Serialize
+    // themselves.
+    pub struct Ser<'a, T: 'a>(pub &'a T);
+
+    // Adapter to deserialize proc-macro2 types, which do not implement
+    // Deserialize themselves.
+    pub struct De<T>(pub T);
+
+    impl<'a> Serialize for Ser<'a, Ident> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    impl<'a> Serialize for Ser<'a, Literal> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    impl<'a> Serialize for Ser<'a, TokenStream> {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serializer.collect_str(self.0)
+        }
+    }
+
+    fn single_token<E: de::Error>(repr: &str, expected: &'static str) -> Result<TokenTree, E> {
+        let unexpected = || E::invalid_value(Unexpected::Str(repr), &expected);
+        let mut tokens = TokenStream::from_str(repr)
+            .map_err(|_| unexpected())?
+            .into_iter();
+        match (tokens.next(), tokens.next()) {
+            (Some(token), None) => Ok(token),
+            _ => Err(unexpected()),
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<Ident> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match single_token(&repr, "an identifier")? {
+                TokenTree::Ident(ident) => Ok(De(ident)),
+                _ => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"an identifier",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<Literal> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match single_token(&repr, "a literal")? {
+                TokenTree::Literal(literal) => Ok(De(literal)),
+                _ => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"a literal",
+                )),
+            }
+        }
+    }
+
+    impl<'de> Deserialize<'de> for De<TokenStream> {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let repr = String::deserialize(deserializer)?;
+            match TokenStream::from_str(&repr) {
+                Ok(tokens) => Ok(De(tokens)),
+                Err(_) => Err(de::Error::invalid_value(
+                    Unexpected::Str(&repr),
+                    &"a token stream",
+                )),
+            }
+        }
+    }
+
+    // Deserializes the name of a struct field or enum variant into its index
+    // within `names`.
+    pub struct Identifier {
+        pub names: &'static [&'static str],
+        pub variant: bool,
+    }
+
+    impl Identifier {
+        pub fn field(names: &'static [&'static str]) -> Self {
+            Identifier {
+                names,
+                variant: false,
+            }
+        }
+
+        pub fn variant(names: &'static [&'static str]) -> Self {
+            Identifier {
+                names,
+                variant: true,
+            }
+        }
+
+        fn unknown<E: de::Error>(&self, name: &str) -> E {
+            if self.variant {
+                E::unknown_variant(name, self.names)
+            } else {
+                E::unknown_field(name, self.names)
+            }
+        }
+    }
+
+    impl<'de> DeserializeSeed<'de> for Identifier {
+        type Value = usize;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            deserializer.deserialize_identifier(self)
+        }
+    }
+
+    impl<'de> de::Visitor<'de> for Identifier {
+        type Value = usize;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str(if self.variant {
+                "variant identifier"
+            } else {
+                "field identifier"
+            })
+        }
+
+        fn visit_u64<E: de::Error>(self, index: u64) -> Result<Self::Value, E> {
+            if index < self.names.len() as u64 {
+                Ok(index as usize)
+            } else {
+                Err(E::invalid_value(Unexpected::Unsigned(index), &self))
+            }
+        }
+
+        fn visit_str<E: de::Error>(self, name: &str) -> Result<Self::Value, E> {
+            match self.names.iter().position(|candidate| *candidate == name) {
+                Some(index) => Ok(index),
+                None => Err(self.unknown(name)),
+            }
+        }
+
+        fn visit_bytes<E: de::Error>(self, name: &[u8]) -> Result<Self::Value, E> {
+            match self
+                .names
+                .iter()
+                .position(|candidate| candidate.as_bytes() == name)
+            {
+                Some(index) => Ok(index),
+                None => Err(self.unknown(&String::from_utf8_lossy(name))),
+            }
+        }
+    }
+
+    pub fn element<'de, A, T>(
+        seq: &mut A,
+        index: usize,
+        expected: &dyn Expected,
+    ) -> Result<T, A::Error>
+    where
+        A: SeqAccess<'de>,
+        T: Deserialize<'de>,
+    {
+        match seq.next_element()? {
+            Some(value) => Ok(value),
+            None => Err(de::Error::invalid_length(index, expected)),
+        }
+    }
+
+    pub fn field<'de, A, T>(
+        map: &mut A,
+        slot: &mut Option<T>,
+        name: &'static str,
+    ) -> Result<(), A::Error>
+    where
+        A: MapAccess<'de>,
+        T: Deserialize<'de>,
+    {
+        if slot.is_some() {
+            return Err(de::Error::duplicate_field(name));
+        }
+        *slot = Some(map.next_value()?);
+        Ok(())
+    }
+
+    pub fn required<T, E: de::Error>(slot: Option<T>, name: &'static str) -> Result<T, E> {
+        match slot {
+            Some(value) => Ok(value),
+            None => Err(E::missing_field(name)),
+        }
+    }
+
+    #[cfg(not(feature = "full"))]
+    pub fn requires_full<E: de::Error>(variant: &str) -> E {
+        E::custom(format_args!(
+            "variant `{}` requires Syn's \"full\" feature",
+            variant,
+        ))
+    }
+}
@@ -77,16 +77,17 @@ fn parse_delimited<'a>(
             let scope = crate::buffer::close_span_of_group(*cursor);
             let nested = crate::parse::advance_step_cursor(cursor, content);
             let unexpected = crate::parse::get_unexpected(input);
-            let content = crate::parse::new_parse_buffer(scope, nested, unexpected);
+            let recovery = crate::parse::get_recovery(input);
+            let content = crate::parse::new_parse_buffer(scope, nested, unexpected, recovery);
             Ok(((span, content), rest))
         } else {
-            let message = match delimiter {
-                Delimiter::Parenthesis => "expected parentheses",
-                Delimiter::Brace => "expected curly braces",
-                Delimiter::Bracket => "expected square brackets",
-                Delimiter::None => "expected invisible group",
+            let expected = match delimiter {
+                Delimiter::Parenthesis => "parentheses",
+                Delimiter::Brace => "curly braces",
+                Delimiter::Bracket => "square brackets",
+                Delimiter::None => "invisible group",
             };
-            Err(cursor.error(message))
+            Err(cursor.error_expected(&[expected]))
         }
     })
 }
@@ -44,7 +44,7 @@ impl Parse for Ident {
                     return Ok((ident, rest));
                 }
             }
-            Err(cursor.error("expected identifier"))
+            Err(cursor.error_expected(&["identifier"]))
         })
     }
 }
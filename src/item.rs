@@ -1056,7 +1056,7 @@ pub mod parsing {
 
     use crate::ext::IdentExt;
     use crate::parse::discouraged::Speculative;
-    use crate::parse::{Parse, ParseStream, Result};
+    use crate::parse::{Boundary, Parse, ParseStream, Result};
     use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenTree};
     use std::iter::{self, FromIterator};
 
@@ -1329,7 +1329,7 @@ pub mod parsing {
                             } else if input.peek(Token![_]) {
                                 Ident::from(input.parse::<Token![_]>()?)
                             } else {
-                                return Err(input.error("expected identifier or underscore"));
+                                return Err(input.error_expected(&["identifier", "underscore"]));
                             }
                         },
                     }))
@@ -1510,7 +1510,7 @@ pub mod parsing {
 
         Ok(PatType {
             attrs: Vec::new(),
-            pat: input.parse()?,
+            pat: Box::new(Pat::parse_single(input)?),
             colon_token: input.parse()?,
             ty: Box::new(match input.parse::<Option<Token![...]>>()? {
                 Some(dot3) => {
@@ -1556,7 +1556,12 @@ pub mod parsing {
 
                 let mut items = Vec::new();
                 while !content.is_empty() {
-                    items.push(content.parse()?);
+                    items.push(crate::parse::recover(
+                        &content,
+                        Boundary::Item,
+                        Item::parse,
+                        Item::Verbatim,
+                    )?);
                 }
 
                 Ok(ItemMod {
@@ -1583,7 +1588,12 @@ pub mod parsing {
             let inner_attrs = content.call(Attribute::parse_inner)?;
             let mut items = Vec::new();
             while !content.is_empty() {
-                items.push(content.parse()?);
+                items.push(crate::parse::recover(
+                    &content,
+                    Boundary::Item,
+                    ForeignItem::parse,
+                    ForeignItem::Verbatim,
+                )?);
             }
 
             Ok(ItemForeignMod {
@@ -1955,7 +1965,12 @@ pub mod parsing {
         let brace_token = braced!(content in input);
         let mut items = Vec::new();
         while !content.is_empty() {
-            items.push(content.parse()?);
+            items.push(crate::parse::recover(
+                &content,
+                Boundary::Item,
+                TraitItem::parse,
+                TraitItem::Verbatim,
+            )?);
         }
 
         Ok(ItemTrait {
@@ -2265,7 +2280,12 @@ pub mod parsing {
 
             let mut items = Vec::new();
             while !content.is_empty() {
-                items.push(content.parse()?);
+                items.push(crate::parse::recover(
+                    &content,
+                    Boundary::Item,
+                    ImplItem::parse,
+                    ImplItem::Verbatim,
+                )?);
             }
 
             Ok(ItemImpl {
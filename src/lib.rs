@@ -238,11 +238,22 @@
 //!   types.
 //! - **`extra-traits`** — Debug, Eq, PartialEq, Hash impls for all syntax tree
 //!   types.
+//! - **`serde`** — Serialize and Deserialize impls for all syntax tree types,
+//!   following the shape described by [syn.json]. Spans are not serialized.
 //! - **`proc-macro`** *(enabled by default)* — Runtime dependency on the
 //!   dynamic library libproc_macro from rustc toolchain.
+//! - **`span-locations`** — Line, column and byte offset information for
+//!   tokens parsed from a string, accessible through [`source_map`] and used
+//!   by [`Error::render`] to show errors against the source text.
+//! - **`nightly-diagnostics`** — Emit [`Error`] labels, notes and warnings as
+//!   native compiler diagnostics when built with a nightly compiler. Stable
+//!   compilers ignore this feature.
+//!
+//! [syn.json]: https://raw.githubusercontent.com/dtolnay/syn/master/syn.json
 
 // Syn types in rustdoc of other crates get linked to here.
 #![doc(html_root_url = "https://docs.rs/syn/1.0.5")]
+#![cfg_attr(syn_nightly_diagnostics, feature(proc_macro_diagnostic))]
 #![deny(clippy::all, clippy::pedantic)]
 // Ignored clippy lints.
 #![allow(
@@ -261,6 +272,8 @@
 // Ignored clippy_pedantic lints.
 #![allow(
     clippy::cast_possible_truncation,
+    clippy::cast_possible_wrap,
+    clippy::cast_sign_loss,
     clippy::empty_enum,
     clippy::if_not_else,
     clippy::items_after_statements,
@@ -441,6 +454,77 @@ pub mod parse_macro_input;
 #[cfg(all(feature = "parsing", feature = "printing"))]
 pub mod spanned;
 
+#[cfg(all(feature = "full", feature = "printing"))]
+pub mod pretty;
+
+#[cfg(all(feature = "parsing", feature = "printing", feature = "span-locations"))]
+pub mod source_map;
+
+#[cfg(all(
+    feature = "full",
+    feature = "parsing",
+    feature = "printing",
+    feature = "visit",
+    feature = "visit-mut",
+    feature = "clone-impls",
+    feature = "extra-traits"
+))]
+pub mod query;
+
+#[cfg(all(feature = "full", feature = "visit", feature = "clone-impls"))]
+pub mod resolve;
+
+#[cfg(all(feature = "full", feature = "visit-mut", feature = "clone-impls"))]
+pub mod subst;
+
+#[cfg(all(feature = "full", feature = "parsing"))]
+pub mod macro_rules;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "printing",
+    feature = "clone-impls"
+))]
+pub mod from_meta;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "printing"
+))]
+pub mod meta;
+
+#[cfg(all(
+    any(feature = "full", feature = "derive"),
+    feature = "parsing",
+    feature = "printing"
+))]
+pub mod eval;
+
+#[cfg(all(feature = "derive", feature = "parsing", feature = "printing"))]
+pub mod repr;
+
+#[cfg(all(
+    feature = "derive",
+    feature = "parsing",
+    feature = "printing",
+    feature = "clone-impls"
+))]
+pub mod bound;
+
+#[cfg(all(feature = "derive", feature = "printing"))]
+pub mod structure;
+
+#[cfg(any(feature = "full", feature = "derive"))]
+pub mod precedence;
+
+#[cfg(any(feature = "full", feature = "derive"))]
+pub mod elision;
+
+#[cfg(all(feature = "full", feature = "parsing", feature = "printing"))]
+pub mod async_fn;
+
 mod gen {
     /// Syntax tree traversal to walk a shared borrow of a syntax tree.
     ///
@@ -658,6 +742,103 @@ mod gen {
     #[rustfmt::skip]
     pub mod visit_mut;
 
+    /// Syntax tree traversal that tells each hook where it is in the tree and
+    /// lets it stop the traversal early.
+    ///
+    /// Each method of the [`VisitCtx`] and [`VisitMutCtx`] traits is a hook
+    /// that is called before the substructure of the corresponding type of
+    /// node is walked. Along with the node, a hook receives the ancestors of
+    /// that node, outermost first, and returns a [`Control`] that decides how
+    /// the traversal proceeds:
+    ///
+    /// - `Control::Continue` walks into the children of the node;
+    /// - `Control::SkipChildren` moves on to the next sibling instead;
+    /// - `Control::Break(value)` ends the whole traversal, and the free
+    ///   function that started it returns `Err(value)`.
+    ///
+    /// [`VisitCtx`] walks a shared borrow and provides each ancestor as a
+    /// [`Node`], a reference to the enclosing syntax tree node. [`VisitMutCtx`]
+    /// walks an exclusive borrow, so it can only provide the [`NodeKind`] of
+    /// each ancestor.
+    ///
+    /// Hooks of [`VisitCtx`] that are not overridden fall through to its
+    /// `visit_node` hook, which therefore sees every node that is not handled
+    /// more specifically, and decides how the traversal proceeds for every
+    /// node kind without a hook of its own. The default `visit_node`
+    /// continues. [`VisitMutCtx`] has no such fallthrough.
+    ///
+    /// The traversal is driven by the free functions of this module, which
+    /// take the ancestors of the starting node. This is usually an empty
+    /// `Vec`. If the traversal breaks, the `Vec` is left holding the
+    /// ancestors of the node whose hook broke.
+    ///
+    /// Unlike [`Visit`] and [`VisitMut`], these traversals do not visit the
+    /// spans of tokens.
+    ///
+    /// [`VisitCtx`]: visit_ctx::VisitCtx
+    /// [`VisitMutCtx`]: visit_ctx::VisitMutCtx
+    /// [`Control`]: visit_ctx::Control
+    /// [`Node`]: visit_ctx::Node
+    /// [`NodeKind`]: visit_ctx::NodeKind
+    /// [`Visit`]: visit::Visit
+    /// [`VisitMut`]: visit_mut::VisitMut
+    ///
+    /// *This module is available if Syn is built with the `"visit"` or
+    /// `"visit-mut"` feature.*
+    ///
+    /// <br>
+    ///
+    /// # Example
+    ///
+    /// This visitor finds the first method call named `unwrap` and reports
+    /// the function that contains it.
+    ///
+    /// ```
+    /// // [dependencies]
+    /// // syn = { version = "1.0", features = ["full", "visit"] }
+    ///
+    /// use syn::visit_ctx::{self, Control, Node, VisitCtx};
+    /// use syn::{ExprMethodCall, File, Ident};
+    ///
+    /// struct FindUnwrap;
+    ///
+    /// impl<'ast> VisitCtx<'ast> for FindUnwrap {
+    ///     type Break = Option<&'ast Ident>;
+    ///
+    ///     fn visit_expr_method_call(
+    ///         &mut self,
+    ///         node: &'ast ExprMethodCall,
+    ///         ancestors: &[Node<'ast>],
+    ///     ) -> Control<Self::Break> {
+    ///         if node.method != "unwrap" {
+    ///             return Control::Continue;
+    ///         }
+    ///         let enclosing_fn = ancestors.iter().rev().find_map(|node| match node {
+    ///             Node::ItemFn(item) => Some(&item.sig.ident),
+    ///             Node::ImplItemMethod(method) => Some(&method.sig.ident),
+    ///             _ => None,
+    ///         });
+    ///         Control::Break(enclosing_fn)
+    ///     }
+    /// }
+    ///
+    /// fn main() {
+    ///     let code = "
+    ///         fn first() {}
+    ///         fn second() -> u8 {
+    ///             \"1\".parse().unwrap()
+    ///         }
+    ///     ";
+    ///
+    ///     let syntax_tree: File = syn::parse_str(code).unwrap();
+    ///     let found = visit_ctx::visit_file(&mut FindUnwrap, &syntax_tree, &mut Vec::new());
+    ///     assert_eq!(found.unwrap_err().unwrap(), "second");
+    /// }
+    /// ```
+    #[cfg(any(feature = "visit", feature = "visit-mut"))]
+    #[rustfmt::skip]
+    pub mod visit_ctx;
+
     /// Syntax tree traversal to transform the nodes of an owned syntax tree.
     ///
     /// Each method of the [`Fold`] trait is a hook that can be overridden to
@@ -744,6 +925,10 @@ mod gen {
     #[rustfmt::skip]
     pub mod fold;
 
+    #[cfg(all(feature = "serde", any(feature = "full", feature = "derive")))]
+    #[rustfmt::skip]
+    mod serde;
+
     #[cfg(any(feature = "full", feature = "derive"))]
     #[path = "../gen_helper.rs"]
     mod helper;
@@ -785,7 +970,7 @@ mod rustdoc_workaround {
 ////////////////////////////////////////////////////////////////////////////////
 
 mod error;
-pub use crate::error::{Error, Result};
+pub use crate::error::{Error, ErrorKind, Result};
 
 /// Parse tokens of source code into the chosen syntax tree node.
 ///
@@ -923,7 +1108,91 @@ pub fn parse_str<T: parse::Parse>(s: &str) -> Result<T> {
 /// # }
 /// ```
 #[cfg(all(feature = "parsing", feature = "full"))]
-pub fn parse_file(mut content: &str) -> Result<File> {
+pub fn parse_file(content: &str) -> Result<File> {
+    let (shebang, content) = split_file(content);
+    let mut file: File = parse_str(content)?;
+    file.shebang = shebang;
+    Ok(file)
+}
+
+/// Parse a string of Rust code into the chosen syntax tree node, recovering
+/// from syntax errors in items and statements.
+///
+/// An item or statement that fails to parse is skipped up to the next `;` or,
+/// for items, the end of its body. It is kept in the syntax tree as a
+/// `Verbatim` placeholder holding the skipped tokens: `Item::Verbatim`,
+/// `ImplItem::Verbatim`, `TraitItem::Verbatim` or `ForeignItem::Verbatim` for
+/// items, and an `Expr::Verbatim` inside `Stmt::Semi` or `Stmt::Expr` for
+/// statements. Parsing then continues with the next one.
+///
+/// The returned error, if any, combines every error recovered from. Errors
+/// outside of items and statements cannot be recovered from and fail the
+/// whole parse, combined with those recovered from before.
+///
+/// *This function is available if Syn is built with the `"parsing"` and
+/// `"full"` features.*
+///
+/// # Example
+///
+/// ```
+/// use syn::{Block, Expr, Result, Stmt};
+///
+/// fn run() -> Result<()> {
+///     let code = "{ let x = ; let y = 1; f(y z); y }";
+///     let (block, errors) = syn::parse_str_recovering::<Block>(code)?;
+///
+///     assert_eq!(block.stmts.len(), 4);
+///     match &block.stmts[0] {
+///         Stmt::Semi(Expr::Verbatim(tokens), _) => assert_eq!(tokens.to_string(), "let x ="),
+///         _ => unreachable!(),
+///     }
+///     assert_eq!(errors.unwrap().into_iter().count(), 2);
+///     Ok(())
+/// }
+/// #
+/// # fn main() {
+/// #     run().unwrap();
+/// # }
+/// ```
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub fn parse_str_recovering<T: parse::Parse>(s: &str) -> Result<(T, Option<Error>)> {
+    let tokens = s.parse::<proc_macro2::TokenStream>()?;
+    parse::parse_recovering(T::parse, tokens)
+}
+
+/// Parse the content of a file of Rust code, recovering from syntax errors in
+/// items and statements.
+///
+/// This treats a byte order mark and shebang line the same way as
+/// [`parse_file`], and recovers from errors the same way as
+/// [`parse_str_recovering`]. If the file cannot be parsed at all, for example
+/// because of an unclosed delimiter, the returned `File` has no items.
+///
+/// *This function is available if Syn is built with the `"parsing"` and
+/// `"full"` features.*
+#[cfg(all(feature = "parsing", feature = "full"))]
+pub fn parse_file_recovering(content: &str) -> (File, Option<Error>) {
+    let (shebang, content) = split_file(content);
+    match parse_str_recovering::<File>(content) {
+        Ok((mut file, errors)) => {
+            file.shebang = shebang;
+            (file, errors)
+        }
+        Err(err) => {
+            let file = File {
+                shebang,
+                attrs: Vec::new(),
+                items: Vec::new(),
+            };
+            (file, Some(err))
+        }
+    }
+}
+
+// Splits the BOM and shebang line, if any, off the front of a source file. The
+// returned content is always a suffix of the input.
+#[cfg(all(feature = "parsing", feature = "full"))]
+fn split_file(mut content: &str) -> (Option<String>, &str) {
     // Strip the BOM if it is present
     const BOM: &str = "\u{feff}";
     if content.starts_with(BOM) {
@@ -941,7 +1210,5 @@ pub fn parse_file(mut content: &str) -> Result<File> {
         }
     }
 
-    let mut file: File = parse_str(content)?;
-    file.shebang = shebang;
-    Ok(file)
+    (shebang, content)
 }
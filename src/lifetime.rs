@@ -116,7 +116,7 @@ pub mod parsing {
             input.step(|cursor| {
                 cursor
                     .lifetime()
-                    .ok_or_else(|| cursor.error("expected lifetime"))
+                    .ok_or_else(|| cursor.error_expected(&["lifetime"]))
             })
         }
     }
@@ -17,7 +17,7 @@ use std::hash::{Hash, Hasher};
 use crate::lookahead;
 #[cfg(feature = "parsing")]
 use crate::parse::{Parse, Parser};
-use crate::{Error, Result};
+use crate::{Error, ErrorKind, Result};
 
 ast_enum_of_structs! {
     /// A Rust literal such as a string or integer or boolean.
@@ -451,7 +451,7 @@ impl LitInt {
     {
         self.base10_digits()
             .parse()
-            .map_err(|err| Error::new(self.span(), err))
+            .map_err(|err| Error::new(self.span(), err).with_kind(ErrorKind::InvalidLiteral))
     }
 
     pub fn suffix(&self) -> &str {
@@ -518,7 +518,7 @@ impl LitFloat {
     {
         self.base10_digits()
             .parse()
-            .map_err(|err| Error::new(self.span(), err))
+            .map_err(|err| Error::new(self.span(), err).with_kind(ErrorKind::InvalidLiteral))
     }
 
     pub fn suffix(&self) -> &str {
@@ -713,7 +713,7 @@ pub mod parsing {
                     };
                     return Ok((Lit::Bool(lit_bool), rest));
                 }
-                Err(cursor.error("expected literal"))
+                Err(cursor.error_expected(&["literal"]))
             })
         }
     }
@@ -723,7 +723,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Str(lit) => Ok(lit),
-                _ => Err(head.error("expected string literal")),
+                _ => Err(head.error_expected(&["string literal"])),
             }
         }
     }
@@ -733,7 +733,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::ByteStr(lit) => Ok(lit),
-                _ => Err(head.error("expected byte string literal")),
+                _ => Err(head.error_expected(&["byte string literal"])),
             }
         }
     }
@@ -743,7 +743,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Byte(lit) => Ok(lit),
-                _ => Err(head.error("expected byte literal")),
+                _ => Err(head.error_expected(&["byte literal"])),
             }
         }
     }
@@ -753,7 +753,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Char(lit) => Ok(lit),
-                _ => Err(head.error("expected character literal")),
+                _ => Err(head.error_expected(&["character literal"])),
             }
         }
     }
@@ -763,7 +763,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Int(lit) => Ok(lit),
-                _ => Err(head.error("expected integer literal")),
+                _ => Err(head.error_expected(&["integer literal"])),
             }
         }
     }
@@ -773,7 +773,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Float(lit) => Ok(lit),
-                _ => Err(head.error("expected floating point literal")),
+                _ => Err(head.error_expected(&["floating point literal"])),
             }
         }
     }
@@ -783,7 +783,7 @@ pub mod parsing {
             let head = input.fork();
             match input.parse()? {
                 Lit::Bool(lit) => Ok(lit),
-                _ => Err(head.error("expected boolean literal")),
+                _ => Err(head.error_expected(&["boolean literal"])),
             }
         }
     }
@@ -839,6 +839,73 @@ mod printing {
     }
 }
 
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use proc_macro2::{TokenStream, TokenTree};
+    use serde::de::{self, Deserialize, Deserializer, Unexpected};
+    use serde::ser::{Serialize, Serializer};
+    use std::result::Result;
+
+    // Literals are serialized as their source representation, for example
+    // `"\"foo\""` or `"1u8"`.
+    fn literal<'de, D, T>(
+        deserializer: D,
+        expected: &'static str,
+        kind: fn(Lit) -> Option<T>,
+    ) -> Result<T, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let repr = String::deserialize(deserializer)?;
+        let unexpected = || de::Error::invalid_value(Unexpected::Str(&repr), &expected);
+        let mut tokens = match repr.parse::<TokenStream>() {
+            Ok(tokens) => tokens.into_iter(),
+            Err(_) => return Err(unexpected()),
+        };
+        match (tokens.next(), tokens.next()) {
+            (Some(TokenTree::Literal(token)), None) => kind(Lit::new(token)).ok_or_else(unexpected),
+            _ => Err(unexpected()),
+        }
+    }
+
+    macro_rules! lit_serde {
+        ($($ty:ident::$variant:ident, $expected:expr, self.$($token:ident).+;)*) => {
+            $(
+                impl Serialize for $ty {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: Serializer,
+                    {
+                        serializer.collect_str(&self.$($token).+)
+                    }
+                }
+
+                impl<'de> Deserialize<'de> for $ty {
+                    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                    where
+                        D: Deserializer<'de>,
+                    {
+                        literal(deserializer, $expected, |lit| match lit {
+                            Lit::$variant(lit) => Some(lit),
+                            _ => None,
+                        })
+                    }
+                }
+            )*
+        };
+    }
+
+    lit_serde! {
+        LitStr::Str, "a string literal", self.repr.token;
+        LitByteStr::ByteStr, "a byte string literal", self.token;
+        LitByte::Byte, "a byte literal", self.token;
+        LitChar::Char, "a character literal", self.token;
+        LitInt::Int, "an integer literal", self.repr.token;
+        LitFloat::Float, "a floating point literal", self.repr.token;
+    }
+}
+
 mod value {
     use super::*;
     use crate::bigint::BigInt;
@@ -110,28 +110,7 @@ impl<'a> Lookahead1<'a> {
     /// have been peeked against this lookahead instance.
     pub fn error(self) -> Error {
         let comparisons = self.comparisons.borrow();
-        match comparisons.len() {
-            0 => {
-                if self.cursor.eof() {
-                    Error::new(self.scope, "unexpected end of input")
-                } else {
-                    Error::new(self.cursor.span(), "unexpected token")
-                }
-            }
-            1 => {
-                let message = format!("expected {}", comparisons[0]);
-                error::new_at(self.scope, self.cursor, message)
-            }
-            2 => {
-                let message = format!("expected {} or {}", comparisons[0], comparisons[1]);
-                error::new_at(self.scope, self.cursor, message)
-            }
-            _ => {
-                let join = comparisons.join(", ");
-                let message = format!("expected one of: {}", join);
-                error::new_at(self.scope, self.cursor, message)
-            }
-        }
+        error::new_expected(self.scope, self.cursor, &comparisons)
     }
 }
 
@@ -180,12 +180,12 @@ pub fn parse_delimiter(input: ParseStream) -> Result<(MacroDelimiter, TokenStrea
                 Delimiter::Brace => MacroDelimiter::Brace(Brace(span)),
                 Delimiter::Bracket => MacroDelimiter::Bracket(Bracket(span)),
                 Delimiter::None => {
-                    return Err(cursor.error("expected delimiter"));
+                    return Err(cursor.error_expected(&["delimiter"]));
                 }
             };
             Ok(((delimiter, g.stream()), rest))
         } else {
-            Err(cursor.error("expected delimiter"))
+            Err(cursor.error_expected(&["delimiter"]))
         }
     })
 }
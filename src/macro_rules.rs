@@ -0,0 +1,959 @@
+//! Macro-by-example definitions and their expansion.
+//!
+//! The body of a `macro_rules!` item is stored in the syntax tree as a plain
+//! [`TokenStream`]. [`MacroRules`] parses that body into its rules, each made
+//! of a matcher and a transcriber, with typed nodes for the `$name:kind`
+//! fragments and `$(...) sep op` repetitions they contain.
+//!
+//! [`MacroRules::expand`] takes the tokens of an invocation, such as the
+//! [`Macro::tokens`] of a `Macro` whose path names the macro, and tries each
+//! rule in order. Fragments are matched by running Syn's own parser for the
+//! fragment kind, so `$e:expr` consumes exactly the tokens that
+//! [`Expr`]'s `Parse` impl would. Repetitions are matched greedily. The first
+//! rule that matches the whole input is transcribed into the expansion.
+//!
+//! As in the compiler, a fragment other than `ident`, `lifetime` and `tt` is
+//! substituted into the transcriber wrapped in a `None`-delimited group, so
+//! `$e * 2` with `$e` bound to `1 + 1` parses as `(1 + 1) * 2`. Hygiene is
+//! not modeled, and `$crate` expands to `crate`.
+//!
+//! *This module is available if Syn is built with the `"full"` and
+//! `"parsing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::macro_rules::MacroRules;
+//! use syn::{Expr, File, Item, Stmt};
+//!
+//! let file: File = syn::parse_str("
+//!     macro_rules! square {
+//!         ($e:expr) => { $e * $e };
+//!     }
+//!
+//!     fn f(x: u8) -> u8 {
+//!         square!(x + 1)
+//!     }
+//! ").unwrap();
+//!
+//! let rules = match &file.items[0] {
+//!     Item::Macro(item) => MacroRules::from_item(item).unwrap(),
+//!     _ => unreachable!(),
+//! };
+//!
+//! let mac = match &file.items[1] {
+//!     Item::Fn(item) => match &item.block.stmts[0] {
+//!         Stmt::Expr(Expr::Macro(expr)) => &expr.mac,
+//!         _ => unreachable!(),
+//!     },
+//!     _ => unreachable!(),
+//! };
+//!
+//! let expansion = rules.expand(mac.tokens.clone()).unwrap();
+//! let expr: Expr = syn::parse2(expansion).unwrap();
+//! assert!(match expr {
+//!     Expr::Binary(_) => true,
+//!     _ => false,
+//! });
+//! ```
+
+use std::collections::HashMap;
+use std::iter;
+
+use proc_macro2::{Delimiter, Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
+
+use crate::buffer;
+use crate::ext::IdentExt;
+use crate::parse::discouraged::Speculative;
+use crate::parse::{Parse, ParseStream, Parser};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// The rules of a `macro_rules!` definition.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct MacroRules {
+    pub rules: Punctuated<Rule, token::Semi>,
+}
+
+/// One rule of a macro definition: `($e:expr) => { $e }`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Rule {
+    pub matcher: Delimited<MatcherTree>,
+    pub fat_arrow_token: token::FatArrow,
+    pub transcriber: Delimited<TranscriberTree>,
+}
+
+/// A sequence of matcher or transcriber trees surrounded by delimiters.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Delimited<T> {
+    pub delimiter: Delimiter,
+    pub span: Span,
+    pub tokens: Vec<T>,
+}
+
+/// A piece of the left-hand side of a rule.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum MatcherTree {
+    /// A token that must appear in the input as written.
+    Token(TokenTree),
+
+    /// A delimited group whose contents are matched in turn.
+    Group(Delimited<MatcherTree>),
+
+    /// A fragment that binds a metavariable: `$e:expr`.
+    Fragment(Fragment),
+
+    /// A repeated sequence: `$($e:expr),*`.
+    Repetition(Repetition<MatcherTree>),
+}
+
+/// A piece of the right-hand side of a rule.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum TranscriberTree {
+    /// A token copied into the expansion as written.
+    Token(TokenTree),
+
+    /// A delimited group whose contents are transcribed in turn.
+    Group(Delimited<TranscriberTree>),
+
+    /// A use of a metavariable, or `$crate`.
+    Variable(Variable),
+
+    /// A sequence repeated once for each match of the metavariables it uses:
+    /// `$($e),*`.
+    Repetition(Repetition<TranscriberTree>),
+}
+
+/// A metavariable declaration in a matcher: `$e:expr`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Fragment {
+    pub dollar_token: token::Dollar,
+    pub name: Ident,
+    pub colon_token: token::Colon,
+    pub kind: FragmentKind,
+}
+
+/// The fragment specifier of a metavariable, which selects the parser used
+/// to match it.
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq, Hash))]
+#[derive(Copy, Clone)]
+pub enum FragmentKind {
+    /// `block`: a [`Block`].
+    Block,
+    /// `expr`: an [`Expr`].
+    Expr,
+    /// `ident`: an identifier or keyword.
+    Ident,
+    /// `item`: an [`Item`].
+    Item,
+    /// `lifetime`: a [`Lifetime`].
+    Lifetime,
+    /// `literal`: a [`Lit`], optionally preceded by `-`.
+    Literal,
+    /// `meta`: the contents of an attribute, a path followed by a delimited
+    /// group or by `=` and an expression.
+    Meta,
+    /// `pat`: a [`Pat`].
+    Pat,
+    /// `path`: a [`Path`].
+    Path,
+    /// `stmt`: a statement without its trailing semicolon.
+    Stmt,
+    /// `tt`: a single token tree.
+    Tt,
+    /// `ty`: a [`Type`].
+    Ty,
+    /// `vis`: a possibly empty [`Visibility`].
+    Vis,
+}
+
+/// A metavariable use in a transcriber: `$e`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Variable {
+    pub dollar_token: token::Dollar,
+    pub name: Ident,
+}
+
+/// A repetition: `$(...) sep op`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct Repetition<T> {
+    pub dollar_token: token::Dollar,
+    pub paren_token: token::Paren,
+    pub tokens: Vec<T>,
+    pub separator: Option<TokenStream>,
+    pub op: RepetitionOp,
+}
+
+/// The repetition operator following a repetition.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum RepetitionOp {
+    /// `*`
+    ZeroOrMore(token::Star),
+    /// `+`
+    OneOrMore(token::Add),
+    /// `?`
+    ZeroOrOne(token::Question),
+}
+
+impl MacroRules {
+    /// Parses the rules of a `macro_rules!` item.
+    pub fn from_item(item: &ItemMacro) -> Result<Self> {
+        if !item.mac.path.is_ident("macro_rules") {
+            return Err(Error::new(
+                item.mac.bang_token.spans[0],
+                "expected a `macro_rules!` definition",
+            ));
+        }
+        item.mac.parse_body()
+    }
+
+    /// Expands an invocation of this macro, given the tokens between the
+    /// delimiters of the invocation.
+    pub fn expand(&self, tokens: TokenStream) -> Result<TokenStream> {
+        let mut error = None;
+        for rule in &self.rules {
+            match rule.match_input(tokens.clone()) {
+                Ok(bindings) => return rule.transcribe(&bindings),
+                Err(err) => error = Some(err),
+            }
+        }
+        match error {
+            Some(error) if self.rules.len() == 1 => Err(error),
+            _ => {
+                let span = match tokens.into_iter().next() {
+                    Some(token) => token.span(),
+                    None => Span::call_site(),
+                };
+                Err(Error::new(
+                    span,
+                    "no rules expected this token in macro call",
+                ))
+            }
+        }
+    }
+}
+
+impl Rule {
+    fn match_input(&self, tokens: TokenStream) -> Result<Bindings> {
+        let parser = |input: ParseStream| {
+            let mut bindings = Bindings::new();
+            match_trees(input, &self.matcher.tokens, &mut bindings)?;
+            Ok(bindings)
+        };
+        parser.parse2(tokens)
+    }
+
+    fn transcribe(&self, bindings: &Bindings) -> Result<TokenStream> {
+        let mut tokens = TokenStream::new();
+        transcribe_trees(
+            &self.transcriber.tokens,
+            bindings,
+            &mut Vec::new(),
+            &mut tokens,
+        )?;
+        Ok(tokens)
+    }
+}
+
+impl FragmentKind {
+    fn from_ident(ident: &Ident) -> Option<Self> {
+        let kind = match ident.to_string().as_str() {
+            "block" => FragmentKind::Block,
+            "expr" => FragmentKind::Expr,
+            "ident" => FragmentKind::Ident,
+            "item" => FragmentKind::Item,
+            "lifetime" => FragmentKind::Lifetime,
+            "literal" => FragmentKind::Literal,
+            "meta" => FragmentKind::Meta,
+            "pat" => FragmentKind::Pat,
+            "path" => FragmentKind::Path,
+            "stmt" => FragmentKind::Stmt,
+            "tt" => FragmentKind::Tt,
+            "ty" => FragmentKind::Ty,
+            "vis" => FragmentKind::Vis,
+            _ => return None,
+        };
+        Some(kind)
+    }
+
+    #[cfg(feature = "printing")]
+    fn as_str(self) -> &'static str {
+        match self {
+            FragmentKind::Block => "block",
+            FragmentKind::Expr => "expr",
+            FragmentKind::Ident => "ident",
+            FragmentKind::Item => "item",
+            FragmentKind::Lifetime => "lifetime",
+            FragmentKind::Literal => "literal",
+            FragmentKind::Meta => "meta",
+            FragmentKind::Pat => "pat",
+            FragmentKind::Path => "path",
+            FragmentKind::Stmt => "stmt",
+            FragmentKind::Tt => "tt",
+            FragmentKind::Ty => "ty",
+            FragmentKind::Vis => "vis",
+        }
+    }
+
+    // Fragments the compiler passes through as plain tokens rather than as
+    // an opaque syntax tree node.
+    fn is_transparent(self) -> bool {
+        match self {
+            FragmentKind::Ident | FragmentKind::Lifetime | FragmentKind::Tt => true,
+            _ => false,
+        }
+    }
+}
+
+impl RepetitionOp {
+    fn from_punct(punct: &Punct) -> Option<Self> {
+        let span = punct.span();
+        match punct.as_char() {
+            '*' => Some(RepetitionOp::ZeroOrMore(Token![*](span))),
+            '+' => Some(RepetitionOp::OneOrMore(Token![+](span))),
+            '?' => Some(RepetitionOp::ZeroOrOne(Token![?](span))),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for MacroRules {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(MacroRules {
+            rules: input.call(Punctuated::parse_terminated)?,
+        })
+    }
+}
+
+impl Parse for Rule {
+    fn parse(input: ParseStream) -> Result<Self> {
+        Ok(Rule {
+            matcher: parse_delimited(input, parse_matcher)?,
+            fat_arrow_token: input.parse()?,
+            transcriber: parse_delimited(input, parse_transcriber)?,
+        })
+    }
+}
+
+fn next_token(input: ParseStream) -> Result<TokenTree> {
+    input.step(|cursor| match cursor.token_tree() {
+        Some((token, rest)) => Ok((token, rest)),
+        None => Err(cursor.error("unexpected end of input")),
+    })
+}
+
+fn peek_token(input: ParseStream) -> Option<TokenTree> {
+    input.cursor().token_tree().map(|(token, _rest)| token)
+}
+
+fn parse_delimited<T>(
+    input: ParseStream,
+    parse_contents: fn(ParseStream) -> Result<Vec<T>>,
+) -> Result<Delimited<T>> {
+    match next_token(input)? {
+        TokenTree::Group(ref group) if group.delimiter() != Delimiter::None => {
+            nested(group, parse_contents)
+        }
+        token => Err(Error::new(token.span(), "expected `(`, `[` or `{`")),
+    }
+}
+
+fn nested<T>(
+    group: &Group,
+    parse_contents: fn(ParseStream) -> Result<Vec<T>>,
+) -> Result<Delimited<T>> {
+    Ok(Delimited {
+        delimiter: group.delimiter(),
+        span: group.span(),
+        tokens: parse::parse_scoped(parse_contents, group.span(), group.stream())?,
+    })
+}
+
+fn parse_matcher(input: ParseStream) -> Result<Vec<MatcherTree>> {
+    let mut trees = Vec::new();
+    while !input.is_empty() {
+        let tree = match next_token(input)? {
+            TokenTree::Group(group) => MatcherTree::Group(nested(&group, parse_matcher)?),
+            TokenTree::Punct(ref dollar) if dollar.as_char() == '$' => {
+                let dollar_token = Token![$](dollar.span());
+                match peek_token(input) {
+                    Some(TokenTree::Group(ref group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        MatcherTree::Repetition(parse_repetition(
+                            input,
+                            dollar_token,
+                            parse_matcher,
+                        )?)
+                    }
+                    Some(TokenTree::Ident(_)) => {
+                        MatcherTree::Fragment(parse_fragment(input, dollar_token)?)
+                    }
+                    _ => {
+                        return Err(Error::new(
+                            dollar.span(),
+                            "expected a fragment or repetition after `$`",
+                        ))
+                    }
+                }
+            }
+            token => MatcherTree::Token(token),
+        };
+        trees.push(tree);
+    }
+    Ok(trees)
+}
+
+fn parse_transcriber(input: ParseStream) -> Result<Vec<TranscriberTree>> {
+    let mut trees = Vec::new();
+    while !input.is_empty() {
+        let tree = match next_token(input)? {
+            TokenTree::Group(group) => TranscriberTree::Group(nested(&group, parse_transcriber)?),
+            TokenTree::Punct(ref dollar) if dollar.as_char() == '$' => {
+                let dollar_token = Token![$](dollar.span());
+                match peek_token(input) {
+                    Some(TokenTree::Group(ref group))
+                        if group.delimiter() == Delimiter::Parenthesis =>
+                    {
+                        TranscriberTree::Repetition(parse_repetition(
+                            input,
+                            dollar_token,
+                            parse_transcriber,
+                        )?)
+                    }
+                    Some(TokenTree::Ident(_)) => TranscriberTree::Variable(Variable {
+                        dollar_token,
+                        name: input.call(Ident::parse_any)?,
+                    }),
+                    _ => TranscriberTree::Token(TokenTree::Punct(dollar.clone())),
+                }
+            }
+            token => TranscriberTree::Token(token),
+        };
+        trees.push(tree);
+    }
+    Ok(trees)
+}
+
+fn parse_fragment(input: ParseStream, dollar_token: Token![$]) -> Result<Fragment> {
+    let name = input.call(Ident::parse_any)?;
+    if !input.peek(Token![:]) {
+        return Err(Error::new(name.span(), "missing fragment specifier"));
+    }
+    let colon_token = input.parse()?;
+    let specifier = input.call(Ident::parse_any)?;
+    let kind = match FragmentKind::from_ident(&specifier) {
+        Some(kind) => kind,
+        None => {
+            return Err(Error::new(
+                specifier.span(),
+                format!("invalid fragment specifier `{}`", specifier),
+            ))
+        }
+    };
+    Ok(Fragment {
+        dollar_token,
+        name,
+        colon_token,
+        kind,
+    })
+}
+
+fn parse_repetition<T>(
+    input: ParseStream,
+    dollar_token: Token![$],
+    parse_contents: fn(ParseStream) -> Result<Vec<T>>,
+) -> Result<Repetition<T>> {
+    let contents = parse_delimited(input, parse_contents)?;
+    let (separator, op) = input.step(|cursor| {
+        // The separator is a single token, or a run of joint punctuation like
+        // `=>`. A `*` or `+` is the operator unless another operator follows.
+        let mut rest = *cursor;
+        let mut separator = TokenStream::new();
+        let mut joint = true;
+        loop {
+            let (token, next) = match rest.token_tree() {
+                Some(token) => token,
+                None => return Err(cursor.error("expected one of: `*`, `+`, `?`")),
+            };
+            if let TokenTree::Punct(punct) = &token {
+                if let Some(op) = RepetitionOp::from_punct(punct) {
+                    let followed_by_op = match next.token_tree() {
+                        Some((TokenTree::Punct(next), _)) => {
+                            next.as_char() == '*' || next.as_char() == '+'
+                        }
+                        _ => false,
+                    };
+                    if !separator.is_empty() || !followed_by_op {
+                        let separator = if separator.is_empty() {
+                            None
+                        } else {
+                            Some(separator)
+                        };
+                        return Ok(((separator, op), next));
+                    }
+                }
+            }
+            if !joint {
+                return Err(Error::new(token.span(), "expected one of: `*`, `+`, `?`"));
+            }
+            joint = match &token {
+                TokenTree::Punct(punct) => punct.spacing() == Spacing::Joint,
+                _ => false,
+            };
+            separator.extend(iter::once(token));
+            rest = next;
+        }
+    })?;
+    Ok(Repetition {
+        dollar_token,
+        paren_token: token::Paren(contents.span),
+        tokens: contents.tokens,
+        separator,
+        op,
+    })
+}
+
+// The tokens bound to each metavariable, nested one level per repetition
+// the metavariable appears in.
+type Bindings = HashMap<String, Binding>;
+
+enum Binding {
+    Fragment(FragmentKind, TokenStream),
+    Repeated(Vec<Binding>),
+}
+
+fn match_trees(input: ParseStream, trees: &[MatcherTree], bindings: &mut Bindings) -> Result<()> {
+    for tree in trees {
+        match tree {
+            MatcherTree::Token(token) => match_token(input, token)?,
+            MatcherTree::Group(group) => {
+                let (tokens, span) = input.step(|cursor| match cursor.group(group.delimiter) {
+                    Some((content, span, rest)) => Ok(((content.token_stream(), span), rest)),
+                    None => Err(cursor.error(match group.delimiter {
+                        Delimiter::Parenthesis => "expected parentheses",
+                        Delimiter::Brace => "expected curly braces",
+                        Delimiter::Bracket => "expected square brackets",
+                        Delimiter::None => "expected invisible group",
+                    })),
+                })?;
+                let parser = |input: ParseStream| match_trees(input, &group.tokens, bindings);
+                parse::parse_scoped(parser, span, tokens)?;
+            }
+            MatcherTree::Fragment(fragment) => {
+                let tokens = match_fragment(input, fragment.kind)?;
+                bindings.insert(
+                    fragment.name.to_string(),
+                    Binding::Fragment(fragment.kind, tokens),
+                );
+            }
+            MatcherTree::Repetition(repetition) => match_repetition(input, repetition, bindings)?,
+        }
+    }
+    Ok(())
+}
+
+fn match_token(input: ParseStream, expected: &TokenTree) -> Result<()> {
+    input.step(|cursor| {
+        let rest = match expected {
+            TokenTree::Ident(expected) => match cursor.ident() {
+                Some((ident, rest)) if ident == *expected => Some(rest),
+                _ => None,
+            },
+            // The apostrophe of a lifetime is not returned by `punct`.
+            TokenTree::Punct(expected) if expected.as_char() == '\'' => match cursor.token_tree() {
+                Some((TokenTree::Punct(punct), rest)) if punct.as_char() == '\'' => Some(rest),
+                _ => None,
+            },
+            TokenTree::Punct(expected) => match cursor.punct() {
+                Some((punct, rest)) if punct.as_char() == expected.as_char() => Some(rest),
+                _ => None,
+            },
+            TokenTree::Literal(expected) => match cursor.literal() {
+                Some((lit, rest)) if lit.to_string() == expected.to_string() => Some(rest),
+                _ => None,
+            },
+            TokenTree::Group(_) => None,
+        };
+        match rest {
+            Some(rest) => Ok(((), rest)),
+            None => Err(cursor.error(format!("expected `{}`", expected))),
+        }
+    })
+}
+
+fn match_fragment(input: ParseStream, kind: FragmentKind) -> Result<TokenStream> {
+    let fork = input.fork();
+    parse_fragment_kind(&fork, kind)?;
+    let end = fork.cursor();
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        let mut tokens = TokenStream::new();
+        while !buffer::same_position(rest, end) {
+            match rest.token_tree() {
+                Some((token, next)) => {
+                    tokens.extend(iter::once(token));
+                    rest = next;
+                }
+                None => return Err(cursor.error("fragment ends inside an invisible group")),
+            }
+        }
+        Ok((tokens, rest))
+    })
+}
+
+fn parse_fragment_kind(input: ParseStream, kind: FragmentKind) -> Result<()> {
+    match kind {
+        FragmentKind::Block => input.parse::<Block>().map(drop),
+        FragmentKind::Expr => input.parse::<Expr>().map(drop),
+        FragmentKind::Ident => input.call(Ident::parse_any).map(drop),
+        FragmentKind::Item => input.parse::<Item>().map(drop),
+        FragmentKind::Lifetime => input.parse::<Lifetime>().map(drop),
+        FragmentKind::Literal => {
+            if input.peek(Token![-]) {
+                input.parse::<Token![-]>()?;
+            }
+            input.parse::<Lit>().map(drop)
+        }
+        FragmentKind::Meta => {
+            input.call(Path::parse_mod_style)?;
+            if input.peek(Token![=]) {
+                input.parse::<Token![=]>()?;
+                input.parse::<Expr>()?;
+            } else if input.peek(token::Paren)
+                || input.peek(token::Bracket)
+                || input.peek(token::Brace)
+            {
+                input.parse::<TokenTree>()?;
+            }
+            Ok(())
+        }
+        FragmentKind::Pat => input.parse::<Pat>().map(drop),
+        FragmentKind::Path => input.parse::<Path>().map(drop),
+        FragmentKind::Stmt => {
+            if input.peek(Token![let]) {
+                input.parse::<Token![let]>()?;
+                input.parse::<Pat>()?;
+                if input.peek(Token![:]) {
+                    input.parse::<Token![:]>()?;
+                    input.parse::<Type>()?;
+                }
+                if input.peek(Token![=]) {
+                    input.parse::<Token![=]>()?;
+                    input.parse::<Expr>()?;
+                }
+                Ok(())
+            } else if input.fork().parse::<Item>().is_ok() {
+                input.parse::<Item>().map(drop)
+            } else {
+                input.parse::<Expr>().map(drop)
+            }
+        }
+        FragmentKind::Tt => {
+            if input.peek(Lifetime) {
+                input.parse::<Lifetime>().map(drop)
+            } else {
+                input.parse::<TokenTree>().map(drop)
+            }
+        }
+        FragmentKind::Ty => input.parse::<Type>().map(drop),
+        FragmentKind::Vis => input.parse::<Visibility>().map(drop),
+    }
+}
+
+fn match_repetition(
+    input: ParseStream,
+    repetition: &Repetition<MatcherTree>,
+    bindings: &mut Bindings,
+) -> Result<()> {
+    let mut iterations = Vec::new();
+    loop {
+        let fork = input.fork();
+        if !iterations.is_empty() {
+            if let Some(separator) = &repetition.separator {
+                if separator
+                    .clone()
+                    .into_iter()
+                    .any(|token| match_token(&fork, &token).is_err())
+                {
+                    break;
+                }
+            }
+        }
+        let mut iteration = Bindings::new();
+        if match_trees(&fork, &repetition.tokens, &mut iteration).is_err()
+            || buffer::same_position(fork.cursor(), input.cursor())
+        {
+            break;
+        }
+        input.advance_to(&fork);
+        iterations.push(iteration);
+        if let RepetitionOp::ZeroOrOne(_) = repetition.op {
+            break;
+        }
+    }
+
+    if iterations.is_empty() {
+        if let RepetitionOp::OneOrMore(_) = repetition.op {
+            // Report why the first iteration failed to match.
+            match_trees(&input.fork(), &repetition.tokens, &mut Bindings::new())?;
+            return Err(input.error("expected at least one repetition"));
+        }
+    }
+
+    let mut names = Vec::new();
+    matcher_names(&repetition.tokens, &mut names);
+    for name in names {
+        let repeated = iterations
+            .iter_mut()
+            .filter_map(|iteration| iteration.remove(&name))
+            .collect();
+        bindings.insert(name, Binding::Repeated(repeated));
+    }
+    Ok(())
+}
+
+fn matcher_names(trees: &[MatcherTree], names: &mut Vec<String>) {
+    for tree in trees {
+        match tree {
+            MatcherTree::Token(_) => {}
+            MatcherTree::Group(group) => matcher_names(&group.tokens, names),
+            MatcherTree::Fragment(fragment) => names.push(fragment.name.to_string()),
+            MatcherTree::Repetition(repetition) => matcher_names(&repetition.tokens, names),
+        }
+    }
+}
+
+fn transcriber_names<'a>(trees: &'a [TranscriberTree], names: &mut Vec<&'a Ident>) {
+    for tree in trees {
+        match tree {
+            TranscriberTree::Token(_) => {}
+            TranscriberTree::Group(group) => transcriber_names(&group.tokens, names),
+            TranscriberTree::Variable(variable) => names.push(&variable.name),
+            TranscriberTree::Repetition(repetition) => transcriber_names(&repetition.tokens, names),
+        }
+    }
+}
+
+// Looks up the binding of a metavariable at the current iteration of each
+// enclosing repetition. A metavariable that repeats fewer times than it is
+// nested stays the same across the inner repetitions.
+fn lookup<'a>(bindings: &'a Bindings, name: &Ident, indices: &[usize]) -> Option<&'a Binding> {
+    let mut binding = bindings.get(&name.to_string())?;
+    for &index in indices {
+        match binding {
+            Binding::Fragment(..) => break,
+            Binding::Repeated(repeated) => binding = &repeated[index],
+        }
+    }
+    Some(binding)
+}
+
+fn transcribe_trees(
+    trees: &[TranscriberTree],
+    bindings: &Bindings,
+    indices: &mut Vec<usize>,
+    tokens: &mut TokenStream,
+) -> Result<()> {
+    for tree in trees {
+        match tree {
+            TranscriberTree::Token(token) => tokens.extend(iter::once(token.clone())),
+            TranscriberTree::Group(group) => {
+                let mut stream = TokenStream::new();
+                transcribe_trees(&group.tokens, bindings, indices, &mut stream)?;
+                let mut g = Group::new(group.delimiter, stream);
+                g.set_span(group.span);
+                tokens.extend(iter::once(TokenTree::Group(g)));
+            }
+            TranscriberTree::Variable(variable) => {
+                transcribe_variable(variable, bindings, indices, tokens)?
+            }
+            TranscriberTree::Repetition(repetition) => {
+                let mut names = Vec::new();
+                transcriber_names(&repetition.tokens, &mut names);
+                let mut count: Option<(usize, &Ident)> = None;
+                for name in names {
+                    if let Some(Binding::Repeated(repeated)) = lookup(bindings, name, indices) {
+                        match count {
+                            None => count = Some((repeated.len(), name)),
+                            Some((len, other)) if len != repeated.len() => {
+                                return Err(Error::new(
+                                    name.span(),
+                                    format!(
+                                        "meta-variable `{}` repeats {} times, but `{}` repeats {} times",
+                                        other,
+                                        len,
+                                        name,
+                                        repeated.len(),
+                                    ),
+                                ));
+                            }
+                            Some(_) => {}
+                        }
+                    }
+                }
+                let count = match count {
+                    Some((count, _)) => count,
+                    None => {
+                        return Err(Error::new(
+                            repetition.dollar_token.spans[0],
+                            "attempted to repeat an expression containing no syntax variables matched as repeating at this depth",
+                        ));
+                    }
+                };
+                for i in 0..count {
+                    if i > 0 {
+                        if let Some(separator) = &repetition.separator {
+                            tokens.extend(separator.clone());
+                        }
+                    }
+                    indices.push(i);
+                    transcribe_trees(&repetition.tokens, bindings, indices, tokens)?;
+                    indices.pop();
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+fn transcribe_variable(
+    variable: &Variable,
+    bindings: &Bindings,
+    indices: &[usize],
+    tokens: &mut TokenStream,
+) -> Result<()> {
+    if variable.name == "crate" {
+        let krate = Ident::new("crate", variable.name.span());
+        tokens.extend(iter::once(TokenTree::Ident(krate)));
+        return Ok(());
+    }
+    match lookup(bindings, &variable.name, indices) {
+        Some(Binding::Fragment(kind, stream)) => {
+            if kind.is_transparent() {
+                tokens.extend(stream.clone());
+            } else {
+                let group = Group::new(Delimiter::None, stream.clone());
+                tokens.extend(iter::once(TokenTree::Group(group)));
+            }
+            Ok(())
+        }
+        Some(Binding::Repeated(_)) => Err(Error::new(
+            variable.name.span(),
+            format!(
+                "variable `{}` is still repeating at this depth",
+                variable.name
+            ),
+        )),
+        None => {
+            // Not a metavariable of this rule; the compiler passes it through.
+            let mut dollar = Punct::new('$', Spacing::Alone);
+            dollar.set_span(variable.dollar_token.spans[0]);
+            tokens.extend(iter::once(TokenTree::Punct(dollar)));
+            tokens.extend(iter::once(TokenTree::Ident(variable.name.clone())));
+            Ok(())
+        }
+    }
+}
+
+#[cfg(feature = "printing")]
+mod printing {
+    use super::*;
+
+    use proc_macro2::{Group, TokenStream};
+    use quote::{ToTokens, TokenStreamExt};
+
+    impl ToTokens for MacroRules {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.rules.to_tokens(tokens);
+        }
+    }
+
+    impl ToTokens for Rule {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.matcher.to_tokens(tokens);
+            self.fat_arrow_token.to_tokens(tokens);
+            self.transcriber.to_tokens(tokens);
+        }
+    }
+
+    impl<T: ToTokens> ToTokens for Delimited<T> {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            let mut inner = TokenStream::new();
+            inner.append_all(&self.tokens);
+            let mut group = Group::new(self.delimiter, inner);
+            group.set_span(self.span);
+            tokens.append(group);
+        }
+    }
+
+    impl ToTokens for MatcherTree {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                MatcherTree::Token(token) => token.to_tokens(tokens),
+                MatcherTree::Group(group) => group.to_tokens(tokens),
+                MatcherTree::Fragment(fragment) => fragment.to_tokens(tokens),
+                MatcherTree::Repetition(repetition) => repetition.to_tokens(tokens),
+            }
+        }
+    }
+
+    impl ToTokens for TranscriberTree {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                TranscriberTree::Token(token) => token.to_tokens(tokens),
+                TranscriberTree::Group(group) => group.to_tokens(tokens),
+                TranscriberTree::Variable(variable) => variable.to_tokens(tokens),
+                TranscriberTree::Repetition(repetition) => repetition.to_tokens(tokens),
+            }
+        }
+    }
+
+    impl ToTokens for Fragment {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.dollar_token.to_tokens(tokens);
+            self.name.to_tokens(tokens);
+            self.colon_token.to_tokens(tokens);
+            tokens.append(Ident::new(self.kind.as_str(), self.name.span()));
+        }
+    }
+
+    impl ToTokens for Variable {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.dollar_token.to_tokens(tokens);
+            self.name.to_tokens(tokens);
+        }
+    }
+
+    impl<T: ToTokens> ToTokens for Repetition<T> {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            self.dollar_token.to_tokens(tokens);
+            self.paren_token.surround(tokens, |tokens| {
+                tokens.append_all(&self.tokens);
+            });
+            if let Some(separator) = &self.separator {
+                separator.to_tokens(tokens);
+            }
+            self.op.to_tokens(tokens);
+        }
+    }
+
+    impl ToTokens for RepetitionOp {
+        fn to_tokens(&self, tokens: &mut TokenStream) {
+            match self {
+                RepetitionOp::ZeroOrMore(op) => op.to_tokens(tokens),
+                RepetitionOp::OneOrMore(op) => op.to_tokens(tokens),
+                RepetitionOp::ZeroOrOne(op) => op.to_tokens(tokens),
+            }
+        }
+    }
+}
@@ -0,0 +1,359 @@
+//! Attribute content whose name-value pairs hold arbitrary tokens.
+//!
+//! [`Attribute::parse_meta`] only accepts a literal on the right of `=`, so
+//! attributes like `#[serde(default = path::to::function)]`, `#[attr(x = 1 +
+//! 2)]` or `#[attr(ty = Vec<u8>)]` fail to parse as a [`Meta`]. The types in
+//! this module mirror `Meta` but keep the value of each name-value pair as the
+//! raw tokens that follow the `=`, up to the next comma. The value can then be
+//! parsed as an [`Expr`], a [`Type`], a [`Path`] or anything else implementing
+//! [`Parse`].
+//!
+//! ```
+//! use syn::{parse_quote, Attribute, Path, Result, Type};
+//! use syn::meta::{NestedTokenMeta, TokenMeta};
+//!
+//! # fn main() -> Result<()> {
+//! let attr: Attribute = parse_quote! {
+//!     #[serde(default = path::to::function, with_type = Vec<u8>)]
+//! };
+//!
+//! if let TokenMeta::List(list) = attr.parse_meta_tokens()? {
+//!     for nested in &list.nested {
+//!         if let NestedTokenMeta::Meta(TokenMeta::NameValue(nv)) = nested {
+//!             if nv.path.is_ident("default") {
+//!                 let function: Path = nv.parse_value()?;
+//!                 assert_eq!(function.segments.len(), 3);
+//!             } else {
+//!                 let ty: Type = nv.parse_value()?;
+//!                 # let _ = ty;
+//!             }
+//!         }
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! A comma inside angle brackets, as in `HashMap<K, V>`, does not end the
+//! value. The shift operators `<<` and `<<=` do not open angle brackets, so a
+//! qualified path right after `<` needs a space, as in `Vec< <T as Trait>::A,
+//! B>`. A value containing a comparison followed by a comma, such as `x = a <
+//! b, c`, needs to be parenthesized.
+//!
+//! Any `TokenMeta` whose values are all literals converts to a `Meta` with
+//! [`TokenMeta::to_meta`], and every `Meta` converts to a `TokenMeta` with
+//! `From`.
+//!
+//! [`Attribute::parse_meta`]: ../struct.Attribute.html#method.parse_meta
+//! [`Meta`]: ../enum.Meta.html
+//! [`Expr`]: ../enum.Expr.html
+//! [`Type`]: ../enum.Type.html
+//! [`Path`]: ../struct.Path.html
+//! [`Parse`]: ../parse/trait.Parse.html
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature and with the `"parsing"` and `"printing"` features.*
+
+use std::iter;
+
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use crate::attr::parsing::parse_meta_path;
+use crate::ext::IdentExt;
+use crate::parse::{self, Parse, ParseStream, Parser, Result};
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// Content of an attribute, like [`Meta`] but with name-value pairs whose
+/// value is any sequence of tokens.
+///
+/// [`Meta`]: ../enum.Meta.html
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum TokenMeta {
+    /// A meta path is like the `test` in `#[test]`.
+    Path(Path),
+
+    /// A structured list within an attribute, like `serde(default = f)`.
+    List(TokenMetaList),
+
+    /// A name-value pair within an attribute, like `default = f`.
+    NameValue(TokenMetaNameValue),
+}
+
+/// A structured list within an attribute, like `serde(default = f)`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct TokenMetaList {
+    pub path: Path,
+    pub paren_token: token::Paren,
+    pub nested: Punctuated<NestedTokenMeta, Token![,]>,
+}
+
+/// A name-value pair within an attribute, like `default = path::to::f`.
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub struct TokenMetaNameValue {
+    pub path: Path,
+    pub eq_token: Token![=],
+    /// The tokens after the `=`, up to the next top-level comma. Never empty.
+    pub value: TokenStream,
+}
+
+/// Element of a [`TokenMetaList`].
+///
+/// [`TokenMetaList`]: struct.TokenMetaList.html
+#[cfg_attr(feature = "extra-traits", derive(Debug))]
+#[cfg_attr(feature = "clone-impls", derive(Clone))]
+pub enum NestedTokenMeta {
+    /// A structured meta item, like the `skip` in `#[serde(skip)]`.
+    Meta(TokenMeta),
+
+    /// A Rust literal, like the `"new_name"` in `#[rename("new_name")]`.
+    Lit(Lit),
+}
+
+/// Arguments of an attribute macro, like [`AttributeArgs`] but with
+/// name-value pairs whose value is any sequence of tokens.
+///
+/// ```
+/// extern crate proc_macro;
+///
+/// use proc_macro::TokenStream;
+/// use syn::meta::TokenAttributeArgs;
+/// use syn::{parse_macro_input, ItemFn};
+///
+/// # const IGNORE: &str = stringify! {
+/// #[proc_macro_attribute]
+/// # };
+/// pub fn my_attribute(args: TokenStream, input: TokenStream) -> TokenStream {
+///     let args = parse_macro_input!(args as TokenAttributeArgs);
+///     let input = parse_macro_input!(input as ItemFn);
+///
+///     /* ... */
+/// #   "".parse().unwrap()
+/// }
+/// ```
+///
+/// [`AttributeArgs`]: ../type.AttributeArgs.html
+pub type TokenAttributeArgs = Vec<NestedTokenMeta>;
+
+impl TokenMeta {
+    /// Returns the path that begins this structured meta item.
+    pub fn path(&self) -> &Path {
+        match self {
+            TokenMeta::Path(path) => path,
+            TokenMeta::List(meta) => &meta.path,
+            TokenMeta::NameValue(meta) => &meta.path,
+        }
+    }
+
+    /// Converts to a [`Meta`], failing at the first name-value pair whose
+    /// value is not a single literal.
+    ///
+    /// [`Meta`]: ../enum.Meta.html
+    pub fn to_meta(&self) -> Result<Meta> {
+        parse2(self.to_token_stream())
+    }
+}
+
+impl TokenMetaNameValue {
+    /// Parses the value as a syntax tree node of type `T`, such as an
+    /// [`Expr`], [`Type`] or [`Path`].
+    ///
+    /// The whole value must be consumed by the parser.
+    ///
+    /// [`Expr`]: ../enum.Expr.html
+    /// [`Type`]: ../enum.Type.html
+    /// [`Path`]: ../struct.Path.html
+    pub fn parse_value<T: Parse>(&self) -> Result<T> {
+        self.parse_value_with(T::parse)
+    }
+
+    /// Parses the value using the given parser.
+    pub fn parse_value_with<F: Parser>(&self, parser: F) -> Result<F::Output> {
+        parse::parse_scoped(parser, self.eq_token.spans[0], self.value.clone())
+    }
+}
+
+impl From<Meta> for TokenMeta {
+    fn from(meta: Meta) -> Self {
+        match meta {
+            Meta::Path(path) => TokenMeta::Path(path),
+            Meta::List(list) => TokenMeta::List(list.into()),
+            Meta::NameValue(nv) => TokenMeta::NameValue(nv.into()),
+        }
+    }
+}
+
+impl From<MetaList> for TokenMetaList {
+    fn from(list: MetaList) -> Self {
+        TokenMetaList {
+            path: list.path,
+            paren_token: list.paren_token,
+            nested: list
+                .nested
+                .into_pairs()
+                .map(|pair| {
+                    let (nested, punct) = pair.into_tuple();
+                    punctuated::Pair::new(nested.into(), punct)
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<MetaNameValue> for TokenMetaNameValue {
+    fn from(nv: MetaNameValue) -> Self {
+        TokenMetaNameValue {
+            path: nv.path,
+            eq_token: nv.eq_token,
+            value: nv.lit.into_token_stream(),
+        }
+    }
+}
+
+impl From<NestedMeta> for NestedTokenMeta {
+    fn from(nested: NestedMeta) -> Self {
+        match nested {
+            NestedMeta::Meta(meta) => NestedTokenMeta::Meta(meta.into()),
+            NestedMeta::Lit(lit) => NestedTokenMeta::Lit(lit),
+        }
+    }
+}
+
+pub(crate) fn parse_meta_after_path(path: Path, input: ParseStream) -> Result<TokenMeta> {
+    if input.peek(token::Paren) {
+        parse_meta_list_after_path(path, input).map(TokenMeta::List)
+    } else if input.peek(Token![=]) {
+        parse_meta_name_value_after_path(path, input).map(TokenMeta::NameValue)
+    } else {
+        Ok(TokenMeta::Path(path))
+    }
+}
+
+fn parse_meta_list_after_path(path: Path, input: ParseStream) -> Result<TokenMetaList> {
+    let content;
+    Ok(TokenMetaList {
+        path,
+        paren_token: parenthesized!(content in input),
+        nested: content.parse_terminated(NestedTokenMeta::parse)?,
+    })
+}
+
+fn parse_meta_name_value_after_path(path: Path, input: ParseStream) -> Result<TokenMetaNameValue> {
+    let eq_token: Token![=] = input.parse()?;
+    if input.is_empty() || input.peek(Token![,]) {
+        return Err(input.error("expected a value after `=`"));
+    }
+    Ok(TokenMetaNameValue {
+        path,
+        eq_token,
+        value: input.step(|cursor| {
+            let mut rest = *cursor;
+            let mut value = TokenStream::new();
+            // Depth of angle brackets, so that the comma in `Vec<T, A>` stays
+            // part of the value.
+            let mut depth = 0usize;
+            let mut prev = None;
+            // Whether the previous punct was the second `<` of a shift.
+            let mut shift = false;
+            while let Some((tt, next)) = rest.token_tree() {
+                if let TokenTree::Punct(punct) = &tt {
+                    let joint = |c| prev == Some((c, Spacing::Joint));
+                    let shift_op = punct.as_char() == '<' && joint('<') && !shift;
+                    match punct.as_char() {
+                        ',' if depth == 0 => break,
+                        // `<<` and `<<=` are shifts, so take back the opening
+                        // bracket counted for the first `<`.
+                        '<' if shift_op => depth -= 1,
+                        '<' => depth += 1,
+                        // `->` and `=>` are not closing brackets.
+                        '>' if depth > 0 && !joint('-') && !joint('=') => depth -= 1,
+                        // `<=` is a comparison, not an opening bracket.
+                        '=' if depth > 0 && joint('<') && !shift => depth -= 1,
+                        _ => {}
+                    }
+                    prev = Some((punct.as_char(), punct.spacing()));
+                    shift = shift_op;
+                } else {
+                    prev = None;
+                    shift = false;
+                }
+                value.extend(iter::once(tt));
+                rest = next;
+            }
+            Ok((value, rest))
+        })?,
+    })
+}
+
+impl Parse for TokenMeta {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.call(parse_meta_path)?;
+        parse_meta_after_path(path, input)
+    }
+}
+
+impl Parse for TokenMetaList {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.call(parse_meta_path)?;
+        parse_meta_list_after_path(path, input)
+    }
+}
+
+impl Parse for TokenMetaNameValue {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let path = input.call(parse_meta_path)?;
+        parse_meta_name_value_after_path(path, input)
+    }
+}
+
+impl Parse for NestedTokenMeta {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Lit) && !(input.peek(LitBool) && input.peek2(Token![=])) {
+            input.parse().map(NestedTokenMeta::Lit)
+        } else if input.peek(Ident::peek_any) {
+            input.parse().map(NestedTokenMeta::Meta)
+        } else {
+            Err(input.error("expected identifier or literal"))
+        }
+    }
+}
+
+impl ToTokens for TokenMeta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            TokenMeta::Path(path) => path.to_tokens(tokens),
+            TokenMeta::List(list) => list.to_tokens(tokens),
+            TokenMeta::NameValue(nv) => nv.to_tokens(tokens),
+        }
+    }
+}
+
+impl ToTokens for TokenMetaList {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+        self.paren_token.surround(tokens, |tokens| {
+            self.nested.to_tokens(tokens);
+        })
+    }
+}
+
+impl ToTokens for TokenMetaNameValue {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.path.to_tokens(tokens);
+        self.eq_token.to_tokens(tokens);
+        self.value.to_tokens(tokens);
+    }
+}
+
+impl ToTokens for NestedTokenMeta {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            NestedTokenMeta::Meta(meta) => meta.to_tokens(tokens),
+            NestedTokenMeta::Lit(lit) => lit.to_tokens(tokens),
+        }
+    }
+}
@@ -124,7 +124,7 @@ pub mod parsing {
         } else if input.peek(Token![>]) {
             input.parse().map(BinOp::Gt)
         } else {
-            Err(input.error("expected binary operator"))
+            Err(input.error_expected(&["binary operator"]))
         }
     }
 
@@ -191,7 +191,7 @@
 #[path = "discouraged.rs"]
 pub mod discouraged;
 
-use std::cell::Cell;
+use std::cell::{Cell, RefCell};
 use std::fmt::{self, Debug, Display};
 use std::marker::PhantomData;
 use std::mem;
@@ -207,7 +207,7 @@ use crate::proc_macro;
 use proc_macro2::{self, Delimiter, Group, Literal, Punct, Span, TokenStream, TokenTree};
 
 use crate::buffer::{Cursor, TokenBuffer};
-use crate::error;
+use crate::error::{self, ErrorKind};
 use crate::lookahead;
 use crate::punctuated::Punctuated;
 use crate::token::Token;
@@ -263,13 +263,25 @@ pub struct ParseBuffer<'a> {
     // the cell.
     cell: Cell<Cursor<'static>>,
     marker: PhantomData<Cursor<'a>>,
-    unexpected: Rc<Cell<Option<Span>>>,
+    unexpected: Unexpected,
+    // Errors recovered from so far, if parsing with error recovery.
+    recovery: Option<Rc<RefCell<Option<Error>>>>,
 }
 
+// The first token left unparsed at the end of a group, along with its source
+// text, shared between a parse buffer and the buffers of groups nested in it.
+pub(crate) type Unexpected = Rc<RefCell<Option<(Span, String)>>>;
+
 impl<'a> Drop for ParseBuffer<'a> {
     fn drop(&mut self) {
-        if !self.is_empty() && self.unexpected.get().is_none() {
-            self.unexpected.set(Some(self.cursor().span()));
+        // Nobody looks at the unexpected token of a buffer that does not share
+        // it, such as a fork, so skip describing the token in that case.
+        if !self.is_empty()
+            && Rc::strong_count(&self.unexpected) > 1
+            && self.unexpected.borrow().is_none()
+        {
+            let cursor = self.cursor();
+            *self.unexpected.borrow_mut() = Some((cursor.span(), error::found(cursor)));
         }
     }
 }
@@ -364,6 +376,11 @@ impl<'c, 'a> StepCursor<'c, 'a> {
     pub fn error<T: Display>(self, message: T) -> Error {
         error::new_at(self.scope, self.cursor, message)
     }
+
+    // Like `error`, for a parser that would have accepted any of `expected`.
+    pub(crate) fn error_expected(self, expected: &[&str]) -> Error {
+        error::new_expected(self.scope, self.cursor, expected)
+    }
 }
 
 pub(crate) fn advance_step_cursor<'c, 'a>(proof: StepCursor<'c, 'a>, to: Cursor<'c>) -> Cursor<'a> {
@@ -392,7 +409,8 @@ fn skip(input: ParseStream) -> bool {
 pub(crate) fn new_parse_buffer(
     scope: Span,
     cursor: Cursor,
-    unexpected: Rc<Cell<Option<Span>>>,
+    unexpected: Unexpected,
+    recovery: Option<Rc<RefCell<Option<Error>>>>,
 ) -> ParseBuffer {
     ParseBuffer {
         scope,
@@ -400,13 +418,18 @@ pub(crate) fn new_parse_buffer(
         cell: Cell::new(unsafe { mem::transmute::<Cursor, Cursor<'static>>(cursor) }),
         marker: PhantomData,
         unexpected,
+        recovery,
     }
 }
 
-pub(crate) fn get_unexpected(buffer: &ParseBuffer) -> Rc<Cell<Option<Span>>> {
+pub(crate) fn get_unexpected(buffer: &ParseBuffer) -> Unexpected {
     buffer.unexpected.clone()
 }
 
+pub(crate) fn get_recovery(buffer: &ParseBuffer) -> Option<Rc<RefCell<Option<Error>>>> {
+    buffer.recovery.clone()
+}
+
 impl<'a> ParseBuffer<'a> {
     /// Parses a syntax tree node of type `T`, advancing the position of our
     /// parse stream past it.
@@ -848,7 +871,10 @@ impl<'a> ParseBuffer<'a> {
             marker: PhantomData,
             // Not the parent's unexpected. Nothing cares whether the clone
             // parses all the way.
-            unexpected: Rc::new(Cell::new(None)),
+            unexpected: Rc::new(RefCell::new(None)),
+            // Speculative parses must fail the same way with or without error
+            // recovery.
+            recovery: None,
         }
     }
 
@@ -971,11 +997,29 @@ impl<'a> ParseBuffer<'a> {
     }
 
     fn check_unexpected(&self) -> Result<()> {
-        match self.unexpected.get() {
-            Some(span) => Err(Error::new(span, "unexpected token")),
+        match &*self.unexpected.borrow() {
+            Some((span, found)) => {
+                let kind = ErrorKind::UnexpectedToken {
+                    expected: Vec::new(),
+                    found: found.clone(),
+                };
+                Err(Error::new(*span, "unexpected token").with_kind(kind))
+            }
             None => Ok(()),
         }
     }
+
+    // Like `error`, for a parser that would have accepted any of `expected`.
+    #[cfg(any(feature = "full", feature = "derive"))]
+    pub(crate) fn error_expected(&self, expected: &[&str]) -> Error {
+        error::new_expected(self.scope, self.cursor(), expected)
+    }
+
+    // The error for tokens left over after a complete parse.
+    fn error_leftover(&self) -> Error {
+        let kind = error::expected_kind(self.cursor(), Vec::new());
+        self.error("unexpected token").with_kind(kind)
+    }
 }
 
 impl<T: Parse> Parse for Box<T> {
@@ -1004,7 +1048,7 @@ impl Parse for TokenTree {
     fn parse(input: ParseStream) -> Result<Self> {
         input.step(|cursor| match cursor.token_tree() {
             Some((tt, rest)) => Ok((tt, rest)),
-            None => Err(cursor.error("expected token tree")),
+            None => Err(cursor.error_expected(&["token tree"])),
         })
     }
 }
@@ -1019,7 +1063,7 @@ impl Parse for Group {
                     return Ok((group, rest));
                 }
             }
-            Err(cursor.error("expected group token"))
+            Err(cursor.error_expected(&["group token"]))
         })
     }
 }
@@ -1028,7 +1072,7 @@ impl Parse for Punct {
     fn parse(input: ParseStream) -> Result<Self> {
         input.step(|cursor| match cursor.punct() {
             Some((punct, rest)) => Ok((punct, rest)),
-            None => Err(cursor.error("expected punctuation token")),
+            None => Err(cursor.error_expected(&["punctuation token"])),
         })
     }
 }
@@ -1037,7 +1081,7 @@ impl Parse for Literal {
     fn parse(input: ParseStream) -> Result<Self> {
         input.step(|cursor| match cursor.literal() {
             Some((literal, rest)) => Ok((literal, rest)),
-            None => Err(cursor.error("expected literal token")),
+            None => Err(cursor.error_expected(&["literal token"])),
         })
     }
 }
@@ -1100,11 +1144,148 @@ pub trait Parser: Sized {
     }
 }
 
+// Which kind of syntax tree node to skip over when recovering from an error.
+#[cfg(feature = "full")]
+#[derive(Copy, Clone, PartialEq)]
+pub(crate) enum Boundary {
+    Item,
+    Stmt,
+}
+
+// Runs `parser`, and if it fails while parsing with error recovery, records the
+// error and skips from where the parser started to the next item or statement
+// boundary. The skipped tokens are turned into a placeholder node by
+// `verbatim`.
+#[cfg(feature = "full")]
+pub(crate) fn recover<T>(
+    input: ParseStream,
+    boundary: Boundary,
+    parser: fn(ParseStream) -> Result<T>,
+    verbatim: fn(TokenStream) -> T,
+) -> Result<T> {
+    let recovery = match &input.recovery {
+        Some(recovery) => recovery.clone(),
+        None => return parser(input),
+    };
+
+    let start = input.cursor();
+    let unexpected = input.unexpected.borrow().clone();
+    let err = match parser(input) {
+        Ok(node) => return Ok(node),
+        Err(err) => err,
+    };
+
+    // Tokens left over in groups by the failed parser are about to be skipped.
+    *input.unexpected.borrow_mut() = unexpected;
+    match &mut *recovery.borrow_mut() {
+        Some(errors) => errors.combine(err),
+        errors @ None => *errors = Some(err),
+    }
+
+    // See comment on `cell` in the struct definition.
+    input
+        .cell
+        .set(unsafe { mem::transmute::<Cursor, Cursor<'static>>(start) });
+    input.step(|cursor| {
+        let mut rest = *cursor;
+        let mut tokens = TokenStream::new();
+        let mut prev: Option<TokenTree> = None;
+        while let Some((tt, next)) = rest.token_tree() {
+            if prev.as_ref().map_or(false, ends_modifiers) && starts_item(rest, boundary) {
+                break;
+            }
+            let end = match &tt {
+                TokenTree::Punct(punct) => punct.as_char() == ';',
+                // The body of an item, unless a semicolon follows as in
+                // `use a::{b, c};`.
+                TokenTree::Group(group) => {
+                    boundary == Boundary::Item
+                        && group.delimiter() == Delimiter::Brace
+                        && next
+                            .punct()
+                            .map_or(true, |(punct, _)| punct.as_char() != ';')
+                }
+                _ => false,
+            };
+            tokens.extend(Some(tt.clone()));
+            prev = Some(tt);
+            rest = next;
+            if end {
+                break;
+            }
+        }
+        Ok((verbatim(tokens), rest))
+    })
+}
+
+// Whether an item keyword after this token would start a new item, as opposed
+// to continuing the current one as in `pub fn` or `x: fn()`.
+#[cfg(feature = "full")]
+fn ends_modifiers(tt: &TokenTree) -> bool {
+    match tt {
+        TokenTree::Ident(ident) => {
+            let modifiers = [
+                "async", "auto", "const", "default", "extern", "pub", "unsafe",
+            ];
+            !modifiers.iter().any(|modifier| ident == modifier)
+        }
+        TokenTree::Group(group) => group.delimiter() == Delimiter::Brace,
+        TokenTree::Punct(_) | TokenTree::Literal(_) => false,
+    }
+}
+
+#[cfg(feature = "full")]
+fn starts_item(cursor: Cursor, boundary: Boundary) -> bool {
+    if let Some((punct, _)) = cursor.punct() {
+        return punct.as_char() == '#';
+    }
+    let keywords = [
+        "const", "enum", "extern", "fn", "impl", "mod", "pub", "static", "struct", "trait", "type",
+        "union", "use",
+    ];
+    match cursor.ident() {
+        Some((ident, _)) => {
+            keywords.iter().any(|keyword| ident == keyword)
+                || boundary == Boundary::Stmt && ident == "let"
+        }
+        None => false,
+    }
+}
+
+#[cfg(feature = "full")]
+pub(crate) fn parse_recovering<T>(
+    parser: fn(ParseStream) -> Result<T>,
+    tokens: TokenStream,
+) -> Result<(T, Option<Error>)> {
+    let buf = TokenBuffer::new2(tokens);
+    let scope = Span::call_site();
+    let unexpected = Rc::new(RefCell::new(None));
+    let recovery = Rc::new(RefCell::new(None));
+    let state = new_parse_buffer(scope, buf.begin(), unexpected, Some(recovery.clone()));
+    let result = parser(&state).and_then(|node| {
+        state.check_unexpected()?;
+        if state.is_empty() {
+            Ok(node)
+        } else {
+            Err(state.error_leftover())
+        }
+    });
+    let errors = recovery.borrow_mut().take();
+    match (result, errors) {
+        (Ok(node), errors) => Ok((node, errors)),
+        (Err(err), None) => Err(err),
+        (Err(err), Some(mut errors)) => {
+            errors.combine(err);
+            Err(errors)
+        }
+    }
+}
+
 fn tokens_to_parse_buffer(tokens: &TokenBuffer) -> ParseBuffer {
     let scope = Span::call_site();
     let cursor = tokens.begin();
-    let unexpected = Rc::new(Cell::new(None));
-    new_parse_buffer(scope, cursor, unexpected)
+    let unexpected = Rc::new(RefCell::new(None));
+    new_parse_buffer(scope, cursor, unexpected, None)
 }
 
 impl<F, T> Parser for F
@@ -1121,7 +1302,7 @@ where
         if state.is_empty() {
             Ok(node)
         } else {
-            Err(state.error("unexpected token"))
+            Err(state.error_leftover())
         }
     }
 
@@ -1129,14 +1310,14 @@ where
     fn __parse_scoped(self, scope: Span, tokens: TokenStream) -> Result<Self::Output> {
         let buf = TokenBuffer::new2(tokens);
         let cursor = buf.begin();
-        let unexpected = Rc::new(Cell::new(None));
-        let state = new_parse_buffer(scope, cursor, unexpected);
+        let unexpected = Rc::new(RefCell::new(None));
+        let state = new_parse_buffer(scope, cursor, unexpected, None);
         let node = self(&state)?;
         state.check_unexpected()?;
         if state.is_empty() {
             Ok(node)
         } else {
-            Err(state.error("unexpected token"))
+            Err(state.error_leftover())
         }
     }
 
@@ -108,3 +108,27 @@ impl ParseMacroInput for AttributeArgs {
         Ok(metas)
     }
 }
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+use crate::meta::TokenAttributeArgs;
+
+#[cfg(all(any(feature = "full", feature = "derive"), feature = "printing"))]
+impl ParseMacroInput for TokenAttributeArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut metas = Vec::new();
+
+        loop {
+            if input.is_empty() {
+                break;
+            }
+            let value = input.parse()?;
+            metas.push(value);
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Token![,]>()?;
+        }
+
+        Ok(metas)
+    }
+}
@@ -391,6 +391,22 @@ mod parsing {
 
     impl Parse for Pat {
         fn parse(input: ParseStream) -> Result<Self> {
+            Pat::parse_single(input)
+        }
+    }
+
+    impl Pat {
+        /// Parses a pattern without alternatives separated by `|` at the top
+        /// level.
+        ///
+        /// This is what the compiler accepts as a function or closure
+        /// parameter, as in `fn f(PAT: T)` or `|PAT| ...`, where a `|` would be
+        /// ambiguous with the end of the closure parameters. It is also the
+        /// behavior of `Pat::parse`.
+        ///
+        /// *This function is available if Syn is built with the `"full"` and
+        /// `"parsing"` features.*
+        pub fn parse_single(input: ParseStream) -> Result<Self> {
             let lookahead = input.lookahead1();
             if lookahead.peek(Ident)
                 && ({
@@ -439,6 +455,84 @@ mod parsing {
                 Err(lookahead.error())
             }
         }
+
+        /// Parses a pattern that may have alternatives separated by `|` at the
+        /// top level, like `Some(0) | None`, but no leading `|`.
+        ///
+        /// Returns a [`Pat::Or`] if there is more than one alternative.
+        ///
+        /// *This function is available if Syn is built with the `"full"` and
+        /// `"parsing"` features.*
+        pub fn parse_multi(input: ParseStream) -> Result<Self> {
+            multi_pat_impl(input, None)
+        }
+
+        /// Parses a pattern that may have alternatives separated by `|` at the
+        /// top level, including a leading `|` before the first alternative as
+        /// in `| A | B`.
+        ///
+        /// This is what the compiler accepts in `match` arms, in `let`, `if
+        /// let` and `while let`, and in `for` loops. Returns a [`Pat::Or`] if
+        /// there is more than one alternative or a leading `|`.
+        ///
+        /// *This function is available if Syn is built with the `"full"` and
+        /// `"parsing"` features.*
+        ///
+        /// # Example
+        ///
+        /// ```
+        /// use syn::{Expr, Pat, Result, Token};
+        /// use syn::parse::{Parse, ParseStream};
+        ///
+        /// // Parse the input of a macro invoked as `matches!(expr, PAT)`.
+        /// struct Matches {
+        ///     expr: Expr,
+        ///     comma_token: Token![,],
+        ///     pat: Pat,
+        /// }
+        ///
+        /// impl Parse for Matches {
+        ///     fn parse(input: ParseStream) -> Result<Self> {
+        ///         Ok(Matches {
+        ///             expr: input.parse()?,
+        ///             comma_token: input.parse()?,
+        ///             pat: input.call(Pat::parse_multi_with_leading_vert)?,
+        ///         })
+        ///     }
+        /// }
+        ///
+        /// let input: Matches = syn::parse_str("c, | 'a'..='z' | 'A'..='Z'").unwrap();
+        /// match input.pat {
+        ///     Pat::Or(pat) => assert_eq!(pat.cases.len(), 2),
+        ///     _ => unreachable!(),
+        /// }
+        /// ```
+        pub fn parse_multi_with_leading_vert(input: ParseStream) -> Result<Self> {
+            let leading_vert: Option<Token![|]> = input.parse()?;
+            multi_pat_impl(input, leading_vert)
+        }
+    }
+
+    fn multi_pat_impl(input: ParseStream, leading_vert: Option<Token![|]>) -> Result<Pat> {
+        let mut pat = Pat::parse_single(input)?;
+        if leading_vert.is_some()
+            || input.peek(Token![|]) && !input.peek(Token![||]) && !input.peek(Token![|=])
+        {
+            let mut cases = Punctuated::new();
+            cases.push_value(pat);
+            while input.peek(Token![|]) && !input.peek(Token![||]) && !input.peek(Token![|=]) {
+                let punct = input.parse()?;
+                cases.push_punct(punct);
+                let pat = Pat::parse_single(input)?;
+                cases.push_value(pat);
+            }
+            pat = Pat::Or(PatOr {
+                attrs: Vec::new(),
+                leading_vert,
+                cases,
+            });
+        }
+        Ok(pat)
     }
 
     fn pat_path_or_macro_or_struct_or_range(input: ParseStream) -> Result<Pat> {
@@ -413,9 +413,9 @@ pub mod parsing {
                         segments.push_punct(punct);
                     }
                     if segments.is_empty() {
-                        return Err(input.error("expected path"));
+                        return Err(input.error_expected(&["path"]));
                     } else if segments.trailing_punct() {
-                        return Err(input.error("expected path segment"));
+                        return Err(input.error_expected(&["path segment"]));
                     }
                     segments
                 },
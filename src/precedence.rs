@@ -0,0 +1,357 @@
+//! Operator precedence of expressions.
+//!
+//! The precedence of an expression decides where it can appear as the
+//! operand of another without parentheses: `a + b` can be the left operand
+//! of `+` but not of `*`, since `a + b * c` means `a + (b * c)`.
+//!
+//! Syn's parser uses these levels to build the syntax tree, and printing a
+//! syntax tree with [`ToTokens`] uses them to add parentheses around any
+//! operand whose precedence is too low for its position. A tree built by
+//! hand like a `*` whose left operand is `a + b` therefore prints as
+//! `(a + b) * c`, which parses back into the same tree, rather than as
+//! `a + b * c`. Subexpressions that are already parenthesized in the syntax
+//! tree are printed as they are. Likewise an expression statement that begins
+//! with a block-like expression followed by an operator, like
+//! `match x {} - b;`, is printed in parentheses, since the statement would
+//! otherwise end after the block.
+//!
+//! An expression is likewise kept whole when it is interpolated into
+//! `quote!` or `parse_quote!`. One made of operators, like `a + b`, is printed
+//! inside of an invisible `None`-delimited group, which the parser reads back
+//! as a single operand: an [`Expr::Group`] with the `"full"` feature, or just
+//! the expression inside without it. Interpolating `sum` as `#sum * c`
+//! therefore means `(a + b) * c`, and printing the result adds the
+//! parentheses. The same goes for every other place such an expression is
+//! printed, so that with the `"full"` feature, parsing printed tokens back
+//! wraps each of them in an `Expr::Group`.
+//!
+//! [`ToTokens`]: quote::ToTokens
+//!
+//! *This module is available if Syn is built with the `"derive"` or `"full"`
+//! feature.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::precedence::Precedence;
+//! use syn::{BinOp, Expr};
+//!
+//! let sum: Expr = syn::parse_str("a + b").unwrap();
+//! let product: BinOp = syn::parse_str("*").unwrap();
+//! assert!(Precedence::of(&sum) < Precedence::of_binop(&product));
+//! ```
+//!
+//! ```
+//! use quote::quote;
+//! use syn::{parse_quote, Expr};
+//!
+//! let sum: Expr = parse_quote!(a + b);
+//! let product: Expr = parse_quote!(#sum * c);
+//! assert_eq!(quote!(#product).to_string(), "(a + b) * c");
+//!
+//! let negated: Expr = parse_quote!(-#sum);
+//! assert_eq!(quote!(#negated).to_string(), "- (a + b)");
+//! ```
+
+use crate::*;
+
+/// The precedence of an expression or operator, ordered from the loosest to
+/// the tightest binding.
+///
+/// *This type is available if Syn is built with the `"derive"` or `"full"`
+/// feature.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Precedence {
+    /// Lower than any expression. This is the precedence at which any
+    /// expression is accepted, like in a statement or function argument.
+    Any,
+    /// `return`, `break`, `yield` and closures, which extend as far to the
+    /// right as possible.
+    Jump,
+    /// `=` and compound assignment like `+=`.
+    Assign,
+    /// `..` and `..=`.
+    Range,
+    /// `||`
+    Or,
+    /// `&&`
+    And,
+    /// `==`, `!=`, `<`, `>`, `<=` and `>=`.
+    Compare,
+    /// `|`
+    BitOr,
+    /// `^`
+    BitXor,
+    /// `&`
+    BitAnd,
+    /// `<<` and `>>`.
+    Shift,
+    /// `+` and `-`.
+    Arithmetic,
+    /// `*`, `/` and `%`.
+    Term,
+    /// `as` and type ascription.
+    Cast,
+    /// Unary `-`, `!` and `*`, references and `box`.
+    Prefix,
+    /// Method calls, field access, function calls, indexing, `?` and
+    /// `.await`.
+    Postfix,
+    /// Literals, paths, blocks, and anything delimited, which can appear
+    /// anywhere without parentheses.
+    Unambiguous,
+}
+
+impl Precedence {
+    /// The precedence of an expression, which is that of its outermost
+    /// operator.
+    ///
+    /// An [`Expr::Group`] has the precedence of the expression inside of it,
+    /// since its delimiters disappear when the tokens are printed as text.
+    pub fn of(expr: &Expr) -> Self {
+        match expr {
+            #[cfg(feature = "full")]
+            Expr::Group(expr) => Precedence::of(&expr.expr),
+            Expr::Closure(_) | Expr::Return(_) | Expr::Break(_) | Expr::Yield(_) | Expr::Let(_) => {
+                Precedence::Jump
+            }
+            Expr::Assign(_) | Expr::AssignOp(_) => Precedence::Assign,
+            Expr::Range(_) => Precedence::Range,
+            Expr::Binary(expr) => Precedence::of_binop(&expr.op),
+            Expr::Cast(_) | Expr::Type(_) => Precedence::Cast,
+            Expr::Box(_) | Expr::Reference(_) | Expr::Unary(_) => Precedence::Prefix,
+            Expr::Await(_)
+            | Expr::Call(_)
+            | Expr::MethodCall(_)
+            | Expr::Field(_)
+            | Expr::Index(_)
+            | Expr::Try(_) => Precedence::Postfix,
+            _ => Precedence::Unambiguous,
+        }
+    }
+
+    /// The precedence of a binary operator.
+    pub fn of_binop(op: &BinOp) -> Self {
+        match op {
+            BinOp::Add(_) | BinOp::Sub(_) => Precedence::Arithmetic,
+            BinOp::Mul(_) | BinOp::Div(_) | BinOp::Rem(_) => Precedence::Term,
+            BinOp::And(_) => Precedence::And,
+            BinOp::Or(_) => Precedence::Or,
+            BinOp::BitXor(_) => Precedence::BitXor,
+            BinOp::BitAnd(_) => Precedence::BitAnd,
+            BinOp::BitOr(_) => Precedence::BitOr,
+            BinOp::Shl(_) | BinOp::Shr(_) => Precedence::Shift,
+            BinOp::Eq(_)
+            | BinOp::Lt(_)
+            | BinOp::Le(_)
+            | BinOp::Ne(_)
+            | BinOp::Ge(_)
+            | BinOp::Gt(_) => Precedence::Compare,
+            BinOp::AddEq(_)
+            | BinOp::SubEq(_)
+            | BinOp::MulEq(_)
+            | BinOp::DivEq(_)
+            | BinOp::RemEq(_)
+            | BinOp::BitXorEq(_)
+            | BinOp::BitAndEq(_)
+            | BinOp::BitOrEq(_)
+            | BinOp::ShlEq(_)
+            | BinOp::ShrEq(_) => Precedence::Assign,
+        }
+    }
+}
+
+// Whether an expression is printed inside of invisible delimiters, which is
+// the case for expressions made of operators that something interpolated next
+// to them could take apart. A `let` is left bare, since it can only appear
+// where it is parsed specially, like in the condition of an `if`.
+#[cfg(feature = "printing")]
+pub(crate) fn needs_group(expr: &Expr) -> bool {
+    match expr {
+        Expr::Group(_) | Expr::Let(_) => false,
+        _ => Precedence::of(expr) < Precedence::Postfix,
+    }
+}
+
+// Whether the left operand of a binary operator of the given precedence needs
+// parentheses. Assignment is right-associative, comparisons and ranges do
+// not associate at all, and the other operators are left-associative.
+//
+// An operand ending in a closure or `return`, including one behind a prefix
+// operator like `-return a`, also needs them, since the closure body or
+// returned value would otherwise take in the operator, and so does an operand
+// ending in a cast if the operator begins with `<`.
+#[cfg(feature = "printing")]
+pub(crate) fn left_needs_parens(precedence: Precedence, operand: &Expr, before_lt: bool) -> bool {
+    let operand_precedence = Precedence::of(operand);
+    let too_low = match precedence {
+        Precedence::Assign | Precedence::Compare | Precedence::Range => {
+            operand_precedence <= precedence
+        }
+        _ => operand_precedence < precedence,
+    };
+    too_low
+        || match Precedence::of(rightmost(operand)) {
+            Precedence::Jump => true,
+            Precedence::Cast => before_lt,
+            _ => false,
+        }
+}
+
+// Whether the right operand of a binary operator of the given precedence needs
+// parentheses. A closure or `return` does not, since it takes in everything
+// to its right anyway.
+#[cfg(feature = "printing")]
+pub(crate) fn right_needs_parens(precedence: Precedence, operand: &Expr) -> bool {
+    let operand_precedence = Precedence::of(operand);
+    match precedence {
+        _ if operand_precedence == Precedence::Jump => false,
+        Precedence::Assign => operand_precedence < precedence,
+        _ => operand_precedence <= precedence,
+    }
+}
+
+// Whether the operand of a prefix operator like `-`, `&` or `box` needs
+// parentheses. As on the right of a binary operator, a closure or `return`
+// does not.
+#[cfg(feature = "printing")]
+pub(crate) fn prefix_needs_parens(operand: &Expr) -> bool {
+    let operand_precedence = Precedence::of(operand);
+    operand_precedence < Precedence::Prefix && operand_precedence != Precedence::Jump
+}
+
+// Whether the left operand of a binary operator needs parentheses, as for
+// `left_needs_parens`, given the operator itself.
+#[cfg(feature = "printing")]
+pub(crate) fn binary_left_needs_parens(op: &BinOp, operand: &Expr) -> bool {
+    // `a as usize < b` would start generic arguments of `usize`.
+    let before_lt = match op {
+        BinOp::Lt(_) | BinOp::Shl(_) => true,
+        _ => false,
+    };
+    left_needs_parens(Precedence::of_binop(op), operand, before_lt)
+}
+
+// Whether an expression in statement position needs parentheses because it
+// begins with a block-like expression such as `match x {}` followed by an
+// operator. The statement would otherwise end after the block, so that
+// `match x {} - 1;` parses as two statements. A method call, field access,
+// `.await` or `?` can follow the block without ending the statement, and so
+// can anything after those.
+#[cfg(all(feature = "full", feature = "printing"))]
+pub(crate) fn stmt_needs_parens(expr: &Expr) -> bool {
+    let (needs_parens, left, continues) = match expr {
+        Expr::Binary(expr) => (
+            binary_left_needs_parens(&expr.op, &expr.left),
+            &*expr.left,
+            false,
+        ),
+        Expr::Assign(expr) => (
+            left_needs_parens(Precedence::Assign, &expr.left, false),
+            &*expr.left,
+            false,
+        ),
+        Expr::AssignOp(expr) => (
+            left_needs_parens(Precedence::Assign, &expr.left, false),
+            &*expr.left,
+            false,
+        ),
+        Expr::Range(ExprRange {
+            from: Some(from), ..
+        }) => (
+            left_needs_parens(Precedence::Range, from, false),
+            &**from,
+            false,
+        ),
+        Expr::Cast(expr) => (
+            left_needs_parens(Precedence::Cast, &expr.expr, false),
+            &*expr.expr,
+            false,
+        ),
+        Expr::Type(expr) => (
+            left_needs_parens(Precedence::Cast, &expr.expr, false),
+            &*expr.expr,
+            false,
+        ),
+        Expr::Call(expr) => (
+            match *expr.func {
+                Expr::Field(_) => true,
+                _ => Precedence::of(&expr.func) < Precedence::Postfix,
+            },
+            &*expr.func,
+            false,
+        ),
+        Expr::Index(expr) => (
+            Precedence::of(&expr.expr) < Precedence::Postfix,
+            &*expr.expr,
+            false,
+        ),
+        Expr::MethodCall(expr) => (
+            Precedence::of(&expr.receiver) < Precedence::Postfix,
+            &*expr.receiver,
+            true,
+        ),
+        Expr::Field(expr) => (
+            Precedence::of(&expr.base) < Precedence::Postfix,
+            &*expr.base,
+            true,
+        ),
+        Expr::Await(expr) => (
+            Precedence::of(&expr.base) < Precedence::Postfix,
+            &*expr.base,
+            true,
+        ),
+        Expr::Try(expr) => (
+            Precedence::of(&expr.expr) < Precedence::Postfix,
+            &*expr.expr,
+            true,
+        ),
+        _ => return false,
+    };
+    if needs_parens {
+        false
+    } else if crate::expr::requires_terminator(left) {
+        stmt_needs_parens(left)
+    } else {
+        !continues
+    }
+}
+
+// The operand printed last in an expression, if it is printed without
+// parentheses.
+#[cfg(feature = "printing")]
+fn rightmost(expr: &Expr) -> &Expr {
+    let (needs_parens, right) = match expr {
+        Expr::Binary(expr) => {
+            let precedence = Precedence::of_binop(&expr.op);
+            (right_needs_parens(precedence, &expr.right), &*expr.right)
+        }
+        #[cfg(feature = "full")]
+        Expr::Assign(expr) => (
+            right_needs_parens(Precedence::Assign, &expr.right),
+            &*expr.right,
+        ),
+        #[cfg(feature = "full")]
+        Expr::AssignOp(expr) => (
+            right_needs_parens(Precedence::Assign, &expr.right),
+            &*expr.right,
+        ),
+        #[cfg(feature = "full")]
+        Expr::Range(ExprRange { to: Some(to), .. }) => {
+            (right_needs_parens(Precedence::Range, to), &**to)
+        }
+        #[cfg(feature = "full")]
+        Expr::Group(expr) => (false, &*expr.expr),
+        Expr::Unary(expr) => (prefix_needs_parens(&expr.expr), &*expr.expr),
+        #[cfg(feature = "full")]
+        Expr::Reference(expr) => (prefix_needs_parens(&expr.expr), &*expr.expr),
+        #[cfg(feature = "full")]
+        Expr::Box(expr) => (prefix_needs_parens(&expr.expr), &*expr.expr),
+        _ => return expr,
+    };
+    if needs_parens {
+        expr
+    } else {
+        rightmost(right)
+    }
+}
@@ -0,0 +1,2707 @@
+//! Pretty-printing of a syntax tree as formatted Rust source code.
+//!
+//! The [`ToTokens`] impls of the syntax tree produce a `TokenStream`, whose
+//! `Display` representation is a single long line with a space between every
+//! pair of tokens. That is fine for handing tokens back to the compiler from a
+//! procedural macro but not for code generators that write `.rs` files to
+//! disk. The functions in this module instead render a syntax tree node as
+//! indented, line-wrapped source code close to the style produced by rustfmt.
+//!
+//! [`ToTokens`]: quote::ToTokens
+//!
+//! *This module is available if Syn is built with the `"full"` and
+//! `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::File;
+//!
+//! let tokens = quote! {
+//!     /// Adds one.
+//!     pub fn add_one(x: u32) -> u32 { x + 1 }
+//! };
+//!
+//! let file: File = syn::parse2(tokens).unwrap();
+//! assert_eq!(
+//!     syn::pretty::to_string(&file),
+//!     "/// Adds one.\npub fn add_one(x: u32) -> u32 {\n    x + 1\n}\n",
+//! );
+//! ```
+//!
+//! # Limitations
+//!
+//! A syntax tree does not record comments (other than doc comments) or blank
+//! lines, so these cannot be reproduced. The bodies of macro invocations are
+//! printed from their tokens using heuristics rather than any knowledge of the
+//! macro's grammar.
+
+use std::iter;
+use std::mem;
+
+use proc_macro2::{Delimiter, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use crate::punctuated::Punctuated;
+use crate::*;
+
+/// Maximum width of a line before the printer starts breaking lists.
+const MARGIN: usize = 100;
+
+/// Number of spaces per level of indentation.
+const INDENT: usize = 4;
+
+/// Maximum width of an enum variant with named fields to be kept on one line.
+const STRUCT_VARIANT_WIDTH: usize = 35;
+
+/// A syntax tree node that can be rendered as formatted source code.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub trait Pretty: private::Sealed {
+    #[doc(hidden)]
+    fn pretty(&self, printer: &mut Printer);
+}
+
+/// Renders a syntax tree node as formatted Rust source code.
+///
+/// Items and files are terminated by a trailing newline. Expressions, types
+/// and patterns are not.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub fn to_string<T: Pretty + ?Sized>(node: &T) -> String {
+    let mut printer = Printer::new();
+    node.pretty(&mut printer);
+    printer.finish()
+}
+
+/// Renders a complete file of Rust source code.
+///
+/// Equivalent to `pretty::to_string(file)`.
+///
+/// *This function is available if Syn is built with the `"full"` and
+/// `"printing"` features.*
+pub fn unparse(file: &File) -> String {
+    to_string(file)
+}
+
+/// Output buffer and layout state of an in-progress pretty-print.
+///
+/// This type is only used as the argument of [`Pretty::pretty`] and does not
+/// have any public methods.
+pub struct Printer {
+    out: String,
+    indent: usize,
+    // Column at which `out` begins, used when rendering a trial layout in the
+    // middle of a line.
+    base: usize,
+    // In flat mode, lists are never broken across lines. Only the statements
+    // of a block are placed on their own lines.
+    flat: bool,
+    // Set during a trial rendering that turned out to be unacceptable on one
+    // line regardless of its width.
+    rejected: bool,
+}
+
+impl Printer {
+    fn new() -> Self {
+        Printer {
+            out: String::new(),
+            indent: 0,
+            base: 0,
+            flat: false,
+            rejected: false,
+        }
+    }
+
+    fn finish(mut self) -> String {
+        self.trim_trailing_spaces();
+        self.out
+    }
+
+    fn word(&mut self, word: &str) {
+        self.out.push_str(word);
+    }
+
+    fn space(&mut self) {
+        self.out.push(' ');
+    }
+
+    fn nl(&mut self) {
+        self.trim_trailing_spaces();
+        self.out.push('\n');
+        self.out
+            .extend(iter::repeat(' ').take(self.indent * INDENT));
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn column(&self) -> usize {
+        match self.out.rfind('\n') {
+            Some(i) => self.out[i + 1..].chars().count(),
+            None => self.base + self.out.chars().count(),
+        }
+    }
+
+    fn at_line_start(&self) -> bool {
+        self.out[self.out.rfind('\n').map_or(0, |i| i + 1)..]
+            .chars()
+            .all(|ch| ch == ' ')
+    }
+
+    // Renders `f` starting at the current position without breaking any lists.
+    fn trial<F: FnOnce(&mut Printer)>(&self, f: F) -> Printer {
+        let mut printer = Printer {
+            out: String::new(),
+            indent: self.indent,
+            base: self.column(),
+            flat: true,
+            rejected: false,
+        };
+        f(&mut printer);
+        printer
+    }
+
+    // Rejects the current trial rendering if what was printed since `start`
+    // spilled onto another line.
+    fn reject_multiline_since(&mut self, start: usize) {
+        if self.out[start..].contains('\n') {
+            self.rejected = true;
+        }
+    }
+
+    // Whether the first line of `text` fits within the margin when appended at
+    // the current position.
+    fn fits(&self, text: &str) -> bool {
+        self.fits_reserving(0, text)
+    }
+
+    fn fits_reserving(&self, reserve: usize, text: &str) -> bool {
+        let first_line = text.split('\n').next().unwrap_or("");
+        self.column() + first_line.chars().count() + reserve <= MARGIN
+    }
+
+    // Appends the trial rendering if it fits, otherwise renders `broken`.
+    fn choose<F, B>(&mut self, flat: F, broken: B)
+    where
+        F: FnOnce(&mut Printer),
+        B: FnOnce(&mut Printer),
+    {
+        self.choose_reserving(0, flat, broken);
+    }
+
+    // Like `choose`, but requires `reserve` columns to remain free after the
+    // trial rendering for whatever is printed next.
+    fn choose_reserving<F, B>(&mut self, reserve: usize, flat: F, broken: B)
+    where
+        F: FnOnce(&mut Printer),
+        B: FnOnce(&mut Printer),
+    {
+        if self.flat {
+            flat(self);
+            return;
+        }
+        let trial = self.trial(flat);
+        if !trial.rejected && self.fits_reserving(reserve, &trial.out) {
+            self.out.push_str(&trial.out);
+        } else {
+            broken(self);
+        }
+    }
+
+    // Renders `f` with lists allowed to break again, as inside a block body.
+    fn unflat<F: FnOnce(&mut Printer)>(&mut self, f: F) {
+        let flat = self.flat;
+        self.flat = false;
+        f(self);
+        self.flat = flat;
+    }
+
+    fn comma_list<T, F>(&mut self, open: &str, elems: &[&T], close: &str, trailing: bool, f: F)
+    where
+        F: Fn(&mut Printer, &T),
+    {
+        self.choose(
+            |p| {
+                p.word(open);
+                let start = p.out.len();
+                for (i, elem) in elems.iter().enumerate() {
+                    if i > 0 {
+                        p.reject_multiline_since(start);
+                        p.word(", ");
+                    }
+                    f(p, elem);
+                }
+                if trailing && elems.len() == 1 {
+                    p.word(",");
+                }
+                p.word(close);
+            },
+            |p| {
+                p.word(open);
+                p.indent += 1;
+                for elem in elems {
+                    p.nl();
+                    f(p, elem);
+                    p.word(",");
+                }
+                p.indent -= 1;
+                p.nl();
+                p.word(close);
+            },
+        );
+    }
+
+    // ---------------------------------------------------------------------
+    // Attributes
+
+    fn outer_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs {
+            if let AttrStyle::Outer = attr.style {
+                self.attr(attr);
+                self.nl();
+            }
+        }
+    }
+
+    fn outer_attrs_inline(&mut self, attrs: &[Attribute]) {
+        for attr in attrs {
+            if let AttrStyle::Outer = attr.style {
+                self.attr(attr);
+                self.space();
+            }
+        }
+    }
+
+    fn inner_attrs(&mut self, attrs: &[Attribute]) {
+        for attr in attrs {
+            if let AttrStyle::Inner(_) = attr.style {
+                self.nl();
+                self.attr(attr);
+            }
+        }
+    }
+
+    fn has_inner_attrs(attrs: &[Attribute]) -> bool {
+        attrs.iter().any(|attr| match attr.style {
+            AttrStyle::Inner(_) => true,
+            AttrStyle::Outer => false,
+        })
+    }
+
+    fn attr(&mut self, attr: &Attribute) {
+        if let Some(doc) = doc_comment(attr) {
+            let inner = match attr.style {
+                AttrStyle::Outer => false,
+                AttrStyle::Inner(_) => true,
+            };
+            write_doc_comment(self, inner, &doc);
+            return;
+        }
+        self.word("#");
+        if let AttrStyle::Inner(_) = attr.style {
+            self.word("!");
+        }
+        self.word("[");
+        self.path(&attr.path);
+        let mut tokens = TokenPrinter::after(Prev::Word);
+        tokens.stream(self, &attr.tokens);
+        self.word("]");
+    }
+
+    // ---------------------------------------------------------------------
+    // Items
+
+    fn file(&mut self, file: &File) {
+        if let Some(shebang) = &file.shebang {
+            self.word(shebang);
+            self.nl();
+        }
+        let mut first = true;
+        for attr in &file.attrs {
+            if let AttrStyle::Inner(_) = attr.style {
+                if !first {
+                    self.nl();
+                }
+                self.attr(attr);
+                first = false;
+            }
+        }
+        if !first && !file.items.is_empty() {
+            self.nl();
+            self.nl();
+        }
+        self.items(&file.items);
+        if !self.out.is_empty() {
+            self.nl();
+        }
+    }
+
+    fn items(&mut self, items: &[Item]) {
+        for (i, item) in items.iter().enumerate() {
+            if i > 0 {
+                self.nl();
+                if !(is_use_like(&items[i - 1]) && is_use_like(item)) {
+                    self.nl();
+                }
+            }
+            self.item(item);
+        }
+    }
+
+    fn item(&mut self, item: &Item) {
+        match item {
+            Item::Const(item) => self.item_const(item),
+            Item::Enum(item) => self.item_enum(item),
+            Item::ExternCrate(item) => self.item_extern_crate(item),
+            Item::Fn(item) => self.item_fn(item),
+            Item::ForeignMod(item) => self.item_foreign_mod(item),
+            Item::Impl(item) => self.item_impl(item),
+            Item::Macro(item) => self.item_macro(item),
+            Item::Macro2(item) => self.item_macro2(item),
+            Item::Mod(item) => self.item_mod(item),
+            Item::Static(item) => self.item_static(item),
+            Item::Struct(item) => self.item_struct(item),
+            Item::Trait(item) => self.item_trait(item),
+            Item::TraitAlias(item) => self.item_trait_alias(item),
+            Item::Type(item) => self.item_type(item),
+            Item::Union(item) => self.item_union(item),
+            Item::Use(item) => self.item_use(item),
+            Item::Verbatim(tokens) => self.verbatim(tokens),
+            Item::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn item_const(&mut self, item: &ItemConst) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("const ");
+        self.ident(&item.ident);
+        self.word(": ");
+        self.ty(&item.ty);
+        self.word(" = ");
+        self.expr(&item.expr);
+        self.word(";");
+    }
+
+    fn item_enum(&mut self, item: &ItemEnum) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("enum ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_for_body(&item.generics.where_clause);
+        self.word("{");
+        self.indent += 1;
+        for variant in &item.variants {
+            self.nl();
+            self.variant(variant);
+            self.word(",");
+        }
+        self.indent -= 1;
+        if !item.variants.is_empty() {
+            self.nl();
+        }
+        self.word("}");
+    }
+
+    fn variant(&mut self, variant: &Variant) {
+        self.outer_attrs(&variant.attrs);
+        self.ident(&variant.ident);
+        match &variant.fields {
+            Fields::Named(fields) => {
+                self.space();
+                if fields.named.iter().any(|field| !field.attrs.is_empty()) {
+                    self.fields_named(fields);
+                } else {
+                    let flat = self.trial(|p| p.fields_named_flat(fields)).out;
+                    if flat.len() <= STRUCT_VARIANT_WIDTH && !flat.contains('\n') {
+                        self.word(&flat);
+                    } else {
+                        self.fields_named(fields);
+                    }
+                }
+            }
+            Fields::Unnamed(fields) => self.fields_unnamed(fields),
+            Fields::Unit => {}
+        }
+        if let Some((_eq_token, discriminant)) = &variant.discriminant {
+            self.word(" = ");
+            self.expr(discriminant);
+        }
+    }
+
+    fn item_extern_crate(&mut self, item: &ItemExternCrate) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("extern crate ");
+        self.ident(&item.ident);
+        if let Some((_as_token, rename)) = &item.rename {
+            self.word(" as ");
+            self.ident(rename);
+        }
+        self.word(";");
+    }
+
+    fn item_fn(&mut self, item: &ItemFn) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.signature(&item.sig);
+        self.where_clause_for_body(&item.sig.generics.where_clause);
+        self.block_with_inner_attrs(&item.block, &item.attrs);
+    }
+
+    fn item_foreign_mod(&mut self, item: &ItemForeignMod) {
+        self.outer_attrs(&item.attrs);
+        self.abi(&item.abi);
+        self.word("{");
+        self.indent += 1;
+        self.inner_attrs(&item.attrs);
+        for foreign_item in &item.items {
+            self.nl();
+            self.foreign_item(foreign_item);
+        }
+        self.indent -= 1;
+        if Self::has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.nl();
+        }
+        self.word("}");
+    }
+
+    fn item_impl(&mut self, item: &ItemImpl) {
+        self.outer_attrs(&item.attrs);
+        if item.defaultness.is_some() {
+            self.word("default ");
+        }
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        self.word("impl");
+        self.generics(&item.generics);
+        self.space();
+        if let Some((negative_polarity, path, _for_token)) = &item.trait_ {
+            if negative_polarity.is_some() {
+                self.word("!");
+            }
+            self.path(path);
+            self.word(" for ");
+        }
+        self.ty(&item.self_ty);
+        self.where_clause_for_body(&item.generics.where_clause);
+        self.word("{");
+        self.indent += 1;
+        self.inner_attrs(&item.attrs);
+        for (i, impl_item) in item.items.iter().enumerate() {
+            if i > 0 || Self::has_inner_attrs(&item.attrs) {
+                self.nl();
+            }
+            self.nl();
+            self.impl_item(impl_item);
+        }
+        self.indent -= 1;
+        if Self::has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.nl();
+        }
+        self.word("}");
+    }
+
+    fn item_macro(&mut self, item: &ItemMacro) {
+        self.outer_attrs(&item.attrs);
+        self.path(&item.mac.path);
+        self.word("!");
+        if let Some(ident) = &item.ident {
+            self.space();
+            self.ident(ident);
+        }
+        self.mac_body(&item.mac, true);
+        if item.semi_token.is_some() {
+            self.word(";");
+        }
+    }
+
+    fn item_macro2(&mut self, item: &ItemMacro2) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("macro ");
+        self.ident(&item.ident);
+        let mut tokens = TokenPrinter::after(Prev::Word);
+        tokens.stream(self, &item.rules);
+    }
+
+    fn item_mod(&mut self, item: &ItemMod) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("mod ");
+        self.ident(&item.ident);
+        match &item.content {
+            Some((_brace_token, items)) => {
+                self.word(" {");
+                self.indent += 1;
+                self.inner_attrs(&item.attrs);
+                if Self::has_inner_attrs(&item.attrs) && !items.is_empty() {
+                    self.nl();
+                }
+                if !items.is_empty() {
+                    self.nl();
+                    self.items(items);
+                }
+                self.indent -= 1;
+                if Self::has_inner_attrs(&item.attrs) || !items.is_empty() {
+                    self.nl();
+                }
+                self.word("}");
+            }
+            None => self.word(";"),
+        }
+    }
+
+    fn item_static(&mut self, item: &ItemStatic) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("static ");
+        if item.mutability.is_some() {
+            self.word("mut ");
+        }
+        self.ident(&item.ident);
+        self.word(": ");
+        self.ty(&item.ty);
+        self.word(" = ");
+        self.expr(&item.expr);
+        self.word(";");
+    }
+
+    fn item_struct(&mut self, item: &ItemStruct) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("struct ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        match &item.fields {
+            Fields::Named(fields) => {
+                self.where_clause_for_body(&item.generics.where_clause);
+                self.fields_named(fields);
+            }
+            Fields::Unnamed(fields) => {
+                self.fields_unnamed(fields);
+                self.where_clause_semi(&item.generics.where_clause);
+            }
+            Fields::Unit => {
+                self.where_clause_semi(&item.generics.where_clause);
+            }
+        }
+    }
+
+    fn item_trait(&mut self, item: &ItemTrait) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        if item.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if item.auto_token.is_some() {
+            self.word("auto ");
+        }
+        self.word("trait ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        if !item.supertraits.is_empty() {
+            self.word(": ");
+            self.bounds(&item.supertraits);
+        }
+        self.where_clause_for_body(&item.generics.where_clause);
+        self.word("{");
+        self.indent += 1;
+        self.inner_attrs(&item.attrs);
+        for (i, trait_item) in item.items.iter().enumerate() {
+            if i > 0 || Self::has_inner_attrs(&item.attrs) {
+                self.nl();
+            }
+            self.nl();
+            self.trait_item(trait_item);
+        }
+        self.indent -= 1;
+        if Self::has_inner_attrs(&item.attrs) || !item.items.is_empty() {
+            self.nl();
+        }
+        self.word("}");
+    }
+
+    fn item_trait_alias(&mut self, item: &ItemTraitAlias) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("trait ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.word(" = ");
+        self.bounds(&item.bounds);
+        self.where_clause_semi(&item.generics.where_clause);
+    }
+
+    fn item_type(&mut self, item: &ItemType) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("type ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_oneline(&item.generics.where_clause);
+        self.word(" = ");
+        self.ty(&item.ty);
+        self.word(";");
+    }
+
+    fn item_union(&mut self, item: &ItemUnion) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("union ");
+        self.ident(&item.ident);
+        self.generics(&item.generics);
+        self.where_clause_for_body(&item.generics.where_clause);
+        self.fields_named(&item.fields);
+    }
+
+    fn item_use(&mut self, item: &ItemUse) {
+        self.outer_attrs(&item.attrs);
+        self.visibility(&item.vis);
+        self.word("use ");
+        if item.leading_colon.is_some() {
+            self.word("::");
+        }
+        self.use_tree(&item.tree);
+        self.word(";");
+    }
+
+    fn use_tree(&mut self, tree: &UseTree) {
+        match tree {
+            UseTree::Path(tree) => {
+                self.ident(&tree.ident);
+                self.word("::");
+                self.use_tree(&tree.tree);
+            }
+            UseTree::Name(tree) => self.ident(&tree.ident),
+            UseTree::Rename(tree) => {
+                self.ident(&tree.ident);
+                self.word(" as ");
+                self.ident(&tree.rename);
+            }
+            UseTree::Glob(_) => self.word("*"),
+            UseTree::Group(tree) => {
+                let items: Vec<&UseTree> = tree.items.iter().collect();
+                self.comma_list("{", &items, "}", false, Printer::use_tree);
+            }
+        }
+    }
+
+    fn fields_named_flat(&mut self, fields: &FieldsNamed) {
+        self.word("{");
+        for (i, field) in fields.named.iter().enumerate() {
+            self.word(if i == 0 { " " } else { ", " });
+            self.field(field);
+        }
+        self.word(if fields.named.is_empty() { "}" } else { " }" });
+    }
+
+    fn fields_named(&mut self, fields: &FieldsNamed) {
+        self.word("{");
+        self.indent += 1;
+        for field in &fields.named {
+            self.nl();
+            self.outer_attrs(&field.attrs);
+            self.field(field);
+            self.word(",");
+        }
+        self.indent -= 1;
+        if !fields.named.is_empty() {
+            self.nl();
+        }
+        self.word("}");
+    }
+
+    fn fields_unnamed(&mut self, fields: &FieldsUnnamed) {
+        let fields: Vec<&Field> = fields.unnamed.iter().collect();
+        self.comma_list("(", &fields, ")", false, |p, field| {
+            p.outer_attrs_inline(&field.attrs);
+            p.field(field);
+        });
+    }
+
+    fn field(&mut self, field: &Field) {
+        self.visibility(&field.vis);
+        if let Some(ident) = &field.ident {
+            self.ident(ident);
+            self.word(": ");
+        }
+        self.ty(&field.ty);
+    }
+
+    fn foreign_item(&mut self, foreign_item: &ForeignItem) {
+        match foreign_item {
+            ForeignItem::Fn(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                self.signature(&item.sig);
+                self.where_clause_semi(&item.sig.generics.where_clause);
+            }
+            ForeignItem::Static(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                self.word("static ");
+                if item.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.ident(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(";");
+            }
+            ForeignItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                self.word("type ");
+                self.ident(&item.ident);
+                self.word(";");
+            }
+            ForeignItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ForeignItem::Verbatim(tokens) => self.verbatim(tokens),
+            ForeignItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn impl_item(&mut self, impl_item: &ImplItem) {
+        match impl_item {
+            ImplItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("const ");
+                self.ident(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                self.word(" = ");
+                self.expr(&item.expr);
+                self.word(";");
+            }
+            ImplItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.signature(&item.sig);
+                self.where_clause_for_body(&item.sig.generics.where_clause);
+                self.block_with_inner_attrs(&item.block, &item.attrs);
+            }
+            ImplItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.visibility(&item.vis);
+                if item.defaultness.is_some() {
+                    self.word("default ");
+                }
+                self.word("type ");
+                self.ident(&item.ident);
+                self.generics(&item.generics);
+                self.where_clause_oneline(&item.generics.where_clause);
+                self.word(" = ");
+                self.ty(&item.ty);
+                self.word(";");
+            }
+            ImplItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            ImplItem::Verbatim(tokens) => self.verbatim(tokens),
+            ImplItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn trait_item(&mut self, trait_item: &TraitItem) {
+        match trait_item {
+            TraitItem::Const(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("const ");
+                self.ident(&item.ident);
+                self.word(": ");
+                self.ty(&item.ty);
+                if let Some((_eq_token, default)) = &item.default {
+                    self.word(" = ");
+                    self.expr(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Method(item) => {
+                self.outer_attrs(&item.attrs);
+                self.signature(&item.sig);
+                match &item.default {
+                    Some(block) => {
+                        self.where_clause_for_body(&item.sig.generics.where_clause);
+                        self.block_with_inner_attrs(block, &item.attrs);
+                    }
+                    None => self.where_clause_semi(&item.sig.generics.where_clause),
+                }
+            }
+            TraitItem::Type(item) => {
+                self.outer_attrs(&item.attrs);
+                self.word("type ");
+                self.ident(&item.ident);
+                self.generics(&item.generics);
+                if !item.bounds.is_empty() {
+                    self.word(": ");
+                    self.bounds(&item.bounds);
+                }
+                self.where_clause_oneline(&item.generics.where_clause);
+                if let Some((_eq_token, default)) = &item.default {
+                    self.word(" = ");
+                    self.ty(default);
+                }
+                self.word(";");
+            }
+            TraitItem::Macro(item) => {
+                self.outer_attrs(&item.attrs);
+                self.mac(&item.mac);
+                if item.semi_token.is_some() {
+                    self.word(";");
+                }
+            }
+            TraitItem::Verbatim(tokens) => self.verbatim(tokens),
+            TraitItem::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn signature(&mut self, sig: &Signature) {
+        if sig.constness.is_some() {
+            self.word("const ");
+        }
+        if sig.asyncness.is_some() {
+            self.word("async ");
+        }
+        if sig.unsafety.is_some() {
+            self.word("unsafe ");
+        }
+        if let Some(abi) = &sig.abi {
+            self.abi(abi);
+        }
+        self.word("fn ");
+        self.ident(&sig.ident);
+        self.generics(&sig.generics);
+        let mut args: Vec<SignatureArg> = sig.inputs.iter().map(SignatureArg::Fn).collect();
+        if let Some(variadic) = &sig.variadic {
+            args.push(SignatureArg::Variadic(variadic));
+        }
+        // Leave room for the opening brace of the body.
+        self.choose_reserving(
+            2,
+            |p| {
+                p.word("(");
+                for (i, arg) in args.iter().enumerate() {
+                    if i > 0 {
+                        p.word(", ");
+                    }
+                    p.signature_arg(arg);
+                }
+                p.word(")");
+                p.return_type(&sig.output);
+            },
+            |p| {
+                p.word("(");
+                p.indent += 1;
+                for arg in &args {
+                    p.nl();
+                    p.signature_arg(arg);
+                    p.word(",");
+                }
+                p.indent -= 1;
+                p.nl();
+                p.word(")");
+                p.return_type(&sig.output);
+            },
+        );
+    }
+
+    fn signature_arg(&mut self, arg: &SignatureArg) {
+        match arg {
+            SignatureArg::Fn(arg) => self.fn_arg(arg),
+            SignatureArg::Variadic(variadic) => {
+                self.outer_attrs_inline(&variadic.attrs);
+                self.word("...");
+            }
+        }
+    }
+
+    fn fn_arg(&mut self, arg: &FnArg) {
+        match arg {
+            FnArg::Receiver(receiver) => {
+                self.outer_attrs_inline(&receiver.attrs);
+                if let Some((_ampersand, lifetime)) = &receiver.reference {
+                    self.word("&");
+                    if let Some(lifetime) = lifetime {
+                        self.lifetime(lifetime);
+                        self.space();
+                    }
+                }
+                if receiver.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.word("self");
+            }
+            FnArg::Typed(pat_type) => self.pat_type(pat_type),
+        }
+    }
+
+    fn return_type(&mut self, output: &ReturnType) {
+        if let ReturnType::Type(_arrow, ty) = output {
+            self.word(" -> ");
+            self.ty(ty);
+        }
+    }
+
+    fn abi(&mut self, abi: &Abi) {
+        self.word("extern ");
+        if let Some(name) = &abi.name {
+            self.lit(name);
+            self.space();
+        }
+    }
+
+    fn visibility(&mut self, vis: &Visibility) {
+        match vis {
+            Visibility::Public(_) => self.word("pub "),
+            Visibility::Crate(_) => self.word("crate "),
+            Visibility::Restricted(vis) => {
+                self.word("pub(");
+                if vis.in_token.is_some() {
+                    self.word("in ");
+                }
+                self.path(&vis.path);
+                self.word(") ");
+            }
+            Visibility::Inherited => {}
+        }
+    }
+
+    // ---------------------------------------------------------------------
+    // Generics
+
+    fn generics(&mut self, generics: &Generics) {
+        if generics.params.is_empty() {
+            return;
+        }
+        let params: Vec<&GenericParam> = generics.params.iter().collect();
+        self.comma_list("<", &params, ">", false, Printer::generic_param);
+    }
+
+    fn generic_param(&mut self, param: &GenericParam) {
+        match param {
+            GenericParam::Type(param) => {
+                self.outer_attrs_inline(&param.attrs);
+                self.ident(&param.ident);
+                if !param.bounds.is_empty() {
+                    self.word(": ");
+                    self.bounds(&param.bounds);
+                }
+                if let Some(default) = &param.default {
+                    self.word(" = ");
+                    self.ty(default);
+                }
+            }
+            GenericParam::Lifetime(param) => self.lifetime_def(param),
+            GenericParam::Const(param) => {
+                self.outer_attrs_inline(&param.attrs);
+                self.word("const ");
+                self.ident(&param.ident);
+                self.word(": ");
+                self.ty(&param.ty);
+                if let Some(default) = &param.default {
+                    self.word(" = ");
+                    self.expr(default);
+                }
+            }
+        }
+    }
+
+    fn lifetime_def(&mut self, param: &LifetimeDef) {
+        self.outer_attrs_inline(&param.attrs);
+        self.lifetime(&param.lifetime);
+        for (i, bound) in param.bounds.iter().enumerate() {
+            self.word(if i == 0 { ": " } else { " + " });
+            self.lifetime(bound);
+        }
+    }
+
+    fn bound_lifetimes(&mut self, lifetimes: &BoundLifetimes) {
+        self.word("for<");
+        for (i, param) in lifetimes.lifetimes.iter().enumerate() {
+            if i > 0 {
+                self.word(", ");
+            }
+            self.lifetime_def(param);
+        }
+        self.word("> ");
+    }
+
+    fn bounds<P>(&mut self, bounds: &Punctuated<TypeParamBound, P>) {
+        for (i, bound) in bounds.iter().enumerate() {
+            if i > 0 {
+                self.word(" + ");
+            }
+            self.type_param_bound(bound);
+        }
+    }
+
+    fn type_param_bound(&mut self, bound: &TypeParamBound) {
+        match bound {
+            TypeParamBound::Trait(bound) => {
+                if bound.paren_token.is_some() {
+                    self.word("(");
+                }
+                if let TraitBoundModifier::Maybe(_) = bound.modifier {
+                    self.word("?");
+                }
+                if let Some(lifetimes) = &bound.lifetimes {
+                    self.bound_lifetimes(lifetimes);
+                }
+                self.path(&bound.path);
+                if bound.paren_token.is_some() {
+                    self.word(")");
+                }
+            }
+            TypeParamBound::Lifetime(lifetime) => self.lifetime(lifetime),
+        }
+    }
+
+    fn has_predicates(where_clause: &Option<WhereClause>) -> bool {
+        where_clause
+            .as_ref()
+            .map_or(false, |where_clause| !where_clause.predicates.is_empty())
+    }
+
+    // Where clause of an item followed by a braced body. Leaves the printer
+    // positioned where the opening brace goes.
+    fn where_clause_for_body(&mut self, where_clause: &Option<WhereClause>) {
+        if Self::has_predicates(where_clause) {
+            self.where_clause_multiline(where_clause.as_ref().unwrap(), true);
+            self.nl();
+        } else {
+            self.space();
+        }
+    }
+
+    // Where clause of an item terminated by a semicolon.
+    fn where_clause_semi(&mut self, where_clause: &Option<WhereClause>) {
+        if Self::has_predicates(where_clause) {
+            self.where_clause_multiline(where_clause.as_ref().unwrap(), false);
+        }
+        self.word(";");
+    }
+
+    fn where_clause_oneline(&mut self, where_clause: &Option<WhereClause>) {
+        if let Some(where_clause) = where_clause {
+            if !where_clause.predicates.is_empty() {
+                self.word(" where ");
+                for (i, predicate) in where_clause.predicates.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.where_predicate(predicate);
+                }
+            }
+        }
+    }
+
+    fn where_clause_multiline(&mut self, where_clause: &WhereClause, trailing_comma: bool) {
+        self.nl();
+        self.word("where");
+        self.indent += 1;
+        let last = where_clause.predicates.len() - 1;
+        for (i, predicate) in where_clause.predicates.iter().enumerate() {
+            self.nl();
+            self.where_predicate(predicate);
+            if i < last || trailing_comma {
+                self.word(",");
+            }
+        }
+        self.indent -= 1;
+    }
+
+    fn where_predicate(&mut self, predicate: &WherePredicate) {
+        match predicate {
+            WherePredicate::Type(predicate) => {
+                if let Some(lifetimes) = &predicate.lifetimes {
+                    self.bound_lifetimes(lifetimes);
+                }
+                self.ty(&predicate.bounded_ty);
+                self.word(": ");
+                self.bounds(&predicate.bounds);
+            }
+            WherePredicate::Lifetime(predicate) => {
+                self.lifetime(&predicate.lifetime);
+                self.word(": ");
+                for (i, bound) in predicate.bounds.iter().enumerate() {
+                    if i > 0 {
+                        self.word(" + ");
+                    }
+                    self.lifetime(bound);
+                }
+            }
+            WherePredicate::Eq(predicate) => {
+                self.ty(&predicate.lhs_ty);
+                self.word(" = ");
+                self.ty(&predicate.rhs_ty);
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------------
+    // Statements
+
+    fn block(&mut self, block: &Block) {
+        self.block_with_inner_attrs(block, &[]);
+    }
+
+    fn block_with_inner_attrs(&mut self, block: &Block, attrs: &[Attribute]) {
+        self.word("{");
+        if block.stmts.is_empty() && !Self::has_inner_attrs(attrs) {
+            self.word("}");
+            return;
+        }
+        self.unflat(|p| {
+            p.indent += 1;
+            p.inner_attrs(attrs);
+            for stmt in &block.stmts {
+                p.nl();
+                p.stmt(stmt);
+            }
+            p.indent -= 1;
+            p.nl();
+        });
+        self.word("}");
+    }
+
+    fn stmt(&mut self, stmt: &Stmt) {
+        match stmt {
+            Stmt::Local(local) => {
+                self.outer_attrs(&local.attrs);
+                self.word("let ");
+                self.pat(&local.pat);
+                if let Some((_eq_token, init)) = &local.init {
+                    self.word(" = ");
+                    self.expr(init);
+                }
+                self.word(";");
+            }
+            Stmt::Item(item) => self.item(item),
+            Stmt::Expr(expr) => self.expr(expr),
+            Stmt::Semi(expr, _semi) => {
+                self.expr(expr);
+                self.word(";");
+            }
+        }
+    }
+
+    // ---------------------------------------------------------------------
+    // Expressions
+
+    fn expr(&mut self, expr: &Expr) {
+        match expr {
+            Expr::Array(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                let elems: Vec<&Expr> = expr.elems.iter().collect();
+                self.comma_list("[", &elems, "]", false, Printer::expr);
+            }
+            Expr::Assign(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.left);
+                self.word(" = ");
+                self.expr(&expr.right);
+            }
+            Expr::AssignOp(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.left);
+                self.space();
+                self.bin_op(&expr.op);
+                self.space();
+                self.expr(&expr.right);
+            }
+            Expr::Async(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("async ");
+                if expr.capture.is_some() {
+                    self.word("move ");
+                }
+                self.block_with_inner_attrs(&expr.block, &expr.attrs);
+            }
+            Expr::Await(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.base);
+                self.word(".await");
+            }
+            Expr::Binary(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr_binary(expr);
+            }
+            Expr::Block(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.block_with_inner_attrs(&expr.block, &expr.attrs);
+            }
+            Expr::Box(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("box ");
+                self.expr(&expr.expr);
+            }
+            Expr::Break(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("break");
+                if let Some(label) = &expr.label {
+                    self.space();
+                    self.lifetime(label);
+                }
+                if let Some(value) = &expr.expr {
+                    self.space();
+                    self.expr(value);
+                }
+            }
+            Expr::Call(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.func);
+                self.call_args(&expr.args);
+            }
+            Expr::Cast(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word(" as ");
+                self.ty(&expr.ty);
+            }
+            Expr::Closure(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                if expr.asyncness.is_some() {
+                    self.word("async ");
+                }
+                if expr.movability.is_some() {
+                    self.word("static ");
+                }
+                if expr.capture.is_some() {
+                    self.word("move ");
+                }
+                self.word("|");
+                for (i, input) in expr.inputs.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.pat(input);
+                }
+                self.word("| ");
+                if let ReturnType::Type(..) = expr.output {
+                    self.trim_trailing_spaces();
+                    self.return_type(&expr.output);
+                    self.space();
+                }
+                self.expr(&expr.body);
+            }
+            Expr::Continue(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("continue");
+                if let Some(label) = &expr.label {
+                    self.space();
+                    self.lifetime(label);
+                }
+            }
+            Expr::Field(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.base);
+                self.word(".");
+                self.member(&expr.member);
+            }
+            Expr::ForLoop(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("for ");
+                self.pat(&expr.pat);
+                self.word(" in ");
+                self.expr(&expr.expr);
+                self.space();
+                self.block_with_inner_attrs(&expr.body, &expr.attrs);
+            }
+            Expr::Group(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+            }
+            Expr::If(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr_if(expr);
+            }
+            Expr::Index(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word("[");
+                self.expr(&expr.index);
+                self.word("]");
+            }
+            Expr::Let(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("let ");
+                self.pat(&expr.pat);
+                self.word(" = ");
+                self.expr(&expr.expr);
+            }
+            Expr::Lit(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.lit(&expr.lit);
+            }
+            Expr::Loop(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("loop ");
+                self.block_with_inner_attrs(&expr.body, &expr.attrs);
+            }
+            Expr::Macro(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.mac(&expr.mac);
+            }
+            Expr::Match(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr_match(expr);
+            }
+            Expr::MethodCall(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr_method_call(expr);
+            }
+            Expr::Paren(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("(");
+                self.expr(&expr.expr);
+                self.word(")");
+            }
+            Expr::Path(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.qpath(&expr.qself, &expr.path);
+            }
+            Expr::Range(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                if let Some(from) = &expr.from {
+                    self.expr(from);
+                }
+                self.range_limits(&expr.limits);
+                if let Some(to) = &expr.to {
+                    self.expr(to);
+                }
+            }
+            Expr::Reference(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("&");
+                if expr.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.expr(&expr.expr);
+            }
+            Expr::Repeat(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("[");
+                self.expr(&expr.expr);
+                self.word("; ");
+                self.expr(&expr.len);
+                self.word("]");
+            }
+            Expr::Return(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("return");
+                if let Some(value) = &expr.expr {
+                    self.space();
+                    self.expr(value);
+                }
+            }
+            Expr::Struct(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr_struct(expr);
+            }
+            Expr::Try(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word("?");
+            }
+            Expr::TryBlock(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("try ");
+                self.block_with_inner_attrs(&expr.block, &expr.attrs);
+            }
+            Expr::Tuple(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                let elems: Vec<&Expr> = expr.elems.iter().collect();
+                self.comma_list("(", &elems, ")", true, Printer::expr);
+            }
+            Expr::Type(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.expr(&expr.expr);
+                self.word(": ");
+                self.ty(&expr.ty);
+            }
+            Expr::Unary(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word(match expr.op {
+                    UnOp::Deref(_) => "*",
+                    UnOp::Not(_) => "!",
+                    UnOp::Neg(_) => "-",
+                });
+                self.expr(&expr.expr);
+            }
+            Expr::Unsafe(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("unsafe ");
+                self.block_with_inner_attrs(&expr.block, &expr.attrs);
+            }
+            Expr::Verbatim(tokens) => self.verbatim(tokens),
+            Expr::While(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.label(&expr.label);
+                self.word("while ");
+                self.expr(&expr.cond);
+                self.space();
+                self.block_with_inner_attrs(&expr.body, &expr.attrs);
+            }
+            Expr::Yield(expr) => {
+                self.outer_attrs_inline(&expr.attrs);
+                self.word("yield");
+                if let Some(value) = &expr.expr {
+                    self.space();
+                    self.expr(value);
+                }
+            }
+            Expr::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn expr_if(&mut self, expr: &ExprIf) {
+        self.word("if ");
+        self.expr(&expr.cond);
+        self.space();
+        self.block(&expr.then_branch);
+        if let Some((_else_token, else_branch)) = &expr.else_branch {
+            self.word(" else ");
+            match &**else_branch {
+                Expr::If(expr) if expr.attrs.is_empty() => self.expr_if(expr),
+                Expr::Block(expr) if expr.attrs.is_empty() && expr.label.is_none() => {
+                    self.block(&expr.block);
+                }
+                other => {
+                    // Not produced by the parser, but representable.
+                    self.word("{");
+                    self.indent += 1;
+                    self.nl();
+                    self.expr(other);
+                    self.indent -= 1;
+                    self.nl();
+                    self.word("}");
+                }
+            }
+        }
+    }
+
+    fn expr_binary(&mut self, expr: &ExprBinary) {
+        // Flatten a left-associative chain like `a && b && c` so that it can
+        // be broken before each operator.
+        let mut operands = vec![&*expr.right];
+        let mut ops = vec![&expr.op];
+        let mut left = &*expr.left;
+        while let Expr::Binary(inner) = left {
+            if !inner.attrs.is_empty() || !same_op(&inner.op, &expr.op) {
+                break;
+            }
+            operands.push(&inner.right);
+            ops.push(&inner.op);
+            left = &inner.left;
+        }
+        operands.reverse();
+        ops.reverse();
+        self.choose(
+            |p| {
+                p.expr(left);
+                for (op, operand) in ops.iter().zip(&operands) {
+                    p.space();
+                    p.bin_op(op);
+                    p.space();
+                    p.expr(operand);
+                }
+            },
+            |p| {
+                p.expr(left);
+                p.indent += 1;
+                for (op, operand) in ops.iter().zip(&operands) {
+                    p.nl();
+                    p.bin_op(op);
+                    p.space();
+                    p.expr(operand);
+                }
+                p.indent -= 1;
+            },
+        );
+    }
+
+    fn expr_match(&mut self, expr: &ExprMatch) {
+        self.word("match ");
+        self.expr(&expr.expr);
+        self.word(" {");
+        if expr.arms.is_empty() && !Self::has_inner_attrs(&expr.attrs) {
+            self.word("}");
+            return;
+        }
+        self.unflat(|p| {
+            p.indent += 1;
+            p.inner_attrs(&expr.attrs);
+            for arm in &expr.arms {
+                p.nl();
+                p.arm(arm);
+            }
+            p.indent -= 1;
+            p.nl();
+        });
+        self.word("}");
+    }
+
+    fn arm(&mut self, arm: &Arm) {
+        self.outer_attrs(&arm.attrs);
+        self.pat(&arm.pat);
+        if let Some((_if_token, guard)) = &arm.guard {
+            self.word(" if ");
+            self.expr(guard);
+        }
+        self.word(" => ");
+        match &*arm.body {
+            Expr::Block(body) if body.attrs.is_empty() && body.label.is_none() => {
+                self.block(&body.block);
+            }
+            body => {
+                self.expr(body);
+                self.word(",");
+            }
+        }
+    }
+
+    fn expr_method_call(&mut self, expr: &ExprMethodCall) {
+        let mut chain = Vec::new();
+        let mut root = &*expr.receiver;
+        while let Expr::MethodCall(inner) = root {
+            if !inner.attrs.is_empty() {
+                break;
+            }
+            chain.push(inner);
+            root = &inner.receiver;
+        }
+        if chain.is_empty() {
+            self.expr(&expr.receiver);
+            self.method_call_tail(expr);
+            return;
+        }
+        chain.reverse();
+        chain.push(expr);
+        self.choose(
+            |p| {
+                p.expr(root);
+                for call in &chain {
+                    p.method_call_tail(call);
+                }
+            },
+            |p| {
+                p.expr(root);
+                p.indent += 1;
+                for call in &chain {
+                    p.nl();
+                    p.method_call_tail(call);
+                }
+                p.indent -= 1;
+            },
+        );
+    }
+
+    fn method_call_tail(&mut self, expr: &ExprMethodCall) {
+        self.word(".");
+        self.ident(&expr.method);
+        if let Some(turbofish) = &expr.turbofish {
+            self.word("::<");
+            for (i, arg) in turbofish.args.iter().enumerate() {
+                if i > 0 {
+                    self.word(", ");
+                }
+                match arg {
+                    GenericMethodArgument::Type(ty) => self.ty(ty),
+                    GenericMethodArgument::Const(expr) => self.expr(expr),
+                }
+            }
+            self.word(">");
+        }
+        self.call_args(&expr.args);
+    }
+
+    fn call_args(&mut self, args: &Punctuated<Expr, Token![,]>) {
+        let args: Vec<&Expr> = args.iter().collect();
+        self.comma_list("(", &args, ")", false, Printer::expr);
+    }
+
+    fn expr_struct(&mut self, expr: &ExprStruct) {
+        self.path(&expr.path);
+        self.word(" ");
+        if expr.fields.is_empty() && expr.rest.is_none() && expr.dot2_token.is_none() {
+            self.word("{}");
+            return;
+        }
+        self.choose(
+            |p| {
+                let start = p.out.len();
+                p.word("{ ");
+                for (i, field) in expr.fields.iter().enumerate() {
+                    if i > 0 {
+                        p.word(", ");
+                    }
+                    p.field_value(field);
+                }
+                if expr.dot2_token.is_some() || expr.rest.is_some() {
+                    if !expr.fields.is_empty() {
+                        p.word(", ");
+                    }
+                    p.word("..");
+                    if let Some(rest) = &expr.rest {
+                        p.expr(rest);
+                    }
+                }
+                p.word(" }");
+                p.reject_multiline_since(start);
+            },
+            |p| {
+                p.word("{");
+                p.indent += 1;
+                for field in &expr.fields {
+                    p.nl();
+                    p.field_value(field);
+                    p.word(",");
+                }
+                if expr.dot2_token.is_some() || expr.rest.is_some() {
+                    p.nl();
+                    p.word("..");
+                    if let Some(rest) = &expr.rest {
+                        p.expr(rest);
+                    }
+                }
+                p.indent -= 1;
+                p.nl();
+                p.word("}");
+            },
+        );
+    }
+
+    fn field_value(&mut self, field: &FieldValue) {
+        self.outer_attrs_inline(&field.attrs);
+        if field.colon_token.is_some() {
+            self.member(&field.member);
+            self.word(": ");
+        }
+        self.expr(&field.expr);
+    }
+
+    fn member(&mut self, member: &Member) {
+        match member {
+            Member::Named(ident) => self.ident(ident),
+            Member::Unnamed(index) => self.word(&index.index.to_string()),
+        }
+    }
+
+    fn label(&mut self, label: &Option<Label>) {
+        if let Some(label) = label {
+            self.lifetime(&label.name);
+            self.word(": ");
+        }
+    }
+
+    fn range_limits(&mut self, limits: &RangeLimits) {
+        self.word(match limits {
+            RangeLimits::HalfOpen(_) => "..",
+            RangeLimits::Closed(_) => "..=",
+        });
+    }
+
+    fn bin_op(&mut self, op: &BinOp) {
+        self.word(match op {
+            BinOp::Add(_) => "+",
+            BinOp::Sub(_) => "-",
+            BinOp::Mul(_) => "*",
+            BinOp::Div(_) => "/",
+            BinOp::Rem(_) => "%",
+            BinOp::And(_) => "&&",
+            BinOp::Or(_) => "||",
+            BinOp::BitXor(_) => "^",
+            BinOp::BitAnd(_) => "&",
+            BinOp::BitOr(_) => "|",
+            BinOp::Shl(_) => "<<",
+            BinOp::Shr(_) => ">>",
+            BinOp::Eq(_) => "==",
+            BinOp::Lt(_) => "<",
+            BinOp::Le(_) => "<=",
+            BinOp::Ne(_) => "!=",
+            BinOp::Ge(_) => ">=",
+            BinOp::Gt(_) => ">",
+            BinOp::AddEq(_) => "+=",
+            BinOp::SubEq(_) => "-=",
+            BinOp::MulEq(_) => "*=",
+            BinOp::DivEq(_) => "/=",
+            BinOp::RemEq(_) => "%=",
+            BinOp::BitXorEq(_) => "^=",
+            BinOp::BitAndEq(_) => "&=",
+            BinOp::BitOrEq(_) => "|=",
+            BinOp::ShlEq(_) => "<<=",
+            BinOp::ShrEq(_) => ">>=",
+        });
+    }
+
+    fn lit<T: ToTokens>(&mut self, lit: &T) {
+        self.word(&lit.to_token_stream().to_string());
+    }
+
+    // ---------------------------------------------------------------------
+    // Patterns
+
+    fn pat(&mut self, pat: &Pat) {
+        match pat {
+            Pat::Box(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("box ");
+                self.pat(&pat.pat);
+            }
+            Pat::Ident(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                if pat.by_ref.is_some() {
+                    self.word("ref ");
+                }
+                if pat.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.ident(&pat.ident);
+                if let Some((_at_token, subpat)) = &pat.subpat {
+                    self.word(" @ ");
+                    self.pat(subpat);
+                }
+            }
+            Pat::Lit(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.expr(&pat.expr);
+            }
+            Pat::Macro(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.mac(&pat.mac);
+            }
+            Pat::Or(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                let leading_vert = pat.leading_vert.is_some();
+                self.choose(
+                    |p| {
+                        if leading_vert {
+                            p.word("| ");
+                        }
+                        for (i, case) in pat.cases.iter().enumerate() {
+                            if i > 0 {
+                                p.word(" | ");
+                            }
+                            p.pat(case);
+                        }
+                    },
+                    |p| {
+                        for (i, case) in pat.cases.iter().enumerate() {
+                            if i > 0 {
+                                p.nl();
+                            }
+                            if i > 0 || leading_vert {
+                                p.word("| ");
+                            }
+                            p.pat(case);
+                        }
+                    },
+                );
+            }
+            Pat::Path(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.qpath(&pat.qself, &pat.path);
+            }
+            Pat::Range(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.expr(&pat.lo);
+                self.range_limits(&pat.limits);
+                self.expr(&pat.hi);
+            }
+            Pat::Reference(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("&");
+                if pat.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.pat(&pat.pat);
+            }
+            Pat::Rest(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("..");
+            }
+            Pat::Slice(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                let elems: Vec<&Pat> = pat.elems.iter().collect();
+                self.comma_list("[", &elems, "]", false, Printer::pat);
+            }
+            Pat::Struct(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.path(&pat.path);
+                self.word(" {");
+                for (i, field) in pat.fields.iter().enumerate() {
+                    self.word(if i == 0 { " " } else { ", " });
+                    self.outer_attrs_inline(&field.attrs);
+                    if field.colon_token.is_some() {
+                        self.member(&field.member);
+                        self.word(": ");
+                    }
+                    self.pat(&field.pat);
+                }
+                if pat.dot2_token.is_some() {
+                    self.word(if pat.fields.is_empty() { " .." } else { ", .." });
+                }
+                if pat.fields.is_empty() && pat.dot2_token.is_none() {
+                    self.word("}");
+                } else {
+                    self.word(" }");
+                }
+            }
+            Pat::Tuple(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.pat_tuple(pat, true);
+            }
+            Pat::TupleStruct(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.path(&pat.path);
+                self.pat_tuple(&pat.pat, false);
+            }
+            Pat::Type(pat) => self.pat_type(pat),
+            Pat::Verbatim(tokens) => self.verbatim(tokens),
+            Pat::Wild(pat) => {
+                self.outer_attrs_inline(&pat.attrs);
+                self.word("_");
+            }
+            Pat::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    fn pat_tuple(&mut self, pat: &PatTuple, trailing: bool) {
+        let elems: Vec<&Pat> = pat.elems.iter().collect();
+        // `(..)` is a rest pattern, not a one-element tuple.
+        let trailing = trailing
+            && match elems.first() {
+                Some(Pat::Rest(_)) => false,
+                _ => true,
+            };
+        self.comma_list("(", &elems, ")", trailing, Printer::pat);
+    }
+
+    fn pat_type(&mut self, pat: &PatType) {
+        self.outer_attrs_inline(&pat.attrs);
+        self.pat(&pat.pat);
+        self.word(": ");
+        self.ty(&pat.ty);
+    }
+
+    // ---------------------------------------------------------------------
+    // Types
+
+    fn ty(&mut self, ty: &Type) {
+        match ty {
+            Type::Array(ty) => {
+                self.word("[");
+                self.ty(&ty.elem);
+                self.word("; ");
+                self.expr(&ty.len);
+                self.word("]");
+            }
+            Type::BareFn(ty) => {
+                if let Some(lifetimes) = &ty.lifetimes {
+                    self.bound_lifetimes(lifetimes);
+                }
+                if ty.unsafety.is_some() {
+                    self.word("unsafe ");
+                }
+                if let Some(abi) = &ty.abi {
+                    self.abi(abi);
+                }
+                self.word("fn(");
+                for (i, arg) in ty.inputs.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.outer_attrs_inline(&arg.attrs);
+                    if let Some((name, _colon)) = &arg.name {
+                        self.ident(name);
+                        self.word(": ");
+                    }
+                    self.ty(&arg.ty);
+                }
+                if let Some(variadic) = &ty.variadic {
+                    if !ty.inputs.is_empty() {
+                        self.word(", ");
+                    }
+                    self.outer_attrs_inline(&variadic.attrs);
+                    self.word("...");
+                }
+                self.word(")");
+                self.return_type(&ty.output);
+            }
+            Type::Group(ty) => self.ty(&ty.elem),
+            Type::ImplTrait(ty) => {
+                self.word("impl ");
+                self.bounds(&ty.bounds);
+            }
+            Type::Infer(_) => self.word("_"),
+            Type::Macro(ty) => self.mac(&ty.mac),
+            Type::Never(_) => self.word("!"),
+            Type::Paren(ty) => {
+                self.word("(");
+                self.ty(&ty.elem);
+                self.word(")");
+            }
+            Type::Path(ty) => self.qpath(&ty.qself, &ty.path),
+            Type::Ptr(ty) => {
+                self.word("*");
+                if ty.mutability.is_some() {
+                    self.word("mut ");
+                } else {
+                    self.word("const ");
+                }
+                self.ty(&ty.elem);
+            }
+            Type::Reference(ty) => {
+                self.word("&");
+                if let Some(lifetime) = &ty.lifetime {
+                    self.lifetime(lifetime);
+                    self.space();
+                }
+                if ty.mutability.is_some() {
+                    self.word("mut ");
+                }
+                self.ty(&ty.elem);
+            }
+            Type::Slice(ty) => {
+                self.word("[");
+                self.ty(&ty.elem);
+                self.word("]");
+            }
+            Type::TraitObject(ty) => {
+                if ty.dyn_token.is_some() {
+                    self.word("dyn ");
+                }
+                self.bounds(&ty.bounds);
+            }
+            Type::Tuple(ty) => {
+                let elems: Vec<&Type> = ty.elems.iter().collect();
+                self.comma_list("(", &elems, ")", true, Printer::ty);
+            }
+            Type::Verbatim(tokens) => self.verbatim(tokens),
+            Type::__Nonexhaustive => unreachable!(),
+        }
+    }
+
+    // ---------------------------------------------------------------------
+    // Paths
+
+    fn qpath(&mut self, qself: &Option<QSelf>, path: &Path) {
+        let qself = match qself {
+            Some(qself) => qself,
+            None => {
+                self.path(path);
+                return;
+            }
+        };
+        self.word("<");
+        self.ty(&qself.ty);
+        let pos = if qself.position > 0 && qself.position >= path.segments.len() {
+            path.segments.len() - 1
+        } else {
+            qself.position
+        };
+        if pos > 0 {
+            self.word(" as ");
+            if path.leading_colon.is_some() {
+                self.word("::");
+            }
+            for (i, segment) in path.segments.iter().take(pos).enumerate() {
+                if i > 0 {
+                    self.word("::");
+                }
+                self.path_segment(segment);
+            }
+        }
+        self.word(">");
+        for segment in path.segments.iter().skip(pos) {
+            self.word("::");
+            self.path_segment(segment);
+        }
+    }
+
+    fn path(&mut self, path: &Path) {
+        if path.leading_colon.is_some() {
+            self.word("::");
+        }
+        for (i, segment) in path.segments.iter().enumerate() {
+            if i > 0 {
+                self.word("::");
+            }
+            self.path_segment(segment);
+        }
+    }
+
+    fn path_segment(&mut self, segment: &PathSegment) {
+        self.ident(&segment.ident);
+        match &segment.arguments {
+            PathArguments::None => {}
+            PathArguments::AngleBracketed(arguments) => {
+                if arguments.colon2_token.is_some() {
+                    self.word("::");
+                }
+                self.word("<");
+                for (i, arg) in arguments.args.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.generic_argument(arg);
+                }
+                self.word(">");
+            }
+            PathArguments::Parenthesized(arguments) => {
+                self.word("(");
+                for (i, input) in arguments.inputs.iter().enumerate() {
+                    if i > 0 {
+                        self.word(", ");
+                    }
+                    self.ty(input);
+                }
+                self.word(")");
+                self.return_type(&arguments.output);
+            }
+        }
+    }
+
+    fn generic_argument(&mut self, arg: &GenericArgument) {
+        match arg {
+            GenericArgument::Lifetime(lifetime) => self.lifetime(lifetime),
+            GenericArgument::Type(ty) => self.ty(ty),
+            GenericArgument::Binding(binding) => {
+                self.ident(&binding.ident);
+                self.word(" = ");
+                self.ty(&binding.ty);
+            }
+            GenericArgument::Constraint(constraint) => {
+                self.ident(&constraint.ident);
+                self.word(": ");
+                self.bounds(&constraint.bounds);
+            }
+            GenericArgument::Const(expr) => match expr {
+                Expr::Lit(_) | Expr::Block(_) => self.expr(expr),
+                _ => {
+                    self.word("{ ");
+                    self.expr(expr);
+                    self.word(" }");
+                }
+            },
+        }
+    }
+
+    fn ident(&mut self, ident: &Ident) {
+        self.word(&ident.to_string());
+    }
+
+    fn lifetime(&mut self, lifetime: &Lifetime) {
+        self.word("'");
+        self.ident(&lifetime.ident);
+    }
+
+    // ---------------------------------------------------------------------
+    // Macros and tokens
+
+    fn mac(&mut self, mac: &Macro) {
+        self.path(&mac.path);
+        self.word("!");
+        self.mac_body(mac, false);
+    }
+
+    fn mac_body(&mut self, mac: &Macro, item_position: bool) {
+        let delimiter = match mac.delimiter {
+            MacroDelimiter::Paren(_) => Delimiter::Parenthesis,
+            MacroDelimiter::Brace(_) => {
+                if item_position || !self.at_line_start() {
+                    self.space();
+                }
+                Delimiter::Brace
+            }
+            MacroDelimiter::Bracket(_) => Delimiter::Bracket,
+        };
+        let mut tokens = TokenPrinter::after(Prev::Start);
+        tokens.group(self, delimiter, &mac.tokens);
+    }
+
+    fn verbatim(&mut self, tokens: &TokenStream) {
+        let mut printer = TokenPrinter::after(Prev::Start);
+        printer.stream(self, tokens);
+    }
+}
+
+enum SignatureArg<'a> {
+    Fn(&'a FnArg),
+    Variadic(&'a Variadic),
+}
+
+fn same_op(a: &BinOp, b: &BinOp) -> bool {
+    mem::discriminant(a) == mem::discriminant(b)
+}
+
+fn is_use_like(item: &Item) -> bool {
+    match item {
+        Item::Use(_) | Item::ExternCrate(_) => true,
+        Item::Mod(item) => item.content.is_none(),
+        _ => false,
+    }
+}
+
+// Returns the text of a doc comment if `attr` can be printed as one.
+fn doc_comment(attr: &Attribute) -> Option<String> {
+    let path = &attr.path;
+    let is_doc = path.leading_colon.is_none()
+        && path.segments.len() == 1
+        && path.segments[0].ident == "doc"
+        && path.segments[0].arguments.is_empty();
+    if !is_doc {
+        return None;
+    }
+    doc_value(attr.tokens.clone())
+}
+
+// Extracts the text of the `= "..."` part of a doc attribute.
+fn doc_value(tokens: TokenStream) -> Option<String> {
+    let mut tokens = tokens.into_iter();
+    match tokens.next() {
+        Some(TokenTree::Punct(ref punct)) if punct.as_char() == '=' => {}
+        _ => return None,
+    }
+    let literal = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => literal,
+        _ => return None,
+    };
+    if tokens.next().is_some() {
+        return None;
+    }
+    let value = match Lit::new(literal) {
+        Lit::Str(lit) => lit.value(),
+        _ => return None,
+    };
+    // A comment starting with a fourth slash or containing the end of a block
+    // comment would not lex back into the same doc attribute.
+    let multiline = value.contains('\n');
+    if value.contains('\r')
+        || (multiline && (value.contains("*/") || value.contains("/*")))
+        || (!multiline && value.starts_with('/'))
+        || (multiline && (value.starts_with('*') || value.starts_with('!')))
+    {
+        return None;
+    }
+    Some(value)
+}
+
+// Recognizes a `#[doc = "..."]` or `#![doc = "..."]` attribute at the front of
+// `tokens`, returning whether it is an inner attribute, the text of the
+// comment, and the number of tokens the attribute spans.
+fn doc_attr_tokens(tokens: &[TokenTree]) -> Option<(bool, String, usize)> {
+    let is_punct = |token: &TokenTree, ch: char| match token {
+        TokenTree::Punct(punct) => punct.as_char() == ch,
+        _ => false,
+    };
+    if tokens.is_empty() || !is_punct(&tokens[0], '#') {
+        return None;
+    }
+    let inner = tokens.len() > 1 && is_punct(&tokens[1], '!');
+    let len = if inner { 3 } else { 2 };
+    let group = match tokens.get(len - 1) {
+        Some(TokenTree::Group(group)) if group.delimiter() == Delimiter::Bracket => group,
+        _ => return None,
+    };
+    let mut stream = group.stream().into_iter();
+    match stream.next() {
+        Some(TokenTree::Ident(ref ident)) if ident == "doc" => {}
+        _ => return None,
+    }
+    let value = doc_value(stream.collect())?;
+    Some((inner, value, len))
+}
+
+fn write_doc_comment(p: &mut Printer, inner: bool, doc: &str) {
+    if doc.contains('\n') {
+        p.word(if inner { "/*!" } else { "/**" });
+        p.word(doc);
+        p.word("*/");
+    } else {
+        p.word(if inner { "//!" } else { "///" });
+        p.word(doc);
+    }
+}
+
+// What the token printer emitted most recently, for deciding on spacing.
+#[derive(Copy, Clone, PartialEq)]
+enum Prev {
+    Start,
+    Word,
+    Keyword,
+    Literal,
+    Group(DelimiterKind),
+    JointPunct,
+    Punct(char),
+    // A `::` path separator.
+    Colon2,
+    // A prefix operator such as unary `&`, `*`, `-` or `!`.
+    Prefix,
+    // Opening `<` of generic arguments.
+    OpenAngle,
+    // Closing `>` of generic arguments.
+    CloseAngle,
+    // A `$var` macro metavariable.
+    MetaVar,
+    // The colon between a metavariable and its fragment specifier.
+    MetaColon,
+    // The parenthesized part of a `$(...)*` repetition.
+    Repetition,
+}
+
+#[derive(Copy, Clone, PartialEq)]
+enum DelimiterKind {
+    Paren,
+    Brace,
+    Bracket,
+    None,
+}
+
+// Heuristic printer for token streams whose grammar is not known, such as the
+// bodies of macro invocations and the arguments of attributes.
+struct TokenPrinter {
+    prev: Prev,
+    prev_joint: Option<char>,
+    angle_depth: usize,
+    closure_params: bool,
+    multiline: bool,
+}
+
+impl TokenPrinter {
+    fn after(prev: Prev) -> Self {
+        TokenPrinter {
+            prev,
+            prev_joint: None,
+            angle_depth: 0,
+            closure_params: false,
+            multiline: false,
+        }
+    }
+
+    fn stream(&mut self, p: &mut Printer, tokens: &TokenStream) {
+        let tokens: Vec<TokenTree> = tokens.clone().into_iter().collect();
+        let mut i = 0;
+        while i < tokens.len() {
+            if self.multiline
+                && i > 0
+                && !p.at_line_start()
+                && needs_newline(&tokens[..i], &tokens[i..])
+            {
+                p.nl();
+                self.prev = Prev::Start;
+            }
+            // A line comment is only safe to print where a newline follows.
+            if self.multiline {
+                if let Some((inner, doc, len)) = doc_attr_tokens(&tokens[i..]) {
+                    write_doc_comment(p, inner, &doc);
+                    p.nl();
+                    self.prev = Prev::Start;
+                    i += len;
+                    continue;
+                }
+            }
+            self.token(p, &tokens[i]);
+            i += 1;
+        }
+    }
+
+    fn token(&mut self, p: &mut Printer, token: &TokenTree) {
+        match token {
+            TokenTree::Group(group) => {
+                let delimiter = group.delimiter();
+                if self.space_before_group(delimiter) {
+                    p.space();
+                }
+                let after_dollar = self.prev == Prev::Punct('$');
+                let mut inner = TokenPrinter::after(Prev::Start);
+                inner.group(p, delimiter, &group.stream());
+                self.prev = if after_dollar {
+                    Prev::Repetition
+                } else {
+                    Prev::Group(kind(delimiter))
+                };
+                self.prev_joint = None;
+            }
+            TokenTree::Ident(ident) => {
+                if self.space_before_word() {
+                    p.space();
+                }
+                let ident = ident.to_string();
+                p.word(&ident);
+                self.prev = if self.prev == Prev::Punct('$') {
+                    Prev::MetaVar
+                } else if is_keyword(&ident) {
+                    Prev::Keyword
+                } else {
+                    Prev::Word
+                };
+                self.prev_joint = None;
+            }
+            TokenTree::Literal(literal) => {
+                if self.space_before_word() {
+                    p.space();
+                }
+                p.word(&literal.to_string());
+                self.prev = Prev::Literal;
+                self.prev_joint = None;
+            }
+            TokenTree::Punct(punct) => self.punct(p, punct.as_char(), punct.spacing()),
+        }
+    }
+
+    fn group(&mut self, p: &mut Printer, delimiter: Delimiter, stream: &TokenStream) {
+        let (open, close) = match delimiter {
+            Delimiter::Parenthesis => ("(", ")"),
+            Delimiter::Brace => ("{", "}"),
+            Delimiter::Bracket => ("[", "]"),
+            Delimiter::None => ("", ""),
+        };
+        if delimiter != Delimiter::Brace {
+            let has_comma = stream.clone().into_iter().any(|token| match token {
+                TokenTree::Punct(punct) => punct.as_char() == ',',
+                _ => false,
+            });
+            let flat = p
+                .trial(|p| {
+                    p.word(open);
+                    TokenPrinter::after(Prev::Start).stream(p, stream);
+                    p.word(close);
+                })
+                .out;
+            if p.flat || !has_comma || delimiter == Delimiter::None || p.fits(&flat) {
+                p.word(&flat);
+                return;
+            }
+            p.word(open);
+            p.indent += 1;
+            p.nl();
+            self.multiline = true;
+            self.stream(p, stream);
+            p.indent -= 1;
+            p.nl();
+            p.word(close);
+            return;
+        }
+        if stream.is_empty() {
+            p.word("{}");
+            return;
+        }
+        let has_semi = stream.clone().into_iter().any(|token| match token {
+            TokenTree::Punct(punct) => punct.as_char() == ';' || punct.as_char() == '#',
+            _ => false,
+        });
+        let flat = p
+            .trial(|p| {
+                p.word("{ ");
+                TokenPrinter::after(Prev::Start).stream(p, stream);
+                p.word(" }");
+            })
+            .out;
+        if !has_semi && !flat.contains('\n') && p.fits(&flat) {
+            p.word(&flat);
+            return;
+        }
+        p.word("{");
+        p.indent += 1;
+        p.nl();
+        self.multiline = true;
+        self.stream(p, stream);
+        p.indent -= 1;
+        p.nl();
+        p.word("}");
+    }
+
+    fn space_before_word(&self) -> bool {
+        match self.prev {
+            Prev::Start
+            | Prev::JointPunct
+            | Prev::Colon2
+            | Prev::Prefix
+            | Prev::OpenAngle
+            | Prev::MetaColon => false,
+            Prev::Punct(ch) => !(ch == '.' || ch == '#' || ch == '$' || ch == '\''),
+            _ => true,
+        }
+    }
+
+    fn space_before_group(&self, delimiter: Delimiter) -> bool {
+        match self.prev {
+            Prev::Start
+            | Prev::JointPunct
+            | Prev::Colon2
+            | Prev::Prefix
+            | Prev::OpenAngle
+            | Prev::MetaColon => false,
+            Prev::Punct(ch) => match ch {
+                '#' | '$' | '!' | '.' => false,
+                _ => true,
+            },
+            Prev::Keyword => true,
+            Prev::Word
+            | Prev::MetaVar
+            | Prev::Literal
+            | Prev::Group(_)
+            | Prev::CloseAngle
+            | Prev::Repetition => delimiter == Delimiter::Brace,
+        }
+    }
+
+    fn punct(&mut self, p: &mut Printer, ch: char, spacing: Spacing) {
+        let joined_to_prev = self.prev == Prev::JointPunct;
+        let operand_before = match self.prev {
+            Prev::Word | Prev::MetaVar | Prev::Literal | Prev::Group(_) | Prev::CloseAngle => true,
+            Prev::Repetition | Prev::Punct('?') => true,
+            _ => false,
+        };
+        let open_angle = ch == '<'
+            && !joined_to_prev
+            && (self.prev == Prev::Colon2 || self.prev == Prev::Word && self.angle_is_generic(p));
+        let close_angle =
+            ch == '>' && self.angle_depth > 0 && (!joined_to_prev || self.prev_joint == Some('>'));
+        let closure_bar = ch == '|' && spacing == Spacing::Alone && !joined_to_prev;
+        let close_params = closure_bar && self.closure_params;
+        let open_params = closure_bar && !self.closure_params && !operand_before;
+
+        let space = if joined_to_prev {
+            false
+        } else if self.prev_is_alone_punct() {
+            // Adjacent punctuation would lex as a single joint operator.
+            true
+        } else {
+            match ch {
+                ',' | ';' => false,
+                '?' | '*' | '+' if self.prev == Prev::Repetition => false,
+                '?' => !operand_before,
+                ':' if spacing == Spacing::Joint => {
+                    !(operand_before
+                        || self.prev == Prev::Start
+                        || self.prev == Prev::Prefix
+                        || self.prev == Prev::OpenAngle
+                        || self.prev == Prev::MetaColon)
+                }
+                ':' => false,
+                '.' => !(operand_before || self.prev == Prev::Start || self.prev == Prev::Prefix),
+                '>' if close_angle => false,
+                '<' if open_angle => false,
+                '|' if close_params => false,
+                '!' if self.prev == Prev::Word && spacing == Spacing::Alone => false,
+                _ => self.space_before_word(),
+            }
+        };
+        if space {
+            p.space();
+        }
+        p.word(&ch.to_string());
+
+        let colon2 = ch == ':' && joined_to_prev && self.prev_joint == Some(':');
+        if open_angle {
+            self.angle_depth += 1;
+        } else if close_angle {
+            self.angle_depth -= 1;
+        }
+        if open_params {
+            self.closure_params = true;
+        } else if close_params {
+            self.closure_params = false;
+        }
+        self.prev_joint = if spacing == Spacing::Joint {
+            Some(ch)
+        } else {
+            None
+        };
+        self.prev = if spacing == Spacing::Joint {
+            Prev::JointPunct
+        } else if colon2 {
+            Prev::Colon2
+        } else if open_angle {
+            Prev::OpenAngle
+        } else if close_angle {
+            Prev::CloseAngle
+        } else if joined_to_prev {
+            Prev::Punct(ch)
+        } else if ch == ':' && self.prev == Prev::MetaVar {
+            Prev::MetaColon
+        } else if open_params {
+            Prev::Prefix
+        } else if (ch == '&' || ch == '*' || ch == '-' || ch == '!') && !operand_before {
+            Prev::Prefix
+        } else {
+            Prev::Punct(ch)
+        };
+    }
+
+    fn prev_is_alone_punct(&self) -> bool {
+        match self.prev {
+            Prev::Punct(_)
+            | Prev::Colon2
+            | Prev::Prefix
+            | Prev::OpenAngle
+            | Prev::CloseAngle
+            | Prev::MetaColon => true,
+            _ => false,
+        }
+    }
+
+    fn angle_is_generic(&self, p: &Printer) -> bool {
+        // Only treat `<` as generics after a `::` or an identifier that looks
+        // like a type name, which is the common case in macro input.
+        let line = &p.out[p.out.rfind('\n').map_or(0, |i| i + 1)..];
+        let before = line.trim_end_matches('<');
+        before.ends_with("::")
+            || before
+                .rsplit(|ch: char| !(ch.is_alphanumeric() || ch == '_'))
+                .next()
+                .and_then(|word| word.chars().next())
+                .map_or(false, char::is_uppercase)
+    }
+}
+
+// Whether a multi-line brace group should start a new line between the tokens
+// `before` and `after`.
+fn needs_newline(before: &[TokenTree], after: &[TokenTree]) -> bool {
+    let is_punct = |token: Option<&TokenTree>, ch: char| match token {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == ch,
+        _ => false,
+    };
+    let last = &before[before.len() - 1];
+    let next = &after[0];
+    // Start of an attribute.
+    if is_punct(Some(next), '#') {
+        let attr = match after.get(1) {
+            Some(TokenTree::Group(group)) => group.delimiter() == Delimiter::Bracket,
+            Some(TokenTree::Punct(punct)) => punct.as_char() == '!',
+            _ => false,
+        };
+        if attr {
+            match last {
+                TokenTree::Punct(punct) => match punct.as_char() {
+                    // End of a `$(...)*` repetition.
+                    '*' | '+' | '?' => return true,
+                    _ => {}
+                },
+                _ => return true,
+            }
+        }
+    }
+    match last {
+        TokenTree::Punct(punct) => {
+            (punct.as_char() == ';' || punct.as_char() == ',') && punct.spacing() == Spacing::Alone
+        }
+        TokenTree::Group(group) => match group.delimiter() {
+            Delimiter::Brace => match next {
+                TokenTree::Ident(ident) => ident != "else",
+                TokenTree::Punct(punct) => punct.as_char() == '$',
+                _ => false,
+            },
+            // End of an attribute.
+            Delimiter::Bracket => {
+                let len = before.len();
+                is_punct(before.get(len.wrapping_sub(2)), '#')
+                    || is_punct(before.get(len.wrapping_sub(2)), '!')
+                        && is_punct(before.get(len.wrapping_sub(3)), '#')
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn kind(delimiter: Delimiter) -> DelimiterKind {
+    match delimiter {
+        Delimiter::Parenthesis => DelimiterKind::Paren,
+        Delimiter::Brace => DelimiterKind::Brace,
+        Delimiter::Bracket => DelimiterKind::Bracket,
+        Delimiter::None => DelimiterKind::None,
+    }
+}
+
+fn is_keyword(ident: &str) -> bool {
+    match ident {
+        "as" | "async" | "await" | "box" | "break" | "const" | "continue" | "dyn" | "else"
+        | "enum" | "extern" | "fn" | "for" | "if" | "impl" | "in" | "let" | "loop" | "match"
+        | "mod" | "move" | "mut" | "pub" | "ref" | "return" | "static" | "struct" | "trait"
+        | "type" | "union" | "unsafe" | "use" | "where" | "while" | "yield" => true,
+        _ => false,
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! pretty_impls {
+    ($($ty:ident => $method:ident,)*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl Pretty for $ty {
+                fn pretty(&self, printer: &mut Printer) {
+                    printer.$method(self);
+                }
+            }
+        )*
+    };
+}
+
+pretty_impls! {
+    Block => block,
+    Expr => expr,
+    ForeignItem => foreign_item,
+    Generics => generics,
+    ImplItem => impl_item,
+    Pat => pat,
+    Path => path,
+    Signature => signature,
+    Stmt => stmt,
+    TraitItem => trait_item,
+    Type => ty,
+}
+
+impl private::Sealed for File {}
+
+impl Pretty for File {
+    fn pretty(&self, printer: &mut Printer) {
+        printer.file(self);
+    }
+}
+
+impl private::Sealed for Item {}
+
+impl Pretty for Item {
+    fn pretty(&self, printer: &mut Printer) {
+        printer.item(self);
+        printer.nl();
+    }
+}
@@ -916,3 +916,80 @@ mod printing {
         }
     }
 }
+
+#[cfg(feature = "serde")]
+mod serde_impls {
+    use super::*;
+    use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+    use serde::ser::{Serialize, SerializeSeq, Serializer};
+    use std::fmt;
+    use std::marker::PhantomData;
+    use std::result::Result;
+
+    // Values and punctuation are serialized interleaved in a single sequence,
+    // in the order they appear in the source, so that trailing punctuation
+    // survives a round trip.
+    impl<T, P> Serialize for Punctuated<T, P>
+    where
+        T: Serialize,
+        P: Serialize,
+    {
+        fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            let len = self.inner.len() * 2 + usize::from(self.last.is_some());
+            let mut seq = serializer.serialize_seq(Some(len))?;
+            for (t, p) in &self.inner {
+                seq.serialize_element(t)?;
+                seq.serialize_element(p)?;
+            }
+            if let Some(t) = &self.last {
+                seq.serialize_element(t)?;
+            }
+            seq.end()
+        }
+    }
+
+    impl<'de, T, P> Deserialize<'de> for Punctuated<T, P>
+    where
+        T: Deserialize<'de>,
+        P: Deserialize<'de>,
+    {
+        fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            struct PunctuatedVisitor<T, P>(PhantomData<(T, P)>);
+
+            impl<'de, T, P> Visitor<'de> for PunctuatedVisitor<T, P>
+            where
+                T: Deserialize<'de>,
+                P: Deserialize<'de>,
+            {
+                type Value = Punctuated<T, P>;
+
+                fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+                    formatter.write_str("a sequence of values separated by punctuation")
+                }
+
+                fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+                where
+                    A: SeqAccess<'de>,
+                {
+                    let mut punctuated = Punctuated::new();
+                    while let Some(value) = seq.next_element()? {
+                        punctuated.push_value(value);
+                        match seq.next_element()? {
+                            Some(punct) => punctuated.push_punct(punct),
+                            None => break,
+                        }
+                    }
+                    Ok(punctuated)
+                }
+            }
+
+            deserializer.deserialize_seq(PunctuatedVisitor(PhantomData))
+        }
+    }
+}
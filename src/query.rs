@@ -0,0 +1,713 @@
+//! Structural search over syntax trees.
+//!
+//! A [`Pattern`] is a fragment of Rust source in which `$name` stands for an
+//! arbitrary subtree. The pattern is parsed with the ordinary parser for its
+//! type of node, so `$x.unwrap()` is an [`Expr`] and `impl Drop for $t {}` is
+//! an [`Item`]. Matching is structural: a node matches if it is equal to the
+//! pattern once every metavariable has been replaced by the subtree it is
+//! bound to. Spans, and therefore whitespace and formatting, are ignored.
+//!
+//! A metavariable binds to whatever expression, type, pattern or identifier
+//! sits in the position where it is written. A metavariable that occurs more
+//! than once must bind to equal subtrees each time, so `$a == $a` finds
+//! comparisons of a value with itself.
+//!
+//! The wildcard `$..` matches without binding anything. As an entry of a
+//! block, or of the items of an `impl`, `trait`, `mod` or `extern` block, it
+//! stands for any number of statements or items, so `fn drop(&mut self) {
+//! $.. }` matches `drop` whatever its body. As the only generic parameter, as
+//! in `impl<$..> Drop for $t { $.. }`, it stands for any generics including
+//! none, together with their `where` clause.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"parsing"`,
+//! `"printing"`, `"visit"`, `"visit-mut"`, `"clone-impls"` and
+//! `"extra-traits"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::query::{Binding, Pattern};
+//! use syn::{Expr, File};
+//!
+//! let file: File = syn::parse_str("
+//!     fn main() {
+//!         let config = load().unwrap();
+//!         let port = config.port().unwrap_or(80);
+//!         std::env::args().nth(1).unwrap();
+//!     }
+//! ").unwrap();
+//!
+//! let pattern: Pattern<Expr> = Pattern::parse("$receiver.unwrap()").unwrap();
+//! let receivers: Vec<String> = pattern
+//!     .find(&file)
+//!     .iter()
+//!     .map(|found| match found.get("receiver") {
+//!         Some(Binding::Expr(expr)) => quote::quote!(#expr).to_string(),
+//!         _ => unreachable!(),
+//!     })
+//!     .collect();
+//!
+//! assert_eq!(receivers, ["load ()", "std :: env :: args () . nth (1)"]);
+//! ```
+
+use std::mem;
+use std::str::FromStr;
+
+use proc_macro2::{Delimiter, Group, Punct, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+
+use crate::parse::Parse;
+use crate::visit_ctx::{self, Control, Node, VisitCtx};
+use crate::visit_mut::{self, VisitMut};
+use crate::*;
+
+/// Prefix that turns a `$name` metavariable into an identifier the parser
+/// accepts in any position.
+const PREFIX: &str = "__syn_query_";
+
+/// Identifier that a `$..` wildcard turns into. No `$name` produces it.
+const WILDCARD: &str = "__syn_query";
+
+/// A syntax tree node type that patterns can be written as.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"full"`, `"parsing"`,
+/// `"printing"`, `"visit"`, `"visit-mut"`, `"clone-impls"` and
+/// `"extra-traits"` features.*
+pub trait Fragment: Parse + Clone + PartialEq + ToTokens + private::Sealed {
+    #[doc(hidden)]
+    fn to_node(&self) -> Node;
+
+    #[doc(hidden)]
+    fn from_node(node: Node) -> Option<&Self>;
+
+    #[doc(hidden)]
+    fn substitute<V: VisitMut>(&mut self, v: &mut V);
+}
+
+/// A fragment of Rust source with `$name` metavariables, to be matched
+/// against syntax tree nodes of type `T`.
+///
+/// *This type is available if Syn is built with the `"full"`, `"parsing"`,
+/// `"printing"`, `"visit"`, `"visit-mut"`, `"clone-impls"` and
+/// `"extra-traits"` features.*
+#[derive(Debug, Clone)]
+pub struct Pattern<T> {
+    tree: T,
+}
+
+/// A node that matched a [`Pattern`], along with the subtrees its
+/// metavariables are bound to.
+///
+/// *This type is available if Syn is built with the `"full"`, `"parsing"`,
+/// `"printing"`, `"visit"`, `"visit-mut"`, `"clone-impls"` and
+/// `"extra-traits"` features.*
+#[derive(Debug, Clone)]
+pub struct Match<'ast, T> {
+    node: &'ast T,
+    bindings: Vec<(String, Binding<'ast>)>,
+}
+
+/// What a `$..` wildcard stood for in the matched node.
+#[derive(Clone)]
+enum Wildcard<'ast> {
+    Generics(&'ast Generics),
+    List(Vec<Node<'ast>>),
+}
+
+/// The subtree bound to a metavariable.
+///
+/// Use [`Spanned`] to find out where in the searched tree it came from.
+///
+/// [`Spanned`]: crate::spanned::Spanned
+///
+/// *This type is available if Syn is built with the `"full"`, `"parsing"`,
+/// `"printing"`, `"visit"`, `"visit-mut"`, `"clone-impls"` and
+/// `"extra-traits"` features.*
+#[derive(Debug, Clone, Copy)]
+pub enum Binding<'ast> {
+    Expr(&'ast Expr),
+    Type(&'ast Type),
+    Pat(&'ast Pat),
+    Ident(&'ast Ident),
+}
+
+impl<T: Fragment> Pattern<T> {
+    /// Parses a pattern from Rust source in which `$name` stands for any
+    /// subtree.
+    pub fn parse(source: &str) -> Result<Self> {
+        let tokens = TokenStream::from_str(source)?;
+        let tree = parse2(metavariables(tokens)?)?;
+        Ok(Pattern { tree })
+    }
+
+    /// Matches the pattern against `node` itself, without looking at its
+    /// descendants.
+    pub fn matches<'ast>(&self, node: &'ast T) -> Option<Match<'ast, T>> {
+        let pattern = preorder(self.tree.to_node());
+        let target = preorder(node.to_node());
+        self.match_at(&pattern, &target, 0)
+    }
+
+    /// Finds every node within `root`, including `root` itself, that matches
+    /// the pattern.
+    ///
+    /// Matches are returned in the order their nodes begin in the tree, and
+    /// may nest inside one another.
+    pub fn find<'ast, N>(&self, root: N) -> Vec<Match<'ast, T>>
+    where
+        N: Into<Node<'ast>>,
+    {
+        let pattern = preorder(self.tree.to_node());
+        let target = preorder(root.into());
+        (0..target.len())
+            .filter_map(|i| self.match_at(&pattern, &target, i))
+            .collect()
+    }
+
+    fn match_at<'ast>(
+        &self,
+        pattern: &[(Node, usize)],
+        target: &[(Node<'ast>, usize)],
+        start: usize,
+    ) -> Option<Match<'ast, T>> {
+        let node = T::from_node(target[start].0)?;
+        let end = subtree_end(target, start);
+
+        // Line up the two trees node by node in the order they are visited.
+        // This finds what each metavariable stands for; whether everything
+        // around the metavariables agrees is checked afterward.
+        let mut bindings: Vec<(String, Binding<'ast>)> = Vec::new();
+        let mut wildcards = Vec::new();
+        let mut p = 0;
+        let mut t = start;
+        while p < pattern.len() {
+            if is_list_wildcard(pattern[p].0) {
+                // Leave as many statements or items for the rest of the
+                // pattern as it has after the wildcard.
+                let kind = pattern[p].0.kind();
+                let depth = pattern[p].1;
+                let mut after = 0;
+                p = subtree_end(pattern, p);
+                let mut i = p;
+                while i < pattern.len() && pattern[i].1 == depth && pattern[i].0.kind() == kind {
+                    after += 1;
+                    i = subtree_end(pattern, i);
+                }
+                let mut siblings = Vec::new();
+                let depth = depth + target[start].1;
+                let mut i = t;
+                while i < end && target[i].1 == depth && target[i].0.kind() == kind {
+                    siblings.push(i);
+                    i = subtree_end(target, i);
+                }
+                let count = siblings.len().checked_sub(after)?;
+                wildcards.push(Wildcard::List(
+                    siblings[..count].iter().map(|&i| target[i].0).collect(),
+                ));
+                if count > 0 {
+                    t = subtree_end(target, siblings[count - 1]);
+                }
+                continue;
+            }
+            if t == end || pattern[p].0.kind() != target[t].0.kind() {
+                return None;
+            }
+            if let Some(name) = node_metavariable(pattern[p].0) {
+                let binding = Binding::from_node(target[t].0)?;
+                match bindings.iter().find(|(bound, _)| *bound == name) {
+                    Some((_, previous)) if !previous.same_kind(binding) => return None,
+                    Some(_) => {}
+                    None => bindings.push((name, binding)),
+                }
+                p = subtree_end(pattern, p);
+                t = subtree_end(target, t);
+            } else if let (Node::Generics(generics), true) =
+                (target[t].0, is_generics_wildcard(pattern[p].0))
+            {
+                wildcards.push(Wildcard::Generics(generics));
+                p = subtree_end(pattern, p);
+                t = subtree_end(target, t);
+            } else {
+                p += 1;
+                t += 1;
+            }
+        }
+        if t != end {
+            return None;
+        }
+
+        let mut expected = self.tree.clone();
+        expected.substitute(&mut Substitute {
+            bindings: &bindings,
+            wildcards: wildcards.into_iter(),
+        });
+        if expected == *node {
+            Some(Match { node, bindings })
+        } else {
+            None
+        }
+    }
+}
+
+impl<'ast, T> Match<'ast, T> {
+    /// The node that matched the pattern.
+    pub fn node(&self) -> &'ast T {
+        self.node
+    }
+
+    /// The subtree bound to the metavariable `$name`.
+    pub fn get(&self, name: &str) -> Option<Binding<'ast>> {
+        self.bindings
+            .iter()
+            .find(|(bound, _)| bound == name)
+            .map(|(_, binding)| *binding)
+    }
+
+    /// All metavariables of the pattern with their subtrees, in the order
+    /// they first occur in the pattern.
+    pub fn bindings(&self) -> &[(String, Binding<'ast>)] {
+        &self.bindings
+    }
+}
+
+impl<'ast> Binding<'ast> {
+    fn from_node(node: Node<'ast>) -> Option<Self> {
+        match node {
+            Node::Expr(expr) => Some(Binding::Expr(expr)),
+            Node::Type(ty) => Some(Binding::Type(ty)),
+            Node::Pat(pat) => Some(Binding::Pat(pat)),
+            Node::Ident(ident) => Some(Binding::Ident(ident)),
+            _ => None,
+        }
+    }
+
+    fn same_kind(self, other: Self) -> bool {
+        match (self, other) {
+            (Binding::Expr(_), Binding::Expr(_))
+            | (Binding::Type(_), Binding::Type(_))
+            | (Binding::Pat(_), Binding::Pat(_))
+            | (Binding::Ident(_), Binding::Ident(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+impl<'ast> ToTokens for Binding<'ast> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        match self {
+            Binding::Expr(expr) => expr.to_tokens(tokens),
+            Binding::Type(ty) => ty.to_tokens(tokens),
+            Binding::Pat(pat) => pat.to_tokens(tokens),
+            Binding::Ident(ident) => ident.to_tokens(tokens),
+        }
+    }
+}
+
+/// Replaces `$name` with an identifier carrying `PREFIX`, and `$..` with a
+/// `WILDCARD` type parameter after `<` or a `WILDCARD!();` macro elsewhere.
+fn metavariables(tokens: TokenStream) -> Result<TokenStream> {
+    let mut output = TokenStream::new();
+    let mut tokens = tokens.into_iter();
+    let mut after_lt = false;
+    while let Some(token) = tokens.next() {
+        let token = match token {
+            TokenTree::Punct(punct) => {
+                if punct.as_char() != '$' {
+                    TokenTree::Punct(punct)
+                } else {
+                    match tokens.next() {
+                        Some(TokenTree::Ident(name)) => {
+                            let ident = Ident::new(&format!("{}{}", PREFIX, name), name.span());
+                            TokenTree::Ident(ident)
+                        }
+                        Some(TokenTree::Punct(ref dot)) if dot.as_char() == '.' => {
+                            match tokens.next() {
+                                Some(TokenTree::Punct(ref dot)) if dot.as_char() == '.' => {}
+                                _ => return Err(Error::new(dot.span(), "expected `$..`")),
+                            }
+                            let ident = Ident::new(WILDCARD, punct.span());
+                            if !after_lt {
+                                output.extend(wildcard_macro(ident));
+                                continue;
+                            }
+                            TokenTree::Ident(ident)
+                        }
+                        _ => {
+                            return Err(Error::new(
+                                punct.span(),
+                                "expected metavariable name after `$`",
+                            ));
+                        }
+                    }
+                }
+            }
+            TokenTree::Group(group) => {
+                let stream = metavariables(group.stream())?;
+                let mut replacement = Group::new(group.delimiter(), stream);
+                replacement.set_span(group.span());
+                TokenTree::Group(replacement)
+            }
+            token => token,
+        };
+        after_lt = match &token {
+            TokenTree::Punct(punct) => punct.as_char() == '<',
+            _ => false,
+        };
+        output.extend(std::iter::once(token));
+    }
+    Ok(output)
+}
+
+fn wildcard_macro(ident: Ident) -> TokenStream {
+    let span = ident.span();
+    let mut bang = Punct::new('!', Spacing::Alone);
+    bang.set_span(span);
+    let mut args = Group::new(Delimiter::Parenthesis, TokenStream::new());
+    args.set_span(span);
+    let mut semi = Punct::new(';', Spacing::Alone);
+    semi.set_span(span);
+    vec![
+        TokenTree::Ident(ident),
+        TokenTree::Punct(bang),
+        TokenTree::Group(args),
+        TokenTree::Punct(semi),
+    ]
+    .into_iter()
+    .collect()
+}
+
+fn ident_metavariable(ident: &Ident) -> Option<String> {
+    let ident = ident.to_string();
+    if ident.starts_with(PREFIX) {
+        Some(ident[PREFIX.len()..].to_owned())
+    } else {
+        None
+    }
+}
+
+fn path_metavariable(qself: &Option<QSelf>, path: &Path) -> Option<String> {
+    if qself.is_some() {
+        return None;
+    }
+    path.get_ident().and_then(ident_metavariable)
+}
+
+fn expr_metavariable(expr: &Expr) -> Option<String> {
+    match expr {
+        Expr::Path(expr) if expr.attrs.is_empty() => path_metavariable(&expr.qself, &expr.path),
+        _ => None,
+    }
+}
+
+fn type_metavariable(ty: &Type) -> Option<String> {
+    match ty {
+        Type::Path(ty) => path_metavariable(&ty.qself, &ty.path),
+        _ => None,
+    }
+}
+
+fn pat_metavariable(pat: &Pat) -> Option<String> {
+    match pat {
+        Pat::Ident(pat)
+            if pat.attrs.is_empty()
+                && pat.by_ref.is_none()
+                && pat.mutability.is_none()
+                && pat.subpat.is_none() =>
+        {
+            ident_metavariable(&pat.ident)
+        }
+        _ => None,
+    }
+}
+
+fn node_metavariable(node: Node) -> Option<String> {
+    match node {
+        Node::Expr(expr) => expr_metavariable(expr),
+        Node::Type(ty) => type_metavariable(ty),
+        Node::Pat(pat) => pat_metavariable(pat),
+        Node::Ident(ident) => ident_metavariable(ident),
+        _ => None,
+    }
+}
+
+fn is_generics_wildcard(node: Node) -> bool {
+    match node {
+        Node::Generics(generics) => match generics.params.first() {
+            Some(GenericParam::Type(param)) => {
+                generics.params.len() == 1 && param.ident == WILDCARD
+            }
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn is_macro_wildcard(mac: &Macro) -> bool {
+    mac.path.is_ident(WILDCARD)
+}
+
+fn is_stmt_wildcard(stmt: &Stmt) -> bool {
+    match stmt {
+        Stmt::Item(item) => is_item_wildcard(item),
+        Stmt::Expr(Expr::Macro(expr)) | Stmt::Semi(Expr::Macro(expr), _) => {
+            is_macro_wildcard(&expr.mac)
+        }
+        _ => false,
+    }
+}
+
+fn is_item_wildcard(item: &Item) -> bool {
+    match item {
+        Item::Macro(item) => is_macro_wildcard(&item.mac),
+        _ => false,
+    }
+}
+
+fn is_impl_item_wildcard(item: &ImplItem) -> bool {
+    match item {
+        ImplItem::Macro(item) => is_macro_wildcard(&item.mac),
+        _ => false,
+    }
+}
+
+fn is_trait_item_wildcard(item: &TraitItem) -> bool {
+    match item {
+        TraitItem::Macro(item) => is_macro_wildcard(&item.mac),
+        _ => false,
+    }
+}
+
+fn is_foreign_item_wildcard(item: &ForeignItem) -> bool {
+    match item {
+        ForeignItem::Macro(item) => is_macro_wildcard(&item.mac),
+        _ => false,
+    }
+}
+
+fn is_list_wildcard(node: Node) -> bool {
+    match node {
+        Node::Stmt(stmt) => is_stmt_wildcard(stmt),
+        Node::Item(item) => is_item_wildcard(item),
+        Node::ImplItem(item) => is_impl_item_wildcard(item),
+        Node::TraitItem(item) => is_trait_item_wildcard(item),
+        Node::ForeignItem(item) => is_foreign_item_wildcard(item),
+        _ => false,
+    }
+}
+
+/// Every node of a tree in the order it is visited, with its depth.
+fn preorder(root: Node) -> Vec<(Node, usize)> {
+    struct Preorder<'ast>(Vec<(Node<'ast>, usize)>);
+
+    impl<'ast> VisitCtx<'ast> for Preorder<'ast> {
+        type Break = ();
+
+        fn visit_node(&mut self, node: Node<'ast>, ancestors: &[Node<'ast>]) -> Control<()> {
+            self.0.push((node, ancestors.len()));
+            Control::Continue
+        }
+    }
+
+    let mut nodes = Preorder(Vec::new());
+    let _ = visit_ctx::visit_node(&mut nodes, root, &mut Vec::new());
+    nodes.0
+}
+
+/// Index one past the last descendant of `nodes[i]`.
+fn subtree_end(nodes: &[(Node, usize)], i: usize) -> usize {
+    let depth = nodes[i].1;
+    let mut end = i + 1;
+    while end < nodes.len() && nodes[end].1 > depth {
+        end += 1;
+    }
+    end
+}
+
+struct Substitute<'a, 'ast> {
+    bindings: &'a [(String, Binding<'ast>)],
+    // In the order the wildcards are visited.
+    wildcards: std::vec::IntoIter<Wildcard<'ast>>,
+}
+
+impl<'a, 'ast> Substitute<'a, 'ast> {
+    fn get(&self, name: Option<String>) -> Option<Binding<'ast>> {
+        let name = name?;
+        self.bindings
+            .iter()
+            .find(|(bound, _)| *bound == name)
+            .map(|(_, binding)| *binding)
+    }
+
+    /// Replaces each wildcard entry of `list` with the statements or items
+    /// it stood for. Lists are the last field of every node that has one, so
+    /// splicing after the rest of the node keeps wildcards in visiting order.
+    fn splice<T: Clone>(
+        &mut self,
+        list: &mut Vec<T>,
+        is_wildcard: fn(&T) -> bool,
+        from_node: fn(Node<'ast>) -> Option<&'ast T>,
+        visit: fn(&mut Self, &mut T),
+    ) {
+        for mut entry in mem::replace(list, Vec::new()) {
+            if !is_wildcard(&entry) {
+                visit(self, &mut entry);
+                list.push(entry);
+            } else if let Some(Wildcard::List(nodes)) = self.wildcards.next() {
+                list.extend(nodes.into_iter().filter_map(from_node).cloned());
+            }
+        }
+    }
+}
+
+impl<'a, 'ast> VisitMut for Substitute<'a, 'ast> {
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        match self.get(expr_metavariable(node)) {
+            Some(Binding::Expr(expr)) => *node = expr.clone(),
+            _ => visit_mut::visit_expr_mut(self, node),
+        }
+    }
+
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        match self.get(type_metavariable(node)) {
+            Some(Binding::Type(ty)) => *node = ty.clone(),
+            _ => visit_mut::visit_type_mut(self, node),
+        }
+    }
+
+    fn visit_pat_mut(&mut self, node: &mut Pat) {
+        match self.get(pat_metavariable(node)) {
+            Some(Binding::Pat(pat)) => *node = pat.clone(),
+            _ => visit_mut::visit_pat_mut(self, node),
+        }
+    }
+
+    fn visit_ident_mut(&mut self, node: &mut Ident) {
+        if let Some(Binding::Ident(ident)) = self.get(ident_metavariable(node)) {
+            *node = ident.clone();
+        }
+    }
+
+    fn visit_generics_mut(&mut self, node: &mut Generics) {
+        if !is_generics_wildcard(Node::Generics(node)) {
+            visit_mut::visit_generics_mut(self, node);
+        } else if let Some(Wildcard::Generics(generics)) = self.wildcards.next() {
+            *node = generics.clone();
+        }
+    }
+
+    fn visit_block_mut(&mut self, node: &mut Block) {
+        let mut stmts = mem::replace(&mut node.stmts, Vec::new());
+        self.splice(
+            &mut stmts,
+            is_stmt_wildcard,
+            Stmt::from_node,
+            Self::visit_stmt_mut,
+        );
+        node.stmts = stmts;
+    }
+
+    fn visit_file_mut(&mut self, node: &mut File) {
+        let mut items = mem::replace(&mut node.items, Vec::new());
+        visit_mut::visit_file_mut(self, node);
+        self.splice(
+            &mut items,
+            is_item_wildcard,
+            Item::from_node,
+            Self::visit_item_mut,
+        );
+        node.items = items;
+    }
+
+    fn visit_item_foreign_mod_mut(&mut self, node: &mut ItemForeignMod) {
+        let mut items = mem::replace(&mut node.items, Vec::new());
+        visit_mut::visit_item_foreign_mod_mut(self, node);
+        self.splice(
+            &mut items,
+            is_foreign_item_wildcard,
+            ForeignItem::from_node,
+            Self::visit_foreign_item_mut,
+        );
+        node.items = items;
+    }
+
+    fn visit_item_impl_mut(&mut self, node: &mut ItemImpl) {
+        let mut items = mem::replace(&mut node.items, Vec::new());
+        visit_mut::visit_item_impl_mut(self, node);
+        self.splice(
+            &mut items,
+            is_impl_item_wildcard,
+            ImplItem::from_node,
+            Self::visit_impl_item_mut,
+        );
+        node.items = items;
+    }
+
+    fn visit_item_mod_mut(&mut self, node: &mut ItemMod) {
+        let mut content = node.content.take();
+        visit_mut::visit_item_mod_mut(self, node);
+        if let Some((_, items)) = &mut content {
+            self.splice(
+                items,
+                is_item_wildcard,
+                Item::from_node,
+                Self::visit_item_mut,
+            );
+        }
+        node.content = content;
+    }
+
+    fn visit_item_trait_mut(&mut self, node: &mut ItemTrait) {
+        let mut items = mem::replace(&mut node.items, Vec::new());
+        visit_mut::visit_item_trait_mut(self, node);
+        self.splice(
+            &mut items,
+            is_trait_item_wildcard,
+            TraitItem::from_node,
+            Self::visit_trait_item_mut,
+        );
+        node.items = items;
+    }
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+macro_rules! fragment_impls {
+    ($($ty:ident => $method:ident,)*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl Fragment for $ty {
+                fn to_node(&self) -> Node {
+                    Node::$ty(self)
+                }
+
+                fn from_node(node: Node) -> Option<&Self> {
+                    match node {
+                        Node::$ty(node) => Some(node),
+                        _ => None,
+                    }
+                }
+
+                fn substitute<V: VisitMut>(&mut self, v: &mut V) {
+                    v.$method(self);
+                }
+            }
+        )*
+    };
+}
+
+fragment_impls! {
+    Block => visit_block_mut,
+    Expr => visit_expr_mut,
+    ForeignItem => visit_foreign_item_mut,
+    ImplItem => visit_impl_item_mut,
+    Item => visit_item_mut,
+    Pat => visit_pat_mut,
+    Stmt => visit_stmt_mut,
+    TraitItem => visit_trait_item_mut,
+    Type => visit_type_mut,
+}
@@ -0,0 +1,310 @@
+//! Data layout of structs, enums and unions from their `#[repr(...)]`
+//! attributes, and the discriminants of enum variants.
+//!
+//! [`DeriveInput::repr`] reads the `#[repr(...)]` attributes of a type into a
+//! [`Repr`], and [`DataEnum::discriminants`] computes the discriminant of
+//! every variant the way the compiler does: a variant takes the value of its
+//! `= expr` if it has one, which is evaluated with [`syn::eval`], and
+//! otherwise the value of the previous variant plus one, starting from zero.
+//! Discriminants have the primitive integer type named in the `repr`, or
+//! `isize` by default.
+//!
+//! [`syn::eval`]: crate::eval
+//!
+//! *This module is available if Syn is built with the `"derive"`,
+//! `"parsing"` and `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::eval::IntType;
+//! use syn::DeriveInput;
+//!
+//! let input: DeriveInput = syn::parse_str("
+//!     #[repr(u8)]
+//!     enum Opcode {
+//!         Nop,
+//!         Load = 0x10,
+//!         Store,
+//!         Halt = 0xff,
+//!     }
+//! ").unwrap();
+//!
+//! let repr = input.repr().unwrap();
+//! assert_eq!(repr.int, Some(IntType::U8));
+//!
+//! let discriminants: Vec<u128> = input
+//!     .discriminants()
+//!     .unwrap()
+//!     .iter()
+//!     .map(|discriminant| discriminant.to_u128().unwrap())
+//!     .collect();
+//! assert_eq!(discriminants, [0x00, 0x10, 0x11, 0xff]);
+//! ```
+
+use std::collections::HashMap;
+
+use crate::eval::{Evaluator, Int, IntType};
+use crate::*;
+
+/// The representation of a type given by its `#[repr(...)]` attributes.
+///
+/// The default value is the Rust representation, which is what a type
+/// without `#[repr(...)]` attributes has.
+///
+/// *This type is available if Syn is built with the `"derive"`, `"parsing"`
+/// and `"printing"` features.*
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct Repr {
+    /// `repr(C)`: lay out fields as C would.
+    pub c: bool,
+    /// `repr(transparent)`: the same layout as the only non-zero-sized
+    /// field.
+    pub transparent: bool,
+    /// `repr(packed)` or `repr(packed(N))`: the maximum alignment of the
+    /// fields in bytes, which is 1 for a bare `packed`.
+    pub packed: Option<u32>,
+    /// `repr(align(N))`: the minimum alignment of the type in bytes.
+    pub align: Option<u32>,
+    /// `repr(u8)`, `repr(i32)` and so on: the type of the discriminant of an
+    /// enum.
+    pub int: Option<IntType>,
+}
+
+impl Repr {
+    /// Reads the `#[repr(...)]` attributes among `attrs`, which may be more
+    /// than one.
+    ///
+    /// Reports unknown hints, as well as hints that conflict with each
+    /// other or repeat with different values.
+    pub fn from_attrs(attrs: &[Attribute]) -> Result<Self> {
+        let mut repr = Repr::default();
+        let mut errors: Option<Error> = None;
+        for attr in attrs {
+            if !attr.path.is_ident("repr") {
+                continue;
+            }
+            let hints = match attr.parse_meta()? {
+                Meta::List(list) => list.nested,
+                meta => {
+                    return Err(Error::new_spanned(
+                        meta,
+                        "expected a list of hints: `#[repr(...)]`",
+                    ));
+                }
+            };
+            for hint in &hints {
+                if let Err(err) = repr.add(hint) {
+                    push_error(&mut errors, err);
+                }
+            }
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(repr),
+        }
+    }
+
+    /// The type of the discriminant of an enum with this representation:
+    /// the primitive integer type named in the `repr`, or `isize`.
+    pub fn discriminant_type(&self) -> IntType {
+        self.int.unwrap_or(IntType::Isize)
+    }
+
+    fn add(&mut self, hint: &NestedMeta) -> Result<()> {
+        let meta = match hint {
+            NestedMeta::Meta(meta) => meta,
+            NestedMeta::Lit(lit) => {
+                return Err(Error::new_spanned(lit, "expected a representation hint"));
+            }
+        };
+        let name = match meta.path().get_ident() {
+            Some(ident) => ident.to_string(),
+            None => return Err(unrecognized(meta)),
+        };
+        match meta {
+            Meta::Path(_) => match name.as_str() {
+                "Rust" => {}
+                "C" => self.c = true,
+                "transparent" => self.transparent = true,
+                "packed" => set_align(&mut self.packed, 1, meta, "packed")?,
+                _ => match IntType::from_name(&name) {
+                    Some(int) => {
+                        if self.int.map_or(false, |prev| prev != int) {
+                            return Err(Error::new_spanned(
+                                meta,
+                                "conflicting representation hints",
+                            ));
+                        }
+                        self.int = Some(int);
+                    }
+                    None => return Err(unrecognized(meta)),
+                },
+            },
+            Meta::List(list) if name == "packed" || name == "align" => {
+                let value = match list.nested.iter().next() {
+                    Some(NestedMeta::Lit(Lit::Int(lit))) if list.nested.len() == 1 => lit,
+                    _ => {
+                        let message = format!("`{}` expects a literal integer as argument", name);
+                        return Err(Error::new_spanned(list, message));
+                    }
+                };
+                let invalid = |reason: &str| {
+                    let message = format!("invalid `repr({})` attribute: {}", name, reason);
+                    Error::new(value.span(), message)
+                };
+                let value = match value.base10_parse::<u32>() {
+                    Ok(value) if value > 1 << 29 => return Err(invalid("larger than 2^29")),
+                    Ok(value) if value.is_power_of_two() => value,
+                    _ => return Err(invalid("not a power of two")),
+                };
+                if name == "packed" {
+                    set_align(&mut self.packed, value, meta, "packed")?;
+                } else {
+                    set_align(&mut self.align, value, meta, "align")?;
+                }
+            }
+            _ => return Err(unrecognized(meta)),
+        }
+        if self.transparent
+            && (self.c || self.packed.is_some() || self.align.is_some() || self.int.is_some())
+        {
+            return Err(Error::new_spanned(
+                meta,
+                "`repr(transparent)` cannot be combined with other representation hints",
+            ));
+        }
+        if self.packed.is_some() && self.align.is_some() {
+            return Err(Error::new_spanned(
+                meta,
+                "conflicting `packed` and `align` representation hints",
+            ));
+        }
+        Ok(())
+    }
+}
+
+fn set_align(slot: &mut Option<u32>, value: u32, meta: &Meta, name: &str) -> Result<()> {
+    match *slot {
+        Some(prev) if prev != value => Err(Error::new_spanned(
+            meta,
+            format!("conflicting `{}` representation hints", name),
+        )),
+        _ => {
+            *slot = Some(value);
+            Ok(())
+        }
+    }
+}
+
+fn push_error(errors: &mut Option<Error>, err: Error) {
+    match errors {
+        Some(errors) => errors.combine(err),
+        None => *errors = Some(err),
+    }
+}
+
+fn unrecognized(meta: &Meta) -> Error {
+    Error::new_spanned(meta, "unrecognized representation hint")
+}
+
+impl DeriveInput {
+    /// Reads the `#[repr(...)]` attributes of the type.
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn repr(&self) -> Result<Repr> {
+        Repr::from_attrs(&self.attrs)
+    }
+
+    /// Computes the discriminant of every variant of an enum, in order, with
+    /// the type given by its `#[repr(...)]` attributes.
+    ///
+    /// Returns an error if the type is not an enum. See
+    /// [`DataEnum::discriminants`] for the other errors.
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn discriminants(&self) -> Result<Vec<Int>> {
+        match &self.data {
+            Data::Enum(data) => data.discriminants(self.repr()?.discriminant_type()),
+            Data::Struct(data) => Err(Error::new(
+                data.struct_token.span,
+                "only enums have discriminants",
+            )),
+            Data::Union(data) => Err(Error::new(
+                data.union_token.span,
+                "only enums have discriminants",
+            )),
+        }
+    }
+}
+
+impl DataEnum {
+    /// Computes the discriminant of every variant, in order, as a value of
+    /// type `ty`.
+    ///
+    /// Returns an error if an explicit discriminant cannot be evaluated or
+    /// does not fit in `ty`, if an implicit discriminant overflows `ty`, or
+    /// if two variants have the same discriminant.
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn discriminants(&self, ty: IntType) -> Result<Vec<Int>> {
+        self.discriminants_with(ty, &Evaluator::new())
+    }
+
+    /// Computes the discriminant of every variant like [`discriminants`],
+    /// evaluating explicit discriminants with `evaluator`, which may know
+    /// the constants they refer to.
+    ///
+    /// [`discriminants`]: DataEnum::discriminants
+    ///
+    /// *This method is available if Syn is built with the `"derive"`,
+    /// `"parsing"` and `"printing"` features.*
+    pub fn discriminants_with(&self, ty: IntType, evaluator: &Evaluator) -> Result<Vec<Int>> {
+        let mut discriminants = Vec::new();
+        let mut seen = HashMap::new();
+        let mut errors: Option<Error> = None;
+        let mut prev: Option<Int> = None;
+        for variant in &self.variants {
+            let discriminant = match &variant.discriminant {
+                Some((_eq_token, expr)) => evaluator.eval_as(expr, ty),
+                None => match prev {
+                    None => Ok(Int::zero(ty)),
+                    Some(prev) => prev.checked_succ().ok_or_else(|| {
+                        let message = format!(
+                            "enum discriminant overflowed: `{}` overflows after `{}`",
+                            variant.ident, prev,
+                        );
+                        Error::new(variant.ident.span(), message)
+                    }),
+                },
+            };
+            let discriminant = match discriminant {
+                Ok(discriminant) => discriminant,
+                Err(err) => {
+                    // Later variants cannot be numbered without a value for
+                    // this one.
+                    push_error(&mut errors, err);
+                    break;
+                }
+            };
+            if let Some(first) = seen.insert(discriminant, &variant.ident) {
+                let message = format!(
+                    "discriminant value `{}` assigned more than once",
+                    discriminant,
+                );
+                let label = format!("first use of `{}`", discriminant);
+                let err = Error::new(variant.ident.span(), message).label(first.span(), label);
+                push_error(&mut errors, err);
+            }
+            discriminants.push(discriminant);
+            prev = Some(discriminant);
+        }
+        match errors {
+            Some(errors) => Err(errors),
+            None => Ok(discriminants),
+        }
+    }
+}
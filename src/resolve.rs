@@ -0,0 +1,913 @@
+//! Resolution of paths to the items and bindings they refer to.
+//!
+//! A [`Resolver`] builds a table of the modules of a [`File`]: the items each
+//! module defines, and the names it brings in with `use`. Given a [`Path`]
+//! somewhere inside that file, it works outward through the enclosing
+//! scopes the way the compiler does to find what the path names:
+//!
+//! - a `let`, parameter, closure, `match` arm, `for` or `if let` binding that
+//!   is in scope at that point;
+//! - a generic type or const parameter of an enclosing item;
+//! - an item of the file or an import, which is reported by its fully
+//!   qualified path beginning with `crate`;
+//! - a name from the standard library prelude, or a path through another
+//!   crate, which is reported by its absolute path beginning with `::`;
+//! - a primitive type.
+//!
+//! Type, value and macro names live in separate namespaces, as in the
+//! language, so a `let Result = ...;` does not hide the type `Result`.
+//!
+//! Names are compared as written. Spans do not expose their hygiene context
+//! outside of the compiler, so an identifier introduced by a `macro_rules!`
+//! expansion is treated like one written directly in the file. Items declared
+//! inside function bodies are not recorded, nor are the contents of modules
+//! loaded from other files, and names brought in by a glob import from
+//! another crate cannot be resolved.
+//!
+//! *This module is available if Syn is built with the `"full"`, `"visit"`
+//! and `"clone-impls"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::resolve::{Resolution, Resolver};
+//! use syn::{File, Item, Path, ReturnType, Type};
+//!
+//! let file: File = syn::parse_str("
+//!     mod error {
+//!         pub struct Result;
+//!     }
+//!
+//!     fn std_result() -> Result<(), ()> { Ok(()) }
+//!
+//!     use error::Result;
+//!     fn own_result() -> Result { Result }
+//! ").unwrap();
+//!
+//! fn return_type(item: &Item) -> &Path {
+//!     if let Item::Fn(item) = item {
+//!         if let ReturnType::Type(_, ty) = &item.sig.output {
+//!             if let Type::Path(ty) = &**ty {
+//!                 return &ty.path;
+//!             }
+//!         }
+//!     }
+//!     unimplemented!()
+//! }
+//!
+//! let resolver = Resolver::new(&file);
+//! let resolve = |path| match resolver.resolve(path) {
+//!     Some(Resolution::Item(path)) => quote!(#path).to_string(),
+//!     _ => unimplemented!(),
+//! };
+//!
+//! // Before the `use`, as everywhere else in the module, `Result` is the
+//! // imported one: imports are not ordered.
+//! assert_eq!(resolve(return_type(&file.items[1])), "crate :: error :: Result < () , () >");
+//! assert_eq!(resolve(return_type(&file.items[3])), "crate :: error :: Result");
+//! ```
+
+use std::ptr;
+
+use proc_macro2::Span;
+
+use crate::punctuated::Punctuated;
+use crate::visit::{self, Visit};
+use crate::visit_ctx::{self, Control, Node, VisitCtx};
+use crate::*;
+
+/// What a path refers to.
+///
+/// *This type is available if Syn is built with the `"full"`, `"visit"` and
+/// `"clone-impls"` features.*
+#[cfg_attr(feature = "extra-traits", derive(Debug, PartialEq, Eq))]
+#[derive(Clone)]
+pub enum Resolution<'ast> {
+    /// A local variable, identified by the identifier that binds it.
+    Local(&'ast Ident),
+    /// A generic type or const parameter, identified by its declaration.
+    Generic(&'ast Ident),
+    /// An item, identified by its fully qualified path: `crate::...` for the
+    /// items of the file and `::...` for those of other crates.
+    Item(Path),
+    /// A primitive type such as `u8` or `str`.
+    Primitive,
+}
+
+/// Resolves paths within a file.
+///
+/// See the [module documentation] for details.
+///
+/// [module documentation]: self
+///
+/// *This type is available if Syn is built with the `"full"`, `"visit"` and
+/// `"clone-impls"` features.*
+pub struct Resolver<'ast> {
+    file: &'ast File,
+    modules: Vec<Module<'ast>>,
+    // Crates renamed by an `extern crate` at the crate root, which are in
+    // scope in every module.
+    extern_prelude: Vec<(&'ast Ident, &'ast Ident)>,
+}
+
+struct Module<'ast> {
+    item: Option<&'ast ItemMod>,
+    path: Vec<Ident>,
+    parent: Option<usize>,
+    // False for `mod m;`, whose items live in a different file.
+    known: bool,
+    defs: Vec<Def<'ast>>,
+    imports: Vec<Import<'ast>>,
+}
+
+struct Def<'ast> {
+    ident: &'ast Ident,
+    ns: u8,
+    module: Option<usize>,
+}
+
+struct Import<'ast> {
+    // None for a glob import.
+    name: Option<&'ast Ident>,
+    leading_colon: bool,
+    path: Vec<&'ast Ident>,
+}
+
+/// Where a prefix of a path has been resolved to so far.
+struct Target {
+    external: bool,
+    // Below `crate` for items of this file, and below `::` otherwise.
+    segments: Vec<PathSegment>,
+    module: Option<usize>,
+}
+
+enum Lookup {
+    Found(Target),
+    NotFound,
+    // The name may come from a glob import whose contents are unknown.
+    Unknown,
+}
+
+const TYPE: u8 = 0b001;
+const VALUE: u8 = 0b010;
+const MACRO: u8 = 0b100;
+
+/// Guards against cycles between imports.
+const MAX_DEPTH: usize = 32;
+
+impl<'ast> Resolver<'ast> {
+    /// Builds the module table of `file`.
+    pub fn new(file: &'ast File) -> Self {
+        let mut resolver = Resolver {
+            file,
+            modules: Vec::new(),
+            extern_prelude: Vec::new(),
+        };
+        resolver.add_module(None, Vec::new(), None, Some(&file.items));
+        resolver
+    }
+
+    /// Resolves a path that is part of the file this resolver was built
+    /// from.
+    ///
+    /// Returns `None` if the path is not inside the file, names something
+    /// that is not in scope, or names an associated item through a generic
+    /// parameter, which would require type checking.
+    pub fn resolve(&self, path: &'ast Path) -> Option<Resolution<'ast>> {
+        let ancestors = self.locate(path)?;
+        let ns = match ancestors.last() {
+            Some(Node::TypePath(_))
+            | Some(Node::TraitBound(_))
+            | Some(Node::ItemImpl(_))
+            | Some(Node::ExprStruct(_))
+            | Some(Node::PatStruct(_))
+            | Some(Node::VisRestricted(_)) => TYPE,
+            Some(Node::Macro(_)) | Some(Node::Attribute(_)) => MACRO,
+            _ => VALUE,
+        };
+        self.resolve_in(&ancestors, Some(path), path, ns, 0)
+    }
+
+    fn locate(&self, path: &'ast Path) -> Option<Vec<Node<'ast>>> {
+        struct Locate<'ast>(&'ast Path);
+
+        impl<'ast> VisitCtx<'ast> for Locate<'ast> {
+            type Break = ();
+
+            fn visit_path(&mut self, node: &'ast Path, _: &[Node<'ast>]) -> Control<()> {
+                if ptr::eq(node, self.0) {
+                    Control::Break(())
+                } else {
+                    Control::Continue
+                }
+            }
+        }
+
+        let mut ancestors = Vec::new();
+        match visit_ctx::visit_file(&mut Locate(path), self.file, &mut ancestors) {
+            Ok(()) => None,
+            Err(()) => Some(ancestors),
+        }
+    }
+
+    fn add_module(
+        &mut self,
+        item: Option<&'ast ItemMod>,
+        path: Vec<Ident>,
+        parent: Option<usize>,
+        items: Option<&'ast [Item]>,
+    ) -> usize {
+        let index = self.modules.len();
+        self.modules.push(Module {
+            item,
+            path,
+            parent,
+            known: items.is_some(),
+            defs: Vec::new(),
+            imports: Vec::new(),
+        });
+
+        for item in items.unwrap_or(&[]) {
+            let (ident, ns) = match item {
+                Item::Const(item) => (&item.ident, VALUE),
+                Item::Enum(item) => (&item.ident, TYPE),
+                Item::ExternCrate(item) => {
+                    let name = match &item.rename {
+                        Some((_, rename)) => rename,
+                        None => &item.ident,
+                    };
+                    if index == 0 {
+                        self.extern_prelude.push((name, &item.ident));
+                    }
+                    if name != "_" {
+                        self.modules[index].imports.push(Import {
+                            name: Some(name),
+                            leading_colon: true,
+                            path: vec![&item.ident],
+                        });
+                    }
+                    continue;
+                }
+                Item::Fn(item) => (&item.sig.ident, VALUE),
+                Item::ForeignMod(item) => {
+                    for item in &item.items {
+                        let def = match item {
+                            ForeignItem::Fn(item) => (&item.sig.ident, VALUE),
+                            ForeignItem::Static(item) => (&item.ident, VALUE),
+                            ForeignItem::Type(item) => (&item.ident, TYPE),
+                            _ => continue,
+                        };
+                        self.add_def(index, def.0, def.1, None);
+                    }
+                    continue;
+                }
+                Item::Macro(item) => match &item.ident {
+                    Some(ident) => (ident, MACRO),
+                    None => continue,
+                },
+                Item::Macro2(item) => (&item.ident, MACRO),
+                Item::Mod(item) => {
+                    let mut path = self.modules[index].path.clone();
+                    path.push(item.ident.clone());
+                    let items = item.content.as_ref().map(|(_, items)| &items[..]);
+                    let module = self.add_module(Some(item), path, Some(index), items);
+                    self.add_def(index, &item.ident, TYPE, Some(module));
+                    continue;
+                }
+                Item::Static(item) => (&item.ident, VALUE),
+                Item::Struct(item) => match item.fields {
+                    Fields::Named(_) => (&item.ident, TYPE),
+                    Fields::Unnamed(_) | Fields::Unit => (&item.ident, TYPE | VALUE),
+                },
+                Item::Trait(item) => (&item.ident, TYPE),
+                Item::TraitAlias(item) => (&item.ident, TYPE),
+                Item::Type(item) => (&item.ident, TYPE),
+                Item::Union(item) => (&item.ident, TYPE),
+                Item::Use(item) => {
+                    let mut prefix = Vec::new();
+                    let leading_colon = item.leading_colon.is_some();
+                    self.add_imports(index, leading_colon, &mut prefix, &item.tree);
+                    continue;
+                }
+                _ => continue,
+            };
+            self.add_def(index, ident, ns, None);
+        }
+
+        index
+    }
+
+    fn add_def(&mut self, module: usize, ident: &'ast Ident, ns: u8, def: Option<usize>) {
+        self.modules[module].defs.push(Def {
+            ident,
+            ns,
+            module: def,
+        });
+    }
+
+    fn add_imports(
+        &mut self,
+        module: usize,
+        leading_colon: bool,
+        prefix: &mut Vec<&'ast Ident>,
+        tree: &'ast UseTree,
+    ) {
+        let mut import = |name: Option<&'ast Ident>, path: Vec<&'ast Ident>| {
+            if name.map_or(true, |name| name != "_") {
+                self.modules[module].imports.push(Import {
+                    name,
+                    leading_colon,
+                    path,
+                });
+            }
+        };
+
+        match tree {
+            UseTree::Path(tree) => {
+                prefix.push(&tree.ident);
+                self.add_imports(module, leading_colon, prefix, &tree.tree);
+                prefix.pop();
+            }
+            UseTree::Name(tree) => {
+                let mut path = prefix.clone();
+                let name = if tree.ident == "self" {
+                    *prefix.last().unwrap_or(&&tree.ident)
+                } else {
+                    path.push(&tree.ident);
+                    &tree.ident
+                };
+                import(Some(name), path);
+            }
+            UseTree::Rename(tree) => {
+                let mut path = prefix.clone();
+                if tree.ident != "self" {
+                    path.push(&tree.ident);
+                }
+                import(Some(&tree.rename), path);
+            }
+            UseTree::Glob(_) => import(None, prefix.clone()),
+            UseTree::Group(tree) => {
+                for tree in &tree.items {
+                    self.add_imports(module, leading_colon, prefix, tree);
+                }
+            }
+        }
+    }
+
+    fn resolve_in(
+        &self,
+        ancestors: &[Node<'ast>],
+        child: Option<&'ast Path>,
+        path: &Path,
+        ns: u8,
+        depth: usize,
+    ) -> Option<Resolution<'ast>> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+
+        let module = self.enclosing_module(ancestors);
+        let first = &path.segments[0];
+        let rest: Vec<&PathSegment> = path.segments.iter().skip(1).collect();
+        let first_ns = if rest.is_empty() { ns } else { TYPE };
+
+        let target = if path.leading_colon.is_some() {
+            Target {
+                external: true,
+                segments: vec![first.clone()],
+                module: None,
+            }
+        } else if first.ident == "Self" {
+            match self.self_type(ancestors, depth)? {
+                Resolution::Item(path) => target_of(path, &first.arguments),
+                resolution => {
+                    return if rest.is_empty() {
+                        Some(resolution)
+                    } else {
+                        None
+                    }
+                }
+            }
+        } else if first.ident == "crate" || first.ident == "self" || first.ident == "super" {
+            if first.ident == "self" && rest.is_empty() {
+                // The receiver of a method.
+                return None;
+            }
+            self.module_target(module, &first.ident)?
+        } else {
+            if rest.is_empty() {
+                if ns == VALUE {
+                    if let Some(local) = self.local(ancestors, child, module, &first.ident) {
+                        return Some(Resolution::Local(local));
+                    }
+                }
+                if let Some(param) = generic(ancestors, &first.ident, ns) {
+                    return Some(Resolution::Generic(param));
+                }
+            } else if generic(ancestors, &first.ident, TYPE).is_some() {
+                return None;
+            }
+            match self.lookup_in(module, &first.ident, first_ns, depth) {
+                Lookup::Found(mut target) => {
+                    set_arguments(&mut target, &first.arguments);
+                    target
+                }
+                Lookup::Unknown => return None,
+                Lookup::NotFound => {
+                    if let Some(target) = prelude(first, first_ns) {
+                        target
+                    } else if rest.is_empty() {
+                        return if first_ns == TYPE && is_primitive(&first.ident) {
+                            Some(Resolution::Primitive)
+                        } else {
+                            None
+                        };
+                    } else {
+                        // A crate from the extern prelude.
+                        let mut krate = first.clone();
+                        for (name, original) in &self.extern_prelude {
+                            if **name == first.ident {
+                                krate.ident = (*original).clone();
+                            }
+                        }
+                        Target {
+                            external: true,
+                            segments: vec![krate],
+                            module: None,
+                        }
+                    }
+                }
+            }
+        };
+
+        self.finish(target, &rest, ns, depth)
+    }
+
+    /// Resolves the remaining segments of a path, first through modules of
+    /// this file and then verbatim once the path leaves them.
+    fn finish(
+        &self,
+        mut target: Target,
+        rest: &[&PathSegment],
+        ns: u8,
+        depth: usize,
+    ) -> Option<Resolution<'ast>> {
+        for (i, segment) in rest.iter().enumerate() {
+            let segment_ns = if i + 1 == rest.len() { ns } else { TYPE };
+            let module = target.module.filter(|&module| self.modules[module].known);
+            target = match module {
+                Some(module) if segment.ident == "super" => {
+                    self.module_target(module, &segment.ident)?
+                }
+                Some(module) => match self.lookup_in(module, &segment.ident, segment_ns, depth) {
+                    Lookup::Found(mut target) => {
+                        set_arguments(&mut target, &segment.arguments);
+                        target
+                    }
+                    Lookup::NotFound | Lookup::Unknown => return None,
+                },
+                None => {
+                    target.segments.push((*segment).clone());
+                    target.module = None;
+                    target
+                }
+            };
+        }
+
+        let mut segments = Punctuated::new();
+        let leading_colon = if target.external {
+            Some(Token![::](Span::call_site()))
+        } else {
+            segments.push(PathSegment::from(Ident::new("crate", Span::call_site())));
+            None
+        };
+        segments.extend(target.segments);
+        Some(Resolution::Item(Path {
+            leading_colon,
+            segments,
+        }))
+    }
+
+    fn module_target(&self, module: usize, keyword: &Ident) -> Option<Target> {
+        let module = if keyword == "crate" {
+            0
+        } else if keyword == "super" {
+            self.modules[module].parent?
+        } else {
+            module
+        };
+        Some(Target {
+            external: false,
+            segments: self.modules[module]
+                .path
+                .iter()
+                .cloned()
+                .map(PathSegment::from)
+                .collect(),
+            module: Some(module),
+        })
+    }
+
+    fn enclosing_module(&self, ancestors: &[Node<'ast>]) -> usize {
+        for node in ancestors.iter().rev() {
+            if let Node::ItemMod(item) = node {
+                let found = self.modules.iter().position(|module| match module.item {
+                    Some(module) => ptr::eq(module, *item),
+                    None => false,
+                });
+                if let Some(index) = found {
+                    return index;
+                }
+            }
+        }
+        0
+    }
+
+    /// Looks a name up among the definitions and imports of a module.
+    fn lookup_in(&self, module: usize, name: &Ident, ns: u8, depth: usize) -> Lookup {
+        if depth > MAX_DEPTH {
+            return Lookup::NotFound;
+        }
+        let m = &self.modules[module];
+
+        for def in &m.defs {
+            if def.ident == name && def.ns & ns != 0 {
+                let mut segments: Vec<PathSegment> =
+                    m.path.iter().cloned().map(PathSegment::from).collect();
+                segments.push(PathSegment::from(def.ident.clone()));
+                return Lookup::Found(Target {
+                    external: false,
+                    segments,
+                    module: def.module,
+                });
+            }
+        }
+
+        for import in &m.imports {
+            if import.name.map_or(false, |import| import == name) {
+                if let Some(target) = self.import_target(module, import, ns, depth + 1) {
+                    return Lookup::Found(target);
+                }
+            }
+        }
+
+        let mut unknown = false;
+        for import in &m.imports {
+            if import.name.is_some() {
+                continue;
+            }
+            match self.import_target(module, import, TYPE, depth + 1) {
+                Some(Target {
+                    module: Some(glob), ..
+                }) if self.modules[glob].known => match self.lookup_in(glob, name, ns, depth + 1) {
+                    Lookup::Found(target) => return Lookup::Found(target),
+                    Lookup::NotFound => {}
+                    Lookup::Unknown => unknown = true,
+                },
+                _ => unknown = true,
+            }
+        }
+
+        if unknown {
+            Lookup::Unknown
+        } else {
+            Lookup::NotFound
+        }
+    }
+
+    fn import_target(
+        &self,
+        module: usize,
+        import: &Import<'ast>,
+        ns: u8,
+        depth: usize,
+    ) -> Option<Target> {
+        let mut segments = import
+            .path
+            .iter()
+            .map(|&ident| PathSegment::from(ident.clone()));
+        let first = segments.next()?;
+        let rest: Vec<PathSegment> = segments.collect();
+        let rest: Vec<&PathSegment> = rest.iter().collect();
+        let first_ns = if rest.is_empty() { ns } else { TYPE };
+
+        let target = if import.leading_colon {
+            Target {
+                external: true,
+                segments: vec![first],
+                module: None,
+            }
+        } else if first.ident == "crate" || first.ident == "self" || first.ident == "super" {
+            self.module_target(module, &first.ident)?
+        } else {
+            match self.lookup_in(module, &first.ident, first_ns, depth) {
+                Lookup::Found(target) => target,
+                Lookup::Unknown => return None,
+                Lookup::NotFound => prelude(&first, first_ns).unwrap_or(Target {
+                    external: true,
+                    segments: vec![first],
+                    module: None,
+                }),
+            }
+        };
+
+        match self.finish(target, &rest, ns, depth)? {
+            Resolution::Item(path) => {
+                let mut target = target_of(path, &PathArguments::None);
+                target.module = self.module_of(&target);
+                Some(target)
+            }
+            _ => None,
+        }
+    }
+
+    /// Finds the module of this file that a resolved path names, if any.
+    fn module_of(&self, target: &Target) -> Option<usize> {
+        if target.external {
+            return None;
+        }
+        self.modules.iter().position(|module| {
+            module.path.len() == target.segments.len()
+                && module
+                    .path
+                    .iter()
+                    .zip(&target.segments)
+                    .all(|(a, b)| *a == b.ident)
+        })
+    }
+
+    fn local(
+        &self,
+        ancestors: &[Node<'ast>],
+        child: Option<&'ast Path>,
+        module: usize,
+        name: &Ident,
+    ) -> Option<&'ast Ident> {
+        let mut child = child.map(Node::Path);
+        for &node in ancestors.iter().rev() {
+            let pats: Vec<&'ast Pat> = match node {
+                Node::Block(block) => {
+                    let position = block.stmts.iter().position(|stmt| match child {
+                        Some(Node::Stmt(child)) => ptr::eq(stmt, child),
+                        _ => false,
+                    });
+                    let stmts = &block.stmts[..position.unwrap_or(0)];
+                    stmts
+                        .iter()
+                        .rev()
+                        .filter_map(|stmt| match stmt {
+                            Stmt::Local(local) => Some(&local.pat),
+                            _ => None,
+                        })
+                        .collect()
+                }
+                Node::ExprClosure(expr) if is_expr(child, &expr.body) => {
+                    expr.inputs.iter().collect()
+                }
+                Node::Arm(arm) if !is_pat(child, &arm.pat) => vec![&arm.pat],
+                Node::ExprForLoop(expr) if is_block(child, &expr.body) => vec![&expr.pat],
+                Node::ExprIf(ExprIf {
+                    cond, then_branch, ..
+                })
+                | Node::ExprWhile(ExprWhile {
+                    cond,
+                    body: then_branch,
+                    ..
+                }) if is_block(child, then_branch) => match &**cond {
+                    Expr::Let(expr) => vec![&expr.pat],
+                    _ => Vec::new(),
+                },
+                Node::ItemFn(ItemFn { sig, .. })
+                | Node::ImplItemMethod(ImplItemMethod { sig, .. })
+                | Node::TraitItemMethod(TraitItemMethod { sig, .. }) => sig
+                    .inputs
+                    .iter()
+                    .filter_map(|arg| match arg {
+                        FnArg::Typed(arg) => Some(&*arg.pat),
+                        FnArg::Receiver(_) => None,
+                    })
+                    .collect(),
+                Node::Item(_) | Node::ImplItem(_) | Node::TraitItem(_) | Node::ForeignItem(_) => {
+                    return None
+                }
+                _ => Vec::new(),
+            };
+
+            for pat in pats {
+                if let Some(ident) = self.binding(pat, module, name) {
+                    return Some(ident);
+                }
+            }
+            child = Some(node);
+        }
+        None
+    }
+
+    /// Finds where `pat` binds `name`, telling bindings apart from constants
+    /// and unit structs or variants written as a single identifier.
+    fn binding(&self, pat: &'ast Pat, module: usize, name: &Ident) -> Option<&'ast Ident> {
+        struct Bindings<'ast>(Vec<&'ast PatIdent>);
+
+        impl<'ast> Visit<'ast> for Bindings<'ast> {
+            fn visit_pat_ident(&mut self, node: &'ast PatIdent) {
+                self.0.push(node);
+                visit::visit_pat_ident(self, node);
+            }
+        }
+
+        let mut bindings = Bindings(Vec::new());
+        bindings.visit_pat(pat);
+        bindings
+            .0
+            .into_iter()
+            .find(|pat| {
+                pat.ident == *name
+                    && (pat.by_ref.is_some()
+                        || pat.mutability.is_some()
+                        || pat.subpat.is_some()
+                        || !self.is_value_item(module, name))
+            })
+            .map(|pat| &pat.ident)
+    }
+
+    fn is_value_item(&self, module: usize, name: &Ident) -> bool {
+        match self.lookup_in(module, name, VALUE, 0) {
+            Lookup::Found(_) | Lookup::Unknown => true,
+            Lookup::NotFound => prelude(&PathSegment::from(name.clone()), VALUE).is_some(),
+        }
+    }
+
+    fn self_type(&self, ancestors: &[Node<'ast>], depth: usize) -> Option<Resolution<'ast>> {
+        for (i, node) in ancestors.iter().enumerate().rev() {
+            let ident = match node {
+                Node::ItemImpl(item) => {
+                    return match &*item.self_ty {
+                        Type::Path(ty) if ty.qself.is_none() => {
+                            self.resolve_in(&ancestors[..=i], None, &ty.path, TYPE, depth + 1)
+                        }
+                        _ => None,
+                    };
+                }
+                Node::ItemStruct(item) => &item.ident,
+                Node::ItemEnum(item) => &item.ident,
+                Node::ItemUnion(item) => &item.ident,
+                Node::ItemTrait(_) => return None,
+                _ => continue,
+            };
+            let module = self.enclosing_module(&ancestors[..i]);
+            return match self.lookup_in(module, ident, TYPE, depth) {
+                Lookup::Found(target) => self.finish(target, &[], TYPE, depth),
+                Lookup::NotFound | Lookup::Unknown => None,
+            };
+        }
+        None
+    }
+}
+
+fn generic<'ast>(ancestors: &[Node<'ast>], name: &Ident, ns: u8) -> Option<&'ast Ident> {
+    for node in ancestors.iter().rev() {
+        let (generics, nested) = match node {
+            Node::ItemFn(item) => (&item.sig.generics, false),
+            Node::ItemStruct(item) => (&item.generics, false),
+            Node::ItemEnum(item) => (&item.generics, false),
+            Node::ItemUnion(item) => (&item.generics, false),
+            Node::ItemTrait(item) => (&item.generics, false),
+            Node::ItemTraitAlias(item) => (&item.generics, false),
+            Node::ItemType(item) => (&item.generics, false),
+            Node::ItemImpl(item) => (&item.generics, false),
+            Node::ForeignItemFn(item) => (&item.sig.generics, false),
+            Node::ImplItemMethod(item) => (&item.sig.generics, true),
+            Node::ImplItemType(item) => (&item.generics, true),
+            Node::TraitItemMethod(item) => (&item.sig.generics, true),
+            Node::TraitItemType(item) => (&item.generics, true),
+            _ => continue,
+        };
+        for param in &generics.params {
+            match param {
+                GenericParam::Type(param) if ns == TYPE && param.ident == *name => {
+                    return Some(&param.ident);
+                }
+                GenericParam::Const(param) if ns == VALUE && param.ident == *name => {
+                    return Some(&param.ident);
+                }
+                _ => {}
+            }
+        }
+        if !nested {
+            return None;
+        }
+    }
+    None
+}
+
+fn target_of(path: Path, arguments: &PathArguments) -> Target {
+    let external = path.leading_colon.is_some();
+    let mut segments: Vec<PathSegment> = path.segments.into_iter().collect();
+    if !external {
+        segments.remove(0);
+    }
+    let mut target = Target {
+        external,
+        segments,
+        module: None,
+    };
+    set_arguments(&mut target, arguments);
+    target
+}
+
+fn is_block(node: Option<Node>, block: &Block) -> bool {
+    match node {
+        Some(Node::Block(node)) => ptr::eq(node, block),
+        _ => false,
+    }
+}
+
+fn is_expr(node: Option<Node>, expr: &Expr) -> bool {
+    match node {
+        Some(Node::Expr(node)) => ptr::eq(node, expr),
+        _ => false,
+    }
+}
+
+fn is_pat(node: Option<Node>, pat: &Pat) -> bool {
+    match node {
+        Some(Node::Pat(node)) => ptr::eq(node, pat),
+        _ => false,
+    }
+}
+
+fn set_arguments(target: &mut Target, arguments: &PathArguments) {
+    if let Some(last) = target.segments.last_mut() {
+        if let PathArguments::None = arguments {
+            return;
+        }
+        last.arguments = arguments.clone();
+    }
+}
+
+fn is_primitive(ident: &Ident) -> bool {
+    let primitives = [
+        "bool", "char", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "str", "u8",
+        "u16", "u32", "u64", "u128", "usize",
+    ];
+    primitives.iter().any(|primitive| ident == primitive)
+}
+
+/// The path through `std` of a name from the standard library prelude.
+fn prelude(segment: &PathSegment, ns: u8) -> Option<Target> {
+    let name = segment.ident.to_string();
+    let path: &[&str] = match (ns, &name[..]) {
+        (TYPE, "Copy") | (TYPE, "Send") | (TYPE, "Sized") | (TYPE, "Sync") | (TYPE, "Unpin") => {
+            &["marker"]
+        }
+        (TYPE, "Drop") | (TYPE, "Fn") | (TYPE, "FnMut") | (TYPE, "FnOnce") => &["ops"],
+        (VALUE, "drop") => &["mem"],
+        (TYPE, "Box") => &["boxed"],
+        (TYPE, "ToOwned") => &["borrow"],
+        (TYPE, "Clone") => &["clone"],
+        (TYPE, "PartialEq") | (TYPE, "PartialOrd") | (TYPE, "Eq") | (TYPE, "Ord") => &["cmp"],
+        (TYPE, "AsRef") | (TYPE, "AsMut") | (TYPE, "Into") | (TYPE, "From") => &["convert"],
+        (TYPE, "Default") => &["default"],
+        (TYPE, "Iterator")
+        | (TYPE, "Extend")
+        | (TYPE, "IntoIterator")
+        | (TYPE, "DoubleEndedIterator")
+        | (TYPE, "ExactSizeIterator") => &["iter"],
+        (TYPE, "Option") => &["option"],
+        (VALUE, "Some") | (VALUE, "None") => &["option", "Option"],
+        (TYPE, "Result") => &["result"],
+        (VALUE, "Ok") | (VALUE, "Err") => &["result", "Result"],
+        (TYPE, "String") | (TYPE, "ToString") => &["string"],
+        (TYPE, "Vec") => &["vec"],
+        (MACRO, "assert")
+        | (MACRO, "assert_eq")
+        | (MACRO, "assert_ne")
+        | (MACRO, "debug_assert")
+        | (MACRO, "debug_assert_eq")
+        | (MACRO, "debug_assert_ne")
+        | (MACRO, "eprint")
+        | (MACRO, "eprintln")
+        | (MACRO, "format")
+        | (MACRO, "panic")
+        | (MACRO, "print")
+        | (MACRO, "println")
+        | (MACRO, "unimplemented")
+        | (MACRO, "unreachable")
+        | (MACRO, "vec")
+        | (MACRO, "write")
+        | (MACRO, "writeln") => &[],
+        _ => return None,
+    };
+
+    let mut segments = vec![PathSegment::from(Ident::new("std", Span::call_site()))];
+    for module in path {
+        segments.push(PathSegment::from(Ident::new(module, Span::call_site())));
+    }
+    segments.push(segment.clone());
+    Some(Target {
+        external: true,
+        segments,
+        module: None,
+    })
+}
@@ -0,0 +1,536 @@
+//! Mapping syntax tree nodes back to the source text they were parsed from.
+//!
+//! Spans produced by `proc_macro2` outside of a procedural macro normally
+//! carry no location information. With the `"span-locations"` feature enabled,
+//! parsing through [`SourceMap::parse_str`] or [`SourceMap::parse_file`]
+//! returns the syntax tree together with a `SourceMap` that resolves the span
+//! of any node in that tree to a byte range, a line and column, or the
+//! original text.
+//!
+//! *This module is available if Syn is built with the `"parsing"`,
+//! `"printing"` and `"span-locations"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use syn::source_map::SourceMap;
+//! use syn::{Expr, ExprBinary};
+//!
+//! # fn main() -> syn::Result<()> {
+//! let (expr, map) = SourceMap::parse_str::<ExprBinary>("first\n    + second")?;
+//!
+//! let right: &Expr = &expr.right;
+//! assert_eq!(map.source_text(right), Some("second"));
+//! assert_eq!(map.byte_range(right), Some(12..18));
+//!
+//! let start = map.start(right).unwrap();
+//! assert_eq!((start.line, start.column), (2, 6));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Lossless printing
+//!
+//! The syntax tree itself does not hold on to comments and whitespace, but the
+//! source map does. Every token parsed from the source has *trivia* attached:
+//! the whitespace and comments before it on its own line and preceding lines
+//! are its leading trivia, and those after it up to the end of its line are
+//! its trailing trivia. [`SourceMap::print`] prints a syntax tree node back out
+//! together with the trivia of its tokens, so an unmodified `File` prints as
+//! exactly the input it was parsed from. Tokens that the parser leaves out of
+//! the syntax tree, like stray semicolons between statements, are remembered
+//! by the map and printed in their place too.
+//!
+//! Tokens created after parsing have no trivia and are printed with a single
+//! space between them. Trivia belongs to the token it is attached to, so
+//! replacing a node also drops the comments around it, while a token that
+//! keeps the span of the one it replaces keeps its trivia too. Either way a
+//! tool that rewrites one function leaves the rest of the file byte-identical.
+//!
+//! ```
+//! use proc_macro2::Ident;
+//! use syn::source_map::SourceMap;
+//! use syn::Item;
+//!
+//! # fn main() -> syn::Result<()> {
+//! let source = "\
+//! // Two items.
+//! struct A; // first
+//!
+//! fn b() {}
+//! ";
+//! let (mut file, map) = SourceMap::parse_file(source)?;
+//! assert_eq!(map.print(&file), source);
+//!
+//! let trivia = map.trivia(&file.items[0]).unwrap();
+//! assert_eq!(trivia.leading, "// Two items.\n");
+//! assert_eq!(trivia.trailing, " // first\n");
+//!
+//! if let Item::Fn(item) = &mut file.items[1] {
+//!     item.sig.ident = Ident::new("c", item.sig.ident.span());
+//! }
+//! assert_eq!(map.print(&file), source.replace("fn b", "fn c"));
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Limitations
+//!
+//! Positions are only available when parsing happens outside of a procedural
+//! macro, where `proc_macro2` uses its own implementation of spans. Tokens that
+//! were not parsed from this map's source, such as tokens created with
+//! [`Span::call_site()`], have no position.
+//!
+//! [`Span::call_site()`]: proc_macro2::Span::call_site
+
+use crate::parse::{Parse, Parser};
+use crate::spanned::Spanned;
+use crate::Result;
+use proc_macro2::{Delimiter, LineColumn, Spacing, Span, TokenStream, TokenTree};
+use quote::ToTokens;
+use std::cmp::{self, Ordering};
+use std::ops::Range;
+use std::str::FromStr;
+
+#[cfg(feature = "full")]
+use crate::File;
+
+/// The source text of a parsed syntax tree, along with the positions of its
+/// lines.
+///
+/// *This type is available if Syn is built with the `"parsing"`, `"printing"`
+/// and `"span-locations"` features.*
+pub struct SourceMap {
+    source: String,
+    // Byte offset of the tokenized portion within `source`.
+    offset: usize,
+    // Byte offset of the start of every line.
+    lines: Vec<usize>,
+    // Span covering all tokens of the source, used to tell whether some other
+    // span came from this source. None if the source has no tokens.
+    extent: Option<Span>,
+    // Byte range of every token in order. The tokens a doc comment expands to
+    // all share one range covering the whole comment.
+    tokens: Vec<SourceToken>,
+}
+
+struct SourceToken {
+    range: Range<usize>,
+    // Whether the range covers more than one token, as for doc comments.
+    compound: bool,
+    // Whether the parser left the token out of the syntax tree, like a stray
+    // `;` between statements. Such tokens are printed along with the trivia
+    // around them.
+    dropped: bool,
+}
+
+/// The whitespace and comments around a syntax tree node.
+///
+/// *This type is available if Syn is built with the `"parsing"`, `"printing"`
+/// and `"span-locations"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Trivia<'a> {
+    /// Whitespace and comments between the end of the line containing the
+    /// previous token and the first token of the node.
+    pub leading: &'a str,
+    /// Whitespace and comments after the last token of the node, up to and
+    /// including the end of its line.
+    pub trailing: &'a str,
+}
+
+impl SourceMap {
+    /// Parse a string of Rust code into the chosen syntax tree node, keeping
+    /// track of where each token came from.
+    ///
+    /// The returned error, if any, carries a span whose `start()` and `end()`
+    /// are relative to the beginning of `source`.
+    pub fn parse_str<T: Parse + ToTokens>(source: &str) -> Result<(T, SourceMap)> {
+        let (mut map, tokens) = SourceMap::tokenize(source, 0)?;
+        let node = T::parse.parse2(tokens)?;
+        map.mark_dropped(&node);
+        Ok((node, map))
+    }
+
+    /// Parse the content of a file of Rust code, keeping track of where each
+    /// token came from.
+    ///
+    /// This behaves like [`syn::parse_file`] with respect to a byte order mark
+    /// and shebang line at the start of the file. Offsets reported by the map
+    /// are relative to the beginning of `source` including either of those.
+    ///
+    /// [`syn::parse_file`]: crate::parse_file
+    ///
+    /// *This function is available if Syn is built with the `"full"` feature
+    /// in addition to the ones required by this module.*
+    #[cfg(feature = "full")]
+    pub fn parse_file(source: &str) -> Result<(File, SourceMap)> {
+        let (shebang, content) = crate::split_file(source);
+        let offset = source.len() - content.len();
+        let (mut map, tokens) = SourceMap::tokenize(source, offset)?;
+        let mut file: File = Parse::parse.parse2(tokens)?;
+        file.shebang = shebang;
+        map.mark_dropped(&file);
+        Ok((file, map))
+    }
+
+    fn tokenize(source: &str, offset: usize) -> Result<(SourceMap, TokenStream)> {
+        let tokens = TokenStream::from_str(&source[offset..])?;
+
+        let mut spans = tokens.clone().into_iter().map(|tt| tt.span());
+        let extent = spans.next().map(|first| match spans.last() {
+            Some(last) => first.join(last).unwrap_or(first),
+            None => first,
+        });
+
+        let mut lines = vec![0];
+        lines.extend(source.match_indices('\n').map(|(i, _)| i + 1));
+
+        let mut map = SourceMap {
+            source: source.to_owned(),
+            offset,
+            lines,
+            extent,
+            tokens: Vec::new(),
+        };
+
+        let mut ranges = Vec::new();
+        map.token_ranges(tokens.clone(), &mut ranges);
+        ranges.sort_by_key(|range| range.start);
+        let mut merged: Vec<SourceToken> = Vec::new();
+        for range in ranges {
+            match merged.last_mut() {
+                Some(last) if range.start < last.range.end => {
+                    last.range.end = cmp::max(last.range.end, range.end);
+                    last.compound = true;
+                }
+                _ => merged.push(SourceToken {
+                    range,
+                    compound: false,
+                    dropped: false,
+                }),
+            }
+        }
+        map.tokens = merged;
+        Ok((map, tokens))
+    }
+
+    /// The complete source text.
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+
+    /// The byte range in the source text covered by the given syntax tree
+    /// node or span.
+    ///
+    /// Returns `None` if the node is empty or was not parsed from this map's
+    /// source.
+    pub fn byte_range<T: Spanned + ?Sized>(&self, node: &T) -> Option<Range<usize>> {
+        let span = node.span();
+        self.extent?.join(span)?;
+        Some(self.span_range(span))
+    }
+
+    /// The source text of the given syntax tree node or span.
+    ///
+    /// Returns `None` if the node is empty or was not parsed from this map's
+    /// source.
+    pub fn source_text<T: Spanned + ?Sized>(&self, node: &T) -> Option<&str> {
+        self.byte_range(node).map(|range| &self.source[range])
+    }
+
+    /// The line and column at which the given syntax tree node or span
+    /// begins.
+    ///
+    /// Lines are 1-indexed and columns are 0-indexed counts of characters, as
+    /// in [`proc_macro2::LineColumn`].
+    pub fn start<T: Spanned + ?Sized>(&self, node: &T) -> Option<LineColumn> {
+        self.byte_range(node)
+            .map(|range| self.line_column(range.start))
+    }
+
+    /// The line and column at which the given syntax tree node or span ends,
+    /// exclusive.
+    pub fn end<T: Spanned + ?Sized>(&self, node: &T) -> Option<LineColumn> {
+        self.byte_range(node)
+            .map(|range| self.line_column(range.end))
+    }
+
+    /// The whitespace and comments surrounding the given syntax tree node.
+    ///
+    /// Returns `None` if the node is empty or was not parsed from this map's
+    /// source. See the [module documentation] for how trivia is divided
+    /// between tokens.
+    ///
+    /// [module documentation]: self#lossless-printing
+    pub fn trivia<T: Spanned + ?Sized>(&self, node: &T) -> Option<Trivia<'_>> {
+        let range = self.byte_range(node)?;
+        let first = self.token_at(range.start)?;
+        let last = self.token_at(range.end - 1)?;
+        Some(Trivia {
+            leading: self.leading(first),
+            trailing: self.trailing(last),
+        })
+    }
+
+    /// Print a syntax tree node as source code, reproducing the comments and
+    /// whitespace around every token that was parsed from this map's source.
+    ///
+    /// Printing the unmodified `File` returned by [`SourceMap::parse_file`]
+    /// results in the original source text.
+    pub fn print<T: ToTokens + ?Sized>(&self, node: &T) -> String {
+        let mut printer = Printer {
+            map: self,
+            out: String::new(),
+            last: Last::Start,
+        };
+        printer.stream(node.to_token_stream());
+        printer.finish()
+    }
+
+    /// The line and column of a byte offset into the source text.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset` is past the end of the source text or not on a
+    /// character boundary.
+    pub fn line_column(&self, offset: usize) -> LineColumn {
+        assert!(self.source.is_char_boundary(offset));
+        let line = match self.lines.binary_search(&offset) {
+            Ok(line) => line,
+            Err(next) => next - 1,
+        };
+        let column = self.source[self.lines[line]..offset].chars().count();
+        LineColumn {
+            line: line + 1,
+            column,
+        }
+    }
+
+    // Marks the tokens of the source that do not appear in the syntax tree
+    // parsed from it.
+    fn mark_dropped<T: ToTokens + ?Sized>(&mut self, node: &T) {
+        let mut kept = vec![false; self.tokens.len()];
+        self.mark_kept(node.to_token_stream(), &mut kept);
+        for (token, kept) in self.tokens.iter_mut().zip(kept) {
+            token.dropped = !kept;
+        }
+    }
+
+    fn mark_kept(&self, tokens: TokenStream, kept: &mut [bool]) {
+        for tt in tokens {
+            let spans = match &tt {
+                TokenTree::Group(group) => {
+                    self.mark_kept(group.stream(), kept);
+                    if group.delimiter() == Delimiter::None {
+                        continue;
+                    }
+                    vec![group.span_open(), group.span_close()]
+                }
+                tt => vec![tt.span()],
+            };
+            for span in spans {
+                if let Some(i) = self.token_index(span) {
+                    kept[i] = true;
+                }
+            }
+        }
+    }
+
+    fn token_at(&self, offset: usize) -> Option<usize> {
+        self.tokens
+            .binary_search_by(|token| {
+                if token.range.end <= offset {
+                    Ordering::Less
+                } else if token.range.start > offset {
+                    Ordering::Greater
+                } else {
+                    Ordering::Equal
+                }
+            })
+            .ok()
+    }
+
+    fn token_index(&self, span: Span) -> Option<usize> {
+        self.extent?.join(span)?;
+        self.token_at(self.span_range(span).start)
+    }
+
+    // Byte range in the source text of a span parsed from it.
+    fn span_range(&self, span: Span) -> Range<usize> {
+        self.offset_of(span.start())..self.offset_of(span.end())
+    }
+
+    // Byte offset of a position within the tokenized portion of the source,
+    // whose first line begins at `self.offset` rather than at the start of a
+    // line of the source.
+    fn offset_of(&self, position: LineColumn) -> usize {
+        let line_start = if position.line == 1 {
+            self.offset
+        } else {
+            let first = match self.lines.binary_search(&self.offset) {
+                Ok(line) => line,
+                Err(next) => next - 1,
+            };
+            self.lines[first + position.line - 1]
+        };
+        let rest = &self.source[line_start..];
+        match rest.char_indices().nth(position.column) {
+            Some((column, _)) => line_start + column,
+            None => self.source.len(),
+        }
+    }
+
+    fn token_ranges(&self, tokens: TokenStream, ranges: &mut Vec<Range<usize>>) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(group) => {
+                    let delimited = group.delimiter() != Delimiter::None;
+                    if delimited {
+                        ranges.push(self.span_range(group.span_open()));
+                    }
+                    self.token_ranges(group.stream(), ranges);
+                    if delimited {
+                        ranges.push(self.span_range(group.span_close()));
+                    }
+                }
+                tt => ranges.push(self.span_range(tt.span())),
+            }
+        }
+    }
+
+    // Offset dividing the trivia between token `i - 1` and token `i` into the
+    // trailing trivia of the former and the leading trivia of the latter.
+    fn split(&self, i: usize) -> usize {
+        if i == 0 {
+            return 0;
+        }
+        if i == self.tokens.len() {
+            return self.source.len();
+        }
+        let start = self.tokens[i - 1].range.end;
+        let end = self.tokens[i].range.start;
+        match self.source[start..end].find('\n') {
+            Some(newline) => start + newline + 1,
+            None => end,
+        }
+    }
+
+    fn leading(&self, i: usize) -> &str {
+        &self.source[self.split(i)..self.tokens[i].range.start]
+    }
+
+    fn trailing(&self, i: usize) -> &str {
+        &self.source[self.tokens[i].range.end..self.split(i + 1)]
+    }
+}
+
+struct Printer<'a> {
+    map: &'a SourceMap,
+    out: String,
+    last: Last,
+}
+
+#[derive(Copy, Clone)]
+enum Last {
+    Start,
+    // Index of a token from the source.
+    Source(usize),
+    // A token created after parsing, and whether it is a punctuation character
+    // joined to the next one.
+    New { joint: bool },
+}
+
+impl<'a> Printer<'a> {
+    fn stream(&mut self, tokens: TokenStream) {
+        for tt in tokens {
+            match tt {
+                TokenTree::Group(group) => {
+                    let (open, close) = match group.delimiter() {
+                        Delimiter::Parenthesis => ("(", ")"),
+                        Delimiter::Brace => ("{", "}"),
+                        Delimiter::Bracket => ("[", "]"),
+                        Delimiter::None => {
+                            self.stream(group.stream());
+                            continue;
+                        }
+                    };
+                    self.token(group.span_open(), open, false, true);
+                    self.stream(group.stream());
+                    self.token(group.span_close(), close, false, true);
+                }
+                TokenTree::Punct(punct) => {
+                    let joint = punct.spacing() == Spacing::Joint;
+                    self.token(punct.span(), &punct.as_char().to_string(), joint, true);
+                }
+                tt => self.token(tt.span(), &tt.to_string(), false, false),
+            }
+        }
+    }
+
+    // Punctuation that came from the source is printed as written, since the
+    // syntax tree normalizes some spellings, like `...` in range patterns.
+    fn token(&mut self, span: Span, text: &str, joint: bool, punct: bool) {
+        let map = self.map;
+        match map.token_index(span) {
+            Some(i) => {
+                match self.last {
+                    // Another token of the same doc comment.
+                    Last::Source(prev) if prev == i => return,
+                    // Adjacent in the source, apart from tokens the parser
+                    // dropped.
+                    Last::Source(prev)
+                        if prev < i && map.tokens[prev + 1..i].iter().all(|t| t.dropped) =>
+                    {
+                        let gap = map.tokens[prev].range.end..map.tokens[i].range.start;
+                        self.out += &map.source[gap];
+                    }
+                    Last::Source(prev) => {
+                        self.out += map.trailing(prev);
+                        self.out += map.leading(i);
+                    }
+                    Last::Start => self.out += map.leading(i),
+                    Last::New { joint } => {
+                        let leading = map.leading(i);
+                        if leading.is_empty() && !joint {
+                            self.out.push(' ');
+                        }
+                        self.out += leading;
+                    }
+                }
+                let token = &map.tokens[i];
+                if token.compound || punct {
+                    self.out += &map.source[token.range.clone()];
+                } else {
+                    self.out += text;
+                }
+                self.last = Last::Source(i);
+            }
+            None => {
+                match self.last {
+                    Last::Source(prev) => {
+                        let trailing = map.trailing(prev);
+                        if trailing.is_empty() {
+                            self.out.push(' ');
+                        }
+                        self.out += trailing;
+                    }
+                    Last::New { joint: false } => self.out.push(' '),
+                    Last::New { joint: true } | Last::Start => {}
+                }
+                self.out += text;
+                self.last = Last::New { joint };
+            }
+        }
+    }
+
+    fn finish(mut self) -> String {
+        if let Last::Source(prev) = self.last {
+            self.out += self.map.trailing(prev);
+        }
+        if self.map.tokens.is_empty() {
+            // Nothing but trivia in the source.
+            self.out.insert_str(0, &self.map.source);
+        }
+        self.out
+    }
+}
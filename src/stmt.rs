@@ -47,8 +47,8 @@ ast_struct! {
 pub mod parsing {
     use super::*;
 
-    use crate::parse::{Parse, ParseStream, Result};
-    use crate::punctuated::Punctuated;
+    use crate::parse::{Boundary, Parse, ParseStream, Result};
+    use proc_macro2::{TokenStream, TokenTree};
 
     impl Block {
         /// Parse the body of a block as zero or more statements, possibly
@@ -112,23 +112,38 @@ pub mod parsing {
                 if input.is_empty() {
                     break;
                 }
-                let s = parse_stmt(input, true)?;
-                let requires_semicolon = if let Stmt::Expr(s) = &s {
-                    expr::requires_terminator(s)
-                } else {
-                    false
-                };
+                let s = crate::parse::recover(input, Boundary::Stmt, block_stmt, verbatim_stmt)?;
                 stmts.push(s);
-                if input.is_empty() {
-                    break;
-                } else if requires_semicolon {
-                    return Err(input.error("unexpected token"));
-                }
             }
             Ok(stmts)
         }
     }
 
+    fn block_stmt(input: ParseStream) -> Result<Stmt> {
+        let s = parse_stmt(input, true)?;
+        if let Stmt::Expr(s) = &s {
+            if expr::requires_terminator(s) && !input.is_empty() {
+                return Err(input.error("unexpected token"));
+            }
+        }
+        Ok(s)
+    }
+
+    // Placeholder for a statement that failed to parse.
+    fn verbatim_stmt(tokens: TokenStream) -> Stmt {
+        let mut tokens: Vec<TokenTree> = tokens.into_iter().collect();
+        match tokens.pop() {
+            Some(TokenTree::Punct(ref semi)) if semi.as_char() == ';' => {
+                let expr = Expr::Verbatim(tokens.into_iter().collect());
+                Stmt::Semi(expr, Token![;](semi.span()))
+            }
+            last => {
+                tokens.extend(last);
+                Stmt::Expr(Expr::Verbatim(tokens.into_iter().collect()))
+            }
+        }
+    }
+
     impl Parse for Block {
         fn parse(input: ParseStream) -> Result<Self> {
             let content;
@@ -218,28 +233,7 @@ pub mod parsing {
             attrs: input.call(Attribute::parse_outer)?,
             let_token: input.parse()?,
             pat: {
-                let leading_vert: Option<Token![|]> = input.parse()?;
-                let mut pat: Pat = input.parse()?;
-                if leading_vert.is_some()
-                    || input.peek(Token![|]) && !input.peek(Token![||]) && !input.peek(Token![|=])
-                {
-                    let mut cases = Punctuated::new();
-                    cases.push_value(pat);
-                    while input.peek(Token![|])
-                        && !input.peek(Token![||])
-                        && !input.peek(Token![|=])
-                    {
-                        let punct = input.parse()?;
-                        cases.push_punct(punct);
-                        let pat: Pat = input.parse()?;
-                        cases.push_value(pat);
-                    }
-                    pat = Pat::Or(PatOr {
-                        attrs: Vec::new(),
-                        leading_vert,
-                        cases,
-                    });
-                }
+                let mut pat = Pat::parse_multi_with_leading_vert(input)?;
                 if input.peek(Token![:]) {
                     let colon_token: Token![:] = input.parse()?;
                     let ty: Type = input.parse()?;
@@ -279,7 +273,7 @@ pub mod parsing {
         if allow_nosemi || !expr::requires_terminator(&e) {
             Ok(Stmt::Expr(e))
         } else {
-            Err(input.error("expected semicolon"))
+            Err(input.error_expected(&["semicolon"]))
         }
     }
 }
@@ -288,6 +282,7 @@ pub mod parsing {
 mod printing {
     use super::*;
 
+    use crate::precedence;
     use proc_macro2::TokenStream;
     use quote::{ToTokens, TokenStreamExt};
 
@@ -304,15 +299,27 @@ mod printing {
             match self {
                 Stmt::Local(local) => local.to_tokens(tokens),
                 Stmt::Item(item) => item.to_tokens(tokens),
-                Stmt::Expr(expr) => expr.to_tokens(tokens),
+                Stmt::Expr(expr) => expr_to_tokens(expr, tokens),
                 Stmt::Semi(expr, semi) => {
-                    expr.to_tokens(tokens);
+                    expr_to_tokens(expr, tokens);
                     semi.to_tokens(tokens);
                 }
             }
         }
     }
 
+    // An expression like `match x {} - 1` is parenthesized, since the
+    // statement would otherwise end after the `match`.
+    fn expr_to_tokens(expr: &Expr, tokens: &mut TokenStream) {
+        if precedence::stmt_needs_parens(expr) {
+            token::Paren::default().surround(tokens, |tokens| {
+                expr.to_tokens(tokens);
+            });
+        } else {
+            expr.to_tokens(tokens);
+        }
+    }
+
     impl ToTokens for Local {
         fn to_tokens(&self, tokens: &mut TokenStream) {
             expr::printing::outer_attrs_to_tokens(&self.attrs, tokens);
@@ -0,0 +1,428 @@
+//! Generation of `match` expressions over the variants and fields of a type,
+//! for writing the body of a derived impl.
+//!
+//! A [`Structure`] describes the variants of an enum, or the single variant
+//! of a struct, along with a binding for each of their fields named
+//! `__binding_0`, `__binding_1` and so on. Its methods produce a whole
+//! `match` expression on `self` with one arm per variant, whose pattern
+//! binds every field by reference, by mutable reference or by value, and
+//! whose body is built from the bindings by a closure. Unit, tuple and named
+//! variants each get the right shape of pattern.
+//!
+//! *This module is available if Syn is built with the `"derive"` and
+//! `"printing"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::structure::Structure;
+//! use syn::{parse_quote, DeriveInput};
+//!
+//! let input: DeriveInput = parse_quote! {
+//!     enum Shape {
+//!         Circle { radius: u32 },
+//!         Rectangle(u32, u32),
+//!         Empty,
+//!     }
+//! };
+//!
+//! let structure = Structure::new(&input).unwrap();
+//! let body = structure.fold(quote!(0), |acc, binding| quote!(#acc + #binding.size()));
+//!
+//! let expected = quote! {
+//!     match *self {
+//!         Shape::Circle { radius: ref __binding_0, } => {
+//!             0 + __binding_0.size()
+//!         }
+//!         Shape::Rectangle(ref __binding_0, ref __binding_1,) => {
+//!             0 + __binding_0.size() + __binding_1.size()
+//!         }
+//!         Shape::Empty => {
+//!             0
+//!         }
+//!     }
+//! };
+//! assert_eq!(body.to_string(), expected.to_string());
+//! ```
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::*;
+
+/// How a pattern binds a field.
+///
+/// *This type is available if Syn is built with the `"derive"` and
+/// `"printing"` features.*
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BindStyle {
+    /// `ref x`, which matches on `*self`. This is the default.
+    Ref,
+    /// `ref mut x`, which matches on `*self`.
+    RefMut,
+    /// `x`, which moves out of `self`.
+    Move,
+    /// `mut x`, which moves out of `self`.
+    MoveMut,
+}
+
+/// The variants of a struct or enum with bindings for their fields.
+///
+/// *This type is available if Syn is built with the `"derive"` and
+/// `"printing"` features.*
+#[derive(Clone)]
+pub struct Structure<'a> {
+    input: &'a DeriveInput,
+    variants: Vec<VariantInfo<'a>>,
+}
+
+/// A variant of a [`Structure`], or the struct itself.
+///
+/// *This type is available if Syn is built with the `"derive"` and
+/// `"printing"` features.*
+#[derive(Clone)]
+pub struct VariantInfo<'a> {
+    prefix: Option<&'a Ident>,
+    ident: &'a Ident,
+    attrs: &'a [Attribute],
+    fields: &'a Fields,
+    bindings: Vec<BindingInfo<'a>>,
+}
+
+/// A field of a [`VariantInfo`] bound by its pattern.
+///
+/// Prints as the name of the binding.
+///
+/// *This type is available if Syn is built with the `"derive"` and
+/// `"printing"` features.*
+#[derive(Clone)]
+pub struct BindingInfo<'a> {
+    /// The name the pattern binds the field to.
+    pub binding: Ident,
+    /// How the pattern binds the field.
+    pub style: BindStyle,
+    /// The field in the syntax tree.
+    pub field: &'a Field,
+    index: usize,
+}
+
+impl<'a> Structure<'a> {
+    /// Describes the variants of a struct or enum, with every field bound by
+    /// reference.
+    ///
+    /// Returns an error for a union, whose fields cannot be bound by a
+    /// pattern without knowing which one is in use.
+    pub fn new(input: &'a DeriveInput) -> Result<Self> {
+        let variants = match &input.data {
+            Data::Struct(data) => vec![VariantInfo::new(
+                None,
+                &input.ident,
+                &input.attrs,
+                &data.fields,
+            )],
+            Data::Enum(data) => data
+                .variants
+                .iter()
+                .map(|variant| {
+                    VariantInfo::new(
+                        Some(&input.ident),
+                        &variant.ident,
+                        &variant.attrs,
+                        &variant.fields,
+                    )
+                })
+                .collect(),
+            Data::Union(data) => {
+                return Err(Error::new(
+                    data.union_token.span,
+                    "unions are not supported",
+                ));
+            }
+        };
+        Ok(Structure { input, variants })
+    }
+
+    /// The input this structure describes.
+    pub fn input(&self) -> &'a DeriveInput {
+        self.input
+    }
+
+    /// The variants, in order. A struct has exactly one.
+    pub fn variants(&self) -> &[VariantInfo<'a>] {
+        &self.variants
+    }
+
+    /// The variants, for changing their bindings one variant at a time.
+    pub fn variants_mut(&mut self) -> &mut [VariantInfo<'a>] {
+        &mut self.variants
+    }
+
+    /// Binds every field with `style`.
+    pub fn bind_with(&mut self, style: BindStyle) -> &mut Self {
+        for variant in &mut self.variants {
+            variant.bind_with(style);
+        }
+        self
+    }
+
+    /// Removes the bindings for which `f` returns false. Their fields are
+    /// matched by `_` or `..` instead, and do not show up in [`each`] and
+    /// [`fold`].
+    ///
+    /// [`each`]: Structure::each
+    /// [`fold`]: Structure::fold
+    pub fn filter<F>(&mut self, mut f: F) -> &mut Self
+    where
+        F: FnMut(&BindingInfo) -> bool,
+    {
+        for variant in &mut self.variants {
+            variant.filter(&mut f);
+        }
+        self
+    }
+
+    /// Produces a `match` with an arm for every variant whose body evaluates
+    /// `f` for each of the bindings in turn, as statements.
+    pub fn each<F, R>(&self, mut f: F) -> TokenStream
+    where
+        F: FnMut(&BindingInfo) -> R,
+        R: ToTokens,
+    {
+        self.build_match(|variant| variant.each(&mut f))
+    }
+
+    /// Produces a `match` with an arm for every variant whose body folds the
+    /// bindings into one expression, starting from `init`: the arm for
+    /// bindings `a` and `b` evaluates to `f(f(init, a), b)`.
+    pub fn fold<F, I, R>(&self, init: I, mut f: F) -> TokenStream
+    where
+        F: FnMut(TokenStream, &BindingInfo) -> R,
+        I: ToTokens,
+        R: ToTokens,
+    {
+        let init = init.into_token_stream();
+        self.build_match(|variant| variant.fold(init.clone(), &mut f))
+    }
+
+    /// Produces a `match` with an arm for every variant whose body is `f` of
+    /// the variant.
+    pub fn each_variant<F, R>(&self, mut f: F) -> TokenStream
+    where
+        F: FnMut(&VariantInfo) -> R,
+        R: ToTokens,
+    {
+        self.build_match(|variant| {
+            let pat = variant.pat();
+            let body = f(variant);
+            quote!(#pat => { #body })
+        })
+    }
+
+    // The bind style of the first binding decides whether to match on
+    // `*self` or `self`.
+    fn build_match<F>(&self, arm: F) -> TokenStream
+    where
+        F: FnMut(&VariantInfo) -> TokenStream,
+    {
+        let by_ref = self
+            .variants
+            .iter()
+            .find_map(|variant| variant.bindings.first())
+            .map_or(true, |binding| match binding.style {
+                BindStyle::Ref | BindStyle::RefMut => true,
+                BindStyle::Move | BindStyle::MoveMut => false,
+            });
+        let arms = self.variants.iter().map(arm);
+        if by_ref {
+            quote!(match *self { #(#arms)* })
+        } else {
+            quote!(match self { #(#arms)* })
+        }
+    }
+}
+
+impl<'a> VariantInfo<'a> {
+    fn new(
+        prefix: Option<&'a Ident>,
+        ident: &'a Ident,
+        attrs: &'a [Attribute],
+        fields: &'a Fields,
+    ) -> Self {
+        let bindings = fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| BindingInfo {
+                binding: Ident::new(&format!("__binding_{}", index), Span::call_site()),
+                style: BindStyle::Ref,
+                field,
+                index,
+            })
+            .collect();
+        VariantInfo {
+            prefix,
+            ident,
+            attrs,
+            fields,
+            bindings,
+        }
+    }
+
+    /// The name of the variant, or of the struct.
+    pub fn ident(&self) -> &'a Ident {
+        self.ident
+    }
+
+    /// The attributes of the variant, or of the struct.
+    pub fn attrs(&self) -> &'a [Attribute] {
+        self.attrs
+    }
+
+    /// All fields of the variant, including those without a binding.
+    pub fn fields(&self) -> &'a Fields {
+        self.fields
+    }
+
+    /// The bindings of the fields, in order.
+    pub fn bindings(&self) -> &[BindingInfo<'a>] {
+        &self.bindings
+    }
+
+    /// Binds every field with `style`.
+    pub fn bind_with(&mut self, style: BindStyle) -> &mut Self {
+        for binding in &mut self.bindings {
+            binding.style = style;
+        }
+        self
+    }
+
+    /// Removes the bindings for which `f` returns false.
+    pub fn filter<F>(&mut self, f: F) -> &mut Self
+    where
+        F: FnMut(&BindingInfo) -> bool,
+    {
+        let mut f = f;
+        self.bindings.retain(|binding| f(binding));
+        self
+    }
+
+    /// The pattern that matches the variant and binds its fields, like
+    /// `Enum::Variant { a: ref __binding_0, .. }`.
+    pub fn pat(&self) -> TokenStream {
+        let path = self.path();
+        match self.fields {
+            Fields::Named(_) => {
+                let bindings = self.bindings.iter().map(|binding| {
+                    let ident = &binding.field.ident;
+                    let pat = binding.pat();
+                    quote!(#ident: #pat,)
+                });
+                let rest = if self.bindings.len() < self.fields.iter().len() {
+                    Some(quote!(..))
+                } else {
+                    None
+                };
+                quote!(#path { #(#bindings)* #rest })
+            }
+            Fields::Unnamed(_) => {
+                let mut bindings = self.bindings.iter().peekable();
+                let pats = (0..self.fields.iter().len()).map(|index| match bindings.peek() {
+                    Some(binding) if binding.index == index => {
+                        let pat = binding.pat();
+                        bindings.next();
+                        pat
+                    }
+                    _ => quote!(_),
+                });
+                quote!(#path(#(#pats,)*))
+            }
+            Fields::Unit => path,
+        }
+    }
+
+    /// An expression that constructs the variant, with the value of each
+    /// field, including those without a binding, given by `f`.
+    pub fn construct<F, R>(&self, mut f: F) -> TokenStream
+    where
+        F: FnMut(&Field, usize) -> R,
+        R: ToTokens,
+    {
+        let path = self.path();
+        match self.fields {
+            Fields::Named(fields) => {
+                let fields = fields.named.iter().enumerate().map(|(index, field)| {
+                    let ident = &field.ident;
+                    let value = f(field, index);
+                    quote!(#ident: #value,)
+                });
+                quote!(#path { #(#fields)* })
+            }
+            Fields::Unnamed(fields) => {
+                let values = fields
+                    .unnamed
+                    .iter()
+                    .enumerate()
+                    .map(|(index, field)| f(field, index));
+                quote!(#path(#(#values,)*))
+            }
+            Fields::Unit => path,
+        }
+    }
+
+    /// A match arm for the variant whose body evaluates `f` for each of the
+    /// bindings in turn, as statements.
+    pub fn each<F, R>(&self, f: F) -> TokenStream
+    where
+        F: FnMut(&BindingInfo) -> R,
+        R: ToTokens,
+    {
+        let pat = self.pat();
+        let stmts = self.bindings.iter().map(f);
+        quote!(#pat => { #(#stmts;)* })
+    }
+
+    /// A match arm for the variant whose body folds the bindings into one
+    /// expression, starting from `init`.
+    pub fn fold<F, I, R>(&self, init: I, mut f: F) -> TokenStream
+    where
+        F: FnMut(TokenStream, &BindingInfo) -> R,
+        I: ToTokens,
+        R: ToTokens,
+    {
+        let pat = self.pat();
+        let body = self
+            .bindings
+            .iter()
+            .fold(init.into_token_stream(), |acc, binding| {
+                f(acc, binding).into_token_stream()
+            });
+        quote!(#pat => { #body })
+    }
+
+    fn path(&self) -> TokenStream {
+        let ident = self.ident;
+        match self.prefix {
+            Some(prefix) => quote!(#prefix::#ident),
+            None => quote!(#ident),
+        }
+    }
+}
+
+impl BindingInfo<'_> {
+    /// The pattern that binds the field, like `ref __binding_0`.
+    pub fn pat(&self) -> TokenStream {
+        let binding = &self.binding;
+        match self.style {
+            BindStyle::Ref => quote!(ref #binding),
+            BindStyle::RefMut => quote!(ref mut #binding),
+            BindStyle::Move => quote!(#binding),
+            BindStyle::MoveMut => quote!(mut #binding),
+        }
+    }
+}
+
+impl ToTokens for BindingInfo<'_> {
+    fn to_tokens(&self, tokens: &mut TokenStream) {
+        self.binding.to_tokens(tokens);
+    }
+}
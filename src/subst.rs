@@ -0,0 +1,531 @@
+//! Substitution of generic parameters in syntax trees.
+//!
+//! A [`Substitution`] maps type, lifetime and const parameters to the types,
+//! lifetimes and expressions that replace them, and rewrites every place a
+//! syntax tree refers to those parameters: types like `Vec<T>`, paths like
+//! `T::default()` or `T::Item`, array lengths and const arguments, lifetimes
+//! in references and bounds, and the `where` clause. `Self` can be replaced
+//! the same way as a type parameter named `Self`.
+//!
+//! Applied to an item that declares some of the parameters being replaced,
+//! like a generic function, the substitution also removes them from the
+//! item's generics. Their bounds move to the `where` clause, so that `fn
+//! f<T: Ord>` becomes `fn f() where u64: Ord` when `T` is replaced by `u64`.
+//!
+//! The substitution respects scoping. Generic parameters of an associated
+//! function or type, and lifetimes of a `for<'a>` binder, hide parameters of
+//! the same name in the enclosing item. Items nested inside the tree, like
+//! a function declared in a block, are left alone, since they cannot refer
+//! to the generic parameters or `Self` type of an enclosing item. Labels are
+//! not lifetimes and are never replaced, and neither are the tokens inside
+//! of macro invocations.
+//!
+//! A path that begins with a replaced type parameter is rewritten to begin
+//! with the replacement. In an expression or pattern, `T::new` becomes
+//! `Vec::<u8>::new`, or `<&str>::len` if the replacement is not a path. In a
+//! type, `T::Item` becomes the qualified path `<Vec<u8>>::Item`. This does not
+//! name the trait an associated item came from, so the compiler may find an
+//! associated type written this way ambiguous.
+//!
+//! *This module is available if Syn is built with the `"full"`,
+//! `"visit-mut"` and `"clone-impls"` features.*
+//!
+//! # Example
+//!
+//! ```
+//! use quote::quote;
+//! use syn::subst::Substitution;
+//! use syn::{parse_quote, Item};
+//!
+//! let mut item: Item = parse_quote! {
+//!     fn max<'a, T: Ord>(a: &'a T, b: &'a T) -> &'a T {
+//!         if a > b { a } else { b }
+//!     }
+//! };
+//!
+//! let mut subst = Substitution::new();
+//! subst.insert_type(parse_quote!(T), parse_quote!(u64));
+//! subst.insert_lifetime(parse_quote!('a), parse_quote!('static));
+//! subst.apply(&mut item);
+//!
+//! let expected = quote! {
+//!     fn max(a: &'static u64, b: &'static u64) -> &'static u64
+//!     where
+//!         u64: Ord
+//!     {
+//!         if a > b { a } else { b }
+//!     }
+//! };
+//! assert_eq!(quote!(#item).to_string(), expected.to_string());
+//! ```
+
+use std::collections::HashMap;
+use std::mem;
+
+use crate::punctuated::Punctuated;
+use crate::visit_mut::{self, VisitMut};
+use crate::*;
+
+/// A mapping of generic parameters to their replacements.
+///
+/// *This type is available if Syn is built with the `"full"`, `"visit-mut"`
+/// and `"clone-impls"` features.*
+#[derive(Clone, Default)]
+pub struct Substitution {
+    types: HashMap<Ident, Type>,
+    lifetimes: HashMap<Ident, Lifetime>,
+    consts: HashMap<Ident, Expr>,
+}
+
+/// A syntax tree node that a [`Substitution`] can be applied to.
+///
+/// This trait is sealed and cannot be implemented for types outside of Syn.
+///
+/// *This trait is available if Syn is built with the `"full"`, `"visit-mut"`
+/// and `"clone-impls"` features.*
+pub trait Substitutable: private::Sealed {
+    #[doc(hidden)]
+    fn generics_mut(&mut self) -> Option<&mut Generics>;
+
+    #[doc(hidden)]
+    fn substitute<V: VisitMut>(&mut self, v: &mut V);
+}
+
+impl Substitution {
+    /// Creates a substitution that does not replace anything.
+    pub fn new() -> Self {
+        Substitution::default()
+    }
+
+    /// Replaces the type parameter `param`, or `Self`, with `ty`.
+    ///
+    /// An identifier `Self` can be made from the token with
+    /// `Token![Self](span).into()`.
+    pub fn insert_type(&mut self, param: Ident, ty: Type) {
+        self.consts.remove(&param);
+        self.types.insert(param, ty);
+    }
+
+    /// Replaces the lifetime parameter `param` with `lifetime`.
+    pub fn insert_lifetime(&mut self, param: Lifetime, lifetime: Lifetime) {
+        self.lifetimes.insert(param.ident, lifetime);
+    }
+
+    /// Replaces the const parameter `param` with the expression `value`.
+    pub fn insert_const(&mut self, param: Ident, value: Expr) {
+        self.types.remove(&param);
+        self.consts.insert(param, value);
+    }
+
+    /// Whether the substitution does not replace anything.
+    pub fn is_empty(&self) -> bool {
+        self.types.is_empty() && self.lifetimes.is_empty() && self.consts.is_empty()
+    }
+
+    /// Replaces the generic parameters throughout `node`.
+    ///
+    /// All parameters are replaced at once: parameters that appear in the
+    /// replacements themselves are not replaced again.
+    pub fn apply<T: Substitutable>(&self, node: &mut T) {
+        if let Some(generics) = node.generics_mut() {
+            self.remove_params(generics);
+        }
+        node.substitute(&mut Substitute {
+            subst: self,
+            shadowed: Vec::new(),
+            shadowed_lifetimes: Vec::new(),
+        });
+    }
+
+    fn remove_params(&self, generics: &mut Generics) {
+        let mut predicates = Vec::new();
+        for param in mem::replace(&mut generics.params, Punctuated::new()) {
+            match param {
+                GenericParam::Type(param) if self.types.contains_key(&param.ident) => {
+                    if !param.bounds.is_empty() {
+                        predicates.push(WherePredicate::Type(PredicateType {
+                            lifetimes: None,
+                            bounded_ty: Type::Path(TypePath {
+                                qself: None,
+                                path: Path::from(param.ident),
+                            }),
+                            colon_token: param.colon_token.unwrap_or_default(),
+                            bounds: param.bounds,
+                        }));
+                    }
+                }
+                GenericParam::Lifetime(param)
+                    if self.lifetimes.contains_key(&param.lifetime.ident) =>
+                {
+                    if !param.bounds.is_empty() {
+                        predicates.push(WherePredicate::Lifetime(PredicateLifetime {
+                            lifetime: param.lifetime,
+                            colon_token: param.colon_token.unwrap_or_default(),
+                            bounds: param.bounds,
+                        }));
+                    }
+                }
+                GenericParam::Const(param) if self.consts.contains_key(&param.ident) => {}
+                param => generics.params.push(param),
+            }
+        }
+        if !predicates.is_empty() {
+            generics.make_where_clause().predicates.extend(predicates);
+        }
+    }
+}
+
+struct Substitute<'a> {
+    subst: &'a Substitution,
+    // Parameters of the same name declared inside the tree, which hide the
+    // ones being replaced.
+    shadowed: Vec<Ident>,
+    shadowed_lifetimes: Vec<Ident>,
+}
+
+struct Scope {
+    shadowed: usize,
+    shadowed_lifetimes: usize,
+}
+
+impl<'a> Substitute<'a> {
+    fn ty(&self, ident: &Ident) -> Option<&'a Type> {
+        if self.shadowed.contains(ident) {
+            None
+        } else {
+            self.subst.types.get(ident)
+        }
+    }
+
+    fn konst(&self, ident: &Ident) -> Option<&'a Expr> {
+        if self.shadowed.contains(ident) {
+            None
+        } else {
+            self.subst.consts.get(ident)
+        }
+    }
+
+    // The replacement of the type parameter a path begins with.
+    fn head(&self, qself: Option<&QSelf>, path: &Path) -> Option<&'a Type> {
+        if qself.is_some() || path.leading_colon.is_some() {
+            return None;
+        }
+        let first = path.segments.first()?;
+        match first.arguments {
+            PathArguments::None => self.ty(&first.ident),
+            _ => None,
+        }
+    }
+
+    // The replacement of a const parameter used as an expression or a generic
+    // argument, which parses as a type.
+    fn const_param(&self, qself: Option<&QSelf>, path: &Path) -> Option<&'a Expr> {
+        if qself.is_some() || path.leading_colon.is_some() || path.segments.len() != 1 {
+            return None;
+        }
+        let first = path.segments.first()?;
+        match first.arguments {
+            PathArguments::None => self.konst(&first.ident),
+            _ => None,
+        }
+    }
+
+    fn enter(&mut self, generics: &Generics) -> Scope {
+        let scope = self.scope();
+        for param in &generics.params {
+            match param {
+                GenericParam::Type(param) => self.shadowed.push(param.ident.clone()),
+                GenericParam::Lifetime(param) => {
+                    self.shadowed_lifetimes.push(param.lifetime.ident.clone());
+                }
+                GenericParam::Const(param) => self.shadowed.push(param.ident.clone()),
+            }
+        }
+        scope
+    }
+
+    fn enter_lifetimes(&mut self, lifetimes: Option<&BoundLifetimes>) -> Scope {
+        let scope = self.scope();
+        if let Some(lifetimes) = lifetimes {
+            for param in &lifetimes.lifetimes {
+                self.shadowed_lifetimes.push(param.lifetime.ident.clone());
+            }
+        }
+        scope
+    }
+
+    fn scope(&self) -> Scope {
+        Scope {
+            shadowed: self.shadowed.len(),
+            shadowed_lifetimes: self.shadowed_lifetimes.len(),
+        }
+    }
+
+    fn exit(&mut self, scope: Scope) {
+        self.shadowed.truncate(scope.shadowed);
+        self.shadowed_lifetimes.truncate(scope.shadowed_lifetimes);
+    }
+}
+
+// Replaces the first segment of `path`, which is in a value position like an
+// expression or pattern, by the path of `ty` with turbofish syntax. Returns
+// false if `ty` is not a path.
+fn splice(ty: &Type, path: &mut Path) -> bool {
+    let mut spliced = match ty {
+        Type::Path(TypePath { qself: None, path }) => path.clone(),
+        _ => return false,
+    };
+    for segment in &mut spliced.segments {
+        if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+            arguments.colon2_token = Some(<Token![::]>::default());
+        }
+    }
+    let segments = mem::replace(&mut path.segments, Punctuated::new());
+    spliced.segments.extend(segments.into_iter().skip(1));
+    *path = spliced;
+    true
+}
+
+// Replaces the first segment of `path` by `<ty>`.
+fn qualify(ty: &Type, qself: &mut Option<QSelf>, path: &mut Path) {
+    *qself = Some(QSelf {
+        lt_token: <Token![<]>::default(),
+        ty: Box::new(ty.clone()),
+        position: 0,
+        as_token: None,
+        gt_token: <Token![>]>::default(),
+    });
+    let segments = mem::replace(&mut path.segments, Punctuated::new());
+    path.leading_colon = Some(<Token![::]>::default());
+    path.segments.extend(segments.into_iter().skip(1));
+}
+
+// A const argument other than a literal or block needs braces.
+fn const_argument(value: &Expr) -> Expr {
+    match value {
+        Expr::Lit(_) | Expr::Block(_) => value.clone(),
+        _ => Expr::Block(ExprBlock {
+            attrs: Vec::new(),
+            label: None,
+            block: Block {
+                brace_token: token::Brace::default(),
+                stmts: vec![Stmt::Expr(value.clone())],
+            },
+        }),
+    }
+}
+
+// Each method first visits the children of the node, and only then replaces
+// parameters in the node itself, so that the replacements are not visited.
+impl VisitMut for Substitute<'_> {
+    fn visit_item_mut(&mut self, _node: &mut Item) {
+        // A nested item cannot refer to the generic parameters of the
+        // enclosing one.
+    }
+
+    fn visit_impl_item_method_mut(&mut self, node: &mut ImplItemMethod) {
+        let scope = self.enter(&node.sig.generics);
+        visit_mut::visit_impl_item_method_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_impl_item_type_mut(&mut self, node: &mut ImplItemType) {
+        let scope = self.enter(&node.generics);
+        visit_mut::visit_impl_item_type_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_trait_item_method_mut(&mut self, node: &mut TraitItemMethod) {
+        let scope = self.enter(&node.sig.generics);
+        visit_mut::visit_trait_item_method_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_trait_item_type_mut(&mut self, node: &mut TraitItemType) {
+        let scope = self.enter(&node.generics);
+        visit_mut::visit_trait_item_type_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_foreign_item_fn_mut(&mut self, node: &mut ForeignItemFn) {
+        let scope = self.enter(&node.sig.generics);
+        visit_mut::visit_foreign_item_fn_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_predicate_type_mut(&mut self, node: &mut PredicateType) {
+        let scope = self.enter_lifetimes(node.lifetimes.as_ref());
+        visit_mut::visit_predicate_type_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_trait_bound_mut(&mut self, node: &mut TraitBound) {
+        let scope = self.enter_lifetimes(node.lifetimes.as_ref());
+        visit_mut::visit_trait_bound_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_type_bare_fn_mut(&mut self, node: &mut TypeBareFn) {
+        let scope = self.enter_lifetimes(node.lifetimes.as_ref());
+        visit_mut::visit_type_bare_fn_mut(self, node);
+        self.exit(scope);
+    }
+
+    fn visit_type_mut(&mut self, node: &mut Type) {
+        visit_mut::visit_type_mut(self, node);
+        if let Type::Path(ty) = node {
+            if let Some(replacement) = self.head(ty.qself.as_ref(), &ty.path) {
+                // The rest of a path in a type names an associated type,
+                // which needs the qualified form.
+                if ty.path.segments.len() == 1 {
+                    *node = replacement.clone();
+                } else {
+                    qualify(replacement, &mut ty.qself, &mut ty.path);
+                }
+            }
+        }
+    }
+
+    fn visit_expr_mut(&mut self, node: &mut Expr) {
+        visit_mut::visit_expr_mut(self, node);
+        match node {
+            Expr::Path(expr) => {
+                if let Some(value) = self.const_param(expr.qself.as_ref(), &expr.path) {
+                    *node = value.clone();
+                } else if let Some(replacement) = self.head(expr.qself.as_ref(), &expr.path) {
+                    if !splice(replacement, &mut expr.path) && expr.path.segments.len() > 1 {
+                        qualify(replacement, &mut expr.qself, &mut expr.path);
+                    }
+                }
+            }
+            Expr::Struct(expr) => {
+                if let Some(replacement) = self.head(None, &expr.path) {
+                    splice(replacement, &mut expr.path);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn visit_pat_mut(&mut self, node: &mut Pat) {
+        visit_mut::visit_pat_mut(self, node);
+        let (qself, path) = match node {
+            Pat::Path(pat) => (Some(&mut pat.qself), &mut pat.path),
+            Pat::Struct(pat) => (None, &mut pat.path),
+            Pat::TupleStruct(pat) => (None, &mut pat.path),
+            _ => return,
+        };
+        let replacement = match self.head(None, path) {
+            Some(replacement) => replacement,
+            None => return,
+        };
+        if !splice(replacement, path) && path.segments.len() > 1 {
+            if let Some(qself) = qself {
+                if qself.is_none() {
+                    qualify(replacement, qself, path);
+                }
+            }
+        }
+    }
+
+    fn visit_generic_argument_mut(&mut self, node: &mut GenericArgument) {
+        visit_mut::visit_generic_argument_mut(self, node);
+        if let GenericArgument::Type(Type::Path(ty)) = node {
+            if let Some(value) = self.const_param(ty.qself.as_ref(), &ty.path) {
+                *node = GenericArgument::Const(const_argument(value));
+            }
+        }
+    }
+
+    fn visit_generic_method_argument_mut(&mut self, node: &mut GenericMethodArgument) {
+        visit_mut::visit_generic_method_argument_mut(self, node);
+        if let GenericMethodArgument::Type(Type::Path(ty)) = node {
+            if let Some(value) = self.const_param(ty.qself.as_ref(), &ty.path) {
+                *node = GenericMethodArgument::Const(const_argument(value));
+            }
+        }
+    }
+
+    fn visit_lifetime_mut(&mut self, node: &mut Lifetime) {
+        if self.shadowed_lifetimes.contains(&node.ident) {
+            return;
+        }
+        if let Some(lifetime) = self.subst.lifetimes.get(&node.ident) {
+            *node = lifetime.clone();
+        }
+    }
+
+    fn visit_label_mut(&mut self, _node: &mut Label) {}
+
+    fn visit_expr_break_mut(&mut self, node: &mut ExprBreak) {
+        if let Some(expr) = &mut node.expr {
+            self.visit_expr_mut(expr);
+        }
+    }
+
+    fn visit_expr_continue_mut(&mut self, _node: &mut ExprContinue) {}
+}
+
+mod private {
+    pub trait Sealed {}
+}
+
+impl private::Sealed for Item {}
+
+impl Substitutable for Item {
+    fn generics_mut(&mut self) -> Option<&mut Generics> {
+        match self {
+            Item::Enum(item) => Some(&mut item.generics),
+            Item::Fn(item) => Some(&mut item.sig.generics),
+            Item::Impl(item) => Some(&mut item.generics),
+            Item::Struct(item) => Some(&mut item.generics),
+            Item::Trait(item) => Some(&mut item.generics),
+            Item::TraitAlias(item) => Some(&mut item.generics),
+            Item::Type(item) => Some(&mut item.generics),
+            Item::Union(item) => Some(&mut item.generics),
+            _ => None,
+        }
+    }
+
+    fn substitute<V: VisitMut>(&mut self, v: &mut V) {
+        // Not `v.visit_item_mut`, which skips nested items.
+        visit_mut::visit_item_mut(v, self);
+    }
+}
+
+macro_rules! substitutable_impls {
+    ($($ty:ident => $method:ident, |$node:ident| $generics:expr,)*) => {
+        $(
+            impl private::Sealed for $ty {}
+
+            impl Substitutable for $ty {
+                fn generics_mut(&mut self) -> Option<&mut Generics> {
+                    let $node = self;
+                    $generics
+                }
+
+                fn substitute<V: VisitMut>(&mut self, v: &mut V) {
+                    v.$method(self);
+                }
+            }
+        )*
+    };
+}
+
+substitutable_impls! {
+    Block => visit_block_mut, |_node| None,
+    Expr => visit_expr_mut, |_node| None,
+    ImplItem => visit_impl_item_mut, |node| match node {
+        ImplItem::Method(item) => Some(&mut item.sig.generics),
+        ImplItem::Type(item) => Some(&mut item.generics),
+        _ => None,
+    },
+    Signature => visit_signature_mut, |node| Some(&mut node.generics),
+    Stmt => visit_stmt_mut, |_node| None,
+    TraitItem => visit_trait_item_mut, |node| match node {
+        TraitItem::Method(item) => Some(&mut item.sig.generics),
+        TraitItem::Type(item) => Some(&mut item.generics),
+        _ => None,
+    },
+    Type => visit_type_mut, |_node| None,
+}
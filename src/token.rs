@@ -106,6 +106,8 @@ use proc_macro2::Span;
 use proc_macro2::TokenStream;
 #[cfg(feature = "printing")]
 use quote::{ToTokens, TokenStreamExt};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use self::private::WithSpan;
 #[cfg(feature = "parsing")]
@@ -158,12 +160,12 @@ impl private::Sealed for Ident {}
 #[cfg(any(feature = "full", feature = "derive"))]
 #[cfg(feature = "parsing")]
 fn peek_impl(cursor: Cursor, peek: fn(ParseStream) -> bool) -> bool {
-    use std::cell::Cell;
+    use std::cell::RefCell;
     use std::rc::Rc;
 
     let scope = Span::call_site();
-    let unexpected = Rc::new(Cell::new(None));
-    let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected);
+    let unexpected = Rc::new(RefCell::new(None));
+    let buffer = crate::parse::new_parse_buffer(scope, cursor, unexpected, None);
     peek(&buffer)
 }
 
@@ -282,6 +284,27 @@ macro_rules! define_keywords {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "serde")]
+            impl Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_str($token)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializing::token(deserializer, $token)?;
+                    Ok($name::default())
+                }
+            }
+
             #[cfg(feature = "printing")]
             impl ToTokens for $name {
                 fn to_tokens(&self, tokens: &mut TokenStream) {
@@ -388,6 +411,27 @@ macro_rules! define_punctuation_structs {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "serde")]
+            impl Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_str($token)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializing::token(deserializer, $token)?;
+                    Ok($name::default())
+                }
+            }
+
             impl_deref_if_len_is_1!($name/$len);
         )*
     };
@@ -480,6 +524,27 @@ macro_rules! define_delimiters {
                 fn hash<H: Hasher>(&self, _state: &mut H) {}
             }
 
+            #[cfg(feature = "serde")]
+            impl Serialize for $name {
+                fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+                where
+                    S: Serializer,
+                {
+                    serializer.serialize_str($token)
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    deserializing::token(deserializer, $token)?;
+                    Ok($name::default())
+                }
+            }
+
             impl $name {
                 #[cfg(feature = "printing")]
                 pub fn surround<F>(&self, tokens: &mut TokenStream, f: F)
@@ -521,7 +586,7 @@ impl Parse for Underscore {
                     return Ok((Underscore(punct.span()), rest));
                 }
             }
-            Err(cursor.error("expected `_`"))
+            Err(cursor.error_expected(&["`_`"]))
         })
     }
 }
@@ -831,7 +896,7 @@ pub mod parsing {
     use proc_macro2::{Spacing, Span};
 
     use crate::buffer::Cursor;
-    use crate::error::{Error, Result};
+    use crate::error::{self, Error, Result};
     use crate::parse::ParseStream;
     use crate::span::FromSpans;
 
@@ -842,7 +907,7 @@ pub mod parsing {
                     return Ok((ident.span(), rest));
                 }
             }
-            Err(cursor.error(format!("expected `{}`", token)))
+            Err(cursor.error_expected(&[&format!("`{}`", token)]))
         })
     }
 
@@ -862,6 +927,7 @@ pub mod parsing {
 
     fn punct_helper(input: ParseStream, token: &str, spans: &mut [Span; 3]) -> Result<()> {
         input.step(|cursor| {
+            let start = *cursor;
             let mut cursor = *cursor;
             assert!(token.len() <= spans.len());
 
@@ -882,7 +948,10 @@ pub mod parsing {
                 }
             }
 
-            Err(Error::new(spans[0], format!("expected `{}`", token)))
+            let expected = format!("`{}`", token);
+            let message = format!("expected {}", expected);
+            let kind = error::expected_kind(start, vec![expected]);
+            Err(Error::new(spans[0], message).with_kind(kind))
         })
     }
 
@@ -953,3 +1022,34 @@ pub mod printing {
         tokens.append(g);
     }
 }
+
+#[cfg(feature = "serde")]
+mod deserializing {
+    use serde::de::{self, Deserializer, Unexpected, Visitor};
+    use std::fmt;
+
+    struct TokenVisitor(&'static str);
+
+    impl<'de> Visitor<'de> for TokenVisitor {
+        type Value = ();
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            write!(formatter, "`{}`", self.0)
+        }
+
+        fn visit_str<E: de::Error>(self, repr: &str) -> Result<Self::Value, E> {
+            if repr == self.0 {
+                Ok(())
+            } else {
+                Err(E::invalid_value(Unexpected::Str(repr), &self))
+            }
+        }
+    }
+
+    pub fn token<'de, D: Deserializer<'de>>(
+        deserializer: D,
+        token: &'static str,
+    ) -> Result<(), D::Error> {
+        deserializer.deserialize_str(TokenVisitor(token))
+    }
+}
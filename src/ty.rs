@@ -492,7 +492,7 @@ pub mod parsing {
                         elems.push_punct(content.parse()?);
                         let rest: Punctuated<Type, Token![,]> =
                             content.parse_terminated(Parse::parse)?;
-                        elems.extend(rest);
+                        elems.extend(rest.into_pairs());
                         elems
                     },
                 }));
@@ -16,6 +16,8 @@ macro_rules! hide_from_rustfmt {
     feature = "clone-impls",
     feature = "extra-traits",
     feature = "proc-macro",
+    feature = "serde",
+    feature = "span-locations",
 )))]
 hide_from_rustfmt! {
     mod error;
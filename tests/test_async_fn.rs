@@ -0,0 +1,170 @@
+mod features;
+
+use quote::quote;
+use syn::async_fn::FutureStyle;
+use syn::{parse_quote, ImplItemMethod, ItemFn, TraitItemMethod};
+
+#[test]
+fn test_impl_future() {
+    let mut item: ItemFn = parse_quote! {
+        async fn parse<T: FromStr>(text: &str) -> Result<T, T::Err> {
+            text.trim().parse()
+        }
+    };
+    item.desugar_async(FutureStyle::Impl).unwrap();
+
+    let expected = quote! {
+        fn parse<'life0, 'future, T: FromStr>(text: &'life0 str)
+            -> impl ::std::future::Future<Output = Result<T, T::Err> > + 'future
+        where
+            T: 'future,
+            &'life0 str: 'future
+        {
+            async move {
+                let text = text;
+                let __ret: Result<T, T::Err> = {
+                    text.trim().parse()
+                };
+                __ret
+            }
+        }
+    };
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_argument_patterns() {
+    let mut item: ItemFn = parse_quote! {
+        async fn f(mut n: u32, (a, b): (u8, u8), _: Guard, ref r: u8) {
+            n += a + b;
+        }
+    };
+    item.desugar_async(FutureStyle::Boxed).unwrap();
+
+    let expected = quote! {
+        fn f<'future>(n: u32, __arg1: (u8, u8), __arg2: Guard, __arg3: u8)
+            -> ::std::pin::Pin<::std::boxed::Box<
+                dyn ::std::future::Future<Output = ()> + 'future
+            > >
+        where
+            u32: 'future,
+            (u8, u8): 'future,
+            Guard: 'future,
+            u8: 'future
+        {
+            ::std::boxed::Box::pin(async move {
+                let mut n = n;
+                let (a, b) = __arg1;
+                let __arg2 = __arg2;
+                let ref r = __arg3;
+                let __ret: () = {
+                    n += a + b;
+                };
+                __ret
+            })
+        }
+    };
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_no_arguments() {
+    let mut item: ItemFn = parse_quote! {
+        async fn answer() -> u8 { 42 }
+    };
+    item.desugar_async(FutureStyle::BoxedSend).unwrap();
+
+    let expected = quote! {
+        fn answer()
+            -> ::std::pin::Pin<::std::boxed::Box<
+                dyn ::std::future::Future<Output = u8> + ::std::marker::Send
+            > >
+        {
+            ::std::boxed::Box::pin(async move {
+                let __ret: u8 = { 42 };
+                __ret
+            })
+        }
+    };
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_impl_trait() {
+    let mut item: ItemFn = parse_quote! {
+        async fn show(x: impl Display) -> impl Display { x.to_string() }
+    };
+    item.desugar_async(FutureStyle::Impl).unwrap();
+
+    // Neither type can be written in a `where` clause or on a `let`.
+    let expected = quote! {
+        fn show<'future>(x: impl Display + 'future)
+            -> impl ::std::future::Future<Output = impl Display> + 'future
+        {
+            async move {
+                let x = x;
+                x.to_string()
+            }
+        }
+    };
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_methods() {
+    let mut method: TraitItemMethod = parse_quote! {
+        async fn take(self, n: usize) -> usize;
+    };
+    method.desugar_async(FutureStyle::BoxedSend).unwrap();
+    let expected = quote! {
+        fn take<'future>(self, n: usize)
+            -> ::std::pin::Pin<::std::boxed::Box<
+                dyn ::std::future::Future<Output = usize> + ::std::marker::Send + 'future
+            > >
+        where
+            Self: 'future,
+            usize: 'future;
+    };
+    assert_eq!(quote!(#method).to_string(), expected.to_string());
+
+    let mut method: ImplItemMethod = parse_quote! {
+        pub async fn clear(&mut self) { self.items.clear() }
+    };
+    method.desugar_async(FutureStyle::Impl).unwrap();
+    let expected = quote! {
+        pub fn clear<'life0, 'future>(&'life0 mut self)
+            -> impl ::std::future::Future<Output = ()> + 'future
+        where
+            &'life0 mut Self: 'future
+        {
+            async move {
+                let __ret: () = { self.items.clear() };
+                __ret
+            }
+        }
+    };
+    assert_eq!(quote!(#method).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_unchanged() {
+    let mut item: ItemFn = parse_quote!(
+        fn f(x: &u8) -> &u8 {
+            x
+        }
+    );
+    item.desugar_async(FutureStyle::Impl).unwrap();
+    let expected = quote!(
+        fn f(x: &u8) -> &u8 {
+            x
+        }
+    );
+    assert_eq!(quote!(#item).to_string(), expected.to_string());
+
+    let mut item: ItemFn = parse_quote!(
+        async fn f(x: &u8, y: &u8) -> &u8 {
+            x
+        }
+    );
+    assert!(item.desugar_async(FutureStyle::Impl).is_err());
+}
@@ -0,0 +1,112 @@
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, DeriveInput, WherePredicate};
+
+fn bounds(input: DeriveInput) -> String {
+    let predicates = input.infer_bounds(&parse_quote!(Trait), "attr").unwrap();
+    let predicates = predicates.iter().map(WherePredicate::clone);
+    quote!(#(#predicates),*).to_string()
+}
+
+#[test]
+fn test_type_params() {
+    let input = parse_quote! {
+        struct S<'a, T, U, V, W> {
+            t: Vec<T>,
+            u: &'a [U; 4],
+            also_t: Option<T>,
+            marker: PhantomData<(V, W)>,
+        }
+    };
+    assert_eq!(bounds(input), quote!(T: Trait, U: Trait).to_string());
+
+    let input = parse_quote! {
+        enum E<T, U> {
+            A(fn(T) -> u8),
+            B { u: ::std::marker::PhantomData<U> },
+            C,
+        }
+    };
+    assert_eq!(bounds(input), quote!(T: Trait).to_string());
+
+    // A path that only shares a name with a parameter is not the parameter.
+    let input = parse_quote! {
+        struct S<T> {
+            t: ::T,
+            u: self::T,
+        }
+    };
+    assert_eq!(bounds(input), "");
+}
+
+#[test]
+fn test_projections() {
+    let input = parse_quote! {
+        struct S<T: Iterator, U> {
+            item: Box<T::Item>,
+            other: Vec<<U as IntoIterator>::IntoIter>,
+            marker: PhantomData<<T as Trait>::Output>,
+        }
+    };
+    assert_eq!(
+        bounds(input),
+        quote!(T::Item: Trait, <U as IntoIterator>::IntoIter: Trait).to_string(),
+    );
+}
+
+#[test]
+fn test_macro_field() {
+    let input = parse_quote! {
+        struct S<T, U> {
+            t: T,
+            hidden: my_type!(U),
+        }
+    };
+    assert_eq!(bounds(input), quote!(T: Trait, U: Trait).to_string());
+}
+
+#[test]
+fn test_field_attribute() {
+    let input = parse_quote! {
+        struct S<T, U, V> {
+            #[attr(skip, bound = "")]
+            t: T,
+            #[attr(bound = "U: Other, Vec<U>: Trait")]
+            u: U,
+            #[other(bound = "")]
+            v: V,
+        }
+    };
+    assert_eq!(
+        bounds(input),
+        quote!(V: Trait, U: Other, Vec<U>: Trait).to_string(),
+    );
+
+    let input: DeriveInput = parse_quote! {
+        struct S<T> {
+            #[attr(bound = "T +")]
+            t: T,
+        }
+    };
+    assert!(input.infer_bounds(&parse_quote!(Trait), "attr").is_err());
+}
+
+#[test]
+fn test_generics_with_bounds() {
+    let input: DeriveInput = parse_quote! {
+        struct S<T, U> where T: Copy {
+            t: T,
+            marker: PhantomData<U>,
+        }
+    };
+    let generics = input
+        .generics_with_bounds(&parse_quote!(Clone), "attr")
+        .unwrap();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let expanded = quote!(impl #impl_generics Clone for S #ty_generics #where_clause {});
+    let expected = quote! {
+        impl<T, U> Clone for S<T, U> where T: Copy, T: Clone {}
+    };
+    assert_eq!(expanded.to_string(), expected.to_string());
+}
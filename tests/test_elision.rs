@@ -0,0 +1,135 @@
+mod features;
+
+use quote::quote;
+use syn::{parse_quote, Signature, TraitItemMethod, TypeBareFn};
+
+fn expand(mut sig: Signature) -> String {
+    sig.expand_elided_lifetimes().unwrap();
+    quote!(#sig).to_string()
+}
+
+fn sig(method: TraitItemMethod) -> Signature {
+    method.sig
+}
+
+#[test]
+fn test_receiver() {
+    let input = sig(parse_quote!(
+        fn get(&self, key: &str) -> Option<&V>;
+    ));
+    let expected = quote! {
+        fn get<'life0, 'life1>(&'life0 self, key: &'life1 str) -> Option<&'life0 V>
+    };
+    assert_eq!(expand(input), expected.to_string());
+
+    let input = sig(parse_quote!(
+        fn get<'a>(&'a mut self, key: &str) -> impl Iterator + '_;
+    ));
+    let expected = quote! {
+        fn get<'a, 'life0>(&'a mut self, key: &'life0 str) -> impl Iterator + 'a
+    };
+    assert_eq!(expand(input), expected.to_string());
+
+    let input = sig(parse_quote!(
+        fn get(self: &Self, key: &str) -> &str;
+    ));
+    let expected = quote! {
+        fn get<'life0, 'life1>(self: &'life0 Self, key: &'life1 str) -> &'life0 str
+    };
+    assert_eq!(expand(input), expected.to_string());
+}
+
+#[test]
+fn test_single_input() {
+    let input = sig(parse_quote! {
+        fn f<'a, T>(x: Formatter<'_>, n: usize) -> &T where T: 'a;
+    });
+    let expected = quote! {
+        fn f<'a, 'life0, T>(x: Formatter<'life0>, n: usize) -> &'life0 T where T: 'a
+    };
+    assert_eq!(expand(input), expected.to_string());
+
+    // An explicit lifetime counts as the one input lifetime too.
+    let input = sig(parse_quote!(
+        fn f(x: &'static str) -> &str;
+    ));
+    let expected = quote!(fn f(x: &'static str) -> &'static str);
+    assert_eq!(expand(input), expected.to_string());
+}
+
+#[test]
+fn test_fresh_names() {
+    let input = sig(parse_quote!(
+        fn f<'life0>(x: &'life0 u8, y: &u8, z: Ref<'_, u8>);
+    ));
+    let expected = quote! {
+        fn f<'life0, 'life1, 'life2>(x: &'life0 u8, y: &'life1 u8, z: Ref<'life2, u8>)
+    };
+    assert_eq!(expand(input), expected.to_string());
+
+    // Nothing to expand.
+    let input = sig(parse_quote!(
+        fn f<T>(x: T) -> T;
+    ));
+    assert_eq!(expand(input), quote!(fn f<T>(x: T) -> T).to_string());
+}
+
+#[test]
+fn test_nested_scopes() {
+    let input = sig(parse_quote! {
+        fn f(x: &u8, g: fn(&u8) -> &u8, h: Box<dyn Fn(&str) -> &str>) -> &u8;
+    });
+    let expected = quote! {
+        fn f<'life0>(
+            x: &'life0 u8,
+            g: fn(&u8) -> &u8,
+            h: Box<dyn Fn(&str) -> &str>
+        ) -> &'life0 u8
+    };
+    assert_eq!(expand(input), expected.to_string());
+}
+
+#[test]
+fn test_ambiguous() {
+    let mut input = sig(parse_quote!(
+        fn f(x: &u8, y: &u8) -> &u8;
+    ));
+    let err = input.expand_elided_lifetimes().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot infer an elided lifetime in the return type: it is ambiguous which argument it borrows from",
+    );
+    // The signature is left as it was.
+    let expected = quote!(fn f(x: &u8, y: &u8) -> &u8);
+    assert_eq!(quote!(#input).to_string(), expected.to_string());
+
+    let mut input = sig(parse_quote!(
+        fn f() -> &'_ str;
+    ));
+    let err = input.expand_elided_lifetimes().unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cannot infer an elided lifetime in the return type: no argument has a lifetime",
+    );
+}
+
+#[test]
+fn test_bare_fn() {
+    let mut ty: TypeBareFn = parse_quote!(fn(&u8, &str) -> u8);
+    ty.expand_elided_lifetimes().unwrap();
+    let expected = quote!(for<'life0, 'life1> fn(&'life0 u8, &'life1 str) -> u8);
+    assert_eq!(quote!(#ty).to_string(), expected.to_string());
+
+    let mut ty: TypeBareFn = parse_quote!(for<'a> fn(&'a u8) -> &u8);
+    ty.expand_elided_lifetimes().unwrap();
+    let expected = quote!(for<'a> fn(&'a u8) -> &'a u8);
+    assert_eq!(quote!(#ty).to_string(), expected.to_string());
+
+    let mut ty: TypeBareFn = parse_quote!(for<'life0> fn(&'life0 u8, &u8));
+    ty.expand_elided_lifetimes().unwrap();
+    let expected = quote!(for<'life0, 'life1> fn(&'life0 u8, &'life1 u8));
+    assert_eq!(quote!(#ty).to_string(), expected.to_string());
+
+    let mut ty: TypeBareFn = parse_quote!(fn(&u8, &u8) -> &u8);
+    assert!(ty.expand_elided_lifetimes().is_err());
+}
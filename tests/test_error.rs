@@ -0,0 +1,274 @@
+mod features;
+
+use proc_macro2::Span;
+use syn::{Error, ErrorKind, Expr, ItemStruct, LitInt, Path, Type};
+
+#[test]
+fn test_notes() {
+    let span = Span::call_site();
+    let err = Error::new(span, "duplicate field `a`")
+        .label(span, "first defined here")
+        .note("fields must be unique")
+        .span_help(span, "rename one of them")
+        .help("or remove it");
+    assert_eq!(err.to_string(), "duplicate field `a`");
+    assert!(err.is_fatal());
+    assert_eq!(
+        err.to_compile_error().to_string(),
+        concat!(
+            r#"compile_error ! { "duplicate field `a`\n= note: fields must be unique\n= help: or remove it" } "#,
+            r#"compile_error ! { "first defined here" } "#,
+            r#"compile_error ! { "help: rename one of them" }"#,
+        ),
+    );
+}
+
+#[test]
+fn test_warning() {
+    let span = Span::call_site();
+    let warning = Error::warning(span, "deprecated option").note("use `x` instead");
+    assert!(!warning.is_fatal());
+    let tokens = warning.to_compile_error().to_string();
+    let prefix = "# [allow (non_upper_case_globals , dead_code)] const __syn_warning_";
+    assert!(tokens.starts_with(prefix), "{}", tokens);
+    assert!(
+        tokens.ends_with(concat!(
+            " : () = { ",
+            r#"# [deprecated (note = "deprecated option\n= note: use `x` instead")] "#,
+            "# [allow (non_upper_case_globals)] ",
+            "const warning : () = () ; warning } ;",
+        )),
+        "{}",
+        tokens,
+    );
+    assert_ne!(tokens, warning.to_compile_error().to_string());
+
+    let mut err = warning.clone();
+    err.combine(Error::new(span, "bad input"));
+    assert!(err.is_fatal());
+}
+
+#[test]
+fn test_combine() {
+    let span = Span::call_site();
+    let mut err = Error::new(span, "first").note("about first");
+    err.combine(Error::new(span, "second"));
+    let err = err.help("about second");
+
+    let messages: Vec<String> = err
+        .into_iter()
+        .map(|err| err.to_compile_error().to_string())
+        .collect();
+    assert_eq!(
+        messages,
+        [
+            r#"compile_error ! { "first\n= note: about first" }"#,
+            r#"compile_error ! { "second\n= help: about second" }"#,
+        ],
+    );
+}
+
+#[test]
+fn test_render() {
+    let source = "fn f() {\n\tlet x = ;\n}\n";
+    let err = syn::parse_file(source).unwrap_err();
+    assert_eq!(
+        err.render(source, None),
+        concat!(
+            "error: expected expression\n",
+            " --> <anon>:2:10\n",
+            "  |\n",
+            "2 |     let x = ;\n",
+            "  |             ^\n",
+        ),
+    );
+}
+
+#[test]
+fn test_render_children() {
+    let source = "struct S {\n    a: u8,\n    a: u16,\n}\n";
+    let item: ItemStruct = syn::parse_str(source).unwrap();
+    let first = item.fields.iter().next().unwrap();
+    let second = item.fields.iter().nth(1).unwrap();
+    let mut err = Error::new_spanned(second, "duplicate field `a`")
+        .label(first.ident.as_ref().unwrap().span(), "first defined here")
+        .help("rename one of the fields");
+    err.combine(
+        Error::warning(item.ident.span(), "unused struct")
+            .span_note(Span::call_site(), "no location"),
+    );
+    assert_eq!(
+        err.render(source, Some("lib.rs")),
+        concat!(
+            "error: duplicate field `a`\n",
+            " --> lib.rs:3:5\n",
+            "  |\n",
+            "2 |     a: u8,\n",
+            "  |     - first defined here\n",
+            "3 |     a: u16,\n",
+            "  |     ^^^^^^\n",
+            "  |\n",
+            "  = help: rename one of the fields\n",
+            "\n",
+            "warning: unused struct\n",
+            " --> lib.rs:1:8\n",
+            "  |\n",
+            "1 | struct S {\n",
+            "  |        ^\n",
+            "  |\n",
+            "  = note: no location\n",
+        ),
+    );
+}
+
+#[test]
+fn test_render_json() {
+    let source = "fn f() {\n    let x = ;\n}\n";
+    let err = syn::parse_file(source).unwrap_err();
+    let json = err.render_json(source, Some("main.rs"));
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"message":"expected expression","code":null,"level":"error","spans":[{"#,
+            r#""file_name":"main.rs","byte_start":21,"byte_end":22,"line_start":2,"line_end":2,"#,
+            r#""column_start":13,"column_end":14,"is_primary":true,"text":[{"text":"    let x = ;","#,
+            r#""highlight_start":13,"highlight_end":14}],"label":null,"suggested_replacement":null,"#,
+            r#""suggestion_applicability":null,"expansion":null}],"children":[],"#,
+            r#""rendered":"error: expected expression\n --> main.rs:2:13\n  |\n2 |     let x = ;\n  |             ^\n"}"#,
+            "\n",
+        ),
+    );
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(value["spans"][0]["byte_start"], 21);
+}
+
+fn kind(err: &Error) -> (String, ErrorKind) {
+    (err.to_string(), err.kind().clone())
+}
+
+fn strings(strings: &[&str]) -> Vec<String> {
+    strings.iter().map(|s| s.to_string()).collect()
+}
+
+#[test]
+fn test_kind_unexpected_token() {
+    let err = syn::parse_str::<Expr>("f(a b)").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "expected `,`".to_owned(),
+            ErrorKind::UnexpectedToken {
+                expected: strings(&["`,`"]),
+                found: "b".to_owned(),
+            },
+        ),
+    );
+
+    let err = syn::parse_str::<Path>("a::{b}").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "expected identifier".to_owned(),
+            ErrorKind::UnexpectedToken {
+                expected: strings(&["identifier"]),
+                found: "{".to_owned(),
+            },
+        ),
+    );
+}
+
+#[test]
+fn test_kind_lookahead() {
+    let err = syn::parse_str::<Type>("+").unwrap_err();
+    match err.kind() {
+        ErrorKind::UnexpectedToken { expected, found } => {
+            assert!(expected.len() > 2);
+            assert!(err.to_string().starts_with("expected one of: "));
+            assert_eq!(found, "+");
+        }
+        other => panic!("unexpected kind: {:?}", other),
+    }
+}
+
+#[test]
+fn test_kind_unexpected_eof() {
+    let err = syn::parse_str::<Expr>("a +").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "unexpected end of input, expected expression".to_owned(),
+            ErrorKind::UnexpectedEof {
+                expected: strings(&["expression"]),
+            },
+        ),
+    );
+
+    let err = syn::parse_str::<Expr>("").unwrap_err();
+    assert_eq!(
+        kind(&err).1,
+        ErrorKind::UnexpectedEof {
+            expected: strings(&["expression"]),
+        },
+    );
+}
+
+#[test]
+fn test_kind_leftover() {
+    let err = syn::parse_str::<Expr>("a b").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "unexpected token".to_owned(),
+            ErrorKind::UnexpectedToken {
+                expected: Vec::new(),
+                found: "b".to_owned(),
+            },
+        ),
+    );
+
+    let err = syn::parse_str::<Expr>("[a b]").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "expected `,` or `;`".to_owned(),
+            ErrorKind::UnexpectedToken {
+                expected: strings(&["`,`", "`;`"]),
+                found: "b".to_owned(),
+            },
+        ),
+    );
+
+    let err = syn::parse_str::<Type>("fn(u8) -> [u8 5]").unwrap_err();
+    assert_eq!(
+        kind(&err),
+        (
+            "unexpected token".to_owned(),
+            ErrorKind::UnexpectedToken {
+                expected: Vec::new(),
+                found: "5".to_owned(),
+            },
+        ),
+    );
+}
+
+#[test]
+fn test_kind_other() {
+    let lit: LitInt = syn::parse_str("300").unwrap();
+    let err = lit.base10_parse::<u8>().unwrap_err();
+    assert_eq!(err.kind(), &ErrorKind::InvalidLiteral);
+
+    let mut err = Error::new(Span::call_site(), "custom");
+    assert_eq!(err.kind(), &ErrorKind::Other);
+    err.combine(syn::parse_str::<Expr>("a b").unwrap_err());
+    let kinds: Vec<ErrorKind> = err.iter().map(|err| err.kind().clone()).collect();
+    assert_eq!(
+        kinds,
+        [
+            ErrorKind::Other,
+            ErrorKind::UnexpectedToken {
+                expected: Vec::new(),
+                found: "b".to_owned(),
+            },
+        ],
+    );
+}
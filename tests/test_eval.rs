@@ -0,0 +1,167 @@
+mod features;
+
+use syn::eval::{self, Evaluator, IntType, Value};
+use syn::{Expr, File, Ident};
+
+fn eval(expr: &str) -> Value {
+    let expr: Expr = syn::parse_str(expr).unwrap();
+    eval::eval(&expr).unwrap()
+}
+
+fn int(expr: &str) -> (i128, IntType) {
+    match eval(expr) {
+        Value::Int(int) => (int.to_i128().unwrap(), int.ty()),
+        Value::Bool(b) => panic!("expected integer, found {}", b),
+    }
+}
+
+fn error(expr: &str) -> String {
+    let expr: Expr = syn::parse_str(expr).unwrap();
+    eval::eval(&expr).unwrap_err().to_string()
+}
+
+#[test]
+fn test_literals() {
+    assert_eq!(int("1"), (1, IntType::I32));
+    assert_eq!(int("1u8 + 2"), (3, IntType::U8));
+    assert_eq!(int("2 * (3 + 4i64)"), (14, IntType::I64));
+    assert_eq!(int("b'a'"), (97, IntType::U8));
+    assert_eq!(int("-128i8"), (-128, IntType::I8));
+    assert_eq!(int("0xff_u16 & !0x0f"), (0xf0, IntType::U16));
+    assert_eq!(int("-7 / 2"), (-3, IntType::I32));
+    assert_eq!(int("-7 % 2"), (-1, IntType::I32));
+    assert_eq!(int("-1i64 ^ 1"), (-2, IntType::I64));
+
+    let expr: Expr = syn::parse_str("340282366920938463463374607431768211455u128").unwrap();
+    match eval::eval(&expr).unwrap() {
+        Value::Int(int) => {
+            assert_eq!(int.to_u128(), Some(u128::max_value()));
+            assert_eq!(int.to_i128(), None);
+        }
+        Value::Bool(_) => unreachable!(),
+    }
+}
+
+#[test]
+fn test_shifts_and_casts() {
+    assert_eq!(int("1u8 << 7"), (128, IntType::U8));
+    assert_eq!(int("1u64 << 2u8"), (4, IntType::U64));
+    assert_eq!(int("-16i8 >> 2"), (-4, IntType::I8));
+    assert_eq!(int("0x80u8 >> 7"), (1, IntType::U8));
+    assert_eq!(int("300i32 as u8"), (44, IntType::U8));
+    assert_eq!(int("-1i32 as u8"), (255, IntType::U8));
+    assert_eq!(int("255u8 as i8"), (-1, IntType::I8));
+    assert_eq!(int("-1i8 as u64 >> 60"), (15, IntType::U64));
+    assert_eq!(int("true as u8 + 1"), (2, IntType::U8));
+    assert_eq!(int("'\u{1f980}' as u16"), (0xf980, IntType::U16));
+}
+
+#[test]
+fn test_bool() {
+    assert_eq!(eval("1 < 2"), Value::Bool(true));
+    assert_eq!(eval("-1i8 < 0 && 255u8 > 1"), Value::Bool(true));
+    assert_eq!(eval("false || !(1u8 == 1)"), Value::Bool(false));
+    assert_eq!(eval("true ^ true"), Value::Bool(false));
+    assert_eq!(eval("false && 1 / 0 == 0"), Value::Bool(false));
+}
+
+#[test]
+fn test_errors() {
+    assert_eq!(error("255u8 + 1"), "attempt to add with overflow");
+    assert_eq!(error("0u32 - 1"), "attempt to subtract with overflow");
+    assert_eq!(error("1 << 32"), "attempt to shift left with overflow");
+    assert_eq!(error("1 / (2 - 2)"), "attempt to divide by zero");
+    assert_eq!(error("-128i8 / -1"), "attempt to divide with overflow");
+    assert_eq!(error("-(-128i8)"), "attempt to negate with overflow");
+    assert_eq!(error("256u8"), "literal out of range for `u8`");
+    assert_eq!(error("128i8"), "literal out of range for `i8`");
+    assert_eq!(error("300 as u8"), "literal out of range for `u8`");
+    assert_eq!(error("-1u8"), "cannot apply operator `-` to type `u8`");
+    assert_eq!(
+        error("true + 1"),
+        "mismatched types: expected `bool`, found `i32`"
+    );
+    assert_eq!(
+        error("1u8 + 1u16"),
+        "mismatched types: expected `u8`, found `u16`"
+    );
+    assert_eq!(
+        error("true + false"),
+        "cannot apply operator `+` to type `bool`"
+    );
+    assert_eq!(
+        error("1 as f32"),
+        "only casts to integer types can be evaluated"
+    );
+    assert_eq!(
+        error("1.0"),
+        "only integer, byte and `bool` literals can be evaluated",
+    );
+    assert_eq!(
+        error("f(1)"),
+        "expression cannot be evaluated at compile time",
+    );
+    assert_eq!(error("N"), "cannot find constant `N` in this scope");
+}
+
+#[test]
+fn test_consts() {
+    let file: File = syn::parse_str(
+        "
+        const A: u8 = 200;
+        const B: u16 = A as u16 * 2;
+        const C: bool = B > 255;
+        const D: u8 = A + 100;
+        const E: u32 = F;
+        const F: u32 = E;
+        const G: u8 = B;
+        const H: &str = \"h\";
+        ",
+    )
+    .unwrap();
+    let evaluator = Evaluator::from_file(&file);
+    let eval_const =
+        |name: &str| evaluator.eval_const(&Ident::new(name, proc_macro2::Span::call_site()));
+
+    let b = match eval_const("B").unwrap() {
+        Value::Int(int) => int,
+        Value::Bool(_) => unreachable!(),
+    };
+    assert_eq!((b.to_u128(), b.ty()), (Some(400), IntType::U16));
+    assert_eq!(eval_const("C").unwrap(), Value::Bool(true));
+
+    let expr: Expr = syn::parse_str("B + 1").unwrap();
+    let value = evaluator.eval_as(&expr, IntType::U16).unwrap();
+    assert_eq!(value.to_u128(), Some(401));
+    let err = evaluator.eval_as(&expr, IntType::Usize).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "mismatched types: expected `usize`, found `u16`"
+    );
+
+    let err = eval_const("D").unwrap_err();
+    assert_eq!(err.to_string(), "attempt to add with overflow");
+    let err = eval_const("E").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "cycle detected when evaluating constant `E`"
+    );
+    let err = eval_const("G").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "mismatched types: expected `u8`, found `u16`"
+    );
+    let err = eval_const("H").unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "constant `H` does not have an integer or `bool` type",
+    );
+}
+
+#[test]
+fn test_error_span() {
+    let expr: Expr = syn::parse_str("1 + (255u8 + 1)").unwrap();
+    let err = eval::eval(&expr).unwrap_err();
+    let start = err.span().start();
+    assert_eq!((start.line, start.column), (1, 5));
+}
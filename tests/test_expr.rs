@@ -4,7 +4,9 @@ mod macros;
 use std::str::FromStr;
 
 use proc_macro2::TokenStream;
-use syn::{Expr, ExprRange};
+use quote::quote;
+use syn::parse::{ParseStream, Parser};
+use syn::{Block, Expr, ExprRange};
 
 #[test]
 fn test_expr_parse() {
@@ -35,3 +37,57 @@ fn test_await() {
     }
     "###);
 }
+
+#[test]
+fn test_parse_without_eager_brace() {
+    let parser = |input: ParseStream| {
+        let cond = input.call(Expr::parse_without_eager_brace)?;
+        let body: Block = input.parse()?;
+        Ok((cond, body))
+    };
+
+    let (cond, body) = parser.parse_str("a == S { f(); }").unwrap();
+    assert_eq!(quote!(#cond).to_string(), "a == S");
+    assert_eq!(body.stmts.len(), 1);
+
+    // Struct literals are allowed inside of parentheses.
+    let (cond, _) = parser.parse_str("a == (S { x: 1 }) {}").unwrap();
+    assert_eq!(quote!(#cond).to_string(), "a == (S { x : 1 })");
+
+    // Struct literals are allowed by the primary parser.
+    let expr: Expr = syn::parse_str("a == S { x: 1 }").unwrap();
+    assert_eq!(quote!(#expr).to_string(), "a == S { x : 1 }");
+}
+
+#[test]
+fn test_parse_as_statement() {
+    let parser = |input: ParseStream| {
+        let mut exprs = Vec::new();
+        while !input.is_empty() {
+            exprs.push(input.call(Expr::parse_as_statement)?);
+        }
+        Ok(exprs)
+    };
+
+    let exprs = parser.parse_str("match x {} - 1").unwrap();
+    assert_eq!(exprs.len(), 2);
+    match &exprs[1] {
+        Expr::Unary(_) => {}
+        expr => panic!("expected unary expression, found {:?}", expr),
+    }
+
+    // A method call after the block continues the expression.
+    let exprs = parser.parse_str("if a { b } else { c }.d() + 1").unwrap();
+    assert_eq!(exprs.len(), 1);
+    match &exprs[0] {
+        Expr::Binary(_) => {}
+        expr => panic!("expected binary expression, found {:?}", expr),
+    }
+
+    // Outside of statement position the block is the left operand.
+    let expr: Expr = syn::parse_str("match x {} - 1").unwrap();
+    match expr {
+        Expr::Binary(_) => {}
+        expr => panic!("expected binary expression, found {:?}", expr),
+    }
+}
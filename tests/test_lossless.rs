@@ -0,0 +1,156 @@
+mod features;
+
+mod repo;
+
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use std::fs;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use syn::source_map::SourceMap;
+use syn::{parse_quote, Item, ItemFn, Stmt};
+use walkdir::{DirEntry, WalkDir};
+
+#[test]
+fn test_trivia() {
+    let source = "\
+//! Inner doc.
+
+/* before */ #[inline] /// Outer doc.
+fn f<'a>(x: &'a str) -> &'a str { // open
+    x /* after x */
+    // end of block
+} // close
+
+struct S; // last";
+    let (file, map) = SourceMap::parse_file(source).unwrap();
+    assert_eq!(map.print(&file), source);
+
+    let trivia = map.trivia(&file.items[0]).unwrap();
+    assert_eq!(trivia.leading, "\n/* before */ ");
+    assert_eq!(trivia.trailing, " // close\n");
+
+    let trivia = map.trivia(&file.items[1]).unwrap();
+    assert_eq!(trivia.leading, "\n");
+    assert_eq!(trivia.trailing, " // last");
+}
+
+#[test]
+fn test_file_prelude() {
+    let source = "\u{feff}#!/usr/bin/env run-cargo-script\r\n\r\n// Comment.\r\nfn main() {}\r\n";
+    let (file, map) = SourceMap::parse_file(source).unwrap();
+    assert_eq!(map.print(&file), source);
+}
+
+#[test]
+fn test_only_trivia() {
+    let source = "// Nothing here.\n";
+    let (file, map) = SourceMap::parse_file(source).unwrap();
+    assert_eq!(map.print(&file), source);
+}
+
+#[test]
+fn test_replace_item() {
+    let source = "\
+// First.
+fn first() {
+    one(); // one
+}
+
+// Second.
+fn second() {}
+
+// Third.
+fn third() {
+    three(); // three
+}
+";
+    let (mut file, map) = SourceMap::parse_file(source).unwrap();
+    file.items[1] = parse_quote! { fn replaced() {} };
+    let expected = "\
+// First.
+fn first() {
+    one(); // one
+}
+fn replaced ( ) { }
+// Third.
+fn third() {
+    three(); // three
+}
+";
+    assert_eq!(map.print(&file), expected);
+
+    let item: Item = parse_quote! { fn new() {} };
+    file.items.push(item);
+    assert!(map.print(&file).ends_with("// three\n}\nfn new ( ) { }"));
+}
+
+#[test]
+fn test_stray_semicolons() {
+    let source = "fn f() {\n    a(); ; // stray\n    ;\n    b();\n}\n";
+    let (mut item, map) = SourceMap::parse_str::<ItemFn>(source).unwrap();
+    assert_eq!(item.block.stmts.len(), 2);
+    assert_eq!(map.print(&item), source);
+
+    // A semicolon that was part of the syntax tree stays removed, along with
+    // its trailing trivia.
+    let stmt = match item.block.stmts.pop() {
+        Some(Stmt::Semi(expr, _semi)) => Stmt::Expr(expr),
+        _ => unreachable!(),
+    };
+    item.block.stmts.push(stmt);
+    assert_eq!(
+        map.print(&item),
+        "fn f() {\n    a(); ; // stray\n    ;\n    b()}\n",
+    );
+}
+
+#[test]
+fn test_own_source() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(path).unwrap();
+        let (file, map) = SourceMap::parse_file(&content).unwrap();
+        assert_eq!(map.print(&file), content, "{}", path.display());
+    }
+}
+
+#[test]
+#[ignore] // requires a download of the rust-lang/rust repo
+fn test_repo() {
+    repo::clone_rust();
+
+    let failed = AtomicUsize::new(0);
+
+    WalkDir::new("tests/rust")
+        .sort_by(|a, b| a.file_name().cmp(b.file_name()))
+        .into_iter()
+        .filter_entry(repo::base_dir_filter)
+        .collect::<Result<Vec<DirEntry>, walkdir::Error>>()
+        .unwrap()
+        .into_par_iter()
+        .for_each(|entry| {
+            let path = entry.path();
+            if path.is_dir() {
+                return;
+            }
+
+            let content = fs::read_to_string(path).unwrap();
+            let (file, map) = match SourceMap::parse_file(&content) {
+                Ok(parsed) => parsed,
+                // Parse failures are covered by test_round_trip.
+                Err(_) => return,
+            };
+            if map.print(&file) != content {
+                eprintln!("=== {}: lossless print differs", path.display());
+                failed.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+    let failed = failed.load(Ordering::SeqCst);
+    if failed > 0 {
+        panic!("{} failures", failed);
+    }
+}
@@ -0,0 +1,219 @@
+mod features;
+
+use proc_macro2::{Delimiter, Group, TokenStream, TokenTree};
+use quote::quote;
+use syn::macro_rules::{FragmentKind, MacroRules, MatcherTree, RepetitionOp};
+use syn::{parse_quote, Expr, ItemMacro};
+
+fn rules(item: ItemMacro) -> MacroRules {
+    MacroRules::from_item(&item).unwrap()
+}
+
+fn rules_from_str(s: &str) -> MacroRules {
+    rules(syn::parse_str(s).unwrap())
+}
+
+fn expand(rules: &MacroRules, input: TokenStream) -> String {
+    flatten(rules.expand(input).unwrap()).to_string()
+}
+
+// Splices the contents of invisible groups into the surrounding tokens.
+fn flatten(tokens: TokenStream) -> TokenStream {
+    tokens
+        .into_iter()
+        .flat_map(|token| match token {
+            TokenTree::Group(group) => {
+                let stream = flatten(group.stream());
+                if group.delimiter() == Delimiter::None {
+                    stream
+                } else {
+                    TokenTree::Group(Group::new(group.delimiter(), stream)).into()
+                }
+            }
+            token => token.into(),
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse() {
+    let item: ItemMacro = parse_quote! {
+        macro_rules! hashmap {
+            () => { HashMap::new() };
+            ($($k:expr => $v:expr),+ $(,)?) => {{
+                let mut map = HashMap::new();
+                $(map.insert($k, $v);)+
+                map
+            }};
+        }
+    };
+    let rules = rules(item);
+    assert_eq!(rules.rules.len(), 2);
+
+    let matcher = &rules.rules[1].matcher.tokens;
+    assert_eq!(matcher.len(), 2);
+    match &matcher[0] {
+        MatcherTree::Repetition(repetition) => {
+            match repetition.op {
+                RepetitionOp::OneOrMore(_) => {}
+                _ => panic!("expected `+`"),
+            }
+            let separator = repetition.separator.as_ref().unwrap();
+            assert_eq!(separator.to_string(), ",");
+            match &repetition.tokens[0] {
+                MatcherTree::Fragment(fragment) => {
+                    assert_eq!(fragment.name, "k");
+                    assert_eq!(fragment.kind, FragmentKind::Expr);
+                }
+                _ => panic!("expected fragment"),
+            }
+        }
+        _ => panic!("expected repetition"),
+    }
+
+    let printed = quote!(#rules).to_string();
+    let reparsed: MacroRules = syn::parse_str(&printed).unwrap();
+    assert_eq!(quote!(#reparsed).to_string(), printed);
+}
+
+#[test]
+fn test_repetition() {
+    let rules = rules(parse_quote! {
+        macro_rules! hashmap {
+            () => { HashMap::new() };
+            ($($k:expr => $v:expr),+ $(,)?) => {{
+                let mut map = HashMap::new();
+                $(map.insert($k, $v);)+
+                map
+            }};
+        }
+    });
+
+    assert_eq!(expand(&rules, quote!()), "HashMap :: new ()");
+    let expansion: Expr = syn::parse2(rules.expand(quote!(1 => "a", 2 => "b",)).unwrap()).unwrap();
+    let expected: Expr = parse_quote! {{
+        let mut map = HashMap::new();
+        map.insert(1, "a");
+        map.insert(2, "b");
+        map
+    }};
+    assert_eq!(
+        quote!(#expansion).to_string(),
+        quote!(#expected).to_string()
+    );
+}
+
+#[test]
+fn test_precedence() {
+    let rules = rules(parse_quote! {
+        macro_rules! double {
+            ($e:expr) => { $e * 2 };
+        }
+    });
+    let expr: Expr = syn::parse2(rules.expand(quote!(1 + 1)).unwrap()).unwrap();
+    match expr {
+        Expr::Binary(binary) => match *binary.left {
+            Expr::Group(group) => assert_eq!(quote!(#group).to_string(), "1 + 1"),
+            _ => panic!("expected the operand to stay grouped"),
+        },
+        _ => panic!("expected binary expression"),
+    }
+}
+
+#[test]
+fn test_fragment_kinds() {
+    let rules = rules(parse_quote! {
+        macro_rules! kinds {
+            ($v:vis struct $name:ident<$lt:lifetime>($t:ty);) => { $v struct $name<$lt>($t); };
+            (stmt $s:stmt; lit $l:literal; pat $p:pat; tt $($x:tt)*) => { $s; $l; $p; $($x)* };
+            (meta #[$m:meta] path $p:path; block $b:block) => { #[$m] fn f() -> $p $b };
+        }
+    });
+
+    assert_eq!(
+        expand(
+            &rules,
+            quote!(
+                struct S<'a>(&'a str);
+            )
+        ),
+        "struct S < 'a > (& 'a str) ;",
+    );
+    assert_eq!(
+        expand(
+            &rules,
+            quote!(
+                pub(crate) struct T<'b>(Vec<u8>);
+            )
+        ),
+        "pub (crate) struct T < 'b > (Vec < u8 >) ;",
+    );
+    assert_eq!(
+        expand(
+            &rules,
+            quote!(stmt let x: u8 = 1; lit -1; pat Some(_); tt 'a ? [z])
+        ),
+        "let x : u8 = 1 ; - 1 ; Some (_) ; 'a ? [z]",
+    );
+    assert_eq!(
+        expand(
+            &rules,
+            quote!(meta #[doc = "f"] path std::io::Result<()>; block { Ok(()) })
+        ),
+        "# [doc = \"f\"] fn f () -> std :: io :: Result < () > { Ok (()) }",
+    );
+}
+
+#[test]
+fn test_nested_repetition() {
+    let rules = rules(parse_quote! {
+        macro_rules! table {
+            ($name:ident: $($row:ident => [$($cell:expr),*]);*) => {
+                const $name: &[&[u32]] = &[$(&[$($cell + $row),*]),*];
+            };
+        }
+    });
+    assert_eq!(
+        expand(&rules, quote!(T: a => [1, 2]; b => []; c => [3])),
+        "const T : & [& [u32]] = & [& [1 + a , 2 + a] , & [] , & [3 + c]] ;",
+    );
+}
+
+#[test]
+fn test_errors() {
+    let rules = rules(parse_quote! {
+        macro_rules! one {
+            ($e:expr) => { $e };
+        }
+    });
+    let err = rules.expand(quote!(struct)).unwrap_err();
+    assert_eq!(err.to_string(), "expected expression");
+
+    let rules = rules_from_str("macro_rules! two { (a) => {}; (b) => {}; }");
+    let err = rules.expand(quote!(c)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "no rules expected this token in macro call"
+    );
+
+    let rules =
+        rules_from_str("macro_rules! zip { ($($a:ident)* ; $($b:ident)*) => { $(($a, $b))* }; }");
+    assert_eq!(expand(&rules, quote!(x y; z w)), "(x , z) (y , w)");
+    let err = rules.expand(quote!(x y; z)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "meta-variable `a` repeats 2 times, but `b` repeats 1 times",
+    );
+
+    let rules = rules_from_str("macro_rules! flat { ($($a:ident)*) => { $a }; }");
+    let err = rules.expand(quote!(x)).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "variable `a` is still repeating at this depth"
+    );
+
+    let err = syn::parse_str::<MacroRules>("($x) => {}").unwrap_err();
+    assert_eq!(err.to_string(), "missing fragment specifier");
+    let err = syn::parse_str::<MacroRules>("($x:expression) => {}").unwrap_err();
+    assert_eq!(err.to_string(), "invalid fragment specifier `expression`");
+}
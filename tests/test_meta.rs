@@ -3,7 +3,9 @@ mod features;
 #[macro_use]
 mod macros;
 
-use syn::{Meta, MetaList, MetaNameValue, NestedMeta};
+use quote::quote;
+use syn::meta::{NestedTokenMeta, TokenMeta, TokenMetaNameValue};
+use syn::{parse_quote, Attribute, Expr, Meta, MetaList, MetaNameValue, NestedMeta, Path, Type};
 
 #[test]
 fn test_parse_meta_item_word() {
@@ -339,3 +341,91 @@ fn test_parse_nested_meta() {
     })
     "###);
 }
+
+fn name_values(attr: &Attribute) -> Vec<TokenMetaNameValue> {
+    let list = match attr.parse_meta_tokens().unwrap() {
+        TokenMeta::List(list) => list,
+        _ => panic!("expected list"),
+    };
+    list.nested
+        .into_iter()
+        .map(|nested| match nested {
+            NestedTokenMeta::Meta(TokenMeta::NameValue(nv)) => nv,
+            _ => panic!("expected name-value"),
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_meta_tokens() {
+    let attr: Attribute = parse_quote! {
+        #[attr(default = path::to::f, x = 1 + 2, ty = HashMap<K, Vec<V>>, f = |a| a <= 1)]
+    };
+    let values = name_values(&attr);
+    assert_eq!(values.len(), 4);
+
+    let path: Path = values[0].parse_value().unwrap();
+    assert_eq!(quote!(#path).to_string(), "path :: to :: f");
+    match values[1].parse_value().unwrap() {
+        Expr::Binary(_) => {}
+        _ => panic!("expected binary expression"),
+    }
+    let ty: Type = values[2].parse_value().unwrap();
+    assert_eq!(quote!(#ty).to_string(), "HashMap < K , Vec < V > >");
+    assert!(values[3].path.is_ident("f"));
+    values[3].parse_value::<Expr>().unwrap();
+
+    let attr: Attribute = parse_quote!(#[attr(ret = fn() -> u8, next = 0)]);
+    let values = name_values(&attr);
+    assert_eq!(values[0].value.to_string(), "fn () -> u8");
+    assert_eq!(values[1].value.to_string(), "0");
+}
+
+#[test]
+fn test_parse_meta_tokens_operators() {
+    let attr: Attribute = parse_quote!(#[a(mask = 1 << 4, name = "x")]);
+    let values = name_values(&attr);
+    assert_eq!(values.len(), 2);
+    assert_eq!(values[0].value.to_string(), "1 << 4");
+    assert!(values[1].path.is_ident("name"));
+
+    let attr: Attribute = parse_quote!(#[a(f = |x| x <<= 1, g = a <= b, h = 0)]);
+    let values = name_values(&attr);
+    assert_eq!(values.len(), 3);
+    assert_eq!(values[1].value.to_string(), "a <= b");
+
+    // A comparison followed by a comma reads the rest of the list as
+    // generic arguments.
+    let attr: Attribute = parse_quote!(#[a(x = a < b, c = 0)]);
+    let values = name_values(&attr);
+    assert_eq!(values.len(), 1);
+    assert_eq!(values[0].value.to_string(), "a < b , c = 0");
+}
+
+#[test]
+fn test_parse_meta_tokens_errors() {
+    let attr: Attribute = parse_quote!(#[attr(x = , y)]);
+    let err = attr.parse_meta_tokens().err().unwrap();
+    assert_eq!(err.to_string(), "expected a value after `=`");
+
+    let attr: Attribute = parse_quote!(#[attr(x = a b)]);
+    let err = name_values(&attr)[0].parse_value::<Expr>().err().unwrap();
+    assert_eq!(err.to_string(), "unexpected token");
+}
+
+#[test]
+fn test_token_meta_to_meta() {
+    let attr: Attribute = parse_quote!(#[serde(rename = "x", skip, with(a = 1))]);
+    let tokens = attr.parse_meta_tokens().unwrap();
+    let meta = tokens.to_meta().unwrap();
+    assert_eq!(meta, attr.parse_meta().unwrap());
+    assert_eq!(
+        TokenMeta::from(meta).to_meta().unwrap(),
+        tokens.to_meta().unwrap()
+    );
+
+    let attr: Attribute = parse_quote!(#[serde(default = path::to::f)]);
+    let err = attr.parse_meta_tokens().unwrap().to_meta().err().unwrap();
+    assert_eq!(err.to_string(), "expected literal");
+    attr.parse_meta().err().unwrap();
+}
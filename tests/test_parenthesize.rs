@@ -0,0 +1,228 @@
+mod features;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::precedence::Precedence;
+use syn::{
+    parse_quote, BinOp, Block, Expr, ExprBinary, ExprCall, ExprCast, ExprField, ExprMethodCall,
+    Stmt,
+};
+
+fn binary(left: Expr, op: BinOp, right: Expr) -> Expr {
+    Expr::Binary(ExprBinary {
+        attrs: Vec::new(),
+        left: Box::new(left),
+        op,
+        right: Box::new(right),
+    })
+}
+
+fn cast_u8(expr: Expr) -> Expr {
+    Expr::Cast(ExprCast {
+        attrs: Vec::new(),
+        expr: Box::new(expr),
+        as_token: Default::default(),
+        ty: parse_quote!(u8),
+    })
+}
+
+fn assert_prints(expr: &Expr, expected: &str) {
+    let expected: TokenStream = expected.parse().unwrap();
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+
+    // What gets printed must parse back into the same syntax tree, apart from
+    // the added parentheses.
+    let reparsed: Expr = syn::parse2(quote!(#expr)).unwrap();
+    assert_eq!(quote!(#reparsed).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_precedence_of() {
+    let expr: Expr = parse_quote!(a + b * c);
+    assert_eq!(Precedence::of(&expr), Precedence::Arithmetic);
+    let expr: Expr = parse_quote!(a as u8);
+    assert_eq!(Precedence::of(&expr), Precedence::Cast);
+    let expr: Expr = parse_quote!((a || b));
+    assert_eq!(Precedence::of(&expr), Precedence::Unambiguous);
+    let expr: Expr = parse_quote!(|x| a);
+    assert_eq!(Precedence::of(&expr), Precedence::Jump);
+    assert_eq!(Precedence::of_binop(&parse_quote!(+=)), Precedence::Assign);
+    assert!(Precedence::of_binop(&parse_quote!(&&)) < Precedence::of_binop(&parse_quote!(&)));
+}
+
+#[test]
+fn test_binary_operands() {
+    let sum = binary(parse_quote!(a), parse_quote!(+), parse_quote!(b));
+    let expr = binary(sum.clone(), parse_quote!(*), parse_quote!(c));
+    assert_prints(&expr, "(a + b) * c");
+    let expr = binary(parse_quote!(c), parse_quote!(*), sum.clone());
+    assert_prints(&expr, "c * (a + b)");
+
+    // Left-associative operators only need parentheses on the right.
+    let expr = binary(sum.clone(), parse_quote!(-), parse_quote!(c));
+    assert_prints(&expr, "a + b - c");
+    let expr = binary(parse_quote!(c), parse_quote!(-), sum);
+    assert_prints(&expr, "c - (a + b)");
+
+    // Comparisons do not associate at all.
+    let lt = binary(parse_quote!(a), parse_quote!(<), parse_quote!(b));
+    let expr = binary(lt, parse_quote!(==), parse_quote!(c));
+    assert_prints(&expr, "(a < b) == c");
+}
+
+#[test]
+fn test_cast_operands() {
+    let sum = binary(parse_quote!(a), parse_quote!(+), parse_quote!(b));
+    let expr = cast_u8(sum);
+    assert_prints(&expr, "(a + b) as u8");
+
+    // Without parentheses, `<` would start generic arguments of `usize`.
+    let cast: Expr = parse_quote!(a as usize);
+    let expr = binary(cast.clone(), parse_quote!(<), parse_quote!(b));
+    assert_prints(&expr, "(a as usize) < b");
+    let expr = binary(cast, parse_quote!(>), parse_quote!(b));
+    assert_prints(&expr, "a as usize > b");
+}
+
+#[test]
+fn test_postfix_operands() {
+    let neg: Expr = parse_quote!(-a);
+    let expr = Expr::MethodCall(ExprMethodCall {
+        attrs: Vec::new(),
+        receiver: Box::new(neg.clone()),
+        dot_token: Default::default(),
+        method: parse_quote!(f),
+        turbofish: None,
+        paren_token: Default::default(),
+        args: Default::default(),
+    });
+    assert_prints(&expr, "(-a).f()");
+
+    let expr = Expr::Field(ExprField {
+        attrs: Vec::new(),
+        base: Box::new(neg),
+        dot_token: Default::default(),
+        member: parse_quote!(0),
+    });
+    assert_prints(&expr, "(-a).0");
+
+    // Calling a field is not a method call.
+    let field: Expr = parse_quote!(a.f);
+    let expr = Expr::Call(ExprCall {
+        attrs: Vec::new(),
+        func: Box::new(field),
+        paren_token: Default::default(),
+        args: Default::default(),
+    });
+    assert_prints(&expr, "(a.f)()");
+}
+
+#[test]
+fn test_jump_operands() {
+    let closure: Expr = parse_quote!(|x| a);
+    let expr = binary(parse_quote!(x), parse_quote!(+), closure.clone());
+    assert_prints(&expr, "x + |x| a");
+    let expr = binary(closure, parse_quote!(+), parse_quote!(x));
+    assert_prints(&expr, "(|x| a) + x");
+
+    let expr: Expr = parse_quote!(&mut |x| a);
+    assert_prints(&expr, "&mut |x| a");
+    let expr: Expr = parse_quote!(-return a);
+    assert_prints(&expr, "-return a");
+
+    let ret = binary(parse_quote!(a), parse_quote!(-), parse_quote!(return b));
+    let expr = binary(ret, parse_quote!(-), parse_quote!(c));
+    assert_prints(&expr, "(a - return b) - c");
+
+    // Behind a prefix operator, the closure or `return` still takes in
+    // whatever follows.
+    let expr = binary(parse_quote!(&|x| a), parse_quote!(+), parse_quote!(c));
+    assert_prints(&expr, "(& |x| a) + c");
+    let expr = binary(parse_quote!(-return a), parse_quote!(+), parse_quote!(c));
+    assert_prints(&expr, "(-return a) + c");
+    let expr = binary(parse_quote!(!-return a), parse_quote!(+), parse_quote!(c));
+    assert_prints(&expr, "(! -return a) + c");
+    let expr = cast_u8(parse_quote!(-return a));
+    assert_prints(&expr, "(-return a) as u8");
+    let expr = cast_u8(parse_quote!(&|x| a));
+    assert_prints(&expr, "(& |x| a) as u8");
+    let expr = cast_u8(parse_quote!(|x| a));
+    assert_prints(&expr, "(|x| a) as u8");
+}
+
+fn assert_block_prints(stmts: Vec<Stmt>, expected: &str) {
+    let block = Block {
+        brace_token: Default::default(),
+        stmts,
+    };
+    let expected: TokenStream = expected.parse().unwrap();
+    assert_eq!(quote!(#block).to_string(), expected.to_string());
+
+    let reparsed: Block = syn::parse2(quote!(#block)).unwrap();
+    assert_eq!(reparsed.stmts.len(), block.stmts.len());
+    assert_eq!(quote!(#reparsed).to_string(), expected.to_string());
+}
+
+#[test]
+fn test_statement_position() {
+    // Without parentheses, the statement would end after the `match`.
+    let expr = binary(parse_quote!(match x {}), parse_quote!(-), parse_quote!(b));
+    assert_block_prints(
+        vec![Stmt::Semi(expr, Default::default())],
+        "{ (match x {} - b); }",
+    );
+    let expr = binary(
+        parse_quote!(if a {
+        } else {
+        }),
+        parse_quote!(+),
+        parse_quote!(b),
+    );
+    let expr = binary(expr, parse_quote!(*), parse_quote!(c));
+    assert_block_prints(vec![Stmt::Expr(expr)], "{ (if a {} else {} + b) * c }");
+    let expr = cast_u8(parse_quote!({ a }));
+    assert_block_prints(vec![Stmt::Expr(expr)], "{ ({ a } as u8) }");
+
+    // A method call or `?` can follow the block in the same statement.
+    let block: Block = parse_quote!({
+        match x {}.f() - b;
+        loop {}? + c
+    });
+    assert_block_prints(block.stmts, "{ match x {}.f() - b; loop {}? + c }");
+}
+
+#[test]
+fn test_interpolation() {
+    let sum = binary(parse_quote!(a), parse_quote!(+), parse_quote!(b));
+    let expr: Expr = parse_quote!(#sum * c);
+    assert_prints(&expr, "(a + b) * c");
+    match &expr {
+        Expr::Binary(ExprBinary { left, .. }) => match &**left {
+            Expr::Group(group) => assert_eq!(*group.expr, sum),
+            _ => panic!("expected group"),
+        },
+        _ => panic!("expected binary expression"),
+    }
+
+    let expr: Expr = parse_quote!(-#sum);
+    assert_prints(&expr, "-(a + b)");
+    let neg: Expr = parse_quote!(-a);
+    let expr: Expr = parse_quote!(#neg.abs());
+    assert_prints(&expr, "(-a).abs()");
+    let closure: Expr = parse_quote!(|x| a);
+    let expr: Expr = parse_quote!(#closure + c);
+    assert_prints(&expr, "(|x| a) + c");
+
+    // Expressions that bind tightly enough are interpolated as they are.
+    let call: Expr = parse_quote!(f(a).b[c]?);
+    let expr: Expr = parse_quote!(#call);
+    assert_eq!(expr, call);
+}
+
+#[test]
+fn test_parsed_unchanged() {
+    let code = "a = b + c * d - (e - f) as u8 .. -g.h()? && |x| i < j";
+    let expected: TokenStream = code.parse().unwrap();
+    let expr: Expr = syn::parse2(expected.clone()).unwrap();
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+}
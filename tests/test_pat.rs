@@ -1,7 +1,8 @@
 mod features;
 
 use quote::quote;
-use syn::Pat;
+use syn::parse::Parser;
+use syn::{Item, Pat, Stmt};
 
 #[test]
 fn test_pat_ident() {
@@ -18,3 +19,66 @@ fn test_pat_path() {
         value => panic!("expected PatPath, got {:?}", value),
     }
 }
+
+#[test]
+fn test_leading_vert() {
+    // A leading `|` is accepted only at the top level of a `let`, `match`
+    // arm or `for` loop pattern.
+    syn::parse_str::<Item>("fn fun1(| A: E) {}").unwrap_err();
+    syn::parse_str::<Item>("fn fun2(|| A: E) {}").unwrap_err();
+
+    syn::parse_str::<Stmt>("let | () = ();").unwrap();
+    syn::parse_str::<Stmt>("let (| A): E;").unwrap_err();
+    syn::parse_str::<Stmt>("let [| A]: [E; 1];").unwrap_err();
+    syn::parse_str::<Stmt>("let TS(| A): TS;").unwrap_err();
+    syn::parse_str::<Stmt>("let NS { f: | A }: NS;").unwrap_err();
+}
+
+#[test]
+fn test_parse_single() {
+    let pat = Pat::parse_single.parse_str("Some(x)").unwrap();
+    match pat {
+        Pat::TupleStruct(_) => (),
+        value => panic!("expected PatTupleStruct, got {:?}", value),
+    }
+    Pat::parse_single.parse_str("A | B").unwrap_err();
+    Pat::parse_single.parse_str("| A").unwrap_err();
+}
+
+#[test]
+fn test_parse_multi() {
+    match Pat::parse_multi.parse_str("A").unwrap() {
+        Pat::Ident(_) => (),
+        value => panic!("expected PatIdent, got {:?}", value),
+    }
+    match Pat::parse_multi.parse_str("Some(0) | None").unwrap() {
+        Pat::Or(pat) => {
+            assert!(pat.leading_vert.is_none());
+            assert_eq!(pat.cases.len(), 2);
+        }
+        value => panic!("expected PatOr, got {:?}", value),
+    }
+    Pat::parse_multi.parse_str("| A | B").unwrap_err();
+}
+
+#[test]
+fn test_parse_multi_with_leading_vert() {
+    match Pat::parse_multi_with_leading_vert.parse_str("| A").unwrap() {
+        Pat::Or(pat) => {
+            assert!(pat.leading_vert.is_some());
+            assert_eq!(pat.cases.len(), 1);
+        }
+        value => panic!("expected PatOr, got {:?}", value),
+    }
+    match Pat::parse_multi_with_leading_vert
+        .parse_str("| A | B | C")
+        .unwrap()
+    {
+        Pat::Or(pat) => assert_eq!(pat.cases.len(), 3),
+        value => panic!("expected PatOr, got {:?}", value),
+    }
+    match Pat::parse_multi_with_leading_vert.parse_str("A").unwrap() {
+        Pat::Ident(_) => (),
+        value => panic!("expected PatIdent, got {:?}", value),
+    }
+}
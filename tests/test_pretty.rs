@@ -0,0 +1,125 @@
+mod features;
+
+use quote::ToTokens;
+use std::fs;
+use syn::{Expr, File, Item};
+use walkdir::WalkDir;
+
+fn pretty_item(input: &str) -> String {
+    let item: Item = syn::parse_str(input).unwrap();
+    syn::pretty::to_string(&item)
+}
+
+#[test]
+fn test_struct() {
+    let input = "#[derive(Debug)] pub struct Point<T> where T: Copy { x: T, pub y: T }";
+    let expected = "\
+#[derive(Debug)]
+pub struct Point<T>
+where
+    T: Copy,
+{
+    x: T,
+    pub y: T,
+}
+";
+    assert_eq!(pretty_item(input), expected);
+}
+
+#[test]
+fn test_enum() {
+    let input = "enum E { A, B(u8, String), C { x: i32 }, D = 4 }";
+    let expected = "\
+enum E {
+    A,
+    B(u8, String),
+    C { x: i32 },
+    D = 4,
+}
+";
+    assert_eq!(pretty_item(input), expected);
+}
+
+#[test]
+fn test_fn() {
+    let input = "/// Docs.\nfn f(a: u8) -> u8 { let b = a + 1; if b > 2 { b } else { 0 } }";
+    let expected = "\
+/// Docs.
+fn f(a: u8) -> u8 {
+    let b = a + 1;
+    if b > 2 {
+        b
+    } else {
+        0
+    }
+}
+";
+    assert_eq!(pretty_item(input), expected);
+}
+
+#[test]
+fn test_expr() {
+    let expr: Expr = syn::parse_str("match x { Some ( y ) => y , None => { 0 } }").unwrap();
+    let expected = "\
+match x {
+    Some(y) => y,
+    None => {
+        0
+    }
+}";
+    assert_eq!(syn::pretty::to_string(&expr), expected);
+}
+
+#[test]
+fn test_long_call_breaks() {
+    let input = "fn f() { call(aaaaaaaaaaaaaaaaaaaa, bbbbbbbbbbbbbbbbbbbbbbbbb, cccccccccccccccccccccccc, dddddddddddddddddd); }";
+    let expected = "\
+fn f() {
+    call(
+        aaaaaaaaaaaaaaaaaaaa,
+        bbbbbbbbbbbbbbbbbbbbbbbbb,
+        cccccccccccccccccccccccc,
+        dddddddddddddddddd,
+    );
+}
+";
+    assert_eq!(pretty_item(input), expected);
+}
+
+// Every source file of this crate must print to something that parses back to
+// the same syntax tree.
+#[test]
+fn test_round_trip_own_source() {
+    for entry in WalkDir::new("src") {
+        let entry = entry.unwrap();
+        let path = entry.path();
+        if path.extension().map_or(true, |ext| ext != "rs") {
+            continue;
+        }
+        let content = fs::read_to_string(path).unwrap();
+        let file: File = syn::parse_file(&content).unwrap();
+        let pretty = syn::pretty::unparse(&file);
+        let reparsed = match syn::parse_file(&pretty) {
+            Ok(reparsed) => reparsed,
+            Err(err) => panic!("failed to reparse {}: {}", path.display(), err),
+        };
+        assert_eq!(
+            normalize(file.into_token_stream().to_string()),
+            normalize(reparsed.into_token_stream().to_string()),
+            "{}",
+            path.display(),
+        );
+    }
+}
+
+// The printer adds trailing commas to broken lists; ignore them.
+fn normalize(tokens: String) -> String {
+    tokens
+        .replace(" , }", " }")
+        .replace(" , )", " )")
+        .replace(" , ]", " ]")
+        .replace(" , {", " {")
+        .replace(" , ;", " ;")
+        .replace(" ,)", ")")
+        .replace(" ,]", "]")
+}
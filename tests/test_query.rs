@@ -0,0 +1,181 @@
+mod features;
+
+use quote::ToTokens;
+use syn::query::{Binding, Match, Pattern};
+use syn::{Block, Expr, File, ImplItem, Item, Stmt};
+
+fn binding(found: &Match<impl ToTokens>, name: &str) -> String {
+    match found.get(name) {
+        Some(Binding::Expr(expr)) => expr.to_token_stream().to_string(),
+        Some(Binding::Type(ty)) => ty.to_token_stream().to_string(),
+        Some(Binding::Pat(pat)) => pat.to_token_stream().to_string(),
+        Some(Binding::Ident(ident)) => ident.to_string(),
+        None => panic!("${} is not bound", name),
+    }
+}
+
+#[test]
+fn test_method_chain() {
+    let file: File = syn::parse_str(
+        "
+        fn f() {
+            let a = x.unwrap();
+            let b = x.y().unwrap().z().unwrap();
+            let c = unwrap(x);
+            let d = x.unwrap_or(0);
+        }
+        ",
+    )
+    .unwrap();
+
+    let pattern: Pattern<Expr> = Pattern::parse("$x.unwrap()").unwrap();
+    let found: Vec<String> = pattern
+        .find(&file)
+        .iter()
+        .map(|found| binding(found, "x"))
+        .collect();
+    assert_eq!(found, ["x", "x . y () . unwrap () . z ()", "x . y ()"]);
+}
+
+#[test]
+fn test_impl_drop() {
+    let file: File = syn::parse_str(
+        "
+        impl Drop for A { fn drop(&mut self) {} }
+        impl<T> Drop for B<T> { fn drop(&mut self) { free(self) } }
+        impl Clone for C { fn clone(&self) -> Self { C } }
+        impl Drop for D {}
+        ",
+    )
+    .unwrap();
+
+    let pattern: Pattern<Item> =
+        Pattern::parse("impl Drop for $t { fn drop(&mut self) {} }").unwrap();
+    let found = pattern.find(&file);
+    assert_eq!(found.len(), 1);
+    assert_eq!(binding(&found[0], "t"), "A");
+    match found[0].node() {
+        Item::Impl(item) => assert!(item.generics.params.is_empty()),
+        _ => panic!("expected impl"),
+    }
+
+    let pattern: Pattern<Item> = Pattern::parse("impl<$..> Drop for $t { $.. }").unwrap();
+    let found: Vec<String> = pattern
+        .find(&file)
+        .iter()
+        .map(|found| binding(found, "t"))
+        .collect();
+    assert_eq!(found, ["A", "B < T >", "D"]);
+}
+
+#[test]
+fn test_wildcards() {
+    let file: File = syn::parse_str(
+        "
+        impl<T> Drop for A<T> where T: Copy { fn drop(&mut self) { free(self); } }
+        impl Drop for B { fn drop(&mut self) {} }
+        impl Drop for C { fn size() {} fn drop(&mut self) { let x = 1; } }
+        impl Drop for D { fn drop(&mut self, x: u8) {} }
+        mod m { fn drop(&mut self) { free(self); } }
+        ",
+    )
+    .unwrap();
+
+    let pattern: Pattern<ImplItem> = Pattern::parse("fn drop(&mut self) { $.. }").unwrap();
+    assert_eq!(pattern.find(&file).len(), 3);
+
+    let pattern: Pattern<Item> =
+        Pattern::parse("impl<$..> Drop for $t { $.. fn drop(&mut self) { $.. } }").unwrap();
+    let found: Vec<String> = pattern
+        .find(&file)
+        .iter()
+        .map(|found| binding(found, "t"))
+        .collect();
+    assert_eq!(found, ["A < T >", "B", "C"]);
+
+    // Statements around the wildcard still have to match.
+    let pattern: Pattern<Block> = Pattern::parse("{ $.. free($x); }").unwrap();
+    let found: Vec<String> = pattern
+        .find(&file)
+        .iter()
+        .map(|found| binding(found, "x"))
+        .collect();
+    assert_eq!(found, ["self", "self"]);
+
+    let pattern: Pattern<Item> = Pattern::parse("mod $m { $.. }").unwrap();
+    assert_eq!(pattern.find(&file).len(), 1);
+
+    let err = Pattern::<Expr>::parse("$.x").unwrap_err();
+    assert_eq!(err.to_string(), "expected `$..`");
+}
+
+#[test]
+fn test_repeated_metavariable() {
+    let pattern: Pattern<Expr> = Pattern::parse("$a == $a").unwrap();
+
+    let same: Expr = syn::parse_str("v.len() == v.len()").unwrap();
+    let found = pattern.matches(&same).unwrap();
+    assert_eq!(binding(&found, "a"), "v . len ()");
+
+    let different: Expr = syn::parse_str("v.len() == w.len()").unwrap();
+    assert!(pattern.matches(&different).is_none());
+}
+
+#[test]
+fn test_precedence() {
+    // Structurally `a + (b.unwrap())`, so the receiver is only `b`.
+    let pattern: Pattern<Expr> = Pattern::parse("$x.unwrap()").unwrap();
+    let expr: Expr = syn::parse_str("a + b.unwrap()").unwrap();
+    assert!(pattern.matches(&expr).is_none());
+    let found = pattern.find(&expr);
+    assert_eq!(found.len(), 1);
+    assert_eq!(binding(&found[0], "x"), "b");
+}
+
+#[test]
+fn test_binding_kinds() {
+    let pattern: Pattern<Stmt> = Pattern::parse("let $p: $t = $f($e);").unwrap();
+    let stmt: Stmt = syn::parse_str("let (a, b): (u8, u8) = split(0xff);").unwrap();
+    let found = pattern.matches(&stmt).unwrap();
+    assert_eq!(binding(&found, "p"), "(a , b)");
+    assert_eq!(binding(&found, "t"), "(u8 , u8)");
+    assert_eq!(binding(&found, "f"), "split");
+    assert_eq!(binding(&found, "e"), "0xff");
+
+    let names: Vec<&str> = found
+        .bindings()
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect();
+    assert_eq!(names, ["p", "t", "f", "e"]);
+
+    let pattern: Pattern<Item> = Pattern::parse("fn $name() {}").unwrap();
+    let item: Item = syn::parse_str("fn empty() {}").unwrap();
+    let found = pattern.matches(&item).unwrap();
+    match found.get("name") {
+        Some(Binding::Ident(ident)) => assert_eq!(ident, "empty"),
+        _ => panic!("expected ident binding"),
+    }
+}
+
+#[test]
+fn test_literals_and_operators() {
+    let pattern: Pattern<Expr> = Pattern::parse("$x + 1").unwrap();
+    for (source, matches) in &[
+        ("y + 1", true),
+        ("y - 1", false),
+        ("y + 2", false),
+        ("y + 1u8", false),
+        ("(y) + 1", true),
+    ] {
+        let expr: Expr = syn::parse_str(source).unwrap();
+        assert_eq!(pattern.matches(&expr).is_some(), *matches, "{}", source);
+    }
+}
+
+#[test]
+fn test_invalid_pattern() {
+    let err = Pattern::<Expr>::parse("$ + 1").unwrap_err();
+    assert_eq!(err.to_string(), "expected metavariable name after `$`");
+    assert!(Pattern::<Item>::parse("$x.unwrap()").is_err());
+}
@@ -0,0 +1,146 @@
+mod features;
+
+use quote::ToTokens;
+use syn::{Block, Expr, ImplItem, Item, Stmt};
+
+fn messages(errors: Option<syn::Error>) -> Vec<String> {
+    errors
+        .into_iter()
+        .flat_map(|errors| errors.into_iter().map(|err| err.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_items() {
+    let content = "
+        struct A;
+        struct B { x: }
+        #[inline]
+        fn f() -> u8 { 0 }
+        impl C { fn g(&self) { } const : u8 = 1; }
+        enum D { X Y }
+        use e::{f g};
+        struct G;
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    let items: Vec<String> = file
+        .items
+        .iter()
+        .map(|item| match item {
+            Item::Verbatim(tokens) => format!("verbatim: {}", tokens),
+            item => item.to_token_stream().to_string(),
+        })
+        .collect();
+    assert_eq!(
+        items,
+        [
+            "struct A ;",
+            "verbatim: struct B { x : }",
+            "# [inline] fn f () -> u8 { 0 }",
+            "impl C { fn g (& self) { } const : u8 = 1 ; }",
+            "verbatim: enum D { X Y }",
+            "verbatim: use e :: { f g } ;",
+            "struct G ;",
+        ],
+    );
+
+    match &file.items[3] {
+        Item::Impl(item) => match &item.items[1] {
+            ImplItem::Verbatim(tokens) => assert_eq!(tokens.to_string(), "const : u8 = 1 ;"),
+            _ => panic!("expected verbatim impl item"),
+        },
+        _ => panic!("expected impl"),
+    }
+
+    assert_eq!(messages(errors).len(), 4);
+}
+
+#[test]
+fn test_item_without_terminator() {
+    let content = "struct S fn f() {}";
+    let (file, errors) = syn::parse_file_recovering(content);
+    assert_eq!(file.items.len(), 2);
+    match &file.items[0] {
+        Item::Verbatim(tokens) => assert_eq!(tokens.to_string(), "struct S"),
+        _ => panic!("expected verbatim item"),
+    }
+    match &file.items[1] {
+        Item::Fn(item) => assert_eq!(item.sig.ident, "f"),
+        _ => panic!("expected fn"),
+    }
+    assert_eq!(messages(errors).len(), 1);
+}
+
+#[test]
+fn test_stmts() {
+    let code = "{
+        let a: fn() -> u8 = ;
+        if a { b } else c
+        let d = 1;
+        e(d f);
+        d
+    }";
+    let (block, errors) = syn::parse_str_recovering::<Block>(code).unwrap();
+    let stmts: Vec<String> = block
+        .stmts
+        .iter()
+        .map(|stmt| match stmt {
+            Stmt::Semi(Expr::Verbatim(tokens), _) => format!("verbatim: {} ;", tokens),
+            Stmt::Expr(Expr::Verbatim(tokens)) => format!("verbatim: {}", tokens),
+            stmt => stmt.to_token_stream().to_string(),
+        })
+        .collect();
+    assert_eq!(
+        stmts,
+        [
+            "verbatim: let a : fn () -> u8 = ;",
+            "verbatim: if a { b } else c",
+            "let d = 1 ;",
+            "verbatim: e (d f) ;",
+            "d",
+        ],
+    );
+    assert_eq!(messages(errors).len(), 3);
+}
+
+#[test]
+fn test_nested() {
+    let content = "
+        mod m {
+            fn f() {
+                let x = 1 +;
+                x
+            }
+        }
+    ";
+    let (file, errors) = syn::parse_file_recovering(content);
+    let messages = messages(errors);
+    assert_eq!(messages, ["expected expression"]);
+    let body = match &file.items[0] {
+        Item::Mod(item) => match &item.content.as_ref().unwrap().1[0] {
+            Item::Fn(item) => &item.block.stmts,
+            _ => panic!("expected fn"),
+        },
+        _ => panic!("expected mod"),
+    };
+    assert_eq!(body.len(), 2);
+}
+
+#[test]
+fn test_speculative_parse_unaffected() {
+    // Without recovery these parse and fail exactly as before.
+    assert!(syn::parse_str::<Block>("{ let x = ; }").is_err());
+    let (block, errors) = syn::parse_str_recovering::<Block>("{ x }").unwrap();
+    assert!(errors.is_none());
+    assert_eq!(block.stmts.len(), 1);
+}
+
+#[test]
+fn test_unrecoverable() {
+    let (file, errors) = syn::parse_file_recovering("fn f() { (] }");
+    assert!(file.items.is_empty());
+    assert_eq!(messages(errors).len(), 1);
+
+    let err = syn::parse_str_recovering::<Block>("{ let x = ; } extra").unwrap_err();
+    assert_eq!(err.into_iter().count(), 2);
+}
@@ -0,0 +1,162 @@
+mod features;
+
+use syn::eval::{Evaluator, IntType};
+use syn::repr::Repr;
+use syn::{Data, DeriveInput, Expr, Ident, Type};
+
+fn input(input: &str) -> DeriveInput {
+    syn::parse_str(input).unwrap()
+}
+
+fn discriminants(input: &DeriveInput) -> Vec<i128> {
+    input
+        .discriminants()
+        .unwrap()
+        .iter()
+        .map(|discriminant| discriminant.to_i128().unwrap())
+        .collect()
+}
+
+fn repr_error(attrs: &str) -> String {
+    let input = input(&format!("{} struct S;", attrs));
+    input.repr().unwrap_err().to_string()
+}
+
+#[test]
+fn test_repr() {
+    assert_eq!(input("struct S;").repr().unwrap(), Repr::default());
+
+    let repr = input("#[repr(C, packed)] #[derive(Copy)] #[repr(C)] struct S;")
+        .repr()
+        .unwrap();
+    assert_eq!(
+        repr,
+        Repr {
+            c: true,
+            packed: Some(1),
+            ..Repr::default()
+        },
+    );
+
+    let repr = input("#[repr(align(16), u16)] enum E { A }")
+        .repr()
+        .unwrap();
+    assert_eq!(repr.align, Some(16));
+    assert_eq!(repr.int, Some(IntType::U16));
+    assert_eq!(repr.discriminant_type(), IntType::U16);
+    assert_eq!(Repr::default().discriminant_type(), IntType::Isize);
+
+    let repr = input("#[repr(transparent)] struct S(u8);").repr().unwrap();
+    assert!(repr.transparent);
+    let repr = input("#[repr(packed(4))] struct S(u8);").repr().unwrap();
+    assert_eq!(repr.packed, Some(4));
+}
+
+#[test]
+fn test_repr_errors() {
+    assert_eq!(
+        repr_error("#[repr(u8, i32)]"),
+        "conflicting representation hints"
+    );
+    assert_eq!(
+        repr_error("#[repr(transparent, C)]"),
+        "`repr(transparent)` cannot be combined with other representation hints",
+    );
+    assert_eq!(
+        repr_error("#[repr(packed, align(8))]"),
+        "conflicting `packed` and `align` representation hints",
+    );
+    assert_eq!(
+        repr_error("#[repr(align(3))]"),
+        "invalid `repr(align)` attribute: not a power of two",
+    );
+    assert_eq!(
+        repr_error("#[repr(align(x))]"),
+        "`align` expects a literal integer as argument",
+    );
+    assert_eq!(
+        repr_error("#[repr(simd)]"),
+        "unrecognized representation hint"
+    );
+    assert_eq!(
+        repr_error("#[repr = \"C\"]"),
+        "expected a list of hints: `#[repr(...)]`",
+    );
+
+    let err = input("#[repr(simd, u8, i8)] struct S;").repr().unwrap_err();
+    assert_eq!(err.into_iter().count(), 2);
+}
+
+#[test]
+fn test_discriminants() {
+    let e = input("enum E { A, B, C }");
+    assert_eq!(discriminants(&e), [0, 1, 2]);
+
+    let e = input("enum E { A = -2, B, C = 1 << 3, D }");
+    assert_eq!(discriminants(&e), [-2, -1, 8, 9]);
+    assert_eq!(e.discriminants().unwrap()[0].ty(), IntType::Isize);
+
+    let e = input("#[repr(i8)] enum E { A = -128, B = 0x7f }");
+    assert_eq!(discriminants(&e), [-128, 127]);
+    assert_eq!(e.discriminants().unwrap()[1].ty(), IntType::I8);
+
+    let e = input("#[repr(u8)] enum E { A = b'a', B = 'b' as u8, C = (2 + 3) * 4 }");
+    assert_eq!(discriminants(&e), [97, 98, 20]);
+}
+
+#[test]
+fn test_discriminants_with_consts() {
+    let ident: Ident = syn::parse_str("BASE").unwrap();
+    let ty: Type = syn::parse_str("u32").unwrap();
+    let expr: Expr = syn::parse_str("100").unwrap();
+    let mut evaluator = Evaluator::new();
+    evaluator.define(&ident, &ty, &expr);
+
+    let e = input("#[repr(u32)] enum E { A = BASE, B, C = BASE * 2 }");
+    let data = match &e.data {
+        Data::Enum(data) => data,
+        _ => unreachable!(),
+    };
+    let ty = e.repr().unwrap().discriminant_type();
+    let values: Vec<u128> = data
+        .discriminants_with(ty, &evaluator)
+        .unwrap()
+        .iter()
+        .map(|discriminant| discriminant.to_u128().unwrap())
+        .collect();
+    assert_eq!(values, [100, 101, 200]);
+
+    let err = data.discriminants(ty).unwrap_err();
+    assert_eq!(err.to_string(), "cannot find constant `BASE` in this scope");
+}
+
+#[test]
+fn test_discriminant_errors() {
+    let err = input("#[repr(u8)] enum E { A = 255, B }")
+        .discriminants()
+        .unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "enum discriminant overflowed: `B` overflows after `255`",
+    );
+
+    let err = input("#[repr(u8)] enum E { A = 256 }")
+        .discriminants()
+        .unwrap_err();
+    assert_eq!(err.to_string(), "literal out of range for `u8`");
+
+    let err = input("enum E { A = 1, B = 0, C, D = f() }")
+        .discriminants()
+        .unwrap_err();
+    let messages: Vec<String> = err.into_iter().map(|err| err.to_string()).collect();
+    assert_eq!(
+        messages,
+        [
+            "discriminant value `1` assigned more than once",
+            "expression cannot be evaluated at compile time",
+        ],
+    );
+
+    let err = input("struct S;").discriminants().unwrap_err();
+    assert_eq!(err.to_string(), "only enums have discriminants");
+}
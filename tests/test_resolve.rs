@@ -0,0 +1,232 @@
+mod features;
+
+use quote::quote;
+use syn::resolve::{Resolution, Resolver};
+use syn::visit::{self, Visit};
+use syn::{File, Path};
+
+/// Resolves the `nth` occurrence of a path written as `needle`.
+fn resolve(file: &File, needle: &str, nth: usize) -> String {
+    struct Paths<'ast>(Vec<&'ast Path>);
+
+    impl<'ast> Visit<'ast> for Paths<'ast> {
+        fn visit_path(&mut self, node: &'ast Path) {
+            self.0.push(node);
+            visit::visit_path(self, node);
+        }
+    }
+
+    let mut paths = Paths(Vec::new());
+    paths.visit_file(file);
+    let path = paths
+        .0
+        .into_iter()
+        .filter(|path| quote!(#path).to_string() == needle)
+        .nth(nth)
+        .unwrap_or_else(|| panic!("no path {} #{}", needle, nth));
+
+    match Resolver::new(file).resolve(path) {
+        Some(Resolution::Local(ident)) => {
+            let start = ident.span().start();
+            format!("local {}:{}", start.line, start.column)
+        }
+        Some(Resolution::Generic(ident)) => {
+            let start = ident.span().start();
+            format!("generic {}:{}", start.line, start.column)
+        }
+        Some(Resolution::Item(path)) => quote!(#path).to_string(),
+        Some(Resolution::Primitive) => "primitive".to_owned(),
+        None => "none".to_owned(),
+    }
+}
+
+#[test]
+fn test_result() {
+    let file: File = syn::parse_str(
+        "
+mod a {
+    pub type Result<T> = std::result::Result<T, ()>;
+    fn f() -> Result<u8> { Ok(0) }
+}
+mod b {
+    fn f() -> Result<u8, ()> { Ok(0) }
+}
+mod c {
+    use super::a::Result;
+    fn f() -> Result<u8> { Ok(0) }
+}
+mod d {
+    use std::io::*;
+    fn f() -> Result<u8> { Ok(0) }
+}
+",
+    )
+    .unwrap();
+
+    assert_eq!(
+        resolve(&file, "Result < u8 >", 0),
+        "crate :: a :: Result < u8 >"
+    );
+    assert_eq!(
+        resolve(&file, "Result < u8 , () >", 0),
+        ":: std :: result :: Result < u8 , () >",
+    );
+    assert_eq!(
+        resolve(&file, "Result < u8 >", 1),
+        "crate :: a :: Result < u8 >"
+    );
+    assert_eq!(resolve(&file, "Result < u8 >", 2), "none");
+    assert_eq!(
+        resolve(&file, "std :: result :: Result < T , () >", 0),
+        ":: std :: result :: Result < T , () >",
+    );
+    assert_eq!(resolve(&file, "Ok", 0), ":: std :: result :: Result :: Ok");
+}
+
+#[test]
+fn test_locals() {
+    let file: File = syn::parse_str(
+        "
+fn f(x: u8, y: Option<u8>) -> u8 {
+    let z = x;
+    let x = z + 1;
+    match y {
+        Some(y) => x + y,
+        None => {
+            let w = |x| x;
+            w(x)
+        }
+    }
+}
+",
+    )
+    .unwrap();
+
+    assert_eq!(resolve(&file, "x", 0), "local 2:5");
+    assert_eq!(resolve(&file, "z", 0), "local 3:8");
+    assert_eq!(resolve(&file, "y", 0), "local 2:12");
+    assert_eq!(resolve(&file, "x", 1), "local 4:8");
+    assert_eq!(resolve(&file, "y", 1), "local 6:13");
+    assert_eq!(resolve(&file, "x", 2), "local 8:21");
+    assert_eq!(resolve(&file, "x", 3), "local 4:8");
+    assert_eq!(
+        resolve(&file, "Option < u8 >", 0),
+        ":: std :: option :: Option < u8 >",
+    );
+    assert_eq!(resolve(&file, "u8", 0), "primitive");
+}
+
+#[test]
+fn test_namespaces() {
+    let file: File = syn::parse_str(
+        "
+struct Unit;
+struct Named { x: u8 }
+fn f<T: Clone, const N: usize>(t: T) {
+    let Named = 1;
+    let Unit = Unit;
+    let _: Named = Named { x: N };
+    Named;
+    Unit;
+    t.clone();
+    T::default();
+    Self::new();
+}
+macro_rules! m { () => {} }
+fn g() { m!(); println!(); }
+",
+    )
+    .unwrap();
+
+    assert_eq!(resolve(&file, "Named", 0), "crate :: Named");
+    assert_eq!(resolve(&file, "Named", 1), "crate :: Named");
+    assert_eq!(resolve(&file, "Named", 2), "local 5:8");
+    assert_eq!(resolve(&file, "Unit", 0), "crate :: Unit");
+    assert_eq!(resolve(&file, "Unit", 1), "crate :: Unit");
+    assert_eq!(resolve(&file, "N", 0), "generic 4:21");
+    assert_eq!(resolve(&file, "T", 0), "generic 4:5");
+    assert_eq!(resolve(&file, "t", 0), "local 4:31");
+    assert_eq!(resolve(&file, "Clone", 0), ":: std :: clone :: Clone");
+    assert_eq!(resolve(&file, "T :: default", 0), "none");
+    assert_eq!(resolve(&file, "Self :: new", 0), "none");
+    assert_eq!(resolve(&file, "m", 0), "crate :: m");
+    assert_eq!(resolve(&file, "println", 0), ":: std :: println");
+}
+
+#[test]
+fn test_modules() {
+    let file: File = syn::parse_str(
+        "
+extern crate serde as s;
+use self::outer::inner::{self as renamed, Deep};
+mod outer {
+    pub mod inner {
+        pub struct Deep;
+        pub use super::Sibling as Alias;
+    }
+    pub struct Sibling;
+    pub use self::inner::*;
+    fn f() {
+        super::outer::Sibling;
+        crate::outer::inner::Alias;
+        Deep;
+        s::Serialize;
+        regex::Regex::new;
+        ::std::mem::swap;
+        other::Thing;
+    }
+    mod other;
+}
+struct S;
+impl S {
+    fn new() -> Self {
+        Self::helper();
+        Self
+    }
+}
+enum E { A, B }
+fn variants() {
+    E::A;
+    renamed::Deep;
+}
+",
+    )
+    .unwrap();
+
+    assert_eq!(
+        resolve(&file, "super :: outer :: Sibling", 0),
+        "crate :: outer :: Sibling",
+    );
+    assert_eq!(
+        resolve(&file, "crate :: outer :: inner :: Alias", 0),
+        "crate :: outer :: Sibling",
+    );
+    assert_eq!(resolve(&file, "Deep", 0), "crate :: outer :: inner :: Deep");
+    assert_eq!(
+        resolve(&file, "renamed :: Deep", 0),
+        "crate :: outer :: inner :: Deep",
+    );
+    assert_eq!(resolve(&file, "s :: Serialize", 0), ":: serde :: Serialize");
+    assert_eq!(
+        resolve(&file, "regex :: Regex :: new", 0),
+        ":: regex :: Regex :: new",
+    );
+    assert_eq!(
+        resolve(&file, ":: std :: mem :: swap", 0),
+        ":: std :: mem :: swap",
+    );
+    assert_eq!(
+        resolve(&file, "other :: Thing", 0),
+        "crate :: outer :: other :: Thing",
+    );
+    assert_eq!(resolve(&file, "Self :: helper", 0), "crate :: S :: helper");
+    assert_eq!(resolve(&file, "Self", 1), "crate :: S");
+    assert_eq!(resolve(&file, "E :: A", 0), "crate :: E :: A");
+}
+
+#[test]
+fn test_foreign_path() {
+    let file: File = syn::parse_str("fn f() {}").unwrap();
+    let path: Path = syn::parse_str("f").unwrap();
+    assert!(Resolver::new(&file).resolve(&path).is_none());
+}
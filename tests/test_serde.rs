@@ -0,0 +1,66 @@
+mod features;
+
+use quote::ToTokens;
+use syn::{Expr, File, Item, Lit, Pat};
+
+fn round_trip<T>(input: &str) -> String
+where
+    T: syn::parse::Parse + serde::Serialize + serde::de::DeserializeOwned + ToTokens,
+{
+    let node: T = syn::parse_str(input).unwrap();
+    let json = serde_json::to_string(&node).unwrap();
+    let back: T = serde_json::from_str(&json).unwrap();
+    assert_eq!(
+        node.into_token_stream().to_string(),
+        back.into_token_stream().to_string(),
+    );
+    json
+}
+
+#[test]
+fn test_struct() {
+    let json = round_trip::<Item>("#[derive(Debug)] pub struct S<'a, T: 'a> { x: &'a T }");
+    assert!(json.starts_with(r##"{"Struct":{"attrs":[{"pound_token":"#""##));
+}
+
+#[test]
+fn test_token() {
+    let json = round_trip::<Expr>("a + b");
+    assert_eq!(
+        json,
+        r#"{"Binary":{"attrs":[],"left":{"Path":{"attrs":[],"qself":null,"path":{"leading_colon":null,"segments":[{"ident":"a","arguments":"None"}]}}},"op":{"Add":"+"},"right":{"Path":{"attrs":[],"qself":null,"path":{"leading_colon":null,"segments":[{"ident":"b","arguments":"None"}]}}}}}"#,
+    );
+}
+
+#[test]
+fn test_lit() {
+    let lit: Lit = syn::parse_str(r#"b"\x00bytes""#).unwrap();
+    let json = serde_json::to_string(&lit).unwrap();
+    assert_eq!(json, r#"{"ByteStr":"b\"\\x00bytes\""}"#);
+    let back: Lit = serde_json::from_str(&json).unwrap();
+    assert_eq!(lit, back);
+}
+
+#[test]
+fn test_trailing_punct() {
+    // `(a,)` is a one element tuple pattern while `(a)` is not.
+    let json = round_trip::<Pat>("(a,)");
+    assert!(json.contains(r#"[{"Ident""#));
+    assert!(json.contains(r#"},","]"#));
+}
+
+#[test]
+fn test_wrong_token() {
+    let json = round_trip::<Expr>("a + b").replace(r#""+""#, r#""-""#);
+    let err = serde_json::from_str::<Expr>(&json).unwrap_err();
+    assert!(err.to_string().contains("invalid value"), "{}", err);
+}
+
+#[test]
+fn test_file() {
+    let content = include_str!("../src/gen_helper.rs");
+    let file: File = syn::parse_file(content).unwrap();
+    let json = serde_json::to_string(&file).unwrap();
+    let back: File = serde_json::from_str(&json).unwrap();
+    assert_eq!(file, back);
+}
@@ -0,0 +1,57 @@
+mod features;
+
+use proc_macro2::Span;
+use syn::source_map::SourceMap;
+use syn::{Expr, Item, Stmt};
+
+#[test]
+fn test_positions() {
+    let source = "fn f() {\n    let x = 1;\n    x + \"é\".len()\n}\n";
+    let (item, map) = SourceMap::parse_str::<Item>(source).unwrap();
+    let block = match &item {
+        Item::Fn(item) => &item.block,
+        _ => panic!("expected fn"),
+    };
+
+    assert_eq!(map.source_text(&item), Some(source.trim_end()));
+    assert_eq!(map.byte_range(&block.stmts[0]), Some(13..23));
+
+    let expr = match &block.stmts[1] {
+        Stmt::Expr(Expr::Binary(expr)) => expr,
+        _ => panic!("expected binary expression"),
+    };
+    assert_eq!(map.source_text(&expr.right), Some("\"é\".len()"));
+    let start = map.start(&expr.right).unwrap();
+    assert_eq!((start.line, start.column), (3, 8));
+    let end = map.end(&expr.right).unwrap();
+    assert_eq!((end.line, end.column), (3, 17));
+}
+
+#[test]
+fn test_file_prelude() {
+    let source = "\u{feff}#!/usr/bin/env run-cargo-script\nstruct S;\n";
+    let (file, map) = SourceMap::parse_file(source).unwrap();
+    assert_eq!(
+        file.shebang.as_ref().unwrap(),
+        "#!/usr/bin/env run-cargo-script"
+    );
+    assert_eq!(map.source_text(&file.items[0]), Some("struct S;"));
+    let start = map.start(&file.items[0]).unwrap();
+    assert_eq!((start.line, start.column), (2, 0));
+}
+
+#[test]
+fn test_foreign_span() {
+    let (expr, map) = SourceMap::parse_str::<Expr>("a + b").unwrap();
+    let (other, _) = SourceMap::parse_str::<Expr>("a + b").unwrap();
+    assert_eq!(map.byte_range(&expr), Some(0..5));
+    assert_eq!(map.byte_range(&other), None);
+    assert_eq!(map.byte_range(&Span::call_site()), None);
+}
+
+#[test]
+fn test_error_position() {
+    let err = SourceMap::parse_str::<Expr>("a +\n    ;").err().unwrap();
+    let start = err.span().start();
+    assert_eq!((start.line, start.column), (2, 4));
+}
@@ -0,0 +1,160 @@
+mod features;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::structure::{BindStyle, Structure};
+use syn::{parse_quote, DeriveInput};
+
+fn assert_tokens(actual: TokenStream, expected: TokenStream) {
+    assert_eq!(actual.to_string(), expected.to_string());
+}
+
+#[test]
+fn test_struct_each() {
+    let input: DeriveInput = parse_quote! {
+        struct Point {
+            x: i32,
+            y: i32,
+        }
+    };
+    let structure = Structure::new(&input).unwrap();
+    assert_eq!(structure.variants().len(), 1);
+
+    let expected = quote! {
+        match *self {
+            Point { x: ref __binding_0, y: ref __binding_1, } => {
+                walk(__binding_0);
+                walk(__binding_1);
+            }
+        }
+    };
+    assert_tokens(structure.each(|b| quote!(walk(#b))), expected);
+}
+
+#[test]
+fn test_enum_shapes() {
+    let input: DeriveInput = parse_quote! {
+        enum E {
+            Named { a: u8 },
+            Tuple(u8, u16),
+            Unit,
+        }
+    };
+    let mut structure = Structure::new(&input).unwrap();
+    structure.bind_with(BindStyle::RefMut);
+
+    let expected = quote! {
+        match *self {
+            E::Named { a: ref mut __binding_0, } => {
+                clear(__binding_0);
+            }
+            E::Tuple(ref mut __binding_0, ref mut __binding_1,) => {
+                clear(__binding_0);
+                clear(__binding_1);
+            }
+            E::Unit => {}
+        }
+    };
+    assert_tokens(structure.each(|b| quote!(clear(#b))), expected);
+
+    let body = structure.each(|b| quote!(clear(#b)));
+    syn::parse2::<syn::Expr>(body).unwrap();
+}
+
+#[test]
+fn test_move_fold() {
+    let input: DeriveInput = parse_quote! {
+        enum E {
+            A(u8, u8),
+            B,
+        }
+    };
+    let mut structure = Structure::new(&input).unwrap();
+    structure.bind_with(BindStyle::Move);
+
+    let expected = quote! {
+        match self {
+            E::A(__binding_0, __binding_1,) => {
+                Vec::new().push(__binding_0).push(__binding_1)
+            }
+            E::B => {
+                Vec::new()
+            }
+        }
+    };
+    let body = structure.fold(quote!(Vec::new()), |acc, b| quote!(#acc.push(#b)));
+    assert_tokens(body, expected);
+}
+
+#[test]
+fn test_filter() {
+    let input: DeriveInput = parse_quote! {
+        enum E {
+            Named {
+                a: u8,
+                #[skip]
+                b: u8,
+            },
+            Tuple(#[skip] u8, u8),
+        }
+    };
+    let mut structure = Structure::new(&input).unwrap();
+    structure.filter(|b| !b.field.attrs.iter().any(|attr| attr.path.is_ident("skip")));
+
+    let expected = quote! {
+        match *self {
+            E::Named { a: ref __binding_0, .. } => {
+                walk(__binding_0);
+            }
+            E::Tuple(_, ref __binding_1,) => {
+                walk(__binding_1);
+            }
+        }
+    };
+    assert_tokens(structure.each(|b| quote!(walk(#b))), expected);
+}
+
+#[test]
+fn test_each_variant_construct() {
+    let input: DeriveInput = parse_quote! {
+        enum E {
+            Named { a: u8, b: u8 },
+            Tuple(u8),
+            Unit,
+        }
+    };
+    let structure = Structure::new(&input).unwrap();
+
+    let expected = quote! {
+        match *self {
+            E::Named { a: ref __binding_0, b: ref __binding_1, } => {
+                E::Named { a: __binding_0.clone(), b: __binding_1.clone(), }
+            }
+            E::Tuple(ref __binding_0,) => {
+                E::Tuple(__binding_0.clone(),)
+            }
+            E::Unit => {
+                E::Unit
+            }
+        }
+    };
+    let body = structure.each_variant(|variant| {
+        let bindings = variant.bindings();
+        variant.construct(|_, i| {
+            let binding = &bindings[i];
+            quote!(#binding.clone())
+        })
+    });
+    assert_tokens(body, expected);
+}
+
+#[test]
+fn test_union() {
+    let input: DeriveInput = parse_quote! {
+        union U {
+            a: u8,
+        }
+    };
+    let err = Structure::new(&input).err().unwrap();
+    assert_eq!(err.to_string(), "unions are not supported");
+}
@@ -0,0 +1,185 @@
+mod features;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+use syn::subst::Substitution;
+use syn::{parse_quote, Expr, ImplItem, Item, Token, Type};
+
+fn assert_tokens<T: ToTokens>(node: &T, expected: TokenStream) {
+    assert_eq!(node.to_token_stream().to_string(), expected.to_string(),);
+}
+
+fn subst_t_u8() -> Substitution {
+    let mut subst = Substitution::new();
+    subst.insert_type(parse_quote!(T), parse_quote!(u8));
+    subst
+}
+
+#[test]
+fn test_type() {
+    let mut subst = Substitution::new();
+    subst.insert_type(parse_quote!(T), parse_quote!(Vec<u8>));
+    subst.insert_type(parse_quote!(U), parse_quote!(&'a str));
+    subst.insert_lifetime(parse_quote!('a), parse_quote!('b));
+
+    let mut ty: Type = parse_quote!(HashMap<T, &'a U>);
+    subst.apply(&mut ty);
+    assert_tokens(&ty, quote!(HashMap<Vec<u8>, &'b &'a str>));
+
+    let mut ty: Type = parse_quote!(T::Item);
+    subst.apply(&mut ty);
+    // Compared as a string, since `>>` would be a single token.
+    assert_eq!(ty.to_token_stream().to_string(), "< Vec < u8 > > :: Item");
+
+    let mut ty: Type = parse_quote!(Option<T::Item>);
+    subst_t_u8().apply(&mut ty);
+    assert_eq!(
+        ty.to_token_stream().to_string(),
+        "Option < < u8 > :: Item >"
+    );
+
+    let mut ty: Type = parse_quote!(U::Owned);
+    subst.apply(&mut ty);
+    assert_tokens(&ty, quote!(<&'a str>::Owned));
+
+    // Only a path that begins with the parameter refers to it.
+    let mut ty: Type = parse_quote!((::T, self::T, <T as Trait>::T));
+    subst.apply(&mut ty);
+    assert_tokens(&ty, quote!((::T, self::T, <Vec<u8> as Trait>::T)));
+}
+
+#[test]
+fn test_expr() {
+    let mut subst = subst_t_u8();
+    subst.insert_type(parse_quote!(V), parse_quote!([u8]));
+    subst.insert_const(parse_quote!(N), parse_quote!(2 + 2));
+
+    let mut expr: Expr = parse_quote!(T::max_value() as usize * N + V::len(x) + f::<T, N>(T::BITS));
+    subst.apply(&mut expr);
+    assert_tokens(
+        &expr,
+        quote!(u8::max_value() as usize * (2 + 2) + <[u8]>::len(x) + f::<u8, { 2 + 2 }>(u8::BITS)),
+    );
+
+    let mut subst = Substitution::new();
+    subst.insert_type(parse_quote!(T), parse_quote!(Vec<u8>));
+    let mut expr: Expr = parse_quote!(T::new());
+    subst.apply(&mut expr);
+    assert_tokens(&expr, quote!(Vec::<u8>::new()));
+}
+
+#[test]
+fn test_item() {
+    let mut item: Item = parse_quote! {
+        impl<'a, T: Clone + 'a, const N: usize> Trait<'a> for [T; N]
+        where
+            T: Default,
+        {
+            type Item = &'a T;
+
+            fn get(&self) -> Option<&'a T> {
+                let array: [T; N] = Default::default();
+                'a: loop {
+                    break 'a None;
+                }
+            }
+        }
+    };
+
+    let mut subst = subst_t_u8();
+    subst.insert_lifetime(parse_quote!('a), parse_quote!('static));
+    subst.insert_const(parse_quote!(N), parse_quote!(4));
+    subst.apply(&mut item);
+
+    assert_tokens(
+        &item,
+        quote! {
+            impl Trait<'static> for [u8; 4]
+            where
+                u8: Default,
+                u8: Clone + 'static
+            {
+                type Item = &'static u8;
+
+                fn get(&self) -> Option<&'static u8> {
+                    let array: [u8; 4] = Default::default();
+                    'a: loop {
+                        break 'a None;
+                    }
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_shadowing() {
+    let mut item: Item = parse_quote! {
+        impl<T> S<T> {
+            fn f<T>(t: T) -> for<'a> fn(&'a T) {
+                fn nested() -> T {}
+                unimplemented!()
+            }
+            fn g(t: T) {}
+        }
+    };
+
+    let mut subst = subst_t_u8();
+    subst.insert_lifetime(parse_quote!('a), parse_quote!('static));
+    subst.apply(&mut item);
+
+    assert_tokens(
+        &item,
+        quote! {
+            impl S<u8> {
+                fn f<T>(t: T) -> for<'a> fn(&'a T) {
+                    fn nested() -> T {}
+                    unimplemented!()
+                }
+                fn g(t: u8) {}
+            }
+        },
+    );
+}
+
+#[test]
+fn test_self() {
+    let mut item: ImplItem = parse_quote! {
+        fn new(value: u8) -> Self {
+            match Self::parse(value) {
+                Self::A => Self { value },
+                Self::B(b) => Self(b),
+            }
+        }
+    };
+
+    let mut subst = Substitution::new();
+    subst.insert_type(
+        Token![Self](Span::call_site()).into(),
+        parse_quote!(Wrapper<u8>),
+    );
+    subst.apply(&mut item);
+
+    assert_tokens(
+        &item,
+        quote! {
+            fn new(value: u8) -> Wrapper<u8> {
+                match Wrapper::<u8>::parse(value) {
+                    Wrapper::<u8>::A => Wrapper::<u8> { value },
+                    Wrapper::<u8>::B(b) => Wrapper::<u8>(b),
+                }
+            }
+        },
+    );
+}
+
+#[test]
+fn test_simultaneous() {
+    let mut subst = Substitution::new();
+    subst.insert_type(parse_quote!(T), parse_quote!(U));
+    subst.insert_type(parse_quote!(U), parse_quote!(T));
+
+    let mut ty: Type = parse_quote!(Result<T, U>);
+    subst.apply(&mut ty);
+    assert_tokens(&ty, quote!(Result<U, T>));
+}
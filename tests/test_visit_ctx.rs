@@ -0,0 +1,156 @@
+mod features;
+
+use quote::quote;
+use syn::visit_ctx::{self, Control, Node, NodeKind, VisitCtx, VisitMutCtx};
+use syn::{parse_quote, Expr, ExprPath, File, Ident, ItemFn};
+
+fn enclosing_fn<'ast>(ancestors: &[Node<'ast>]) -> Option<&'ast Ident> {
+    ancestors.iter().rev().find_map(|node| match node {
+        Node::ItemFn(item) => Some(&item.sig.ident),
+        Node::ImplItemMethod(method) => Some(&method.sig.ident),
+        _ => None,
+    })
+}
+
+#[test]
+fn test_enclosing_fn() {
+    struct FindCall<'a>(&'a str);
+
+    impl<'a, 'ast> VisitCtx<'ast> for FindCall<'a> {
+        type Break = Option<&'ast Ident>;
+
+        fn visit_expr_path(
+            &mut self,
+            node: &'ast ExprPath,
+            ancestors: &[Node<'ast>],
+        ) -> Control<Self::Break> {
+            if node.path.is_ident(self.0) {
+                Control::Break(enclosing_fn(ancestors))
+            } else {
+                Control::Continue
+            }
+        }
+    }
+
+    let file: File = parse_quote! {
+        fn outer() {
+            a();
+        }
+        impl S {
+            fn method(&self) {
+                let _ = || b();
+            }
+        }
+    };
+
+    let mut ancestors = Vec::new();
+    let found = visit_ctx::visit_file(&mut FindCall("b"), &file, &mut ancestors);
+    assert_eq!(found.unwrap_err().unwrap(), "method");
+    let kinds: Vec<NodeKind> = ancestors.iter().map(|node| node.kind()).collect();
+    assert_eq!(
+        kinds,
+        [
+            NodeKind::File,
+            NodeKind::Item,
+            NodeKind::ItemImpl,
+            NodeKind::ImplItem,
+            NodeKind::ImplItemMethod,
+            NodeKind::Block,
+            NodeKind::Stmt,
+            NodeKind::Local,
+            NodeKind::Expr,
+            NodeKind::ExprClosure,
+            NodeKind::Expr,
+            NodeKind::ExprCall,
+            NodeKind::Expr,
+        ],
+    );
+
+    let mut ancestors = Vec::new();
+    let found = visit_ctx::visit_file(&mut FindCall("c"), &file, &mut ancestors);
+    assert!(found.is_ok());
+    assert!(ancestors.is_empty());
+}
+
+#[test]
+fn test_stop_after_first() {
+    struct FirstIdent(usize);
+
+    impl<'ast> VisitCtx<'ast> for FirstIdent {
+        type Break = &'ast Ident;
+
+        fn visit_ident(&mut self, node: &'ast Ident, _: &[Node<'ast>]) -> Control<Self::Break> {
+            self.0 += 1;
+            Control::Break(node)
+        }
+    }
+
+    let expr: Expr = parse_quote!(first + second + third);
+    let mut visitor = FirstIdent(0);
+    let found = visit_ctx::visit_expr(&mut visitor, &expr, &mut Vec::new());
+    assert_eq!(found.unwrap_err(), "first");
+    assert_eq!(visitor.0, 1);
+}
+
+#[test]
+fn test_skip_children() {
+    struct OuterIdents(Vec<String>);
+
+    impl<'ast> VisitCtx<'ast> for OuterIdents {
+        type Break = ();
+
+        fn visit_item_fn(&mut self, node: &'ast ItemFn, ancestors: &[Node<'ast>]) -> Control<()> {
+            if ancestors.iter().any(|node| node.kind() == NodeKind::ItemFn) {
+                Control::SkipChildren
+            } else {
+                Control::Continue
+            }
+        }
+
+        fn visit_ident(&mut self, node: &'ast Ident, _: &[Node<'ast>]) -> Control<()> {
+            self.0.push(node.to_string());
+            Control::Continue
+        }
+    }
+
+    let item: ItemFn = parse_quote! {
+        fn outer() {
+            fn inner() {
+                hidden
+            }
+            shown
+        }
+    };
+    let mut visitor = OuterIdents(Vec::new());
+    visit_ctx::visit_item_fn(&mut visitor, &item, &mut Vec::new()).unwrap();
+    assert_eq!(visitor.0, ["outer", "shown"]);
+}
+
+#[test]
+fn test_visit_mut() {
+    struct RenameInClosures(usize);
+
+    impl VisitMutCtx for RenameInClosures {
+        type Break = ();
+
+        fn visit_ident_mut(&mut self, node: &mut Ident, ancestors: &[NodeKind]) -> Control<()> {
+            if ancestors.contains(&NodeKind::ExprClosure) && node == "x" {
+                *node = Ident::new("y", node.span());
+                self.0 -= 1;
+                if self.0 == 0 {
+                    return Control::Break(());
+                }
+            }
+            Control::Continue
+        }
+    }
+
+    let mut expr: Expr = parse_quote!(x + (|x| x + x)(x));
+    let mut ancestors = Vec::new();
+    let result = visit_ctx::visit_expr_mut(&mut RenameInClosures(2), &mut expr, &mut ancestors);
+    assert_eq!(result, Err(()));
+    assert_eq!(ancestors.last(), Some(&NodeKind::PathSegment));
+
+    let expected = quote!(x + (|y| y + x)(x));
+    assert_eq!(quote!(#expr).to_string(), expected.to_string());
+}